@@ -5,6 +5,7 @@
 
 pub mod data;
 pub mod persistence;
+pub mod share_card;
 pub mod types;
 
 pub use data::{get_achievement_def, get_achievements_by_category};
@@ -3,17 +3,32 @@
 use super::types::Achievements;
 use std::fs;
 use std::io;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-/// Get the achievements save file path (~/.quest/achievements.json).
+/// Achievements save file name inside the data directory.
+pub const ACHIEVEMENTS_FILE: &str = "achievements.json";
+
+/// Get the achievements save file path (`achievements.json` in the data directory).
 pub fn achievements_save_path() -> io::Result<PathBuf> {
-    let home_dir = dirs::home_dir().ok_or_else(|| {
-        io::Error::new(
-            io::ErrorKind::NotFound,
-            "Could not determine home directory",
-        )
-    })?;
-    Ok(home_dir.join(".quest").join("achievements.json"))
+    Ok(crate::core::paths::quest_dir()?.join(ACHIEVEMENTS_FILE))
+}
+
+/// Read an achievements file from any path, failing if it is missing or invalid.
+pub fn read_achievements_file(path: &Path) -> io::Result<Achievements> {
+    let json = fs::read_to_string(path)?;
+    serde_json::from_str(&json).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// Merge another machine's achievements file into the saved achievements.
+/// Loads `~/.quest/achievements.json`, merges `path` into it (see
+/// `Achievements::merge`), saves, and returns the merged result.
+#[allow(dead_code)]
+pub fn import_and_merge(path: &Path) -> io::Result<Achievements> {
+    let other = read_achievements_file(path)?;
+    let mut achievements = load_achievements();
+    achievements.merge(&other);
+    save_achievements(&achievements)?;
+    Ok(achievements)
 }
 
 /// Load achievements from disk, or return default if not found.
@@ -31,8 +46,11 @@ pub fn load_achievements() -> Achievements {
 
 /// Save achievements to disk.
 pub fn save_achievements(achievements: &Achievements) -> io::Result<()> {
-    let path = achievements_save_path()?;
+    write_achievements_file(achievements, &achievements_save_path()?)
+}
 
+/// Write achievements to any path, creating its directory if needed.
+pub fn write_achievements_file(achievements: &Achievements, path: &Path) -> io::Result<()> {
     // Ensure directory exists
     if let Some(parent) = path.parent() {
         fs::create_dir_all(parent)?;
@@ -73,6 +91,27 @@ mod tests {
         assert!(!default.is_unlocked(AchievementId::SlayerI));
     }
 
+    #[test]
+    fn test_read_achievements_file() {
+        let path = std::env::temp_dir().join(format!(
+            "quest-achievements-import-{}.json",
+            std::process::id()
+        ));
+        let mut achievements = Achievements::default();
+        achievements.unlock(AchievementId::SlayerI, None);
+        fs::write(&path, serde_json::to_string(&achievements).unwrap()).unwrap();
+
+        let loaded = read_achievements_file(&path).unwrap();
+        assert!(loaded.is_unlocked(AchievementId::SlayerI));
+
+        fs::write(&path, "not json").unwrap();
+        let err = read_achievements_file(&path).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        fs::remove_file(&path).unwrap();
+        assert!(read_achievements_file(&path).is_err());
+    }
+
     #[test]
     fn test_achievements_save_path() {
         // Just verify the path generation doesn't panic
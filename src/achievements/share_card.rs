@@ -0,0 +1,101 @@
+//! Shareable Markdown progress card (`quest card`).
+
+use super::data::get_achievement_def;
+use super::types::Achievements;
+use crate::character::prestige::get_prestige_tier;
+use crate::core::game_state::GameState;
+use crate::zones::get_zone;
+
+/// Most recent unlocks listed on the card.
+const CARD_RECENT_ACHIEVEMENTS: usize = 5;
+
+impl Achievements {
+    /// Markdown summary of a character's progress for pasting into chats or forums.
+    /// Lists level, prestige, zone, play time, achievement completion, and the
+    /// most recently unlocked achievements.
+    pub fn to_share_card(&self, state: &GameState) -> String {
+        let progression = &state.zone_progression;
+        let zone = get_zone(progression.current_zone_id);
+        let zone_name = zone.map_or("Unknown", |z| z.name);
+        let subzone_count = zone.map_or(0, |z| z.subzones.len());
+        let prestige = if state.prestige_rank > 0 {
+            format!(
+                "{} ({})",
+                state.prestige_rank,
+                get_prestige_tier(state.prestige_rank).name
+            )
+        } else {
+            "None".to_string()
+        };
+        let hours = state.play_time_seconds / 3600;
+        let minutes = (state.play_time_seconds % 3600) / 60;
+
+        let mut card = format!("## ⚔️ {}\n\n", state.character_name);
+        card.push_str("| | |\n|---|---|\n");
+        card.push_str(&format!("| Level | {} |\n", state.character_level));
+        card.push_str(&format!("| Prestige | {} |\n", prestige));
+        card.push_str(&format!(
+            "| Zone | {}: {} ({}/{}) |\n",
+            progression.current_zone_id, zone_name, progression.current_subzone_id, subzone_count
+        ));
+        card.push_str(&format!("| Play time | {}h {}m |\n", hours, minutes));
+        card.push_str(&format!(
+            "| Achievements | {}/{} ({:.1}%) |\n",
+            self.unlocked_count(),
+            self.total_count(),
+            self.unlock_percentage()
+        ));
+        let titles = self.titles();
+        if !titles.is_empty() {
+            card.push_str(&format!("| Titles | {} |\n", titles.join(", ")));
+        }
+
+        let mut recent: Vec<_> = self.unlocked.iter().collect();
+        recent.sort_by_key(|(_, unlocked)| std::cmp::Reverse(unlocked.unlocked_at));
+        let notable: Vec<String> = recent
+            .into_iter()
+            .filter_map(|(id, _)| get_achievement_def(*id))
+            .take(CARD_RECENT_ACHIEVEMENTS)
+            .map(|def| format!("- {} **{}**: {}", def.icon, def.name, def.description))
+            .collect();
+        if !notable.is_empty() {
+            card.push_str("\n**Recent achievements**\n\n");
+            card.push_str(&notable.join("\n"));
+            card.push('\n');
+        }
+
+        card
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::achievements::types::AchievementId;
+
+    #[test]
+    fn test_card_contains_level_and_achievement_percentage() {
+        let mut state = GameState::new("Sharer".to_string(), 0);
+        state.character_level = 37;
+        let mut achievements = Achievements::default();
+        achievements.unlock(AchievementId::SlayerI, Some("Sharer".to_string()));
+
+        let card = achievements.to_share_card(&state);
+
+        assert!(card.contains("## ⚔️ Sharer"));
+        assert!(card.contains("| Level | 37 |"));
+        assert!(card.contains(&format!("({:.1}%)", achievements.unlock_percentage())));
+        let slayer = get_achievement_def(AchievementId::SlayerI).unwrap();
+        assert!(card.contains(slayer.name));
+    }
+
+    #[test]
+    fn test_card_without_achievements_skips_recent_section() {
+        let state = GameState::new("Fresh".to_string(), 0);
+        let card = Achievements::default().to_share_card(&state);
+
+        assert!(card.contains("| Prestige | None |"));
+        assert!(card.contains("(0.0%)"));
+        assert!(!card.contains("Recent achievements"));
+    }
+}
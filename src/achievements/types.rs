@@ -36,6 +36,18 @@ impl AchievementCategory {
             AchievementCategory::Stats => "Stats",
         }
     }
+
+    /// Title granted once every achievement in the category is unlocked.
+    pub fn completion_title(&self) -> &'static str {
+        match self {
+            AchievementCategory::Combat => "the Relentless",
+            AchievementCategory::Level => "the Seasoned",
+            AchievementCategory::Progression => "the Ascendant",
+            AchievementCategory::Challenges => "the Grandmaster",
+            AchievementCategory::Exploration => "the Wayfarer",
+            AchievementCategory::Stats => "the Meticulous",
+        }
+    }
 }
 
 /// Unique identifier for each achievement.
@@ -183,6 +195,21 @@ pub enum AchievementId {
     HavenArchitect, // All rooms at T3
 }
 
+/// Level thresholds and the achievements they unlock, in ascending order.
+pub const LEVEL_MILESTONES: [(u64, AchievementId); 11] = [
+    (10, AchievementId::Level10),
+    (25, AchievementId::Level25),
+    (50, AchievementId::Level50),
+    (100, AchievementId::Level100),
+    (150, AchievementId::Level150),
+    (200, AchievementId::Level200),
+    (250, AchievementId::Level250),
+    (500, AchievementId::Level500),
+    (750, AchievementId::Level750),
+    (1000, AchievementId::Level1000),
+    (1500, AchievementId::Level1500),
+];
+
 /// Static definition of an achievement.
 #[derive(Debug, Clone)]
 pub struct AchievementDef {
@@ -227,6 +254,10 @@ pub struct Achievements {
     pub zones_fully_cleared: u32,
     pub expanse_cycles_completed: u64,
 
+    /// Categories whose completion title has been granted (never re-granted).
+    #[serde(default)]
+    pub completed_categories: Vec<AchievementCategory>,
+
     /// Achievements unlocked but not yet viewed (not persisted) - for UI indicator
     #[serde(skip)]
     pub pending_notifications: Vec<AchievementId>,
@@ -235,6 +266,10 @@ pub struct Achievements {
     #[serde(skip)]
     pub newly_unlocked: Vec<AchievementId>,
 
+    /// Categories completed this tick that need to be logged (not persisted)
+    #[serde(skip)]
+    pub newly_completed_categories: Vec<AchievementCategory>,
+
     /// Achievements waiting to be shown in modal (accumulation window)
     #[serde(skip)]
     pub modal_queue: Vec<AchievementId>,
@@ -275,9 +310,45 @@ impl Achievements {
             self.accumulation_start = Some(std::time::Instant::now());
         }
 
+        if let Some(def) = super::data::get_achievement_def(id) {
+            if self.grant_category_reward(def.category) {
+                self.newly_completed_categories.push(def.category);
+            }
+        }
+
+        true
+    }
+
+    /// Check if every achievement in a category is unlocked. Categories
+    /// without achievements (the Stats tab) are never complete.
+    pub fn category_complete(&self, category: AchievementCategory) -> bool {
+        let (unlocked, total) = self.count_by_category(category);
+        total > 0 && unlocked == total
+    }
+
+    /// Records a completed category's reward. Returns true only the first
+    /// time, so the title is granted once per account.
+    fn grant_category_reward(&mut self, category: AchievementCategory) -> bool {
+        if !self.category_complete(category) || self.completed_categories.contains(&category) {
+            return false;
+        }
+        self.completed_categories.push(category);
         true
     }
 
+    /// Titles earned by completing achievement categories, in completion order.
+    pub fn titles(&self) -> Vec<&'static str> {
+        self.completed_categories
+            .iter()
+            .map(|category| category.completion_title())
+            .collect()
+    }
+
+    /// Take newly completed categories for logging (clears the list).
+    pub fn take_newly_completed_categories(&mut self) -> Vec<AchievementCategory> {
+        std::mem::take(&mut self.newly_completed_categories)
+    }
+
     /// Get the count of pending achievement notifications.
     pub fn pending_count(&self) -> usize {
         self.pending_notifications.len()
@@ -349,6 +420,54 @@ impl Achievements {
         self.progress.get(&id)
     }
 
+    /// Combines another progress file into this one (e.g. from another machine).
+    ///
+    /// Unlocked sets are unioned, keeping the earlier unlock when both have
+    /// one. Progress entries and aggregate counters take the larger value, so
+    /// merging the same file twice changes nothing. Does not queue notifications.
+    pub fn merge(&mut self, other: &Achievements) {
+        for (id, theirs) in &other.unlocked {
+            let keep_ours = self
+                .unlocked
+                .get(id)
+                .is_some_and(|ours| ours.unlocked_at <= theirs.unlocked_at);
+            if !keep_ours {
+                self.unlocked.insert(*id, theirs.clone());
+            }
+        }
+
+        for (id, theirs) in &other.progress {
+            let ours = self.progress.entry(*id).or_default();
+            ours.current = ours.current.max(theirs.current);
+            ours.target = ours.target.max(theirs.target);
+        }
+
+        self.total_kills = self.total_kills.max(other.total_kills);
+        self.total_bosses_defeated = self.total_bosses_defeated.max(other.total_bosses_defeated);
+        self.total_fish_caught = self.total_fish_caught.max(other.total_fish_caught);
+        self.total_dungeons_completed = self
+            .total_dungeons_completed
+            .max(other.total_dungeons_completed);
+        self.total_minigame_wins = self.total_minigame_wins.max(other.total_minigame_wins);
+        self.highest_prestige_rank = self.highest_prestige_rank.max(other.highest_prestige_rank);
+        self.highest_level = self.highest_level.max(other.highest_level);
+        self.highest_fishing_rank = self.highest_fishing_rank.max(other.highest_fishing_rank);
+        self.zones_fully_cleared = self.zones_fully_cleared.max(other.zones_fully_cleared);
+        self.expanse_cycles_completed = self
+            .expanse_cycles_completed
+            .max(other.expanse_cycles_completed);
+
+        for category in AchievementCategory::ALL {
+            if other.completed_categories.contains(&category) {
+                if !self.completed_categories.contains(&category) {
+                    self.completed_categories.push(category);
+                }
+            } else {
+                self.grant_category_reward(category);
+            }
+        }
+    }
+
     /// Get the total number of achievements.
     pub fn total_count(&self) -> usize {
         use super::data::ALL_ACHIEVEMENTS;
@@ -504,23 +623,7 @@ impl Achievements {
             self.highest_level = new_level;
         }
 
-        self.check_milestones(
-            new_level as u64,
-            &[
-                (10, AchievementId::Level10),
-                (25, AchievementId::Level25),
-                (50, AchievementId::Level50),
-                (100, AchievementId::Level100),
-                (150, AchievementId::Level150),
-                (200, AchievementId::Level200),
-                (250, AchievementId::Level250),
-                (500, AchievementId::Level500),
-                (750, AchievementId::Level750),
-                (1000, AchievementId::Level1000),
-                (1500, AchievementId::Level1500),
-            ],
-            character_name,
-        );
+        self.check_milestones(new_level as u64, &LEVEL_MILESTONES, character_name);
     }
 
     /// Called when the character prestiges.
@@ -932,6 +1035,93 @@ mod tests {
         assert_eq!(progress.target, 100);
     }
 
+    #[test]
+    fn test_completing_a_category_grants_its_title_once() {
+        use crate::achievements::get_achievements_by_category;
+
+        let mut achievements = Achievements::default();
+        let defs = get_achievements_by_category(AchievementCategory::Level);
+        let (last, rest) = defs.split_last().unwrap();
+        for def in rest {
+            achievements.unlock(def.id, None);
+        }
+        assert!(!achievements.category_complete(AchievementCategory::Level));
+        assert!(achievements.take_newly_completed_categories().is_empty());
+
+        achievements.unlock(last.id, None);
+        assert!(achievements.category_complete(AchievementCategory::Level));
+        assert_eq!(
+            achievements.take_newly_completed_categories(),
+            vec![AchievementCategory::Level]
+        );
+        assert_eq!(achievements.titles(), vec!["the Seasoned"]);
+
+        // Re-unlocking and merging an identical file never re-grants it
+        assert!(!achievements.unlock(last.id, None));
+        let copy = achievements.clone();
+        achievements.merge(&copy);
+        assert!(achievements.take_newly_completed_categories().is_empty());
+        assert_eq!(achievements.completed_categories.len(), 1);
+
+        // The Stats tab has no achievements, so it never completes
+        assert!(!achievements.category_complete(AchievementCategory::Stats));
+    }
+
+    #[test]
+    fn test_merge_unions_unlocks_and_keeps_max_progress() {
+        let mut home = Achievements::default();
+        home.unlock(AchievementId::SlayerI, Some("Home".to_string()));
+        home.update_progress(AchievementId::SlayerII, 400, 1000);
+        home.total_kills = 400;
+        home.highest_level = 30;
+
+        let mut laptop = Achievements::default();
+        laptop.unlock(AchievementId::SlayerI, Some("Laptop".to_string()));
+        laptop.unlock(AchievementId::BossHunterI, Some("Laptop".to_string()));
+        laptop.update_progress(AchievementId::SlayerII, 700, 1000);
+        laptop.update_progress(AchievementId::BossHunterII, 3, 10);
+        laptop.total_kills = 700;
+        laptop.highest_level = 12;
+        // Unlocked earlier on the laptop than at home
+        laptop
+            .unlocked
+            .get_mut(&AchievementId::SlayerI)
+            .unwrap()
+            .unlocked_at -= 100;
+        home.take_modal_queue();
+        home.clear_pending_notifications();
+        home.clear_recently_unlocked();
+
+        home.merge(&laptop);
+
+        assert!(home.is_unlocked(AchievementId::SlayerI));
+        assert!(home.is_unlocked(AchievementId::BossHunterI));
+        assert_eq!(home.unlocked_count(), 2);
+        assert_eq!(
+            home.unlocked[&AchievementId::SlayerI].character_name,
+            Some("Laptop".to_string())
+        );
+        assert_eq!(
+            home.get_progress(AchievementId::SlayerII).unwrap().current,
+            700
+        );
+        assert_eq!(
+            home.get_progress(AchievementId::BossHunterII)
+                .unwrap()
+                .current,
+            3
+        );
+        assert_eq!(home.total_kills, 700);
+        assert_eq!(home.highest_level, 30);
+        assert_eq!(home.pending_count(), 0);
+
+        // Merging again is a no-op
+        let before = home.clone();
+        home.merge(&laptop);
+        assert_eq!(home.total_kills, before.total_kills);
+        assert_eq!(home.unlocked_count(), before.unlocked_count());
+    }
+
     #[test]
     fn test_category_names() {
         assert_eq!(AchievementCategory::Combat.name(), "Combat");
@@ -14,13 +14,21 @@
 //!   --verbose       Per-tick event logging
 //!   --csv FILE      Write time-series CSV
 //!   --quiet         Only final summary line
+//!   --bench         Run the fixed combat benchmark and print its end state
 
 use quest::achievements::Achievements;
 use quest::character::attributes::AttributeType;
 use quest::character::derived_stats::DerivedStats;
+use quest::character::prestige::PrestigeCombatBonuses;
+use quest::combat::logic::damage_after_defense;
+use quest::core::constants::TICKS_PER_SECOND;
 use quest::core::game_state::GameState;
 use quest::core::tick::{game_tick, TickEvent, TickResult};
 use quest::haven::Haven;
+use quest::simulator::analysis::{afk_survival, AfkBuild};
+use quest::simulator::bench::run_fixed_scenario;
+use quest::zones::data::enemy_stats_for;
+use quest::zones::{get_all_zones, get_zone};
 use rand::SeedableRng;
 use rand_chacha::ChaCha8Rng;
 use std::collections::HashMap;
@@ -37,6 +45,9 @@ struct SimConfig {
     csv_path: Option<String>,
     quiet: bool,
     stormbreaker: bool,
+    bench: bool,
+    /// Uniform attribute value for the per-zone AFK survival report
+    afk: Option<u32>,
 }
 
 impl Default for SimConfig {
@@ -50,6 +61,8 @@ impl Default for SimConfig {
             csv_path: None,
             quiet: false,
             stormbreaker: false,
+            bench: false,
+            afk: None,
         }
     }
 }
@@ -83,6 +96,11 @@ fn parse_args() -> SimConfig {
             }
             "--quiet" => config.quiet = true,
             "--stormbreaker" => config.stormbreaker = true,
+            "--bench" => config.bench = true,
+            "--afk" => {
+                i += 1;
+                config.afk = Some(args[i].parse().expect("--afk requires a number"));
+            }
             "--help" | "-h" => {
                 print_usage();
                 std::process::exit(0);
@@ -113,12 +131,24 @@ fn print_usage() {
          \x20 --csv FILE      Write time-series CSV\n\
          \x20 --quiet         Only final summary line\n\
          \x20 --stormbreaker  Unlock Stormbreaker achievement (access Zone 10 boss)\n\
+         \x20 --bench         Run the fixed combat benchmark (uses --ticks, --seed, --runs)\n\
+         \x20 --afk N         AFK survival per zone, all attributes at N (uses --ticks, --prestige)\n\
          \x20 --help, -h      Show this help"
     );
 }
 
 // ── Simulation Statistics ────────────────────────────────────────────
 
+/// Player damage against normal mobs on first entering a zone.
+#[derive(Debug, Clone, Copy)]
+struct ZoneDamage {
+    /// Pre-crit damage before enemy defense
+    raw: u32,
+    enemy_defense: u32,
+    /// Damage per non-crit hit after enemy defense
+    effective: u32,
+}
+
 #[derive(Debug, Clone)]
 struct SimStats {
     total_ticks: u64,
@@ -131,6 +161,7 @@ struct SimStats {
     zone_entry_tick: HashMap<(u32, u32), u64>,
     zone_boss_defeated_tick: HashMap<(u32, u32), u64>,
     deaths_per_zone: HashMap<(u32, u32), u64>,
+    damage_per_zone: HashMap<(u32, u32), ZoneDamage>,
     items_by_rarity: [u64; 5],
     items_equipped: u64,
     boss_items_dropped: u64,
@@ -163,6 +194,7 @@ impl Default for SimStats {
             zone_entry_tick: HashMap::new(),
             zone_boss_defeated_tick: HashMap::new(),
             deaths_per_zone: HashMap::new(),
+            damage_per_zone: HashMap::new(),
             items_by_rarity: [0; 5],
             items_equipped: 0,
             boss_items_dropped: 0,
@@ -190,6 +222,24 @@ impl SimStats {
             .or_insert(tick);
     }
 
+    /// Records the player's damage after defense against the zone's normal mobs.
+    fn record_zone_damage(&mut self, state: &GameState, zone_id: u32, subzone_id: u32) {
+        let depth = get_zone(zone_id)
+            .and_then(|z| z.subzones.iter().find(|s| s.id == subzone_id))
+            .map_or(1, |s| s.depth);
+        let derived = DerivedStats::calculate_derived_stats(&state.attributes, &state.equipment);
+        let raw = derived.total_damage()
+            + PrestigeCombatBonuses::from_rank(state.prestige_rank).flat_damage;
+        let enemy_defense = enemy_stats_for(zone_id, depth).defense_min;
+        self.damage_per_zone
+            .entry((zone_id, subzone_id))
+            .or_insert(ZoneDamage {
+                raw,
+                enemy_defense,
+                effective: damage_after_defense(raw, enemy_defense),
+            });
+    }
+
     fn process_tick(
         &mut self,
         tick: u64,
@@ -326,6 +376,7 @@ fn run_simulation(config: &SimConfig, seed: u64) -> (SimStats, GameState) {
         state.zone_progression.current_subzone_id,
     );
     stats.record_zone_entry(0, prev_zone.0, prev_zone.1);
+    stats.record_zone_damage(&state, prev_zone.0, prev_zone.1);
 
     // CSV writer (if requested)
     let mut csv_writer = config.csv_path.as_ref().map(|path| {
@@ -339,7 +390,10 @@ fn run_simulation(config: &SimConfig, seed: u64) -> (SimStats, GameState) {
         w
     });
 
+    // Simulated wall clock, so world events follow game time
+    let start_time = chrono::Utc::now().timestamp();
     for tick in 0..config.ticks {
+        let now = start_time + (tick / TICKS_PER_SECOND as u64) as i64;
         let result = game_tick(
             &mut state,
             &mut tick_counter,
@@ -347,6 +401,7 @@ fn run_simulation(config: &SimConfig, seed: u64) -> (SimStats, GameState) {
             &mut achievements,
             false,
             &mut rng,
+            now,
         );
 
         // Detect zone changes
@@ -356,6 +411,7 @@ fn run_simulation(config: &SimConfig, seed: u64) -> (SimStats, GameState) {
         );
         if curr_zone != prev_zone {
             stats.record_zone_entry(tick, curr_zone.0, curr_zone.1);
+            stats.record_zone_damage(&state, curr_zone.0, curr_zone.1);
             prev_zone = curr_zone;
         }
 
@@ -627,6 +683,20 @@ fn print_summary(stats: &SimStats, seed: u64, config: &SimConfig) {
         println!();
     }
 
+    // Damage after defense by zone
+    if !stats.damage_per_zone.is_empty() {
+        let mut damage_zones: Vec<_> = stats.damage_per_zone.iter().collect();
+        damage_zones.sort_by_key(|&(k, _)| (k.0, k.1));
+        println!("--- Damage vs Defense by Zone (on entry) ---");
+        for ((z, s), d) in &damage_zones {
+            println!(
+                "  Zone {z}-{s}: {} dmg - {} def = {} per hit",
+                d.raw, d.enemy_defense, d.effective
+            );
+        }
+        println!();
+    }
+
     // Deaths by zone
     if !stats.deaths_per_zone.is_empty() {
         let mut death_zones: Vec<_> = stats.deaths_per_zone.iter().collect();
@@ -746,6 +816,22 @@ fn print_multi_run_summary(all_stats: &[SimStats]) {
 fn main() {
     let config = parse_args();
 
+    if config.bench {
+        for run in 0..config.runs {
+            let result = run_fixed_scenario(config.seed + run as u64, config.ticks);
+            println!("{}", result.summary());
+        }
+        return;
+    }
+
+    if let Some(attribute_value) = config.afk {
+        let build = AfkBuild::uniform(config.prestige, attribute_value);
+        for zone in get_all_zones() {
+            println!("{}", afk_survival(zone.id, build, config.ticks).summary());
+        }
+        return;
+    }
+
     if !config.quiet {
         eprintln!(
             "Quest Simulator: {} ticks ({}) x {} run(s), seed={}, prestige=P{}, stormbreaker={}",
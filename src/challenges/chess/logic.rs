@@ -166,6 +166,33 @@ pub fn process_ai_thinking<R: Rng>(game: &mut ChessGame, rng: &mut R) {
     }
 }
 
+/// Plays the player's turn for the auto-challenge setting: the engine at
+/// Novice strength picks the move, which then goes through the same path as
+/// a move made on the board. Returns true if a move was played.
+pub fn auto_play_move<R: Rng>(game: &mut ChessGame, rng: &mut R) -> bool {
+    if game.game_result.is_some() || game.ai_thinking || !game.is_player_turn() {
+        return false;
+    }
+
+    let home_rank = if game.player_is_white { 0 } else { 7 };
+    let (from, to) = match get_ai_move(&game.board, ChessDifficulty::Novice, rng) {
+        chess_engine::Move::Piece(from, to) => (
+            (from.get_col() as u8, from.get_row() as u8),
+            (to.get_col() as u8, to.get_row() as u8),
+        ),
+        chess_engine::Move::KingSideCastle => ((4, home_rank), (6, home_rank)),
+        chess_engine::Move::QueenSideCastle => ((4, home_rank), (2, home_rank)),
+        chess_engine::Move::Resign => {
+            game.game_result = Some(ChessResult::Loss);
+            return true;
+        }
+    };
+    game.selected_square = Some(from);
+    game.legal_move_destinations = vec![to];
+    game.cursor = to;
+    game.try_move_to_cursor()
+}
+
 /// Extract from/to squares from a Move
 fn extract_move_squares(m: &chess_engine::Move, player_is_white: bool) -> ((u8, u8), (u8, u8)) {
     match m {
@@ -218,7 +245,11 @@ pub fn check_game_over(game: &mut ChessGame) {
 
 /// Apply game result: update stats, grant rewards, and add combat log entries.
 /// Returns Some(MinigameWinInfo) if the player won, None otherwise.
-pub fn apply_game_result(state: &mut GameState) -> Option<crate::challenges::MinigameWinInfo> {
+/// `today` decides whether this was the challenge of the day.
+pub fn apply_game_result(
+    state: &mut GameState,
+    today: chrono::NaiveDate,
+) -> Option<crate::challenges::MinigameWinInfo> {
     use crate::challenges::menu::DifficultyInfo;
     use crate::challenges::{apply_challenge_rewards, GameResultInfo};
 
@@ -236,7 +267,9 @@ pub fn apply_game_result(state: &mut GameState) -> Option<crate::challenges::Min
     let (won, loss_message) = match result {
         ChessResult::Win => {
             state.chess_stats.games_won += 1;
-            state.chess_stats.prestige_earned += reward.prestige_ranks;
+            state.chess_stats.prestige_earned += reward
+                .scaled_for_prestige(state.prestige_rank)
+                .prestige_ranks;
             (true, "")
         }
         ChessResult::Loss => {
@@ -263,6 +296,7 @@ pub fn apply_game_result(state: &mut GameState) -> Option<crate::challenges::Min
             win_message: "Checkmate! You defeated the mysterious figure.",
             loss_message,
         },
+        today,
     )
 }
 
@@ -292,6 +326,18 @@ mod tests {
         assert!(!state.challenge_menu.is_open);
     }
 
+    #[test]
+    fn test_auto_play_move_plays_for_the_player_then_waits() {
+        let mut game = ChessGame::new(ChessDifficulty::Master);
+        let mut rng = rand::rng();
+
+        assert!(auto_play_move(&mut game, &mut rng));
+        assert_eq!(game.move_history.len(), 1);
+        assert!(game.ai_thinking);
+        // The AI's turn now
+        assert!(!auto_play_move(&mut game, &mut rng));
+    }
+
     #[test]
     fn test_apply_win_result() {
         let mut state = GameState::new("Test".to_string(), 0);
@@ -300,7 +346,7 @@ mod tests {
         game.game_result = Some(ChessResult::Win);
         state.active_minigame = Some(ActiveMinigame::Chess(Box::new(game)));
 
-        let processed = apply_game_result(&mut state);
+        let processed = apply_game_result(&mut state, crate::challenges::test_date());
         assert!(processed.is_some()); // Win returns Some(MinigameWinInfo)
         assert_eq!(state.prestige_rank, 10); // 5 + 5 (Master reward)
         assert_eq!(state.chess_stats.games_won, 1);
@@ -315,7 +361,7 @@ mod tests {
         game.game_result = Some(ChessResult::Loss);
         state.active_minigame = Some(ActiveMinigame::Chess(Box::new(game)));
 
-        let processed = apply_game_result(&mut state);
+        let processed = apply_game_result(&mut state, crate::challenges::test_date());
         assert!(processed.is_none()); // Loss returns None
         assert_eq!(state.prestige_rank, 5); // Unchanged
         assert_eq!(state.chess_stats.games_lost, 1);
@@ -489,7 +535,7 @@ mod tests {
         game.game_result = Some(ChessResult::Loss);
         state.active_minigame = Some(ActiveMinigame::Chess(Box::new(game)));
 
-        let processed = apply_game_result(&mut state);
+        let processed = apply_game_result(&mut state, crate::challenges::test_date());
         assert!(processed.is_none()); // Forfeit counts as loss, returns None
         assert_eq!(state.chess_stats.games_lost, 1); // Counts as loss
         assert_eq!(state.chess_stats.games_won, 0);
@@ -505,7 +551,7 @@ mod tests {
         game.game_result = Some(ChessResult::Draw);
         state.active_minigame = Some(ActiveMinigame::Chess(Box::new(game)));
 
-        let processed = apply_game_result(&mut state);
+        let processed = apply_game_result(&mut state, crate::challenges::test_date());
         assert!(processed.is_none()); // Draw returns None
         assert_eq!(state.chess_stats.games_drawn, 1);
         assert_eq!(state.prestige_rank, 5); // Unchanged
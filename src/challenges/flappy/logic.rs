@@ -231,7 +231,11 @@ impl DifficultyInfo for FlappyBirdDifficulty {
 
 /// Apply game result using the shared challenge reward system.
 /// Returns `Some(MinigameWinInfo)` if the player won, `None` otherwise.
-pub fn apply_game_result(state: &mut GameState) -> Option<MinigameWinInfo> {
+/// `today` decides whether this was the challenge of the day.
+pub fn apply_game_result(
+    state: &mut GameState,
+    today: chrono::NaiveDate,
+) -> Option<MinigameWinInfo> {
     let (result, difficulty, score, target) = {
         if let Some(ActiveMinigame::FlappyBird(ref game)) = state.active_minigame {
             (
@@ -281,6 +285,7 @@ pub fn apply_game_result(state: &mut GameState) -> Option<MinigameWinInfo> {
             win_message: "Skyward Gauntlet conquered!",
             loss_message: "The gauntlet claims another.",
         },
+        today,
     )
 }
 
@@ -622,7 +627,7 @@ mod tests {
         game.score = 15;
         state.active_minigame = Some(ActiveMinigame::FlappyBird(game));
 
-        let result = apply_game_result(&mut state);
+        let result = apply_game_result(&mut state, crate::challenges::test_date());
         assert!(result.is_some());
         let info = result.unwrap();
         assert_eq!(info.game_type, "flappy_bird");
@@ -640,7 +645,7 @@ mod tests {
         game.game_result = Some(FlappyBirdResult::Loss);
         state.active_minigame = Some(ActiveMinigame::FlappyBird(game));
 
-        let result = apply_game_result(&mut state);
+        let result = apply_game_result(&mut state, crate::challenges::test_date());
         assert!(result.is_none());
         assert_eq!(state.character_xp, initial_xp);
         assert!(state.active_minigame.is_none());
@@ -651,7 +656,7 @@ mod tests {
         let mut state = GameState::new("Test".to_string(), 0);
         state.active_minigame = None;
 
-        let result = apply_game_result(&mut state);
+        let result = apply_game_result(&mut state, crate::challenges::test_date());
         assert!(result.is_none());
     }
 
@@ -1,6 +1,6 @@
 //! Go game logic: placement, capture, ko, scoring.
 
-use super::types::{GoDifficulty, GoGame, GoMove, GoResult, Stone, BOARD_SIZE};
+use super::types::{GoDifficulty, GoGame, GoMove, GoResult, Owner, Stone, BOARD_SIZE};
 use std::collections::HashSet;
 
 /// Get all stones in the same group as the stone at (row, col).
@@ -253,7 +253,7 @@ pub fn make_move(game: &mut GoGame, mv: GoMove) -> bool {
 
 /// End the game and calculate scores using Chinese rules.
 fn end_game_by_scoring(game: &mut GoGame) {
-    let (black_score, white_score) = calculate_score(&game.board);
+    let (black_score, white_score) = calculate_score(&game.board, game.komi);
 
     // Determine winner (Black plays as human)
     game.game_result = Some(if black_score > white_score {
@@ -265,45 +265,45 @@ fn end_game_by_scoring(game: &mut GoGame) {
     });
 }
 
-/// Calculate scores using Chinese rules (stones + territory).
-pub fn calculate_score(board: &[[Option<Stone>; BOARD_SIZE]; BOARD_SIZE]) -> (i32, i32) {
-    let mut black_score = 0i32;
-    let mut white_score = 0i32;
-    let mut counted = [[false; BOARD_SIZE]; BOARD_SIZE];
-
-    // Count stones
-    for row in board {
-        for cell in row {
-            match cell {
-                Some(Stone::Black) => black_score += 1,
-                Some(Stone::White) => white_score += 1,
-                None => {}
-            }
+/// Calculate scores using Chinese rules (stones + territory), with `komi`
+/// added to White.
+pub fn calculate_score(board: &[[Option<Stone>; BOARD_SIZE]; BOARD_SIZE], komi: f64) -> (f64, f64) {
+    let mut black_score = 0.0;
+    let mut white_score = komi;
+
+    // Stones and territory (empty regions completely surrounded by one color)
+    for owner in ownership_map(board).iter().flatten() {
+        match owner {
+            Owner::Black => black_score += 1.0,
+            Owner::White => white_score += 1.0,
+            Owner::Neutral => {} // Contested - no points
         }
     }
 
-    // Count territory (empty regions completely surrounded by one color)
+    (black_score, white_score)
+}
+
+/// Ownership of every point on the board, indexed `[row][col]`.
+pub fn ownership_map(board: &[[Option<Stone>; BOARD_SIZE]; BOARD_SIZE]) -> Vec<Vec<Owner>> {
+    let mut map: Vec<Vec<Owner>> = board
+        .iter()
+        .map(|row| row.iter().map(|&cell| Owner::from(cell)).collect())
+        .collect();
+    let mut counted = [[false; BOARD_SIZE]; BOARD_SIZE];
+
     for (row, board_row) in board.iter().enumerate() {
         for (col, cell) in board_row.iter().enumerate() {
             if cell.is_none() && !counted[row][col] {
                 let (region, owner) = get_empty_region(board, row, col);
                 for &(r, c) in &region {
                     counted[r][c] = true;
-                }
-                match owner {
-                    Some(Stone::Black) => black_score += region.len() as i32,
-                    Some(Stone::White) => white_score += region.len() as i32,
-                    None => {} // Contested - no points
+                    map[r][c] = Owner::from(owner);
                 }
             }
         }
     }
 
-    // Apply komi (6.5 points to White for going second)
-    // We use integer math, so 6 points (simplified)
-    white_score += 6;
-
-    (black_score, white_score)
+    map
 }
 
 /// Get an empty region and determine its owner (if surrounded by one color).
@@ -347,7 +347,7 @@ fn get_empty_region(
     (region, owner)
 }
 
-use super::mcts::mcts_best_move;
+use super::mcts::mcts_search;
 use crate::challenges::ActiveMinigame;
 
 /// Input actions for Go game (UI-agnostic).
@@ -417,8 +417,10 @@ pub fn process_input(game: &mut GoGame, input: GoInput) -> bool {
 
 /// Apply Go game result to state (rewards for win, clear game).
 /// Returns Some(MinigameWinInfo) if the player won, None otherwise.
+/// `today` decides whether this was the challenge of the day.
 pub fn apply_go_result(
     state: &mut crate::core::game_state::GameState,
+    today: chrono::NaiveDate,
 ) -> Option<crate::challenges::MinigameWinInfo> {
     use crate::challenges::menu::DifficultyInfo;
     use crate::challenges::{apply_challenge_rewards, GameResultInfo};
@@ -448,6 +450,7 @@ pub fn apply_go_result(
             win_message: "Victory! The master bows in respect.",
             loss_message,
         },
+        today,
     )
 }
 
@@ -494,6 +497,57 @@ pub fn process_human_pass(game: &mut GoGame) -> bool {
     true
 }
 
+/// Plays the player's turn for the auto-challenge setting: the legal move
+/// that captures the most stones, never filling one of its own eyes, ties
+/// broken at random; passes once no such move is left.
+/// Returns true if a move (or pass) was played.
+pub fn auto_play_move<R: rand::Rng>(game: &mut GoGame, rng: &mut R) -> bool {
+    use rand::seq::IndexedRandom;
+
+    if game.game_result.is_some() || game.ai_thinking || game.current_player != Stone::Black {
+        return false;
+    }
+
+    let fills_own_eye = |row: usize, col: usize| {
+        [(-1i32, 0i32), (1, 0), (0, -1), (0, 1)]
+            .iter()
+            .all(|(dr, dc)| {
+                let nr = row as i32 + dr;
+                let nc = col as i32 + dc;
+                nr < 0
+                    || nc < 0
+                    || nr >= BOARD_SIZE as i32
+                    || nc >= BOARD_SIZE as i32
+                    || game.board[nr as usize][nc as usize] == Some(Stone::Black)
+            })
+    };
+    let scored: Vec<((usize, usize), u32)> = get_legal_moves(game)
+        .into_iter()
+        .filter_map(|mv| match mv {
+            GoMove::Place(row, col) if !fills_own_eye(row, col) => {
+                let mut after = game.clone();
+                make_move(&mut after, mv);
+                Some(((row, col), after.captured_by_black - game.captured_by_black))
+            }
+            _ => None,
+        })
+        .collect();
+    let best = scored.iter().map(|&(_, captured)| captured).max();
+    let best_moves: Vec<(usize, usize)> = scored
+        .into_iter()
+        .filter(|&(_, captured)| Some(captured) == best)
+        .map(|(pos, _)| pos)
+        .collect();
+
+    match best_moves.choose(rng) {
+        Some(&pos) => {
+            game.cursor = pos;
+            process_human_move(game)
+        }
+        None => process_human_pass(game),
+    }
+}
+
 /// Process AI turn (called each tick while ai_thinking is true).
 pub fn process_ai_thinking<R: rand::Rng>(game: &mut GoGame, rng: &mut R) {
     if !game.ai_thinking || game.game_result.is_some() {
@@ -514,14 +568,15 @@ pub fn process_ai_thinking<R: rand::Rng>(game: &mut GoGame, rng: &mut R) {
     }
 
     // Get AI move using MCTS
-    let ai_move = mcts_best_move(game, rng);
+    let (ai_move, iterations) = mcts_search(game, rng);
+    game.ai_search_iterations = iterations;
     make_move(game, ai_move);
     game.ai_thinking = false;
 }
 
 #[cfg(test)]
 mod tests {
-    use super::super::types::GoDifficulty;
+    use super::super::types::{GoDifficulty, DEFAULT_KOMI, MAX_HANDICAP};
     use super::*;
 
     fn place(
@@ -788,10 +843,10 @@ mod tests {
     #[test]
     fn test_calculate_score_empty_board() {
         let board = [[None; BOARD_SIZE]; BOARD_SIZE];
-        let (black, white) = calculate_score(&board);
+        let (black, white) = calculate_score(&board, DEFAULT_KOMI);
         // Empty board = 0 + 0 stones, all territory contested, white gets 6 komi
-        assert_eq!(black, 0);
-        assert_eq!(white, 6);
+        assert_eq!(black, 0.0);
+        assert_eq!(white, 6.0);
     }
 
     #[test]
@@ -810,11 +865,149 @@ mod tests {
         board[4][4] = Some(Stone::White);
         board[5][5] = Some(Stone::White);
 
-        let (black, white) = calculate_score(&board);
+        let (black, white) = calculate_score(&board, DEFAULT_KOMI);
         // Black: 5 stones + 4 territory (positions (0,0), (0,1), (1,0), (1,1)) = 9
         // White: 2 stones + 0 territory + 6 komi = 8
         // Rest of board is contested (touches both colors or neither)
-        assert_eq!(black, 9);
-        assert_eq!(white, 8);
+        assert_eq!(black, 9.0);
+        assert_eq!(white, 8.0);
+    }
+
+    #[test]
+    fn test_ownership_map_matches_score() {
+        let mut game = GoGame::new(GoDifficulty::Novice);
+        // Black walls off the top-left corner: 4 points of territory
+        for (r, c) in [(0, 2), (1, 2), (2, 0), (2, 1), (2, 2)] {
+            game.board[r][c] = Some(Stone::Black);
+        }
+        // White walls off the bottom-right corner: 1 point of territory
+        for (r, c) in [(7, 8), (8, 7)] {
+            game.board[r][c] = Some(Stone::White);
+        }
+
+        let map = game.ownership_map();
+        let count = |owner: Owner| map.iter().flatten().filter(|&&o| o == owner).count() as i32;
+
+        // Enclosed points belong to the surrounding color
+        for (r, c) in [(0, 0), (0, 1), (1, 0), (1, 1)] {
+            assert_eq!(map[r][c], Owner::Black);
+        }
+        assert_eq!(map[8][8], Owner::White);
+        // Open area touches both colors: dame
+        assert_eq!(map[4][4], Owner::Neutral);
+
+        let (black, white) = calculate_score(&game.board, game.komi);
+        assert_eq!(count(Owner::Black), 9); // 5 stones + 4 territory
+        assert_eq!(count(Owner::White), 3); // 2 stones + 1 territory
+        assert_eq!(black, count(Owner::Black) as f64);
+        assert_eq!(white, count(Owner::White) as f64 + DEFAULT_KOMI);
+    }
+
+    fn ai_search_iterations_with_budget(playouts: u32) -> u32 {
+        let mut game = GoGame::new_with_playouts(GoDifficulty::Novice, playouts);
+        let mut rng = rand::rng();
+        make_move(&mut game, GoMove::Place(4, 4));
+        game.ai_thinking = true;
+        while game.ai_thinking {
+            process_ai_thinking(&mut game, &mut rng);
+        }
+        game.ai_search_iterations
+    }
+
+    #[test]
+    fn test_playout_budget_controls_search_iterations() {
+        assert_eq!(
+            GoGame::new(GoDifficulty::Master).playouts,
+            GoDifficulty::Master.simulation_count()
+        );
+
+        let low = ai_search_iterations_with_budget(50);
+        let high = ai_search_iterations_with_budget(200);
+        assert_eq!(low, 50);
+        assert_eq!(high, 200);
+    }
+
+    #[test]
+    fn test_komi_added_to_white_final_score() {
+        let mut board = [[None; BOARD_SIZE]; BOARD_SIZE];
+        board[4][4] = Some(Stone::White);
+        let (black, white) = calculate_score(&board, 7.5);
+        // White's lone stone owns the whole board: 81 points + komi
+        assert_eq!(black, 0.0);
+        assert_eq!(white, 81.0 + 7.5);
+
+        // Half-point komi breaks what would otherwise be a tie
+        let mut game = GoGame::new(GoDifficulty::Novice);
+        game.komi = 0.5;
+        make_move(&mut game, GoMove::Pass);
+        make_move(&mut game, GoMove::Pass);
+        assert_eq!(game.game_result, Some(GoResult::Loss));
+    }
+
+    #[test]
+    fn test_default_game_keeps_standard_komi() {
+        let game = GoGame::new(GoDifficulty::Novice);
+        assert_eq!(game.komi, DEFAULT_KOMI);
+        assert_eq!(game.handicap, 0);
+        assert_eq!(game.current_player, Stone::Black);
+    }
+
+    #[test]
+    fn test_auto_play_move_prefers_captures() {
+        let mut game = GoGame::new(GoDifficulty::Novice);
+        let mut rng = rand::rng();
+        // White stone at (0,0) in atari: Black at (0,1), liberty at (1,0)
+        place(&mut game.board, 0, 0, Stone::White);
+        place(&mut game.board, 0, 1, Stone::Black);
+
+        assert!(auto_play_move(&mut game, &mut rng));
+        assert_eq!(game.board[1][0], Some(Stone::Black));
+        assert_eq!(game.captured_by_black, 1);
+        assert!(game.ai_thinking);
+    }
+
+    #[test]
+    fn test_auto_play_move_passes_instead_of_filling_own_eyes() {
+        let mut game = GoGame::new(GoDifficulty::Novice);
+        let mut rng = rand::rng();
+        // Black everywhere but two single-point eyes
+        for row in 0..BOARD_SIZE {
+            for col in 0..BOARD_SIZE {
+                place(&mut game.board, row, col, Stone::Black);
+            }
+        }
+        game.board[0][0] = None;
+        game.board[8][8] = None;
+
+        assert!(auto_play_move(&mut game, &mut rng));
+        assert_eq!(game.last_move, Some(GoMove::Pass));
+        assert!(game.board[0][0].is_none() && game.board[8][8].is_none());
+    }
+
+    #[test]
+    fn test_handicap_places_stones_on_star_points() {
+        for stones in 2..=MAX_HANDICAP {
+            let game = GoGame::new_with_handicap(GoDifficulty::Novice, stones);
+            let placed: Vec<_> = game
+                .board
+                .iter()
+                .flatten()
+                .filter(|&&cell| cell == Some(Stone::Black))
+                .collect();
+            assert_eq!(placed.len(), stones as usize);
+            assert_eq!(game.handicap, stones);
+            // White moves first in a handicap game
+            assert_eq!(game.current_player, Stone::White);
+            assert!(game.ai_thinking);
+        }
+
+        // Odd counts from 5 include the center; even counts don't
+        assert!(GoGame::new_with_handicap(GoDifficulty::Novice, 5).board[4][4].is_some());
+        assert!(GoGame::new_with_handicap(GoDifficulty::Novice, 6).board[4][4].is_none());
+        // Capped at the number of star points
+        assert_eq!(
+            GoGame::new_with_handicap(GoDifficulty::Novice, 20).handicap,
+            MAX_HANDICAP
+        );
     }
 }
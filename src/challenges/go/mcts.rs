@@ -1,7 +1,7 @@
 //! Monte Carlo Tree Search AI for Go.
 
 use super::logic::{get_legal_moves, is_legal_move, make_move};
-use super::types::{GoDifficulty, GoGame, GoMove, GoResult, Stone, BOARD_SIZE};
+use super::types::{GoGame, GoMove, GoResult, Stone, BOARD_SIZE};
 use rand::{Rng, RngExt};
 
 /// UCT exploration constant
@@ -57,8 +57,15 @@ impl MctsNode {
 }
 
 /// Run MCTS and return the best move.
+#[allow(dead_code)]
 pub fn mcts_best_move<R: Rng>(game: &GoGame, rng: &mut R) -> GoMove {
-    let simulations = game.difficulty.simulation_count();
+    mcts_search(game, rng).0
+}
+
+/// Run MCTS with the game's playout budget.
+/// Returns the best move and the number of search iterations run.
+pub fn mcts_search<R: Rng>(game: &GoGame, rng: &mut R) -> (GoMove, u32) {
+    let simulations = game.playouts;
     let mut nodes: Vec<MctsNode> = Vec::with_capacity(simulations as usize);
 
     // Create root node
@@ -107,7 +114,7 @@ pub fn mcts_best_move<R: Rng>(game: &GoGame, rng: &mut R) -> GoMove {
     }
 
     // Select best move (most visits)
-    select_best_move(&nodes)
+    (select_best_move(&nodes), nodes[0].visits)
 }
 
 /// Select child with highest UCT value.
@@ -157,7 +164,7 @@ fn simulate_random_game<R: Rng>(game: &mut GoGame, rng: &mut R) -> Option<Stone>
         }
     } else {
         // Score the position
-        let (black, white) = super::logic::calculate_score(&game.board);
+        let (black, white) = super::logic::calculate_score(&game.board, game.komi);
         if black > white {
             Some(Stone::Black)
         } else if white > black {
@@ -243,6 +250,7 @@ fn select_best_move(nodes: &[MctsNode]) -> GoMove {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::challenges::go::types::GoDifficulty;
 
     #[test]
     fn test_mcts_returns_move() {
@@ -7,7 +7,7 @@ pub mod mcts;
 pub mod types;
 
 pub use logic::{
-    apply_go_result, calculate_score, get_legal_moves, is_legal_move, make_move,
+    apply_go_result, auto_play_move, calculate_score, get_legal_moves, is_legal_move, make_move,
     process_ai_thinking, process_human_move, process_human_pass, process_input, GoInput,
 };
 pub use types::*;
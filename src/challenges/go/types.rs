@@ -7,6 +7,41 @@ use serde::{Deserialize, Serialize};
 /// Board size (9x9)
 pub const BOARD_SIZE: usize = 9;
 
+/// Points added to White's score for moving second
+/// (whole number, so even boards can still end in a draw)
+pub const DEFAULT_KOMI: f64 = 6.0;
+
+/// Most handicap stones a 9x9 board supports (every star point)
+pub const MAX_HANDICAP: u8 = 9;
+
+/// Star points in standard handicap placement order: opposite corners first,
+/// then the remaining corners, then sides. The center is listed last;
+/// `handicap_points` moves it forward for odd counts from 5 up.
+const HANDICAP_POINTS: [(usize, usize); 9] = [
+    (2, 6),
+    (6, 2),
+    (6, 6),
+    (2, 2),
+    (4, 2),
+    (4, 6),
+    (2, 4),
+    (6, 4),
+    (4, 4),
+];
+
+/// Intersections for `stones` handicap stones (capped at `MAX_HANDICAP`).
+pub fn handicap_points(stones: u8) -> Vec<(usize, usize)> {
+    let stones = stones.min(MAX_HANDICAP) as usize;
+    let center = HANDICAP_POINTS[8];
+    if stones >= 5 && stones % 2 == 1 {
+        let mut points = HANDICAP_POINTS[..stones - 1].to_vec();
+        points.push(center);
+        points
+    } else {
+        HANDICAP_POINTS[..stones].to_vec()
+    }
+}
+
 /// Stone color
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Stone {
@@ -23,6 +58,26 @@ impl Stone {
     }
 }
 
+/// Ownership of a board point under area scoring.
+/// Stones belong to their color; empty points belong to the color that
+/// completely surrounds them, or are neutral (dame) if both colors touch them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Owner {
+    Black,
+    White,
+    Neutral,
+}
+
+impl From<Option<Stone>> for Owner {
+    fn from(stone: Option<Stone>) -> Self {
+        match stone {
+            Some(Stone::Black) => Owner::Black,
+            Some(Stone::White) => Owner::White,
+            None => Owner::Neutral,
+        }
+    }
+}
+
 /// A move in Go
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum GoMove {
@@ -88,6 +143,14 @@ pub struct GoGame {
     pub cursor: (usize, usize),
     /// Difficulty level
     pub difficulty: GoDifficulty,
+    /// MCTS simulations per AI move (the difficulty's `simulation_count` unless overridden)
+    pub playouts: u32,
+    /// Search iterations the AI ran for its last move
+    pub ai_search_iterations: u32,
+    /// Points added to White's final score
+    pub komi: f64,
+    /// Black stones placed before the first move (White then moves first)
+    pub handicap: u8,
     /// Game result (None if in progress)
     pub game_result: Option<GoResult>,
     /// Is AI currently thinking?
@@ -102,6 +165,12 @@ pub struct GoGame {
 
 impl GoGame {
     pub fn new(difficulty: GoDifficulty) -> Self {
+        Self::new_with_playouts(difficulty, difficulty.simulation_count())
+    }
+
+    /// New game with a custom MCTS budget, for a stronger (or faster) AI than
+    /// the difficulty's default. Rewards still follow `difficulty`.
+    pub fn new_with_playouts(difficulty: GoDifficulty, playouts: u32) -> Self {
         Self {
             board: [[None; BOARD_SIZE]; BOARD_SIZE],
             current_player: Stone::Black, // Black plays first in Go
@@ -111,6 +180,10 @@ impl GoGame {
             consecutive_passes: 0,
             cursor: (BOARD_SIZE / 2, BOARD_SIZE / 2), // Center (4, 4)
             difficulty,
+            playouts: playouts.max(1),
+            ai_search_iterations: 0,
+            komi: DEFAULT_KOMI,
+            handicap: 0,
             game_result: None,
             ai_thinking: false,
             ai_think_ticks: 0,
@@ -119,6 +192,24 @@ impl GoGame {
         }
     }
 
+    /// New game with `handicap` black stones on the star points.
+    /// White (the AI) moves first, so it starts thinking right away.
+    /// Komi stays at the default; set `komi` directly to change it.
+    pub fn new_with_handicap(difficulty: GoDifficulty, handicap: u8) -> Self {
+        let mut game = Self::new(difficulty);
+        let points = handicap_points(handicap);
+        if points.is_empty() {
+            return game;
+        }
+        for &(row, col) in &points {
+            game.board[row][col] = Some(Stone::Black);
+        }
+        game.handicap = points.len() as u8;
+        game.current_player = Stone::White;
+        game.ai_thinking = true;
+        game
+    }
+
     /// Move cursor in a direction
     pub fn move_cursor(&mut self, d_row: i32, d_col: i32) {
         let new_row = (self.cursor.0 as i32 + d_row).clamp(0, BOARD_SIZE as i32 - 1) as usize;
@@ -135,6 +226,11 @@ impl GoGame {
     pub fn switch_player(&mut self) {
         self.current_player = self.current_player.opponent();
     }
+
+    /// Ownership of every point on the board, indexed `[row][col]`.
+    pub fn ownership_map(&self) -> Vec<Vec<Owner>> {
+        super::logic::ownership_map(&self.board)
+    }
 }
 
 #[cfg(test)]
@@ -724,9 +724,40 @@ pub fn find_best_move<R: Rng>(game: &GomokuGame, rng: &mut R) -> Option<(usize,
     best_moves.choose(rng).copied()
 }
 
+/// Plays the player's turn for the auto-challenge setting: the candidate with
+/// the best one-move score (no search), ties broken at random.
+/// Returns true if a stone was placed.
+pub fn auto_play_move<R: Rng>(game: &mut GomokuGame, rng: &mut R) -> bool {
+    if game.game_result.is_some() || game.current_player != Player::Human {
+        return false;
+    }
+
+    let scored: Vec<((usize, usize), i32)> = get_candidate_moves(&game.board)
+        .into_iter()
+        .map(|(r, c)| ((r, c), score_move_quick(&game.board, r, c, Player::Human)))
+        .collect();
+    let Some(best) = scored.iter().map(|&(_, score)| score).max() else {
+        return false;
+    };
+    let best_moves: Vec<(usize, usize)> = scored
+        .into_iter()
+        .filter(|&(_, score)| score == best)
+        .map(|(pos, _)| pos)
+        .collect();
+    let Some(&pos) = best_moves.choose(rng) else {
+        return false;
+    };
+    game.cursor = pos;
+    process_human_move(game)
+}
+
 /// Apply game result: update stats, grant rewards, and add combat log entries.
 /// Returns Some(MinigameWinInfo) if the player won, None otherwise.
-pub fn apply_game_result(state: &mut GameState) -> Option<crate::challenges::MinigameWinInfo> {
+/// `today` decides whether this was the challenge of the day.
+pub fn apply_game_result(
+    state: &mut GameState,
+    today: chrono::NaiveDate,
+) -> Option<crate::challenges::MinigameWinInfo> {
     use crate::challenges::menu::DifficultyInfo;
     use crate::challenges::{apply_challenge_rewards, GameResultInfo};
 
@@ -755,6 +786,7 @@ pub fn apply_game_result(state: &mut GameState) -> Option<crate::challenges::Min
             win_message: "Victory! The strategist bows in defeat.",
             loss_message,
         },
+        today,
     )
 }
 
@@ -1167,7 +1199,13 @@ mod ai_tests {
         game.game_result = Some(GomokuResult::Win);
         state.active_minigame = Some(ActiveMinigame::Gomoku(game));
 
-        let result = apply_game_result(&mut state);
+        // 2024-01-03 features Chess, so no challenge of the day bonus applies
+        let date = chrono::NaiveDate::from_ymd_opt(2024, 1, 3).unwrap();
+        assert_ne!(
+            crate::challenges::menu::featured_challenge(date),
+            crate::challenges::menu::ChallengeType::Gomoku
+        );
+        let result = apply_game_result(&mut state, date);
 
         assert!(result.is_some()); // Win returns Some(MinigameWinInfo)
         assert!(
@@ -1191,7 +1229,7 @@ mod ai_tests {
         game.game_result = Some(GomokuResult::Win);
         state.active_minigame = Some(ActiveMinigame::Gomoku(game));
 
-        apply_game_result(&mut state);
+        apply_game_result(&mut state, crate::challenges::test_date());
 
         assert_eq!(state.prestige_rank, initial_prestige + 2);
     }
@@ -1208,7 +1246,7 @@ mod ai_tests {
         game.game_result = Some(GomokuResult::Loss);
         state.active_minigame = Some(ActiveMinigame::Gomoku(game));
 
-        let result = apply_game_result(&mut state);
+        let result = apply_game_result(&mut state, crate::challenges::test_date());
 
         assert!(result.is_none()); // Loss returns None
         assert!(state.active_minigame.is_none()); // But game is still cleared
@@ -1227,7 +1265,7 @@ mod ai_tests {
         game.game_result = Some(GomokuResult::Draw);
         state.active_minigame = Some(ActiveMinigame::Gomoku(game));
 
-        let result = apply_game_result(&mut state);
+        let result = apply_game_result(&mut state, crate::challenges::test_date());
 
         assert!(result.is_none()); // Draw returns None
         assert!(state.active_minigame.is_none()); // But game is still cleared
@@ -1239,7 +1277,7 @@ mod ai_tests {
         use crate::core::game_state::GameState;
 
         let mut state = GameState::new("Test".to_string(), 0);
-        assert!(apply_game_result(&mut state).is_none());
+        assert!(apply_game_result(&mut state, crate::challenges::test_date()).is_none());
     }
 
     #[test]
@@ -1250,7 +1288,7 @@ mod ai_tests {
         let game = GomokuGame::new(GomokuDifficulty::Novice);
         state.active_minigame = Some(ActiveMinigame::Gomoku(game));
 
-        assert!(apply_game_result(&mut state).is_none());
+        assert!(apply_game_result(&mut state, crate::challenges::test_date()).is_none());
     }
 
     #[test]
@@ -1264,7 +1302,7 @@ mod ai_tests {
         game.game_result = Some(GomokuResult::Win);
         state.active_minigame = Some(ActiveMinigame::Gomoku(game));
 
-        apply_game_result(&mut state);
+        apply_game_result(&mut state, crate::challenges::test_date());
 
         assert!(
             state.combat_state.combat_log.len() > initial_log_len,
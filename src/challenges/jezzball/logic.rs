@@ -510,7 +510,11 @@ impl DifficultyInfo for JezzballDifficulty {
 }
 
 /// Apply game result via shared challenge reward system.
-pub fn apply_game_result(state: &mut GameState) -> Option<MinigameWinInfo> {
+/// `today` decides whether this was the challenge of the day.
+pub fn apply_game_result(
+    state: &mut GameState,
+    today: chrono::NaiveDate,
+) -> Option<MinigameWinInfo> {
     let (result, difficulty, captured, target) = {
         if let Some(ActiveMinigame::Jezzball(ref game)) = state.active_minigame {
             (
@@ -560,6 +564,7 @@ pub fn apply_game_result(state: &mut GameState) -> Option<MinigameWinInfo> {
             win_message: "Containment Breach conquered!",
             loss_message: "The arena remains uncontrolled.",
         },
+        today,
     )
 }
 
@@ -753,7 +758,7 @@ mod tests {
         game.captured_percent = 62.0;
         state.active_minigame = Some(ActiveMinigame::Jezzball(game));
 
-        let info = apply_game_result(&mut state);
+        let info = apply_game_result(&mut state, crate::challenges::test_date());
 
         assert!(info.is_some());
         let info = info.unwrap();
@@ -770,7 +775,7 @@ mod tests {
         game.captured_percent = 23.0;
         state.active_minigame = Some(ActiveMinigame::Jezzball(game));
 
-        let info = apply_game_result(&mut state);
+        let info = apply_game_result(&mut state, crate::challenges::test_date());
 
         assert!(info.is_none());
         assert!(state.active_minigame.is_none());
@@ -4,30 +4,39 @@
 //! Challenge discovery uses a single roll per tick. On success, a weighted distribution
 //! table determines which challenge type appears.
 
-use super::chess::{ChessDifficulty, ChessGame};
+use super::chess::{ChessDifficulty, ChessGame, ChessResult};
 use super::flappy::{FlappyBirdDifficulty, FlappyBirdGame};
-use super::go::{GoDifficulty, GoGame};
-use super::gomoku::{GomokuDifficulty, GomokuGame};
+use super::go::{GoDifficulty, GoGame, GoResult, MAX_HANDICAP};
+use super::gomoku::{GomokuDifficulty, GomokuGame, GomokuResult};
 use super::jezzball::logic::start_jezzball_game;
 use super::jezzball::JezzballDifficulty;
 use super::minesweeper::{MinesweeperDifficulty, MinesweeperGame};
-use super::morris::{MorrisDifficulty, MorrisGame};
+use super::morris::{MorrisDifficulty, MorrisGame, MorrisResult};
 use super::rune::{RuneDifficulty, RuneGame};
 use super::snake::logic::start_snake_game;
 use super::snake::SnakeDifficulty;
 use super::ActiveMinigame;
-use crate::core::constants::CHALLENGE_DISCOVERY_CHANCE;
+use crate::core::constants::{
+    AUTO_CHALLENGE_MAX_MOVES, CHALLENGE_DISCOVERY_CHANCE, CHALLENGE_REWARD_SCALING_MAX,
+    CHALLENGE_REWARD_SCALING_PER_PRESTIGE, CHALLENGE_REWARD_SCALING_START_PRESTIGE,
+    FEATURED_CHALLENGE_MIN_XP_PERCENT, FEATURED_CHALLENGE_XP_MULTIPLIER,
+};
 use crate::core::game_state::GameState;
+use chrono::{Datelike, NaiveDate};
 use rand::{Rng, RngExt};
+use serde::{Deserialize, Serialize};
 
 /// Input actions for the Challenge Menu (UI-agnostic).
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum MenuInput {
     Up,
     Down,
-    Select,  // Enter - open detail or accept challenge
-    Decline, // D - decline/remove challenge
-    Cancel,  // Esc/Tab - close detail or close menu
+    Left,     // ← - fewer handicap stones (Go)
+    Right,    // → - more handicap stones (Go)
+    Select,   // Enter - open detail or accept challenge
+    Decline,  // D - decline/remove challenge
+    Practice, // P - play the selected challenge for practice (kept in the menu)
+    Cancel,   // Esc/Tab - close detail or close menu
     Other,
 }
 
@@ -43,12 +52,17 @@ pub fn process_input(state: &mut GameState, input: MenuInput) -> bool {
         match input {
             MenuInput::Up => menu.navigate_up(),
             MenuInput::Down => menu.navigate_down(4),
+            MenuInput::Left => menu.adjust_go_handicap(-1),
+            MenuInput::Right => menu.adjust_go_handicap(1),
             MenuInput::Select => {
                 accept_selected_challenge(state);
             }
             MenuInput::Decline => {
                 decline_selected_challenge(state);
             }
+            MenuInput::Practice => {
+                practice_selected_challenge(state);
+            }
             MenuInput::Cancel => {
                 state.challenge_menu.close_detail();
             }
@@ -60,7 +74,11 @@ pub fn process_input(state: &mut GameState, input: MenuInput) -> bool {
             MenuInput::Down => menu.navigate_down(4),
             MenuInput::Select => menu.open_detail(),
             MenuInput::Cancel => menu.close(),
-            MenuInput::Decline | MenuInput::Other => {}
+            MenuInput::Left
+            | MenuInput::Right
+            | MenuInput::Decline
+            | MenuInput::Practice
+            | MenuInput::Other => {}
         }
     }
 
@@ -70,58 +88,256 @@ pub fn process_input(state: &mut GameState, input: MenuInput) -> bool {
 /// Accept the currently selected challenge and start the appropriate game.
 fn accept_selected_challenge(state: &mut GameState) {
     let difficulty_index = state.challenge_menu.selected_difficulty;
+    let go_handicap = state.challenge_menu.go_handicap;
 
     if let Some(challenge) = state.challenge_menu.take_selected() {
-        let minigame = match challenge.challenge_type {
-            ChallengeType::Chess => {
-                let d = ChessDifficulty::from_index(difficulty_index);
-                ActiveMinigame::Chess(Box::new(ChessGame::new(d)))
-            }
-            ChallengeType::Morris => {
-                let d = MorrisDifficulty::from_index(difficulty_index);
-                ActiveMinigame::Morris(MorrisGame::new(d))
+        state.active_minigame = Some(new_menu_minigame(
+            &challenge.challenge_type,
+            difficulty_index,
+            go_handicap,
+        ));
+        state.practice_minigame = false;
+        state.challenge_menu.close();
+    }
+}
+
+/// Start a practice run of the selected challenge. The challenge stays in the
+/// menu, and the result grants no rewards and records no stats.
+fn practice_selected_challenge(state: &mut GameState) {
+    let difficulty_index = state.challenge_menu.selected_difficulty;
+    let menu = &state.challenge_menu;
+
+    if let Some(challenge) = menu.challenges.get(menu.selected_index) {
+        state.active_minigame = Some(new_menu_minigame(
+            &challenge.challenge_type,
+            difficulty_index,
+            menu.go_handicap,
+        ));
+        state.practice_minigame = true;
+        state.challenge_menu.close();
+    }
+}
+
+/// Build the game started from the menu: like `new_minigame`, but Go games
+/// begin with the handicap stones picked in the detail view.
+fn new_menu_minigame(
+    challenge_type: &ChallengeType,
+    difficulty_index: usize,
+    go_handicap: u8,
+) -> ActiveMinigame {
+    match challenge_type {
+        ChallengeType::Go => {
+            let d = GoDifficulty::from_index(difficulty_index);
+            ActiveMinigame::Go(GoGame::new_with_handicap(d, go_handicap))
+        }
+        _ => new_minigame(challenge_type, difficulty_index),
+    }
+}
+
+/// Build a fresh game of `challenge_type` at the given difficulty index.
+pub(crate) fn new_minigame(
+    challenge_type: &ChallengeType,
+    difficulty_index: usize,
+) -> ActiveMinigame {
+    match challenge_type {
+        ChallengeType::Chess => {
+            let d = ChessDifficulty::from_index(difficulty_index);
+            ActiveMinigame::Chess(Box::new(ChessGame::new(d)))
+        }
+        ChallengeType::Morris => {
+            let d = MorrisDifficulty::from_index(difficulty_index);
+            ActiveMinigame::Morris(MorrisGame::new(d))
+        }
+        ChallengeType::Gomoku => {
+            let d = GomokuDifficulty::from_index(difficulty_index);
+            ActiveMinigame::Gomoku(GomokuGame::new(d))
+        }
+        ChallengeType::Minesweeper => {
+            let d = MinesweeperDifficulty::from_index(difficulty_index);
+            ActiveMinigame::Minesweeper(MinesweeperGame::new(d))
+        }
+        ChallengeType::Rune => {
+            let d = RuneDifficulty::from_index(difficulty_index);
+            ActiveMinigame::Rune(RuneGame::new(d))
+        }
+        ChallengeType::Go => {
+            let d = GoDifficulty::from_index(difficulty_index);
+            ActiveMinigame::Go(GoGame::new(d))
+        }
+        ChallengeType::Jezzball => {
+            let d = JezzballDifficulty::from_index(difficulty_index);
+            start_jezzball_game(d)
+        }
+        ChallengeType::FlappyBird => {
+            let d = FlappyBirdDifficulty::from_index(difficulty_index);
+            ActiveMinigame::FlappyBird(FlappyBirdGame::new(d))
+        }
+        ChallengeType::Snake => {
+            let d = SnakeDifficulty::from_index(difficulty_index);
+            start_snake_game(d)
+        }
+    }
+}
+
+/// Decline the currently selected challenge and remove it from the menu.
+fn decline_selected_challenge(state: &mut GameState) {
+    state.challenge_menu.take_selected();
+    state.challenge_menu.close_detail();
+    if state.challenge_menu.challenges.is_empty() {
+        state.challenge_menu.close();
+    }
+}
+
+/// Difficulty tier shared across all challenge types. Maps onto each game's own
+/// difficulty enum by index (Novice = 0 ... Master = 3).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChallengeDifficulty {
+    Novice,
+    Apprentice,
+    Journeyman,
+    Master,
+}
+
+difficulty_enum_impl!(ChallengeDifficulty);
+
+/// Next auto-challenge setting in the Off → Novice → ... → Master → Off cycle.
+pub fn next_auto_challenge(current: Option<ChallengeDifficulty>) -> Option<ChallengeDifficulty> {
+    match current {
+        None => Some(ChallengeDifficulty::Novice),
+        Some(ChallengeDifficulty::Master) => None,
+        Some(d) => Some(ChallengeDifficulty::from_index(d.index() + 1)),
+    }
+}
+
+/// Previous auto-challenge setting: the reverse of [`next_auto_challenge`].
+pub fn previous_auto_challenge(
+    current: Option<ChallengeDifficulty>,
+) -> Option<ChallengeDifficulty> {
+    match current {
+        None => Some(ChallengeDifficulty::Master),
+        Some(ChallengeDifficulty::Novice) => None,
+        Some(d) => Some(ChallengeDifficulty::from_index(d.index() - 1)),
+    }
+}
+
+/// A board game the auto-challenge setting is playing in the background. It
+/// lives outside `GameState::active_minigame`, so it never takes over the
+/// screen or the keyboard.
+#[derive(Debug, Clone)]
+pub struct AutoMinigame {
+    pub game: ActiveMinigame,
+    /// Turns played for the player so far
+    pub player_moves: u32,
+}
+
+/// Resolves pending challenges without player input (auto-challenge setting).
+///
+/// The AI board games (see [`ChallengeType::is_auto_playable`]) are played at
+/// `difficulty` in the background, advancing one step per call: the game's
+/// own AI answers, and each game's `auto_play_move` heuristic plays the
+/// player's side. A finished game goes through `apply_minigame_result` like
+/// one played by hand, so a win pays the normal reward and a loss nothing.
+/// Pure-skill games are forfeited. Nothing new is started while a minigame
+/// is active, the menu is open, or a background game is still running.
+/// Returns the challenge taken off the menu by this call, if any.
+pub fn auto_resolve_challenge<R: Rng>(
+    state: &mut GameState,
+    difficulty: ChallengeDifficulty,
+    today: NaiveDate,
+    rng: &mut R,
+) -> Option<ChallengeType> {
+    if state.auto_minigame.is_some() {
+        advance_auto_minigame(state, today, rng);
+        return None;
+    }
+    if state.active_minigame.is_some() || state.challenge_menu.is_open {
+        return None;
+    }
+    if state.challenge_menu.challenges.is_empty() {
+        return None;
+    }
+
+    let challenge = state.challenge_menu.challenges.remove(0);
+    state.challenge_menu.selected_index = 0;
+    let message = if challenge.challenge_type.is_auto_playable() {
+        state.auto_minigame = Some(AutoMinigame {
+            game: new_minigame(&challenge.challenge_type, difficulty.index()),
+            player_moves: 0,
+        });
+        format!(
+            "{} Auto-challenge: playing {} ({})",
+            challenge.icon,
+            challenge.title,
+            difficulty.name()
+        )
+    } else {
+        format!(
+            "{} Auto-challenge: {} forfeited",
+            challenge.icon, challenge.title
+        )
+    };
+    state.combat_state.add_log_entry(message, false, true);
+    Some(challenge.challenge_type)
+}
+
+/// Plays one step of the background game, or resolves it once it is over
+/// and the player isn't in a minigame of their own. A game still running
+/// after `AUTO_CHALLENGE_MAX_MOVES` player turns is scored as a loss, since
+/// repeated positions never end a chess or morris game on their own.
+fn advance_auto_minigame<R: Rng>(state: &mut GameState, today: NaiveDate, rng: &mut R) {
+    let Some(auto) = state.auto_minigame.as_mut() else {
+        return;
+    };
+
+    if !auto.game.is_finished() {
+        let out_of_moves = auto.player_moves >= AUTO_CHALLENGE_MAX_MOVES;
+        let moved = match &mut auto.game {
+            ActiveMinigame::Chess(game) if out_of_moves => {
+                game.game_result = Some(ChessResult::Loss);
+                false
             }
-            ChallengeType::Gomoku => {
-                let d = GomokuDifficulty::from_index(difficulty_index);
-                ActiveMinigame::Gomoku(GomokuGame::new(d))
+            ActiveMinigame::Chess(game) => {
+                super::chess::logic::process_ai_thinking(game, rng);
+                super::chess::logic::auto_play_move(game, rng)
             }
-            ChallengeType::Minesweeper => {
-                let d = MinesweeperDifficulty::from_index(difficulty_index);
-                ActiveMinigame::Minesweeper(MinesweeperGame::new(d))
+            ActiveMinigame::Morris(game) if out_of_moves => {
+                game.game_result = Some(MorrisResult::Loss);
+                false
             }
-            ChallengeType::Rune => {
-                let d = RuneDifficulty::from_index(difficulty_index);
-                ActiveMinigame::Rune(RuneGame::new(d))
+            ActiveMinigame::Morris(game) => {
+                super::morris::logic::process_ai_thinking(game, rng);
+                super::morris::logic::auto_play_move(game, rng)
             }
-            ChallengeType::Go => {
-                let d = GoDifficulty::from_index(difficulty_index);
-                ActiveMinigame::Go(GoGame::new(d))
+            ActiveMinigame::Gomoku(game) if out_of_moves => {
+                game.game_result = Some(GomokuResult::Loss);
+                false
             }
-            ChallengeType::Jezzball => {
-                let d = JezzballDifficulty::from_index(difficulty_index);
-                start_jezzball_game(d)
+            ActiveMinigame::Gomoku(game) => {
+                super::gomoku::logic::process_ai_thinking(game, rng);
+                super::gomoku::logic::auto_play_move(game, rng)
             }
-            ChallengeType::FlappyBird => {
-                let d = FlappyBirdDifficulty::from_index(difficulty_index);
-                ActiveMinigame::FlappyBird(FlappyBirdGame::new(d))
+            ActiveMinigame::Go(game) if out_of_moves => {
+                game.game_result = Some(GoResult::Loss);
+                false
             }
-            ChallengeType::Snake => {
-                let d = SnakeDifficulty::from_index(difficulty_index);
-                start_snake_game(d)
+            ActiveMinigame::Go(game) => {
+                super::go::process_ai_thinking(game, rng);
+                super::go::auto_play_move(game, rng)
             }
+            // Only the board games are ever auto-played
+            _ => false,
         };
-        state.active_minigame = Some(minigame);
-        state.challenge_menu.close();
+        if moved {
+            auto.player_moves += 1;
+        }
+        return;
     }
-}
 
-/// Decline the currently selected challenge and remove it from the menu.
-fn decline_selected_challenge(state: &mut GameState) {
-    state.challenge_menu.take_selected();
-    state.challenge_menu.close_detail();
-    if state.challenge_menu.challenges.is_empty() {
-        state.challenge_menu.close();
+    if state.active_minigame.is_some() {
+        return;
     }
+    state.active_minigame = state.auto_minigame.take().map(|auto| auto.game);
+    state.last_minigame_win = super::apply_minigame_result(state, today);
 }
 
 /// Structured reward for challenge victories - single source of truth
@@ -160,6 +376,35 @@ impl ChallengeReward {
             format!("Win: {}", parts.join(", "))
         }
     }
+
+    /// Multiplier on prestige-rank rewards for a character at `prestige_rank`.
+    pub fn prestige_scaling(prestige_rank: u32) -> f64 {
+        let ranks_past_start =
+            prestige_rank.saturating_sub(CHALLENGE_REWARD_SCALING_START_PRESTIGE);
+        (1.0 + ranks_past_start as f64 * CHALLENGE_REWARD_SCALING_PER_PRESTIGE)
+            .min(CHALLENGE_REWARD_SCALING_MAX)
+    }
+
+    /// Reward with prestige ranks scaled for a character at `prestige_rank`,
+    /// so wins stay meaningful late in the game. XP already scales with
+    /// level and fishing ranks are capped, so both are left alone.
+    pub fn scaled_for_prestige(&self, prestige_rank: u32) -> ChallengeReward {
+        ChallengeReward {
+            prestige_ranks: (self.prestige_ranks as f64 * Self::prestige_scaling(prestige_rank))
+                as u32,
+            ..*self
+        }
+    }
+
+    /// Reward with the challenge-of-the-day bonus applied.
+    /// Level XP is multiplied, with a floor so prestige-only rewards still gain XP.
+    pub fn featured(&self) -> ChallengeReward {
+        ChallengeReward {
+            xp_percent: (self.xp_percent * FEATURED_CHALLENGE_XP_MULTIPLIER)
+                .max(FEATURED_CHALLENGE_MIN_XP_PERCENT),
+            ..*self
+        }
+    }
 }
 
 /// Trait for difficulty levels that can be displayed in the challenge menu
@@ -418,6 +663,47 @@ pub enum ChallengeType {
 }
 
 impl ChallengeType {
+    /// All challenge types in a fixed order. Used for the daily featured rotation,
+    /// so new types must be appended to keep past dates stable.
+    pub const ALL: [ChallengeType; 9] = [
+        ChallengeType::Chess,
+        ChallengeType::FlappyBird,
+        ChallengeType::Jezzball,
+        ChallengeType::Morris,
+        ChallengeType::Gomoku,
+        ChallengeType::Minesweeper,
+        ChallengeType::Rune,
+        ChallengeType::Go,
+        ChallengeType::Snake,
+    ];
+
+    /// Whether auto-challenge plays this game (the AI board games) instead
+    /// of forfeiting it.
+    pub fn is_auto_playable(&self) -> bool {
+        matches!(
+            self,
+            ChallengeType::Chess
+                | ChallengeType::Morris
+                | ChallengeType::Gomoku
+                | ChallengeType::Go
+        )
+    }
+
+    /// Returns the game type key used for achievements and reward tracking.
+    pub fn game_type(&self) -> &'static str {
+        match self {
+            ChallengeType::Chess => "chess",
+            ChallengeType::FlappyBird => "flappy_bird",
+            ChallengeType::Jezzball => "jezzball",
+            ChallengeType::Morris => "morris",
+            ChallengeType::Gomoku => "gomoku",
+            ChallengeType::Minesweeper => "minesweeper",
+            ChallengeType::Rune => "rune",
+            ChallengeType::Go => "go",
+            ChallengeType::Snake => "snake",
+        }
+    }
+
     /// Returns the icon used for this challenge type in log messages.
     pub fn icon(&self) -> &'static str {
         match self {
@@ -455,6 +741,25 @@ impl ChallengeType {
     }
 }
 
+/// Returns the featured "challenge of the day" for the given date.
+///
+/// Deterministic: every player sees the same featured game on the same date.
+/// The day number is mixed with a SplitMix64 finalizer so consecutive days
+/// don't simply walk through `ChallengeType::ALL` in order.
+pub fn featured_challenge(date: NaiveDate) -> ChallengeType {
+    let mut x = date.num_days_from_ce() as u64;
+    x = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    x ^= x >> 31;
+    ChallengeType::ALL[(x % ChallengeType::ALL.len() as u64) as usize].clone()
+}
+
+/// Returns the featured challenge for the local calendar date.
+pub fn todays_featured_challenge() -> ChallengeType {
+    featured_challenge(chrono::Local::now().date_naive())
+}
+
 /// Menu state for navigation
 #[derive(Debug, Clone, Default)]
 pub struct ChallengeMenu {
@@ -463,6 +768,8 @@ pub struct ChallengeMenu {
     pub selected_index: usize,
     pub viewing_detail: bool,
     pub selected_difficulty: usize,
+    /// Black handicap stones for a Go game (0 = even game)
+    pub go_handicap: u8,
 }
 
 impl ChallengeMenu {
@@ -509,16 +816,33 @@ impl ChallengeMenu {
         }
     }
 
+    /// Change the Go handicap by `delta` stones, clamped to `0..=MAX_HANDICAP`.
+    /// Only applies while viewing a Go challenge.
+    pub fn adjust_go_handicap(&mut self, delta: i8) {
+        let is_go = self
+            .challenges
+            .get(self.selected_index)
+            .is_some_and(|c| c.challenge_type == ChallengeType::Go);
+        if self.viewing_detail && is_go {
+            self.go_handicap = self
+                .go_handicap
+                .saturating_add_signed(delta)
+                .min(MAX_HANDICAP);
+        }
+    }
+
     pub fn open_detail(&mut self) {
         if !self.challenges.is_empty() {
             self.viewing_detail = true;
             self.selected_difficulty = 0;
+            self.go_handicap = 0;
         }
     }
 
     pub fn close_detail(&mut self) {
         self.viewing_detail = false;
         self.selected_difficulty = 0;
+        self.go_handicap = 0;
     }
 
     pub fn open(&mut self) {
@@ -526,6 +850,7 @@ impl ChallengeMenu {
         self.selected_index = 0;
         self.viewing_detail = false;
         self.selected_difficulty = 0;
+        self.go_handicap = 0;
     }
 
     pub fn close(&mut self) {
@@ -698,6 +1023,9 @@ pub fn create_challenge(ct: &ChallengeType) -> PendingChallenge {
 mod tests {
     use super::*;
 
+    /// Fixed time between Elite Hunts, so spawns depend only on the seeded RNG
+    const BETWEEN_HUNTS: i64 = crate::core::constants::ELITE_HUNT_ACTIVE_SECONDS;
+
     fn make_chess_challenge() -> PendingChallenge {
         PendingChallenge {
             challenge_type: ChallengeType::Chess,
@@ -1045,6 +1373,24 @@ mod tests {
         assert!(!state.challenge_menu.is_open);
     }
 
+    #[test]
+    fn test_process_input_practice_keeps_challenge() {
+        let mut state = GameState::new("Test".to_string(), 0);
+        state.challenge_menu.add_challenge(make_chess_challenge());
+        state.challenge_menu.open();
+        state.challenge_menu.open_detail();
+
+        process_input(&mut state, MenuInput::Practice);
+
+        assert!(matches!(
+            state.active_minigame,
+            Some(ActiveMinigame::Chess(_))
+        ));
+        assert!(state.practice_minigame);
+        assert!(!state.challenge_menu.is_open);
+        assert_eq!(state.challenge_menu.challenges.len(), 1);
+    }
+
     #[test]
     fn test_process_input_select_starts_morris_game() {
         let mut state = GameState::new("Test".to_string(), 0);
@@ -1087,6 +1433,54 @@ mod tests {
         assert!(!state.challenge_menu.is_open);
     }
 
+    #[test]
+    fn test_process_input_select_starts_go_game_with_handicap() {
+        let mut state = GameState::new("Test".to_string(), 0);
+        state
+            .challenge_menu
+            .add_challenge(create_challenge(&ChallengeType::Go));
+        state.challenge_menu.open();
+        state.challenge_menu.open_detail();
+
+        process_input(&mut state, MenuInput::Right);
+        process_input(&mut state, MenuInput::Right);
+        process_input(&mut state, MenuInput::Right);
+        process_input(&mut state, MenuInput::Left);
+        assert_eq!(state.challenge_menu.go_handicap, 2);
+
+        process_input(&mut state, MenuInput::Select);
+
+        let Some(ActiveMinigame::Go(game)) = &state.active_minigame else {
+            panic!("expected a Go game");
+        };
+        assert_eq!(game.handicap, 2);
+        assert_eq!(game.current_player, crate::challenges::go::Stone::White);
+    }
+
+    #[test]
+    fn test_go_handicap_clamps_and_ignores_other_challenges() {
+        let mut menu = ChallengeMenu::new();
+        menu.add_challenge(create_challenge(&ChallengeType::Go));
+        menu.add_challenge(make_chess_challenge());
+        menu.open();
+        menu.open_detail();
+
+        menu.adjust_go_handicap(-1);
+        assert_eq!(menu.go_handicap, 0);
+        for _ in 0..20 {
+            menu.adjust_go_handicap(1);
+        }
+        assert_eq!(menu.go_handicap, MAX_HANDICAP);
+
+        menu.close_detail();
+        assert_eq!(menu.go_handicap, 0);
+
+        menu.selected_index = 1; // Chess
+        menu.open_detail();
+        menu.adjust_go_handicap(1);
+        assert_eq!(menu.go_handicap, 0);
+    }
+
     #[test]
     fn test_process_input_select_starts_minesweeper_game() {
         let mut state = GameState::new("Test".to_string(), 0);
@@ -1208,4 +1602,213 @@ mod tests {
             assert_eq!(SnakeDifficulty::ALL[i].difficulty_str(), *expected);
         }
     }
+
+    // ============ Auto-Challenge Tests ============
+
+    #[test]
+    fn test_next_auto_challenge_cycles_through_off() {
+        let mut setting = None;
+        let mut seen = Vec::new();
+        for _ in 0..5 {
+            setting = next_auto_challenge(setting);
+            seen.push(setting);
+        }
+        assert_eq!(
+            seen,
+            vec![
+                Some(ChallengeDifficulty::Novice),
+                Some(ChallengeDifficulty::Apprentice),
+                Some(ChallengeDifficulty::Journeyman),
+                Some(ChallengeDifficulty::Master),
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_previous_auto_challenge_undoes_next() {
+        let mut setting = None;
+        for _ in 0..5 {
+            assert_eq!(
+                previous_auto_challenge(next_auto_challenge(setting)),
+                setting
+            );
+            setting = next_auto_challenge(setting);
+        }
+    }
+
+    #[test]
+    fn test_auto_challenge_consumes_discovered_challenge_within_few_ticks() {
+        use crate::core::tick::game_tick;
+        use crate::haven::Haven;
+        use rand::SeedableRng;
+
+        let mut state = GameState::new("Auto".to_string(), 0);
+        let mut haven = Haven::default();
+        let mut achievements = crate::achievements::Achievements::default();
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(42);
+        let mut tick_counter = 0;
+        state.challenge_menu.add_challenge(make_chess_challenge());
+
+        for _ in 0..3 {
+            game_tick(
+                &mut state,
+                &mut tick_counter,
+                &mut haven,
+                &mut achievements,
+                false,
+                &mut rng,
+                BETWEEN_HUNTS,
+            );
+            auto_resolve_challenge(
+                &mut state,
+                ChallengeDifficulty::Novice,
+                crate::challenges::test_date(),
+                &mut rng,
+            );
+        }
+
+        assert!(state.challenge_menu.challenges.is_empty());
+        assert!(state.active_minigame.is_none());
+        assert!(matches!(
+            state.auto_minigame.as_ref().map(|auto| &auto.game),
+            Some(ActiveMinigame::Chess(game)) if game.difficulty == ChessDifficulty::Novice
+        ));
+        assert!(state
+            .combat_state
+            .combat_log
+            .iter()
+            .any(|e| e.message.contains("Auto-challenge: playing")));
+    }
+
+    #[test]
+    fn test_auto_challenge_waits_while_menu_open_or_game_active() {
+        let mut state = GameState::new("Auto".to_string(), 0);
+        let mut rng = rand::rng();
+        let today = crate::challenges::test_date();
+        state.challenge_menu.add_challenge(make_chess_challenge());
+
+        state.challenge_menu.open();
+        assert!(
+            auto_resolve_challenge(&mut state, ChallengeDifficulty::Master, today, &mut rng)
+                .is_none()
+        );
+        state.challenge_menu.close();
+
+        state.active_minigame = Some(ActiveMinigame::Morris(MorrisGame::new(
+            MorrisDifficulty::Novice,
+        )));
+        assert!(
+            auto_resolve_challenge(&mut state, ChallengeDifficulty::Master, today, &mut rng)
+                .is_none()
+        );
+        assert_eq!(state.challenge_menu.challenges.len(), 1);
+
+        state.active_minigame = None;
+        assert_eq!(
+            auto_resolve_challenge(&mut state, ChallengeDifficulty::Master, today, &mut rng),
+            Some(ChallengeType::Chess)
+        );
+    }
+
+    #[test]
+    fn test_auto_challenge_forfeits_skill_games() {
+        let mut state = GameState::new("Auto".to_string(), 0);
+        let mut rng = rand::rng();
+        state
+            .challenge_menu
+            .add_challenge(create_challenge(&ChallengeType::Minesweeper));
+
+        assert_eq!(
+            auto_resolve_challenge(
+                &mut state,
+                ChallengeDifficulty::Master,
+                crate::challenges::test_date(),
+                &mut rng
+            ),
+            Some(ChallengeType::Minesweeper)
+        );
+        assert!(state.auto_minigame.is_none());
+        assert!(state.active_minigame.is_none());
+        assert!(state
+            .combat_state
+            .combat_log
+            .iter()
+            .any(|e| e.message.contains("forfeited")));
+    }
+
+    #[test]
+    fn test_auto_challenge_plays_board_game_to_a_result() {
+        use rand::SeedableRng;
+
+        let mut state = GameState::new("Auto".to_string(), 0);
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(7);
+        let today = crate::challenges::test_date();
+        state
+            .challenge_menu
+            .add_challenge(create_challenge(&ChallengeType::Gomoku));
+
+        auto_resolve_challenge(&mut state, ChallengeDifficulty::Novice, today, &mut rng);
+        let mut steps = 0;
+        while state.auto_minigame.is_some() && steps < 20_000 {
+            auto_resolve_challenge(&mut state, ChallengeDifficulty::Novice, today, &mut rng);
+            steps += 1;
+        }
+
+        assert!(state.auto_minigame.is_none(), "game never finished");
+        assert!(state.active_minigame.is_none());
+        // Resolved through the game's own result path, which logs with its icon
+        assert!(state
+            .combat_state
+            .combat_log
+            .iter()
+            .any(|e| e.message.starts_with('◎')));
+    }
+
+    #[test]
+    fn test_auto_challenge_scores_endless_game_as_loss() {
+        let mut state = GameState::new("Auto".to_string(), 0);
+        let mut rng = rand::rng();
+        let today = crate::challenges::test_date();
+        state.auto_minigame = Some(AutoMinigame {
+            game: new_minigame(&ChallengeType::Chess, 0),
+            player_moves: AUTO_CHALLENGE_MAX_MOVES,
+        });
+
+        auto_resolve_challenge(&mut state, ChallengeDifficulty::Novice, today, &mut rng);
+        assert!(matches!(
+            state.auto_minigame.as_ref().map(|auto| &auto.game),
+            Some(ActiveMinigame::Chess(game)) if game.game_result == Some(ChessResult::Loss)
+        ));
+
+        auto_resolve_challenge(&mut state, ChallengeDifficulty::Novice, today, &mut rng);
+        assert!(state.auto_minigame.is_none());
+        assert_eq!(state.chess_stats.games_lost, 1);
+        assert_eq!(state.prestige_rank, 0);
+    }
+
+    #[test]
+    fn test_auto_challenge_waits_for_players_own_game_before_resolving() {
+        let mut state = GameState::new("Auto".to_string(), 0);
+        let mut rng = rand::rng();
+        let today = crate::challenges::test_date();
+        let mut finished = ChessGame::new(ChessDifficulty::Novice);
+        finished.game_result = Some(ChessResult::Win);
+        state.auto_minigame = Some(AutoMinigame {
+            game: ActiveMinigame::Chess(Box::new(finished)),
+            player_moves: 10,
+        });
+        state.active_minigame = Some(ActiveMinigame::Morris(MorrisGame::new(
+            MorrisDifficulty::Novice,
+        )));
+
+        auto_resolve_challenge(&mut state, ChallengeDifficulty::Novice, today, &mut rng);
+        assert!(state.auto_minigame.is_some());
+
+        state.active_minigame = None;
+        auto_resolve_challenge(&mut state, ChallengeDifficulty::Novice, today, &mut rng);
+        assert!(state.auto_minigame.is_none());
+        assert_eq!(state.chess_stats.games_won, 1);
+        assert!(state.last_minigame_win.is_some());
+    }
 }
@@ -15,6 +15,8 @@ pub enum MinesweeperInput {
     Right,
     Reveal,
     ToggleFlag,
+    /// Highlight a cell the solver hint can prove (Novice only)
+    Hint,
     Forfeit,
     Other,
 }
@@ -39,6 +41,9 @@ pub fn process_input<R: Rng>(
         return true;
     }
 
+    // Any input clears the previous hint highlight
+    game.highlighted_hint = None;
+
     // Normal game input
     match input {
         MinesweeperInput::Up => game.move_cursor(-1, 0),
@@ -49,6 +54,8 @@ pub fn process_input<R: Rng>(
             let (row, col) = game.cursor;
             if !game.first_click_done {
                 handle_first_click(game, row, col, rng);
+            } else if game.grid[row][col].revealed {
+                chord_cell(game, row, col);
             } else {
                 reveal_cell(game, row, col);
             }
@@ -57,6 +64,9 @@ pub fn process_input<R: Rng>(
             let (row, col) = game.cursor;
             toggle_flag(game, row, col);
         }
+        MinesweeperInput::Hint => {
+            game.highlighted_hint = game.hint();
+        }
         MinesweeperInput::Forfeit => {
             game.forfeit_pending = true;
         }
@@ -182,6 +192,36 @@ pub fn reveal_cell(game: &mut MinesweeperGame, row: usize, col: usize) -> bool {
     true
 }
 
+/// Chord on a revealed numbered cell.
+///
+/// Returns true if the game continues, false if a mine was hit.
+/// - If the cell is hidden, blank or a mine, no action is taken.
+/// - If the number of flagged neighbors differs from the cell's number, no action.
+/// - Otherwise every unflagged hidden neighbor is revealed. A misplaced flag
+///   leaves a mine among them, ending the game in a loss.
+pub fn chord_cell(game: &mut MinesweeperGame, row: usize, col: usize) -> bool {
+    let cell = &game.grid[row][col];
+    if !cell.revealed || cell.has_mine || cell.adjacent_mines == 0 {
+        return true;
+    }
+
+    let neighbors = get_neighbors(row, col, game.height, game.width);
+    let flagged = neighbors
+        .iter()
+        .filter(|&&(r, c)| game.grid[r][c].flagged)
+        .count();
+    if flagged != cell.adjacent_mines as usize {
+        return true;
+    }
+
+    for (n_row, n_col) in neighbors {
+        if !reveal_cell(game, n_row, n_col) {
+            return false;
+        }
+    }
+    true
+}
+
 /// Flood-fill reveal cells starting from a cell with 0 adjacent mines.
 ///
 /// Uses a stack-based approach to avoid recursion.
@@ -278,8 +318,10 @@ pub fn handle_first_click<R: Rng>(game: &mut MinesweeperGame, row: usize, col: u
 
 /// Apply game result: update stats, grant rewards, and add combat log entries.
 /// Returns Some(MinigameWinInfo) if the player won, None otherwise.
+/// `today` decides whether this was the challenge of the day.
 pub fn apply_game_result(
     state: &mut crate::core::game_state::GameState,
+    today: chrono::NaiveDate,
 ) -> Option<crate::challenges::MinigameWinInfo> {
     use crate::challenges::menu::DifficultyInfo;
     use crate::challenges::{apply_challenge_rewards, GameResultInfo};
@@ -310,12 +352,13 @@ pub fn apply_game_result(
             win_message: "All traps identified! The scout salutes you.",
             loss_message,
         },
+        today,
     )
 }
 
 #[cfg(test)]
 mod tests {
-    use super::super::MinesweeperDifficulty;
+    use super::super::{HintKind, MinesweeperDifficulty};
     use super::*;
     use rand::rngs::StdRng;
     use rand::SeedableRng;
@@ -796,7 +839,7 @@ mod tests {
         game.game_result = Some(MinesweeperResult::Win);
         state.active_minigame = Some(ActiveMinigame::Minesweeper(game));
 
-        let processed = apply_game_result(&mut state);
+        let processed = apply_game_result(&mut state, crate::challenges::test_date());
         assert!(processed.is_some()); // Win returns Some(MinigameWinInfo)
         assert!(state.character_xp > initial_xp); // Master gives XP
         assert!(state.prestige_rank > 5); // Master gives prestige
@@ -815,7 +858,7 @@ mod tests {
         game.game_result = Some(MinesweeperResult::Loss);
         state.active_minigame = Some(ActiveMinigame::Minesweeper(game));
 
-        let processed = apply_game_result(&mut state);
+        let processed = apply_game_result(&mut state, crate::challenges::test_date());
         assert!(processed.is_none()); // Loss returns None
         assert_eq!(state.character_xp, initial_xp); // XP unchanged
         assert_eq!(state.prestige_rank, 5); // Prestige unchanged
@@ -829,7 +872,7 @@ mod tests {
         let mut state = GameState::new("Test".to_string(), 0);
         state.active_minigame = None;
 
-        let processed = apply_game_result(&mut state);
+        let processed = apply_game_result(&mut state, crate::challenges::test_date());
         assert!(processed.is_none());
     }
 
@@ -842,7 +885,7 @@ mod tests {
         // game.game_result is None
         state.active_minigame = Some(ActiveMinigame::Minesweeper(game));
 
-        let processed = apply_game_result(&mut state);
+        let processed = apply_game_result(&mut state, crate::challenges::test_date());
         assert!(processed.is_none());
         // Game should still be active
         assert!(matches!(
@@ -991,6 +1034,7 @@ mod tests {
             total_mines: mine_count,
             flags_placed: 0,
             forfeit_pending: false,
+            highlighted_hint: None,
         };
 
         calculate_adjacent_counts(&mut game);
@@ -1205,6 +1249,120 @@ mod tests {
         assert!(game.grid[2][2].revealed, "Other mine also revealed on loss");
     }
 
+    // ---- Chording on a revealed number ----
+
+    #[test]
+    fn test_chord_with_correct_flags_reveals_neighbors() {
+        //   M . .
+        //   . . .
+        //   . . M
+        let mut game = make_game(&["M..", "...", "..M"]);
+        reveal_cell(&mut game, 1, 1);
+        assert_eq!(game.grid[1][1].adjacent_mines, 2);
+        toggle_flag(&mut game, 0, 0);
+        toggle_flag(&mut game, 2, 2);
+
+        assert!(chord_cell(&mut game, 1, 1));
+        assert_eq!(count_revealed(&game), 7, "All safe neighbors revealed");
+        assert!(!game.grid[0][0].revealed && !game.grid[2][2].revealed);
+        assert_eq!(game.game_result, Some(MinesweeperResult::Win));
+    }
+
+    #[test]
+    fn test_chord_with_wrong_flag_hits_mine() {
+        let mut game = make_game(&["M..", "...", "..M"]);
+        reveal_cell(&mut game, 1, 1);
+        toggle_flag(&mut game, 0, 0);
+        // Wrong flag: (0, 1) is safe, the mine at (2, 2) is unflagged
+        toggle_flag(&mut game, 0, 1);
+
+        assert!(!chord_cell(&mut game, 1, 1));
+        assert_eq!(game.game_result, Some(MinesweeperResult::Loss));
+        assert!(game.grid[2][2].revealed);
+    }
+
+    #[test]
+    fn test_chord_needs_matching_flag_count() {
+        let mut game = make_game(&["M..", "...", "..M"]);
+        reveal_cell(&mut game, 1, 1);
+        toggle_flag(&mut game, 0, 0);
+
+        // Only one of two flags placed: nothing happens
+        assert!(chord_cell(&mut game, 1, 1));
+        assert_eq!(count_revealed(&game), 1);
+        assert_eq!(game.game_result, None);
+    }
+
+    #[test]
+    fn test_process_input_reveal_on_revealed_number_chords() {
+        let mut game = make_game(&["M..", "...", "..M"]);
+        let mut rng = rand::rng();
+        reveal_cell(&mut game, 1, 1);
+        toggle_flag(&mut game, 0, 0);
+        toggle_flag(&mut game, 2, 2);
+        game.cursor = (1, 1);
+
+        process_input(&mut game, MinesweeperInput::Reveal, &mut rng);
+        assert_eq!(game.game_result, Some(MinesweeperResult::Win));
+    }
+
+    // ---- Solver hint ----
+
+    #[test]
+    fn test_hint_suggests_provably_safe_cell() {
+        //   M . .
+        //   . . .
+        //   . . .
+        let mut game = make_game(&["M..", "...", "..."]);
+        reveal_cell(&mut game, 0, 1);
+        assert_eq!(game.grid[0][1].adjacent_mines, 1);
+        toggle_flag(&mut game, 0, 0);
+
+        // (0, 1)'s one mine is flagged, so its first hidden neighbor is safe
+        let (row, col, kind) = game.hint().expect("hint available");
+        assert_eq!(kind, HintKind::Safe);
+        assert!(!game.grid[row][col].has_mine);
+        assert!(!game.grid[row][col].revealed && !game.grid[row][col].flagged);
+    }
+
+    #[test]
+    fn test_hint_suggests_certain_mine() {
+        //   M .
+        //   . .
+        //   . .
+        let mut game = make_game(&["M.", "..", ".."]);
+        reveal_cell(&mut game, 0, 1);
+        reveal_cell(&mut game, 1, 1);
+        reveal_cell(&mut game, 1, 0);
+
+        // Only one hidden cell next to a "1": it must be the mine
+        assert_eq!(game.hint(), Some((0, 0, HintKind::Mine)));
+    }
+
+    #[test]
+    fn test_hint_only_at_novice() {
+        let mut game = make_game(&["M..", "...", "..."]);
+        reveal_cell(&mut game, 0, 1);
+        toggle_flag(&mut game, 0, 0);
+        game.difficulty = MinesweeperDifficulty::Apprentice;
+        assert_eq!(game.hint(), None);
+    }
+
+    #[test]
+    fn test_process_input_hint_highlights_until_next_input() {
+        let mut game = make_game(&["M..", "...", "..."]);
+        let mut rng = rand::rng();
+        reveal_cell(&mut game, 0, 1);
+        toggle_flag(&mut game, 0, 0);
+
+        process_input(&mut game, MinesweeperInput::Hint, &mut rng);
+        assert_eq!(game.highlighted_hint, game.hint());
+        assert!(game.highlighted_hint.is_some());
+
+        process_input(&mut game, MinesweeperInput::Down, &mut rng);
+        assert_eq!(game.highlighted_hint, None);
+    }
+
     // ---- Flagged cells are not revealed by flood fill ----
 
     #[test]
@@ -57,6 +57,15 @@ pub enum MinesweeperResult {
     Loss,
 }
 
+/// What a solver hint says about a hidden cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HintKind {
+    /// Guaranteed safe to reveal
+    Safe,
+    /// Guaranteed to be a mine; flag it
+    Mine,
+}
+
 /// Active minesweeper game session.
 #[derive(Debug, Clone)]
 pub struct MinesweeperGame {
@@ -80,6 +89,8 @@ pub struct MinesweeperGame {
     pub flags_placed: u16,
     /// Forfeit confirmation pending.
     pub forfeit_pending: bool,
+    /// Cell highlighted by the last hint request; cleared by the next input.
+    pub highlighted_hint: Option<(usize, usize, HintKind)>,
 }
 
 impl MinesweeperGame {
@@ -100,6 +111,7 @@ impl MinesweeperGame {
             total_mines: difficulty.mine_count(),
             flags_placed: 0,
             forfeit_pending: false,
+            highlighted_hint: None,
         }
     }
 
@@ -110,6 +122,57 @@ impl MinesweeperGame {
         self.cursor = (new_row, new_col);
     }
 
+    /// Suggests a cell whose state follows from a single revealed number
+    /// (Novice only, read-only).
+    ///
+    /// For each revealed number, if its flagged neighbors already account for
+    /// every adjacent mine, its other hidden neighbors are safe; if its hidden
+    /// neighbors are exactly the mines still missing, they are all mines.
+    /// Returns the first such cell in row-major order, or None if single-cell
+    /// logic finds nothing (or a flag is wrong, which this cannot detect).
+    pub fn hint(&self) -> Option<(usize, usize, HintKind)> {
+        if self.difficulty != MinesweeperDifficulty::Novice
+            || !self.first_click_done
+            || self.game_result.is_some()
+        {
+            return None;
+        }
+
+        for row in 0..self.height {
+            for col in 0..self.width {
+                let cell = &self.grid[row][col];
+                if !cell.revealed || cell.has_mine || cell.adjacent_mines == 0 {
+                    continue;
+                }
+
+                let neighbors = super::logic::get_neighbors(row, col, self.height, self.width);
+                let flagged = neighbors
+                    .iter()
+                    .filter(|&&(r, c)| self.grid[r][c].flagged)
+                    .count();
+                let Some(&(h_row, h_col)) = neighbors.iter().find(|&&(r, c)| {
+                    let n = &self.grid[r][c];
+                    !n.revealed && !n.flagged
+                }) else {
+                    continue;
+                };
+                let hidden = neighbors
+                    .iter()
+                    .filter(|&&(r, c)| !self.grid[r][c].revealed && !self.grid[r][c].flagged)
+                    .count();
+
+                let mines = cell.adjacent_mines as usize;
+                if flagged == mines {
+                    return Some((h_row, h_col, HintKind::Safe));
+                }
+                if flagged + hidden == mines {
+                    return Some((h_row, h_col, HintKind::Mine));
+                }
+            }
+        }
+        None
+    }
+
     /// Returns the number of mines remaining (total mines - flags placed).
     /// Can be negative if player has placed more flags than mines.
     pub fn mines_remaining(&self) -> i32 {
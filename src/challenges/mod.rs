@@ -18,6 +18,12 @@ macro_rules! difficulty_enum_impl {
                 Self::ALL.get(index).copied().unwrap_or($name::Novice)
             }
 
+            /// Position in `ALL` (Novice = 0, Master = 3).
+            #[allow(dead_code)]
+            pub fn index(&self) -> usize {
+                Self::ALL.iter().position(|d| d == self).unwrap_or(0)
+            }
+
             pub fn name(&self) -> &'static str {
                 match self {
                     Self::Novice => "Novice",
@@ -40,6 +46,7 @@ pub mod minesweeper;
 pub mod morris;
 pub mod rune;
 pub mod snake;
+pub mod wager;
 
 pub use chess::{ChessDifficulty, ChessGame, ChessResult};
 pub use flappy::{FlappyBirdDifficulty, FlappyBirdGame, FlappyBirdResult};
@@ -56,6 +63,7 @@ pub use morris::{
 };
 pub use rune::{FeedbackMark, RuneDifficulty, RuneGame, RuneResult, RUNE_SYMBOLS};
 pub use snake::{SnakeDifficulty, SnakeGame, SnakeResult};
+pub use wager::{start_wager, wager_rematch_difficulty, Wager};
 
 /// A currently active challenge minigame. Only one can be active at a time.
 #[derive(Debug, Clone)]
@@ -71,6 +79,67 @@ pub enum ActiveMinigame {
     Snake(SnakeGame),
 }
 
+impl ActiveMinigame {
+    pub fn challenge_type(&self) -> menu::ChallengeType {
+        match self {
+            ActiveMinigame::Chess(_) => menu::ChallengeType::Chess,
+            ActiveMinigame::FlappyBird(_) => menu::ChallengeType::FlappyBird,
+            ActiveMinigame::Morris(_) => menu::ChallengeType::Morris,
+            ActiveMinigame::Gomoku(_) => menu::ChallengeType::Gomoku,
+            ActiveMinigame::Minesweeper(_) => menu::ChallengeType::Minesweeper,
+            ActiveMinigame::Rune(_) => menu::ChallengeType::Rune,
+            ActiveMinigame::Go(_) => menu::ChallengeType::Go,
+            ActiveMinigame::Jezzball(_) => menu::ChallengeType::Jezzball,
+            ActiveMinigame::Snake(_) => menu::ChallengeType::Snake,
+        }
+    }
+
+    /// Difficulty as an index into the game's `ALL` (Novice = 0).
+    pub fn difficulty_index(&self) -> usize {
+        match self {
+            ActiveMinigame::Chess(g) => g.difficulty.index(),
+            ActiveMinigame::FlappyBird(g) => g.difficulty.index(),
+            ActiveMinigame::Morris(g) => g.difficulty.index(),
+            ActiveMinigame::Gomoku(g) => g.difficulty.index(),
+            ActiveMinigame::Minesweeper(g) => g.difficulty.index(),
+            ActiveMinigame::Rune(g) => g.difficulty.index(),
+            ActiveMinigame::Go(g) => g.difficulty.index(),
+            ActiveMinigame::Jezzball(g) => g.difficulty.index(),
+            ActiveMinigame::Snake(g) => g.difficulty.index(),
+        }
+    }
+
+    /// Whether the game has a result (won, lost, drawn or forfeited).
+    pub fn is_finished(&self) -> bool {
+        match self {
+            ActiveMinigame::Chess(g) => g.game_result.is_some(),
+            ActiveMinigame::FlappyBird(g) => g.game_result.is_some(),
+            ActiveMinigame::Morris(g) => g.game_result.is_some(),
+            ActiveMinigame::Gomoku(g) => g.game_result.is_some(),
+            ActiveMinigame::Minesweeper(g) => g.game_result.is_some(),
+            ActiveMinigame::Rune(g) => g.game_result.is_some(),
+            ActiveMinigame::Go(g) => g.game_result.is_some(),
+            ActiveMinigame::Jezzball(g) => g.game_result.is_some(),
+            ActiveMinigame::Snake(g) => g.game_result.is_some(),
+        }
+    }
+
+    /// Whether the game has finished in a player win.
+    pub fn is_won(&self) -> bool {
+        match self {
+            ActiveMinigame::Chess(g) => g.game_result == Some(ChessResult::Win),
+            ActiveMinigame::FlappyBird(g) => g.game_result == Some(FlappyBirdResult::Win),
+            ActiveMinigame::Morris(g) => g.game_result == Some(MorrisResult::Win),
+            ActiveMinigame::Gomoku(g) => g.game_result == Some(GomokuResult::Win),
+            ActiveMinigame::Minesweeper(g) => g.game_result == Some(MinesweeperResult::Win),
+            ActiveMinigame::Rune(g) => g.game_result == Some(RuneResult::Win),
+            ActiveMinigame::Go(g) => g.game_result == Some(GoResult::Win),
+            ActiveMinigame::Jezzball(g) => g.game_result == Some(JezzballResult::Win),
+            ActiveMinigame::Snake(g) => g.game_result == Some(SnakeResult::Win),
+        }
+    }
+}
+
 /// Information about a minigame win for achievement tracking.
 #[derive(Debug, Clone)]
 pub struct MinigameWinInfo {
@@ -98,16 +167,105 @@ pub struct GameResultInfo {
     pub loss_message: &'static str,
 }
 
+/// Resolve the finished active minigame: the game's own `apply_game_result`
+/// for a real challenge, or nothing at all for a practice run. `today` (the
+/// local date) decides whether the game was the challenge of the day.
+/// Returns `Some(MinigameWinInfo)` if the player won a real challenge.
+pub fn apply_minigame_result(
+    state: &mut crate::core::game_state::GameState,
+    today: chrono::NaiveDate,
+) -> Option<MinigameWinInfo> {
+    if state.practice_minigame {
+        end_practice_game(state);
+        return None;
+    }
+
+    match state.active_minigame.as_ref()? {
+        ActiveMinigame::Chess(_) => chess::apply_game_result(state, today),
+        ActiveMinigame::FlappyBird(_) => flappy::logic::apply_game_result(state, today),
+        ActiveMinigame::Morris(_) => morris::apply_game_result(state, today),
+        ActiveMinigame::Gomoku(_) => gomoku::apply_game_result(state, today),
+        ActiveMinigame::Minesweeper(_) => minesweeper::apply_game_result(state, today),
+        ActiveMinigame::Rune(_) => rune::apply_game_result(state, today),
+        ActiveMinigame::Go(_) => go::apply_go_result(state, today),
+        ActiveMinigame::Jezzball(_) => jezzball::logic::apply_game_result(state, today),
+        ActiveMinigame::Snake(_) => snake::logic::apply_game_result(state, today),
+    }
+}
+
+/// A fixed date for tests, so they don't depend on today's challenge of the
+/// day. Its featured game is Chess.
+#[cfg(test)]
+pub(crate) fn test_date() -> chrono::NaiveDate {
+    chrono::NaiveDate::from_ymd_opt(2024, 1, 3).unwrap()
+}
+
+/// Close a practice minigame without rewards, penalties or stat changes.
+fn end_practice_game(state: &mut crate::core::game_state::GameState) {
+    state.active_minigame = None;
+    state.practice_minigame = false;
+    state.combat_state.add_log_entry(
+        "Practice over. No rewards or records this time.".to_string(),
+        false,
+        true,
+    );
+}
+
 /// Apply challenge rewards to game state, clear active_minigame, and log results.
+/// `today` decides whether the game was the challenge of the day.
 /// Returns `Some(MinigameWinInfo)` if the player won, `None` otherwise.
 #[allow(clippy::needless_pass_by_value)]
 pub fn apply_challenge_rewards(
     state: &mut crate::core::game_state::GameState,
-    info: GameResultInfo,
+    mut info: GameResultInfo,
+    today: chrono::NaiveDate,
 ) -> Option<MinigameWinInfo> {
+    let wager = std::mem::take(&mut state.wager);
+
     if info.won {
         let old_prestige = state.prestige_rank;
 
+        // Prestige scaling and challenge of the day bonus (already included
+        // in a wager's stake)
+        let staked = matches!(wager, Wager::Pending { .. });
+        if !staked {
+            info.reward = info.reward.scaled_for_prestige(old_prestige);
+        }
+        let featured = !staked && menu::featured_challenge(today).game_type() == info.game_type;
+        if featured {
+            info.reward = info.reward.featured();
+        }
+
+        match wager {
+            Wager::Staking => {
+                state.wager = Wager::Pending { stake: info.reward };
+                state.combat_state.add_log_entry(
+                    format!(
+                        "{} {} Double or nothing: {} at stake!",
+                        info.icon,
+                        info.win_message,
+                        info.reward.description()
+                    ),
+                    false,
+                    true,
+                );
+                state.active_minigame = None;
+                return Some(MinigameWinInfo {
+                    game_type: info.game_type,
+                    difficulty: info.difficulty_str,
+                });
+            }
+            Wager::Pending { stake } => {
+                info.reward = stake.doubled();
+                state.combat_state.add_log_entry(
+                    format!("{} Wager won! The stake is doubled.", info.icon),
+                    false,
+                    true,
+                );
+            }
+            Wager::None => {}
+        }
+
         // XP reward
         let xp_gained = if info.reward.xp_percent > 0 {
             let xp_for_level =
@@ -136,6 +294,13 @@ pub fn apply_challenge_rewards(
             false,
             true,
         );
+        if featured {
+            state.combat_state.add_log_entry(
+                format!("{} \u{2605} Challenge of the day bonus!", info.icon),
+                false,
+                true,
+            );
+        }
         if info.reward.prestige_ranks > 0 {
             state.combat_state.add_log_entry(
                 format!(
@@ -171,6 +336,17 @@ pub fn apply_challenge_rewards(
             false,
             true,
         );
+        if let Wager::Pending { stake } = wager {
+            state.combat_state.add_log_entry(
+                format!(
+                    "{} Wager lost. {} forfeited.",
+                    info.icon,
+                    stake.description()
+                ),
+                false,
+                true,
+            );
+        }
     }
 
     state.active_minigame = None;
@@ -202,6 +378,38 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_practice_win_grants_nothing_and_records_nothing() {
+        let mut state = GameState::new("Test".to_string(), 0);
+        state.prestige_rank = 5;
+        let xp_before = state.character_xp;
+        let mut game = ChessGame::new(ChessDifficulty::Master);
+        game.game_result = Some(ChessResult::Win);
+        state.active_minigame = Some(ActiveMinigame::Chess(Box::new(game)));
+        state.practice_minigame = true;
+
+        let result = apply_minigame_result(&mut state, test_date());
+
+        assert!(result.is_none());
+        assert_eq!(state.prestige_rank, 5);
+        assert_eq!(state.character_xp, xp_before);
+        assert_eq!(state.chess_stats.games_played, 0);
+        assert_eq!(state.chess_stats.games_won, 0);
+        assert!(state.active_minigame.is_none());
+        assert!(!state.practice_minigame);
+    }
+
+    #[test]
+    fn test_real_win_goes_through_game_result() {
+        let mut state = GameState::new("Test".to_string(), 0);
+        let mut game = ChessGame::new(ChessDifficulty::Master);
+        game.game_result = Some(ChessResult::Win);
+        state.active_minigame = Some(ActiveMinigame::Chess(Box::new(game)));
+
+        assert!(apply_minigame_result(&mut state, test_date()).is_some());
+        assert_eq!(state.chess_stats.games_played, 1);
+    }
+
     #[test]
     fn test_apply_rewards_win_returns_minigame_win_info() {
         let mut state = GameState::new("Test".to_string(), 0);
@@ -210,7 +418,7 @@ mod tests {
             ..Default::default()
         };
 
-        let result = apply_challenge_rewards(&mut state, make_info(true, reward));
+        let result = apply_challenge_rewards(&mut state, make_info(true, reward), test_date());
 
         assert!(result.is_some());
         let info = result.unwrap();
@@ -223,7 +431,7 @@ mod tests {
         let mut state = GameState::new("Test".to_string(), 0);
         let reward = menu::ChallengeReward::default();
 
-        let result = apply_challenge_rewards(&mut state, make_info(false, reward));
+        let result = apply_challenge_rewards(&mut state, make_info(false, reward), test_date());
 
         assert!(result.is_none());
     }
@@ -234,7 +442,7 @@ mod tests {
         state.active_minigame = Some(ActiveMinigame::Rune(RuneGame::new(RuneDifficulty::Novice)));
         let reward = menu::ChallengeReward::default();
 
-        apply_challenge_rewards(&mut state, make_info(false, reward));
+        apply_challenge_rewards(&mut state, make_info(false, reward), test_date());
 
         assert!(state.active_minigame.is_none());
     }
@@ -249,7 +457,7 @@ mod tests {
             ..Default::default()
         };
 
-        apply_challenge_rewards(&mut state, make_info(true, reward));
+        apply_challenge_rewards(&mut state, make_info(true, reward), test_date());
 
         assert!(state.character_xp > old_xp);
     }
@@ -265,7 +473,7 @@ mod tests {
             ..Default::default()
         };
 
-        apply_challenge_rewards(&mut state, make_info(true, reward));
+        apply_challenge_rewards(&mut state, make_info(true, reward), test_date());
 
         assert_eq!(state.character_xp, old_xp);
     }
@@ -279,7 +487,7 @@ mod tests {
             ..Default::default()
         };
 
-        apply_challenge_rewards(&mut state, make_info(true, reward));
+        apply_challenge_rewards(&mut state, make_info(true, reward), test_date());
 
         assert_eq!(state.prestige_rank, 8);
     }
@@ -293,7 +501,7 @@ mod tests {
             ..Default::default()
         };
 
-        apply_challenge_rewards(&mut state, make_info(true, reward));
+        apply_challenge_rewards(&mut state, make_info(true, reward), test_date());
 
         assert_eq!(state.fishing.rank, 12);
     }
@@ -307,7 +515,7 @@ mod tests {
             ..Default::default()
         };
 
-        apply_challenge_rewards(&mut state, make_info(true, reward));
+        apply_challenge_rewards(&mut state, make_info(true, reward), test_date());
 
         assert_eq!(state.fishing.rank, 30);
     }
@@ -321,7 +529,7 @@ mod tests {
             ..Default::default()
         };
 
-        apply_challenge_rewards(&mut state, make_info(true, reward));
+        apply_challenge_rewards(&mut state, make_info(true, reward), test_date());
 
         assert_eq!(state.fishing.rank, 30);
     }
@@ -339,7 +547,7 @@ mod tests {
             fishing_ranks: 2,
         };
 
-        apply_challenge_rewards(&mut state, make_info(false, reward));
+        apply_challenge_rewards(&mut state, make_info(false, reward), test_date());
 
         assert_eq!(state.prestige_rank, 5);
         assert_eq!(state.character_xp, old_xp);
@@ -356,7 +564,7 @@ mod tests {
             ..Default::default()
         };
 
-        apply_challenge_rewards(&mut state, make_info(true, reward));
+        apply_challenge_rewards(&mut state, make_info(true, reward), test_date());
 
         // Should have win message + prestige + XP entries
         assert!(state.combat_state.combat_log.len() >= 2);
@@ -365,13 +573,138 @@ mod tests {
             .contains("You won!"));
     }
 
+    fn make_featured_info(won: bool, reward: menu::ChallengeReward) -> GameResultInfo {
+        let date = chrono::NaiveDate::from_ymd_opt(2026, 3, 14).unwrap();
+        GameResultInfo {
+            game_type: menu::featured_challenge(date).game_type(),
+            ..make_info(won, reward)
+        }
+    }
+
+    #[test]
+    fn test_featured_challenge_is_stable_for_date() {
+        let date = chrono::NaiveDate::from_ymd_opt(2026, 3, 14).unwrap();
+        let first = menu::featured_challenge(date);
+        for _ in 0..10 {
+            assert_eq!(menu::featured_challenge(date), first);
+        }
+    }
+
+    #[test]
+    fn test_featured_challenge_rotates_across_dates() {
+        let start = chrono::NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+        let featured: Vec<menu::ChallengeType> = (0..60)
+            .map(|d| menu::featured_challenge(start + chrono::Days::new(d)))
+            .collect();
+        for ct in menu::ChallengeType::ALL.iter() {
+            assert!(featured.contains(ct), "{:?} never featured", ct);
+        }
+    }
+
+    #[test]
+    fn test_high_prestige_win_grants_more_prestige() {
+        let win_master_chess = |prestige_rank: u32| {
+            let mut state = GameState::new("Test".to_string(), 0);
+            state.prestige_rank = prestige_rank;
+            let mut game = ChessGame::new(ChessDifficulty::Master);
+            game.game_result = Some(ChessResult::Win);
+            state.active_minigame = Some(ActiveMinigame::Chess(Box::new(game)));
+            apply_minigame_result(&mut state, test_date());
+            state.prestige_rank - prestige_rank
+        };
+        let base = ChessDifficulty::Master.reward_prestige();
+
+        assert_eq!(win_master_chess(0), base);
+        assert_eq!(win_master_chess(10), base);
+        assert_eq!(win_master_chess(20), base * 6 / 5);
+        assert_eq!(win_master_chess(35), base * 3 / 2);
+        assert_eq!(win_master_chess(100), base * 3 / 2);
+    }
+
+    #[test]
+    fn test_prestige_scaling_is_conservative_for_small_rewards() {
+        let reward = menu::ChallengeReward {
+            prestige_ranks: 1,
+            xp_percent: 50,
+            fishing_ranks: 1,
+        };
+        assert_eq!(reward.scaled_for_prestige(100).prestige_ranks, 1);
+
+        let reward = menu::ChallengeReward {
+            prestige_ranks: 2,
+            ..reward
+        };
+        assert_eq!(reward.scaled_for_prestige(34).prestige_ranks, 2);
+        let scaled = reward.scaled_for_prestige(35);
+        assert_eq!(scaled.prestige_ranks, 3);
+        assert_eq!(scaled.xp_percent, 50);
+        assert_eq!(scaled.fishing_ranks, 1);
+    }
+
+    #[test]
+    fn test_apply_rewards_featured_win_doubles_xp() {
+        let date = chrono::NaiveDate::from_ymd_opt(2026, 3, 14).unwrap();
+        let reward = menu::ChallengeReward {
+            xp_percent: 100,
+            ..Default::default()
+        };
+
+        let mut normal = GameState::new("Test".to_string(), 0);
+        normal.character_level = 5;
+        let mut non_featured = make_featured_info(true, reward);
+        non_featured.game_type = "not_a_game";
+        apply_challenge_rewards(&mut normal, non_featured, date);
+
+        let mut featured = GameState::new("Test".to_string(), 0);
+        featured.character_level = 5;
+        apply_challenge_rewards(&mut featured, make_featured_info(true, reward), date);
+
+        assert_eq!(featured.character_xp, normal.character_xp * 2);
+        assert!(featured
+            .combat_state
+            .combat_log
+            .iter()
+            .any(|e| e.message.contains("Challenge of the day")));
+    }
+
+    #[test]
+    fn test_apply_rewards_featured_prestige_only_reward_grants_min_xp() {
+        let date = chrono::NaiveDate::from_ymd_opt(2026, 3, 14).unwrap();
+        let mut state = GameState::new("Test".to_string(), 0);
+        state.character_level = 5;
+        let reward = menu::ChallengeReward {
+            prestige_ranks: 1,
+            ..Default::default()
+        };
+
+        apply_challenge_rewards(&mut state, make_featured_info(true, reward), date);
+
+        assert_eq!(state.prestige_rank, 1);
+        assert!(state.character_xp > 0);
+    }
+
+    #[test]
+    fn test_apply_rewards_featured_loss_grants_nothing() {
+        let date = chrono::NaiveDate::from_ymd_opt(2026, 3, 14).unwrap();
+        let mut state = GameState::new("Test".to_string(), 0);
+        state.character_level = 5;
+        let reward = menu::ChallengeReward {
+            xp_percent: 100,
+            ..Default::default()
+        };
+
+        apply_challenge_rewards(&mut state, make_featured_info(false, reward), date);
+
+        assert_eq!(state.character_xp, 0);
+    }
+
     #[test]
     fn test_apply_rewards_adds_combat_log_on_loss() {
         let mut state = GameState::new("Test".to_string(), 0);
         state.combat_state.combat_log.clear();
         let reward = menu::ChallengeReward::default();
 
-        apply_challenge_rewards(&mut state, make_info(false, reward));
+        apply_challenge_rewards(&mut state, make_info(false, reward), test_date());
 
         assert_eq!(state.combat_state.combat_log.len(), 1);
         assert!(state.combat_state.combat_log[0]
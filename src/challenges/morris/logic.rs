@@ -534,6 +534,30 @@ pub fn process_ai_thinking<R: Rng>(game: &mut MorrisGame, rng: &mut R) {
     }
 }
 
+/// Plays the player's turn for the auto-challenge setting: a move that closes
+/// a mill if there is one, otherwise a random legal move (captures included).
+/// Returns true if a move was played.
+pub fn auto_play_move<R: Rng>(game: &mut MorrisGame, rng: &mut R) -> bool {
+    if game.game_result.is_some() || game.current_player != Player::Human {
+        return false;
+    }
+
+    let legal_moves = get_legal_moves(game);
+    let closes_mill = |mv: &MorrisMove| {
+        let mut after = game.clone();
+        apply_move(&mut after, *mv);
+        after.must_capture
+    };
+    let mv = match legal_moves.iter().find(|mv| closes_mill(mv)) {
+        Some(mv) => *mv,
+        None if legal_moves.is_empty() => return false,
+        None => legal_moves[rng.random_range(0..legal_moves.len())],
+    };
+    game.selected_position = None;
+    apply_move(game, mv);
+    true
+}
+
 /// Calculate variable AI thinking time in ticks (1-3 seconds at 100ms/tick)
 pub fn calculate_think_ticks<R: Rng>(rng: &mut R) -> u32 {
     rng.random_range(10..=30)
@@ -731,7 +755,11 @@ fn count_mobility(game: &MorrisGame, player: Player) -> i32 {
 
 /// Apply game result: grant rewards and add combat log entries.
 /// Returns Some(MinigameWinInfo) if the player won, None otherwise.
-pub fn apply_game_result(state: &mut GameState) -> Option<crate::challenges::MinigameWinInfo> {
+/// `today` decides whether this was the challenge of the day.
+pub fn apply_game_result(
+    state: &mut GameState,
+    today: chrono::NaiveDate,
+) -> Option<crate::challenges::MinigameWinInfo> {
     use crate::challenges::menu::DifficultyInfo;
     use crate::challenges::{apply_challenge_rewards, GameResultInfo};
 
@@ -766,6 +794,7 @@ pub fn apply_game_result(state: &mut GameState) -> Option<crate::challenges::Min
             win_message: "Victory! The sage bows with respect.",
             loss_message,
         },
+        today,
     )
 }
 
@@ -1224,7 +1253,13 @@ mod tests {
 
         let old_xp = state.character_xp;
         let old_fishing_rank = state.fishing.rank;
-        let processed = apply_game_result(&mut state);
+        // 2024-01-03 features Chess, so no challenge of the day bonus applies
+        let date = chrono::NaiveDate::from_ymd_opt(2024, 1, 3).unwrap();
+        assert_ne!(
+            crate::challenges::menu::featured_challenge(date),
+            crate::challenges::menu::ChallengeType::Morris
+        );
+        let processed = apply_game_result(&mut state, date);
 
         assert!(processed.is_some()); // Win returns Some(MinigameWinInfo)
                                       // Master = 200% of xp_for_next_level(10) = 6324
@@ -1244,7 +1279,7 @@ mod tests {
         state.active_minigame = Some(ActiveMinigame::Morris(game));
 
         let old_fishing_rank = state.fishing.rank;
-        let processed = apply_game_result(&mut state);
+        let processed = apply_game_result(&mut state, crate::challenges::test_date());
 
         assert!(processed.is_some()); // Win returns Some(MinigameWinInfo)
         assert_eq!(state.fishing.rank, old_fishing_rank); // Novice grants no fishing rank
@@ -1260,7 +1295,7 @@ mod tests {
         game.game_result = Some(MorrisResult::Win);
         state.active_minigame = Some(ActiveMinigame::Morris(game));
 
-        let processed = apply_game_result(&mut state);
+        let processed = apply_game_result(&mut state, crate::challenges::test_date());
 
         assert!(processed.is_some()); // Win returns Some(MinigameWinInfo)
         assert_eq!(state.fishing.rank, 30); // Capped at max
@@ -1275,7 +1310,7 @@ mod tests {
         state.active_minigame = Some(ActiveMinigame::Morris(game));
 
         let old_xp = state.character_xp;
-        let processed = apply_game_result(&mut state);
+        let processed = apply_game_result(&mut state, crate::challenges::test_date());
 
         assert!(processed.is_none()); // Loss returns None
         assert_eq!(state.character_xp, old_xp); // Unchanged
@@ -1292,7 +1327,7 @@ mod tests {
         state.active_minigame = Some(ActiveMinigame::Morris(game));
 
         let old_xp = state.character_xp;
-        let processed = apply_game_result(&mut state);
+        let processed = apply_game_result(&mut state, crate::challenges::test_date());
 
         assert!(processed.is_none()); // Forfeit returns None
         assert_eq!(state.character_xp, old_xp); // Unchanged
@@ -164,8 +164,10 @@ pub fn submit_guess<R: Rng>(game: &mut RuneGame, rng: &mut R) -> bool {
 
 /// Apply game result: update stats, grant rewards, and add combat log entries.
 /// Returns Some(MinigameWinInfo) if the player won, None otherwise.
+/// `today` decides whether this was the challenge of the day.
 pub fn apply_game_result(
     state: &mut crate::core::game_state::GameState,
+    today: chrono::NaiveDate,
 ) -> Option<crate::challenges::MinigameWinInfo> {
     use crate::challenges::menu::DifficultyInfo;
     use crate::challenges::{apply_challenge_rewards, GameResultInfo};
@@ -194,6 +196,7 @@ pub fn apply_game_result(
             win_message: "The runes glow with approval! Code deciphered.",
             loss_message,
         },
+        today,
     )
 }
 
@@ -565,7 +568,7 @@ mod tests {
         game.game_result = Some(RuneResult::Win);
         state.active_minigame = Some(ActiveMinigame::Rune(game));
 
-        let processed = apply_game_result(&mut state);
+        let processed = apply_game_result(&mut state, crate::challenges::test_date());
         assert!(processed.is_some()); // Win returns Some(MinigameWinInfo)
         assert!(state.character_xp > initial_xp); // Journeyman gives 75% XP
         assert!(state.fishing.rank > initial_fishing); // Journeyman gives fishing ranks
@@ -585,7 +588,7 @@ mod tests {
         game.game_result = Some(RuneResult::Win);
         state.active_minigame = Some(ActiveMinigame::Rune(game));
 
-        let processed = apply_game_result(&mut state);
+        let processed = apply_game_result(&mut state, crate::challenges::test_date());
         assert!(processed.is_some()); // Win returns Some(MinigameWinInfo)
         assert!(state.prestige_rank > 5); // Master gives prestige
         assert!(state.fishing.rank > initial_fishing); // Master gives fishing ranks
@@ -604,7 +607,7 @@ mod tests {
         game.game_result = Some(RuneResult::Loss);
         state.active_minigame = Some(ActiveMinigame::Rune(game));
 
-        let processed = apply_game_result(&mut state);
+        let processed = apply_game_result(&mut state, crate::challenges::test_date());
         assert!(processed.is_none()); // Loss returns None
         assert_eq!(state.character_xp, initial_xp); // XP unchanged
         assert_eq!(state.prestige_rank, 5); // Prestige unchanged
@@ -618,7 +621,7 @@ mod tests {
         let mut state = GameState::new("Test".to_string(), 0);
         state.active_minigame = None;
 
-        let processed = apply_game_result(&mut state);
+        let processed = apply_game_result(&mut state, crate::challenges::test_date());
         assert!(processed.is_none());
     }
 
@@ -631,7 +634,7 @@ mod tests {
         // game.game_result is None
         state.active_minigame = Some(ActiveMinigame::Rune(game));
 
-        let processed = apply_game_result(&mut state);
+        let processed = apply_game_result(&mut state, crate::challenges::test_date());
         assert!(processed.is_none());
         // Game should still be active
         assert!(matches!(
@@ -226,7 +226,11 @@ impl DifficultyInfo for SnakeDifficulty {
 
 /// Apply game result using the shared challenge reward system.
 /// Returns `Some(MinigameWinInfo)` if the player won, `None` otherwise.
-pub fn apply_game_result(state: &mut GameState) -> Option<MinigameWinInfo> {
+/// `today` decides whether this was the challenge of the day.
+pub fn apply_game_result(
+    state: &mut GameState,
+    today: chrono::NaiveDate,
+) -> Option<MinigameWinInfo> {
     let (result, difficulty, score, target) = {
         if let Some(ActiveMinigame::Snake(ref game)) = state.active_minigame {
             (
@@ -273,6 +277,7 @@ pub fn apply_game_result(state: &mut GameState) -> Option<MinigameWinInfo> {
             win_message: "Serpent's Path conquered!",
             loss_message: "The serpent has fallen.",
         },
+        today,
     )
 }
 
@@ -634,7 +639,7 @@ mod tests {
         game.score = 15;
         state.active_minigame = Some(ActiveMinigame::Snake(game));
 
-        let result = apply_game_result(&mut state);
+        let result = apply_game_result(&mut state, crate::challenges::test_date());
         assert!(result.is_some());
         let info = result.unwrap();
         assert_eq!(info.game_type, "snake");
@@ -653,7 +658,7 @@ mod tests {
         game.game_result = Some(SnakeResult::Loss);
         state.active_minigame = Some(ActiveMinigame::Snake(game));
 
-        let result = apply_game_result(&mut state);
+        let result = apply_game_result(&mut state, crate::challenges::test_date());
         assert!(result.is_none());
         assert_eq!(state.character_xp, initial_xp);
         assert!(state.active_minigame.is_none());
@@ -664,7 +669,7 @@ mod tests {
         let mut state = GameState::new("Test".to_string(), 0);
         state.active_minigame = None;
 
-        let result = apply_game_result(&mut state);
+        let result = apply_game_result(&mut state, crate::challenges::test_date());
         assert!(result.is_none());
     }
 
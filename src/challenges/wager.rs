@@ -0,0 +1,193 @@
+//! Double-or-nothing wagers on challenge wins.
+//!
+//! After winning a real (non-practice) challenge below Master, the player can
+//! stake the reward on a rematch one difficulty higher instead of collecting
+//! it. The win still counts for stats and achievements; only the reward is
+//! held. Winning the rematch pays the stake twice over (the rematch's own
+//! reward is not added); losing or forfeiting it pays nothing.
+//!
+//! `apply_challenge_rewards` consults `GameState::wager` to hold,
+//! double or forfeit the reward. The wager is transient: quitting mid-rematch
+//! forfeits it.
+
+use super::menu::{new_minigame, ChallengeReward};
+use super::{apply_minigame_result, MinigameWinInfo};
+use crate::core::game_state::GameState;
+
+/// Highest difficulty index; a win here can't be wagered.
+const MAX_DIFFICULTY_INDEX: usize = 3;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Wager {
+    #[default]
+    None,
+    /// The win being resolved is staked instead of paid out
+    Staking,
+    /// `stake` rides on the active rematch
+    Pending { stake: ChallengeReward },
+}
+
+impl ChallengeReward {
+    /// The payout for a won wager on this stake.
+    pub fn doubled(&self) -> Self {
+        Self {
+            prestige_ranks: self.prestige_ranks * 2,
+            xp_percent: self.xp_percent * 2,
+            fishing_ranks: self.fishing_ranks * 2,
+        }
+    }
+}
+
+/// Difficulty index of the rematch if the finished active game can be
+/// wagered: won, not practice, no wager already riding on it, below Master.
+pub fn wager_rematch_difficulty(state: &GameState) -> Option<usize> {
+    let game = state.active_minigame.as_ref()?;
+    if state.practice_minigame || state.wager != Wager::None || !game.is_won() {
+        return None;
+    }
+    let difficulty = game.difficulty_index();
+    (difficulty < MAX_DIFFICULTY_INDEX).then_some(difficulty + 1)
+}
+
+/// Stakes the reward of the finished active game and starts the rematch one
+/// difficulty higher. Returns the original win for achievement tracking, or
+/// None (changing nothing) if the game can't be wagered.
+pub fn start_wager(state: &mut GameState, today: chrono::NaiveDate) -> Option<MinigameWinInfo> {
+    let rematch_difficulty = wager_rematch_difficulty(state)?;
+    let challenge_type = state.active_minigame.as_ref()?.challenge_type();
+
+    state.wager = Wager::Staking;
+    let win = apply_minigame_result(state, today);
+    state.active_minigame = Some(new_minigame(&challenge_type, rematch_difficulty));
+    win
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::challenges::menu::DifficultyInfo;
+    use crate::challenges::{
+        ActiveMinigame, MinesweeperDifficulty, MinesweeperGame, MinesweeperResult,
+    };
+
+    /// A date whose Challenge of the day (Chess) doesn't boost Minesweeper.
+    fn unfeatured_date() -> chrono::NaiveDate {
+        let date = chrono::NaiveDate::from_ymd_opt(2024, 1, 3).unwrap();
+        assert_ne!(
+            crate::challenges::menu::featured_challenge(date).game_type(),
+            "minesweeper"
+        );
+        date
+    }
+
+    fn finished_minesweeper(
+        difficulty: MinesweeperDifficulty,
+        result: MinesweeperResult,
+    ) -> ActiveMinigame {
+        let mut game = MinesweeperGame::new(difficulty);
+        game.game_result = Some(result);
+        ActiveMinigame::Minesweeper(game)
+    }
+
+    /// Wins a Novice game, stakes it, then finishes the Apprentice rematch.
+    fn play_wager(rematch_result: MinesweeperResult) -> GameState {
+        let mut state = GameState::new("Gambler".to_string(), 0);
+        state.character_level = 10;
+        state.active_minigame = Some(finished_minesweeper(
+            MinesweeperDifficulty::Novice,
+            MinesweeperResult::Win,
+        ));
+
+        assert!(start_wager(&mut state, unfeatured_date()).is_some());
+        assert_eq!(
+            state.wager,
+            Wager::Pending {
+                stake: MinesweeperDifficulty::Novice.reward()
+            }
+        );
+        match &state.active_minigame {
+            Some(ActiveMinigame::Minesweeper(game)) => {
+                assert_eq!(game.difficulty, MinesweeperDifficulty::Apprentice);
+            }
+            other => panic!("expected a Minesweeper rematch, got {:?}", other),
+        }
+
+        if let Some(ActiveMinigame::Minesweeper(game)) = &mut state.active_minigame {
+            game.game_result = Some(rematch_result);
+        }
+        apply_minigame_result(&mut state, unfeatured_date());
+        state
+    }
+
+    #[test]
+    fn test_wager_win_doubles_the_stake() {
+        let stake = MinesweeperDifficulty::Novice.reward();
+        let xp_for_level = crate::core::game_logic::xp_for_next_level(10);
+
+        let state = play_wager(MinesweeperResult::Win);
+
+        assert_eq!(
+            state.character_xp,
+            xp_for_level * stake.doubled().xp_percent as u64 / 100
+        );
+        assert_eq!(state.prestige_rank, stake.doubled().prestige_ranks);
+        assert_eq!(state.wager, Wager::None);
+        assert!(state.active_minigame.is_none());
+    }
+
+    #[test]
+    fn test_wager_loss_pays_nothing() {
+        let state = play_wager(MinesweeperResult::Loss);
+
+        assert_eq!(state.character_xp, 0);
+        assert_eq!(state.prestige_rank, 0);
+        assert_eq!(state.wager, Wager::None);
+        assert!(state.active_minigame.is_none());
+    }
+
+    #[test]
+    fn test_no_wager_pays_the_base_reward() {
+        let mut state = GameState::new("Collector".to_string(), 0);
+        state.character_level = 10;
+        state.active_minigame = Some(finished_minesweeper(
+            MinesweeperDifficulty::Novice,
+            MinesweeperResult::Win,
+        ));
+        let reward = MinesweeperDifficulty::Novice.reward();
+        let xp_for_level = crate::core::game_logic::xp_for_next_level(10);
+
+        apply_minigame_result(&mut state, unfeatured_date());
+
+        assert_eq!(
+            state.character_xp,
+            xp_for_level * reward.xp_percent as u64 / 100
+        );
+        assert_eq!(state.prestige_rank, reward.prestige_ranks);
+    }
+
+    #[test]
+    fn test_cannot_wager_losses_master_or_practice() {
+        let mut state = GameState::new("Gambler".to_string(), 0);
+        state.active_minigame = Some(finished_minesweeper(
+            MinesweeperDifficulty::Novice,
+            MinesweeperResult::Loss,
+        ));
+        assert_eq!(wager_rematch_difficulty(&state), None);
+
+        state.active_minigame = Some(finished_minesweeper(
+            MinesweeperDifficulty::Master,
+            MinesweeperResult::Win,
+        ));
+        assert_eq!(wager_rematch_difficulty(&state), None);
+        assert!(start_wager(&mut state, unfeatured_date()).is_none());
+        assert!(state.active_minigame.is_some());
+
+        state.active_minigame = Some(finished_minesweeper(
+            MinesweeperDifficulty::Journeyman,
+            MinesweeperResult::Win,
+        ));
+        assert_eq!(wager_rematch_difficulty(&state), Some(3));
+        state.practice_minigame = true;
+        assert_eq!(wager_rematch_difficulty(&state), None);
+    }
+}
@@ -1,7 +1,66 @@
 use super::attributes::{AttributeType, Attributes};
 use crate::core::constants::*;
+use crate::items::types::AffixType;
 use crate::items::Equipment;
 
+/// Diminishing-returns curve for the stacked bonus of one affix type (in percent).
+///
+/// Bonus up to `soft_cap` applies linearly. Each point above it is worth
+/// `falloff` points, and the result never exceeds `hard_cap`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AffixCap {
+    pub affix_type: AffixType,
+    pub soft_cap: f64,
+    pub hard_cap: f64,
+    pub falloff: f64,
+}
+
+impl AffixCap {
+    /// Applies the curve to a raw summed bonus.
+    pub fn apply(&self, raw: f64) -> f64 {
+        if raw <= self.soft_cap {
+            return raw;
+        }
+        (self.soft_cap + (raw - self.soft_cap) * self.falloff).min(self.hard_cap)
+    }
+}
+
+/// Caps for additive affixes. Types not listed here are uncapped.
+pub const AFFIX_CAPS: [AffixCap; 4] = [
+    AffixCap {
+        affix_type: AffixType::AttackSpeed,
+        soft_cap: 100.0,
+        hard_cap: 200.0,
+        falloff: 0.5,
+    },
+    AffixCap {
+        affix_type: AffixType::CritChance,
+        soft_cap: 30.0,
+        hard_cap: 50.0,
+        falloff: 0.5,
+    },
+    AffixCap {
+        affix_type: AffixType::HPRegen,
+        soft_cap: 200.0,
+        hard_cap: 400.0,
+        falloff: 0.5,
+    },
+    AffixCap {
+        affix_type: AffixType::DamageReflection,
+        soft_cap: 100.0,
+        hard_cap: 200.0,
+        falloff: 0.5,
+    },
+];
+
+/// Applies the affix type's cap from `AFFIX_CAPS`, if it has one.
+pub fn apply_affix_cap(affix_type: AffixType, raw: f64) -> f64 {
+    AFFIX_CAPS
+        .iter()
+        .find(|cap| cap.affix_type == affix_type)
+        .map_or(raw, |cap| cap.apply(raw))
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct DerivedStats {
     pub max_hp: u32,
@@ -13,6 +72,8 @@ pub struct DerivedStats {
     pub attack_speed_multiplier: f64,
     pub hp_regen_multiplier: f64,
     pub damage_reflection_percent: f64,
+    /// Chance to parry an enemy attack (negate it and counterattack)
+    pub parry_chance_percent: f64,
     #[allow(dead_code)]
     pub xp_multiplier: f64,
 }
@@ -62,6 +123,10 @@ impl DerivedStats {
         // Crit Chance = BASE_CRIT_CHANCE_PERCENT + (DEX_mod × 1%)
         let mut crit_chance_percent = (BASE_CRIT_CHANCE_PERCENT + dex_mod).max(0) as u32;
 
+        // Parry Chance = DEX_mod × PARRY_CHANCE_PER_DEX_MODIFIER%, capped
+        let parry_chance_percent =
+            (dex_mod as f64 * PARRY_CHANCE_PER_DEX_MODIFIER).clamp(0.0, PARRY_CHANCE_CAP_PERCENT);
+
         // XP Multiplier = 1.0 + (WIS_mod × XP_MULT_PER_WIS_MODIFIER)
         let mut xp_multiplier = 1.0 + (wis_mod as f64 * XP_MULT_PER_WIS_MODIFIER);
 
@@ -75,10 +140,11 @@ impl DerivedStats {
         let mut hp_regen_bonus: f64 = 0.0;
         let mut damage_reflection: f64 = 0.0;
         let mut xp_mult: f64 = 1.0;
+        let mut fragile_armor: f64 = 0.0;
 
         for item in equipment.iter_equipped() {
-            for affix in &item.affixes {
-                use crate::items::types::AffixType;
+            // Socketed gems count as extra affixes
+            for affix in item.affixes.iter().cloned().chain(item.gem_affixes()) {
                 match affix.affix_type {
                     AffixType::DamagePercent => {
                         damage_mult *= 1.0 + (affix.value / AFFIX_PERCENT_DIVISOR)
@@ -93,15 +159,30 @@ impl DerivedStats {
                     AffixType::HPRegen => hp_regen_bonus += affix.value,
                     AffixType::DamageReflection => damage_reflection += affix.value,
                     AffixType::XPGain => xp_mult *= 1.0 + (affix.value / AFFIX_PERCENT_DIVISOR),
+                    // On-kill procs trigger in combat, not as passive stats
+                    AffixType::OnKillHeal | AffixType::OnKillRage => {}
+                    AffixType::FragileArmor => {
+                        fragile_armor += affix.value;
+                        damage_mult *= 1.0
+                            + (affix.value * FRAGILE_ARMOR_DAMAGE_RATIO / AFFIX_PERCENT_DIVISOR);
+                    }
                 }
             }
         }
 
+        // Diminishing returns on stacked additive affixes
+        crit_bonus = apply_affix_cap(AffixType::CritChance, crit_bonus);
+        attack_speed_bonus = apply_affix_cap(AffixType::AttackSpeed, attack_speed_bonus);
+        hp_regen_bonus = apply_affix_cap(AffixType::HPRegen, hp_regen_bonus);
+        damage_reflection = apply_affix_cap(AffixType::DamageReflection, damage_reflection);
+
         // Apply multipliers to stats
         max_hp = ((max_hp as f64 + hp_bonus) as u32).max(1);
         physical_damage = ((physical_damage as f64 * damage_mult) as u32).max(1);
         magic_damage = ((magic_damage as f64 * damage_mult) as u32).max(1);
-        defense = (defense as f64 * defense_mult) as u32;
+        // Curses stack additively, so several can strip defense to zero but not below
+        defense_mult *= (1.0 - fragile_armor / AFFIX_PERCENT_DIVISOR).max(0.0);
+        defense = (defense as f64 * defense_mult.max(0.0)) as u32;
         crit_chance_percent = (crit_chance_percent as f64 + crit_bonus) as u32;
         xp_multiplier *= xp_mult;
 
@@ -127,6 +208,7 @@ impl DerivedStats {
             attack_speed_multiplier,
             hp_regen_multiplier,
             damage_reflection_percent,
+            parry_chance_percent,
             xp_multiplier,
         }
     }
@@ -135,6 +217,18 @@ impl DerivedStats {
         self.physical_damage + self.magic_damage
     }
 
+    /// Single-number strength rating for comparing characters: the geometric
+    /// mean of average damage per second (crits included) and effective HP
+    /// (max HP plus 5 per defense point).
+    pub fn power_score(&self) -> u64 {
+        let crit_factor =
+            1.0 + (self.crit_chance_percent.min(100) as f64 / 100.0) * (self.crit_multiplier - 1.0);
+        let dps = self.total_damage() as f64 * crit_factor * self.attack_speed_multiplier
+            / ATTACK_INTERVAL_SECONDS;
+        let effective_hp = self.max_hp as f64 + self.defense as f64 * 5.0;
+        (dps * effective_hp).sqrt().round() as u64
+    }
+
     /// Calculates prestige multiplier with equipment bonuses included.
     #[allow(dead_code)]
     pub fn prestige_multiplier_with_equipment(
@@ -200,6 +294,28 @@ mod tests {
         assert_eq!(stats.crit_chance_percent, 9); // 5 + 4
         assert_eq!(stats.xp_multiplier, 1.25); // 1.0 + (5 * 0.05)
         assert_eq!(stats.total_damage(), 18);
+        assert_eq!(stats.parry_chance_percent, 2.0); // 4 * 0.5
+    }
+
+    #[test]
+    fn test_parry_chance_scales_with_dex_and_caps() {
+        let mut attrs = Attributes::new();
+        assert_eq!(
+            DerivedStats::from_attributes(&attrs).parry_chance_percent,
+            0.0
+        );
+
+        attrs.set(AttributeType::Dexterity, 6); // -2 mod
+        assert_eq!(
+            DerivedStats::from_attributes(&attrs).parry_chance_percent,
+            0.0
+        );
+
+        attrs.set(AttributeType::Dexterity, 200); // +95 mod
+        assert_eq!(
+            DerivedStats::from_attributes(&attrs).parry_chance_percent,
+            PARRY_CHANCE_CAP_PERCENT
+        );
     }
 
     #[test]
@@ -245,6 +361,7 @@ mod tests {
                 cha: 0,
             },
             affixes: vec![],
+            sockets: Vec::new(),
         };
 
         equipment.set(EquipmentSlot::Weapon, Some(weapon));
@@ -282,6 +399,7 @@ mod tests {
                 cha: 3,
             },
             affixes: vec![],
+            sockets: Vec::new(),
         };
 
         equipment.set(EquipmentSlot::Amulet, Some(amulet));
@@ -316,6 +434,7 @@ mod tests {
                 affix_type: AffixType::DamagePercent,
                 value: 20.0,
             }],
+            sockets: Vec::new(),
         };
 
         equipment.set(EquipmentSlot::Weapon, Some(weapon));
@@ -362,6 +481,7 @@ mod tests {
                     value: 20.0,
                 },
             ],
+            sockets: Vec::new(),
         };
 
         equipment.set(EquipmentSlot::Weapon, Some(weapon));
@@ -406,6 +526,7 @@ mod tests {
                 affix_type: AffixType::DamageReduction,
                 value: 15.0,
             }],
+            sockets: Vec::new(),
         };
 
         equipment.set(EquipmentSlot::Armor, Some(armor));
@@ -417,6 +538,69 @@ mod tests {
         assert_eq!(stats.defense, 0);
     }
 
+    fn fragile_item(slot: EquipmentSlot, dex: u32, fragile: f64) -> Item {
+        Item {
+            slot,
+            rarity: Rarity::Legendary,
+            ilvl: 10,
+            base_name: "Armor".to_string(),
+            display_name: "Brittle Armor".to_string(),
+            attributes: AttributeBonuses {
+                dex,
+                ..AttributeBonuses::new()
+            },
+            affixes: vec![Affix {
+                affix_type: AffixType::FragileArmor,
+                value: fragile,
+            }],
+            sockets: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_fragile_armor_trades_defense_for_damage() {
+        let attrs = Attributes::new();
+        let mut plain = Equipment::new();
+        plain.set(
+            EquipmentSlot::Armor,
+            Some(fragile_item(EquipmentSlot::Armor, 10, 0.0)),
+        );
+        let mut cursed = Equipment::new();
+        cursed.set(
+            EquipmentSlot::Armor,
+            Some(fragile_item(EquipmentSlot::Armor, 10, 50.0)),
+        );
+
+        let before = DerivedStats::calculate_derived_stats(&attrs, &plain);
+        let after = DerivedStats::calculate_derived_stats(&attrs, &cursed);
+
+        // DEX 20 (+5 mod) gives 5 defense, halved by the curse
+        assert_eq!(before.defense, 5);
+        assert_eq!(after.defense, 2);
+        assert!(after.physical_damage > before.physical_damage);
+    }
+
+    #[test]
+    fn test_stacked_curses_never_underflow() {
+        let attrs = Attributes::new();
+        let mut equipment = Equipment::new();
+        for slot in [
+            EquipmentSlot::Armor,
+            EquipmentSlot::Helmet,
+            EquipmentSlot::Boots,
+        ] {
+            equipment.set(slot, Some(fragile_item(slot, 10, 80.0)));
+        }
+
+        let stats = DerivedStats::calculate_derived_stats(&attrs, &equipment);
+
+        // 240% defense lost clamps to zero instead of wrapping
+        assert_eq!(stats.defense, 0);
+        assert!(stats.max_hp >= 1);
+        assert!(stats.physical_damage >= 1);
+        assert!(stats.magic_damage >= 1);
+    }
+
     #[test]
     fn test_derived_stats_with_xp_gain_affix() {
         let attrs = Attributes::new();
@@ -441,6 +625,7 @@ mod tests {
                 affix_type: AffixType::XPGain,
                 value: 50.0,
             }],
+            sockets: Vec::new(),
         };
 
         equipment.set(EquipmentSlot::Amulet, Some(amulet));
@@ -469,6 +654,7 @@ mod tests {
                 affix_type: AffixType::CritMultiplier,
                 value: 50.0,
             }],
+            sockets: Vec::new(),
         };
 
         equipment.set(EquipmentSlot::Weapon, Some(weapon));
@@ -496,6 +682,7 @@ mod tests {
                 affix_type: AffixType::CritMultiplier,
                 value: 25.0,
             }],
+            sockets: Vec::new(),
         };
 
         let ring = Item {
@@ -509,6 +696,7 @@ mod tests {
                 affix_type: AffixType::CritMultiplier,
                 value: 25.0,
             }],
+            sockets: Vec::new(),
         };
 
         equipment.set(EquipmentSlot::Weapon, Some(weapon));
@@ -536,6 +724,7 @@ mod tests {
                 affix_type: AffixType::AttackSpeed,
                 value: 25.0,
             }],
+            sockets: Vec::new(),
         };
 
         equipment.set(EquipmentSlot::Gloves, Some(gloves));
@@ -546,6 +735,88 @@ mod tests {
         assert!((stats.attack_speed_multiplier - 1.25).abs() < f64::EPSILON);
     }
 
+    fn equipment_with_affix(affix_type: AffixType, value_per_slot: f64, slots: usize) -> Equipment {
+        let mut equipment = Equipment::new();
+        for slot in [
+            EquipmentSlot::Weapon,
+            EquipmentSlot::Armor,
+            EquipmentSlot::Helmet,
+            EquipmentSlot::Gloves,
+            EquipmentSlot::Boots,
+            EquipmentSlot::Amulet,
+            EquipmentSlot::Ring,
+        ]
+        .into_iter()
+        .take(slots)
+        {
+            equipment.set(
+                slot,
+                Some(Item {
+                    slot,
+                    rarity: Rarity::Legendary,
+                    ilvl: 100,
+                    base_name: "Item".to_string(),
+                    display_name: "Item".to_string(),
+                    attributes: AttributeBonuses::new(),
+                    affixes: vec![Affix {
+                        affix_type,
+                        value: value_per_slot,
+                    }],
+                    sockets: Vec::new(),
+                }),
+            );
+        }
+        equipment
+    }
+
+    #[test]
+    fn test_attack_speed_linear_below_soft_cap() {
+        let attrs = Attributes::new();
+        // 4 × 25% = 100%, exactly at the soft cap
+        let equipment = equipment_with_affix(AffixType::AttackSpeed, 25.0, 4);
+        let stats = DerivedStats::calculate_derived_stats(&attrs, &equipment);
+        assert!((stats.attack_speed_multiplier - 2.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_attack_speed_diminishes_above_soft_cap() {
+        let attrs = Attributes::new();
+        // 5 × 30% = 150% raw → 100 + 50 × 0.5 = 125%
+        let equipment = equipment_with_affix(AffixType::AttackSpeed, 30.0, 5);
+        let stats = DerivedStats::calculate_derived_stats(&attrs, &equipment);
+        assert!((stats.attack_speed_multiplier - 2.25).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_attack_speed_clamps_at_hard_cap() {
+        let attrs = Attributes::new();
+        // 7 × 100% = 700% raw, clamped to +200%
+        let equipment = equipment_with_affix(AffixType::AttackSpeed, 100.0, 7);
+        let stats = DerivedStats::calculate_derived_stats(&attrs, &equipment);
+        assert!((stats.attack_speed_multiplier - 3.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_crit_chance_clamps_at_hard_cap() {
+        let attrs = Attributes::new();
+        let equipment = equipment_with_affix(AffixType::CritChance, 40.0, 7);
+        let stats = DerivedStats::calculate_derived_stats(&attrs, &equipment);
+        // Base 5% + capped 50%
+        assert_eq!(stats.crit_chance_percent, 55);
+    }
+
+    #[test]
+    fn test_affix_cap_table_is_consistent() {
+        for cap in AFFIX_CAPS {
+            assert!(cap.soft_cap < cap.hard_cap, "{:?}", cap.affix_type);
+            assert!(cap.falloff > 0.0 && cap.falloff <= 1.0);
+            assert_eq!(cap.apply(cap.soft_cap), cap.soft_cap);
+            assert_eq!(cap.apply(cap.hard_cap * 100.0), cap.hard_cap);
+        }
+        // Uncapped affixes pass through unchanged
+        assert_eq!(apply_affix_cap(AffixType::XPGain, 1000.0), 1000.0);
+    }
+
     #[test]
     fn test_hp_regen_affix() {
         let attrs = Attributes::new();
@@ -562,6 +833,7 @@ mod tests {
                 affix_type: AffixType::HPRegen,
                 value: 50.0,
             }],
+            sockets: Vec::new(),
         };
 
         equipment.set(EquipmentSlot::Armor, Some(armor));
@@ -588,6 +860,7 @@ mod tests {
                 affix_type: AffixType::DamageReflection,
                 value: 30.0,
             }],
+            sockets: Vec::new(),
         };
 
         equipment.set(EquipmentSlot::Armor, Some(armor));
@@ -614,6 +887,7 @@ mod tests {
                 affix_type: AffixType::DamageReflection,
                 value: 20.0,
             }],
+            sockets: Vec::new(),
         };
 
         let helmet = Item {
@@ -627,6 +901,7 @@ mod tests {
                 affix_type: AffixType::DamageReflection,
                 value: 15.0,
             }],
+            sockets: Vec::new(),
         };
 
         equipment.set(EquipmentSlot::Armor, Some(armor));
@@ -37,6 +37,8 @@ pub enum SelectInput {
     Delete,
     /// Rename selected character
     Rename,
+    /// Restore the most recently deleted character
+    Restore,
     /// Quit the game
     Quit,
     /// Any other key
@@ -101,6 +103,8 @@ pub enum SelectResult {
     GoToDelete,
     /// Go to character rename screen
     GoToRename,
+    /// Restore the most recently deleted character
+    RestoreDeleted,
     /// Quit the game
     Quit,
     /// Load failed with error
@@ -189,6 +193,9 @@ pub fn process_select_input(
     input: SelectInput,
     characters: &[CharacterInfo],
 ) -> SelectResult {
+    // A restore error stays up until the next key press
+    screen.status_message = None;
+
     if characters.is_empty() {
         return SelectResult::NoCharacters;
     }
@@ -232,6 +239,7 @@ pub fn process_select_input(
                 SelectResult::GoToRename
             }
         }
+        SelectInput::Restore => SelectResult::RestoreDeleted,
         SelectInput::Quit => SelectResult::Quit,
         SelectInput::Other => SelectResult::Continue,
     }
@@ -420,6 +428,7 @@ mod tests {
                 character_level: 10,
                 prestige_rank: 1,
                 play_time_seconds: 3600,
+                total_kills: 0,
                 last_save_time: 1000,
                 attributes: crate::character::attributes::Attributes::new(),
                 equipment: crate::items::Equipment::new(),
@@ -432,6 +441,7 @@ mod tests {
                 character_level: 20,
                 prestige_rank: 2,
                 play_time_seconds: 7200,
+                total_kills: 0,
                 last_save_time: 2000,
                 attributes: crate::character::attributes::Attributes::new(),
                 equipment: crate::items::Equipment::new(),
@@ -462,6 +472,16 @@ mod tests {
         assert_eq!(screen.selected_index, 0);
     }
 
+    #[test]
+    fn test_select_restore_requests_undelete() {
+        let mut screen = CharacterSelectScreen::new();
+        let characters = create_test_characters();
+
+        let result = process_select_input(&mut screen, SelectInput::Restore, &characters);
+
+        assert_eq!(result, SelectResult::RestoreDeleted);
+    }
+
     #[test]
     fn test_select_down_moves_selection() {
         let mut screen = CharacterSelectScreen::new();
@@ -614,6 +634,7 @@ mod tests {
             character_level: 10,
             prestige_rank: 1,
             play_time_seconds: 3600,
+            total_kills: 0,
             last_save_time: 1000,
             attributes: crate::character::attributes::Attributes::new(),
             equipment: crate::items::Equipment::new(),
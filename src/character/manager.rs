@@ -1,6 +1,7 @@
+use std::fmt;
 use std::fs;
 use std::io;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
@@ -26,9 +27,102 @@ struct CharacterSaveData {
     #[serde(default)]
     fishing: crate::fishing::FishingState,
     #[serde(default)]
+    fishing_gear: crate::fishing::FishingGear,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    active_bait: Option<crate::fishing::Bait>,
+    #[serde(default)]
     zone_progression: crate::zones::ZoneProgression,
     #[serde(default)]
     chess_stats: crate::challenges::chess::ChessStats,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    expedition: Option<crate::core::expedition::Expedition>,
+    #[serde(default)]
+    total_kills: u64,
+    #[serde(default)]
+    tutorial: crate::core::tutorial::TutorialState,
+    #[serde(default)]
+    prestige_streak: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    loot_filter_min_rarity: Option<crate::items::Rarity>,
+    #[serde(default)]
+    salvage_materials: u64,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    gems: Vec<crate::items::Gem>,
+    #[serde(default)]
+    verbose_loot: bool,
+    #[serde(default)]
+    verbose_combat: bool,
+    #[serde(default)]
+    combat_stance: crate::combat::CombatStance,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    auto_allocate: Option<[u32; crate::core::constants::NUM_ATTRIBUTES]>,
+    #[serde(default)]
+    loot_history: std::collections::VecDeque<crate::core::game_state::LootRecord>,
+    #[serde(default)]
+    boss_ledger: crate::zones::BossLedger,
+    #[serde(default)]
+    adaptive_intensity: crate::combat::adaptive::AdaptiveIntensity,
+    #[serde(default)]
+    banked_xp: u64,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    prestige_history: Vec<crate::core::game_state::PrestigeRecord>,
+    /// FNV-1a hash of the save without this field (absent in older saves)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    checksum: Option<String>,
+}
+
+/// Why a character save could not be loaded.
+#[derive(Debug)]
+pub enum LoadError {
+    /// The file could not be read or is not a valid save
+    Io(io::Error),
+    /// The save parsed but its contents don't match the stored checksum
+    /// (edited by hand or corrupted). `force_load_character` skips the check.
+    ChecksumMismatch,
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadError::Io(e) => write!(f, "{}", e),
+            LoadError::ChecksumMismatch => {
+                write!(f, "save checksum mismatch (file was edited or corrupted)")
+            }
+        }
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+impl From<io::Error> for LoadError {
+    fn from(e: io::Error) -> Self {
+        LoadError::Io(e)
+    }
+}
+
+impl From<LoadError> for io::Error {
+    fn from(e: LoadError) -> Self {
+        match e {
+            LoadError::Io(e) => e,
+            LoadError::ChecksumMismatch => io::Error::new(io::ErrorKind::InvalidData, e),
+        }
+    }
+}
+
+/// Checksum of a save's JSON with any `checksum` field removed. Hashes the
+/// compact, key-sorted form so formatting changes don't affect it.
+fn save_checksum(save: &serde_json::Value) -> String {
+    let mut save = save.clone();
+    if let Some(fields) = save.as_object_mut() {
+        fields.remove("checksum");
+    }
+    // FNV-1a (64-bit): stable across Rust versions, unlike std's hasher
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in save.to_string().bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    format!("{:016x}", hash)
 }
 
 #[derive(Debug, Clone)]
@@ -40,56 +134,262 @@ pub struct CharacterInfo {
     pub character_level: u32,
     pub prestige_rank: u32,
     pub play_time_seconds: u64,
+    pub total_kills: u64,
     pub last_save_time: i64,
     pub attributes: super::attributes::Attributes,
     pub equipment: crate::items::Equipment,
     pub is_corrupted: bool,
 }
 
+/// Account-wide totals across all characters, shown on the select screen.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AccountStats {
+    pub total_characters: usize,
+    pub total_kills: u64,
+    pub total_play_time_seconds: u64,
+    pub highest_prestige: u32,
+}
+
+impl AccountStats {
+    /// Aggregates stats from an already-loaded character list, so the select
+    /// screen doesn't read every save twice. Corrupted saves are skipped.
+    pub fn from_characters(characters: &[CharacterInfo]) -> Self {
+        let mut stats = Self::default();
+        for character in characters.iter().filter(|c| !c.is_corrupted) {
+            stats.total_characters += 1;
+            stats.total_kills += character.total_kills;
+            stats.total_play_time_seconds += character.play_time_seconds;
+            stats.highest_prestige = stats.highest_prestige.max(character.prestige_rank);
+        }
+        stats
+    }
+}
+
+/// One side of a `CharacterComparison`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CharacterSnapshot {
+    pub character_name: String,
+    pub character_level: u32,
+    pub prestige_rank: u32,
+    pub zone_id: u32,
+    pub subzone_id: u32,
+    /// `DerivedStats::power_score` from attributes and equipment
+    pub power_score: u64,
+    pub play_time_seconds: u64,
+}
+
+impl CharacterSnapshot {
+    fn from_state(state: &crate::core::game_state::GameState) -> Self {
+        let derived = super::derived_stats::DerivedStats::calculate_derived_stats(
+            &state.attributes,
+            &state.equipment,
+        );
+        Self {
+            character_name: state.character_name.clone(),
+            character_level: state.character_level,
+            prestige_rank: state.prestige_rank,
+            zone_id: state.zone_progression.current_zone_id,
+            subzone_id: state.zone_progression.current_subzone_id,
+            power_score: derived.power_score(),
+            play_time_seconds: state.play_time_seconds,
+        }
+    }
+}
+
+/// Side-by-side summary of two saved characters, for picking which alt to play.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CharacterComparison {
+    pub a: CharacterSnapshot,
+    pub b: CharacterSnapshot,
+}
+
+impl CharacterComparison {
+    /// How `a`'s power score compares to `b`'s.
+    #[allow(dead_code)]
+    pub fn power_ordering(&self) -> std::cmp::Ordering {
+        self.a.power_score.cmp(&self.b.power_score)
+    }
+}
+
+/// A soft-deleted character save in `~/.quest/deleted/`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeletedCharacter {
+    /// Filename inside the deleted directory (`{name}.{timestamp}.json`)
+    pub filename: String,
+    /// Original save filename (`{name}.json`)
+    pub original_filename: String,
+    /// Unix timestamp of deletion
+    pub deleted_at: i64,
+}
+
+impl DeletedCharacter {
+    fn parse(filename: &str) -> Option<Self> {
+        let stem = filename.strip_suffix(".json")?;
+        let (name, timestamp) = stem.rsplit_once('.')?;
+        Some(Self {
+            filename: filename.to_string(),
+            original_filename: format!("{}.json", name),
+            deleted_at: timestamp.parse().ok()?,
+        })
+    }
+}
+
+/// Serializes a save with its checksum, stamped with `last_save_time`.
+fn save_json(
+    state: &crate::core::game_state::GameState,
+    last_save_time: i64,
+) -> io::Result<String> {
+    let save_data = CharacterSaveData {
+        version: SAVE_FILE_VERSION,
+        character_id: state.character_id.clone(),
+        character_name: state.character_name.clone(),
+        character_level: state.character_level,
+        character_xp: state.character_xp,
+        attributes: state.attributes,
+        prestige_rank: state.prestige_rank,
+        total_prestige_count: state.total_prestige_count,
+        last_save_time,
+        play_time_seconds: state.play_time_seconds,
+        combat_state: state.combat_state.clone(),
+        equipment: state.equipment.clone(),
+        active_dungeon: state.active_dungeon.clone(),
+        fishing: state.fishing.clone(),
+        fishing_gear: state.fishing_gear.clone(),
+        active_bait: state.active_bait,
+        zone_progression: state.zone_progression.clone(),
+        chess_stats: state.chess_stats.clone(),
+        expedition: state.expedition,
+        total_kills: state.total_kills,
+        tutorial: state.tutorial,
+        prestige_streak: state.prestige_streak,
+        loot_filter_min_rarity: state.loot_filter_min_rarity,
+        salvage_materials: state.salvage_materials,
+        gems: state.gems.clone(),
+        verbose_loot: state.verbose_loot,
+        verbose_combat: state.verbose_combat,
+        combat_stance: state.combat_stance,
+        auto_allocate: state.auto_allocate,
+        loot_history: state.loot_history.clone(),
+        boss_ledger: state.boss_ledger.clone(),
+        adaptive_intensity: state.adaptive_intensity,
+        banked_xp: state.banked_xp,
+        prestige_history: state.prestige_history.clone(),
+        checksum: None,
+    };
+
+    let mut value = serde_json::to_value(&save_data)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    let checksum = save_checksum(&value);
+    if let Some(fields) = value.as_object_mut() {
+        fields.insert("checksum".to_string(), checksum.into());
+    }
+    let json = serde_json::to_string_pretty(&value)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    Ok(json)
+}
+
+/// Writes a save to an arbitrary path, keeping its `last_save_time`.
+/// Used by `quest doctor` for repaired copies.
+pub fn write_save_file(state: &crate::core::game_state::GameState, path: &Path) -> io::Result<()> {
+    fs::write(path, save_json(state, state.last_save_time)?)
+}
+
+/// Loads a save from an arbitrary path (see `CharacterManager::load_character`).
+pub fn read_save_file(
+    path: &Path,
+    verify_checksum: bool,
+) -> Result<crate::core::game_state::GameState, LoadError> {
+    let json_content = fs::read_to_string(path)?;
+
+    let value: serde_json::Value = serde_json::from_str(&json_content)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    if verify_checksum {
+        if let Some(stored) = value.get("checksum").and_then(|c| c.as_str()) {
+            if stored != save_checksum(&value) {
+                return Err(LoadError::ChecksumMismatch);
+            }
+        }
+    }
+
+    let save_data: CharacterSaveData =
+        serde_json::from_value(value).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    Ok(crate::core::game_state::GameState {
+        character_id: save_data.character_id,
+        character_name: save_data.character_name,
+        character_level: save_data.character_level,
+        character_xp: save_data.character_xp,
+        attributes: save_data.attributes,
+        prestige_rank: save_data.prestige_rank,
+        total_prestige_count: save_data.total_prestige_count,
+        last_save_time: save_data.last_save_time,
+        play_time_seconds: save_data.play_time_seconds,
+        combat_state: save_data.combat_state,
+        equipment: save_data.equipment,
+        active_dungeon: save_data.active_dungeon,
+        fishing: save_data.fishing,
+        fishing_gear: save_data.fishing_gear,
+        active_bait: save_data.active_bait,
+        active_fishing: None,
+        zone_progression: save_data.zone_progression,
+        challenge_menu: crate::challenges::menu::ChallengeMenu::new(),
+        chess_stats: save_data.chess_stats,
+        total_kills: save_data.total_kills,
+        tutorial: save_data.tutorial,
+        expedition: save_data.expedition,
+        prestige_streak: save_data.prestige_streak,
+        loot_filter_min_rarity: save_data.loot_filter_min_rarity,
+        salvage_materials: save_data.salvage_materials,
+        gems: save_data.gems,
+        verbose_loot: save_data.verbose_loot,
+        verbose_combat: save_data.verbose_combat,
+        combat_stance: save_data.combat_stance,
+        auto_allocate: save_data.auto_allocate,
+        loot_history: save_data.loot_history,
+        boss_ledger: save_data.boss_ledger,
+        adaptive_intensity: save_data.adaptive_intensity,
+        banked_xp: save_data.banked_xp,
+        prestige_history: save_data.prestige_history,
+        active_minigame: None,
+        practice_minigame: false,
+        wager: crate::challenges::Wager::None,
+        auto_minigame: None,
+        session_kills: 0,
+        recent_drops: std::collections::VecDeque::new(),
+        metrics: crate::core::metrics::MetricsBuffer::default(),
+        last_minigame_win: None,
+        training_dummy: None,
+        game_events: Vec::new(),
+        queued_dungeon_seed: None,
+        equipment_history: Default::default(),
+    })
+}
+
 pub struct CharacterManager {
     quest_dir: PathBuf,
 }
 
 impl CharacterManager {
     pub fn new() -> io::Result<Self> {
-        let home_dir = dirs::home_dir().ok_or_else(|| {
-            io::Error::new(
-                io::ErrorKind::NotFound,
-                "Could not determine home directory",
-            )
-        })?;
+        Self::in_dir(crate::core::paths::quest_dir()?)
+    }
 
-        let quest_dir = home_dir.join(".quest");
+    /// Manager rooted at `quest_dir` instead of the data directory.
+    pub fn in_dir(quest_dir: PathBuf) -> io::Result<Self> {
         fs::create_dir_all(&quest_dir)?;
-
         Ok(Self { quest_dir })
     }
 
+    /// Directory holding this manager's saves.
+    pub fn quest_dir(&self) -> &Path {
+        &self.quest_dir
+    }
+
     pub fn save_character(&self, state: &crate::core::game_state::GameState) -> io::Result<()> {
         // Use current time as last_save_time to prevent offline XP exploits.
         // Previously this used state.last_save_time which was only updated on load,
         // allowing players to accumulate offline XP during active play sessions.
-        let save_data = CharacterSaveData {
-            version: SAVE_FILE_VERSION,
-            character_id: state.character_id.clone(),
-            character_name: state.character_name.clone(),
-            character_level: state.character_level,
-            character_xp: state.character_xp,
-            attributes: state.attributes,
-            prestige_rank: state.prestige_rank,
-            total_prestige_count: state.total_prestige_count,
-            last_save_time: Utc::now().timestamp(),
-            play_time_seconds: state.play_time_seconds,
-            combat_state: state.combat_state.clone(),
-            equipment: state.equipment.clone(),
-            active_dungeon: state.active_dungeon.clone(),
-            fishing: state.fishing.clone(),
-            zone_progression: state.zone_progression.clone(),
-            chess_stats: state.chess_stats.clone(),
-        };
-
-        let json = serde_json::to_string_pretty(&save_data)
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let json = save_json(state, Utc::now().timestamp())?;
 
         let filename = format!("{}.json", sanitize_name(&state.character_name));
         let filepath = self.quest_dir.join(filename);
@@ -98,40 +398,36 @@ impl CharacterManager {
         Ok(())
     }
 
-    pub fn load_character(&self, filename: &str) -> io::Result<crate::core::game_state::GameState> {
-        let filepath = self.quest_dir.join(filename);
-        let json_content = fs::read_to_string(filepath)?;
-
-        let save_data: CharacterSaveData = serde_json::from_str(&json_content)
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
-
-        Ok(crate::core::game_state::GameState {
-            character_id: save_data.character_id,
-            character_name: save_data.character_name,
-            character_level: save_data.character_level,
-            character_xp: save_data.character_xp,
-            attributes: save_data.attributes,
-            prestige_rank: save_data.prestige_rank,
-            total_prestige_count: save_data.total_prestige_count,
-            last_save_time: save_data.last_save_time,
-            play_time_seconds: save_data.play_time_seconds,
-            combat_state: save_data.combat_state,
-            equipment: save_data.equipment,
-            active_dungeon: save_data.active_dungeon,
-            fishing: save_data.fishing,
-            active_fishing: None,
-            zone_progression: save_data.zone_progression,
-            challenge_menu: crate::challenges::menu::ChallengeMenu::new(),
-            chess_stats: save_data.chess_stats,
-            active_minigame: None,
-            session_kills: 0,
-            recent_drops: std::collections::VecDeque::new(),
-            last_minigame_win: None,
-        })
+    /// Loads a character save, rejecting it with `LoadError::ChecksumMismatch`
+    /// if its contents don't match the stored checksum. Saves written before
+    /// checksums were added load without verification.
+    pub fn load_character(
+        &self,
+        filename: &str,
+    ) -> Result<crate::core::game_state::GameState, LoadError> {
+        self.read_character(filename, true)
     }
 
-    pub fn list_characters(&self) -> io::Result<Vec<CharacterInfo>> {
-        let mut characters = Vec::new();
+    /// Loads a character save without verifying its checksum, for saves that
+    /// were edited on purpose.
+    pub fn force_load_character(
+        &self,
+        filename: &str,
+    ) -> Result<crate::core::game_state::GameState, LoadError> {
+        self.read_character(filename, false)
+    }
+
+    fn read_character(
+        &self,
+        filename: &str,
+        verify_checksum: bool,
+    ) -> Result<crate::core::game_state::GameState, LoadError> {
+        read_save_file(&self.quest_dir.join(filename), verify_checksum)
+    }
+
+    /// Filenames of all character saves (account-level JSON files excluded).
+    fn save_filenames(&self) -> io::Result<Vec<String>> {
+        let mut filenames = Vec::new();
 
         // Read directory entries
         let entries = fs::read_dir(&self.quest_dir)?;
@@ -156,8 +452,19 @@ impl CharacterManager {
                 continue;
             }
 
-            // Try to load character
-            match self.load_character(&filename) {
+            filenames.push(filename);
+        }
+
+        Ok(filenames)
+    }
+
+    pub fn list_characters(&self) -> io::Result<Vec<CharacterInfo>> {
+        let mut characters = Vec::new();
+
+        for filename in self.save_filenames()? {
+            // Try to load character (edited saves are listed normally and
+            // flagged when played)
+            match self.force_load_character(&filename) {
                 Ok(state) => {
                     characters.push(CharacterInfo {
                         character_id: state.character_id,
@@ -166,6 +473,7 @@ impl CharacterManager {
                         character_level: state.character_level,
                         prestige_rank: state.prestige_rank,
                         play_time_seconds: state.play_time_seconds,
+                        total_kills: state.total_kills,
                         last_save_time: state.last_save_time,
                         attributes: state.attributes,
                         equipment: state.equipment,
@@ -181,6 +489,7 @@ impl CharacterManager {
                         character_level: 0,
                         prestige_rank: 0,
                         play_time_seconds: 0,
+                        total_kills: 0,
                         last_save_time: 0,
                         attributes: super::attributes::Attributes::new(),
                         equipment: crate::items::Equipment::new(),
@@ -196,12 +505,108 @@ impl CharacterManager {
         Ok(characters)
     }
 
+    /// Loads two saves (checksums not verified) and summarizes them side by
+    /// side. Fails with `NotFound` naming the file if either save is missing.
+    #[allow(dead_code)]
+    pub fn compare(&self, a: &str, b: &str) -> Result<CharacterComparison, LoadError> {
+        let load = |filename: &str| -> Result<CharacterSnapshot, LoadError> {
+            if !self.quest_dir.join(filename).exists() {
+                return Err(LoadError::Io(io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("{} not found", filename),
+                )));
+            }
+            let state = self.force_load_character(filename)?;
+            Ok(CharacterSnapshot::from_state(&state))
+        };
+        Ok(CharacterComparison {
+            a: load(a)?,
+            b: load(b)?,
+        })
+    }
+
+    /// Soft-deletes a character by moving its save into `deleted/` with a
+    /// timestamp. Use `restore_character` to undo.
     pub fn delete_character(&self, filename: &str) -> io::Result<()> {
         let filepath = self.quest_dir.join(filename);
-        fs::remove_file(filepath)?;
+        if !filepath.exists() {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("{} not found", filename),
+            ));
+        }
+
+        let stem = filename.strip_suffix(".json").unwrap_or(filename);
+        let deleted_dir = self.deleted_dir();
+        fs::create_dir_all(&deleted_dir)?;
+        let deleted_name = format!("{}.{}.json", stem, Utc::now().timestamp());
+        fs::rename(filepath, deleted_dir.join(deleted_name))?;
         Ok(())
     }
 
+    /// Lists soft-deleted saves, most recently deleted first.
+    pub fn list_deleted(&self) -> io::Result<Vec<DeletedCharacter>> {
+        let deleted_dir = self.deleted_dir();
+        if !deleted_dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut deleted: Vec<DeletedCharacter> = fs::read_dir(deleted_dir)?
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| DeletedCharacter::parse(entry.file_name().to_str()?))
+            .collect();
+        deleted.sort_by_key(|d| std::cmp::Reverse(d.deleted_at));
+        Ok(deleted)
+    }
+
+    /// Moves a soft-deleted save back into place. Fails if a character with
+    /// the same name exists or the roster is already full. Returns the
+    /// restored save filename.
+    pub fn restore_character(&self, deleted_filename: &str) -> io::Result<String> {
+        let deleted = DeletedCharacter::parse(deleted_filename).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{} is not a deleted save", deleted_filename),
+            )
+        })?;
+
+        if self.list_characters()?.len() >= MAX_CHARACTERS {
+            return Err(io::Error::other(format!(
+                "Can't restore {}: already {} characters (delete one first)",
+                deleted.original_filename, MAX_CHARACTERS
+            )));
+        }
+
+        let target = self.quest_dir.join(&deleted.original_filename);
+        if target.exists() {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("{} already exists", deleted.original_filename),
+            ));
+        }
+
+        fs::rename(self.deleted_dir().join(deleted_filename), target)?;
+        Ok(deleted.original_filename)
+    }
+
+    /// Permanently removes soft-deleted saves older than `older_than`.
+    /// Returns the number of saves removed.
+    pub fn purge_deleted(&self, older_than: std::time::Duration) -> io::Result<usize> {
+        let cutoff = Utc::now().timestamp() - older_than.as_secs() as i64;
+        let mut purged = 0;
+        for deleted in self.list_deleted()? {
+            if deleted.deleted_at < cutoff {
+                fs::remove_file(self.deleted_dir().join(&deleted.filename))?;
+                purged += 1;
+            }
+        }
+        Ok(purged)
+    }
+
+    fn deleted_dir(&self) -> PathBuf {
+        self.quest_dir.join(DELETED_DIR)
+    }
+
     pub fn rename_character(&self, old_filename: &str, new_name: String) -> io::Result<()> {
         // Validate new name
         validate_name(&new_name).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
@@ -223,11 +628,17 @@ impl CharacterManager {
     }
 }
 
+/// Most characters the select screen holds at once
+pub const MAX_CHARACTERS: usize = 3;
+
+/// Subdirectory of `~/.quest/` holding soft-deleted saves
+const DELETED_DIR: &str = "deleted";
+
 /// Account-level JSON files that are not character saves
 const ACCOUNT_FILES: &[&str] = &["haven.json", "achievements.json"];
 
 /// Reserved names that cannot be used for characters (would conflict with system files)
-const RESERVED_NAMES: &[&str] = &["haven", "achievements"];
+const RESERVED_NAMES: &[&str] = &["haven", "achievements", "settings"];
 
 pub fn validate_name(name: &str) -> Result<(), String> {
     let trimmed = name.trim();
@@ -296,14 +707,40 @@ mod tests {
             equipment: Equipment::new(),
             active_dungeon: None,
             fishing: crate::fishing::FishingState::default(),
+            fishing_gear: crate::fishing::FishingGear::default(),
+            active_bait: None,
             active_fishing: None,
             zone_progression: crate::zones::ZoneProgression::default(),
             challenge_menu: crate::challenges::menu::ChallengeMenu::new(),
             chess_stats: crate::challenges::chess::ChessStats::default(),
+            total_kills: 0,
+            tutorial: crate::core::tutorial::TutorialState::default(),
+            expedition: None,
+            prestige_streak: 0,
+            loot_filter_min_rarity: None,
+            salvage_materials: 0,
+            gems: Vec::new(),
+            verbose_loot: false,
+            verbose_combat: false,
+            combat_stance: crate::combat::CombatStance::default(),
+            auto_allocate: None,
+            loot_history: std::collections::VecDeque::new(),
+            boss_ledger: crate::zones::BossLedger::default(),
+            adaptive_intensity: Default::default(),
+            banked_xp: 0,
+            prestige_history: Vec::new(),
             active_minigame: None,
+            practice_minigame: false,
+            wager: crate::challenges::Wager::None,
+            auto_minigame: None,
             session_kills: 0,
             recent_drops: std::collections::VecDeque::new(),
+            metrics: crate::core::metrics::MetricsBuffer::default(),
             last_minigame_win: None,
+            training_dummy: None,
+            game_events: Vec::new(),
+            queued_dungeon_seed: None,
+            equipment_history: Default::default(),
         }
     }
 
@@ -345,10 +782,29 @@ mod tests {
     #[test]
     fn test_character_manager_new() {
         let manager = CharacterManager::new().expect("Failed to create CharacterManager");
-        assert!(manager.quest_dir.ends_with(".quest"));
+        assert_eq!(manager.quest_dir, crate::core::paths::quest_dir().unwrap());
         assert!(manager.quest_dir.exists());
     }
 
+    #[test]
+    fn test_save_dir_override_routes_save_and_load() {
+        let dir = std::env::temp_dir().join(format!("quest-profile-{}", std::process::id()));
+        fs::remove_dir_all(&dir).ok();
+        let quest_dir =
+            crate::core::paths::resolve_quest_dir(Some(dir.clone().into()), None).unwrap();
+        fs::create_dir_all(&quest_dir).unwrap();
+        let manager = CharacterManager { quest_dir };
+
+        manager
+            .save_character(&make_test_state("Profiled"))
+            .unwrap();
+        assert!(dir.join("profiled.json").exists());
+        let loaded = manager.load_character("profiled.json").unwrap();
+        assert_eq!(loaded.character_name, "Profiled");
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
     #[test]
     fn test_save_and_load_character() {
         let manager = CharacterManager::new().unwrap();
@@ -425,6 +881,117 @@ mod tests {
 
         manager.delete_character(filename).expect("Delete failed");
         assert!(!manager.quest_dir.join(filename).exists());
+
+        // Cleanup the soft-deleted copy
+        for deleted in manager.list_deleted().unwrap() {
+            if deleted.original_filename == filename {
+                fs::remove_file(manager.deleted_dir().join(deleted.filename)).ok();
+            }
+        }
+    }
+
+    /// Manager rooted in a fresh temp directory (isolated from `~/.quest/`).
+    fn temp_manager(label: &str) -> CharacterManager {
+        let quest_dir =
+            std::env::temp_dir().join(format!("quest-{}-{}", label, std::process::id()));
+        fs::remove_dir_all(&quest_dir).ok();
+        fs::create_dir_all(&quest_dir).unwrap();
+        CharacterManager { quest_dir }
+    }
+
+    #[test]
+    fn test_delete_moves_save_to_deleted_dir() {
+        let manager = temp_manager("soft-delete");
+        manager.save_character(&make_test_state("Doomed")).unwrap();
+
+        manager.delete_character("doomed.json").unwrap();
+
+        assert!(!manager.quest_dir.join("doomed.json").exists());
+        let deleted = manager.list_deleted().unwrap();
+        assert_eq!(deleted.len(), 1);
+        assert_eq!(deleted[0].original_filename, "doomed.json");
+        assert!(manager.deleted_dir().join(&deleted[0].filename).exists());
+        // Deleted saves don't show up in the character list
+        assert!(manager.list_characters().unwrap().is_empty());
+
+        fs::remove_dir_all(&manager.quest_dir).ok();
+    }
+
+    #[test]
+    fn test_restore_brings_deleted_save_back() {
+        let manager = temp_manager("restore");
+        let mut state = make_test_state("Phoenix");
+        state.character_level = 42;
+        manager.save_character(&state).unwrap();
+        manager.delete_character("phoenix.json").unwrap();
+
+        let deleted = manager.list_deleted().unwrap();
+        let restored = manager.restore_character(&deleted[0].filename).unwrap();
+
+        assert_eq!(restored, "phoenix.json");
+        assert_eq!(
+            manager
+                .load_character("phoenix.json")
+                .unwrap()
+                .character_level,
+            42
+        );
+        assert!(manager.list_deleted().unwrap().is_empty());
+
+        fs::remove_dir_all(&manager.quest_dir).ok();
+    }
+
+    #[test]
+    fn test_restore_refuses_to_overwrite_existing_save() {
+        let manager = temp_manager("restore-conflict");
+        manager.save_character(&make_test_state("Twin")).unwrap();
+        manager.delete_character("twin.json").unwrap();
+        manager.save_character(&make_test_state("Twin")).unwrap();
+
+        let deleted = manager.list_deleted().unwrap();
+        let result = manager.restore_character(&deleted[0].filename);
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::AlreadyExists);
+
+        fs::remove_dir_all(&manager.quest_dir).ok();
+    }
+
+    #[test]
+    fn test_restore_refuses_when_roster_is_full() {
+        let manager = temp_manager("restore-full");
+        manager.save_character(&make_test_state("Gone")).unwrap();
+        manager.delete_character("gone.json").unwrap();
+        for name in ["One", "Two", "Three"] {
+            manager.save_character(&make_test_state(name)).unwrap();
+        }
+
+        let deleted = manager.list_deleted().unwrap();
+        let err = manager.restore_character(&deleted[0].filename).unwrap_err();
+        assert!(err.to_string().contains("already 3 characters"));
+        assert_eq!(manager.list_deleted().unwrap().len(), 1);
+        assert_eq!(manager.list_characters().unwrap().len(), 3);
+
+        fs::remove_dir_all(&manager.quest_dir).ok();
+    }
+
+    #[test]
+    fn test_purge_removes_only_old_deleted_saves() {
+        let manager = temp_manager("purge");
+        manager.save_character(&make_test_state("Recent")).unwrap();
+        manager.delete_character("recent.json").unwrap();
+
+        // A save deleted long ago
+        fs::write(manager.deleted_dir().join("ancient.1000.json"), "{}").unwrap();
+
+        let purged = manager
+            .purge_deleted(std::time::Duration::from_secs(3600))
+            .unwrap();
+
+        assert_eq!(purged, 1);
+        let remaining = manager.list_deleted().unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].original_filename, "recent.json");
+
+        fs::remove_dir_all(&manager.quest_dir).ok();
     }
 
     #[test]
@@ -494,6 +1061,58 @@ mod tests {
         fs::remove_file(manager.quest_dir.join("renametest.json")).ok();
     }
 
+    #[test]
+    fn test_saved_character_has_verified_checksum() {
+        let manager = CharacterManager::new().unwrap();
+        let state = make_test_state("ChecksumRoundtrip");
+        manager.save_character(&state).unwrap();
+
+        let json = fs::read_to_string(manager.quest_dir.join("checksumroundtrip.json")).unwrap();
+        assert!(json.contains("\"checksum\""));
+        assert!(manager.load_character("checksumroundtrip.json").is_ok());
+
+        fs::remove_file(manager.quest_dir.join("checksumroundtrip.json")).ok();
+    }
+
+    #[test]
+    fn test_byte_flipped_save_fails_checksum() {
+        let manager = CharacterManager::new().unwrap();
+        let mut state = make_test_state("ChecksumFlip");
+        state.total_kills = 1234;
+        manager.save_character(&state).unwrap();
+
+        // Flip a single digit of the kill count: still valid JSON, wrong checksum
+        let filepath = manager.quest_dir.join("checksumflip.json");
+        let json = fs::read_to_string(&filepath).unwrap();
+        fs::write(&filepath, json.replace("1234", "9234")).unwrap();
+
+        assert!(matches!(
+            manager.load_character("checksumflip.json"),
+            Err(LoadError::ChecksumMismatch)
+        ));
+        // A forced load still succeeds with the edited value
+        let forced = manager.force_load_character("checksumflip.json").unwrap();
+        assert_eq!(forced.total_kills, 9234);
+
+        fs::remove_file(filepath).ok();
+    }
+
+    #[test]
+    fn test_write_save_file_keeps_last_save_time() {
+        let manager = temp_manager("doctor-copy");
+        let mut state = make_test_state("Repaired");
+        state.last_save_time = 1_000;
+        let path = manager.quest_dir.join("repaired-copy.json");
+
+        write_save_file(&state, &path).unwrap();
+
+        let loaded = read_save_file(&path, true).unwrap();
+        assert_eq!(loaded.character_name, "Repaired");
+        assert_eq!(loaded.last_save_time, 1_000);
+
+        fs::remove_dir_all(&manager.quest_dir).ok();
+    }
+
     #[test]
     fn test_corrupted_file_handling() {
         let manager = CharacterManager::new().unwrap();
@@ -516,6 +1135,40 @@ mod tests {
         fs::remove_file(filepath).ok();
     }
 
+    #[test]
+    fn test_account_stats_sums_saves_and_skips_corrupt() {
+        let manager = temp_manager("account-stats");
+        let quest_dir = manager.quest_dir.clone();
+
+        let mut first = make_test_state("AccountOne");
+        first.total_kills = 120;
+        first.play_time_seconds = 3600;
+        first.prestige_rank = 4;
+        manager.save_character(&first).unwrap();
+
+        let mut second = make_test_state("AccountTwo");
+        second.total_kills = 80;
+        second.play_time_seconds = 600;
+        second.prestige_rank = 9;
+        manager.save_character(&second).unwrap();
+
+        fs::write(quest_dir.join("broken.json"), "{ not a save }").unwrap();
+        fs::write(quest_dir.join("haven.json"), "{}").unwrap();
+
+        let stats = AccountStats::from_characters(&manager.list_characters().unwrap());
+        assert_eq!(
+            stats,
+            AccountStats {
+                total_characters: 2,
+                total_kills: 200,
+                total_play_time_seconds: 4200,
+                highest_prestige: 9,
+            }
+        );
+
+        fs::remove_dir_all(quest_dir).ok();
+    }
+
     #[test]
     fn test_sanitize_name_special_cases() {
         // Unicode alphanumeric characters are preserved
@@ -557,6 +1210,7 @@ mod tests {
         state.prestige_rank = 3;
         state.total_prestige_count = 5;
         state.play_time_seconds = 9999;
+        state.total_kills = 4321;
         state.attributes.set(AttributeType::Strength, 15);
         state.attributes.set(AttributeType::Dexterity, 18);
 
@@ -572,6 +1226,7 @@ mod tests {
         assert_eq!(loaded.prestige_rank, 3);
         assert_eq!(loaded.total_prestige_count, 5);
         assert_eq!(loaded.play_time_seconds, 9999);
+        assert_eq!(loaded.total_kills, 4321);
         assert_eq!(loaded.attributes.get(AttributeType::Strength), 15);
         assert_eq!(loaded.attributes.get(AttributeType::Dexterity), 18);
 
@@ -1145,6 +1800,7 @@ mod tests {
                 affix_type: AffixType::DamagePercent,
                 value: 5.0,
             }],
+            sockets: Vec::new(),
         };
         let json = serde_json::to_string(&item).expect("Item should serialize");
         let _: Item = serde_json::from_str(&json).expect("Item should roundtrip");
@@ -1230,4 +1886,46 @@ mod tests {
         assert!(validate_name("achievements2").is_ok());
         assert!(validate_name("myachievements").is_ok());
     }
+
+    #[test]
+    fn test_compare_orders_characters_by_power_score() {
+        use crate::character::attributes::AttributeType;
+
+        let manager = temp_manager("compare");
+        let mut main = make_test_state("Main");
+        main.character_level = 80;
+        main.prestige_rank = 4;
+        main.play_time_seconds = 36_000;
+        for attr in AttributeType::all() {
+            main.attributes.set(attr, 40);
+        }
+        manager.save_character(&main).unwrap();
+        manager.save_character(&make_test_state("Alt")).unwrap();
+
+        let comparison = manager.compare("main.json", "alt.json").unwrap();
+
+        assert_eq!(comparison.a.character_name, "Main");
+        assert_eq!(comparison.a.character_level, 80);
+        assert_eq!(comparison.a.prestige_rank, 4);
+        assert_eq!(comparison.a.play_time_seconds, 36_000);
+        assert_eq!(comparison.b.zone_id, 1);
+        assert_eq!(comparison.power_ordering(), std::cmp::Ordering::Greater);
+        let reversed = manager.compare("alt.json", "main.json").unwrap();
+        assert_eq!(reversed.power_ordering(), std::cmp::Ordering::Less);
+
+        fs::remove_dir_all(&manager.quest_dir).ok();
+    }
+
+    #[test]
+    fn test_compare_missing_save_names_the_file() {
+        let manager = temp_manager("compare-missing");
+        manager.save_character(&make_test_state("Solo")).unwrap();
+
+        let err = manager.compare("solo.json", "ghost.json").unwrap_err();
+
+        assert!(matches!(&err, LoadError::Io(e) if e.kind() == io::ErrorKind::NotFound));
+        assert_eq!(err.to_string(), "ghost.json not found");
+
+        fs::remove_dir_all(&manager.quest_dir).ok();
+    }
 }
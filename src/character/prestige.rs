@@ -34,6 +34,12 @@ impl PrestigeCombatBonuses {
     }
 }
 
+/// Bonus damage percent from the prestige streak (consecutive prestiges
+/// without dying), capped at `PRESTIGE_STREAK_DAMAGE_CAP_PERCENT`.
+pub fn prestige_streak_damage_percent(streak: u32) -> f64 {
+    (streak as f64 * PRESTIGE_STREAK_DAMAGE_PERCENT).min(PRESTIGE_STREAK_DAMAGE_CAP_PERCENT)
+}
+
 /// Represents a prestige tier with its properties
 #[derive(Debug, Clone)]
 pub struct PrestigeTier {
@@ -44,6 +50,56 @@ pub struct PrestigeTier {
     pub multiplier: f64,
 }
 
+/// First rank past the uniquely named tiers.
+const FIRST_ENDGAME_RANK: u32 = 20;
+/// Endgame tier names, each covering `ENDGAME_SUB_RANKS` ranks. The last one
+/// keeps counting sub-ranks forever.
+const ENDGAME_TIER_NAMES: [&str; 4] = ["Eternal", "Ascendant", "Primordial", "Infinite"];
+const ENDGAME_SUB_RANKS: u32 = 10;
+
+impl PrestigeTier {
+    /// Name shown for `rank`. Ranks 0-19 use their tier name as is; from 20
+    /// on, ranks are grouped into endgame tiers of `ENDGAME_SUB_RANKS` with
+    /// a Roman numeral sub-rank ("Eternal I" .. "Eternal X", "Ascendant I", ...).
+    pub fn display_name(rank: u32) -> String {
+        if rank < FIRST_ENDGAME_RANK {
+            return get_prestige_name(rank).to_string();
+        }
+        let steps = rank - FIRST_ENDGAME_RANK;
+        let last = ENDGAME_TIER_NAMES.len() - 1;
+        let band = ((steps / ENDGAME_SUB_RANKS) as usize).min(last);
+        let sub_rank = steps - band as u32 * ENDGAME_SUB_RANKS + 1;
+        format!("{} {}", ENDGAME_TIER_NAMES[band], to_roman(sub_rank))
+    }
+}
+
+/// Roman numeral for `n` (n >= 1).
+fn to_roman(mut n: u32) -> String {
+    const NUMERALS: [(u32, &str); 13] = [
+        (1000, "M"),
+        (900, "CM"),
+        (500, "D"),
+        (400, "CD"),
+        (100, "C"),
+        (90, "XC"),
+        (50, "L"),
+        (40, "XL"),
+        (10, "X"),
+        (9, "IX"),
+        (5, "V"),
+        (4, "IV"),
+        (1, "I"),
+    ];
+    let mut out = String::new();
+    for &(value, numeral) in &NUMERALS {
+        while n >= value {
+            out.push_str(numeral);
+            n -= value;
+        }
+    }
+    out
+}
+
 /// Gets the name for a prestige rank
 fn get_prestige_name(rank: u32) -> &'static str {
     match rank {
@@ -171,6 +227,20 @@ pub fn perform_prestige(state: &mut GameState) {
         return;
     }
 
+    // Record how far this run got before resetting it
+    state
+        .prestige_history
+        .push(crate::core::game_state::PrestigeRecord {
+            rank: state.prestige_rank + 1,
+            level: state.character_level,
+            zone_id: state.zone_progression.current_zone_id,
+            subzone_id: state.zone_progression.current_subzone_id,
+            play_time_seconds: state.play_time_seconds,
+        });
+
+    // Bank a slice of current XP as a head-start (see `apply_banked_xp`)
+    state.banked_xp = state.character_xp * PRESTIGE_BANKED_XP_PERCENT / 100;
+
     // Reset character to level 1, XP 0
     state.character_level = 1;
     state.character_xp = 0;
@@ -178,8 +248,9 @@ pub fn perform_prestige(state: &mut GameState) {
     // Reset attributes to base 10
     state.attributes = Attributes::new();
 
-    // Reset equipment (complete wipe)
+    // Reset equipment (complete wipe), including what quick-swap could restore
     state.equipment = Equipment::new();
+    state.equipment_history = Default::default();
 
     // Reset active dungeon
     state.active_dungeon = None;
@@ -198,11 +269,16 @@ pub fn perform_prestige(state: &mut GameState) {
     // Increment prestige rank and total prestige count
     state.prestige_rank += 1;
     state.total_prestige_count += 1;
+    state.prestige_streak += 1;
 
     // Reset zone progression but keep unlocks based on new prestige rank
     state
         .zone_progression
         .reset_for_prestige(state.prestige_rank);
+
+    state.push_game_event(crate::core::game_event::GameEvent::Prestige {
+        new_rank: state.prestige_rank,
+    });
 }
 
 /// Performs prestige with Vault item preservation.
@@ -234,6 +310,17 @@ pub fn perform_prestige_with_vault(
     }
 }
 
+/// Refunds XP banked by the last prestige to the fresh character, leveling
+/// it up as usual. Called after `perform_prestige[_with_vault]` so restored
+/// Vault items count towards max HP. Returns the XP applied.
+pub fn apply_banked_xp(state: &mut GameState) -> u64 {
+    let banked = std::mem::take(&mut state.banked_xp);
+    if banked > 0 {
+        crate::core::game_logic::apply_tick_xp(state, banked as f64);
+    }
+    banked
+}
+
 /// Gets the adventurer rank based on average level
 ///
 /// # Arguments
@@ -255,6 +342,33 @@ pub fn get_adventurer_rank(avg_level: u32) -> &'static str {
 #[cfg(test)]
 mod tests {
     use super::*;
+
+    #[test]
+    fn test_display_name_keeps_named_tiers() {
+        assert_eq!(PrestigeTier::display_name(0), "None");
+        assert_eq!(PrestigeTier::display_name(3), "Gold");
+        assert_eq!(PrestigeTier::display_name(19), "Legendary");
+    }
+
+    #[test]
+    fn test_display_name_sub_ranks_increment_within_tier() {
+        assert_eq!(PrestigeTier::display_name(20), "Eternal I");
+        assert_eq!(PrestigeTier::display_name(21), "Eternal II");
+        assert_eq!(PrestigeTier::display_name(23), "Eternal IV");
+        assert_eq!(PrestigeTier::display_name(28), "Eternal IX");
+        assert_eq!(PrestigeTier::display_name(29), "Eternal X");
+    }
+
+    #[test]
+    fn test_display_name_changes_at_tier_boundaries() {
+        assert_eq!(PrestigeTier::display_name(30), "Ascendant I");
+        assert_eq!(PrestigeTier::display_name(40), "Primordial I");
+        assert_eq!(PrestigeTier::display_name(49), "Primordial X");
+        assert_eq!(PrestigeTier::display_name(50), "Infinite I");
+        // The last tier keeps counting
+        assert_eq!(PrestigeTier::display_name(63), "Infinite XIV");
+        assert_eq!(PrestigeTier::display_name(148), "Infinite XCIX");
+    }
     use crate::character::attributes::AttributeType;
 
     #[test]
@@ -409,6 +523,54 @@ mod tests {
         assert!(can_prestige(&state));
     }
 
+    #[test]
+    fn test_prestige_increments_streak() {
+        let mut state = GameState::new("Test Hero".to_string(), 0);
+        state.character_level = 10;
+        perform_prestige(&mut state);
+        assert_eq!(state.prestige_streak, 1);
+
+        state.character_level = 25;
+        perform_prestige(&mut state);
+        assert_eq!(state.prestige_streak, 2);
+    }
+
+    #[test]
+    fn test_prestige_streak_damage_scales_and_caps() {
+        assert_eq!(prestige_streak_damage_percent(0), 0.0);
+        assert!(prestige_streak_damage_percent(3) > prestige_streak_damage_percent(1));
+        assert_eq!(
+            prestige_streak_damage_percent(1000),
+            PRESTIGE_STREAK_DAMAGE_CAP_PERCENT
+        );
+    }
+
+    #[test]
+    fn test_prestige_banks_xp_for_fresh_character() {
+        use crate::core::game_logic::xp_for_next_level;
+
+        let mut state = GameState::new("Banker".to_string(), 0);
+        state.character_level = 10;
+        state.character_xp = 5000;
+
+        perform_prestige(&mut state);
+        let banked = 5000 * PRESTIGE_BANKED_XP_PERCENT / 100;
+        assert_eq!(state.banked_xp, banked);
+        assert_eq!(state.character_xp, 0);
+
+        assert_eq!(apply_banked_xp(&mut state), banked);
+        assert_eq!(state.banked_xp, 0);
+        let total_xp: u64 = (1..state.character_level)
+            .map(xp_for_next_level)
+            .sum::<u64>()
+            + state.character_xp;
+        assert_eq!(total_xp, banked);
+        assert!(state.character_level > 1);
+
+        // Nothing left to refund
+        assert_eq!(apply_banked_xp(&mut state), 0);
+    }
+
     #[test]
     fn test_perform_prestige() {
         let mut state = GameState::new("Test Hero".to_string(), 0);
@@ -445,6 +607,53 @@ mod tests {
         assert_eq!(state.prestige_rank, old_rank);
     }
 
+    #[test]
+    fn test_prestige_history_records_each_run() {
+        use crate::core::game_state::PrestigeRecord;
+
+        let mut state = GameState::new("Historian".to_string(), 0);
+        state.character_level = 12;
+        state.zone_progression.current_zone_id = 2;
+        state.zone_progression.current_subzone_id = 3;
+        state.play_time_seconds = 3_600;
+        perform_prestige(&mut state);
+
+        state.character_level = 30;
+        state.zone_progression.current_zone_id = 4;
+        state.zone_progression.current_subzone_id = 1;
+        state.play_time_seconds = 5_400;
+        perform_prestige(&mut state);
+
+        assert_eq!(
+            state.prestige_history,
+            vec![
+                PrestigeRecord {
+                    rank: 1,
+                    level: 12,
+                    zone_id: 2,
+                    subzone_id: 3,
+                    play_time_seconds: 3_600,
+                },
+                PrestigeRecord {
+                    rank: 2,
+                    level: 30,
+                    zone_id: 4,
+                    subzone_id: 1,
+                    play_time_seconds: 5_400,
+                },
+            ]
+        );
+        assert!(
+            state.prestige_history[0].play_time_seconds
+                < state.prestige_history[1].play_time_seconds
+        );
+        let run_lengths: Vec<u64> = state
+            .prestige_timeline()
+            .map(|(_, seconds)| seconds)
+            .collect();
+        assert_eq!(run_lengths, vec![3_600, 1_800]);
+    }
+
     #[test]
     fn test_get_adventurer_rank() {
         assert_eq!(get_adventurer_rank(0), "Novice");
@@ -488,6 +697,7 @@ mod tests {
                 ..AttributeBonuses::new()
             },
             affixes: vec![],
+            sockets: Vec::new(),
         };
         game_state
             .equipment
@@ -531,6 +741,7 @@ mod tests {
                 ..AttributeBonuses::new()
             },
             affixes: vec![],
+            sockets: Vec::new(),
         };
         let armor = Item {
             slot: EquipmentSlot::Armor,
@@ -543,6 +754,7 @@ mod tests {
                 ..AttributeBonuses::new()
             },
             affixes: vec![],
+            sockets: Vec::new(),
         };
         game_state
             .equipment
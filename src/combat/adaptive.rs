@@ -0,0 +1,151 @@
+//! Adaptive combat intensity: optional overworld difficulty that follows the
+//! player's pace.
+//!
+//! When enabled, `game_tick` feeds the per-second kill rate from
+//! `MetricsBuffer` into `on_second()`: sustained fast killing slowly raises
+//! the enemy stat multiplier, slow killing lets it decay, and each death
+//! knocks it down. Regular overworld spawns (not bosses) are scaled by it.
+//! Off by default; turning it off resets the multiplier.
+
+use serde::{Deserialize, Serialize};
+
+use super::types::Enemy;
+use crate::core::constants::{
+    ADAPTIVE_DEATH_PENALTY, ADAPTIVE_DECAY_PER_SECOND, ADAPTIVE_FAST_KILLS_PER_MINUTE,
+    ADAPTIVE_MAX_MULTIPLIER, ADAPTIVE_RAMP_PER_SECOND, ADAPTIVE_SLOW_KILLS_PER_MINUTE,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AdaptiveIntensity {
+    pub enabled: bool,
+    /// Enemy HP/damage/defense multiplier, 1.0 to `ADAPTIVE_MAX_MULTIPLIER`
+    pub multiplier: f64,
+}
+
+impl Default for AdaptiveIntensity {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            multiplier: 1.0,
+        }
+    }
+}
+
+impl AdaptiveIntensity {
+    /// Flips the setting, returning the new state. Turning it off resets
+    /// the multiplier.
+    pub fn toggle(&mut self) -> bool {
+        self.enabled = !self.enabled;
+        if !self.enabled {
+            self.multiplier = 1.0;
+        }
+        self.enabled
+    }
+
+    /// Adjusts the multiplier for one second of play at `kills_per_minute`.
+    pub fn on_second(&mut self, kills_per_minute: f64) {
+        if !self.enabled {
+            return;
+        }
+        if kills_per_minute >= ADAPTIVE_FAST_KILLS_PER_MINUTE {
+            self.multiplier += ADAPTIVE_RAMP_PER_SECOND;
+        } else if kills_per_minute < ADAPTIVE_SLOW_KILLS_PER_MINUTE {
+            self.multiplier -= ADAPTIVE_DECAY_PER_SECOND;
+        }
+        self.clamp();
+    }
+
+    pub fn on_death(&mut self) {
+        if !self.enabled {
+            return;
+        }
+        self.multiplier -= ADAPTIVE_DEATH_PENALTY;
+        self.clamp();
+    }
+
+    fn clamp(&mut self) {
+        self.multiplier = self.multiplier.clamp(1.0, ADAPTIVE_MAX_MULTIPLIER);
+    }
+
+    /// Applies the multiplier to a freshly spawned enemy (unchanged when off).
+    pub fn scale_enemy(&self, mut enemy: Enemy) -> Enemy {
+        if !self.enabled || self.multiplier <= 1.0 {
+            return enemy;
+        }
+        enemy.max_hp = (enemy.max_hp as f64 * self.multiplier) as u32;
+        enemy.current_hp = enemy.max_hp;
+        enemy.damage = (enemy.damage as f64 * self.multiplier) as u32;
+        enemy.defense = (enemy.defense as f64 * self.multiplier) as u32;
+        enemy
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn enabled() -> AdaptiveIntensity {
+        AdaptiveIntensity {
+            enabled: true,
+            multiplier: 1.0,
+        }
+    }
+
+    #[test]
+    fn test_sustained_fast_kills_raise_multiplier() {
+        let mut adaptive = enabled();
+        for _ in 0..100 {
+            adaptive.on_second(ADAPTIVE_FAST_KILLS_PER_MINUTE);
+        }
+        assert!((adaptive.multiplier - (1.0 + 100.0 * ADAPTIVE_RAMP_PER_SECOND)).abs() < 1e-9);
+
+        for _ in 0..10_000 {
+            adaptive.on_second(60.0);
+        }
+        assert_eq!(adaptive.multiplier, ADAPTIVE_MAX_MULTIPLIER);
+    }
+
+    #[test]
+    fn test_multiplier_decays_when_struggling() {
+        let mut adaptive = enabled();
+        adaptive.multiplier = 1.8;
+
+        adaptive.on_death();
+        assert!((adaptive.multiplier - (1.8 - ADAPTIVE_DEATH_PENALTY)).abs() < 1e-9);
+
+        let before = adaptive.multiplier;
+        adaptive.on_second(0.0);
+        assert!(adaptive.multiplier < before);
+
+        for _ in 0..10_000 {
+            adaptive.on_second(0.0);
+        }
+        assert_eq!(adaptive.multiplier, 1.0);
+    }
+
+    #[test]
+    fn test_disabled_by_default_and_leaves_enemies_alone() {
+        let mut adaptive = AdaptiveIntensity::default();
+        assert!(!adaptive.enabled);
+        for _ in 0..100 {
+            adaptive.on_second(60.0);
+        }
+        assert_eq!(adaptive.multiplier, 1.0);
+
+        let enemy = Enemy::new("Rat".to_string(), 100, 10);
+        assert_eq!(adaptive.scale_enemy(enemy).max_hp, 100);
+    }
+
+    #[test]
+    fn test_scale_enemy_and_toggle_off_resets() {
+        let mut adaptive = enabled();
+        adaptive.multiplier = 1.5;
+        let enemy = adaptive.scale_enemy(Enemy::new("Rat".to_string(), 100, 10));
+        assert_eq!(enemy.max_hp, 150);
+        assert_eq!(enemy.current_hp, 150);
+        assert_eq!(enemy.damage, 15);
+
+        assert!(!adaptive.toggle());
+        assert_eq!(adaptive.multiplier, 1.0);
+    }
+}
@@ -0,0 +1,307 @@
+//! Fight outcome forecasts, used to warn before pushing a boss and to rate
+//! how hard the current zone is.
+//!
+//! Uses the same damage, crit, defense, and attack timing formulas as
+//! `update_combat`, but only sees what `GameState` holds: Haven bonuses,
+//! damage reflection, and parry counters are left out, so the forecast errs
+//! on the cautious side.
+
+use super::logic::{damage_after_defense, effective_enemy_attack_interval};
+use super::types::Enemy;
+use crate::character::derived_stats::DerivedStats;
+use crate::character::prestige::{prestige_streak_damage_percent, PrestigeCombatBonuses};
+use crate::core::constants::ATTACK_INTERVAL_SECONDS;
+use crate::core::game_state::GameState;
+use crate::zones::data::enemy_stats_for;
+use crate::zones::get_zone;
+
+/// Win probability below which a fight is flagged as risky.
+pub const RISKY_WIN_PROBABILITY: f64 = 0.75;
+
+/// Win probability at or above which a fight counts as trivial.
+pub const TRIVIAL_WIN_PROBABILITY: f64 = 0.999;
+
+/// Win probability below which a fight is flagged as deadly.
+pub const DEADLY_WIN_PROBABILITY: f64 = 0.25;
+
+/// Predicted outcome of fighting an enemy from the current state.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CombatForecast {
+    /// Player attacks needed to kill the enemy, at average crit luck
+    pub hits_to_kill: u32,
+    /// HP the player is expected to lose before the enemy dies
+    pub damage_taken: u32,
+    /// Chance the enemy dies before the player (0.0 - 1.0)
+    pub win_probability: f64,
+}
+
+impl CombatForecast {
+    pub fn is_risky(&self) -> bool {
+        self.win_probability < RISKY_WIN_PROBABILITY
+    }
+}
+
+/// Coarse rating of how the current zone's regular enemies match up against
+/// the player, shown in the zone info panel instead of raw numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DifficultyLabel {
+    Trivial,
+    Fair,
+    Risky,
+    Deadly,
+}
+
+impl DifficultyLabel {
+    /// Buckets a forecast win probability: Trivial at `TRIVIAL_WIN_PROBABILITY`
+    /// and up, Fair down to `RISKY_WIN_PROBABILITY`, Risky down to
+    /// `DEADLY_WIN_PROBABILITY`, and Deadly below that.
+    pub fn from_win_probability(win_probability: f64) -> Self {
+        if win_probability >= TRIVIAL_WIN_PROBABILITY {
+            DifficultyLabel::Trivial
+        } else if win_probability >= RISKY_WIN_PROBABILITY {
+            DifficultyLabel::Fair
+        } else if win_probability >= DEADLY_WIN_PROBABILITY {
+            DifficultyLabel::Risky
+        } else {
+            DifficultyLabel::Deadly
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            DifficultyLabel::Trivial => "Trivial",
+            DifficultyLabel::Fair => "Fair",
+            DifficultyLabel::Risky => "Risky",
+            DifficultyLabel::Deadly => "Deadly",
+        }
+    }
+}
+
+/// Rates the current zone by forecasting a full-HP player against a
+/// representative enemy of the current subzone (every stat at the middle of
+/// its variance range), so the label doesn't flicker with rolls or regen.
+pub fn difficulty_label(state: &GameState) -> DifficultyLabel {
+    let prog = &state.zone_progression;
+    let depth = get_zone(prog.current_zone_id)
+        .and_then(|zone| {
+            zone.subzones
+                .iter()
+                .find(|subzone| subzone.id == prog.current_subzone_id)
+        })
+        .map_or(1, |subzone| subzone.depth);
+    let range = enemy_stats_for(prog.current_zone_id, depth);
+    let enemy = Enemy::new_with_defense(
+        "Representative".to_string(),
+        (range.hp_min + range.hp_max) / 2,
+        (range.damage_min + range.damage_max) / 2,
+        (range.defense_min + range.defense_max) / 2,
+    );
+    let forecast = forecast_at_hp(state, &enemy, state.combat_state.player_max_hp);
+    DifficultyLabel::from_win_probability(forecast.win_probability)
+}
+
+/// Forecasts a fight between the player (at current HP) and `enemy`
+/// (at its current HP).
+///
+/// Crits are the only randomness modeled: the win probability is the chance
+/// of landing enough crits to kill the enemy before its hits kill the player.
+pub fn forecast(state: &GameState, enemy: &Enemy) -> CombatForecast {
+    forecast_at_hp(state, enemy, state.combat_state.player_current_hp)
+}
+
+/// `forecast` with the player starting the fight at `player_hp`.
+fn forecast_at_hp(state: &GameState, enemy: &Enemy, player_hp: u32) -> CombatForecast {
+    let derived = DerivedStats::calculate_derived_stats(&state.attributes, &state.equipment);
+    let prestige = PrestigeCombatBonuses::from_rank(state.prestige_rank);
+
+    // Player hit, as in update_combat minus the Haven Armory bonus
+    let streak_percent = prestige_streak_damage_percent(state.prestige_streak);
+    let pre_crit_damage = (derived.total_damage() as f64 * (1.0 + streak_percent / 100.0)) as u32
+        + prestige.flat_damage;
+    let hit = damage_after_defense(pre_crit_damage, enemy.defense);
+    let crit_hit = (hit as f64 * derived.crit_multiplier) as u32;
+    let crit_chance =
+        ((derived.crit_chance_percent + prestige.crit_chance as u32).min(100)) as f64 / 100.0;
+
+    let enemy_hit = damage_after_defense(enemy.damage, derived.defense + prestige.flat_defense);
+    let land_chance = (1.0 - derived.parry_chance_percent / 100.0).max(0.01);
+
+    let player_interval = ATTACK_INTERVAL_SECONDS / derived.attack_speed_multiplier;
+    let enemy_interval = effective_enemy_attack_interval(state);
+    let enemy_hp = enemy.current_hp.max(1);
+    let player_hp = player_hp.max(1);
+
+    let average_hit = hit as f64 * (1.0 - crit_chance) + crit_hit as f64 * crit_chance;
+    let hits_to_kill = (enemy_hp as f64 / average_hit).ceil() as u32;
+
+    // The player swings first on a shared tick, so the enemy only gets the
+    // attacks that land strictly before the killing blow
+    let time_to_kill = hits_to_kill as f64 * player_interval;
+    let enemy_attacks = ((time_to_kill / enemy_interval).ceil() as u32).saturating_sub(1);
+    let damage_taken = (enemy_attacks as f64 * enemy_hit as f64 * land_chance).round() as u32;
+
+    // Attacks the player gets in before the enemy lands enough hits to kill
+    let enemy_attacks_to_kill = (player_hp.div_ceil(enemy_hit) as f64 / land_chance).ceil();
+    let time_to_die = enemy_attacks_to_kill * enemy_interval;
+    let player_attacks = (time_to_die / player_interval + 1e-9).floor() as u64;
+
+    CombatForecast {
+        hits_to_kill,
+        damage_taken,
+        win_probability: kill_probability(player_attacks, hit, crit_hit, crit_chance, enemy_hp),
+    }
+}
+
+/// Whether fighting `enemy` is farming already-beaten content: a regular
+/// overworld mob (no boss, hunt elite or dungeon) that the forecast says
+/// dies to one swing, with near-certainty, without taking any HP. `game_tick`
+/// resolves these kills in batches (see `fast_resolve_combat`).
+pub fn is_trivial_fight(state: &GameState, enemy: &Enemy) -> bool {
+    if state.active_dungeon.is_some()
+        || state.zone_progression.fighting_boss
+        || enemy.is_hunt_elite()
+    {
+        return false;
+    }
+    let forecast = forecast(state, enemy);
+    forecast.win_probability >= TRIVIAL_WIN_PROBABILITY
+        && forecast.hits_to_kill == 1
+        && forecast.damage_taken == 0
+}
+
+/// Chance that `attacks` swings, each a crit with `crit_chance`, deal at least `hp`.
+fn kill_probability(attacks: u64, hit: u32, crit_hit: u32, crit_chance: f64, hp: u32) -> f64 {
+    let base_damage = attacks * hit as u64;
+    if base_damage >= hp as u64 {
+        return 1.0;
+    }
+    if crit_hit <= hit || crit_chance <= 0.0 {
+        return 0.0;
+    }
+    let crits_needed = (hp as u64 - base_damage).div_ceil((crit_hit - hit) as u64);
+    if crits_needed > attacks {
+        return 0.0;
+    }
+    if crit_chance >= 1.0 {
+        return 1.0;
+    }
+    binomial_tail(attacks, crits_needed, crit_chance)
+}
+
+/// P(X >= k) for X ~ Binomial(n, p), summed in log space so long fights
+/// don't underflow.
+fn binomial_tail(n: u64, k: u64, p: f64) -> f64 {
+    let log_odds = (p / (1.0 - p)).ln();
+    let mut log_pmf = n as f64 * (1.0 - p).ln();
+    let mut tail = 0.0;
+    for i in 0..=n {
+        if i >= k {
+            tail += log_pmf.exp();
+        }
+        log_pmf += ((n - i) as f64 / (i + 1) as f64).ln() + log_odds;
+    }
+    tail.min(1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::character::attributes::AttributeType;
+
+    #[test]
+    fn test_over_geared_player_forecasts_certain_win() {
+        let mut state = GameState::new("Tank".to_string(), 0);
+        for attr in AttributeType::all() {
+            state.attributes.set(attr, 60);
+        }
+        let derived = DerivedStats::calculate_derived_stats(&state.attributes, &state.equipment);
+        state.combat_state.player_max_hp = derived.max_hp;
+        state.combat_state.player_current_hp = derived.max_hp;
+        let boss = Enemy::new_with_defense("Meadow Queen".to_string(), 150, 8, 2);
+
+        let forecast = forecast(&state, &boss);
+
+        assert!(forecast.win_probability > 0.999);
+        assert!(!forecast.is_risky());
+        assert!(forecast.hits_to_kill <= 3);
+        assert!(forecast.damage_taken < state.combat_state.player_max_hp);
+    }
+
+    #[test]
+    fn test_under_geared_player_forecasts_low_win_chance() {
+        let state = GameState::new("Novice".to_string(), 0);
+        let boss = Enemy::new_with_defense("Storm Titan".to_string(), 20_000, 400, 20);
+
+        let forecast = forecast(&state, &boss);
+
+        assert!(forecast.win_probability < 0.01);
+        assert!(forecast.is_risky());
+        assert!(forecast.damage_taken > state.combat_state.player_max_hp);
+    }
+
+    #[test]
+    fn test_trivial_fight_only_for_regular_overworld_mobs() {
+        let state = GameState::new("Farmer".to_string(), 0);
+        let weak = Enemy::new_with_defense("Field Mouse".to_string(), 1, 1, 0);
+        assert!(is_trivial_fight(&state, &weak));
+
+        let mut boss_state = state.clone();
+        boss_state.zone_progression.fighting_boss = true;
+        assert!(!is_trivial_fight(&boss_state, &weak));
+
+        let strong = Enemy::new_with_defense("Storm Titan".to_string(), 20_000, 400, 20);
+        assert!(!is_trivial_fight(&state, &strong));
+    }
+
+    #[test]
+    fn test_difficulty_label_boundaries() {
+        use DifficultyLabel::*;
+        let cases = [
+            (1.0, Trivial),
+            (TRIVIAL_WIN_PROBABILITY, Trivial),
+            (0.998, Fair),
+            (RISKY_WIN_PROBABILITY, Fair),
+            (0.749, Risky),
+            (DEADLY_WIN_PROBABILITY, Risky),
+            (0.249, Deadly),
+            (0.0, Deadly),
+        ];
+        for (win_probability, expected) in cases {
+            assert_eq!(
+                DifficultyLabel::from_win_probability(win_probability),
+                expected,
+                "win probability {}",
+                win_probability
+            );
+        }
+    }
+
+    #[test]
+    fn test_difficulty_label_tracks_the_current_zone() {
+        let mut state = GameState::new("Scout".to_string(), 0);
+        assert_eq!(difficulty_label(&state), DifficultyLabel::Trivial);
+
+        state.zone_progression.current_zone_id = 10;
+        state.zone_progression.current_subzone_id = 4;
+        assert_eq!(difficulty_label(&state), DifficultyLabel::Deadly);
+    }
+
+    #[test]
+    fn test_binomial_tail_matches_exact_values() {
+        // 2 heads in 2 fair flips: 1/4; at least 1 head: 3/4
+        assert!((binomial_tail(2, 2, 0.5) - 0.25).abs() < 1e-9);
+        assert!((binomial_tail(2, 1, 0.5) - 0.75).abs() < 1e-9);
+        assert!((binomial_tail(10, 0, 0.3) - 1.0).abs() < 1e-9);
+        // Long fights don't underflow to zero
+        assert!(binomial_tail(5_000, 400, 0.1) > 0.0);
+    }
+
+    #[test]
+    fn test_fight_decided_by_crits_is_uncertain() {
+        // 4 swings of 10 (20 on a crit) against 50 HP: needs at least one crit
+        let p = kill_probability(4, 10, 20, 0.25, 50);
+        assert!((p - (1.0 - 0.75f64.powi(4))).abs() < 1e-9);
+        assert_eq!(kill_probability(5, 10, 20, 0.25, 50), 1.0);
+        assert_eq!(kill_probability(4, 10, 10, 0.25, 50), 0.0);
+    }
+}
@@ -1,10 +1,12 @@
+use super::types::{BossPhase, CombatBuff, FastResolve};
 use crate::character::derived_stats::DerivedStats;
-use crate::character::prestige::PrestigeCombatBonuses;
+use crate::character::prestige::{prestige_streak_damage_percent, PrestigeCombatBonuses};
 use crate::core::constants::*;
 use crate::core::game_state::GameState;
 use crate::dungeon::types::RoomType;
+use crate::items::types::AffixType;
 use crate::zones::get_all_zones;
-use rand::RngExt;
+use rand::{Rng, RngExt};
 
 use crate::zones::BossDefeatResult;
 
@@ -23,6 +25,8 @@ pub struct HavenCombatBonuses {
     pub double_strike_chance: f64,
     /// Training Yard: +% XP from kills
     pub xp_gain_percent: f64,
+    /// Alchemy Lab: % chance to emergency heal once per enemy when HP drops low
+    pub emergency_heal_chance: f64,
 }
 
 pub enum CombatEvent {
@@ -30,6 +34,16 @@ pub enum CombatEvent {
         damage: u32,
         was_crit: bool,
     },
+    /// How a player attack's damage was reached, emitted before its
+    /// `PlayerAttack` events when `GameState::verbose_combat` is on
+    DamageBreakdown {
+        /// Damage after all multipliers, before enemy defense
+        base: u32,
+        defense: u32,
+        /// Crit multiplier applied after defense, if the attack crit
+        crit_multiplier: Option<f64>,
+        damage: u32,
+    },
     /// Player's attack was blocked because boss requires a weapon
     PlayerAttackBlocked {
         weapon_needed: String,
@@ -37,6 +51,14 @@ pub enum CombatEvent {
     EnemyAttack {
         damage: u32,
     },
+    /// Player parried an enemy attack, taking no damage and countering
+    Parry {
+        counter_damage: u32,
+    },
+    /// Alchemy Lab emergency heal triggered after the player dropped to low HP
+    EmergencyHeal {
+        amount: u32,
+    },
     PlayerDied,
     /// Player died while in a dungeon (no prestige loss)
     PlayerDiedInDungeon,
@@ -56,11 +78,35 @@ pub enum CombatEvent {
         xp_gained: u64,
         result: BossDefeatResult,
     },
+    /// Storm Citadel final boss crossed an HP threshold into a new phase
+    BossPhaseChange {
+        phase: BossPhase,
+    },
+    /// A kill streak without taking damage hit a multiple of `COMBO_MILESTONE_KILLS`
+    ComboMilestone {
+        combo: u32,
+    },
+    /// A segment of a multi-segment enemy was destroyed; it still has `remaining`
+    SegmentDestroyed {
+        segment: String,
+        remaining: usize,
+    },
 }
 
 /// Calculates the effective enemy attack interval for the current encounter.
 /// Uses fixed constants per enemy tier (game design doc values).
 pub fn effective_enemy_attack_interval(state: &GameState) -> f64 {
+    let phase_multiplier = state
+        .combat_state
+        .current_enemy
+        .as_ref()
+        .and_then(|e| e.boss_phase)
+        .map_or(1.0, |phase| phase.attack_interval_multiplier());
+    base_enemy_attack_interval(state) * phase_multiplier
+}
+
+/// Attack interval for the encounter tier, before boss phase adjustments.
+fn base_enemy_attack_interval(state: &GameState) -> f64 {
     // Check dungeon room type first
     if let Some(dungeon) = &state.active_dungeon {
         if let Some(room) = dungeon.current_room() {
@@ -92,6 +138,187 @@ pub fn effective_enemy_attack_interval(state: &GameState) -> f64 {
     ENEMY_ATTACK_INTERVAL_SECONDS
 }
 
+/// Whether the current enemy is a boss: a dungeon boss room or an overworld
+/// subzone boss. Elites don't count.
+fn is_boss_fight(state: &GameState) -> bool {
+    match state.active_dungeon.as_ref().and_then(|d| d.current_room()) {
+        Some(room) => room.room_type == RoomType::Boss,
+        None => state.active_dungeon.is_none() && state.zone_progression.fighting_boss,
+    }
+}
+
+/// Damage multiplier for a boss that has been fought for `boss_fight_ticks`.
+/// 1.0 until `BOSS_ENRAGE_THRESHOLD_TICKS`, then grows by
+/// `BOSS_ENRAGE_DAMAGE_PER_TICK` each tick so a stalled fight always ends.
+pub fn boss_enrage_multiplier(boss_fight_ticks: u32) -> f64 {
+    let enraged_ticks = boss_fight_ticks.saturating_sub(BOSS_ENRAGE_THRESHOLD_TICKS);
+    1.0 + enraged_ticks as f64 * BOSS_ENRAGE_DAMAGE_PER_TICK
+}
+
+/// Triggers on-kill affixes from equipped items after a player attack kills an enemy.
+///
+/// `OnKillHeal` restores that percent of max HP; `OnKillRage` grants that percent
+/// attack speed for `ON_KILL_RAGE_DURATION_SECONDS`. Values from multiple items add up.
+fn apply_on_kill_effects(state: &mut GameState) {
+    let mut heal_percent = 0.0;
+    let mut rage_percent = 0.0;
+    for item in state.equipment.iter_equipped() {
+        for affix in &item.affixes {
+            match affix.affix_type {
+                AffixType::OnKillHeal => heal_percent += affix.value,
+                AffixType::OnKillRage => rage_percent += affix.value,
+                _ => {}
+            }
+        }
+    }
+
+    let combat = &mut state.combat_state;
+    if heal_percent > 0.0 {
+        let heal = (combat.player_max_hp as f64 * heal_percent / 100.0) as u32;
+        combat.player_current_hp = (combat.player_current_hp + heal).min(combat.player_max_hp);
+    }
+    if rage_percent > 0.0 {
+        combat.push_buff(CombatBuff {
+            source: AffixType::OnKillRage,
+            attack_speed_percent: rage_percent,
+            remaining_seconds: ON_KILL_RAGE_DURATION_SECONDS,
+        });
+    }
+}
+
+/// On-kill buffs, the post-death grace period and the combo bonus run down
+/// whether or not a fight is in progress.
+fn tick_down_timers(state: &mut GameState, delta_time: f64) {
+    state.combat_state.decay_buffs(delta_time);
+    state.combat_state.invuln_timer = (state.combat_state.invuln_timer - delta_time).max(0.0);
+    state.combat_state.combo_bonus_timer =
+        (state.combat_state.combo_bonus_timer - delta_time).max(0.0);
+}
+
+/// Seconds one trivial kill takes in regular combat: the single swing that
+/// kills, plus the HP regen pause before the next enemy spawns.
+pub fn trivial_kill_seconds(
+    state: &GameState,
+    haven: &HavenCombatBonuses,
+    derived: &DerivedStats,
+) -> f64 {
+    let total_regen_multiplier =
+        derived.hp_regen_multiplier * (1.0 + haven.hp_regen_percent / 100.0);
+    let regen_duration = HP_REGEN_DURATION_SECONDS * (1.0 - haven.hp_regen_delay_reduction / 100.0)
+        / total_regen_multiplier;
+    state
+        .combat_state
+        .player_attack_interval(derived.attack_speed_multiplier)
+        + regen_duration
+}
+
+/// Resolves up to `kills` trivial overworld kills at once, with the same
+/// per-kill XP roll, zone progress, achievements, on-kill effects and combo as
+/// a regular kill. Stops early once the subzone boss is due so it is fought
+/// normally.
+pub fn resolve_trivial_kills<R: Rng>(
+    state: &mut GameState,
+    kills: u32,
+    haven: &HavenCombatBonuses,
+    achievements: &mut crate::achievements::Achievements,
+    rng: &mut R,
+) -> Vec<CombatEvent> {
+    let mut events = Vec::new();
+    let wis_mod = state
+        .attributes
+        .modifier(crate::character::attributes::AttributeType::Wisdom);
+    let cha_mod = state
+        .attributes
+        .modifier(crate::character::attributes::AttributeType::Charisma);
+    let xp_per_tick =
+        crate::core::game_logic::xp_gain_per_tick(state.prestige_rank, wis_mod, cha_mod);
+
+    for _ in 0..kills {
+        let xp_gained = crate::core::game_logic::combat_kill_xp_with_rng(
+            xp_per_tick,
+            haven.xp_gain_percent,
+            rng,
+        );
+        state.zone_progression.record_kill();
+        events.push(CombatEvent::EnemyDied { xp_gained });
+        achievements.on_enemy_killed(false, Some(&state.character_name));
+        apply_on_kill_effects(state);
+        if state.combat_state.record_combo_kill() {
+            events.push(CombatEvent::ComboMilestone {
+                combo: state.combat_state.combo,
+            });
+        }
+        if state.zone_progression.fighting_boss {
+            break;
+        }
+    }
+    events
+}
+
+/// `game_tick`'s fast path for farming beaten content. While
+/// `CombatState::fast_resolve` holds a kill time for the current enemy, no
+/// swings are simulated: time is banked, and every `FAST_RESOLVE_BATCH_SECONDS`
+/// this returns how many kills fit in it (0 while still banking). `game_tick`
+/// resolves them one at a time with `resolve_trivial_kills`, so a discovery
+/// can cut the batch short, then calls `end_fast_resolve_batch`. Returns `None`
+/// (and drops the fast path) when the fight needs regular combat.
+pub fn fast_resolve_combat(state: &mut GameState, delta_time: f64) -> Option<u32> {
+    let kill_seconds = state.combat_state.fast_resolve.kill_seconds?;
+    let regular_mob = state
+        .combat_state
+        .current_enemy
+        .as_ref()
+        .is_some_and(|enemy| !enemy.is_hunt_elite());
+    if !regular_mob
+        || state.combat_state.is_regenerating
+        || state.active_dungeon.is_some()
+        || state.zone_progression.fighting_boss
+    {
+        state.combat_state.fast_resolve = FastResolve::default();
+        return None;
+    }
+
+    tick_down_timers(state, delta_time);
+    let fast = &mut state.combat_state.fast_resolve;
+    fast.elapsed += delta_time;
+    if fast.elapsed < FAST_RESOLVE_BATCH_SECONDS {
+        return Some(0);
+    }
+    let kills = (fast.elapsed / kill_seconds) as u32;
+    fast.elapsed -= kills as f64 * kill_seconds;
+    Some(kills)
+}
+
+/// Whether a fast-resolved batch has to stop before its next kill: the
+/// subzone boss is due, or a discovery took the player out of the overworld.
+pub fn fast_resolve_interrupted(state: &GameState) -> bool {
+    state.zone_progression.fighting_boss
+        || state.active_dungeon.is_some()
+        || state.active_fishing.is_some()
+}
+
+/// Clears the stand-in enemy after a fast-resolved batch, so `game_tick`
+/// rolls a fresh one (which may be a hunt elite, or no longer trivial). When
+/// the batch was interrupted, the fast path ends and the usual regen pause
+/// runs before the boss or the next fight.
+pub fn end_fast_resolve_batch(state: &mut GameState) {
+    let interrupted = fast_resolve_interrupted(state);
+    let combat = &mut state.combat_state;
+    combat.current_enemy = None;
+    combat.enemy_attack_timer = 0.0;
+    if interrupted {
+        combat.is_regenerating = true;
+        combat.regen_timer = 0.0;
+        combat.fast_resolve = FastResolve::default();
+    }
+}
+
+/// Damage remaining after subtracting defense, with a floor of 1.
+/// Used for both player hits on enemies and enemy hits on the player.
+pub fn damage_after_defense(damage: u32, defense: u32) -> u32 {
+    damage.saturating_sub(defense).max(1)
+}
+
 /// Updates combat state, returns events that occurred
 /// `haven` contains all Haven bonuses that affect combat
 /// `prestige_bonuses` contains flat combat bonuses from prestige rank
@@ -104,9 +331,33 @@ pub fn update_combat(
     prestige_bonuses: &PrestigeCombatBonuses,
     achievements: &mut crate::achievements::Achievements,
     derived: &DerivedStats,
+) -> Vec<CombatEvent> {
+    update_combat_with_rng(
+        state,
+        delta_time,
+        haven,
+        prestige_bonuses,
+        achievements,
+        derived,
+        &mut rand::rng(),
+    )
+}
+
+/// Same as `update_combat`, but crit, double-strike, emergency-heal and kill XP
+/// rolls come from the given RNG so a seeded run is reproducible.
+pub fn update_combat_with_rng<R: Rng>(
+    state: &mut GameState,
+    delta_time: f64,
+    haven: &HavenCombatBonuses,
+    prestige_bonuses: &PrestigeCombatBonuses,
+    achievements: &mut crate::achievements::Achievements,
+    derived: &DerivedStats,
+    rng: &mut R,
 ) -> Vec<CombatEvent> {
     let mut events = Vec::new();
 
+    tick_down_timers(state, delta_time);
+
     // Handle regeneration after enemy death
     if state.combat_state.is_regenerating {
         // HP regen multiplier: higher = faster regen (equipment + haven bonus)
@@ -143,9 +394,15 @@ pub fn update_combat(
     // --- Phase 1: Accumulate both timers ---
     state.combat_state.player_attack_timer += delta_time;
     state.combat_state.enemy_attack_timer += delta_time;
+    if is_boss_fight(state) {
+        state.combat_state.boss_fight_ticks += 1;
+    }
+    let enrage_multiplier = boss_enrage_multiplier(state.combat_state.boss_fight_ticks);
 
-    // Attack speed multiplier: higher = faster attacks
-    let player_interval = ATTACK_INTERVAL_SECONDS / derived.attack_speed_multiplier;
+    // Attack speed multiplier: higher = faster attacks (on-kill rage stacks on top)
+    let player_interval = state
+        .combat_state
+        .player_attack_interval(derived.attack_speed_multiplier);
     let enemy_interval = effective_enemy_attack_interval(state);
 
     // --- Phase 2: Determine who attacks this tick ---
@@ -166,31 +423,49 @@ pub fn update_combat(
             // Player attacks normally
             // 1. Base damage from DerivedStats (STR/INT + equipment)
             let base_damage = derived.total_damage();
-            // 2. Apply Haven Armory multiplier: +% damage
-            let haven_damage = (base_damage as f64 * (1.0 + haven.damage_percent / 100.0)) as u32;
+            // 2. Apply Haven Armory and prestige streak multipliers: +% damage
+            let streak_percent = prestige_streak_damage_percent(state.prestige_streak);
+            let haven_damage = (base_damage as f64
+                * (1.0 + haven.damage_percent / 100.0)
+                * (1.0 + streak_percent / 100.0)) as u32;
             // 3. Apply prestige flat damage (added after Haven %, before crit)
-            let pre_crit_damage = haven_damage + prestige_bonuses.flat_damage;
-            // 4. Apply enemy defense: min damage floor of 1
+            // 4. Apply combat stance and combo bonus multipliers
+            let pre_crit_damage = ((haven_damage + prestige_bonuses.flat_damage) as f64
+                * state.combat_stance.damage_multiplier()
+                * state.combat_state.combo_damage_multiplier())
+                as u32;
+            // 5. Apply enemy defense: min damage floor of 1
             let enemy_def = state
                 .combat_state
                 .current_enemy
                 .as_ref()
                 .map_or(0, |e| e.defense);
-            let mut damage = pre_crit_damage.saturating_sub(enemy_def).max(1);
+            let mut damage = damage_after_defense(pre_crit_damage, enemy_def);
             let mut was_crit = false;
+            let mut crit_multiplier = None;
 
             // Roll for crit (base + Haven Watchtower + prestige crit)
             let total_crit_chance = derived.crit_chance_percent
                 + haven.crit_chance_percent as u32
                 + prestige_bonuses.crit_chance as u32;
-            let crit_roll = rand::rng().random_range(0..100);
+            let crit_roll = rng.random_range(0..100);
             if crit_roll < total_crit_chance {
                 damage = (damage as f64 * derived.crit_multiplier) as u32;
                 was_crit = true;
+                crit_multiplier = Some(derived.crit_multiplier);
+            }
+
+            if state.verbose_combat {
+                events.push(CombatEvent::DamageBreakdown {
+                    base: pre_crit_damage,
+                    defense: enemy_def,
+                    crit_multiplier,
+                    damage,
+                });
             }
 
             // Roll for double strike (War Room bonus)
-            let double_strike_roll = rand::rng().random::<f64>() * 100.0;
+            let double_strike_roll = rng.random::<f64>() * 100.0;
             let num_strikes = if double_strike_roll < haven.double_strike_chance {
                 2
             } else {
@@ -198,6 +473,9 @@ pub fn update_combat(
             };
 
             if let Some(enemy) = state.combat_state.current_enemy.as_mut() {
+                let segment = enemy.current_segment().map(|s| s.name.clone());
+                let segments_before = enemy.living_segments();
+
                 // Apply damage (potentially multiple times with double strike)
                 for strike in 0..num_strikes {
                     if !enemy.is_alive() {
@@ -211,6 +489,15 @@ pub fn update_combat(
                         was_crit: strike_crit,
                     });
                 }
+                if let Some(phase) = enemy.advance_boss_phase() {
+                    events.push(CombatEvent::BossPhaseChange { phase });
+                }
+                let remaining = enemy.living_segments();
+                if let Some(segment) = segment.filter(|_| remaining < segments_before) {
+                    if remaining > 0 {
+                        events.push(CombatEvent::SegmentDestroyed { segment, remaining });
+                    }
+                }
 
                 // Check if enemy died
                 if !enemy.is_alive() {
@@ -220,13 +507,14 @@ pub fn update_combat(
                     let cha_mod = state
                         .attributes
                         .modifier(crate::character::attributes::AttributeType::Charisma);
-                    let xp_gained = crate::core::game_logic::combat_kill_xp(
+                    let xp_gained = crate::core::game_logic::combat_kill_xp_with_rng(
                         crate::core::game_logic::xp_gain_per_tick(
                             state.prestige_rank,
                             wis_mod,
                             cha_mod,
                         ),
                         haven.xp_gain_percent,
+                        rng,
                     );
 
                     // Check if we're in a dungeon and what type of room
@@ -256,9 +544,17 @@ pub fn update_combat(
                                 events.push(CombatEvent::EnemyDied { xp_gained });
                             } else if state.zone_progression.fighting_boss {
                                 // Overworld boss defeated
-                                let result = state
-                                    .zone_progression
-                                    .on_boss_defeated(state.prestige_rank, achievements);
+                                state.boss_ledger.record_kill(
+                                    state.zone_progression.current_zone_id,
+                                    state.zone_progression.current_subzone_id,
+                                    state.combat_state.boss_fight_ticks,
+                                );
+                                let gear_score = crate::items::gear_score(&state.equipment);
+                                let result = state.zone_progression.on_boss_defeated(
+                                    state.prestige_rank,
+                                    gear_score,
+                                    achievements,
+                                );
                                 events.push(CombatEvent::SubzoneBossDefeated { xp_gained, result });
                             } else {
                                 // Record the kill for boss spawn tracking (boss flag set if threshold reached)
@@ -271,6 +567,13 @@ pub fn update_combat(
                     // Track kill for achievements
                     achievements.on_enemy_killed(is_boss_kill, Some(&state.character_name));
 
+                    apply_on_kill_effects(state);
+                    if state.combat_state.record_combo_kill() {
+                        events.push(CombatEvent::ComboMilestone {
+                            combo: state.combat_state.combo,
+                        });
+                    }
+
                     // Remove enemy and start regeneration
                     state.combat_state.current_enemy = None;
                     state.combat_state.enemy_attack_timer = 0.0;
@@ -288,27 +591,77 @@ pub fn update_combat(
         state.combat_state.enemy_attack_timer = 0.0;
 
         if let Some(enemy) = state.combat_state.current_enemy.as_mut() {
-            let total_defense = derived.defense + prestige_bonuses.flat_defense;
-            let enemy_damage = enemy.damage.saturating_sub(total_defense).max(1);
-            state.combat_state.player_current_hp = state
-                .combat_state
-                .player_current_hp
-                .saturating_sub(enemy_damage);
+            // Parry (DEX): negate the hit and counterattack. Only rolled when
+            // the build has a chance, so low-DEX combat keeps its RNG stream.
+            let parried = derived.parry_chance_percent > 0.0
+                && rng.random::<f64>() * 100.0 < derived.parry_chance_percent;
+
+            if parried {
+                let counter_damage = damage_after_defense(
+                    (derived.total_damage() as f64 * PARRY_COUNTER_DAMAGE_MULTIPLIER) as u32,
+                    enemy.defense,
+                );
+                enemy.take_damage(counter_damage);
+                events.push(CombatEvent::Parry { counter_damage });
+            } else {
+                let total_defense = ((derived.defense + prestige_bonuses.flat_defense) as f64
+                    * state.combat_stance.defense_multiplier())
+                    as u32;
+                let enraged_damage = (enemy.damage as f64 * enrage_multiplier) as u32;
+                // Hits land but deal nothing during the post-death grace period
+                let enemy_damage = if state.combat_state.invuln_timer > 0.0 {
+                    0
+                } else {
+                    damage_after_defense(enraged_damage, total_defense)
+                };
+                state.combat_state.player_current_hp = state
+                    .combat_state
+                    .player_current_hp
+                    .saturating_sub(enemy_damage);
+                // Any damage taken ends the kill combo (field access: `enemy` is borrowed)
+                if enemy_damage > 0 {
+                    state.combat_state.combo = 0;
+                }
 
-            events.push(CombatEvent::EnemyAttack {
-                damage: enemy_damage,
-            });
+                events.push(CombatEvent::EnemyAttack {
+                    damage: enemy_damage,
+                });
+
+                // Emergency heal: one roll per enemy, the first time HP drops below the threshold
+                let hp_threshold =
+                    (state.combat_state.player_max_hp as f64 * EMERGENCY_HEAL_HP_THRESHOLD) as u32;
+                if haven.emergency_heal_chance > 0.0
+                    && !state.combat_state.panic_heal_used
+                    && state.combat_state.player_current_hp > 0
+                    && state.combat_state.player_current_hp < hp_threshold
+                {
+                    state.combat_state.panic_heal_used = true;
+                    let roll = rng.random::<f64>() * 100.0;
+                    if roll < haven.emergency_heal_chance {
+                        let max_hp = state.combat_state.player_max_hp;
+                        let heal = (max_hp as f64 * EMERGENCY_HEAL_RESTORE_FRACTION) as u32;
+                        let new_hp = (state.combat_state.player_current_hp + heal).min(max_hp);
+                        let amount = new_hp - state.combat_state.player_current_hp;
+                        state.combat_state.player_current_hp = new_hp;
+                        events.push(CombatEvent::EmergencyHeal { amount });
+                    }
+                }
 
-            // Damage reflection: reflect percentage of damage taken back to attacker
-            if derived.damage_reflection_percent > 0.0 && enemy_damage > 0 {
-                let reflected =
-                    (enemy_damage as f64 * derived.damage_reflection_percent / 100.0) as u32;
-                if reflected > 0 {
-                    enemy.take_damage(reflected);
+                // Damage reflection: reflect percentage of damage taken back to attacker
+                if derived.damage_reflection_percent > 0.0 && enemy_damage > 0 {
+                    let reflected =
+                        (enemy_damage as f64 * derived.damage_reflection_percent / 100.0) as u32;
+                    if reflected > 0 {
+                        enemy.take_damage(reflected);
+                    }
                 }
             }
 
-            // Check if reflection killed the enemy
+            if let Some(phase) = enemy.advance_boss_phase() {
+                events.push(CombatEvent::BossPhaseChange { phase });
+            }
+
+            // Check if the counterattack or reflection killed the enemy
             if !enemy.is_alive() {
                 let wis_mod = state
                     .attributes
@@ -316,13 +669,14 @@ pub fn update_combat(
                 let cha_mod = state
                     .attributes
                     .modifier(crate::character::attributes::AttributeType::Charisma);
-                let xp_gained = crate::core::game_logic::combat_kill_xp(
+                let xp_gained = crate::core::game_logic::combat_kill_xp_with_rng(
                     crate::core::game_logic::xp_gain_per_tick(
                         state.prestige_rank,
                         wis_mod,
                         cha_mod,
                     ),
                     haven.xp_gain_percent,
+                    rng,
                 );
 
                 let dungeon_room_type = state
@@ -348,9 +702,17 @@ pub fn update_combat(
                         if state.active_dungeon.is_some() {
                             events.push(CombatEvent::EnemyDied { xp_gained });
                         } else if state.zone_progression.fighting_boss {
-                            let result = state
-                                .zone_progression
-                                .on_boss_defeated(state.prestige_rank, achievements);
+                            state.boss_ledger.record_kill(
+                                state.zone_progression.current_zone_id,
+                                state.zone_progression.current_subzone_id,
+                                state.combat_state.boss_fight_ticks,
+                            );
+                            let gear_score = crate::items::gear_score(&state.equipment);
+                            let result = state.zone_progression.on_boss_defeated(
+                                state.prestige_rank,
+                                gear_score,
+                                achievements,
+                            );
                             events.push(CombatEvent::SubzoneBossDefeated { xp_gained, result });
                         } else {
                             state.zone_progression.record_kill();
@@ -360,6 +722,15 @@ pub fn update_combat(
                 }
 
                 achievements.on_enemy_killed(is_boss_kill, Some(&state.character_name));
+                // A parry counter is a player attack; reflected damage isn't
+                if parried {
+                    apply_on_kill_effects(state);
+                }
+                if state.combat_state.record_combo_kill() {
+                    events.push(CombatEvent::ComboMilestone {
+                        combo: state.combat_state.combo,
+                    });
+                }
 
                 state.combat_state.current_enemy = None;
                 state.combat_state.is_regenerating = true;
@@ -370,6 +741,9 @@ pub fn update_combat(
 
             // Check if player died
             if !state.combat_state.is_player_alive() {
+                // Any death ends the prestige streak
+                state.prestige_streak = 0;
+
                 // Check if we're in a dungeon
                 let in_dungeon = state.active_dungeon.is_some();
 
@@ -389,16 +763,31 @@ pub fn update_combat(
                 state.combat_state.player_attack_timer = 0.0;
                 state.combat_state.enemy_attack_timer = 0.0;
 
+                // Short grace period so a fast enemy can't chain-kill on respawn
+                state.combat_state.invuln_timer = RESPAWN_INVULN_SECONDS;
+
                 // Reset enemy HP if we're not in dungeon (normal combat continues)
                 if !in_dungeon {
                     // Check if we died to a boss
                     if state.zone_progression.fighting_boss {
+                        state.boss_ledger.record_death(
+                            state.zone_progression.current_zone_id,
+                            state.zone_progression.current_subzone_id,
+                        );
                         // Reset boss encounter but preserve kill counter
                         // Boss respawns after KILLS_FOR_BOSS_RETRY kills (reduced penalty)
                         state.zone_progression.fighting_boss = false;
                         state.zone_progression.kills_in_subzone =
                             KILLS_FOR_BOSS.saturating_sub(KILLS_FOR_BOSS_RETRY);
                         state.combat_state.current_enemy = None;
+                    } else if state
+                        .combat_state
+                        .current_enemy
+                        .as_ref()
+                        .is_some_and(|enemy| enemy.is_hunt_elite())
+                    {
+                        // A hunt elite that wins gets away instead of walling the player
+                        state.combat_state.current_enemy = None;
                     } else if let Some(enemy) = state.combat_state.current_enemy.as_mut() {
                         enemy.reset_hp();
                     }
@@ -459,7 +848,9 @@ mod tests {
         haven: &HavenCombatBonuses,
         achievements: &mut Achievements,
     ) -> Vec<CombatEvent> {
-        let derived = default_derived(state);
+        let mut derived = default_derived(state);
+        // The forced enemy hit always lands
+        derived.parry_chance_percent = 0.0;
         state.combat_state.player_attack_timer = 0.0;
         state.combat_state.enemy_attack_timer = ENEMY_ATTACK_INTERVAL_SECONDS;
         update_combat(
@@ -478,7 +869,9 @@ mod tests {
         haven: &HavenCombatBonuses,
         achievements: &mut Achievements,
     ) -> Vec<CombatEvent> {
-        let derived = default_derived(state);
+        let mut derived = default_derived(state);
+        // The forced enemy hit always lands
+        derived.parry_chance_percent = 0.0;
         state.combat_state.player_attack_timer = ATTACK_INTERVAL_SECONDS;
         state.combat_state.enemy_attack_timer = ENEMY_ATTACK_INTERVAL_SECONDS;
         update_combat(
@@ -566,6 +959,25 @@ mod tests {
         assert!(!events.is_empty()); // Player attack (enemy not yet at 2.0s)
     }
 
+    #[test]
+    fn test_player_death_lets_hunt_elite_escape() {
+        let mut state = GameState::new("Test Hero".to_string(), 0);
+        let mut achievements = Achievements::default();
+        state.combat_state.player_current_hp = 1;
+        let mut elite = Enemy::new("Alpha Wolf".to_string(), 100, 50);
+        elite.hunt_expires_at = Some(1_000);
+        state.combat_state.current_enemy = Some(elite);
+
+        let events = force_both_attacks(
+            &mut state,
+            &HavenCombatBonuses::default(),
+            &mut achievements,
+        );
+
+        assert!(events.iter().any(|e| matches!(e, CombatEvent::PlayerDied)));
+        assert!(state.combat_state.current_enemy.is_none());
+    }
+
     #[test]
     fn test_player_died_resets() {
         let mut state = GameState::new("Test Hero".to_string(), 0);
@@ -595,6 +1007,105 @@ mod tests {
         assert_eq!(enemy.current_hp, enemy.max_hp);
     }
 
+    #[test]
+    fn test_consecutive_kills_build_combo() {
+        let mut state = GameState::new("Test Hero".to_string(), 0);
+        let mut achievements = Achievements::default();
+
+        for kill in 1..=COMBO_MILESTONE_KILLS {
+            state.combat_state.is_regenerating = false;
+            state.combat_state.current_enemy = Some(Enemy::new("Test".to_string(), 1, 10));
+            let events = force_player_attack(
+                &mut state,
+                &HavenCombatBonuses::default(),
+                &mut achievements,
+            );
+            assert_eq!(state.combat_state.combo, kill);
+            let milestone = events
+                .iter()
+                .any(|e| matches!(e, CombatEvent::ComboMilestone { .. }));
+            assert_eq!(milestone, kill == COMBO_MILESTONE_KILLS);
+        }
+        assert_eq!(
+            state.combat_state.combo_bonus_timer,
+            COMBO_BONUS_DURATION_SECONDS
+        );
+        assert!(state.combat_state.combo_damage_multiplier() > 1.0);
+    }
+
+    #[test]
+    fn test_taking_damage_resets_combo() {
+        let mut state = GameState::new("Test Hero".to_string(), 0);
+        let mut achievements = Achievements::default();
+        state.combat_state.combo = 7;
+        state.combat_state.current_enemy = Some(Enemy::new("Test".to_string(), 100, 10));
+
+        force_enemy_attack(
+            &mut state,
+            &HavenCombatBonuses::default(),
+            &mut achievements,
+        );
+        assert_eq!(state.combat_state.combo, 0);
+    }
+
+    #[test]
+    fn test_no_damage_during_respawn_grace_period() {
+        let mut state = GameState::new("Test Hero".to_string(), 0);
+        let mut achievements = Achievements::default();
+        state.combat_state.player_current_hp = 1;
+        state.combat_state.current_enemy = Some(Enemy::new("Test".to_string(), 100, 50));
+
+        let events = force_both_attacks(
+            &mut state,
+            &HavenCombatBonuses::default(),
+            &mut achievements,
+        );
+        assert!(events.iter().any(|e| matches!(e, CombatEvent::PlayerDied)));
+        assert_eq!(state.combat_state.invuln_timer, RESPAWN_INVULN_SECONDS);
+
+        // The next enemy hit lands but deals nothing
+        let max_hp = state.combat_state.player_max_hp;
+        let events = force_enemy_attack(
+            &mut state,
+            &HavenCombatBonuses::default(),
+            &mut achievements,
+        );
+        assert_has_event(&events, "EnemyAttack", |e| {
+            matches!(e, CombatEvent::EnemyAttack { damage: 0 })
+        });
+        assert_eq!(state.combat_state.player_current_hp, max_hp);
+    }
+
+    #[test]
+    fn test_damage_resumes_after_respawn_grace_period() {
+        let mut state = GameState::new("Test Hero".to_string(), 0);
+        let mut achievements = Achievements::default();
+        state.combat_state.current_enemy = Some(Enemy::new("Test".to_string(), 100, 10));
+        state.combat_state.invuln_timer = RESPAWN_INVULN_SECONDS;
+
+        // Let the grace period run out without either side attacking
+        let derived = default_derived(&state);
+        state.combat_state.player_attack_timer = -RESPAWN_INVULN_SECONDS;
+        state.combat_state.enemy_attack_timer = -RESPAWN_INVULN_SECONDS;
+        update_combat(
+            &mut state,
+            RESPAWN_INVULN_SECONDS,
+            &HavenCombatBonuses::default(),
+            &default_prestige(),
+            &mut achievements,
+            &derived,
+        );
+        assert_eq!(state.combat_state.invuln_timer, 0.0);
+
+        let max_hp = state.combat_state.player_max_hp;
+        force_enemy_attack(
+            &mut state,
+            &HavenCombatBonuses::default(),
+            &mut achievements,
+        );
+        assert!(state.combat_state.player_current_hp < max_hp);
+    }
+
     #[test]
     fn test_regeneration_after_kill() {
         let mut state = GameState::new("Test Hero".to_string(), 0);
@@ -707,6 +1218,155 @@ mod tests {
         assert_eq!(enemy.current_hp, enemy_hp);
     }
 
+    #[test]
+    fn test_segment_destroyed_event_and_kill_only_after_last_segment() {
+        let mut state = GameState::new("Test Hero".to_string(), 0);
+        let mut achievements = Achievements::default();
+        // Each head has 1 HP, so every hit destroys exactly one
+        state.combat_state.current_enemy = Some(
+            Enemy::new("Ember Hydra".to_string(), 3, 1)
+                .with_segments(&crate::combat::types::HYDRA_HEADS),
+        );
+
+        let events = force_player_attack(
+            &mut state,
+            &HavenCombatBonuses::default(),
+            &mut achievements,
+        );
+        assert_has_event(
+            &events,
+            "SegmentDestroyed",
+            |e| matches!(e, CombatEvent::SegmentDestroyed { segment, remaining: 2 } if segment == "Left Head"),
+        );
+        assert_no_event(&events, "EnemyDied", |e| {
+            matches!(e, CombatEvent::EnemyDied { .. })
+        });
+
+        force_player_attack(
+            &mut state,
+            &HavenCombatBonuses::default(),
+            &mut achievements,
+        );
+        assert!(state.combat_state.current_enemy.is_some());
+
+        let events = force_player_attack(
+            &mut state,
+            &HavenCombatBonuses::default(),
+            &mut achievements,
+        );
+        assert_has_event(&events, "EnemyDied", |e| {
+            matches!(e, CombatEvent::EnemyDied { .. })
+        });
+        assert_no_event(&events, "SegmentDestroyed", |e| {
+            matches!(e, CombatEvent::SegmentDestroyed { .. })
+        });
+    }
+
+    /// Zone 10 final boss fight with Stormbreaker forged, against a phased boss
+    /// at `current_hp` out of 100.
+    fn storm_boss_fight(current_hp: u32) -> (GameState, Achievements) {
+        let mut state = GameState::new("Test Hero".to_string(), 0);
+        let mut achievements = Achievements::default();
+        achievements.unlock(crate::achievements::AchievementId::TheStormbreaker, None);
+        state.zone_progression.current_zone_id = 10;
+        state.zone_progression.current_subzone_id = 4;
+        state.zone_progression.fighting_boss = true;
+
+        let mut boss = Enemy::new("Eternal Storm".to_string(), 100, 10);
+        boss.current_hp = current_hp;
+        boss.boss_phase = Some(BossPhase::Calm);
+        state.combat_state.current_enemy = Some(boss);
+        (state, achievements)
+    }
+
+    #[test]
+    fn test_storm_boss_enters_tempest_below_threshold_and_attacks_faster() {
+        let (mut state, mut achievements) = storm_boss_fight(70);
+        let calm_interval = effective_enemy_attack_interval(&state);
+
+        // Base damage is 10 (20 on a crit), either way crossing 66%
+        let events = force_player_attack(
+            &mut state,
+            &HavenCombatBonuses::default(),
+            &mut achievements,
+        );
+
+        assert_has_event(&events, "BossPhaseChange", |e| {
+            matches!(
+                e,
+                CombatEvent::BossPhaseChange {
+                    phase: BossPhase::Tempest
+                }
+            )
+        });
+        let boss = state.combat_state.current_enemy.as_ref().unwrap();
+        assert_eq!(boss.boss_phase, Some(BossPhase::Tempest));
+        assert_eq!(boss.shield_hp, 0);
+        assert!(
+            (effective_enemy_attack_interval(&state)
+                - calm_interval * BOSS_TEMPEST_ATTACK_INTERVAL_MULTIPLIER)
+                .abs()
+                < 1e-9
+        );
+    }
+
+    #[test]
+    fn test_storm_boss_shield_absorbs_damage() {
+        let (mut state, mut achievements) = storm_boss_fight(40);
+
+        let events = force_player_attack(
+            &mut state,
+            &HavenCombatBonuses::default(),
+            &mut achievements,
+        );
+        assert_has_event(&events, "BossPhaseChange", |e| {
+            matches!(
+                e,
+                CombatEvent::BossPhaseChange {
+                    phase: BossPhase::StormShield
+                }
+            )
+        });
+        let boss = state.combat_state.current_enemy.as_ref().unwrap();
+        assert_eq!(boss.shield_hp, 20);
+        let hp_behind_shield = boss.current_hp;
+
+        // The next hit (10, or 20 on a crit) lands on the shield
+        let events = force_player_attack(
+            &mut state,
+            &HavenCombatBonuses::default(),
+            &mut achievements,
+        );
+        assert_no_event(&events, "BossPhaseChange", |e| {
+            matches!(e, CombatEvent::BossPhaseChange { .. })
+        });
+        let boss = state.combat_state.current_enemy.as_ref().unwrap();
+        assert_eq!(boss.current_hp, hp_behind_shield);
+        assert!(boss.shield_hp < 20);
+    }
+
+    #[test]
+    fn test_unphased_boss_never_changes_phase() {
+        let (mut state, mut achievements) = storm_boss_fight(40);
+        state
+            .combat_state
+            .current_enemy
+            .as_mut()
+            .unwrap()
+            .boss_phase = None;
+
+        let events = force_player_attack(
+            &mut state,
+            &HavenCombatBonuses::default(),
+            &mut achievements,
+        );
+
+        assert_no_event(&events, "BossPhaseChange", |e| {
+            matches!(e, CombatEvent::BossPhaseChange { .. })
+        });
+        assert_eq!(state.combat_state.current_enemy.unwrap().shield_hp, 0);
+    }
+
     #[test]
     fn test_weapon_blocked_boss_still_attacks_back() {
         let mut state = GameState::new("Test Hero".to_string(), 0);
@@ -829,6 +1489,82 @@ mod tests {
         assert_eq!(state.combat_state.player_current_hp, initial_hp - 1);
     }
 
+    /// Damage of one non-crit player hit in the given stance.
+    fn stance_hit_damage(stance: super::super::types::CombatStance) -> u32 {
+        let mut state = GameState::new("Test Hero".to_string(), 0);
+        let mut achievements = Achievements::default();
+        state.combat_stance = stance;
+        state
+            .attributes
+            .set(crate::character::attributes::AttributeType::Strength, 30);
+        state.combat_state.current_enemy = Some(Enemy::new("Dummy".to_string(), 100000, 0));
+        state.combat_state.player_attack_timer = ATTACK_INTERVAL_SECONDS;
+
+        let mut derived = default_derived(&state);
+        derived.crit_chance_percent = 0;
+        let events = update_combat(
+            &mut state,
+            0.1,
+            &HavenCombatBonuses::default(),
+            &default_prestige(),
+            &mut achievements,
+            &derived,
+        );
+        events
+            .iter()
+            .find_map(|e| match e {
+                CombatEvent::PlayerAttack { damage, .. } => Some(*damage),
+                _ => None,
+            })
+            .expect("Expected PlayerAttack event")
+    }
+
+    /// HP lost to one enemy hit in the given stance, with 10 base defense.
+    fn stance_damage_taken(stance: super::super::types::CombatStance) -> u32 {
+        let mut state = GameState::new("Test Hero".to_string(), 0);
+        let mut achievements = Achievements::default();
+        state.combat_stance = stance;
+        state
+            .attributes
+            .set(crate::character::attributes::AttributeType::Dexterity, 30); // 10 defense
+        state.combat_state.current_enemy = Some(Enemy::new("Test".to_string(), 100, 30));
+
+        let initial_hp = state.combat_state.player_current_hp;
+        force_enemy_attack(
+            &mut state,
+            &HavenCombatBonuses::default(),
+            &mut achievements,
+        );
+        initial_hp - state.combat_state.player_current_hp
+    }
+
+    #[test]
+    fn test_aggressive_stance_trades_defense_for_damage() {
+        use super::super::types::CombatStance;
+
+        assert!(
+            stance_hit_damage(CombatStance::Aggressive) > stance_hit_damage(CombatStance::Balanced)
+        );
+        // Lower effective defense lets more of the enemy hit through
+        assert!(
+            stance_damage_taken(CombatStance::Aggressive)
+                > stance_damage_taken(CombatStance::Balanced)
+        );
+    }
+
+    #[test]
+    fn test_defensive_stance_trades_damage_for_defense() {
+        use super::super::types::CombatStance;
+
+        assert!(
+            stance_hit_damage(CombatStance::Defensive) < stance_hit_damage(CombatStance::Balanced)
+        );
+        assert!(
+            stance_damage_taken(CombatStance::Defensive)
+                < stance_damage_taken(CombatStance::Balanced)
+        );
+    }
+
     #[test]
     fn test_subzone_boss_defeat() {
         let mut state = GameState::new("Test Hero".to_string(), 0);
@@ -859,6 +1595,52 @@ mod tests {
         assert!(!state.zone_progression.fighting_boss);
     }
 
+    #[test]
+    fn test_subzone_boss_defeat_records_kill_time() {
+        let mut state = GameState::new("Test Hero".to_string(), 0);
+        let mut achievements = Achievements::default();
+        state.zone_progression.current_zone_id = 1;
+        state.zone_progression.current_subzone_id = 2;
+        state.zone_progression.fighting_boss = true;
+        state.combat_state.boss_fight_ticks = 41;
+        state.combat_state.current_enemy = Some(Enemy::new("Boss".to_string(), 1, 5));
+
+        force_player_attack(
+            &mut state,
+            &HavenCombatBonuses::default(),
+            &mut achievements,
+        );
+
+        let record = state.boss_ledger.get(1, 2).unwrap();
+        assert_eq!(record.attempts, 1);
+        assert_eq!(record.kills, 1);
+        assert_eq!(record.deaths, 0);
+        assert_eq!(state.boss_ledger.fastest_kill(1, 2), Some(42));
+    }
+
+    #[test]
+    fn test_death_to_boss_records_attempt_without_kill_time() {
+        let mut state = GameState::new("Test Hero".to_string(), 0);
+        let mut achievements = Achievements::default();
+        state.zone_progression.current_zone_id = 3;
+        state.zone_progression.current_subzone_id = 1;
+        state.zone_progression.fighting_boss = true;
+        state.combat_state.player_current_hp = 1;
+        state.combat_state.current_enemy = Some(Enemy::new("Boss".to_string(), 100, 50));
+
+        force_both_attacks(
+            &mut state,
+            &HavenCombatBonuses::default(),
+            &mut achievements,
+        );
+
+        let record = state.boss_ledger.get(3, 1).unwrap();
+        assert_eq!(record.attempts, 1);
+        assert_eq!(record.deaths, 1);
+        assert_eq!(record.kills, 0);
+        assert_eq!(state.boss_ledger.fastest_kill(3, 1), None);
+    }
+
     #[test]
     fn test_regular_kill_records_progress() {
         let mut state = GameState::new("Test Hero".to_string(), 0);
@@ -1062,6 +1844,66 @@ mod tests {
         assert_eq!(crit_count, 0, "Should never crit with 0% crit chance");
     }
 
+    #[test]
+    fn test_verbose_combat_logs_damage_breakdown() {
+        let mut state = GameState::new("Test Hero".to_string(), 0);
+        let mut achievements = Achievements::default();
+        state
+            .attributes
+            .set(crate::character::attributes::AttributeType::Dexterity, 0); // 0% crit
+        state
+            .attributes
+            .set(crate::character::attributes::AttributeType::Strength, 20);
+        state.attributes.set(
+            crate::character::attributes::AttributeType::Intelligence,
+            16,
+        );
+        let derived = DerivedStats::calculate_derived_stats(&state.attributes, &state.equipment);
+        let base = derived.total_damage(); // 26
+
+        let attack = |state: &mut GameState, achievements: &mut Achievements| {
+            let mut enemy = Enemy::new("Armored Dummy".to_string(), 10000, 0);
+            enemy.defense = 10;
+            state.combat_state.current_enemy = Some(enemy);
+            state.combat_state.player_attack_timer = ATTACK_INTERVAL_SECONDS;
+            update_combat(
+                state,
+                0.1,
+                &HavenCombatBonuses::default(),
+                &default_prestige(),
+                achievements,
+                &derived,
+            )
+        };
+
+        // Off by default: only the final number
+        let events = attack(&mut state, &mut achievements);
+        assert!(!events
+            .iter()
+            .any(|e| matches!(e, CombatEvent::DamageBreakdown { .. })));
+
+        state.verbose_combat = true;
+        let events = attack(&mut state, &mut achievements);
+        match events.first() {
+            Some(CombatEvent::DamageBreakdown {
+                base: logged_base,
+                defense,
+                crit_multiplier,
+                damage,
+            }) => {
+                assert_eq!(*logged_base, base);
+                assert_eq!(*defense, 10);
+                assert_eq!(*crit_multiplier, None);
+                assert_eq!(*damage, base - 10);
+            }
+            _ => panic!("Expected a DamageBreakdown event first"),
+        }
+        assert!(events.iter().any(|e| matches!(
+            e,
+            CombatEvent::PlayerAttack { damage, was_crit: false } if *damage == base - 10
+        )));
+    }
+
     #[test]
     fn test_player_total_damage_matches_derived_stats() {
         // With no crit (low DEX), verify damage equals derived total_damage
@@ -1385,6 +2227,49 @@ mod tests {
         assert_eq!(combat.combat_log[9].message, "Entry 14");
     }
 
+    #[test]
+    fn test_damage_after_defense() {
+        assert_eq!(damage_after_defense(50, 0), 50);
+        assert_eq!(damage_after_defense(50, 52), 1);
+        assert_eq!(damage_after_defense(50, 50), 1);
+        assert_eq!(damage_after_defense(120, 52), 68);
+    }
+
+    #[test]
+    fn test_player_damage_reduced_by_zone_defense() {
+        let mut state = GameState::new("Test Hero".to_string(), 0);
+        let defense = crate::zones::data::enemy_stats_for(10, 1).defense_min;
+        state.combat_state.current_enemy = Some(Enemy::new_with_defense(
+            "Storm Wraith".to_string(),
+            100_000,
+            1,
+            defense,
+        ));
+        state.combat_state.player_attack_timer = ATTACK_INTERVAL_SECONDS;
+        let derived = DerivedStats::calculate_derived_stats(&state.attributes, &state.equipment);
+        let expected = damage_after_defense(derived.total_damage(), defense);
+
+        let events = update_combat(
+            &mut state,
+            0.0,
+            &HavenCombatBonuses::default(),
+            &PrestigeCombatBonuses::default(),
+            &mut Achievements::default(),
+            &derived,
+        );
+
+        let hit = events.iter().find_map(|e| match e {
+            CombatEvent::PlayerAttack { damage, was_crit } => Some((*damage, *was_crit)),
+            _ => None,
+        });
+        let (damage, was_crit) = hit.expect("player should attack");
+        if was_crit {
+            assert_eq!(damage, (expected as f64 * derived.crit_multiplier) as u32);
+        } else {
+            assert_eq!(damage, expected);
+        }
+    }
+
     #[test]
     fn test_enemy_min_damage_with_high_defense() {
         // When defense >= enemy damage, player takes minimum 1 damage (min floor)
@@ -1439,34 +2324,160 @@ mod tests {
     }
 
     #[test]
-    fn test_prestige_rank_preserved_on_death() {
+    fn test_prestige_rank_preserved_on_death() {
+        let mut state = GameState::new("Test Hero".to_string(), 0);
+        let mut achievements = Achievements::default();
+
+        // Set a prestige rank (3 = Gold)
+        state.prestige_rank = 3;
+        let original_rank = state.prestige_rank;
+
+        // Set up boss fight
+        state.zone_progression.fighting_boss = true;
+
+        // Low HP so player dies
+        state.combat_state.player_current_hp = 1;
+        state.combat_state.current_enemy = Some(Enemy::new("Boss".to_string(), 100, 50));
+
+        // Force both attacks (enemy kills player)
+        let events = force_both_attacks(
+            &mut state,
+            &HavenCombatBonuses::default(),
+            &mut achievements,
+        );
+
+        // Verify player died
+        let died = events.iter().any(|e| matches!(e, CombatEvent::PlayerDied));
+        assert!(died);
+
+        // Prestige rank should NOT be changed
+        assert_eq!(state.prestige_rank, original_rank);
+    }
+
+    /// Forces an enemy attack against a build that always parries.
+    fn force_parried_attack(state: &mut GameState) -> (Vec<CombatEvent>, DerivedStats) {
+        let mut achievements = Achievements::default();
+        let mut derived = default_derived(state);
+        derived.parry_chance_percent = 100.0;
+        state.combat_state.player_attack_timer = 0.0;
+        state.combat_state.enemy_attack_timer = ENEMY_ATTACK_INTERVAL_SECONDS;
+        let events = update_combat(
+            state,
+            0.1,
+            &HavenCombatBonuses::default(),
+            &default_prestige(),
+            &mut achievements,
+            &derived,
+        );
+        (events, derived)
+    }
+
+    #[test]
+    fn test_guaranteed_parry_takes_no_damage_and_counters() {
+        let mut state = GameState::new("Duelist".to_string(), 0);
+        state.combat_state.current_enemy =
+            Some(Enemy::new_with_defense("Brute".to_string(), 1000, 30, 2));
+        let hp_before = state.combat_state.player_current_hp;
+
+        let (events, derived) = force_parried_attack(&mut state);
+
+        // Counter: half of base damage, reduced by enemy defense
+        let expected = (derived.total_damage() as f64 * PARRY_COUNTER_DAMAGE_MULTIPLIER) as u32 - 2;
+        assert_eq!(state.combat_state.player_current_hp, hp_before);
+        assert_has_event(
+            &events,
+            "Parry",
+            |e| matches!(e, CombatEvent::Parry { counter_damage } if *counter_damage == expected),
+        );
+        assert_no_event(&events, "EnemyAttack", |e| {
+            matches!(e, CombatEvent::EnemyAttack { .. })
+        });
+        let enemy = state.combat_state.current_enemy.as_ref().unwrap();
+        assert_eq!(enemy.current_hp, 1000 - expected);
+    }
+
+    #[test]
+    fn test_parry_counter_can_kill_enemy() {
+        let mut state = GameState::new("Duelist".to_string(), 0);
+        state.combat_state.current_enemy = Some(Enemy::new("Weakling".to_string(), 1, 30));
+
+        let (events, _) = force_parried_attack(&mut state);
+
+        assert_has_event(&events, "EnemyDied", |e| {
+            matches!(e, CombatEvent::EnemyDied { .. })
+        });
+        assert!(state.combat_state.current_enemy.is_none());
+        assert!(state.combat_state.is_player_alive());
+    }
+
+    #[test]
+    fn test_parry_counter_kill_triggers_on_kill_heal() {
+        let mut state = GameState::new("Duelist".to_string(), 0);
+        equip_weapon_with(&mut state, AffixType::OnKillHeal, 20.0);
+        state.combat_state.player_max_hp = 100;
+        state.combat_state.player_current_hp = 30;
+        state.combat_state.current_enemy = Some(Enemy::new("Weakling".to_string(), 1, 30));
+
+        let (events, _) = force_parried_attack(&mut state);
+
+        assert_has_event(&events, "EnemyDied", |e| {
+            matches!(e, CombatEvent::EnemyDied { .. })
+        });
+        assert_eq!(state.combat_state.player_current_hp, 50);
+    }
+
+    #[test]
+    fn test_death_resets_prestige_streak() {
         let mut state = GameState::new("Test Hero".to_string(), 0);
         let mut achievements = Achievements::default();
+        state.prestige_streak = 4;
 
-        // Set a prestige rank (3 = Gold)
-        state.prestige_rank = 3;
-        let original_rank = state.prestige_rank;
-
-        // Set up boss fight
-        state.zone_progression.fighting_boss = true;
-
-        // Low HP so player dies
         state.combat_state.player_current_hp = 1;
-        state.combat_state.current_enemy = Some(Enemy::new("Boss".to_string(), 100, 50));
-
-        // Force both attacks (enemy kills player)
-        let events = force_both_attacks(
+        state.combat_state.current_enemy = Some(Enemy::new("Brute".to_string(), 100_000, 50));
+        let events = force_enemy_attack(
             &mut state,
             &HavenCombatBonuses::default(),
             &mut achievements,
         );
 
-        // Verify player died
-        let died = events.iter().any(|e| matches!(e, CombatEvent::PlayerDied));
-        assert!(died);
+        assert!(events.iter().any(|e| matches!(e, CombatEvent::PlayerDied)));
+        assert_eq!(state.prestige_streak, 0);
+    }
 
-        // Prestige rank should NOT be changed
-        assert_eq!(state.prestige_rank, original_rank);
+    #[test]
+    fn test_prestige_streak_increases_player_damage() {
+        let hit_with_streak = |streak: u32| {
+            let mut state = GameState::new("Test Hero".to_string(), 0);
+            // DEX 0 drops crit chance to 0 so every hit is a plain hit
+            state
+                .attributes
+                .set(crate::character::attributes::AttributeType::Dexterity, 0);
+            state
+                .attributes
+                .set(crate::character::attributes::AttributeType::Strength, 30);
+            state.prestige_streak = streak;
+            state.combat_state.current_enemy = Some(Enemy::new("Dummy".to_string(), 100_000, 1));
+            force_player_attack(
+                &mut state,
+                &HavenCombatBonuses::default(),
+                &mut Achievements::default(),
+            )
+            .iter()
+            .find_map(|e| match e {
+                CombatEvent::PlayerAttack { damage, .. } => Some(*damage),
+                _ => None,
+            })
+            .expect("player should attack")
+        };
+
+        let base = hit_with_streak(0);
+        let streak_5 = hit_with_streak(5);
+        let streak_50 = hit_with_streak(50);
+        let expected_5 = base as f64 * (1.0 + prestige_streak_damage_percent(5) / 100.0);
+        assert_eq!(streak_5, expected_5 as u32);
+        assert!(streak_5 > base);
+        // Bonus is capped
+        assert_eq!(streak_50, hit_with_streak(10));
     }
 
     #[test]
@@ -1494,6 +2505,7 @@ mod tests {
                 affix_type: AffixType::CritMultiplier,
                 value: 100.0,
             }],
+            sockets: Vec::new(),
         };
         state.equipment.set(EquipmentSlot::Weapon, Some(weapon));
 
@@ -1546,6 +2558,7 @@ mod tests {
                 affix_type: AffixType::AttackSpeed,
                 value: 50.0,
             }],
+            sockets: Vec::new(),
         };
         state.equipment.set(EquipmentSlot::Gloves, Some(gloves));
 
@@ -1615,6 +2628,7 @@ mod tests {
                 affix_type: AffixType::HPRegen,
                 value: 100.0,
             }],
+            sockets: Vec::new(),
         };
         state.equipment.set(EquipmentSlot::Armor, Some(armor));
 
@@ -1715,6 +2729,7 @@ mod tests {
                 affix_type: AffixType::HPRegen,
                 value: 100.0,
             }],
+            sockets: Vec::new(),
         };
         state.equipment.set(EquipmentSlot::Armor, Some(armor));
 
@@ -1770,6 +2785,7 @@ mod tests {
                 affix_type: AffixType::DamageReflection,
                 value: 50.0,
             }],
+            sockets: Vec::new(),
         };
         state.equipment.set(EquipmentSlot::Armor, Some(armor));
 
@@ -1822,6 +2838,7 @@ mod tests {
                 affix_type: AffixType::DamageReflection,
                 value: 1000.0, // 1000% reflection
             }],
+            sockets: Vec::new(),
         };
         state.equipment.set(EquipmentSlot::Armor, Some(armor));
 
@@ -1871,6 +2888,7 @@ mod tests {
                 affix_type: AffixType::DamageReflection,
                 value: 100.0,
             }],
+            sockets: Vec::new(),
         };
         state.equipment.set(EquipmentSlot::Armor, Some(armor));
 
@@ -2140,6 +3158,7 @@ mod tests {
             hp_regen_percent: 50.0,
             hp_regen_delay_reduction: 30.0,
             xp_gain_percent: 20.0,
+            emergency_heal_chance: 20.0,
         };
 
         let derived = default_derived(&state);
@@ -2847,4 +3866,413 @@ mod tests {
             loaded.enemy_attack_timer
         );
     }
+
+    #[test]
+    fn test_emergency_heal_triggers_once_per_enemy() {
+        let mut state = GameState::new("Test Hero".to_string(), 0);
+        let mut achievements = Achievements::default();
+        let haven = HavenCombatBonuses {
+            emergency_heal_chance: 100.0,
+            ..Default::default()
+        };
+
+        let defense = default_derived(&state).defense;
+        let max_hp = state.combat_state.player_max_hp;
+        let threshold = (max_hp as f64 * EMERGENCY_HEAL_HP_THRESHOLD) as u32;
+        state.combat_state.current_enemy =
+            Some(Enemy::new("Brute".to_string(), 100_000, defense + 5));
+
+        // First hit drops the player just below the threshold -> heal
+        state.combat_state.player_current_hp = threshold + 2;
+        let events = force_enemy_attack(&mut state, &haven, &mut achievements);
+        assert_has_event(&events, "EmergencyHeal", |e| {
+            matches!(e, CombatEvent::EmergencyHeal { .. })
+        });
+        assert!(state.combat_state.player_current_hp > threshold);
+        assert!(state.combat_state.panic_heal_used);
+
+        // Dropping low again against the same enemy does not heal
+        state.combat_state.player_current_hp = threshold + 2;
+        let events = force_enemy_attack(&mut state, &haven, &mut achievements);
+        assert_no_event(&events, "EmergencyHeal", |e| {
+            matches!(e, CombatEvent::EmergencyHeal { .. })
+        });
+        assert_eq!(state.combat_state.player_current_hp, threshold - 3);
+    }
+
+    #[test]
+    fn test_emergency_heal_not_triggered_above_threshold_or_without_haven() {
+        let mut state = GameState::new("Test Hero".to_string(), 0);
+        let mut achievements = Achievements::default();
+        let defense = default_derived(&state).defense;
+        state.combat_state.current_enemy =
+            Some(Enemy::new("Brute".to_string(), 100_000, defense + 1));
+
+        // Above threshold with 100% chance: no heal, roll not consumed
+        let haven = HavenCombatBonuses {
+            emergency_heal_chance: 100.0,
+            ..Default::default()
+        };
+        let events = force_enemy_attack(&mut state, &haven, &mut achievements);
+        assert_no_event(&events, "EmergencyHeal", |e| {
+            matches!(e, CombatEvent::EmergencyHeal { .. })
+        });
+        assert!(!state.combat_state.panic_heal_used);
+
+        // Below threshold without Alchemy Lab: no heal
+        state.combat_state.player_current_hp = 2;
+        let events = force_enemy_attack(
+            &mut state,
+            &HavenCombatBonuses::default(),
+            &mut achievements,
+        );
+        assert_no_event(&events, "EmergencyHeal", |e| {
+            matches!(e, CombatEvent::EmergencyHeal { .. })
+        });
+    }
+
+    #[test]
+    fn test_spawn_resets_emergency_heal_flag() {
+        let mut state = GameState::new("Test Hero".to_string(), 0);
+        state.combat_state.panic_heal_used = true;
+        crate::core::game_logic::spawn_enemy_if_needed(&mut state);
+        assert!(state.combat_state.current_enemy.is_some());
+        assert!(!state.combat_state.panic_heal_used);
+    }
+
+    // =========================================================================
+    // Boss enrage
+    // =========================================================================
+
+    #[test]
+    fn test_boss_damage_increases_after_enrage_threshold() {
+        let mut state = GameState::new("Test Hero".to_string(), 0);
+        let mut achievements = Achievements::default();
+        let defense = default_derived(&state).defense;
+        state.zone_progression.fighting_boss = true;
+        state.combat_state.current_enemy = Some(Enemy::new(
+            "Stalled Boss".to_string(),
+            100_000,
+            defense + 10,
+        ));
+
+        // The tick that reaches the threshold still hits at base damage
+        state.combat_state.boss_fight_ticks = BOSS_ENRAGE_THRESHOLD_TICKS - 1;
+        let hp_before = state.combat_state.player_current_hp;
+        force_enemy_attack(
+            &mut state,
+            &HavenCombatBonuses::default(),
+            &mut achievements,
+        );
+        let base_hit = hp_before - state.combat_state.player_current_hp;
+        assert_eq!(base_hit, 10);
+        assert_eq!(
+            state.combat_state.boss_fight_ticks,
+            BOSS_ENRAGE_THRESHOLD_TICKS
+        );
+
+        // 100 ticks past the threshold the boss hits for double
+        state.combat_state.player_current_hp = state.combat_state.player_max_hp;
+        state.combat_state.boss_fight_ticks = BOSS_ENRAGE_THRESHOLD_TICKS + 99;
+        let hp_before = state.combat_state.player_current_hp;
+        force_enemy_attack(
+            &mut state,
+            &HavenCombatBonuses::default(),
+            &mut achievements,
+        );
+        let enraged_hit = hp_before - state.combat_state.player_current_hp;
+        assert!((boss_enrage_multiplier(BOSS_ENRAGE_THRESHOLD_TICKS + 100) - 2.0).abs() < 1e-9);
+        assert_eq!(enraged_hit, 2 * (defense + 10) - defense);
+        assert!(enraged_hit > base_hit);
+    }
+
+    #[test]
+    fn test_non_boss_fights_never_enrage() {
+        let mut state = GameState::new("Test Hero".to_string(), 0);
+        let mut achievements = Achievements::default();
+        let defense = default_derived(&state).defense;
+        state.combat_state.current_enemy =
+            Some(Enemy::new("Slime".to_string(), 100_000, defense + 10));
+
+        for _ in 0..BOSS_ENRAGE_THRESHOLD_TICKS * 2 {
+            state.combat_state.player_current_hp = state.combat_state.player_max_hp;
+            force_enemy_attack(
+                &mut state,
+                &HavenCombatBonuses::default(),
+                &mut achievements,
+            );
+        }
+
+        assert_eq!(state.combat_state.boss_fight_ticks, 0);
+        assert_eq!(
+            state.combat_state.player_max_hp - state.combat_state.player_current_hp,
+            10
+        );
+    }
+
+    #[test]
+    fn test_spawn_resets_boss_fight_ticks() {
+        let mut state = GameState::new("Test Hero".to_string(), 0);
+        state.combat_state.boss_fight_ticks = BOSS_ENRAGE_THRESHOLD_TICKS * 3;
+        crate::core::game_logic::spawn_enemy_if_needed(&mut state);
+        assert!(state.combat_state.current_enemy.is_some());
+        assert_eq!(state.combat_state.boss_fight_ticks, 0);
+    }
+
+    // =========================================================================
+    // On-kill affixes
+    // =========================================================================
+
+    fn equip_weapon_with(state: &mut GameState, affix_type: AffixType, value: f64) {
+        use crate::items::types::{Affix, AttributeBonuses, EquipmentSlot, Item, Rarity};
+        let weapon = Item {
+            slot: EquipmentSlot::Weapon,
+            rarity: Rarity::Rare,
+            ilvl: 10,
+            base_name: "Sword".to_string(),
+            display_name: "Sword".to_string(),
+            attributes: AttributeBonuses::new(),
+            affixes: vec![Affix { affix_type, value }],
+            sockets: Vec::new(),
+        };
+        state.equipment.set(EquipmentSlot::Weapon, Some(weapon));
+    }
+
+    #[test]
+    fn test_on_kill_heal_restores_hp_when_enemy_dies() {
+        let mut state = GameState::new("Test Hero".to_string(), 0);
+        let mut achievements = Achievements::default();
+        equip_weapon_with(&mut state, AffixType::OnKillHeal, 20.0);
+        state.combat_state.player_max_hp = 100;
+        state.combat_state.player_current_hp = 30;
+        state.combat_state.current_enemy = Some(Enemy::new("Weakling".to_string(), 1, 1));
+
+        let events = force_player_attack(
+            &mut state,
+            &HavenCombatBonuses::default(),
+            &mut achievements,
+        );
+
+        assert!(events
+            .iter()
+            .any(|e| matches!(e, CombatEvent::EnemyDied { .. })));
+        assert_eq!(state.combat_state.player_current_hp, 50);
+    }
+
+    #[test]
+    fn test_on_kill_heal_does_not_exceed_max_hp() {
+        let mut state = GameState::new("Test Hero".to_string(), 0);
+        let mut achievements = Achievements::default();
+        equip_weapon_with(&mut state, AffixType::OnKillHeal, 50.0);
+        state.combat_state.player_max_hp = 100;
+        state.combat_state.player_current_hp = 90;
+        state.combat_state.current_enemy = Some(Enemy::new("Weakling".to_string(), 1, 1));
+
+        force_player_attack(
+            &mut state,
+            &HavenCombatBonuses::default(),
+            &mut achievements,
+        );
+
+        assert_eq!(state.combat_state.player_current_hp, 100);
+    }
+
+    #[test]
+    fn test_on_kill_rage_buff_expires_after_duration() {
+        let mut state = GameState::new("Test Hero".to_string(), 0);
+        let mut achievements = Achievements::default();
+        equip_weapon_with(&mut state, AffixType::OnKillRage, 25.0);
+        state.combat_state.current_enemy = Some(Enemy::new("Weakling".to_string(), 1, 1));
+
+        force_player_attack(
+            &mut state,
+            &HavenCombatBonuses::default(),
+            &mut achievements,
+        );
+        assert!((state.combat_state.buff_attack_speed_percent() - 25.0).abs() < f64::EPSILON);
+
+        // Buff decays over time, including while regenerating
+        let derived = default_derived(&state);
+        let mut elapsed = 0.0;
+        while elapsed < ON_KILL_RAGE_DURATION_SECONDS - 1.0 {
+            update_combat(
+                &mut state,
+                0.5,
+                &HavenCombatBonuses::default(),
+                &default_prestige(),
+                &mut achievements,
+                &derived,
+            );
+            elapsed += 0.5;
+        }
+        assert_eq!(state.combat_state.buffs.len(), 1);
+
+        update_combat(
+            &mut state,
+            1.5,
+            &HavenCombatBonuses::default(),
+            &default_prestige(),
+            &mut achievements,
+            &derived,
+        );
+        assert!(state.combat_state.buffs.is_empty());
+        assert_eq!(state.combat_state.buff_attack_speed_percent(), 0.0);
+    }
+
+    #[test]
+    fn test_on_kill_rage_refreshes_instead_of_stacking() {
+        let mut combat = CombatState::new(100);
+        let rage = |remaining_seconds| CombatBuff {
+            source: AffixType::OnKillRage,
+            attack_speed_percent: 25.0,
+            remaining_seconds,
+        };
+        combat.push_buff(rage(1.0));
+        combat.push_buff(rage(ON_KILL_RAGE_DURATION_SECONDS));
+
+        assert_eq!(combat.buffs.len(), 1);
+        assert_eq!(
+            combat.buffs[0].remaining_seconds,
+            ON_KILL_RAGE_DURATION_SECONDS
+        );
+    }
+
+    #[test]
+    fn test_rage_buff_shortens_attack_interval() {
+        let mut state = GameState::new("Test Hero".to_string(), 0);
+        let mut achievements = Achievements::default();
+        state.combat_state.current_enemy = Some(Enemy::new("Punching Bag".to_string(), 100_000, 0));
+        state.combat_state.push_buff(CombatBuff {
+            source: AffixType::OnKillRage,
+            attack_speed_percent: 50.0,
+            remaining_seconds: 60.0,
+        });
+        // 1.5s / 1.5 = 1.0s interval
+        state.combat_state.player_attack_timer = 0.95;
+        let derived = default_derived(&state);
+
+        let events = update_combat(
+            &mut state,
+            0.1,
+            &HavenCombatBonuses::default(),
+            &default_prestige(),
+            &mut achievements,
+            &derived,
+        );
+
+        assert!(events
+            .iter()
+            .any(|e| matches!(e, CombatEvent::PlayerAttack { .. })));
+    }
+
+    #[test]
+    fn test_batched_trivial_kills_grant_same_xp_as_one_by_one() {
+        use rand::SeedableRng;
+
+        let haven = HavenCombatBonuses::default();
+        let kills = KILLS_FOR_BOSS - 1;
+        let kill_xp = |events: &[CombatEvent]| -> u64 {
+            events
+                .iter()
+                .map(|e| match e {
+                    CombatEvent::EnemyDied { xp_gained } => *xp_gained,
+                    _ => 0,
+                })
+                .sum()
+        };
+
+        let mut batched = GameState::new("Farmer".to_string(), 0);
+        let mut one_by_one = batched.clone();
+        let mut achievements = Achievements::default();
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(7);
+        let batched_events =
+            resolve_trivial_kills(&mut batched, kills, &haven, &mut achievements, &mut rng);
+
+        let mut achievements = Achievements::default();
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(7);
+        let mut single_xp = 0;
+        for _ in 0..kills {
+            let events =
+                resolve_trivial_kills(&mut one_by_one, 1, &haven, &mut achievements, &mut rng);
+            single_xp += kill_xp(&events);
+        }
+
+        assert!(single_xp > 0);
+        assert_eq!(kill_xp(&batched_events), single_xp);
+        assert_eq!(
+            batched.zone_progression.kills_in_subzone,
+            one_by_one.zone_progression.kills_in_subzone
+        );
+        assert_eq!(batched.combat_state.combo, one_by_one.combat_state.combo);
+    }
+
+    #[test]
+    fn test_trivial_kills_stop_when_boss_is_due() {
+        let mut state = GameState::new("Farmer".to_string(), 0);
+        let mut achievements = Achievements::default();
+        let events = resolve_trivial_kills(
+            &mut state,
+            KILLS_FOR_BOSS + 5,
+            &HavenCombatBonuses::default(),
+            &mut achievements,
+            &mut rand::rng(),
+        );
+        let kills = events
+            .iter()
+            .filter(|e| matches!(e, CombatEvent::EnemyDied { .. }))
+            .count();
+        assert_eq!(kills as u32, KILLS_FOR_BOSS);
+        assert!(state.zone_progression.fighting_boss);
+    }
+
+    #[test]
+    fn test_fast_resolve_banks_time_then_resolves_a_batch() {
+        let mut state = GameState::new("Farmer".to_string(), 0);
+        state.combat_state.current_enemy = Some(Enemy::new("Field Mouse".to_string(), 1, 1));
+        state.combat_state.fast_resolve.kill_seconds = Some(2.5);
+
+        // 0.5s ticks: nothing resolves until FAST_RESOLVE_BATCH_SECONDS are banked
+        let ticks = (FAST_RESOLVE_BATCH_SECONDS / 0.5) as u32;
+        for _ in 1..ticks {
+            assert_eq!(fast_resolve_combat(&mut state, 0.5), Some(0));
+        }
+        let kills = fast_resolve_combat(&mut state, 0.5).unwrap();
+        assert_eq!(kills as f64, FAST_RESOLVE_BATCH_SECONDS / 2.5);
+        assert_eq!(state.combat_state.fast_resolve.elapsed, 0.0);
+        assert!(state.combat_state.current_enemy.is_some());
+
+        // The stand-in is cleared so a fresh enemy is rolled; the fast path stays on
+        end_fast_resolve_batch(&mut state);
+        assert!(state.combat_state.current_enemy.is_none());
+        assert!(!state.combat_state.is_regenerating);
+        assert_eq!(state.combat_state.fast_resolve.kill_seconds, Some(2.5));
+    }
+
+    #[test]
+    fn test_fast_resolve_batch_ending_for_boss_pauses_for_regen() {
+        let mut state = GameState::new("Farmer".to_string(), 0);
+        state.combat_state.current_enemy = Some(Enemy::new("Field Mouse".to_string(), 1, 1));
+        state.combat_state.fast_resolve.kill_seconds = Some(2.5);
+        state.zone_progression.fighting_boss = true;
+        assert!(fast_resolve_interrupted(&state));
+
+        end_fast_resolve_batch(&mut state);
+        assert!(state.combat_state.current_enemy.is_none());
+        assert!(state.combat_state.is_regenerating);
+        assert_eq!(state.combat_state.fast_resolve, FastResolve::default());
+    }
+
+    #[test]
+    fn test_fast_resolve_hands_real_fights_back() {
+        let mut state = GameState::new("Farmer".to_string(), 0);
+        state.combat_state.current_enemy = Some(Enemy::new("Field Mouse".to_string(), 1, 1));
+
+        // No kill time cached for this enemy: regular combat
+        assert!(fast_resolve_combat(&mut state, 0.1).is_none());
+
+        state.combat_state.fast_resolve.kill_seconds = Some(2.5);
+        state.zone_progression.fighting_boss = true;
+        assert!(fast_resolve_combat(&mut state, 0.1).is_none());
+        assert_eq!(state.combat_state.fast_resolve.kill_seconds, None);
+    }
 }
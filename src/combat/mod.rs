@@ -1,6 +1,9 @@
 //! Combat system types and logic.
 
+pub mod adaptive;
+pub mod combat_math;
 pub mod logic;
+pub mod training_dummy;
 pub mod types;
 
 pub use types::*;
@@ -0,0 +1,257 @@
+//! Training dummy for DPS testing (debug menu only).
+//!
+//! The dummy is an immortal enemy that never attacks back. Combat runs through
+//! the normal `update_combat` path so every bonus (equipment, Haven, prestige)
+//! applies, and each swing is recorded to compute sustained DPS.
+
+use super::logic::{update_combat, CombatEvent, HavenCombatBonuses};
+use super::types::Enemy;
+use crate::character::derived_stats::DerivedStats;
+use crate::character::prestige::PrestigeCombatBonuses;
+use crate::core::game_state::GameState;
+
+pub const TRAINING_DUMMY_NAME: &str = "Training Dummy";
+
+/// Damage log for an active training dummy session (transient, not saved).
+#[derive(Debug, Clone, Default)]
+pub struct TrainingDummy {
+    /// Seconds of combat simulated against the dummy
+    pub elapsed_seconds: f64,
+    /// Player attack rolls (a double strike counts as one attack)
+    pub attacks: u32,
+    /// Individual hits landed, including double-strike bonus hits
+    pub hits: u32,
+    pub crits: u32,
+    pub double_strikes: u32,
+    pub total_damage: u64,
+}
+
+/// Summary statistics for a training dummy session.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrainingDummyReport {
+    pub dps: f64,
+    /// Fraction of attacks that crit (0.0 - 1.0)
+    pub crit_rate: f64,
+    /// Fraction of attacks that struck twice (0.0 - 1.0)
+    pub double_strike_rate: f64,
+    pub attacks: u32,
+    pub elapsed_seconds: f64,
+}
+
+impl TrainingDummy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The dummy enemy: effectively infinite HP, no damage, no defense.
+    pub fn enemy() -> Enemy {
+        Enemy::new(TRAINING_DUMMY_NAME.to_string(), u32::MAX, 0)
+    }
+
+    /// Records the player attacks from one `update_combat` call.
+    fn record(&mut self, events: &[CombatEvent]) {
+        let mut strikes = 0;
+        for event in events {
+            if let CombatEvent::PlayerAttack { damage, was_crit } = event {
+                if strikes == 0 {
+                    self.attacks += 1;
+                    if *was_crit {
+                        self.crits += 1;
+                    }
+                }
+                strikes += 1;
+                self.hits += 1;
+                self.total_damage += *damage as u64;
+            }
+        }
+        if strikes > 1 {
+            self.double_strikes += 1;
+        }
+    }
+
+    pub fn report(&self) -> TrainingDummyReport {
+        let per_attack = |count: u32| {
+            if self.attacks == 0 {
+                0.0
+            } else {
+                count as f64 / self.attacks as f64
+            }
+        };
+        TrainingDummyReport {
+            dps: if self.elapsed_seconds > 0.0 {
+                self.total_damage as f64 / self.elapsed_seconds
+            } else {
+                0.0
+            },
+            crit_rate: per_attack(self.crits),
+            double_strike_rate: per_attack(self.double_strikes),
+            attacks: self.attacks,
+            elapsed_seconds: self.elapsed_seconds,
+        }
+    }
+}
+
+impl TrainingDummyReport {
+    pub fn summary(&self) -> String {
+        format!(
+            "Training dummy: {:.1} DPS over {:.0}s, {} attacks, {:.1}% crit, {:.1}% double strike",
+            self.dps,
+            self.elapsed_seconds,
+            self.attacks,
+            self.crit_rate * 100.0,
+            self.double_strike_rate * 100.0
+        )
+    }
+}
+
+/// Runs one combat tick against the training dummy and records the results.
+///
+/// The dummy's attack timer is held at zero so it never swings, and its HP is
+/// restored after every tick so it never dies. Does nothing if no dummy is active.
+pub fn update_training_dummy(
+    state: &mut GameState,
+    delta_time: f64,
+    haven: &HavenCombatBonuses,
+    prestige_bonuses: &PrestigeCombatBonuses,
+    achievements: &mut crate::achievements::Achievements,
+    derived: &DerivedStats,
+) -> Vec<CombatEvent> {
+    let Some(mut dummy) = state.training_dummy.take() else {
+        return Vec::new();
+    };
+
+    if state.combat_state.current_enemy.is_none() {
+        state.combat_state.current_enemy = Some(TrainingDummy::enemy());
+    }
+    state.combat_state.is_regenerating = false;
+    // Offset the timer so it accumulates back to zero this tick
+    state.combat_state.enemy_attack_timer = -delta_time;
+
+    let events = update_combat(
+        state,
+        delta_time,
+        haven,
+        prestige_bonuses,
+        achievements,
+        derived,
+    );
+
+    if let Some(enemy) = state.combat_state.current_enemy.as_mut() {
+        enemy.reset_hp();
+    }
+    dummy.elapsed_seconds += delta_time;
+    dummy.record(&events);
+    state.training_dummy = Some(dummy);
+
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::achievements::Achievements;
+    use crate::character::attributes::AttributeType;
+    use crate::core::constants::ATTACK_INTERVAL_SECONDS;
+
+    fn start_dummy(state: &mut GameState) {
+        state.training_dummy = Some(TrainingDummy::new());
+        state.combat_state.current_enemy = Some(TrainingDummy::enemy());
+    }
+
+    /// Runs exactly `n` player attacks by stepping one attack interval at a time.
+    fn force_attacks(state: &mut GameState, haven: &HavenCombatBonuses, n: u32) {
+        let derived = DerivedStats::calculate_derived_stats(&state.attributes, &state.equipment);
+        let prestige = PrestigeCombatBonuses::default();
+        let mut achievements = Achievements::default();
+        for _ in 0..n {
+            update_training_dummy(
+                state,
+                ATTACK_INTERVAL_SECONDS,
+                haven,
+                &prestige,
+                &mut achievements,
+                &derived,
+            );
+        }
+    }
+
+    #[test]
+    fn test_hit_count_matches_forced_attacks() {
+        let mut state = GameState::new("Tester".to_string(), 0);
+        start_dummy(&mut state);
+
+        force_attacks(&mut state, &HavenCombatBonuses::default(), 50);
+
+        let dummy = state.training_dummy.as_ref().unwrap();
+        assert_eq!(dummy.attacks, 50);
+        assert_eq!(dummy.hits, 50);
+        assert_eq!(dummy.double_strikes, 0);
+        assert!(dummy.total_damage > 0);
+    }
+
+    #[test]
+    fn test_crit_rate_matches_build() {
+        let mut state = GameState::new("Tester".to_string(), 0);
+        // DEX 20 → +5 modifier → 10% crit chance
+        state.attributes.set(AttributeType::Dexterity, 20);
+        start_dummy(&mut state);
+
+        force_attacks(&mut state, &HavenCombatBonuses::default(), 2000);
+
+        let report = state.training_dummy.as_ref().unwrap().report();
+        assert_eq!(report.attacks, 2000);
+        assert!(
+            (report.crit_rate - 0.10).abs() < 0.03,
+            "crit rate {} should be near 10%",
+            report.crit_rate
+        );
+    }
+
+    #[test]
+    fn test_double_strikes_recorded() {
+        let mut state = GameState::new("Tester".to_string(), 0);
+        start_dummy(&mut state);
+        let haven = HavenCombatBonuses {
+            double_strike_chance: 100.0,
+            ..Default::default()
+        };
+
+        force_attacks(&mut state, &haven, 20);
+
+        let dummy = state.training_dummy.as_ref().unwrap();
+        assert_eq!(dummy.attacks, 20);
+        assert_eq!(dummy.hits, 40);
+        assert!((dummy.report().double_strike_rate - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_dummy_never_dies_or_attacks() {
+        let mut state = GameState::new("Tester".to_string(), 0);
+        start_dummy(&mut state);
+        let hp_before = state.combat_state.player_current_hp;
+
+        force_attacks(&mut state, &HavenCombatBonuses::default(), 100);
+
+        let enemy = state.combat_state.current_enemy.as_ref().unwrap();
+        assert_eq!(enemy.name, TRAINING_DUMMY_NAME);
+        assert_eq!(enemy.current_hp, enemy.max_hp);
+        assert_eq!(state.combat_state.player_current_hp, hp_before);
+        assert_eq!(state.session_kills, 0);
+    }
+
+    #[test]
+    fn test_report_dps() {
+        let dummy = TrainingDummy {
+            elapsed_seconds: 10.0,
+            attacks: 4,
+            hits: 5,
+            crits: 1,
+            double_strikes: 1,
+            total_damage: 250,
+        };
+        let report = dummy.report();
+        assert!((report.dps - 25.0).abs() < f64::EPSILON);
+        assert!((report.crit_rate - 0.25).abs() < f64::EPSILON);
+        assert!((report.double_strike_rate - 0.25).abs() < f64::EPSILON);
+    }
+}
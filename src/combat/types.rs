@@ -1,10 +1,91 @@
-use rand::RngExt;
+use rand::{Rng, RngExt};
 use serde::{Deserialize, Serialize};
 
 use crate::core::constants::*;
+use crate::items::types::AffixType;
+use crate::zones::data::enemy_stats_for;
 use crate::zones::{get_zone, Subzone, Zone};
 use std::collections::VecDeque;
 
+/// Phases of the Storm Citadel final boss, entered as its HP drops.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum BossPhase {
+    Calm,
+    /// Attacks faster
+    Tempest,
+    /// Attacks faster behind a storm shield that absorbs damage
+    StormShield,
+}
+
+impl BossPhase {
+    /// Phase for a boss at `current_hp` out of `max_hp`.
+    pub fn for_hp(current_hp: u32, max_hp: u32) -> Self {
+        let fraction = current_hp as f64 / max_hp.max(1) as f64;
+        if fraction <= BOSS_STORM_SHIELD_HP_THRESHOLD {
+            BossPhase::StormShield
+        } else if fraction <= BOSS_TEMPEST_HP_THRESHOLD {
+            BossPhase::Tempest
+        } else {
+            BossPhase::Calm
+        }
+    }
+
+    /// Multiplier on the boss's attack interval (lower = faster).
+    pub fn attack_interval_multiplier(&self) -> f64 {
+        match self {
+            BossPhase::Calm => 1.0,
+            BossPhase::Tempest | BossPhase::StormShield => BOSS_TEMPEST_ATTACK_INTERVAL_MULTIPLIER,
+        }
+    }
+}
+
+/// Player-chosen trade-off between damage dealt and defense, cycled in game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum CombatStance {
+    /// More damage, less defense
+    Aggressive,
+    #[default]
+    Balanced,
+    /// More defense, less damage
+    Defensive,
+}
+
+impl CombatStance {
+    pub fn name(&self) -> &'static str {
+        match self {
+            CombatStance::Aggressive => "Aggressive",
+            CombatStance::Balanced => "Balanced",
+            CombatStance::Defensive => "Defensive",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            CombatStance::Balanced => CombatStance::Aggressive,
+            CombatStance::Aggressive => CombatStance::Defensive,
+            CombatStance::Defensive => CombatStance::Balanced,
+        }
+    }
+
+    /// Multiplier on the player's damage before enemy defense and crits.
+    pub fn damage_multiplier(&self) -> f64 {
+        match self {
+            CombatStance::Aggressive => 1.0 + COMBAT_STANCE_SHIFT,
+            CombatStance::Balanced => 1.0,
+            CombatStance::Defensive => 1.0 - COMBAT_STANCE_SHIFT,
+        }
+    }
+
+    /// Multiplier on the player's total defense against enemy hits.
+    pub fn defense_multiplier(&self) -> f64 {
+        match self {
+            CombatStance::Aggressive => 1.0 - COMBAT_STANCE_SHIFT,
+            CombatStance::Balanced => 1.0,
+            CombatStance::Defensive => 1.0 + COMBAT_STANCE_SHIFT,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Enemy {
     pub name: String,
@@ -13,8 +94,32 @@ pub struct Enemy {
     pub damage: u32,
     #[serde(default)]
     pub defense: u32,
+    /// Set on an Elite Hunt target: when it escapes (Unix seconds)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hunt_expires_at: Option<i64>,
+    /// Set on the Storm Citadel final boss: its current fight phase
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub boss_phase: Option<BossPhase>,
+    /// Storm shield remaining; absorbs damage before HP
+    #[serde(default)]
+    pub shield_hp: u32,
+    /// Destructible parts, destroyed front to back. When non-empty,
+    /// `current_hp`/`max_hp` are the sums over the segments.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub segments: Vec<Segment>,
 }
 
+/// One destructible part of a multi-segment enemy, e.g. a hydra's head.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Segment {
+    pub name: String,
+    pub max_hp: u32,
+    pub current_hp: u32,
+}
+
+/// Heads of a hydra, in the order they must be destroyed.
+pub const HYDRA_HEADS: [&str; 3] = ["Left Head", "Middle Head", "Right Head"];
+
 impl Enemy {
     #[allow(dead_code)]
     pub fn new(name: String, max_hp: u32, damage: u32) -> Self {
@@ -24,6 +129,10 @@ impl Enemy {
             max_hp,
             damage,
             defense: 0,
+            hunt_expires_at: None,
+            boss_phase: None,
+            shield_hp: 0,
+            segments: Vec::new(),
         }
     }
 
@@ -34,81 +143,153 @@ impl Enemy {
             max_hp,
             damage,
             defense,
+            hunt_expires_at: None,
+            boss_phase: None,
+            shield_hp: 0,
+            segments: Vec::new(),
         }
     }
 
+    /// Whether this is an Elite Hunt target.
+    pub fn is_hunt_elite(&self) -> bool {
+        self.hunt_expires_at.is_some()
+    }
+
     pub fn is_alive(&self) -> bool {
         self.current_hp > 0
     }
 
-    pub fn take_damage(&mut self, amount: u32) {
-        self.current_hp = self.current_hp.saturating_sub(amount);
+    /// Splits the enemy's HP evenly into segments with the given names (the
+    /// first takes any remainder).
+    pub fn with_segments(mut self, names: &[&str]) -> Self {
+        let count = names.len().max(1) as u32;
+        let share = self.max_hp / count;
+        self.segments = names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| {
+                let hp = if i == 0 {
+                    self.max_hp - share * (count - 1)
+                } else {
+                    share
+                };
+                Segment {
+                    name: name.to_string(),
+                    max_hp: hp,
+                    current_hp: hp,
+                }
+            })
+            .collect();
+        self.current_hp = self.max_hp;
+        self
     }
 
-    pub fn reset_hp(&mut self) {
-        self.current_hp = self.max_hp;
+    /// The segment currently being hit, if this is a multi-segment enemy.
+    pub fn current_segment(&self) -> Option<&Segment> {
+        self.segments.iter().find(|s| s.current_hp > 0)
     }
-}
 
-pub fn generate_enemy_name() -> String {
-    let mut rng = rand::rng();
+    pub fn living_segments(&self) -> usize {
+        self.segments.iter().filter(|s| s.current_hp > 0).count()
+    }
 
-    let prefixes = [
-        "Grizz", "Sav", "Dark", "Blood", "Bone", "Shadow", "Fel", "Dire", "Wild", "Grim",
-    ];
-    let roots = [
-        "led", "age", "en", "tooth", "claw", "fang", "heart", "eye", "maw", "tail",
-    ];
-    let suffixes = [
-        "Orc", "Troll", "Drake", "Crusher", "Render", "Maw", "Beast", "Fiend", "Horror", "Terror",
-    ];
+    /// One-line stat readout for the combat scene: damage, defense, seconds
+    /// between attacks (`attack_interval`, which depends on where the fight
+    /// is), then any active modifiers.
+    pub fn info_summary(&self, attack_interval: f64) -> String {
+        let mut parts = vec![
+            format!("DMG {}", self.damage),
+            format!("DEF {}", self.defense),
+            format!("{:.1}s/atk", attack_interval),
+        ];
+        match self.boss_phase {
+            Some(BossPhase::Tempest) => parts.push("Tempest".to_string()),
+            Some(BossPhase::StormShield) => parts.push("Storm Shield".to_string()),
+            Some(BossPhase::Calm) | None => {}
+        }
+        if self.shield_hp > 0 {
+            parts.push(format!("Shield {}", self.shield_hp));
+        }
+        if !self.segments.is_empty() {
+            parts.push(format!(
+                "Parts {}/{}",
+                self.living_segments(),
+                self.segments.len()
+            ));
+        }
+        if self.hunt_expires_at.is_some() {
+            parts.push("Hunted".to_string());
+        }
+        parts.join(" | ")
+    }
 
-    let prefix = prefixes[rng.random_range(0..prefixes.len())];
-    let root = roots[rng.random_range(0..roots.len())];
-    let suffix = suffixes[rng.random_range(0..suffixes.len())];
+    /// Applies damage after the storm shield. Multi-segment enemies take it
+    /// on the current segment only; damage beyond what it has left is lost.
+    pub fn take_damage(&mut self, amount: u32) {
+        let absorbed = amount.min(self.shield_hp);
+        self.shield_hp -= absorbed;
+        let amount = amount - absorbed;
+        if let Some(segment) = self.segments.iter_mut().find(|s| s.current_hp > 0) {
+            segment.current_hp = segment.current_hp.saturating_sub(amount);
+            self.current_hp = self.segments.iter().map(|s| s.current_hp).sum();
+        } else {
+            self.current_hp = self.current_hp.saturating_sub(amount);
+        }
+    }
 
-    format!("{}{} {}", prefix, root, suffix)
-}
+    pub fn reset_hp(&mut self) {
+        self.current_hp = self.max_hp;
+        for segment in &mut self.segments {
+            segment.current_hp = segment.max_hp;
+        }
+        self.shield_hp = 0;
+        if self.boss_phase.is_some() {
+            self.boss_phase = Some(BossPhase::Calm);
+        }
+    }
 
-/// Looks up zone base stats. Returns (base_hp, hp_step, base_dmg, dmg_step, base_def, def_step).
-/// Zone IDs are 1-indexed; defaults to Zone 1 for invalid IDs.
-fn zone_base_stats(zone_id: u32) -> (u32, u32, u32, u32, u32, u32) {
-    let index = (zone_id.saturating_sub(1) as usize).min(ZONE_ENEMY_STATS.len() - 1);
-    ZONE_ENEMY_STATS[index]
+    /// Moves a phased boss into the phase its HP calls for, raising the storm
+    /// shield on entering `StormShield`. Phases only advance, so a boss that
+    /// skips Tempest in one big hit goes straight to the shield. Returns the new
+    /// phase if it changed.
+    pub fn advance_boss_phase(&mut self) -> Option<BossPhase> {
+        let current = self.boss_phase?;
+        let next = BossPhase::for_hp(self.current_hp, self.max_hp);
+        if !self.is_alive() || next <= current {
+            return None;
+        }
+        self.boss_phase = Some(next);
+        if next == BossPhase::StormShield {
+            self.shield_hp = (self.max_hp as f64 * BOSS_STORM_SHIELD_HP_FRACTION) as u32;
+        }
+        Some(next)
+    }
 }
 
 /// Calculates enemy stats for a given zone and subzone depth (1-based).
-/// Returns (hp, damage, defense) with variance applied.
-fn calc_zone_enemy_stats(zone_id: u32, subzone_depth: u32) -> (u32, u32, u32) {
-    let mut rng = rand::rng();
-    let (base_hp, hp_step, base_dmg, dmg_step, base_def, def_step) = zone_base_stats(zone_id);
-
-    let depth_offset = subzone_depth.saturating_sub(1);
-    let raw_hp = base_hp + depth_offset * hp_step;
-    let raw_dmg = base_dmg + depth_offset * dmg_step;
-    let raw_def = base_def + depth_offset * def_step;
+/// Returns (hp, damage, defense) rolled within the range from `enemy_stats_for`.
+fn calc_zone_enemy_stats(zone_id: u32, subzone_depth: u32, rng: &mut impl Rng) -> (u32, u32, u32) {
+    let range = enemy_stats_for(zone_id, subzone_depth);
 
-    let hp_var = rng.random_range(ENEMY_STAT_VARIANCE_MIN..ENEMY_STAT_VARIANCE_MAX);
-    let dmg_var = rng.random_range(ENEMY_STAT_VARIANCE_MIN..ENEMY_STAT_VARIANCE_MAX);
+    let hp = rng.random_range(range.hp_min..=range.hp_max);
+    let damage = rng.random_range(range.damage_min..=range.damage_max);
+    let defense = rng.random_range(range.defense_min..=range.defense_max);
 
-    let hp = ((raw_hp as f64) * hp_var).max(1.0) as u32;
-    let damage = ((raw_dmg as f64) * dmg_var).max(1.0) as u32;
-
-    (hp, damage, raw_def)
+    (hp, damage, defense)
 }
 
 /// Generates a zone-based dungeon enemy using zone_id for base stats.
 pub fn generate_dungeon_enemy(zone_id: u32) -> Enemy {
-    let (hp, damage, defense) = calc_zone_enemy_stats(zone_id, 1);
-    let name = generate_enemy_name();
+    let (hp, damage, defense) = calc_zone_enemy_stats(zone_id, 1, &mut rand::rng());
+    let name = enemy_name_for(zone_id, 1, &mut rand::rng());
     Enemy::new_with_defense(name, hp, damage, defense)
 }
 
 /// Generates a dungeon elite enemy using zone-based stats with elite multipliers.
 pub fn generate_dungeon_elite(zone_id: u32) -> Enemy {
-    let (hp, damage, defense) = calc_zone_enemy_stats(zone_id, 1);
+    let (hp, damage, defense) = calc_zone_enemy_stats(zone_id, 1, &mut rand::rng());
     let (hp_m, dmg_m, def_m) = DUNGEON_ELITE_MULTIPLIERS;
-    let name = format!("Elite {}", generate_enemy_name());
+    let name = format!("Elite {}", enemy_name_for(zone_id, 1, &mut rand::rng()));
     Enemy::new_with_defense(
         name,
         (hp as f64 * hp_m).max(1.0) as u32,
@@ -119,9 +300,9 @@ pub fn generate_dungeon_elite(zone_id: u32) -> Enemy {
 
 /// Generates a dungeon boss enemy using zone-based stats with boss multipliers.
 pub fn generate_dungeon_boss(zone_id: u32) -> Enemy {
-    let (hp, damage, defense) = calc_zone_enemy_stats(zone_id, 1);
+    let (hp, damage, defense) = calc_zone_enemy_stats(zone_id, 1, &mut rand::rng());
     let (hp_m, dmg_m, def_m) = DUNGEON_BOSS_MULTIPLIERS;
-    let name = format!("Boss {}", generate_enemy_name());
+    let name = format!("Boss {}", enemy_name_for(zone_id, 1, &mut rand::rng()));
     Enemy::new_with_defense(
         name,
         (hp as f64 * hp_m).max(1.0) as u32,
@@ -130,8 +311,8 @@ pub fn generate_dungeon_boss(zone_id: u32) -> Enemy {
     )
 }
 
-/// Gets zone-specific enemy name prefixes based on zone ID
-fn get_zone_enemy_prefixes(zone_id: u32) -> &'static [&'static str] {
+/// Zone-themed enemy name prefixes, keyed by zone ID
+fn zone_enemy_prefixes(zone_id: u32) -> &'static [&'static str] {
     match zone_id {
         1 => &["Meadow", "Field", "Flower", "Grass", "Sunny"],
         2 => &["Forest", "Shadow", "Dark", "Thorn", "Wild"],
@@ -147,8 +328,8 @@ fn get_zone_enemy_prefixes(zone_id: u32) -> &'static [&'static str] {
     }
 }
 
-/// Gets zone-specific enemy suffixes based on zone ID
-fn get_zone_enemy_suffixes(zone_id: u32) -> &'static [&'static str] {
+/// Zone-themed enemy creature names, keyed by zone ID
+fn zone_enemy_suffixes(zone_id: u32) -> &'static [&'static str] {
     match zone_id {
         1 => &["Beetle", "Rabbit", "Wasp", "Boar", "Serpent"],
         2 => &["Wolf", "Spider", "Bat", "Treant", "Wisp"],
@@ -159,34 +340,53 @@ fn get_zone_enemy_suffixes(zone_id: u32) -> &'static [&'static str] {
         7 => &["Construct", "Guardian", "Sprite", "Watcher", "Golem"],
         8 => &["Kraken", "Shark", "Naga", "Leviathan", "Siren"],
         9 => &["Griffin", "Djinn", "Sylph", "Roc", "Wyvern"],
-        10 => &["Titan", "Colossus", "Lord", "King", "Champion"],
+        10 => &["Wraith", "Titan", "Colossus", "Lord", "King", "Champion"],
         _ => &["Beast", "Horror", "Fiend", "Terror", "Monster"],
     }
 }
 
-/// Generates a zone-themed enemy name
-pub fn generate_zone_enemy_name(zone_id: u32) -> String {
-    let mut rng = rand::rng();
-    let prefixes = get_zone_enemy_prefixes(zone_id);
-    let suffixes = get_zone_enemy_suffixes(zone_id);
+/// Number of creature names each subzone draws from its zone's pool.
+const SUBZONE_CREATURE_COUNT: usize = 3;
+
+/// Generates a zone-themed enemy name, e.g. "Storm Wraith" in the Storm Citadel.
+///
+/// Names are always drawn from the zone's pool. Each subzone uses a rotating
+/// window of the zone's creatures so neighbouring subzones feel distinct.
+/// Deterministic for a given RNG state.
+pub fn enemy_name_for(zone_id: u32, subzone_id: u32, rng: &mut impl Rng) -> String {
+    let prefixes = zone_enemy_prefixes(zone_id);
+    let suffixes = zone_enemy_suffixes(zone_id);
+
+    let window_start = subzone_id.saturating_sub(1) as usize;
+    let window = SUBZONE_CREATURE_COUNT.min(suffixes.len());
 
     let prefix = prefixes[rng.random_range(0..prefixes.len())];
-    let suffix = suffixes[rng.random_range(0..suffixes.len())];
+    let suffix = suffixes[(window_start + rng.random_range(0..window)) % suffixes.len()];
 
     format!("{} {}", prefix, suffix)
 }
 
 /// Generates an enemy scaled for the current zone and subzone using static zone-based stats.
 /// Player stats are NOT used as input.
+#[allow(dead_code)]
 pub fn generate_zone_enemy(zone: &Zone, subzone: &Subzone) -> Enemy {
-    let (hp, damage, defense) = calc_zone_enemy_stats(zone.id, subzone.depth);
-    let name = generate_zone_enemy_name(zone.id);
-    Enemy::new_with_defense(name, hp, damage, defense)
+    zone_enemy(zone, subzone, &mut rand::rng())
 }
 
 /// Generates a subzone boss with the boss's actual name using zone-based static stats.
+#[allow(dead_code)]
 pub fn generate_subzone_boss(zone: &Zone, subzone: &Subzone) -> Enemy {
-    let (base_hp, base_damage, base_defense) = calc_zone_enemy_stats(zone.id, subzone.depth);
+    subzone_boss(zone, subzone, &mut rand::rng())
+}
+
+fn zone_enemy(zone: &Zone, subzone: &Subzone, rng: &mut impl Rng) -> Enemy {
+    let (hp, damage, defense) = calc_zone_enemy_stats(zone.id, subzone.depth, rng);
+    let name = enemy_name_for(zone.id, subzone.id, rng);
+    Enemy::new_with_defense(name, hp, damage, defense)
+}
+
+fn subzone_boss(zone: &Zone, subzone: &Subzone, rng: &mut impl Rng) -> Enemy {
+    let (base_hp, base_damage, base_defense) = calc_zone_enemy_stats(zone.id, subzone.depth, rng);
 
     let (hp_mult, dmg_mult, def_mult) = if subzone.boss.is_zone_boss {
         ZONE_BOSS_MULTIPLIERS
@@ -198,35 +398,95 @@ pub fn generate_subzone_boss(zone: &Zone, subzone: &Subzone) -> Enemy {
     let boss_damage = (base_damage as f64 * dmg_mult).max(1.0) as u32;
     let boss_defense = (base_defense as f64 * def_mult) as u32;
 
-    Enemy::new_with_defense(
+    let mut boss = Enemy::new_with_defense(
         subzone.boss.name.to_string(),
         boss_hp,
         boss_damage,
         boss_defense,
-    )
+    );
+    // The weapon-gated zone boss (Storm Citadel) fights in phases
+    if zone.boss_requirement.is_some() && subzone.boss.is_zone_boss {
+        boss.boss_phase = Some(BossPhase::Calm);
+    }
+    boss
 }
 
 /// Generates an enemy for the player's current zone and subzone using static zone-based stats.
+#[allow(dead_code)]
 pub fn generate_enemy_for_current_zone(zone_id: u32, subzone_id: u32) -> Enemy {
+    generate_enemy_for_current_zone_with_rng(zone_id, subzone_id, &mut rand::rng())
+}
+
+/// Same as `generate_enemy_for_current_zone`, rolling stats and name with the given RNG.
+pub fn generate_enemy_for_current_zone_with_rng(
+    zone_id: u32,
+    subzone_id: u32,
+    rng: &mut impl Rng,
+) -> Enemy {
     if let Some(zone) = get_zone(zone_id) {
         if let Some(subzone) = zone.subzones.iter().find(|s| s.id == subzone_id) {
-            return generate_zone_enemy(zone, subzone);
+            return zone_enemy(zone, subzone, rng);
         }
     }
     // Fallback: use zone 1, subzone 1 stats
-    let (hp, damage, defense) = calc_zone_enemy_stats(zone_id, 1);
-    Enemy::new_with_defense(generate_enemy_name(), hp, damage, defense)
+    let (hp, damage, defense) = calc_zone_enemy_stats(zone_id, 1, rng);
+    let name = enemy_name_for(zone_id, subzone_id, rng);
+    Enemy::new_with_defense(name, hp, damage, defense)
+}
+
+/// Generates an Elite Hunt target: a regular enemy of the current subzone with
+/// `ELITE_HUNT_MULTIPLIERS` applied, escaping at `expires_at`.
+pub fn generate_hunt_elite_with_rng(
+    zone_id: u32,
+    subzone_id: u32,
+    expires_at: i64,
+    rng: &mut impl Rng,
+) -> Enemy {
+    let base = generate_enemy_for_current_zone_with_rng(zone_id, subzone_id, rng);
+    let (hp_m, dmg_m, def_m) = ELITE_HUNT_MULTIPLIERS;
+    let mut elite = Enemy::new_with_defense(
+        format!("Alpha {}", base.name),
+        (base.max_hp as f64 * hp_m).max(1.0) as u32,
+        (base.damage as f64 * dmg_m).max(1.0) as u32,
+        (base.defense as f64 * def_m) as u32,
+    );
+    elite.hunt_expires_at = Some(expires_at);
+    elite
+}
+
+/// Generates a hydra, e.g. "Volcanic Hydra": a regular enemy of the current
+/// subzone with `HYDRA_HP_MULTIPLIER` HP split across `HYDRA_HEADS`.
+pub fn generate_hydra_with_rng(zone_id: u32, subzone_id: u32, rng: &mut impl Rng) -> Enemy {
+    let base = generate_enemy_for_current_zone_with_rng(zone_id, subzone_id, rng);
+    let prefix = base.name.split(' ').next().unwrap_or("Wild");
+    Enemy::new_with_defense(
+        format!("{} Hydra", prefix),
+        (base.max_hp as f64 * HYDRA_HP_MULTIPLIER).max(HYDRA_HEADS.len() as f64) as u32,
+        base.damage,
+        base.defense,
+    )
+    .with_segments(&HYDRA_HEADS)
 }
 
 /// Generates the subzone boss for the given zone/subzone using static zone-based stats.
+#[allow(dead_code)]
 pub fn generate_boss_for_current_zone(zone_id: u32, subzone_id: u32) -> Enemy {
+    generate_boss_for_current_zone_with_rng(zone_id, subzone_id, &mut rand::rng())
+}
+
+/// Same as `generate_boss_for_current_zone`, rolling stats with the given RNG.
+pub fn generate_boss_for_current_zone_with_rng(
+    zone_id: u32,
+    subzone_id: u32,
+    rng: &mut impl Rng,
+) -> Enemy {
     if let Some(zone) = get_zone(zone_id) {
         if let Some(subzone) = zone.subzones.iter().find(|s| s.id == subzone_id) {
-            return generate_subzone_boss(zone, subzone);
+            return subzone_boss(zone, subzone, rng);
         }
     }
     // Fallback: zone boss with zone_id stats
-    let (hp, damage, defense) = calc_zone_enemy_stats(zone_id, 1);
+    let (hp, damage, defense) = calc_zone_enemy_stats(zone_id, 1, rng);
     let (hp_m, dmg_m, def_m) = ZONE_BOSS_MULTIPLIERS;
     Enemy::new_with_defense(
         "Unknown Boss".to_string(),
@@ -236,6 +496,15 @@ pub fn generate_boss_for_current_zone(zone_id: u32, subzone_id: u32) -> Enemy {
     )
 }
 
+/// A timed combat buff granted by an on-kill affix.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CombatBuff {
+    /// Affix that granted the buff (one active buff per source)
+    pub source: AffixType,
+    pub attack_speed_percent: f64,
+    pub remaining_seconds: f64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CombatLogEntry {
     pub message: String,
@@ -262,10 +531,58 @@ pub struct CombatState {
     pub enemy_attack_timer: f64,
     pub regen_timer: f64,
     pub is_regenerating: bool,
+    /// Whether the Alchemy Lab emergency heal has been rolled against the current enemy.
+    /// Reset whenever a new enemy spawns.
+    #[serde(default)]
+    pub panic_heal_used: bool,
+    /// Ticks spent fighting the current boss; drives the enrage timer.
+    /// Reset whenever a new enemy spawns.
+    #[serde(default)]
+    pub boss_fight_ticks: u32,
+    /// Seconds of post-death invulnerability left; enemy hits deal no damage while > 0
+    #[serde(default)]
+    pub invuln_timer: f64,
+    /// Kills in a row without taking damage (transient)
+    #[serde(skip)]
+    pub combo: u32,
+    /// Seconds left on the combo milestone damage bonus (transient)
+    #[serde(skip)]
+    pub combo_bonus_timer: f64,
+    /// Mirrors the account `log_filter` setting; applied by `add_hit_log_entry`
+    #[serde(skip)]
+    pub log_filter: crate::utils::settings::LogFilter,
+    /// Mirrors the account `hp_display` setting; read by the HP bar renderers
+    #[serde(skip)]
+    pub hp_display: crate::utils::settings::HpDisplay,
+    /// `ends_at` of the last Elite Hunt whose elite spawned (one per hunt)
+    #[serde(default)]
+    pub last_elite_hunt_end: i64,
     #[serde(skip)]
     pub visual_effects: Vec<crate::ui::combat_effects::VisualEffect>,
+    /// Mirrors the account `max_visual_effects` setting; enforced by `push_visual_effect`
+    #[serde(skip, default = "default_max_visual_effects")]
+    pub max_visual_effects: usize,
     #[serde(skip)]
     pub combat_log: VecDeque<CombatLogEntry>,
+    /// Timed buffs from on-kill procs (transient)
+    #[serde(skip)]
+    pub buffs: Vec<CombatBuff>,
+    /// Fast-path state while farming trivial fights (transient)
+    #[serde(skip)]
+    pub fast_resolve: FastResolve,
+}
+
+fn default_max_visual_effects() -> usize {
+    MAX_VISUAL_EFFECTS
+}
+
+/// `game_tick`'s fast path for trivial fights (see `fast_resolve_combat`).
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct FastResolve {
+    /// Seconds per kill, set when the current enemy spawned as a trivial fight
+    pub kill_seconds: Option<f64>,
+    /// Time banked toward the next batch of kills
+    pub elapsed: f64,
 }
 
 impl Default for CombatState {
@@ -284,11 +601,59 @@ impl CombatState {
             enemy_attack_timer: 0.0,
             regen_timer: 0.0,
             is_regenerating: false,
+            panic_heal_used: false,
+            boss_fight_ticks: 0,
+            invuln_timer: 0.0,
+            combo: 0,
+            combo_bonus_timer: 0.0,
+            log_filter: crate::utils::settings::LogFilter::All,
+            hp_display: crate::utils::settings::HpDisplay::Numbers,
+            last_elite_hunt_end: 0,
             visual_effects: Vec::new(),
+            max_visual_effects: MAX_VISUAL_EFFECTS,
             combat_log: VecDeque::with_capacity(COMBAT_LOG_CAPACITY),
+            buffs: Vec::new(),
+            fast_resolve: FastResolve::default(),
         }
     }
 
+    /// Adds a buff. A buff from the same source refreshes the existing one
+    /// instead of stacking.
+    pub fn push_buff(&mut self, buff: CombatBuff) {
+        if let Some(existing) = self.buffs.iter_mut().find(|b| b.source == buff.source) {
+            *existing = buff;
+        } else {
+            self.buffs.push(buff);
+        }
+    }
+
+    /// Counts down buff durations and drops expired buffs.
+    pub fn decay_buffs(&mut self, delta_time: f64) {
+        for buff in &mut self.buffs {
+            buff.remaining_seconds -= delta_time;
+        }
+        self.buffs.retain(|b| b.remaining_seconds > 0.0);
+    }
+
+    /// Total attack speed bonus from active buffs, in percent.
+    pub fn buff_attack_speed_percent(&self) -> f64 {
+        self.buffs.iter().map(|b| b.attack_speed_percent).sum()
+    }
+
+    /// Seconds between player attacks for the given attack speed multiplier,
+    /// including active buffs such as Berserker Rage.
+    pub fn player_attack_interval(&self, attack_speed_multiplier: f64) -> f64 {
+        let buff_multiplier = 1.0 + self.buff_attack_speed_percent() / 100.0;
+        ATTACK_INTERVAL_SECONDS / (attack_speed_multiplier * buff_multiplier)
+    }
+
+    /// Fraction of the way to the next player attack, clamped to 0.0..=1.0.
+    /// The combat scene renders this as a filling gauge.
+    pub fn attack_progress(&self, attack_speed_multiplier: f64) -> f64 {
+        (self.player_attack_timer / self.player_attack_interval(attack_speed_multiplier))
+            .clamp(0.0, 1.0)
+    }
+
     pub fn add_log_entry(&mut self, message: String, is_crit: bool, is_player_action: bool) {
         // Keep only the last 10 entries
         if self.combat_log.len() >= COMBAT_LOG_CAPACITY {
@@ -301,6 +666,28 @@ impl CombatState {
         });
     }
 
+    /// Adds a visual effect, dropping the oldest ones past `max_visual_effects`
+    /// so very fast attack speeds can't pile up effects faster than they expire.
+    pub fn push_visual_effect(&mut self, effect: crate::ui::combat_effects::VisualEffect) {
+        self.visual_effects.push(effect);
+        let excess = self
+            .visual_effects
+            .len()
+            .saturating_sub(self.max_visual_effects);
+        self.visual_effects.drain(..excess);
+    }
+
+    /// Logs a player hit. Routine (non-crit) hits are dropped under
+    /// `LogFilter::Important`, since fast builds would flood the log with them.
+    /// Kills, loot, and other player actions go through `add_log_entry` and
+    /// are always kept.
+    pub fn add_hit_log_entry(&mut self, message: String, is_crit: bool) {
+        if !is_crit && self.log_filter == crate::utils::settings::LogFilter::Important {
+            return;
+        }
+        self.add_log_entry(message, is_crit, true);
+    }
+
     pub fn update_max_hp(&mut self, new_max_hp: u32) {
         self.player_max_hp = new_max_hp;
         // If HP exceeds new max, cap it
@@ -312,12 +699,148 @@ impl CombatState {
     pub fn is_player_alive(&self) -> bool {
         self.player_current_hp > 0
     }
+
+    /// Counts a kill toward the combo. Every `COMBO_MILESTONE_KILLS` in a row
+    /// (re)starts the combo damage bonus; returns true when that happens.
+    pub fn record_combo_kill(&mut self) -> bool {
+        self.combo += 1;
+        if self.combo.is_multiple_of(COMBO_MILESTONE_KILLS) {
+            self.combo_bonus_timer = COMBO_BONUS_DURATION_SECONDS;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Damage multiplier from an active combo milestone bonus (1.0 without one).
+    pub fn combo_damage_multiplier(&self) -> f64 {
+        if self.combo_bonus_timer > 0.0 {
+            1.0 + COMBO_DAMAGE_BONUS_PERCENT / 100.0
+        } else {
+            1.0
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_info_summary_shows_stats_and_modifiers() {
+        let mut enemy = Enemy::new("Storm Lord".to_string(), 1000, 42);
+        enemy.defense = 17;
+        assert_eq!(enemy.info_summary(2.0), "DMG 42 | DEF 17 | 2.0s/atk");
+
+        enemy.boss_phase = Some(BossPhase::StormShield);
+        enemy.shield_hp = 250;
+        let summary = enemy.info_summary(1.2);
+        assert!(summary.starts_with("DMG 42 | DEF 17 | 1.2s/atk"));
+        assert!(summary.contains("Storm Shield"));
+        assert!(summary.contains("Shield 250"));
+    }
+
+    #[test]
+    fn test_only_storm_citadel_zone_boss_is_phased() {
+        for zone in crate::zones::get_all_zones() {
+            for subzone in &zone.subzones {
+                let boss = generate_subzone_boss(zone, subzone);
+                let phased = zone.boss_requirement.is_some() && subzone.boss.is_zone_boss;
+                assert_eq!(boss.boss_phase.is_some(), phased, "{}", boss.name);
+            }
+        }
+    }
+
+    #[test]
+    fn test_boss_phase_reset_drops_shield() {
+        let mut boss = Enemy::new("Eternal Storm".to_string(), 100, 10);
+        boss.boss_phase = Some(BossPhase::Calm);
+        boss.take_damage(80);
+        assert_eq!(boss.advance_boss_phase(), Some(BossPhase::StormShield));
+        assert_eq!(boss.advance_boss_phase(), None);
+
+        boss.reset_hp();
+        assert_eq!(boss.boss_phase, Some(BossPhase::Calm));
+        assert_eq!(boss.shield_hp, 0);
+    }
+
+    #[test]
+    fn test_segments_are_destroyed_in_order() {
+        let mut hydra = Enemy::new("Ember Hydra".to_string(), 90, 10).with_segments(&HYDRA_HEADS);
+        assert_eq!(hydra.living_segments(), 3);
+        assert_eq!(hydra.current_hp, 90);
+
+        // Overkill on a head is lost, not carried to the next one
+        hydra.take_damage(50);
+        assert_eq!(hydra.segments[0].current_hp, 0);
+        assert_eq!(hydra.segments[1].current_hp, 30);
+        assert_eq!(hydra.current_segment().unwrap().name, "Middle Head");
+        assert_eq!(hydra.current_hp, 60);
+        assert!(hydra.is_alive());
+
+        hydra.take_damage(30);
+        assert_eq!(hydra.current_segment().unwrap().name, "Right Head");
+        assert!(hydra.is_alive());
+
+        hydra.take_damage(29);
+        assert!(hydra.is_alive());
+        hydra.take_damage(1);
+        assert_eq!(hydra.living_segments(), 0);
+        assert!(!hydra.is_alive());
+    }
+
+    #[test]
+    fn test_segments_split_hp_and_reset() {
+        let mut hydra = Enemy::new("Ember Hydra".to_string(), 100, 10).with_segments(&HYDRA_HEADS);
+        let hp: Vec<u32> = hydra.segments.iter().map(|s| s.max_hp).collect();
+        assert_eq!(hp, vec![34, 33, 33]);
+
+        hydra.take_damage(40);
+        hydra.reset_hp();
+        assert_eq!(hydra.living_segments(), 3);
+        assert_eq!(hydra.current_hp, 100);
+    }
+
+    #[test]
+    fn test_generate_hydra_has_three_heads() {
+        let mut rng = rand::rng();
+        let hydra = generate_hydra_with_rng(5, 1, &mut rng);
+        assert!(hydra.name.ends_with("Hydra"));
+        assert_eq!(hydra.segments.len(), HYDRA_HEADS.len());
+        assert_eq!(
+            hydra.segments.iter().map(|s| s.max_hp).sum::<u32>(),
+            hydra.max_hp
+        );
+    }
+
+    #[test]
+    fn test_attack_progress_fraction_and_clamp() {
+        let mut combat = CombatState::new(100);
+        combat.player_attack_timer = ATTACK_INTERVAL_SECONDS / 2.0;
+        assert!((combat.attack_progress(1.0) - 0.5).abs() < 1e-9);
+
+        // Twice the attack speed: the same timer is a full interval
+        assert!((combat.attack_progress(2.0) - 1.0).abs() < 1e-9);
+
+        combat.player_attack_timer = ATTACK_INTERVAL_SECONDS * 3.0;
+        assert_eq!(combat.attack_progress(1.0), 1.0);
+    }
+
+    #[test]
+    fn test_generated_zone_enemies_within_reported_range() {
+        for zone in crate::zones::get_all_zones() {
+            for subzone in &zone.subzones {
+                let range = enemy_stats_for(zone.id, subzone.depth);
+                for _ in 0..50 {
+                    let enemy = generate_zone_enemy(zone, subzone);
+                    assert!((range.hp_min..=range.hp_max).contains(&enemy.max_hp));
+                    assert!((range.damage_min..=range.damage_max).contains(&enemy.damage));
+                    assert!((range.defense_min..=range.defense_max).contains(&enemy.defense));
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_enemy_creation() {
         let enemy = Enemy::new("Test Orc".to_string(), 50, 10);
@@ -356,13 +879,6 @@ mod tests {
         assert_eq!(enemy.current_hp, 50);
     }
 
-    #[test]
-    fn test_generate_enemy_name() {
-        let name = generate_enemy_name();
-        assert!(!name.is_empty());
-        assert!(name.contains(' ')); // Should have space between parts
-    }
-
     #[test]
     fn test_generate_dungeon_enemy() {
         let enemy = generate_dungeon_enemy(1);
@@ -412,15 +928,48 @@ mod tests {
     }
 
     #[test]
-    fn test_generate_zone_enemy_name() {
-        // Test zone 1 (Meadow)
-        let name = generate_zone_enemy_name(1);
-        assert!(!name.is_empty());
-        assert!(name.contains(' ')); // Should have space between prefix and suffix
+    fn test_enemy_name_for_draws_from_zone_pool() {
+        let mut rng = rand::rng();
+        for zone_id in 1..=11 {
+            for subzone_id in 1..=4 {
+                for _ in 0..50 {
+                    let name = enemy_name_for(zone_id, subzone_id, &mut rng);
+                    let (prefix, suffix) = name.split_once(' ').unwrap();
+                    assert!(
+                        zone_enemy_prefixes(zone_id).contains(&prefix),
+                        "{name} prefix not in zone {zone_id} pool"
+                    );
+                    assert!(
+                        zone_enemy_suffixes(zone_id).contains(&suffix),
+                        "{name} suffix not in zone {zone_id} pool"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_enemy_name_for_is_deterministic() {
+        use rand::SeedableRng;
+        let mut a = rand_chacha::ChaCha8Rng::seed_from_u64(7);
+        let mut b = rand_chacha::ChaCha8Rng::seed_from_u64(7);
+        for _ in 0..20 {
+            assert_eq!(enemy_name_for(10, 2, &mut a), enemy_name_for(10, 2, &mut b));
+        }
+    }
 
-        // Test zone 10 (Storm Citadel)
-        let name10 = generate_zone_enemy_name(10);
-        assert!(!name10.is_empty());
+    #[test]
+    fn test_subzones_draw_different_creatures() {
+        let mut rng = rand::rng();
+        let creatures = |subzone_id: u32, rng: &mut rand::rngs::ThreadRng| {
+            let mut seen = std::collections::HashSet::new();
+            for _ in 0..200 {
+                let name = enemy_name_for(10, subzone_id, rng);
+                seen.insert(name.split_once(' ').unwrap().1.to_string());
+            }
+            seen
+        };
+        assert_ne!(creatures(1, &mut rng), creatures(4, &mut rng));
     }
 
     #[test]
@@ -439,6 +988,18 @@ mod tests {
         assert_eq!(enemy.defense, 0); // Zone 1 has 0 base defense
     }
 
+    #[test]
+    fn test_zone_10_enemy_defense_exceeds_zone_1() {
+        use crate::zones::get_all_zones;
+
+        let zones = get_all_zones();
+        let zone1 = generate_zone_enemy(&zones[0], &zones[0].subzones[0]);
+        let zone10 = generate_zone_enemy(&zones[9], &zones[9].subzones[0]);
+
+        assert_eq!(zone10.defense, enemy_stats_for(10, 1).defense_min);
+        assert!(zone10.defense > zone1.defense);
+    }
+
     #[test]
     fn test_zone_enemy_static_scaling() {
         use crate::zones::get_all_zones;
@@ -543,4 +1104,57 @@ mod tests {
         assert!(e1.max_hp >= 1);
         assert!(e3.max_hp >= 1);
     }
+
+    #[test]
+    fn test_important_log_filter_drops_only_routine_hits() {
+        let mut combat = CombatState::new(100);
+        combat.log_filter = crate::utils::settings::LogFilter::Important;
+
+        combat.add_hit_log_entry("You hit for 12 damage".to_string(), false);
+        combat.add_hit_log_entry("CRITICAL HIT for 30 damage!".to_string(), true);
+        combat.add_log_entry("Found: Rusty Sword [Common]".to_string(), false, true);
+
+        let messages: Vec<&str> = combat
+            .combat_log
+            .iter()
+            .map(|e| e.message.as_str())
+            .collect();
+        assert_eq!(
+            messages,
+            vec!["CRITICAL HIT for 30 damage!", "Found: Rusty Sword [Common]"]
+        );
+
+        // With the default filter every hit is logged
+        combat.log_filter = crate::utils::settings::LogFilter::All;
+        combat.add_hit_log_entry("You hit for 12 damage".to_string(), false);
+        assert_eq!(combat.combat_log.len(), 3);
+    }
+
+    #[test]
+    fn test_visual_effects_are_capped_keeping_the_newest() {
+        use crate::ui::combat_effects::{EffectType, VisualEffect};
+
+        let mut combat = CombatState::new(100);
+        for value in 0..500 {
+            combat.push_visual_effect(VisualEffect::new(
+                EffectType::DamageNumber {
+                    value,
+                    is_crit: false,
+                },
+                0.8,
+            ));
+        }
+
+        assert_eq!(combat.visual_effects.len(), MAX_VISUAL_EFFECTS);
+        let values: Vec<u32> = combat
+            .visual_effects
+            .iter()
+            .map(|effect| match effect.effect_type {
+                EffectType::DamageNumber { value, .. } => value,
+                _ => unreachable!(),
+            })
+            .collect();
+        let newest: Vec<u32> = (500 - MAX_VISUAL_EFFECTS as u32..500).collect();
+        assert_eq!(values, newest);
+    }
 }
@@ -2,7 +2,35 @@
 pub const TICK_INTERVAL_MS: u64 = 100;
 pub const ATTACK_INTERVAL_SECONDS: f64 = 1.5;
 pub const HP_REGEN_DURATION_SECONDS: f64 = 2.5;
+/// Emergency heal (Alchemy Lab) triggers when HP falls below this fraction of max
+pub const EMERGENCY_HEAL_HP_THRESHOLD: f64 = 0.25;
+/// Fraction of max HP restored by an emergency heal
+pub const EMERGENCY_HEAL_RESTORE_FRACTION: f64 = 0.5;
 pub const _ENEMY_RESPAWN_SECONDS: f64 = 2.5;
+/// Grace period after a death during which enemy attacks deal no damage
+pub const RESPAWN_INVULN_SECONDS: f64 = 2.0;
+/// How long an on-kill rage buff (OnKillRage affix) lasts
+pub const ON_KILL_RAGE_DURATION_SECONDS: f64 = 5.0;
+/// Fraction of damage/defense a non-Balanced combat stance shifts between the two
+pub const COMBAT_STANCE_SHIFT: f64 = 0.2;
+/// Every this many kills in a row without taking damage grants the combo bonus
+pub const COMBO_MILESTONE_KILLS: u32 = 10;
+/// Damage bonus (percent) while a combo milestone bonus is active
+pub const COMBO_DAMAGE_BONUS_PERCENT: f64 = 10.0;
+/// How long a combo milestone bonus lasts
+pub const COMBO_BONUS_DURATION_SECONDS: f64 = 10.0;
+/// Adaptive intensity: kills/min at or above this ramps overworld enemy stats up
+pub const ADAPTIVE_FAST_KILLS_PER_MINUTE: f64 = 20.0;
+/// Adaptive intensity: kills/min below this lets the multiplier decay
+pub const ADAPTIVE_SLOW_KILLS_PER_MINUTE: f64 = 6.0;
+/// Multiplier gained per second of fast killing (1.0 → 2.0 in ~8 minutes)
+pub const ADAPTIVE_RAMP_PER_SECOND: f64 = 0.002;
+/// Multiplier lost per second of slow killing
+pub const ADAPTIVE_DECAY_PER_SECOND: f64 = 0.005;
+/// Multiplier lost on each overworld death
+pub const ADAPTIVE_DEATH_PENALTY: f64 = 0.25;
+/// Cap on the adaptive enemy stat multiplier
+pub const ADAPTIVE_MAX_MULTIPLIER: f64 = 2.0;
 
 // Enemy attack timing (by tier)
 pub const ENEMY_ATTACK_INTERVAL_SECONDS: f64 = 2.0;
@@ -10,9 +38,25 @@ pub const ENEMY_BOSS_ATTACK_INTERVAL_SECONDS: f64 = 1.8;
 pub const ENEMY_ZONE_BOSS_ATTACK_INTERVAL_SECONDS: f64 = 1.5;
 pub const ENEMY_DUNGEON_ELITE_ATTACK_INTERVAL_SECONDS: f64 = 1.6;
 pub const ENEMY_DUNGEON_BOSS_ATTACK_INTERVAL_SECONDS: f64 = 1.4;
+/// Boss fights lasting longer than this many ticks (60s) enrage the boss
+pub const BOSS_ENRAGE_THRESHOLD_TICKS: u32 = 600;
+/// Extra boss damage per tick past the enrage threshold (+100% every 10s)
+pub const BOSS_ENRAGE_DAMAGE_PER_TICK: f64 = 0.01;
+/// Storm Citadel final boss enters the Tempest phase at or below this HP fraction
+pub const BOSS_TEMPEST_HP_THRESHOLD: f64 = 0.66;
+/// ...and raises its storm shield at or below this HP fraction
+pub const BOSS_STORM_SHIELD_HP_THRESHOLD: f64 = 0.33;
+/// Attack interval multiplier from the Tempest phase onward (lower = faster)
+pub const BOSS_TEMPEST_ATTACK_INTERVAL_MULTIPLIER: f64 = 0.6;
+/// Storm shield strength as a fraction of the boss's max HP
+pub const BOSS_STORM_SHIELD_HP_FRACTION: f64 = 0.2;
 pub const AUTOSAVE_INTERVAL_SECONDS: u64 = 30;
+/// How often `--stats-file` rewrites the overlay snapshot
+pub const STATS_FILE_INTERVAL_SECONDS: u64 = 2;
 pub const UPDATE_CHECK_INTERVAL_SECONDS: u64 = 30 * 60; // 30 minutes
 pub const UPDATE_CHECK_JITTER_SECONDS: u64 = 5 * 60; // ±5 minutes jitter
+/// Longest wait between update checks after repeated failures
+pub const UPDATE_CHECK_MAX_BACKOFF_SECONDS: u64 = 4 * 60 * 60; // 4 hours
 
 // XP and leveling
 pub const BASE_XP_PER_TICK: f64 = 1.0;
@@ -20,6 +64,10 @@ pub const XP_CURVE_BASE: f64 = 100.0;
 pub const XP_CURVE_EXPONENT: f64 = 1.5;
 pub const COMBAT_XP_MIN_TICKS: u64 = 200;
 pub const COMBAT_XP_MAX_TICKS: u64 = 400;
+/// Seconds of trivial farming resolved together in one fast-resolve batch
+pub const FAST_RESOLVE_BATCH_SECONDS: f64 = 10.0;
+/// Headroom over the largest legitimate kill award before `verify_kill_xp` clamps it
+pub const KILL_XP_SANITY_MULTIPLIER: f64 = 10.0;
 pub const OFFLINE_MULTIPLIER: f64 = 0.25;
 pub const MAX_OFFLINE_SECONDS: i64 = 7 * 24 * 60 * 60;
 
@@ -33,6 +81,8 @@ pub const LEVEL_UP_ATTRIBUTE_POINTS: u32 = 3;
 // Prestige multiplier formula: 1.0 + BASE_FACTOR * rank^EXPONENT
 pub const PRESTIGE_MULT_BASE_FACTOR: f64 = 0.5;
 pub const PRESTIGE_MULT_EXPONENT: f64 = 0.7;
+/// Percent of current XP banked on prestige and refunded to the fresh character
+pub const PRESTIGE_BANKED_XP_PERCENT: u64 = 10;
 
 // Item drops
 pub const ITEM_DROP_BASE_CHANCE: f64 = 0.15;
@@ -52,6 +102,20 @@ pub const HAVEN_DISCOVERY_BASE_CHANCE: f64 = 0.000014;
 pub const HAVEN_DISCOVERY_RANK_BONUS: f64 = 0.000007;
 pub const HAVEN_MIN_PRESTIGE_RANK: u32 = 10;
 
+// Challenge of the day: winning the featured minigame multiplies level XP
+pub const FEATURED_CHALLENGE_XP_MULTIPLIER: u32 = 2;
+pub const FEATURED_CHALLENGE_MIN_XP_PERCENT: u32 = 50;
+
+// Auto-challenge: a background board game still running after this many
+// player turns is scored as a loss
+pub const AUTO_CHALLENGE_MAX_MOVES: u32 = 150;
+
+// Challenge prestige rewards grow slowly past P10: +2% per rank above it, at
+// most 1.5x, rounded down (rewards below P10 and 1-rank rewards never change)
+pub const CHALLENGE_REWARD_SCALING_START_PRESTIGE: u32 = 10;
+pub const CHALLENGE_REWARD_SCALING_PER_PRESTIGE: f64 = 0.02;
+pub const CHALLENGE_REWARD_SCALING_MAX: f64 = 1.5;
+
 // Fishing ranks
 pub const BASE_MAX_FISHING_RANK: u32 = 30;
 pub const MAX_FISHING_RANK: u32 = 40;
@@ -85,6 +149,22 @@ pub const SUBZONE_BOSS_MULTIPLIERS: (f64, f64, f64) = (3.0, 1.5, 1.8);
 pub const ZONE_BOSS_MULTIPLIERS: (f64, f64, f64) = (5.0, 1.8, 2.5);
 pub const DUNGEON_ELITE_MULTIPLIERS: (f64, f64, f64) = (2.2, 1.5, 1.6);
 pub const DUNGEON_BOSS_MULTIPLIERS: (f64, f64, f64) = (3.5, 1.8, 2.0);
+pub const ELITE_HUNT_MULTIPLIERS: (f64, f64, f64) = (4.0, 2.0, 2.0);
+
+// Elite Hunt world event: each window opens with a hunt for its first ACTIVE seconds
+pub const WORLD_EVENT_WINDOW_SECONDS: i64 = 3 * 3600;
+pub const ELITE_HUNT_ACTIVE_SECONDS: i64 = 1200;
+/// Chance an overworld spawn during a hunt is the hunted elite (one elite per hunt)
+pub const ELITE_HUNT_SPAWN_CHANCE: f64 = 0.05;
+
+/// First zone where multi-headed hydras can replace regular spawns
+pub const HYDRA_MIN_ZONE: u32 = 5;
+/// Chance a regular overworld spawn there is a hydra
+pub const HYDRA_SPAWN_CHANCE: f64 = 0.03;
+/// A hydra's total HP relative to the enemy it replaces (split across heads)
+pub const HYDRA_HP_MULTIPLIER: f64 = 1.5;
+/// Chance the hunted elite's guaranteed drop is Legendary rather than Epic
+pub const ELITE_HUNT_LEGENDARY_CHANCE: f64 = 0.25;
 
 // Prestige combat bonus formulas
 pub const PRESTIGE_FLAT_DAMAGE_FACTOR: f64 = 5.0;
@@ -96,6 +176,10 @@ pub const PRESTIGE_CRIT_CAP: f64 = 15.0;
 pub const PRESTIGE_FLAT_HP_FACTOR: f64 = 15.0;
 pub const PRESTIGE_FLAT_HP_EXPONENT: f64 = 0.6;
 
+// Prestige streak: +% damage per consecutive deathless prestige, lost on any death
+pub const PRESTIGE_STREAK_DAMAGE_PERCENT: f64 = 2.0;
+pub const PRESTIGE_STREAK_DAMAGE_CAP_PERCENT: f64 = 20.0;
+
 // Derived stat formulas
 pub const BASE_HP: i32 = 50;
 pub const HP_PER_CON_MODIFIER: i32 = 10;
@@ -109,6 +193,24 @@ pub const BASE_CRIT_MULTIPLIER: f64 = 2.0;
 pub const PRESTIGE_MULT_PER_CHA_MODIFIER: f64 = 0.1;
 pub const AFFIX_PERCENT_DIVISOR: f64 = 100.0;
 
+// Curse affixes: rare trade-offs rolled on top of Epic/Legendary affixes
+pub const CURSE_AFFIX_CHANCE: f64 = 0.10;
+/// Percent damage gained per percent of defense lost to FragileArmor
+pub const FRAGILE_ARMOR_DAMAGE_RATIO: f64 = 1.5;
+
+// Sockets and gems
+/// Chance for each potential socket to roll on a Rare/Epic/Legendary item
+pub const SOCKET_ROLL_CHANCE: f64 = 0.5;
+/// Chance a regular enemy kill also drops a tier-1 gem
+pub const GEM_DROP_CHANCE: f64 = 0.02;
+/// Highest gem tier
+pub const GEM_MAX_TIER: u8 = 3;
+
+// Parry: DEX-derived chance to negate an enemy hit and counterattack
+pub const PARRY_CHANCE_PER_DEX_MODIFIER: f64 = 0.5;
+pub const PARRY_CHANCE_CAP_PERCENT: f64 = 15.0;
+pub const PARRY_COUNTER_DAMAGE_MULTIPLIER: f64 = 0.5;
+
 // Mob rarity distribution thresholds
 pub const MOB_RARITY_COMMON_BASE: f64 = 0.60;
 pub const MOB_RARITY_MAGIC_BASE: f64 = 0.28;
@@ -132,6 +234,18 @@ pub const FISHING_SESSION_MAX_FISH: u32 = 8;
 pub const FISH_RARITY_COMMON_FLOOR: f64 = 10.0;
 pub const FISH_RARITY_BONUS_INTERVAL: u32 = 5;
 
+// Fishing hotspots: each window boosts one rarity for its first ACTIVE seconds
+pub const FISHING_HOTSPOT_WINDOW_SECONDS: i64 = 3600;
+pub const FISHING_HOTSPOT_ACTIVE_SECONDS: i64 = 900;
+pub const FISHING_HOTSPOT_WEIGHT_MULTIPLIER: f64 = 2.0;
+
+// Fishing tournaments: each window opens with a tournament lasting ACTIVE seconds
+pub const FISHING_TOURNAMENT_WINDOW_SECONDS: i64 = 6 * 3600;
+pub const FISHING_TOURNAMENT_ACTIVE_SECONDS: i64 = 3600;
+pub const FISHING_TOURNAMENT_BRONZE_SCORE: u32 = 50;
+pub const FISHING_TOURNAMENT_SILVER_SCORE: u32 = 150;
+pub const FISHING_TOURNAMENT_GOLD_SCORE: u32 = 400;
+
 // Fishing item drop chances by fish rarity
 pub const FISHING_DROP_CHANCE_COMMON: f64 = 0.05;
 pub const FISHING_DROP_CHANCE_UNCOMMON: f64 = 0.05;
@@ -139,6 +253,23 @@ pub const FISHING_DROP_CHANCE_RARE: f64 = 0.15;
 pub const FISHING_DROP_CHANCE_EPIC: f64 = 0.35;
 pub const FISHING_DROP_CHANCE_LEGENDARY: f64 = 0.75;
 
+// Fishing rods (FishingGear)
+pub const FISHING_ROD_DROP_CHANCE: f64 = 0.02;
+pub const FISHING_ROD_CATCH_SPEED_CAP_PERCENT: f64 = 50.0;
+/// Salvage materials to craft a rod, indexed by FishRarity
+pub const FISHING_ROD_CRAFT_COSTS: [u64; 5] = [20, 60, 150, 400, 1000];
+
+// Fishing bait (GameState::active_bait)
+/// Reels one bait lasts
+pub const BAIT_CASTS: u32 = 10;
+pub const BAIT_DROP_CHANCE: f64 = 0.05;
+/// Salvage materials to craft one bait
+pub const BAIT_CRAFT_COST: u64 = 15;
+/// Glimmer Bait: +% chance of Rare or better fish
+pub const BAIT_RARE_CHANCE_PERCENT: f64 = 50.0;
+/// Quickbite Bait: -% fishing timers
+pub const BAIT_CATCH_SPEED_PERCENT: f64 = 25.0;
+
 // Prestige level requirements
 pub const PRESTIGE_HIGH_RANK_THRESHOLD: u32 = 19;
 pub const PRESTIGE_HIGH_RANK_BASE_LEVEL: u32 = 220;
@@ -151,6 +282,12 @@ pub const DUNGEON_PRESTIGE_PER_SIZE_TIER: u32 = 2;
 pub const DUNGEON_SIZE_VARIATION_DOWN: f64 = 0.2;
 pub const DUNGEON_SIZE_VARIATION_UP: f64 = 0.8;
 
+// Dungeon difficulty (rolled at discovery)
+pub const DUNGEON_HARD_PRESTIGE_REQUIREMENT: u32 = 5;
+pub const DUNGEON_NIGHTMARE_PRESTIGE_REQUIREMENT: u32 = 10;
+pub const DUNGEON_HARD_CHANCE: f64 = 0.25;
+pub const DUNGEON_NIGHTMARE_CHANCE: f64 = 0.10;
+
 // Level-up point distribution
 pub const LEVEL_UP_MAX_DISTRIBUTION_ATTEMPTS: u32 = 100;
 
@@ -164,6 +301,9 @@ pub const TICKS_PER_SECOND: u32 = 10;
 // Combat log and recent drops
 pub const COMBAT_LOG_CAPACITY: usize = 10;
 
+// Default cap on live combat visual effects (oldest are dropped past it)
+pub const MAX_VISUAL_EFFECTS: usize = 200;
+
 // Enemy stat variance
 pub const ENEMY_STAT_VARIANCE_MIN: f64 = 0.9;
 pub const ENEMY_STAT_VARIANCE_MAX: f64 = 1.1;
@@ -174,6 +314,8 @@ pub const NUM_EQUIPMENT_SLOTS: u32 = 7;
 // Character management
 pub const CHARACTER_NAME_MAX_LENGTH: usize = 16;
 pub const SAVE_FILE_VERSION: u32 = 2;
+/// Deleted saves are kept in `~/.quest/deleted/` this long before being purged
+pub const DELETED_CHARACTER_RETENTION_SECONDS: u64 = 7 * 24 * 3600;
 
 // Dungeon generation
 pub const DUNGEON_EXTRA_CONNECTION_CHANCE: f64 = 0.15;
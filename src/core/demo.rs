@@ -0,0 +1,132 @@
+//! Pre-seeded character for `quest --demo` (streams, store-page captures).
+//!
+//! The demo character is mid-progression: prestiged, partway through a gated
+//! zone, and fully geared, so the attract screen shows real fights and drops
+//! rather than a level 1 character in the meadow. Everything is rolled from a
+//! fixed seed, so every launch starts from the same state.
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use super::game_logic::distribute_level_up_points_with_rng;
+use super::game_state::GameState;
+use super::tutorial::TutorialStep;
+use crate::character::derived_stats::DerivedStats;
+use crate::items::{generate_item_with_rng, ilvl_for_zone, EquipmentSlot, Rarity};
+use crate::zones::get_zone;
+
+pub const DEMO_CHARACTER_NAME: &str = "Demo Hero";
+pub const DEMO_LEVEL: u32 = 60;
+pub const DEMO_PRESTIGE_RANK: u32 = 6;
+/// Zone 3 (Mountain Pass) is the first prestige-gated zone
+pub const DEMO_ZONE_ID: u32 = 3;
+pub const DEMO_SUBZONE_ID: u32 = 2;
+
+const DEMO_SEED: u64 = 0xDE30;
+/// STR-leaning melee build (STR, DEX, CON, INT, WIS, CHA)
+const DEMO_ATTRIBUTE_WEIGHTS: [u32; 6] = [4, 2, 3, 0, 1, 0];
+
+const DEMO_SLOTS: [EquipmentSlot; 7] = [
+    EquipmentSlot::Weapon,
+    EquipmentSlot::Armor,
+    EquipmentSlot::Helmet,
+    EquipmentSlot::Gloves,
+    EquipmentSlot::Boots,
+    EquipmentSlot::Amulet,
+    EquipmentSlot::Ring,
+];
+
+/// Builds the demo character. Identical for every call except `last_save_time`.
+pub fn build_demo_state(current_time: i64) -> GameState {
+    let mut rng = ChaCha8Rng::seed_from_u64(DEMO_SEED);
+    let mut state = GameState::new(DEMO_CHARACTER_NAME.to_string(), current_time);
+    state.character_id = "demo".to_string();
+    state.tutorial.step = TutorialStep::Complete;
+
+    state.prestige_rank = DEMO_PRESTIGE_RANK;
+    state.total_prestige_count = DEMO_PRESTIGE_RANK as u64;
+    state.auto_allocate = Some(DEMO_ATTRIBUTE_WEIGHTS);
+    while state.character_level < DEMO_LEVEL {
+        state.character_level += 1;
+        distribute_level_up_points_with_rng(&mut state, &mut rng);
+    }
+
+    // Every boss before the demo subzone is down, as if played up to here
+    let progression = &mut state.zone_progression;
+    progression.reset_for_prestige(DEMO_PRESTIGE_RANK);
+    for zone_id in 1..DEMO_ZONE_ID {
+        let subzones = get_zone(zone_id).map_or(0, |z| z.subzones.len() as u32);
+        for subzone_id in 1..=subzones {
+            progression.defeated_bosses.push((zone_id, subzone_id));
+        }
+    }
+    for subzone_id in 1..DEMO_SUBZONE_ID {
+        progression.defeated_bosses.push((DEMO_ZONE_ID, subzone_id));
+    }
+    progression.current_zone_id = DEMO_ZONE_ID;
+    progression.current_subzone_id = DEMO_SUBZONE_ID;
+
+    let ilvl = ilvl_for_zone(DEMO_ZONE_ID as usize);
+    for slot in DEMO_SLOTS {
+        let item = generate_item_with_rng(slot, Rarity::Rare, ilvl, &mut rng);
+        state.equipment.set(slot, Some(item));
+    }
+
+    let derived = DerivedStats::calculate_derived_stats(&state.attributes, &state.equipment);
+    state.combat_state.update_max_hp(derived.max_hp);
+    state.combat_state.player_current_hp = state.combat_state.player_max_hp;
+
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::character::attributes::AttributeType;
+
+    #[test]
+    fn test_demo_state_is_mid_progression_and_geared() {
+        let state = build_demo_state(0);
+
+        assert_eq!(state.character_name, DEMO_CHARACTER_NAME);
+        assert_eq!(state.character_level, DEMO_LEVEL);
+        assert_eq!(state.prestige_rank, DEMO_PRESTIGE_RANK);
+        assert_eq!(state.zone_progression.current_zone_id, DEMO_ZONE_ID);
+        assert_eq!(state.zone_progression.current_subzone_id, DEMO_SUBZONE_ID);
+        assert!(state.zone_progression.is_zone_unlocked(DEMO_ZONE_ID));
+        assert!(state.auto_allocate.is_some());
+        assert!(state.tutorial.is_complete());
+        for slot in DEMO_SLOTS {
+            let item = state.equipment.get(slot).as_ref().expect("slot filled");
+            assert_eq!(item.rarity, Rarity::Rare);
+        }
+        assert_eq!(
+            state.combat_state.player_current_hp,
+            state.combat_state.player_max_hp
+        );
+        // No healing or repairs needed before play starts
+        assert!(state.clone().validate_and_repair().is_empty());
+    }
+
+    #[test]
+    fn test_demo_state_is_deterministic() {
+        let a = build_demo_state(0);
+        let b = build_demo_state(1_000);
+
+        for attr in AttributeType::all() {
+            assert_eq!(a.attributes.get(attr), b.attributes.get(attr));
+        }
+        for slot in DEMO_SLOTS {
+            let (x, y) = (a.equipment.get(slot), b.equipment.get(slot));
+            assert_eq!(
+                x.as_ref().map(|i| &i.display_name),
+                y.as_ref().map(|i| &i.display_name)
+            );
+        }
+        // Weighted allocation favors STR
+        assert!(
+            a.attributes.get(AttributeType::Strength)
+                > a.attributes.get(AttributeType::Intelligence)
+        );
+    }
+}
@@ -0,0 +1,252 @@
+//! Idle expeditions.
+//!
+//! An expedition is dispatched from the main game screen and runs on wall-clock
+//! time. It resolves during offline progression once its duration has elapsed,
+//! granting XP and items scaled by tier. Only one expedition can be active.
+
+use super::constants::*;
+use super::game_logic::{apply_tick_xp, xp_gain_per_tick};
+use super::game_state::GameState;
+use crate::character::attributes::AttributeType;
+use crate::items::drops::{ilvl_for_zone, roll_random_slot};
+use crate::items::generation::generate_item;
+use crate::items::scoring::auto_equip_if_better;
+use crate::items::types::{Item, Rarity};
+use serde::{Deserialize, Serialize};
+
+/// Expedition tiers. Longer expeditions require more prestige and pay out more.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ExpeditionTier {
+    Scout,
+    Raid,
+    Campaign,
+}
+
+impl ExpeditionTier {
+    pub const ALL: [ExpeditionTier; 3] = [
+        ExpeditionTier::Scout,
+        ExpeditionTier::Raid,
+        ExpeditionTier::Campaign,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ExpeditionTier::Scout => "Scouting Party",
+            ExpeditionTier::Raid => "Raid",
+            ExpeditionTier::Campaign => "Campaign",
+        }
+    }
+
+    /// How long the expedition takes, in seconds.
+    pub fn duration_seconds(&self) -> i64 {
+        match self {
+            ExpeditionTier::Scout => 3600,
+            ExpeditionTier::Raid => 4 * 3600,
+            ExpeditionTier::Campaign => 12 * 3600,
+        }
+    }
+
+    /// Minimum prestige rank required to dispatch this tier.
+    pub fn prestige_requirement(&self) -> u32 {
+        match self {
+            ExpeditionTier::Scout => 0,
+            ExpeditionTier::Raid => 5,
+            ExpeditionTier::Campaign => 10,
+        }
+    }
+
+    /// XP reward expressed as a number of average kills at the current prestige rate.
+    pub fn reward_kills(&self) -> u32 {
+        match self {
+            ExpeditionTier::Scout => 60,
+            ExpeditionTier::Raid => 200,
+            ExpeditionTier::Campaign => 500,
+        }
+    }
+
+    /// Number of guaranteed items and their rarity.
+    pub fn reward_items(&self) -> (usize, Rarity) {
+        match self {
+            ExpeditionTier::Scout => (1, Rarity::Magic),
+            ExpeditionTier::Raid => (2, Rarity::Rare),
+            ExpeditionTier::Campaign => (3, Rarity::Epic),
+        }
+    }
+
+    /// Highest tier unlocked at the given prestige rank.
+    pub fn best_for_prestige(prestige_rank: u32) -> ExpeditionTier {
+        Self::ALL
+            .iter()
+            .rev()
+            .find(|t| prestige_rank >= t.prestige_requirement())
+            .copied()
+            .unwrap_or(ExpeditionTier::Scout)
+    }
+}
+
+/// A dispatched expedition. Saved with the character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Expedition {
+    /// Unix timestamp when the expedition was dispatched
+    pub started_at: i64,
+    /// Duration in seconds
+    pub duration: i64,
+    pub tier: ExpeditionTier,
+}
+
+impl Expedition {
+    pub fn new(tier: ExpeditionTier, started_at: i64) -> Self {
+        Self {
+            started_at,
+            duration: tier.duration_seconds(),
+            tier,
+        }
+    }
+
+    pub fn is_complete(&self, now: i64) -> bool {
+        now >= self.started_at + self.duration
+    }
+
+    /// Seconds until the expedition returns (0 if complete).
+    pub fn remaining_seconds(&self, now: i64) -> i64 {
+        (self.started_at + self.duration - now).max(0)
+    }
+}
+
+/// Rewards granted by a resolved expedition.
+#[derive(Debug, Clone)]
+pub struct ExpeditionReport {
+    pub tier: ExpeditionTier,
+    pub xp_gained: u64,
+    pub level_ups: u32,
+    pub items: Vec<Item>,
+    /// Number of items that were auto-equipped
+    pub items_equipped: usize,
+}
+
+/// Dispatch an expedition. Returns false if one is already active or the
+/// tier's prestige requirement isn't met.
+pub fn start_expedition(state: &mut GameState, tier: ExpeditionTier, now: i64) -> bool {
+    if state.expedition.is_some() || state.prestige_rank < tier.prestige_requirement() {
+        return false;
+    }
+    state.expedition = Some(Expedition::new(tier, now));
+    true
+}
+
+/// Resolve the active expedition if it has returned by `now`.
+///
+/// Grants XP (tier kills × average kill XP at the current prestige/WIS/CHA rate)
+/// and guaranteed items at the current zone's ilvl, auto-equipping upgrades.
+/// Returns None if there is no expedition or it is still running.
+pub fn resolve_expedition(state: &mut GameState, now: i64) -> Option<ExpeditionReport> {
+    let expedition = state.expedition?;
+    if !expedition.is_complete(now) {
+        return None;
+    }
+    state.expedition = None;
+
+    let tier = expedition.tier;
+    let wis_mod = state.attributes.modifier(AttributeType::Wisdom);
+    let cha_mod = state.attributes.modifier(AttributeType::Charisma);
+    let avg_kill_ticks = (COMBAT_XP_MIN_TICKS + COMBAT_XP_MAX_TICKS) as f64 / 2.0;
+    let xp = xp_gain_per_tick(state.prestige_rank, wis_mod, cha_mod)
+        * avg_kill_ticks
+        * tier.reward_kills() as f64;
+    let (level_ups, _) = apply_tick_xp(state, xp);
+
+    let mut rng = rand::rng();
+    let (item_count, rarity) = tier.reward_items();
+    let ilvl = ilvl_for_zone(state.zone_progression.current_zone_id as usize);
+    let mut items = Vec::with_capacity(item_count);
+    let mut items_equipped = 0;
+    for _ in 0..item_count {
+        let item = generate_item(roll_random_slot(&mut rng), rarity, ilvl);
+        if auto_equip_if_better(item.clone(), state) {
+            items_equipped += 1;
+        }
+        items.push(item);
+    }
+
+    Some(ExpeditionReport {
+        tier,
+        xp_gained: xp as u64,
+        level_ups,
+        items,
+        items_equipped,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_start_expedition_only_one_active() {
+        let mut state = GameState::new("Explorer".to_string(), 0);
+        assert!(start_expedition(&mut state, ExpeditionTier::Scout, 1000));
+        assert!(!start_expedition(&mut state, ExpeditionTier::Scout, 1000));
+        assert_eq!(state.expedition.unwrap().started_at, 1000);
+    }
+
+    #[test]
+    fn test_start_expedition_requires_prestige() {
+        let mut state = GameState::new("Explorer".to_string(), 0);
+        assert!(!start_expedition(&mut state, ExpeditionTier::Campaign, 0));
+        state.prestige_rank = 10;
+        assert!(start_expedition(&mut state, ExpeditionTier::Campaign, 0));
+    }
+
+    #[test]
+    fn test_completed_expedition_resolves_with_rewards() {
+        let mut state = GameState::new("Explorer".to_string(), 0);
+        start_expedition(&mut state, ExpeditionTier::Scout, 0);
+
+        let now = ExpeditionTier::Scout.duration_seconds() + 1;
+        let report = resolve_expedition(&mut state, now).expect("should resolve");
+
+        assert!(report.xp_gained > 0);
+        assert_eq!(report.items.len(), 1);
+        assert!(report.items.iter().all(|i| i.rarity == Rarity::Magic));
+        assert!(state.expedition.is_none());
+    }
+
+    #[test]
+    fn test_running_expedition_does_not_resolve() {
+        let mut state = GameState::new("Explorer".to_string(), 0);
+        start_expedition(&mut state, ExpeditionTier::Scout, 0);
+        let xp_before = state.character_xp;
+
+        let now = ExpeditionTier::Scout.duration_seconds() - 1;
+        assert!(resolve_expedition(&mut state, now).is_none());
+        assert!(state.expedition.is_some());
+        assert_eq!(state.character_xp, xp_before);
+        assert_eq!(state.expedition.unwrap().remaining_seconds(now), 1);
+    }
+
+    #[test]
+    fn test_higher_tier_grants_more_xp() {
+        let mut scout = GameState::new("Scout".to_string(), 0);
+        scout.prestige_rank = 10;
+        start_expedition(&mut scout, ExpeditionTier::Scout, 0);
+        let scout_report = resolve_expedition(&mut scout, i64::MAX / 2).unwrap();
+
+        let mut campaign = GameState::new("Campaign".to_string(), 0);
+        campaign.prestige_rank = 10;
+        start_expedition(&mut campaign, ExpeditionTier::Campaign, 0);
+        let campaign_report = resolve_expedition(&mut campaign, i64::MAX / 2).unwrap();
+
+        assert!(campaign_report.xp_gained > scout_report.xp_gained);
+        assert!(campaign_report.items.len() > scout_report.items.len());
+    }
+
+    #[test]
+    fn test_best_tier_for_prestige() {
+        assert_eq!(ExpeditionTier::best_for_prestige(0), ExpeditionTier::Scout);
+        assert_eq!(ExpeditionTier::best_for_prestige(5), ExpeditionTier::Raid);
+        assert_eq!(
+            ExpeditionTier::best_for_prestige(25),
+            ExpeditionTier::Campaign
+        );
+    }
+}
@@ -0,0 +1,83 @@
+//! Machine-readable event stream for external integrations.
+//!
+//! `game_tick` and prestige push a [`GameEvent`] next to the human-readable
+//! combat log whenever something notable happens. Consumers (the web feature,
+//! future achievement hooks) call [`GameState::drain_events`] to take them.
+//! The buffer is bounded and transient: the oldest events are dropped once it
+//! is full, and it is never saved.
+
+use crate::core::game_state::GameState;
+use crate::items::types::Rarity;
+use serde::{Deserialize, Serialize};
+
+/// Max number of undrained events kept on `GameState`
+pub const MAX_GAME_EVENTS: usize = 100;
+
+/// A notable gameplay event, in a stable serde-able shape.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum GameEvent {
+    /// An enemy (mob, elite or boss) was killed
+    Kill { enemy_name: String, xp_gained: u64 },
+    /// The character reached a new level
+    LevelUp { new_level: u32 },
+    /// An item dropped and was kept (equipped or not)
+    Drop {
+        item_name: String,
+        rarity: Rarity,
+        equipped: bool,
+    },
+    /// A zone boss fell and the player advanced to a new zone
+    ZoneAdvance { new_zone_id: u32 },
+    /// The character prestiged to a new rank
+    Prestige { new_rank: u32 },
+}
+
+impl GameState {
+    /// Appends an event, dropping the oldest one if the buffer is full.
+    pub fn push_game_event(&mut self, event: GameEvent) {
+        if self.game_events.len() >= MAX_GAME_EVENTS {
+            self.game_events.remove(0);
+        }
+        self.game_events.push(event);
+    }
+
+    /// Takes every buffered event, oldest first, leaving the buffer empty.
+    #[allow(dead_code)]
+    pub fn drain_events(&mut self) -> Vec<GameEvent> {
+        std::mem::take(&mut self.game_events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_drain_events_empties_buffer_in_order() {
+        let mut state = GameState::new("Test Hero".to_string(), 0);
+        state.push_game_event(GameEvent::LevelUp { new_level: 2 });
+        state.push_game_event(GameEvent::Prestige { new_rank: 1 });
+
+        assert_eq!(
+            state.drain_events(),
+            vec![
+                GameEvent::LevelUp { new_level: 2 },
+                GameEvent::Prestige { new_rank: 1 },
+            ]
+        );
+        assert!(state.drain_events().is_empty());
+    }
+
+    #[test]
+    fn test_buffer_drops_oldest_when_full() {
+        let mut state = GameState::new("Test Hero".to_string(), 0);
+        for level in 0..MAX_GAME_EVENTS as u32 + 5 {
+            state.push_game_event(GameEvent::LevelUp { new_level: level });
+        }
+
+        let events = state.drain_events();
+        assert_eq!(events.len(), MAX_GAME_EVENTS);
+        assert_eq!(events[0], GameEvent::LevelUp { new_level: 5 });
+    }
+}
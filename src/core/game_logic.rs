@@ -1,16 +1,21 @@
 use super::constants::*;
 use super::game_state::GameState;
+use super::world_event::{current_world_event, WorldEvent};
 use crate::character::attributes::AttributeType;
 use crate::character::derived_stats::DerivedStats;
 use crate::combat::types::{
-    generate_boss_for_current_zone, generate_dungeon_boss, generate_dungeon_elite,
-    generate_dungeon_enemy, generate_enemy_for_current_zone,
+    generate_boss_for_current_zone_with_rng, generate_dungeon_boss, generate_dungeon_elite,
+    generate_dungeon_enemy, generate_enemy_for_current_zone_with_rng, generate_hunt_elite_with_rng,
+    generate_hydra_with_rng,
 };
-use crate::dungeon::types::RoomType;
-use rand::RngExt;
+use crate::dungeon::types::{DungeonDifficulty, RoomType};
+use rand::{Rng, RngExt};
 
 // Re-export offline progression types for backwards compatibility
-pub use super::offline::{calculate_offline_xp, process_offline_progression, OfflineReport};
+pub use super::offline::{
+    calculate_offline_xp, process_offline_progression, process_offline_progression_at,
+    OfflineReport,
+};
 
 /// Calculates the XP required to reach the next level
 pub fn xp_for_next_level(level: u32) -> u64 {
@@ -30,9 +35,18 @@ pub fn xp_gain_per_tick(prestige_rank: u32, wis_modifier: i32, cha_modifier: i32
     BASE_XP_PER_TICK * prestige_mult * wis_mult
 }
 
-/// Distributes 3 attribute points randomly among non-capped attributes
+/// Distributes 3 attribute points among non-capped attributes: by the
+/// `auto_allocate` weights when set, otherwise at random
+#[allow(dead_code)]
 pub fn distribute_level_up_points(state: &mut GameState) -> Vec<AttributeType> {
-    let mut rng = rand::rng();
+    distribute_level_up_points_with_rng(state, &mut rand::rng())
+}
+
+/// Same as `distribute_level_up_points`, drawing attributes from the given RNG.
+pub fn distribute_level_up_points_with_rng<R: Rng>(
+    state: &mut GameState,
+    rng: &mut R,
+) -> Vec<AttributeType> {
     let cap = state.get_attribute_cap();
     let mut increased = Vec::new();
 
@@ -41,8 +55,11 @@ pub fn distribute_level_up_points(state: &mut GameState) -> Vec<AttributeType> {
     let max_attempts = LEVEL_UP_MAX_DISTRIBUTION_ATTEMPTS;
 
     while points > 0 && attempts < max_attempts {
-        let attr_index = rng.random_range(0..NUM_ATTRIBUTES);
-        let attr = AttributeType::all()[attr_index];
+        let attr = match state.auto_allocate {
+            Some(weights) => weighted_uncapped_attribute(state, &weights, cap, rng),
+            None => None,
+        }
+        .unwrap_or_else(|| AttributeType::all()[rng.random_range(0..NUM_ATTRIBUTES)]);
 
         if state.attributes.get(attr) < cap {
             state.attributes.increment(attr);
@@ -56,9 +73,47 @@ pub fn distribute_level_up_points(state: &mut GameState) -> Vec<AttributeType> {
     increased
 }
 
+/// Picks an attribute below the cap with probability proportional to its weight.
+/// Returns None when every weighted attribute is capped, so the caller falls
+/// back to random distribution instead of wasting the point.
+fn weighted_uncapped_attribute<R: Rng>(
+    state: &GameState,
+    weights: &[u32; NUM_ATTRIBUTES],
+    cap: u32,
+    rng: &mut R,
+) -> Option<AttributeType> {
+    let eligible: Vec<(AttributeType, u64)> = AttributeType::all()
+        .into_iter()
+        .zip(weights.iter().map(|&weight| weight as u64))
+        .filter(|&(attr, weight)| weight > 0 && state.attributes.get(attr) < cap)
+        .collect();
+    let total: u64 = eligible.iter().map(|&(_, weight)| weight).sum();
+    if total == 0 {
+        return None;
+    }
+
+    let mut roll = rng.random_range(0..total);
+    for (attr, weight) in eligible {
+        if roll < weight {
+            return Some(attr);
+        }
+        roll -= weight;
+    }
+    None
+}
+
 /// Applies XP to the character and processes any level-ups
 /// Returns (number of level-ups, attributes increased)
 pub fn apply_tick_xp(state: &mut GameState, xp_gain: f64) -> (u32, Vec<AttributeType>) {
+    apply_tick_xp_with_rng(state, xp_gain, &mut rand::rng())
+}
+
+/// Same as `apply_tick_xp`, distributing level-up points with the given RNG.
+pub fn apply_tick_xp_with_rng<R: Rng>(
+    state: &mut GameState,
+    xp_gain: f64,
+    rng: &mut R,
+) -> (u32, Vec<AttributeType>) {
     state.character_xp += xp_gain as u64;
 
     let mut levelups = 0;
@@ -72,7 +127,7 @@ pub fn apply_tick_xp(state: &mut GameState, xp_gain: f64) -> (u32, Vec<Attribute
             state.character_level += 1;
             levelups += 1;
 
-            let increased = distribute_level_up_points(state);
+            let increased = distribute_level_up_points_with_rng(state, rng);
             all_increased.extend(increased);
 
             // Update combat state max HP after level up
@@ -89,15 +144,69 @@ pub fn apply_tick_xp(state: &mut GameState, xp_gain: f64) -> (u32, Vec<Attribute
 
 /// Calculates XP bonus from killing an enemy
 /// `haven_xp_gain_percent` is the Training Yard bonus (0.0 if not built)
+#[allow(dead_code)]
 pub fn combat_kill_xp(passive_xp_rate: f64, haven_xp_gain_percent: f64) -> u64 {
-    let ticks = rand::rng().random_range(COMBAT_XP_MIN_TICKS..=COMBAT_XP_MAX_TICKS);
+    combat_kill_xp_with_rng(passive_xp_rate, haven_xp_gain_percent, &mut rand::rng())
+}
+
+/// Same as `combat_kill_xp`, rolling the tick count with the given RNG.
+pub fn combat_kill_xp_with_rng<R: Rng>(
+    passive_xp_rate: f64,
+    haven_xp_gain_percent: f64,
+    rng: &mut R,
+) -> u64 {
+    let ticks = rng.random_range(COMBAT_XP_MIN_TICKS..=COMBAT_XP_MAX_TICKS);
     let base_xp = passive_xp_rate * ticks as f64;
     // Apply Haven Training Yard bonus
     (base_xp * (1.0 + haven_xp_gain_percent / 100.0)) as u64
 }
 
-/// Spawns a new enemy if none exists
+/// Largest kill award this character can plausibly earn: the top
+/// `combat_kill_xp` roll at the current prestige rank, WIS and CHA, times
+/// `KILL_XP_SANITY_MULTIPLIER`.
+pub fn max_plausible_kill_xp(state: &GameState, haven_xp_gain_percent: f64) -> u64 {
+    let wis_mod = state.attributes.modifier(AttributeType::Wisdom);
+    let cha_mod = state.attributes.modifier(AttributeType::Charisma);
+    let top_roll = xp_gain_per_tick(state.prestige_rank, wis_mod, cha_mod)
+        * COMBAT_XP_MAX_TICKS as f64
+        * (1.0 + haven_xp_gain_percent / 100.0);
+    (top_roll * KILL_XP_SANITY_MULTIPLIER) as u64
+}
+
+/// Guards a kill's XP award against inflation from stale or corrupted state.
+/// Returns the XP to apply, clamped to `max_plausible_kill_xp`, and whether
+/// it had to be clamped so the caller can flag it.
+pub fn verify_kill_xp(
+    state: &GameState,
+    xp_gained: u64,
+    haven_xp_gain_percent: f64,
+) -> (u64, bool) {
+    let ceiling = max_plausible_kill_xp(state, haven_xp_gain_percent);
+    if xp_gained > ceiling {
+        (ceiling, true)
+    } else {
+        (xp_gained, false)
+    }
+}
+
+/// Spawns a new enemy if none exists. Never spawns an Elite Hunt target:
+/// world events come through `game_tick`, which is given the time.
+#[allow(dead_code)]
 pub fn spawn_enemy_if_needed(state: &mut GameState) {
+    spawn_enemy_if_needed_with_rng(state, None, &mut rand::rng());
+}
+
+/// Same as `spawn_enemy_if_needed`, rolling overworld enemies with the given RNG.
+/// Dungeon enemies still use the thread RNG.
+///
+/// During an Elite Hunt (`world_event`), each overworld mob spawn has an
+/// `ELITE_HUNT_SPAWN_CHANCE` to be the hunted elite instead, once per hunt.
+/// The RNG is only consumed for that roll while a hunt is eligible.
+pub fn spawn_enemy_if_needed_with_rng<R: Rng>(
+    state: &mut GameState,
+    world_event: Option<WorldEvent>,
+    rng: &mut R,
+) {
     if state.combat_state.current_enemy.is_none() && !state.combat_state.is_regenerating {
         // Check if we're in a dungeon
         if let Some(dungeon) = &state.active_dungeon {
@@ -119,18 +228,53 @@ pub fn spawn_enemy_if_needed(state: &mut GameState) {
             // Normal overworld combat - use zone-based static enemy generation
             let zone_id = state.zone_progression.current_zone_id;
             let subzone_id = state.zone_progression.current_subzone_id;
+            let hunt_end = match world_event {
+                Some(WorldEvent::EliteHunt { ends_at })
+                    if state.combat_state.last_elite_hunt_end != ends_at =>
+                {
+                    Some(ends_at)
+                }
+                _ => None,
+            };
             let enemy = if state.zone_progression.fighting_boss {
-                generate_boss_for_current_zone(zone_id, subzone_id)
+                generate_boss_for_current_zone_with_rng(zone_id, subzone_id, rng)
+            } else if let Some(ends_at) =
+                hunt_end.filter(|_| rng.random::<f64>() < ELITE_HUNT_SPAWN_CHANCE)
+            {
+                state.combat_state.last_elite_hunt_end = ends_at;
+                generate_hunt_elite_with_rng(zone_id, subzone_id, ends_at, rng)
+            } else if zone_id >= HYDRA_MIN_ZONE && rng.random::<f64>() < HYDRA_SPAWN_CHANCE {
+                generate_hydra_with_rng(zone_id, subzone_id, rng)
             } else {
-                generate_enemy_for_current_zone(zone_id, subzone_id)
+                state
+                    .adaptive_intensity
+                    .scale_enemy(generate_enemy_for_current_zone_with_rng(
+                        zone_id, subzone_id, rng,
+                    ))
             };
             state.combat_state.current_enemy = Some(enemy);
             state.combat_state.player_attack_timer = 0.0;
             state.combat_state.enemy_attack_timer = 0.0;
+            state.combat_state.panic_heal_used = false;
+            state.combat_state.boss_fight_ticks = 0;
         }
     }
 }
 
+/// Removes the Elite Hunt target once its hunt has ended.
+/// Returns the escaped elite's name.
+pub fn despawn_escaped_elite(state: &mut GameState, now: i64) -> Option<String> {
+    let expires_at = state.combat_state.current_enemy.as_ref()?.hunt_expires_at?;
+    if now < expires_at {
+        return None;
+    }
+    state
+        .combat_state
+        .current_enemy
+        .take()
+        .map(|enemy| enemy.name)
+}
+
 /// Spawns a dungeon enemy based on the current room type using zone-based stats.
 fn spawn_dungeon_enemy(state: &mut GameState) {
     let dungeon_zone_id = state.active_dungeon.as_ref().map_or(1, |d| d.zone_id);
@@ -141,15 +285,22 @@ fn spawn_dungeon_enemy(state: &mut GameState) {
         .and_then(|d| d.current_room())
         .map(|r| r.room_type);
 
-    let enemy = match room_type {
+    let difficulty = state
+        .active_dungeon
+        .as_ref()
+        .map_or(DungeonDifficulty::Normal, |d| d.difficulty);
+
+    let enemy = difficulty.scale_enemy(match room_type {
         Some(RoomType::Elite) => generate_dungeon_elite(dungeon_zone_id),
         Some(RoomType::Boss) => generate_dungeon_boss(dungeon_zone_id),
         _ => generate_dungeon_enemy(dungeon_zone_id),
-    };
+    });
 
     state.combat_state.current_enemy = Some(enemy);
     state.combat_state.player_attack_timer = 0.0;
     state.combat_state.enemy_attack_timer = 0.0;
+    state.combat_state.panic_heal_used = false;
+    state.combat_state.boss_fight_ticks = 0;
 }
 
 // DUNGEON_DISCOVERY_CHANCE is imported from constants via `use super::constants::*`
@@ -169,13 +320,22 @@ pub fn try_discover_dungeon(state: &mut GameState) -> bool {
     }
 
     // Discover dungeon!
-    // Prestige affects dungeon quality (size, rewards), not discovery rate
+    // Prestige affects dungeon quality (size, difficulty, rewards), not discovery rate
     let zone_id = state.zone_progression.current_zone_id;
-    let dungeon = crate::dungeon::generation::generate_dungeon(
-        state.character_level,
-        state.prestige_rank,
-        zone_id,
-    );
+    let dungeon = match state.queued_dungeon_seed.take() {
+        Some(seed) => crate::dungeon::generation::generate_dungeon_from_seed(
+            seed,
+            state.character_level,
+            state.prestige_rank,
+            zone_id,
+        ),
+        None => crate::dungeon::generation::generate_dungeon_with_difficulty(
+            state.character_level,
+            state.prestige_rank,
+            zone_id,
+            DungeonDifficulty::roll(state.prestige_rank, &mut rng),
+        ),
+    };
     state.active_dungeon = Some(dungeon);
 
     true
@@ -249,6 +409,52 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_auto_allocate_str_weights_level_up_str() {
+        let mut state = GameState::new("Brute".to_string(), 0);
+        state.auto_allocate = Some([1, 0, 0, 0, 0, 0]);
+        let str_before = state.attributes.get(AttributeType::Strength);
+
+        let (levelups, increased) = apply_tick_xp(&mut state, xp_for_next_level(1) as f64);
+
+        assert_eq!(levelups, 1);
+        assert!(increased.iter().all(|&a| a == AttributeType::Strength));
+        assert_eq!(
+            state.attributes.get(AttributeType::Strength),
+            str_before + LEVEL_UP_ATTRIBUTE_POINTS
+        );
+    }
+
+    #[test]
+    fn test_auto_allocate_unset_spreads_points_randomly() {
+        // Without weights, every point is still spent, just not steered
+        let mut state = GameState::new("Drifter".to_string(), 0);
+        assert!(state.auto_allocate.is_none());
+
+        let increased = distribute_level_up_points(&mut state);
+
+        assert_eq!(increased.len(), LEVEL_UP_ATTRIBUTE_POINTS as usize);
+        let sum: u32 = AttributeType::all()
+            .iter()
+            .map(|&a| state.attributes.get(a))
+            .sum();
+        assert_eq!(sum, 60 + LEVEL_UP_ATTRIBUTE_POINTS);
+    }
+
+    #[test]
+    fn test_auto_allocate_capped_weights_fall_back_to_random() {
+        let mut state = GameState::new("Maxed".to_string(), 0);
+        let cap = state.get_attribute_cap();
+        state.attributes.set(AttributeType::Strength, cap);
+        state.auto_allocate = Some([5, 0, 0, 0, 0, 0]);
+
+        let increased = distribute_level_up_points(&mut state);
+
+        assert_eq!(increased.len(), LEVEL_UP_ATTRIBUTE_POINTS as usize);
+        assert!(!increased.contains(&AttributeType::Strength));
+        assert_eq!(state.attributes.get(AttributeType::Strength), cap);
+    }
+
     #[test]
     fn test_apply_tick_xp_no_levelup() {
         let mut state = GameState::new("Test Hero".to_string(), 0);
@@ -277,6 +483,29 @@ mod tests {
         assert!((200..=400).contains(&xp));
     }
 
+    #[test]
+    fn test_verify_kill_xp_passes_normal_kills() {
+        let mut state = GameState::new("Test Hero".to_string(), 0);
+        state.prestige_rank = 5;
+        let rate = xp_gain_per_tick(
+            state.prestige_rank,
+            state.attributes.modifier(AttributeType::Wisdom),
+            state.attributes.modifier(AttributeType::Charisma),
+        );
+        for _ in 0..100 {
+            let xp = combat_kill_xp(rate, 30.0);
+            assert_eq!(verify_kill_xp(&state, xp, 30.0), (xp, false));
+        }
+    }
+
+    #[test]
+    fn test_verify_kill_xp_clamps_huge_award() {
+        let state = GameState::new("Test Hero".to_string(), 0);
+        let ceiling = max_plausible_kill_xp(&state, 0.0);
+        assert_eq!(ceiling, 4000); // 1 XP/tick * 400 ticks * 10
+        assert_eq!(verify_kill_xp(&state, u64::MAX / 2, 0.0), (ceiling, true));
+    }
+
     #[test]
     fn test_combat_kill_xp_with_haven_bonus() {
         // Run many trials to verify average XP is higher with bonus
@@ -355,6 +584,81 @@ mod tests {
         assert!(enemy.max_hp > 0);
     }
 
+    /// Spawns `count` overworld enemies at `timestamp`, killing each one.
+    /// Returns how many were Elite Hunt targets.
+    fn count_hunt_elites(state: &mut GameState, timestamp: i64, count: usize) -> usize {
+        use rand::SeedableRng;
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(7);
+        let mut elites = 0;
+        for _ in 0..count {
+            state.combat_state.current_enemy = None;
+            spawn_enemy_if_needed_with_rng(state, current_world_event(timestamp), &mut rng);
+            if state
+                .combat_state
+                .current_enemy
+                .as_ref()
+                .unwrap()
+                .is_hunt_elite()
+            {
+                elites += 1;
+            }
+        }
+        elites
+    }
+
+    #[test]
+    fn test_hunt_elite_spawns_once_during_elite_hunt() {
+        let mut state = GameState::new("Hunter".to_string(), 0);
+        let hunt_start = 10 * WORLD_EVENT_WINDOW_SECONDS;
+
+        assert_eq!(count_hunt_elites(&mut state, hunt_start, 500), 1);
+        assert_eq!(
+            state.combat_state.last_elite_hunt_end,
+            hunt_start + ELITE_HUNT_ACTIVE_SECONDS
+        );
+
+        // The next hunt can spawn a new elite
+        let next_hunt = hunt_start + WORLD_EVENT_WINDOW_SECONDS;
+        assert_eq!(count_hunt_elites(&mut state, next_hunt, 500), 1);
+    }
+
+    #[test]
+    fn test_hunt_elite_never_spawns_outside_elite_hunt() {
+        let mut state = GameState::new("Hunter".to_string(), 0);
+        let between_hunts = 10 * WORLD_EVENT_WINDOW_SECONDS + ELITE_HUNT_ACTIVE_SECONDS;
+        assert_eq!(current_world_event(between_hunts), None);
+
+        assert_eq!(count_hunt_elites(&mut state, between_hunts, 500), 0);
+    }
+
+    #[test]
+    fn test_hunt_elite_is_boosted_and_escapes_when_hunt_ends() {
+        use rand::SeedableRng;
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(3);
+        let elite = generate_hunt_elite_with_rng(1, 1, 1_000, &mut rng);
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(3);
+        let base = generate_enemy_for_current_zone_with_rng(1, 1, &mut rng);
+        assert_eq!(elite.name, format!("Alpha {}", base.name));
+        assert_eq!(
+            elite.max_hp,
+            (base.max_hp as f64 * ELITE_HUNT_MULTIPLIERS.0) as u32
+        );
+
+        let mut state = GameState::new("Hunter".to_string(), 0);
+        state.combat_state.current_enemy = Some(elite);
+        assert_eq!(despawn_escaped_elite(&mut state, 999), None);
+        assert!(state.combat_state.current_enemy.is_some());
+        assert_eq!(
+            despawn_escaped_elite(&mut state, 1_000),
+            Some(format!("Alpha {}", base.name))
+        );
+        assert!(state.combat_state.current_enemy.is_none());
+
+        // Regular enemies never escape
+        state.combat_state.current_enemy = Some(base);
+        assert_eq!(despawn_escaped_elite(&mut state, i64::MAX), None);
+    }
+
     #[test]
     fn test_try_discover_dungeon_skips_when_in_dungeon() {
         let mut state = GameState::new("Test Hero".to_string(), 0);
@@ -1,14 +1,23 @@
 use crate::challenges::chess::ChessStats;
-use crate::challenges::menu::ChallengeMenu;
+use crate::challenges::menu::{AutoMinigame, ChallengeMenu};
 use crate::challenges::ActiveMinigame;
-use crate::challenges::MinigameWinInfo;
+use crate::challenges::{MinigameWinInfo, Wager};
 use crate::character::attributes::Attributes;
-use crate::combat::types::CombatState;
+use crate::combat::adaptive::AdaptiveIntensity;
+use crate::combat::training_dummy::TrainingDummy;
+use crate::combat::types::{CombatStance, CombatState};
+use crate::core::constants::NUM_ATTRIBUTES;
+use crate::core::expedition::Expedition;
+use crate::core::game_event::GameEvent;
+use crate::core::metrics::MetricsBuffer;
+use crate::core::tutorial::TutorialState;
 use crate::dungeon::types::Dungeon;
+use crate::fishing::bait::Bait;
+use crate::fishing::gear::FishingGear;
 use crate::fishing::types::{FishingSession, FishingState};
-use crate::items::equipment::Equipment;
-use crate::items::types::Rarity;
-use crate::zones::ZoneProgression;
+use crate::items::equipment::{Equipment, EquipmentHistory};
+use crate::items::types::{EquipmentSlot, Gem, Item, Rarity};
+use crate::zones::{BossLedger, ZoneProgression};
 use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 
@@ -23,12 +32,66 @@ pub struct RecentDrop {
     pub slot: String,
     /// Stat summary (e.g. "+8 STR +3 DEX +Crit"), empty for non-equipment
     pub stats: String,
+    /// Detailed stat summary shown when verbose loot is on, empty for non-equipment
+    pub stats_verbose: String,
+    /// `item_power()` of the item, 0 for non-equipment
+    pub power: u64,
 }
 
 /// Max number of recent drops to track
 const MAX_RECENT_DROPS: usize = 10;
 
+/// A kept equipment drop, saved so players can review what dropped while away
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LootRecord {
+    pub name: String,
+    pub rarity: Rarity,
+    /// Equipment slot name (e.g. "Weapon", "Armor")
+    pub slot: String,
+    /// Detailed stat summary (`Item::stat_summary_verbose`)
+    pub stats: String,
+    /// `item_power()` of the item
+    pub power: u64,
+    /// Whether the item was auto-equipped when it dropped
+    pub equipped: bool,
+    /// `play_time_seconds` when it dropped
+    pub play_time_seconds: u64,
+}
+
+/// One prestige, saved so players can compare how far each run got
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PrestigeRecord {
+    /// Prestige rank reached by this prestige
+    pub rank: u32,
+    /// Character level when prestiging
+    pub level: u32,
+    pub zone_id: u32,
+    pub subzone_id: u32,
+    /// `play_time_seconds` when prestiging
+    pub play_time_seconds: u64,
+}
+
+/// Max number of drops kept in the saved loot history
+pub const MAX_LOOT_HISTORY: usize = 50;
+
 impl GameState {
+    /// Equips `item` in its slot, remembering the replaced piece for
+    /// `quick_swap_gear`.
+    pub fn equip(&mut self, item: Item) {
+        self.equipment_history
+            .equip(&mut self.equipment, item.slot, item);
+    }
+
+    /// Swaps the most recently replaced slot back to its previous item (and
+    /// again to undo the swap). Returns the slot, or None if there is nothing
+    /// to swap.
+    pub fn quick_swap_gear(&mut self) -> Option<EquipmentSlot> {
+        let slot = self.equipment_history.last_replaced?;
+        self.equipment_history
+            .swap(&mut self.equipment, slot)
+            .then_some(slot)
+    }
+
     /// Record a recent gain (item drop, fish catch, etc.)
     pub fn add_recent_drop(
         &mut self,
@@ -39,18 +102,72 @@ impl GameState {
         slot: String,
         stats: String,
     ) {
-        if self.recent_drops.len() >= MAX_RECENT_DROPS {
-            self.recent_drops.pop_back();
-        }
-        self.recent_drops.push_front(RecentDrop {
+        self.push_recent_drop(RecentDrop {
             name,
             rarity,
             equipped,
             icon,
             slot,
             stats,
+            stats_verbose: String::new(),
+            power: 0,
         });
     }
+
+    /// Record a dropped equipment item with both compact and verbose stat summaries.
+    pub fn add_recent_item_drop(&mut self, item: &Item, equipped: bool, icon: &'static str) {
+        self.push_recent_drop(RecentDrop {
+            name: item.display_name.clone(),
+            rarity: item.rarity,
+            equipped,
+            icon,
+            slot: item.slot_name().to_string(),
+            stats: item.stat_summary(),
+            stats_verbose: item.stat_summary_verbose(),
+            power: crate::items::item_power(item),
+        });
+        self.push_loot_record(LootRecord {
+            name: item.display_name.clone(),
+            rarity: item.rarity,
+            slot: item.slot_name().to_string(),
+            stats: item.stat_summary_verbose(),
+            power: crate::items::item_power(item),
+            equipped,
+            play_time_seconds: self.play_time_seconds,
+        });
+    }
+
+    fn push_recent_drop(&mut self, drop: RecentDrop) {
+        if self.recent_drops.len() >= MAX_RECENT_DROPS {
+            self.recent_drops.pop_back();
+        }
+        self.recent_drops.push_front(drop);
+    }
+
+    fn push_loot_record(&mut self, record: LootRecord) {
+        if self.loot_history.len() >= MAX_LOOT_HISTORY {
+            self.loot_history.pop_front();
+        }
+        self.loot_history.push_back(record);
+    }
+
+    /// Saved drop history for the loot history overlay, newest first.
+    #[allow(dead_code)]
+    pub fn loot_history(&self) -> impl Iterator<Item = &LootRecord> {
+        self.loot_history.iter().rev()
+    }
+
+    /// Prestige history for the prestige history overlay, oldest first, each
+    /// paired with that run's length in play-time seconds.
+    #[allow(dead_code)]
+    pub fn prestige_timeline(&self) -> impl Iterator<Item = (&PrestigeRecord, u64)> {
+        let starts =
+            std::iter::once(0).chain(self.prestige_history.iter().map(|r| r.play_time_seconds));
+        self.prestige_history
+            .iter()
+            .zip(starts)
+            .map(|(record, start)| (record, record.play_time_seconds.saturating_sub(start)))
+    }
 }
 
 /// Main game state containing all player progress
@@ -73,6 +190,12 @@ pub struct GameState {
     /// Persistent fishing progression state
     #[serde(default)]
     pub fishing: FishingState,
+    /// Equipped fishing rod (saved to disk)
+    #[serde(default)]
+    pub fishing_gear: FishingGear,
+    /// Bait on the hook, boosting the next few catches (saved to disk)
+    #[serde(default)]
+    pub active_bait: Option<Bait>,
     /// Active fishing session (transient, not saved)
     #[serde(skip)]
     #[allow(dead_code)]
@@ -86,18 +209,91 @@ pub struct GameState {
     /// Persistent chess stats (survives prestige, saved to disk)
     #[serde(default)]
     pub chess_stats: ChessStats,
+    /// Lifetime kill count (survives prestige, saved to disk)
+    #[serde(default)]
+    pub total_kills: u64,
+    /// First-run tutorial progress (saved to disk)
+    #[serde(default)]
+    pub tutorial: TutorialState,
+    /// Active idle expedition (at most one, saved to disk)
+    #[serde(default)]
+    pub expedition: Option<Expedition>,
+    /// Consecutive prestiges without dying; grants bonus damage, reset on death
+    #[serde(default)]
+    pub prestige_streak: u32,
+    /// Unequipped drops below this rarity are salvaged (None keeps everything)
+    #[serde(default)]
+    pub loot_filter_min_rarity: Option<Rarity>,
+    /// Materials earned from salvaged drops
+    #[serde(default)]
+    pub salvage_materials: u64,
+    /// Unsocketed gems, from drops and Workshop crafting
+    #[serde(default)]
+    pub gems: Vec<Gem>,
+    /// Loot panel shows full affix names and derived-stat contributions
+    #[serde(default)]
+    pub verbose_loot: bool,
+    /// Combat log shows each hit's base damage, defense and crit multiplier
+    #[serde(default)]
+    pub verbose_combat: bool,
+    /// Damage/defense trade-off applied in `update_combat`
+    #[serde(default)]
+    pub combat_stance: CombatStance,
+    /// Level-up point weights in `AttributeType::all()` order (STR, DEX, CON,
+    /// INT, WIS, CHA). None spreads points at random.
+    #[serde(default)]
+    pub auto_allocate: Option<[u32; NUM_ATTRIBUTES]>,
+    /// Last `MAX_LOOT_HISTORY` kept equipment drops, oldest first
+    #[serde(default)]
+    pub loot_history: VecDeque<LootRecord>,
+    /// Per-boss attempts, deaths and fastest kills (survives prestige)
+    #[serde(default)]
+    pub boss_ledger: BossLedger,
+    /// Optional overworld enemy scaling that follows kill pace (off by default)
+    #[serde(default)]
+    pub adaptive_intensity: AdaptiveIntensity,
+    /// XP banked by the last prestige, not yet refunded by `apply_banked_xp`
+    #[serde(default)]
+    pub banked_xp: u64,
+    /// Every prestige performed, oldest first (for the prestige history)
+    #[serde(default)]
+    pub prestige_history: Vec<PrestigeRecord>,
     /// Active challenge minigame (transient, not saved)
     #[serde(skip)]
     pub active_minigame: Option<ActiveMinigame>,
+    /// Whether `active_minigame` is a practice run: no rewards or stats (transient, not saved)
+    #[serde(skip)]
+    pub practice_minigame: bool,
+    /// Double-or-nothing stake on the active minigame (transient, not saved)
+    #[serde(skip)]
+    pub wager: Wager,
+    /// Board game the auto-challenge setting is playing in the background (transient, not saved)
+    #[serde(skip)]
+    pub auto_minigame: Option<AutoMinigame>,
     /// Session kill count (transient, not saved)
     #[serde(skip)]
     pub session_kills: u64,
     /// Recent item drops for display (transient, not saved)
     #[serde(skip)]
     pub recent_drops: VecDeque<RecentDrop>,
+    /// XP/min and kills/min history for the info panel (transient, not saved)
+    #[serde(skip)]
+    pub metrics: MetricsBuffer,
     /// Last minigame win info for achievement tracking (transient, not saved)
     #[serde(skip)]
     pub last_minigame_win: Option<MinigameWinInfo>,
+    /// Debug training dummy session (transient, not saved)
+    #[serde(skip)]
+    pub training_dummy: Option<TrainingDummy>,
+    /// Structured events for external consumers; see `drain_events` (transient, not saved)
+    #[serde(skip)]
+    pub game_events: Vec<GameEvent>,
+    /// Layout seed for the next discovered dungeon, from `--dungeon-seed` (transient, not saved)
+    #[serde(skip)]
+    pub queued_dungeon_seed: Option<u64>,
+    /// Items replaced by auto-equip, for quick-swap (transient, not saved)
+    #[serde(skip)]
+    pub equipment_history: EquipmentHistory,
 }
 
 impl GameState {
@@ -123,14 +319,40 @@ impl GameState {
             equipment,
             active_dungeon: None,
             fishing: FishingState::default(),
+            fishing_gear: FishingGear::default(),
+            active_bait: None,
             active_fishing: None,
             zone_progression: ZoneProgression::new(),
             challenge_menu: ChallengeMenu::new(),
             chess_stats: ChessStats::default(),
+            total_kills: 0,
+            tutorial: TutorialState::default(),
+            expedition: None,
+            prestige_streak: 0,
+            loot_filter_min_rarity: None,
+            salvage_materials: 0,
+            gems: Vec::new(),
+            verbose_loot: false,
+            verbose_combat: false,
+            combat_stance: CombatStance::default(),
+            auto_allocate: None,
+            loot_history: VecDeque::new(),
+            boss_ledger: BossLedger::default(),
+            adaptive_intensity: AdaptiveIntensity::default(),
+            banked_xp: 0,
+            prestige_history: Vec::new(),
             active_minigame: None,
+            practice_minigame: false,
+            wager: Wager::None,
+            auto_minigame: None,
             session_kills: 0,
             recent_drops: VecDeque::with_capacity(5),
+            metrics: MetricsBuffer::default(),
             last_minigame_win: None,
+            training_dummy: None,
+            game_events: Vec::new(),
+            queued_dungeon_seed: None,
+            equipment_history: EquipmentHistory::default(),
         }
     }
 
@@ -402,6 +624,38 @@ mod tests {
         assert_eq!(gs.recent_drops[1].name, "Extra1");
     }
 
+    #[test]
+    fn test_loot_history_caps_and_evicts_oldest() {
+        use crate::items::generation::generate_item;
+        use crate::items::types::EquipmentSlot;
+
+        let mut gs = GameState::new("Hero".to_string(), 0);
+        for i in 0..MAX_LOOT_HISTORY {
+            let mut item = generate_item(EquipmentSlot::Weapon, Rarity::Rare, 10);
+            item.display_name = format!("Blade {i}");
+            gs.play_time_seconds = i as u64;
+            gs.add_recent_item_drop(&item, i == 0, "");
+        }
+        assert_eq!(gs.loot_history.len(), MAX_LOOT_HISTORY);
+        let oldest = &gs.loot_history[0];
+        assert_eq!(oldest.name, "Blade 0");
+        assert_eq!(oldest.rarity, Rarity::Rare);
+        assert_eq!(oldest.slot, "Weapon");
+        assert!(oldest.equipped);
+        assert!(!oldest.stats.is_empty());
+
+        let mut item = generate_item(EquipmentSlot::Armor, Rarity::Epic, 10);
+        item.display_name = "Newest".to_string();
+        gs.play_time_seconds = 999;
+        gs.add_recent_item_drop(&item, false, "");
+
+        assert_eq!(gs.loot_history.len(), MAX_LOOT_HISTORY);
+        assert_eq!(gs.loot_history[0].name, "Blade 1");
+        let newest = gs.loot_history().next().unwrap();
+        assert_eq!(newest.name, "Newest");
+        assert_eq!(newest.play_time_seconds, 999);
+    }
+
     #[test]
     fn test_serialization_round_trip_preserves_persistent_fields() {
         let mut gs = GameState::new("Serde Hero".to_string(), 42);
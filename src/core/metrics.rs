@@ -0,0 +1,143 @@
+//! Rolling XP and kill rates for the info panel sparkline.
+//!
+//! `game_tick` records XP and kills as they happen and closes a sample once per
+//! real second. Each sample holds the per-minute rate over the trailing minute,
+//! and only the last few minutes of samples are kept.
+
+use std::collections::VecDeque;
+
+/// Samples kept (one per second): the last 5 minutes.
+pub const METRICS_HISTORY_SECONDS: usize = 300;
+
+/// Seconds of raw counts averaged into each per-minute rate.
+const RATE_WINDOW_SECONDS: usize = 60;
+
+/// XP and kill rates at one point in time.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MetricsSample {
+    pub xp_per_minute: f64,
+    pub kills_per_minute: f64,
+}
+
+/// Bounded ring buffer of rate samples (transient, not saved).
+#[derive(Debug, Clone, Default)]
+pub struct MetricsBuffer {
+    samples: VecDeque<MetricsSample>,
+    /// Raw (xp, kills) per second for the trailing rate window
+    window: VecDeque<(u64, u64)>,
+    pending_xp: u64,
+    pending_kills: u64,
+}
+
+impl MetricsBuffer {
+    pub fn record_xp(&mut self, xp: u64) {
+        self.pending_xp += xp;
+    }
+
+    pub fn record_kill(&mut self) {
+        self.pending_kills += 1;
+    }
+
+    /// Closes the current second and appends a sample.
+    ///
+    /// Until a full minute has elapsed, rates are extrapolated from the
+    /// seconds recorded so far.
+    pub fn sample(&mut self) {
+        if self.window.len() >= RATE_WINDOW_SECONDS {
+            self.window.pop_front();
+        }
+        self.window.push_back((self.pending_xp, self.pending_kills));
+        self.pending_xp = 0;
+        self.pending_kills = 0;
+
+        let (xp, kills) = self
+            .window
+            .iter()
+            .fold((0, 0), |(xp, kills), (x, k)| (xp + x, kills + k));
+        let scale = RATE_WINDOW_SECONDS as f64 / self.window.len() as f64;
+
+        if self.samples.len() >= METRICS_HISTORY_SECONDS {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(MetricsSample {
+            xp_per_minute: xp as f64 * scale,
+            kills_per_minute: kills as f64 * scale,
+        });
+    }
+
+    pub fn latest(&self) -> Option<MetricsSample> {
+        self.samples.back().copied()
+    }
+
+    /// XP/min samples, oldest first (rounded for sparkline rendering).
+    pub fn xp_per_minute_series(&self) -> Vec<u64> {
+        self.samples
+            .iter()
+            .map(|s| s.xp_per_minute.round() as u64)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_buffer_has_no_samples() {
+        let metrics = MetricsBuffer::default();
+        assert!(metrics.latest().is_none());
+        assert!(metrics.xp_per_minute_series().is_empty());
+    }
+
+    #[test]
+    fn test_steady_rate_produces_expected_per_minute_values() {
+        let mut metrics = MetricsBuffer::default();
+        // 10 XP every second, one kill every other second
+        for second in 0..120 {
+            metrics.record_xp(10);
+            if second % 2 == 0 {
+                metrics.record_kill();
+            }
+            metrics.sample();
+        }
+
+        let latest = metrics.latest().unwrap();
+        assert!((latest.xp_per_minute - 600.0).abs() < f64::EPSILON);
+        assert!((latest.kills_per_minute - 30.0).abs() < f64::EPSILON);
+        assert_eq!(metrics.xp_per_minute_series().last(), Some(&600));
+    }
+
+    #[test]
+    fn test_partial_minute_is_extrapolated() {
+        let mut metrics = MetricsBuffer::default();
+        metrics.record_xp(50);
+        metrics.sample();
+        // 50 XP in the only second recorded so far → 3000/min
+        assert_eq!(metrics.xp_per_minute_series(), vec![3000]);
+    }
+
+    #[test]
+    fn test_rate_drops_after_activity_stops() {
+        let mut metrics = MetricsBuffer::default();
+        for _ in 0..60 {
+            metrics.record_xp(10);
+            metrics.sample();
+        }
+        for _ in 0..60 {
+            metrics.sample();
+        }
+        assert_eq!(metrics.latest().unwrap().xp_per_minute, 0.0);
+    }
+
+    #[test]
+    fn test_buffer_is_bounded() {
+        let mut metrics = MetricsBuffer::default();
+        for _ in 0..METRICS_HISTORY_SECONDS + 50 {
+            metrics.sample();
+        }
+        assert_eq!(
+            metrics.xp_per_minute_series().len(),
+            METRICS_HISTORY_SECONDS
+        );
+    }
+}
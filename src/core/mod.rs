@@ -3,12 +3,24 @@
 #![allow(unused_imports)]
 
 pub mod constants;
+pub mod demo;
+pub mod expedition;
+pub mod game_event;
 pub mod game_logic;
 pub mod game_state;
+pub mod metrics;
+pub mod notifications;
 pub mod offline;
+pub mod paths;
+pub mod progression;
+pub mod repair;
+pub mod snapshot;
 pub mod tick;
+pub mod tutorial;
+pub mod world_event;
 
 pub use constants::*;
+pub use game_event::GameEvent;
 pub use game_logic::*;
 pub use game_state::*;
 pub use tick::{TickEvent, TickResult};
@@ -0,0 +1,75 @@
+//! Notification hooks for things outside the TUI.
+//!
+//! Accessibility tools, streaming overlays or a future audio layer implement
+//! [`NotificationSink`] to hear about notable moments without depending on
+//! the UI. The game loop hands each tick's events to [`notify_tick_events`];
+//! every hook defaults to a no-op, so a sink only overrides what it needs.
+
+use crate::core::tick::TickEvent;
+use crate::items::types::Rarity;
+
+/// Receives gameplay notifications. All methods default to doing nothing.
+pub trait NotificationSink {
+    /// An item dropped and was kept (auto-salvaged drops are not reported).
+    fn on_drop(&mut self, _rarity: Rarity, _name: &str) {}
+}
+
+/// Sink that ignores everything; used when no integration is attached.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoopNotificationSink;
+
+impl NotificationSink for NoopNotificationSink {}
+
+/// Forwards the notable events from one tick to `sink`.
+pub fn notify_tick_events(events: &[TickEvent], sink: &mut dyn NotificationSink) {
+    for event in events {
+        if let TickEvent::ItemDropped {
+            item_name, rarity, ..
+        } = event
+        {
+            sink.on_drop(*rarity, item_name);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct RecordingSink {
+        drops: Vec<(Rarity, String)>,
+    }
+
+    impl NotificationSink for RecordingSink {
+        fn on_drop(&mut self, rarity: Rarity, name: &str) {
+            self.drops.push((rarity, name.to_string()));
+        }
+    }
+
+    #[test]
+    fn test_legendary_drop_notifies_once() {
+        let events = vec![
+            TickEvent::ItemDropped {
+                item_name: "Stormcaller".to_string(),
+                rarity: Rarity::Legendary,
+                equipped: true,
+                slot: "Weapon".to_string(),
+                stats: String::new(),
+                from_boss: true,
+            },
+            TickEvent::ItemSalvaged {
+                item_name: "Rusty Dagger".to_string(),
+                rarity: Rarity::Common,
+                materials: 1,
+            },
+        ];
+
+        let mut sink = RecordingSink::default();
+        notify_tick_events(&events, &mut sink);
+        assert_eq!(
+            sink.drops,
+            vec![(Rarity::Legendary, "Stormcaller".to_string())]
+        );
+    }
+}
@@ -4,6 +4,7 @@
 //! at a reduced rate. Supports Haven bonuses for increased offline XP.
 
 use super::constants::*;
+use super::expedition::{resolve_expedition, ExpeditionReport};
 use super::game_logic::{apply_tick_xp, xp_gain_per_tick};
 use super::game_state::GameState;
 use crate::character::attributes::AttributeType;
@@ -21,6 +22,8 @@ pub struct OfflineReport {
     pub offline_rate_percent: f64,
     /// Haven bonus percentage (0.0 if Haven not discovered)
     pub haven_bonus_percent: f64,
+    /// Expedition that returned while offline (None if none resolved)
+    pub expedition: Option<ExpeditionReport>,
 }
 
 /// Calculates the XP gained during offline time.
@@ -56,7 +59,15 @@ pub fn process_offline_progression(
     state: &mut GameState,
     haven_offline_xp_percent: f64,
 ) -> OfflineReport {
-    let current_time = Utc::now().timestamp();
+    process_offline_progression_at(state, haven_offline_xp_percent, Utc::now().timestamp())
+}
+
+/// Same as [`process_offline_progression`], with an explicit current time.
+pub fn process_offline_progression_at(
+    state: &mut GameState,
+    haven_offline_xp_percent: f64,
+    current_time: i64,
+) -> OfflineReport {
     let elapsed_seconds = current_time - state.last_save_time;
 
     if elapsed_seconds <= 0 {
@@ -77,6 +88,9 @@ pub fn process_offline_progression(
     let (total_level_ups, _) = apply_tick_xp(state, offline_xp);
     let level_after = state.character_level;
 
+    // Expedition rewards are reported separately from offline kill XP
+    let expedition = resolve_expedition(state, current_time);
+
     state.last_save_time = current_time;
 
     let offline_rate_percent =
@@ -90,6 +104,7 @@ pub fn process_offline_progression(
         level_after,
         offline_rate_percent,
         haven_bonus_percent: haven_offline_xp_percent,
+        expedition,
     }
 }
 
@@ -588,4 +603,34 @@ mod tests {
             report1.xp_gained
         );
     }
+
+    #[test]
+    fn test_offline_resolves_expedition_shorter_than_elapsed() {
+        use crate::core::expedition::{start_expedition, ExpeditionTier};
+
+        let mut state = GameState::new("Expedition Test".to_string(), 0);
+        start_expedition(&mut state, ExpeditionTier::Scout, 0);
+
+        let now = ExpeditionTier::Scout.duration_seconds() + 60;
+        let report = process_offline_progression_at(&mut state, 0.0, now);
+
+        let expedition = report.expedition.expect("expedition should resolve");
+        assert!(expedition.xp_gained > 0);
+        assert!(!expedition.items.is_empty());
+        assert!(state.expedition.is_none());
+    }
+
+    #[test]
+    fn test_offline_keeps_running_expedition() {
+        use crate::core::expedition::{start_expedition, ExpeditionTier};
+
+        let mut state = GameState::new("Expedition Test".to_string(), 0);
+        start_expedition(&mut state, ExpeditionTier::Scout, 0);
+
+        let now = ExpeditionTier::Scout.duration_seconds() / 2;
+        let report = process_offline_progression_at(&mut state, 0.0, now);
+
+        assert!(report.expedition.is_none());
+        assert!(state.expedition.is_some());
+    }
 }
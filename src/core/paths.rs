@@ -0,0 +1,59 @@
+//! Where Quest keeps its files.
+//!
+//! Everything lives in one data directory: `~/.quest/` by default, or the
+//! directory named by `QUEST_SAVE_DIR` so testing and real profiles can sit
+//! side by side without clobbering each other.
+
+use std::ffi::OsString;
+use std::io;
+use std::path::PathBuf;
+
+/// Environment variable that overrides the data directory.
+pub const SAVE_DIR_ENV: &str = "QUEST_SAVE_DIR";
+
+/// The data directory holding saves, Haven, achievements and settings.
+/// Not created here; writers create it as needed.
+pub fn quest_dir() -> io::Result<PathBuf> {
+    resolve_quest_dir(std::env::var_os(SAVE_DIR_ENV), dirs::home_dir())
+}
+
+/// `override_dir` (from `QUEST_SAVE_DIR`) wins unless empty; otherwise
+/// `~/.quest` under `home`.
+pub(crate) fn resolve_quest_dir(
+    override_dir: Option<OsString>,
+    home: Option<PathBuf>,
+) -> io::Result<PathBuf> {
+    if let Some(dir) = override_dir.filter(|d| !d.is_empty()) {
+        return Ok(PathBuf::from(dir));
+    }
+    let home = home.ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::NotFound,
+            "Could not determine home directory",
+        )
+    })?;
+    Ok(home.join(".quest"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_quest_dir_prefers_override() {
+        let home = Some(PathBuf::from("/home/hero"));
+        assert_eq!(
+            resolve_quest_dir(Some("/tmp/quest-testing".into()), home.clone()).unwrap(),
+            PathBuf::from("/tmp/quest-testing")
+        );
+        assert_eq!(
+            resolve_quest_dir(Some(OsString::new()), home.clone()).unwrap(),
+            PathBuf::from("/home/hero/.quest")
+        );
+        assert_eq!(
+            resolve_quest_dir(None, home).unwrap(),
+            PathBuf::from("/home/hero/.quest")
+        );
+        assert!(resolve_quest_dir(None, None).is_err());
+    }
+}
@@ -0,0 +1,206 @@
+//! Progression roadmap.
+//!
+//! Aggregates the scattered unlock rules (zone prestige gates, Haven discovery,
+//! fishing ranks, level achievements) into a single "what unlocks next" list.
+
+use super::constants::{BASE_MAX_FISHING_RANK, HAVEN_MIN_PRESTIGE_RANK, MAX_FISHING_RANK};
+use super::game_state::GameState;
+use crate::achievements::data::get_achievement_def;
+use crate::achievements::types::LEVEL_MILESTONES;
+use crate::fishing::types::FishingState;
+use crate::zones::get_all_zones;
+
+/// What a milestone unlocks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MilestoneKind {
+    ZoneUnlock,
+    HavenDiscovery,
+    FishingRank,
+    Achievement,
+}
+
+/// The next step along one progression track.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Milestone {
+    pub kind: MilestoneKind,
+    pub description: String,
+    /// Amount still needed, in the track's own unit (prestige ranks, fish, levels)
+    pub remaining: u64,
+}
+
+/// Returns the next milestone on each progression track that isn't finished.
+///
+/// Order is fixed: zone unlock, Haven discovery, fishing rank, level achievement.
+/// Haven discovery is only listed while the prestige gate is unmet, since
+/// whether the Haven has been found is account-level state.
+pub fn next_milestones(state: &GameState) -> Vec<Milestone> {
+    let mut milestones = Vec::new();
+
+    if let Some(zone) = get_all_zones()
+        .iter()
+        .find(|z| z.prestige_requirement > state.prestige_rank)
+    {
+        milestones.push(Milestone {
+            kind: MilestoneKind::ZoneUnlock,
+            description: format!(
+                "Reach prestige rank {} to unlock {}",
+                zone.prestige_requirement, zone.name
+            ),
+            remaining: (zone.prestige_requirement - state.prestige_rank) as u64,
+        });
+    }
+
+    if state.prestige_rank < HAVEN_MIN_PRESTIGE_RANK {
+        milestones.push(Milestone {
+            kind: MilestoneKind::HavenDiscovery,
+            description: format!(
+                "Reach prestige rank {} to begin searching for the Haven",
+                HAVEN_MIN_PRESTIGE_RANK
+            ),
+            remaining: (HAVEN_MIN_PRESTIGE_RANK - state.prestige_rank) as u64,
+        });
+    }
+
+    let fishing = &state.fishing;
+    if fishing.rank < MAX_FISHING_RANK {
+        let next = FishingState {
+            rank: fishing.rank + 1,
+            ..Default::default()
+        };
+        let required = FishingState::fish_required_for_rank(fishing.rank);
+        let dock_note = if fishing.rank >= BASE_MAX_FISHING_RANK {
+            " (requires Fishing Dock T4)"
+        } else {
+            ""
+        };
+        let remaining = required.saturating_sub(fishing.fish_toward_next_rank);
+        milestones.push(Milestone {
+            kind: MilestoneKind::FishingRank,
+            description: format!(
+                "Catch {} more fish to reach fishing rank {}: {}{}",
+                remaining,
+                next.rank,
+                next.rank_name(),
+                dock_note
+            ),
+            remaining: remaining as u64,
+        });
+    }
+
+    if let Some(&(level, id)) = LEVEL_MILESTONES
+        .iter()
+        .find(|(level, _)| *level > state.character_level as u64)
+    {
+        let name = get_achievement_def(id).map_or("", |def| def.name);
+        milestones.push(Milestone {
+            kind: MilestoneKind::Achievement,
+            description: format!("Reach level {} for \"{}\"", level, name),
+            remaining: level - state.character_level as u64,
+        });
+    }
+
+    milestones
+}
+
+/// Zone ids whose prestige gate is crossed going from `current_rank` to
+/// `new_rank` (empty if no gate lies in between).
+///
+/// Only prestige gates count: zones unlocked by achievements (requirement 0)
+/// never appear.
+pub fn zones_unlocked_by_prestige(current_rank: u32, new_rank: u32) -> Vec<u32> {
+    get_all_zones()
+        .iter()
+        .filter(|z| z.prestige_requirement > current_rank && z.prestige_requirement <= new_rank)
+        .map(|z| z.id)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find(milestones: &[Milestone], kind: MilestoneKind) -> Option<&Milestone> {
+        milestones.iter().find(|m| m.kind == kind)
+    }
+
+    #[test]
+    fn test_low_prestige_roadmap_includes_next_zone_gate() {
+        let mut state = GameState::new("Roadmap".to_string(), 0);
+        state.prestige_rank = 2;
+
+        let milestones = next_milestones(&state);
+        let zone = find(&milestones, MilestoneKind::ZoneUnlock).expect("zone milestone");
+
+        // Zone 3 (Mountain Pass) is the first zone gated behind P5
+        assert_eq!(zone.remaining, 3);
+        assert!(zone.description.contains("prestige rank 5"));
+        assert!(zone.description.contains("Mountain Pass"));
+    }
+
+    #[test]
+    fn test_haven_milestone_only_below_prestige_gate() {
+        let mut state = GameState::new("Roadmap".to_string(), 0);
+        state.prestige_rank = 7;
+        let haven = next_milestones(&state);
+        assert_eq!(
+            find(&haven, MilestoneKind::HavenDiscovery)
+                .unwrap()
+                .remaining,
+            3
+        );
+
+        state.prestige_rank = HAVEN_MIN_PRESTIGE_RANK;
+        assert!(find(&next_milestones(&state), MilestoneKind::HavenDiscovery).is_none());
+    }
+
+    #[test]
+    fn test_fishing_milestone_counts_remaining_fish() {
+        let mut state = GameState::new("Roadmap".to_string(), 0);
+        state.fishing.fish_toward_next_rank = 40;
+
+        let milestones = next_milestones(&state);
+        let fishing = find(&milestones, MilestoneKind::FishingRank).unwrap();
+        assert_eq!(
+            fishing.remaining,
+            (FishingState::fish_required_for_rank(1) - 40) as u64
+        );
+
+        state.fishing.rank = MAX_FISHING_RANK;
+        assert!(find(&next_milestones(&state), MilestoneKind::FishingRank).is_none());
+    }
+
+    #[test]
+    fn test_achievement_milestone_is_next_level_threshold() {
+        let mut state = GameState::new("Roadmap".to_string(), 0);
+        state.character_level = 12;
+
+        let milestones = next_milestones(&state);
+        let achievement = find(&milestones, MilestoneKind::Achievement).unwrap();
+        assert_eq!(achievement.remaining, 13);
+        assert!(achievement.description.contains("level 25"));
+    }
+
+    #[test]
+    fn test_prestige_across_zone_gate_unlocks_zones() {
+        // P4 -> P5 crosses the gate for Mountain Pass (3) and Ancient Ruins (4)
+        assert_eq!(zones_unlocked_by_prestige(4, 5), vec![3, 4]);
+        // Skipping several gates at once lists every zone in between
+        assert_eq!(zones_unlocked_by_prestige(0, 10), vec![3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_prestige_without_zone_gate_unlocks_nothing() {
+        assert!(zones_unlocked_by_prestige(5, 6).is_empty());
+        assert!(zones_unlocked_by_prestige(20, 21).is_empty());
+    }
+
+    #[test]
+    fn test_endgame_character_has_short_roadmap() {
+        let mut state = GameState::new("Roadmap".to_string(), 0);
+        state.prestige_rank = 100;
+        state.fishing.rank = MAX_FISHING_RANK;
+        state.character_level = 5000;
+
+        assert!(next_milestones(&state).is_empty());
+    }
+}
@@ -0,0 +1,265 @@
+//! Save consistency checks, used on load and by `quest doctor`.
+//!
+//! Saves from older builds, hand edits, or crashes mid-write can leave state
+//! the game never produces on its own. `validate_and_repair` fixes what it can
+//! in place and reports each change so callers can log or print it.
+
+use std::fmt;
+
+use crate::character::derived_stats::DerivedStats;
+use crate::core::game_state::GameState;
+use crate::items::types::EquipmentSlot;
+use crate::zones::{get_all_zones, get_zone};
+
+/// Max possible enemy HP is 2.4x player HP (boss with max variance), so
+/// anything above this multiple is stale from before a stat reset.
+const STALE_ENEMY_HP_MULTIPLIER: f64 = 2.5;
+
+/// A single fix applied by `GameState::validate_and_repair`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RepairAction {
+    /// Player HP was above max and was lowered to max
+    ClampedPlayerHp { from: u32, to: u32 },
+    /// Enemy HP was impossibly high for this character and the enemy was dropped
+    ClearedStaleEnemy { name: String, max_hp: u32 },
+    /// Enemy current HP was above its max and was lowered to max
+    ClampedEnemyHp { from: u32, to: u32 },
+    /// Zone/subzone didn't exist and was moved to the nearest valid one
+    ClampedZone { from: (u32, u32), to: (u32, u32) },
+    /// An item sat in a slot it doesn't belong to and was unequipped
+    RemovedMisplacedItem {
+        slot: EquipmentSlot,
+        item_name: String,
+    },
+}
+
+impl fmt::Display for RepairAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RepairAction::ClampedPlayerHp { from, to } => {
+                write!(f, "Player HP {} exceeded max, set to {}", from, to)
+            }
+            RepairAction::ClearedStaleEnemy { name, max_hp } => {
+                write!(f, "Cleared stale enemy {} ({} max HP)", name, max_hp)
+            }
+            RepairAction::ClampedEnemyHp { from, to } => {
+                write!(f, "Enemy HP {} exceeded max, set to {}", from, to)
+            }
+            RepairAction::ClampedZone { from, to } => write!(
+                f,
+                "Zone {}-{} does not exist, moved to {}-{}",
+                from.0, from.1, to.0, to.1
+            ),
+            RepairAction::RemovedMisplacedItem { slot, item_name } => {
+                write!(f, "Unequipped {} (not a {} item)", item_name, slot.name())
+            }
+        }
+    }
+}
+
+const ALL_SLOTS: [EquipmentSlot; 7] = [
+    EquipmentSlot::Weapon,
+    EquipmentSlot::Armor,
+    EquipmentSlot::Helmet,
+    EquipmentSlot::Gloves,
+    EquipmentSlot::Boots,
+    EquipmentSlot::Amulet,
+    EquipmentSlot::Ring,
+];
+
+impl GameState {
+    /// Runs consistency checks and fixes anything out of range.
+    /// Returns the repairs made (empty for a healthy save).
+    pub fn validate_and_repair(&mut self) -> Vec<RepairAction> {
+        let mut actions = Vec::new();
+
+        // Equipment first, since it feeds into the derived stats below
+        for slot in ALL_SLOTS {
+            let misplaced = self
+                .equipment
+                .get(slot)
+                .as_ref()
+                .filter(|item| item.slot != slot)
+                .map(|item| item.display_name.clone());
+            if let Some(item_name) = misplaced {
+                self.equipment.set(slot, None);
+                actions.push(RepairAction::RemovedMisplacedItem { slot, item_name });
+            }
+        }
+
+        let combat = &mut self.combat_state;
+        if combat.player_current_hp > combat.player_max_hp {
+            actions.push(RepairAction::ClampedPlayerHp {
+                from: combat.player_current_hp,
+                to: combat.player_max_hp,
+            });
+            combat.player_current_hp = combat.player_max_hp;
+        }
+
+        let derived = DerivedStats::calculate_derived_stats(&self.attributes, &self.equipment);
+        let stale_limit = (derived.max_hp as f64 * STALE_ENEMY_HP_MULTIPLIER) as u32;
+        if let Some(enemy) = &mut combat.current_enemy {
+            if enemy.max_hp > stale_limit {
+                actions.push(RepairAction::ClearedStaleEnemy {
+                    name: enemy.name.clone(),
+                    max_hp: enemy.max_hp,
+                });
+                combat.current_enemy = None;
+            } else if enemy.current_hp > enemy.max_hp {
+                actions.push(RepairAction::ClampedEnemyHp {
+                    from: enemy.current_hp,
+                    to: enemy.max_hp,
+                });
+                enemy.current_hp = enemy.max_hp;
+            }
+        }
+
+        let progression = &mut self.zone_progression;
+        let from = (progression.current_zone_id, progression.current_subzone_id);
+        let to = nearest_valid_zone(from.0, from.1);
+        if to != from {
+            progression.current_zone_id = to.0;
+            progression.current_subzone_id = to.1;
+            progression.kills_in_subzone = 0;
+            progression.fighting_boss = false;
+            actions.push(RepairAction::ClampedZone { from, to });
+        }
+
+        actions
+    }
+}
+
+/// Clamps a zone/subzone pair into the ranges defined in zone data.
+fn nearest_valid_zone(zone_id: u32, subzone_id: u32) -> (u32, u32) {
+    let zones = get_all_zones();
+    let min_id = zones.iter().map(|z| z.id).min().unwrap_or(1);
+    let max_id = zones.iter().map(|z| z.id).max().unwrap_or(1);
+    let zone_id = zone_id.clamp(min_id, max_id);
+    let subzone_count = get_zone(zone_id).map_or(1, |z| z.subzones.len() as u32);
+    (zone_id, subzone_id.clamp(1, subzone_count.max(1)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combat::types::Enemy;
+    use crate::items::types::{AttributeBonuses, Item, Rarity};
+
+    fn test_item(slot: EquipmentSlot) -> Item {
+        Item {
+            slot,
+            rarity: Rarity::Common,
+            ilvl: 10,
+            base_name: "Test".to_string(),
+            display_name: "Test Item".to_string(),
+            attributes: AttributeBonuses::new(),
+            affixes: vec![],
+            sockets: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_healthy_state_needs_no_repair() {
+        let mut state = GameState::new("Healthy".to_string(), 0);
+        assert!(state.validate_and_repair().is_empty());
+    }
+
+    #[test]
+    fn test_stale_enemy_is_cleared() {
+        let mut state = GameState::new("Stale".to_string(), 0);
+        state.combat_state.current_enemy =
+            Some(Enemy::new("Ancient Wyrm".to_string(), 100_000, 50));
+
+        let actions = state.validate_and_repair();
+
+        assert!(state.combat_state.current_enemy.is_none());
+        assert_eq!(
+            actions,
+            vec![RepairAction::ClearedStaleEnemy {
+                name: "Ancient Wyrm".to_string(),
+                max_hp: 100_000,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_normal_enemy_is_kept() {
+        let mut state = GameState::new("Normal".to_string(), 0);
+        state.combat_state.current_enemy = Some(Enemy::new("Rat".to_string(), 40, 5));
+
+        assert!(state.validate_and_repair().is_empty());
+        assert!(state.combat_state.current_enemy.is_some());
+    }
+
+    #[test]
+    fn test_out_of_bounds_zone_is_clamped() {
+        let mut state = GameState::new("Lost".to_string(), 0);
+        state.zone_progression.current_zone_id = 99;
+        state.zone_progression.current_subzone_id = 42;
+        state.zone_progression.fighting_boss = true;
+
+        let actions = state.validate_and_repair();
+
+        let max_zone = get_all_zones().iter().map(|z| z.id).max().unwrap();
+        let subzones = get_zone(max_zone).unwrap().subzones.len() as u32;
+        assert_eq!(state.zone_progression.current_zone_id, max_zone);
+        assert_eq!(state.zone_progression.current_subzone_id, subzones);
+        assert!(!state.zone_progression.fighting_boss);
+        assert_eq!(
+            actions,
+            vec![RepairAction::ClampedZone {
+                from: (99, 42),
+                to: (max_zone, subzones),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_zone_zero_is_clamped_to_first_zone() {
+        let mut state = GameState::new("Zero".to_string(), 0);
+        state.zone_progression.current_zone_id = 0;
+        state.zone_progression.current_subzone_id = 0;
+
+        state.validate_and_repair();
+
+        assert_eq!(state.zone_progression.current_zone_id, 1);
+        assert_eq!(state.zone_progression.current_subzone_id, 1);
+    }
+
+    #[test]
+    fn test_player_and_enemy_hp_clamped_to_max() {
+        let mut state = GameState::new("Overheal".to_string(), 0);
+        state.combat_state.player_current_hp = state.combat_state.player_max_hp + 10;
+        let mut enemy = Enemy::new("Rat".to_string(), 40, 5);
+        enemy.current_hp = 60;
+        state.combat_state.current_enemy = Some(enemy);
+
+        let actions = state.validate_and_repair();
+
+        assert_eq!(
+            state.combat_state.player_current_hp,
+            state.combat_state.player_max_hp
+        );
+        assert_eq!(state.combat_state.current_enemy.unwrap().current_hp, 40);
+        assert_eq!(actions.len(), 2);
+    }
+
+    #[test]
+    fn test_misplaced_item_is_unequipped() {
+        let mut state = GameState::new("Mixup".to_string(), 0);
+        state.equipment.weapon = Some(test_item(EquipmentSlot::Ring));
+        state.equipment.ring = Some(test_item(EquipmentSlot::Ring));
+
+        let actions = state.validate_and_repair();
+
+        assert!(state.equipment.weapon.is_none());
+        assert!(state.equipment.ring.is_some());
+        assert_eq!(
+            actions,
+            vec![RepairAction::RemovedMisplacedItem {
+                slot: EquipmentSlot::Weapon,
+                item_name: "Test Item".to_string(),
+            }]
+        );
+    }
+}
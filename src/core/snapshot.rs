@@ -0,0 +1,132 @@
+//! JSON stats snapshots for stream overlays.
+//!
+//! With `quest --stats-file <path>`, the game loop writes a `GameSnapshot`
+//! of the active character every `STATS_FILE_INTERVAL_SECONDS`. Writes go to
+//! a sibling temp file that is then renamed over `path`, so readers never see
+//! a half-written file.
+
+use super::game_state::GameState;
+use crate::zones::get_zone;
+use serde::Serialize;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The overlay-facing view of a character.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GameSnapshot {
+    pub character_name: String,
+    pub character_level: u32,
+    pub character_xp: u64,
+    pub prestige_rank: u32,
+    pub zone_id: u32,
+    pub zone_name: String,
+    pub subzone_id: u32,
+    pub subzone_name: String,
+    pub fighting_boss: bool,
+    pub player_hp: u32,
+    pub player_max_hp: u32,
+    /// Name of the enemy being fought, if any
+    pub enemy_name: Option<String>,
+    pub enemy_hp: Option<u32>,
+    pub enemy_max_hp: Option<u32>,
+    pub in_dungeon: bool,
+    pub fishing_rank: u32,
+    pub xp_per_minute: f64,
+    pub kills_per_minute: f64,
+    pub play_time_seconds: u64,
+}
+
+impl GameSnapshot {
+    pub fn from_state(state: &GameState) -> Self {
+        let zone_id = state.zone_progression.current_zone_id;
+        let subzone_id = state.zone_progression.current_subzone_id;
+        let zone = get_zone(zone_id);
+        let subzone = zone.and_then(|z| z.subzones.iter().find(|s| s.id == subzone_id));
+        let enemy = state.combat_state.current_enemy.as_ref();
+        let rates = state.metrics.latest();
+
+        Self {
+            character_name: state.character_name.clone(),
+            character_level: state.character_level,
+            character_xp: state.character_xp,
+            prestige_rank: state.prestige_rank,
+            zone_id,
+            zone_name: zone.map_or("", |z| z.name).to_string(),
+            subzone_id,
+            subzone_name: subzone.map_or("", |s| s.name).to_string(),
+            fighting_boss: state.zone_progression.fighting_boss,
+            player_hp: state.combat_state.player_current_hp,
+            player_max_hp: state.combat_state.player_max_hp,
+            enemy_name: enemy.map(|e| e.name.clone()),
+            enemy_hp: enemy.map(|e| e.current_hp),
+            enemy_max_hp: enemy.map(|e| e.max_hp),
+            in_dungeon: state.active_dungeon.is_some(),
+            fishing_rank: state.fishing.rank,
+            xp_per_minute: rates.map_or(0.0, |r| r.xp_per_minute),
+            kills_per_minute: rates.map_or(0.0, |r| r.kills_per_minute),
+            play_time_seconds: state.play_time_seconds,
+        }
+    }
+
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+/// Writes a snapshot of `state` to `path`, replacing it atomically.
+pub fn write_stats_file(state: &GameState, path: &Path) -> io::Result<()> {
+    let json = GameSnapshot::from_state(state).to_json()?;
+    let temp = temp_path(path);
+    std::fs::write(&temp, json)?;
+    std::fs::rename(&temp, path)
+}
+
+/// `path` with `.tmp` appended, in the same directory so rename is atomic.
+fn temp_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".tmp");
+    PathBuf::from(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combat::types::Enemy;
+
+    #[test]
+    fn test_snapshot_json_matches_state() {
+        let mut state = GameState::new("Streamer".to_string(), 0);
+        state.character_level = 42;
+        state.prestige_rank = 3;
+        state.combat_state.current_enemy = Some(Enemy::new("Goblin".to_string(), 80, 5));
+
+        let json = GameSnapshot::from_state(&state).to_json().unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(value["character_name"], "Streamer");
+        assert_eq!(value["character_level"], 42);
+        assert_eq!(value["prestige_rank"], 3);
+        assert_eq!(value["zone_id"], state.zone_progression.current_zone_id);
+        assert_eq!(value["zone_name"], get_zone(1).unwrap().name);
+        assert_eq!(value["enemy_name"], "Goblin");
+        assert_eq!(value["enemy_max_hp"], 80);
+        assert_eq!(value["in_dungeon"], false);
+    }
+
+    #[test]
+    fn test_write_stats_file_replaces_contents() {
+        let dir = std::env::temp_dir().join(format!("quest-stats-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("stats.json");
+        std::fs::write(&path, "stale").unwrap();
+
+        let state = GameState::new("Streamer".to_string(), 0);
+        write_stats_file(&state, &path).unwrap();
+
+        let value: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(value["character_name"], "Streamer");
+        assert!(!temp_path(&path).exists());
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -11,28 +11,48 @@ use crate::challenges::menu::ChallengeType;
 use crate::challenges::ActiveMinigame;
 use crate::character::derived_stats::DerivedStats;
 use crate::character::prestige::PrestigeCombatBonuses;
-use crate::combat::logic::{update_combat, CombatEvent, HavenCombatBonuses};
+use crate::combat::combat_math::is_trivial_fight;
+use crate::combat::logic::{
+    end_fast_resolve_batch, fast_resolve_combat, fast_resolve_interrupted, resolve_trivial_kills,
+    trivial_kill_seconds, update_combat_with_rng, CombatEvent, HavenCombatBonuses,
+};
+use crate::combat::training_dummy::update_training_dummy;
+use crate::combat::types::{BossPhase, FastResolve};
 use crate::core::constants::{
-    FINAL_ZONE_ID, HAVEN_MIN_PRESTIGE_RANK, TICKS_PER_SECOND, TICK_INTERVAL_MS,
+    COMBO_BONUS_DURATION_SECONDS, COMBO_DAMAGE_BONUS_PERCENT, FINAL_ZONE_ID,
+    HAVEN_MIN_PRESTIGE_RANK, TICKS_PER_SECOND, TICK_INTERVAL_MS,
+};
+use crate::core::game_event::GameEvent;
+use crate::core::game_logic::{
+    apply_tick_xp_with_rng, despawn_escaped_elite, spawn_enemy_if_needed_with_rng,
+    try_discover_dungeon, verify_kill_xp,
 };
-use crate::core::game_logic::{apply_tick_xp, spawn_enemy_if_needed, try_discover_dungeon};
 use crate::core::game_state::GameState;
+use crate::core::tutorial::TutorialStep;
+use crate::core::world_event::current_world_event;
 use crate::dungeon::logic::{
     add_dungeon_xp, calculate_boss_xp_reward, on_boss_defeated, on_elite_defeated,
     on_room_enemy_defeated, on_treasure_room_entered, update_dungeon,
 };
-use crate::dungeon::types::RoomType;
+use crate::dungeon::types::{DungeonDifficulty, RoomType};
 use crate::fishing::logic::{
-    check_rank_up_with_max, get_max_fishing_rank, tick_fishing_with_haven_result,
+    check_rank_up_with_max, current_hotspot, get_max_fishing_rank, tick_fishing_with_haven_result,
     HavenFishingBonuses,
 };
+use crate::fishing::tournament::{
+    current_tournament, record_tournament_catch, resolve_finished_tournament,
+};
 use crate::haven::Haven;
 use crate::haven::HavenBonusType;
-use crate::items::drops::{try_drop_from_boss, try_drop_from_mob};
+use crate::items::drops::{
+    try_drop_from_boss, try_drop_from_hunt_elite, try_drop_from_mob, try_gem_drop,
+};
+use crate::items::salvage::{is_filtered, salvage_value};
 use crate::items::scoring::auto_equip_if_better;
-use crate::items::types::Rarity;
+use crate::items::types::{Item, Rarity};
 use crate::zones::BossDefeatResult;
 use rand::{Rng, RngExt};
+use std::collections::VecDeque;
 
 /// A single event produced by a game tick.
 ///
@@ -50,6 +70,9 @@ pub enum TickEvent {
         message: String,
     },
 
+    /// Verbose combat log: how the next hit's damage was reached.
+    DamageBreakdown { message: String },
+
     /// Player's attack was blocked because the boss requires a specific weapon.
     PlayerAttackBlocked {
         weapon_needed: String,
@@ -63,16 +86,57 @@ pub enum TickEvent {
         message: String,
     },
 
+    /// Player parried an enemy attack and countered.
+    Parry {
+        counter_damage: u32,
+        enemy_name: String,
+        message: String,
+    },
+
+    /// Alchemy Lab emergency heal restored HP at low health.
+    EmergencyHeal { amount: u32, message: String },
+
+    /// A no-damage kill streak reached a combo milestone.
+    ComboMilestone { combo: u32, message: String },
+
+    /// Storm Citadel final boss entered a new fight phase.
+    BossPhaseChanged { phase: BossPhase, message: String },
+
+    /// A segment of a multi-segment enemy (a hydra head) was destroyed.
+    SegmentDestroyed { segment: String, message: String },
+
+    /// A kill's XP award exceeded `max_plausible_kill_xp` and was clamped.
+    KillXpClamped {
+        awarded: u64,
+        clamped_to: u64,
+        message: String,
+    },
+
     /// Normal enemy or dungeon combat-room enemy was defeated.
     EnemyDefeated {
         xp_gained: u64,
         enemy_name: String,
         message: String,
+        /// Fast-path kill, logged in this tick's `FastResolveSummary` instead
+        quiet: bool,
+    },
+
+    /// A batch of trivial kills resolved in one tick, logged as one line.
+    FastResolveSummary {
+        kills: u32,
+        xp_gained: u64,
+        message: String,
     },
 
     /// Player died in overworld combat (boss encounter reset).
     PlayerDied { message: String },
 
+    /// An Elite Hunt target spawned in the overworld.
+    EliteHuntSpawned { enemy_name: String, message: String },
+
+    /// An Elite Hunt target escaped because its hunt ended.
+    EliteHuntEscaped { enemy_name: String, message: String },
+
     /// Player died in a dungeon (safe exit, no prestige loss).
     PlayerDiedInDungeon { message: String },
 
@@ -87,6 +151,16 @@ pub enum TickEvent {
         from_boss: bool,
     },
 
+    /// A gem dropped into `GameState::gems`.
+    GemFound { message: String },
+
+    /// An unequipped drop fell below the loot filter and was salvaged.
+    ItemSalvaged {
+        item_name: String,
+        rarity: Rarity,
+        materials: u64,
+    },
+
     // ── Zone Progression ────────────────────────────────────────
     /// A subzone boss was defeated and zone progression updated.
     SubzoneBossDefeated {
@@ -182,6 +256,8 @@ pub enum TickEvent {
     // ── Achievements ────────────────────────────────────────────
     /// An achievement was unlocked during this tick.
     AchievementUnlocked { name: String, message: String },
+    /// Every achievement in a category is now unlocked, granting its title.
+    AchievementCategoryCompleted { title: String, message: String },
 
     // ── Level Up ────────────────────────────────────────────────
     /// Player leveled up (may occur multiple times per tick from large XP gains).
@@ -210,6 +286,10 @@ pub struct TickResult {
     /// Populated when the 500ms accumulation window has elapsed.
     /// Empty if no modal is ready or another overlay is already active.
     pub achievement_modal_ready: Vec<crate::achievements::AchievementId>,
+
+    /// If set, the tutorial advanced to this step during the tick.
+    /// The presentation layer shows the step's hint.
+    pub tutorial_hint: Option<TutorialStep>,
 }
 
 /// Processes a single 100ms game tick.
@@ -227,6 +307,9 @@ pub struct TickResult {
 /// - `rng` — Random number generator (any `impl Rng`). Pass
 ///   `&mut rand::rng()` in production, or a seeded
 ///   `rand_chacha::ChaCha8Rng` in tests for deterministic behavior.
+/// - `now` — Wall-clock Unix seconds for scheduled content (Elite Hunts,
+///   fishing tournaments). Pass `chrono::Utc::now().timestamp()` in
+///   production, or a fixed time in tests.
 ///
 /// # Returns
 /// A [`TickResult`] containing all events and flags. The caller (main.rs)
@@ -245,6 +328,7 @@ pub fn game_tick<R: Rng>(
     achievements: &mut Achievements,
     debug_mode: bool,
     rng: &mut R,
+    now: i64,
 ) -> TickResult {
     let mut result = TickResult::default();
     let delta_time = TICK_INTERVAL_MS as f64 / 1000.0;
@@ -354,13 +438,30 @@ pub fn game_tick<R: Rng>(
     }
 
     // ── 5. Update fishing (mutually exclusive with combat) ──────
+    if let Some(message) = resolve_finished_tournament(state, now) {
+        result.events.push(TickEvent::FishingMessage {
+            message: format!("\u{1f3a3} {}", message),
+        });
+    }
+    if let Some(message) = crate::fishing::logic::try_resume_fishing(state, rng) {
+        result.events.push(TickEvent::FishingMessage {
+            message: format!("\u{1f3a3} {}", message),
+        });
+    }
     if state.active_fishing.is_some() {
         let haven_fishing = HavenFishingBonuses {
             timer_reduction_percent: haven.get_bonus(HavenBonusType::FishingTimerReduction),
             double_fish_chance_percent: haven.get_bonus(HavenBonusType::DoubleFishChance),
             max_fishing_rank_bonus: haven.fishing_rank_bonus(),
         };
-        let fishing_result = tick_fishing_with_haven_result(state, rng, &haven_fishing);
+        let hotspot = current_hotspot(now);
+        let fishing_result = tick_fishing_with_haven_result(state, rng, &haven_fishing, hotspot);
+
+        if let Some(window) = current_tournament(now) {
+            for &rarity in &fishing_result.caught {
+                record_tournament_catch(&mut state.fishing, window, rarity);
+            }
+        }
 
         // Storm Leviathan caught → achievement
         if fishing_result.caught_storm_leviathan {
@@ -448,10 +549,12 @@ pub fn game_tick<R: Rng>(
         *tick_counter += 1;
         if *tick_counter >= TICKS_PER_SECOND {
             state.play_time_seconds += 1;
+            state.metrics.sample();
             *tick_counter = 0;
         }
 
         // Skip combat processing while fishing — collect achievements and return
+        process_tutorial(state, &mut result);
         collect_achievement_events(achievements, &mut result);
         return result;
     }
@@ -464,6 +567,7 @@ pub fn game_tick<R: Rng>(
         crit_chance_percent: haven.get_bonus(HavenBonusType::CritChancePercent),
         double_strike_chance: haven.get_bonus(HavenBonusType::DoubleStrikeChance),
         xp_gain_percent: haven.get_bonus(HavenBonusType::XpGainPercent),
+        emergency_heal_chance: haven.emergency_heal_chance(),
     };
     let prestige_combat = PrestigeCombatBonuses::from_rank(state.prestige_rank);
     // Apply prestige flat HP bonus to combat max HP (not in DerivedStats to avoid enemy scaling)
@@ -471,16 +575,80 @@ pub fn game_tick<R: Rng>(
         let boosted_max = derived.max_hp + prestige_combat.flat_hp;
         state.combat_state.update_max_hp(boosted_max);
     }
-    let combat_events = update_combat(
-        state,
-        delta_time,
-        &haven_combat,
-        &prestige_combat,
-        achievements,
-        &derived,
-    );
 
-    for event in combat_events {
+    // Training dummy (debug): immortal target, no kills, drops, or discoveries
+    if state.training_dummy.is_some() {
+        let dummy_events = update_training_dummy(
+            state,
+            delta_time,
+            &haven_combat,
+            &prestige_combat,
+            achievements,
+            &derived,
+        );
+        for event in dummy_events {
+            if let CombatEvent::PlayerAttack { damage, was_crit } = event {
+                result.events.push(player_attack_event(damage, was_crit));
+            }
+        }
+
+        *tick_counter += 1;
+        if *tick_counter >= TICKS_PER_SECOND {
+            state.play_time_seconds += 1;
+            state.metrics.sample();
+            *tick_counter = 0;
+        }
+        collect_achievement_events(achievements, &mut result);
+        return result;
+    }
+
+    // The combat update clears a killed enemy, so remember who we were fighting
+    let fought_enemy_name = state
+        .combat_state
+        .current_enemy
+        .as_ref()
+        .map(|e| e.name.clone())
+        .unwrap_or_default();
+
+    // Farming beaten content: trivial kills are resolved in batches instead
+    // of swing by swing, and logged as one summary line per batch
+    let fast_resolve_kills_due = fast_resolve_combat(state, delta_time);
+    let fast_resolve = fast_resolve_kills_due.is_some();
+    let mut fast_resolve_pending = fast_resolve_kills_due.unwrap_or(0);
+    let mut combat_events: VecDeque<CombatEvent> = if fast_resolve {
+        VecDeque::new()
+    } else {
+        update_combat_with_rng(
+            state,
+            delta_time,
+            &haven_combat,
+            &prestige_combat,
+            achievements,
+            &derived,
+            rng,
+        )
+        .into()
+    };
+    let mut fast_resolve_kills = 0u32;
+    let mut fast_resolve_xp = 0u64;
+
+    loop {
+        // Batched kills are resolved one at a time, so one that calls the boss
+        // or discovers a dungeon or fishing spot ends the batch
+        if combat_events.is_empty() && fast_resolve_pending > 0 && !fast_resolve_interrupted(state)
+        {
+            fast_resolve_pending -= 1;
+            combat_events.extend(resolve_trivial_kills(
+                state,
+                1,
+                &haven_combat,
+                achievements,
+                rng,
+            ));
+        }
+        let Some(event) = combat_events.pop_front() else {
+            break;
+        };
         match event {
             CombatEvent::PlayerAttackBlocked { weapon_needed } => {
                 let message = format!("\u{1f6ab} {} required to damage this foe!", weapon_needed);
@@ -490,55 +658,132 @@ pub fn game_tick<R: Rng>(
                 });
             }
             CombatEvent::PlayerAttack { damage, was_crit } => {
-                let message = if was_crit {
-                    format!("\u{1f4a5} CRITICAL HIT for {} damage!", damage)
-                } else {
-                    format!("\u{2694} You hit for {} damage", damage)
-                };
-                result.events.push(TickEvent::PlayerAttack {
+                result.events.push(player_attack_event(damage, was_crit));
+            }
+            CombatEvent::DamageBreakdown {
+                base,
+                defense,
+                crit_multiplier,
+                damage,
+            } => {
+                let crit =
+                    crit_multiplier.map_or(String::new(), |m| format!(" \u{d7} {:.2} crit", m));
+                let message = format!(
+                    "\u{1f50e} {} base \u{2212} {} DEF{} = {} damage",
+                    base, defense, crit, damage
+                );
+                result.events.push(TickEvent::DamageBreakdown { message });
+            }
+            CombatEvent::EnemyAttack { damage } => {
+                let enemy_name = fought_enemy_name.clone();
+                let message = format!("\u{1f6e1} {} hits you for {} damage", enemy_name, damage);
+                result.events.push(TickEvent::EnemyAttack {
                     damage,
-                    was_crit,
+                    enemy_name,
                     message,
                 });
             }
-            CombatEvent::EnemyAttack { damage } => {
-                let enemy_name = state
+            CombatEvent::Parry { counter_damage } => {
+                let enemy_name = fought_enemy_name.clone();
+                let message = format!(
+                    "\u{2694} Parried {}! Counter for {} damage",
+                    enemy_name, counter_damage
+                );
+                result.events.push(TickEvent::Parry {
+                    counter_damage,
+                    enemy_name,
+                    message,
+                });
+            }
+            CombatEvent::BossPhaseChange { phase } => {
+                let boss_name = state
                     .combat_state
                     .current_enemy
                     .as_ref()
                     .map(|e| e.name.clone())
                     .unwrap_or_default();
-                let message = format!("\u{1f6e1} {} hits you for {} damage", enemy_name, damage);
-                result.events.push(TickEvent::EnemyAttack {
-                    damage,
-                    enemy_name,
-                    message,
-                });
+                let message = match phase {
+                    BossPhase::Calm => continue,
+                    BossPhase::Tempest => {
+                        format!(
+                            "\u{26a1} {} whips into a tempest! Its attacks quicken.",
+                            boss_name
+                        )
+                    }
+                    BossPhase::StormShield => format!(
+                        "\u{1f6e1} {} raises a storm shield! Only Stormbreaker can break through.",
+                        boss_name
+                    ),
+                };
+                result
+                    .events
+                    .push(TickEvent::BossPhaseChanged { phase, message });
             }
-            CombatEvent::EnemyDied { xp_gained } => {
+            CombatEvent::SegmentDestroyed { segment, remaining } => {
                 let enemy_name = state
                     .combat_state
                     .current_enemy
                     .as_ref()
                     .map(|e| e.name.clone())
                     .unwrap_or_default();
+                let message = format!(
+                    "\u{1f5e1} {}'s {} falls! {} to go.",
+                    enemy_name, segment, remaining
+                );
+                result
+                    .events
+                    .push(TickEvent::SegmentDestroyed { segment, message });
+            }
+            CombatEvent::ComboMilestone { combo } => {
+                let message = format!(
+                    "\u{1f525} {} kill combo! +{:.0}% damage for {:.0}s",
+                    combo, COMBO_DAMAGE_BONUS_PERCENT, COMBO_BONUS_DURATION_SECONDS
+                );
+                result
+                    .events
+                    .push(TickEvent::ComboMilestone { combo, message });
+            }
+            CombatEvent::EmergencyHeal { amount } => {
+                let message = format!("\u{2697} Emergency tonic! Restored {} HP", amount);
+                result
+                    .events
+                    .push(TickEvent::EmergencyHeal { amount, message });
+            }
+            CombatEvent::EnemyDied { xp_gained } => {
+                let xp_gained = checked_kill_xp(state, xp_gained, &haven_combat, &mut result);
+                let enemy_name = fought_enemy_name.clone();
                 let message = format!("\u{2728} {} defeated! +{} XP", enemy_name, xp_gained);
+                state.push_game_event(GameEvent::Kill {
+                    enemy_name: enemy_name.clone(),
+                    xp_gained,
+                });
                 result.events.push(TickEvent::EnemyDefeated {
                     xp_gained,
                     enemy_name,
                     message,
+                    quiet: fast_resolve,
                 });
+                if fast_resolve {
+                    fast_resolve_kills += 1;
+                    fast_resolve_xp += xp_gained;
+                }
 
                 // Apply XP and check level up
                 let level_before = state.character_level;
-                apply_tick_xp(state, xp_gained as f64);
+                apply_tick_xp_with_rng(state, xp_gained as f64, rng);
+                state.metrics.record_xp(xp_gained);
                 if state.character_level > level_before {
                     achievements.on_level_up(state.character_level, Some(&state.character_name));
                     result.events.push(TickEvent::LeveledUp {
                         new_level: state.character_level,
                     });
+                    state.push_game_event(GameEvent::LevelUp {
+                        new_level: state.character_level,
+                    });
                 }
                 state.session_kills += 1;
+                state.metrics.record_kill();
+                state.total_kills += 1;
 
                 // Track XP in dungeon and mark room cleared
                 add_dungeon_xp(state, xp_gained);
@@ -548,21 +793,29 @@ pub fn game_tick<R: Rng>(
 
                 // Item drops
                 process_item_drop(state, haven, &mut result);
+                if let Some(gem) = try_gem_drop(rng) {
+                    state.gems.push(gem);
+                    let message = format!(
+                        "\u{1f48e} Found a {}! Socket it at the Workshop.",
+                        gem.name()
+                    );
+                    result.events.push(TickEvent::GemFound { message });
+                }
 
                 // Discovery: dungeon, then fishing
                 process_discoveries(state, rng, &mut result);
             }
             CombatEvent::EliteDefeated { xp_gained } => {
-                let enemy_name = state
-                    .combat_state
-                    .current_enemy
-                    .as_ref()
-                    .map(|e| e.name.clone())
-                    .unwrap_or_default();
+                let xp_gained = checked_kill_xp(state, xp_gained, &haven_combat, &mut result);
+                let enemy_name = fought_enemy_name.clone();
                 let message = format!(
                     "\u{2694}\u{fe0f} {} defeated! +{} XP",
                     enemy_name, xp_gained
                 );
+                state.push_game_event(GameEvent::Kill {
+                    enemy_name: enemy_name.clone(),
+                    xp_gained,
+                });
                 result.events.push(TickEvent::DungeonEliteDefeated {
                     xp_gained,
                     enemy_name,
@@ -570,12 +823,16 @@ pub fn game_tick<R: Rng>(
                 });
 
                 let level_before = state.character_level;
-                apply_tick_xp(state, xp_gained as f64);
+                apply_tick_xp_with_rng(state, xp_gained as f64, rng);
+                state.metrics.record_xp(xp_gained);
                 if state.character_level > level_before {
                     achievements.on_level_up(state.character_level, Some(&state.character_name));
                     result.events.push(TickEvent::LeveledUp {
                         new_level: state.character_level,
                     });
+                    state.push_game_event(GameEvent::LevelUp {
+                        new_level: state.character_level,
+                    });
                 }
                 add_dungeon_xp(state, xp_gained);
 
@@ -592,19 +849,20 @@ pub fn game_tick<R: Rng>(
                 }
             }
             CombatEvent::BossDefeated { xp_gained } => {
-                let enemy_name = state
-                    .combat_state
-                    .current_enemy
-                    .as_ref()
-                    .map(|e| e.name.clone())
-                    .unwrap_or_default();
+                let xp_gained = checked_kill_xp(state, xp_gained, &haven_combat, &mut result);
+                let enemy_name = fought_enemy_name.clone();
+                state.push_game_event(GameEvent::Kill {
+                    enemy_name: enemy_name.clone(),
+                    xp_gained,
+                });
 
                 let level_before = state.character_level;
-                apply_tick_xp(state, xp_gained as f64);
+                apply_tick_xp_with_rng(state, xp_gained as f64, rng);
+                state.metrics.record_xp(xp_gained);
 
                 // Calculate boss bonus XP
                 let (bonus_xp, total_xp, items) = if let Some(dungeon) = &state.active_dungeon {
-                    let bonus = calculate_boss_xp_reward(dungeon.size);
+                    let bonus = calculate_boss_xp_reward(dungeon.size, dungeon.difficulty);
                     let total = dungeon.xp_earned + xp_gained + bonus;
                     let item_count = dungeon.collected_items.len();
                     (bonus, total, item_count)
@@ -612,12 +870,16 @@ pub fn game_tick<R: Rng>(
                     (0, xp_gained, 0)
                 };
 
-                apply_tick_xp(state, bonus_xp as f64);
+                apply_tick_xp_with_rng(state, bonus_xp as f64, rng);
+                state.metrics.record_xp(bonus_xp);
                 if state.character_level > level_before {
                     achievements.on_level_up(state.character_level, Some(&state.character_name));
                     result.events.push(TickEvent::LeveledUp {
                         new_level: state.character_level,
                     });
+                    state.push_game_event(GameEvent::LevelUp {
+                        new_level: state.character_level,
+                    });
                 }
 
                 achievements.on_dungeon_completed(Some(&state.character_name));
@@ -645,6 +907,7 @@ pub fn game_tick<R: Rng>(
                 });
             }
             CombatEvent::PlayerDied => {
+                state.adaptive_intensity.on_death();
                 result.events.push(TickEvent::PlayerDied {
                     message: "\u{1f480} You died! Boss encounter reset.".to_string(),
                 });
@@ -653,15 +916,32 @@ pub fn game_tick<R: Rng>(
                 xp_gained,
                 result: defeat_result,
             } => {
+                let xp_gained = checked_kill_xp(state, xp_gained, &haven_combat, &mut result);
+                state.push_game_event(GameEvent::Kill {
+                    enemy_name: fought_enemy_name.clone(),
+                    xp_gained,
+                });
+                if let BossDefeatResult::ZoneComplete { new_zone_id, .. } = &defeat_result {
+                    state.push_game_event(GameEvent::ZoneAdvance {
+                        new_zone_id: *new_zone_id,
+                    });
+                }
+
                 let level_before = state.character_level;
-                apply_tick_xp(state, xp_gained as f64);
+                apply_tick_xp_with_rng(state, xp_gained as f64, rng);
+                state.metrics.record_xp(xp_gained);
                 if state.character_level > level_before {
                     achievements.on_level_up(state.character_level, Some(&state.character_name));
                     result.events.push(TickEvent::LeveledUp {
                         new_level: state.character_level,
                     });
+                    state.push_game_event(GameEvent::LevelUp {
+                        new_level: state.character_level,
+                    });
                 }
                 state.session_kills += 1;
+                state.metrics.record_kill();
+                state.total_kills += 1;
 
                 // Track zone achievements
                 process_zone_achievements(&defeat_result, achievements, &state.character_name);
@@ -695,6 +975,16 @@ pub fn game_tick<R: Rng>(
                             zone_name, xp_gained, required_prestige
                         )
                     }
+                    BossDefeatResult::ZoneCompleteButUndergeared {
+                        zone_name,
+                        required_gear_score,
+                        gear_score,
+                    } => {
+                        format!(
+                            "\u{1f451} {} conquered! +{} XP \u{2014} Next zone requires Gear Score {} (yours: {}).",
+                            zone_name, xp_gained, required_gear_score, gear_score
+                        )
+                    }
                     BossDefeatResult::StormsEnd => {
                         format!(
                             "\u{1f451} All zones conquered! +{} XP \u{2014} You have completed the game!",
@@ -721,27 +1011,86 @@ pub fn game_tick<R: Rng>(
         }
     }
 
+    if fast_resolve_kills > 0 {
+        end_fast_resolve_batch(state);
+        let message = format!(
+            "\u{26a1} Swept aside {} foes: +{} XP",
+            fast_resolve_kills, fast_resolve_xp
+        );
+        result.events.push(TickEvent::FastResolveSummary {
+            kills: fast_resolve_kills,
+            xp_gained: fast_resolve_xp,
+            message,
+        });
+    }
+
     // ── 7. Spawn enemy if needed ────────────────────────────────
-    spawn_enemy_if_needed(state);
+    if let Some(enemy_name) = despawn_escaped_elite(state, now) {
+        let message = format!("\u{1f4a8} {} escaped! The Elite Hunt is over.", enemy_name);
+        result.events.push(TickEvent::EliteHuntEscaped {
+            enemy_name,
+            message,
+        });
+    }
+    let had_enemy = state.combat_state.current_enemy.is_some();
+    spawn_enemy_if_needed_with_rng(state, current_world_event(now), rng);
+    if !had_enemy {
+        // Checked once per spawn (and once per batch while it lasts), not every tick
+        let trivial = state
+            .combat_state
+            .current_enemy
+            .as_ref()
+            .is_some_and(|enemy| is_trivial_fight(state, enemy));
+        // Time banked toward the next batch carries over to a re-rolled stand-in
+        let elapsed = if trivial {
+            state.combat_state.fast_resolve.elapsed
+        } else {
+            0.0
+        };
+        state.combat_state.fast_resolve = FastResolve {
+            kill_seconds: trivial.then(|| trivial_kill_seconds(state, &haven_combat, &derived)),
+            elapsed,
+        };
+    }
+    if let Some(enemy) = &state.combat_state.current_enemy {
+        if !had_enemy && enemy.is_hunt_elite() {
+            let message = format!(
+                "\u{26a1} Elite Hunt! {} prowls the area. Defeat it before it escapes!",
+                enemy.name
+            );
+            result.events.push(TickEvent::EliteHuntSpawned {
+                enemy_name: enemy.name.clone(),
+                message,
+            });
+        }
+    }
 
     // ── 8. Update play time ─────────────────────────────────────
     *tick_counter += 1;
     if *tick_counter >= TICKS_PER_SECOND {
         state.play_time_seconds += 1;
+        state.metrics.sample();
+        if state.active_dungeon.is_none() {
+            if let Some(sample) = state.metrics.latest() {
+                state.adaptive_intensity.on_second(sample.kills_per_minute);
+            }
+        }
         *tick_counter = 0;
     }
 
     // ── 9. Collect achievement notifications ────────────────────
+    process_tutorial(state, &mut result);
     collect_achievement_events(achievements, &mut result);
 
     // ── 10. Haven discovery check ────────────────────────────────
     // Independent roll per tick, only when eligible (P10+, no active content)
+    let haven_discovery = crate::haven::HavenDiscoveryConfig::default();
     if !haven.discovered
-        && state.prestige_rank >= HAVEN_MIN_PRESTIGE_RANK
+        && haven_discovery.is_eligible(state.prestige_rank)
         && state.active_dungeon.is_none()
         && state.active_fishing.is_none()
         && state.active_minigame.is_none()
-        && crate::haven::try_discover_haven(haven, state.prestige_rank, rng)
+        && crate::haven::try_discover_haven(haven, state.prestige_rank, &haven_discovery, rng)
     {
         // Track Haven discovery achievement
         achievements.on_haven_discovered(Some(&state.character_name));
@@ -760,6 +1109,67 @@ pub fn game_tick<R: Rng>(
     result
 }
 
+/// Advance the first-run tutorial from this tick's events.
+fn process_tutorial(state: &mut GameState, result: &mut TickResult) {
+    if state.tutorial.is_complete() {
+        return;
+    }
+    let tutorial = &mut state.tutorial;
+    let is_fresh = state.character_level == 1 && state.prestige_rank == 0 && state.total_kills == 0;
+    let mut reached = tutorial.begin(is_fresh);
+    for event in &result.events {
+        let step = match event {
+            TickEvent::EnemyDefeated { .. } | TickEvent::SubzoneBossDefeated { .. } => {
+                tutorial.on_enemy_killed()
+            }
+            TickEvent::LeveledUp { .. } => tutorial.on_level_up(),
+            TickEvent::ChallengeDiscovered { .. } => tutorial.on_challenge_discovered(),
+            _ => None,
+        };
+        reached = step.or(reached);
+    }
+    if reached.is_some() {
+        result.tutorial_hint = reached;
+    }
+}
+
+/// Run a kill's XP award through `verify_kill_xp`, warning in the combat
+/// log when it had to be clamped. Returns the XP to apply.
+fn checked_kill_xp(
+    state: &GameState,
+    xp_gained: u64,
+    haven_combat: &HavenCombatBonuses,
+    result: &mut TickResult,
+) -> u64 {
+    let (xp, clamped) = verify_kill_xp(state, xp_gained, haven_combat.xp_gain_percent);
+    if clamped {
+        let message = format!(
+            "\u{26a0} Kill XP {} exceeds the expected maximum, clamped to {}",
+            xp_gained, xp
+        );
+        result.events.push(TickEvent::KillXpClamped {
+            awarded: xp_gained,
+            clamped_to: xp,
+            message,
+        });
+    }
+    xp
+}
+
+/// Build the combat log event for a player hit.
+fn player_attack_event(damage: u32, was_crit: bool) -> TickEvent {
+    let message = if was_crit {
+        format!("\u{1f4a5} CRITICAL HIT for {} damage!", damage)
+    } else {
+        format!("\u{2694} You hit for {} damage", damage)
+    };
+    TickEvent::PlayerAttack {
+        damage,
+        was_crit,
+        message,
+    }
+}
+
 /// Collect newly unlocked achievements into TickResult events.
 fn collect_achievement_events(achievements: &mut Achievements, result: &mut TickResult) {
     for id in achievements.take_newly_unlocked() {
@@ -772,6 +1182,18 @@ fn collect_achievement_events(achievements: &mut Achievements, result: &mut Tick
             result.achievements_changed = true;
         }
     }
+    for category in achievements.take_newly_completed_categories() {
+        let message = format!(
+            "\u{1f451} {} achievements complete! Title earned: {}",
+            category.name(),
+            category.completion_title()
+        );
+        result.events.push(TickEvent::AchievementCategoryCompleted {
+            title: category.completion_title().to_string(),
+            message,
+        });
+        result.achievements_changed = true;
+    }
 }
 
 /// Process item drops after killing a mob/boss in overworld combat.
@@ -780,8 +1202,16 @@ fn process_item_drop(state: &mut GameState, haven: &Haven, result: &mut TickResu
     let was_boss = state.zone_progression.fighting_boss;
     let is_final_zone = zone_id == FINAL_ZONE_ID as usize;
 
+    let was_hunt_elite = state
+        .combat_state
+        .current_enemy
+        .as_ref()
+        .is_some_and(|e| e.is_hunt_elite());
+
     let dropped_item = if was_boss {
         Some(try_drop_from_boss(zone_id, is_final_zone))
+    } else if was_hunt_elite {
+        Some(try_drop_from_hunt_elite(zone_id))
     } else {
         let haven_drop_rate = haven.get_bonus(HavenBonusType::DropRatePercent);
         let haven_rarity = haven.get_bonus(HavenBonusType::ItemRarityPercent);
@@ -789,29 +1219,41 @@ fn process_item_drop(state: &mut GameState, haven: &Haven, result: &mut TickResu
     };
 
     if let Some(item) = dropped_item {
-        let item_name = item.display_name.clone();
-        let rarity = item.rarity;
-        let slot = item.slot_name().to_string();
-        let stats = item.stat_summary();
-        let icon = if was_boss { "\u{1f451}" } else { "\u{1f381}" };
-        let equipped = auto_equip_if_better(item, state);
-        state.add_recent_drop(
-            item_name.clone(),
-            rarity,
-            equipped,
-            icon,
-            slot.clone(),
-            stats.clone(),
-        );
-        result.events.push(TickEvent::ItemDropped {
-            item_name,
-            rarity,
-            equipped,
-            slot,
-            stats,
-            from_boss: was_boss,
+        resolve_item_drop(state, item, was_boss || was_hunt_elite, result);
+    }
+}
+
+/// Auto-equips a dropped item, or salvages it if it isn't equipped and falls
+/// below the loot filter. Kept drops are shown in the Loot panel.
+fn resolve_item_drop(state: &mut GameState, item: Item, was_boss: bool, result: &mut TickResult) {
+    let icon = if was_boss { "\u{1f451}" } else { "\u{1f381}" };
+    let equipped = auto_equip_if_better(item.clone(), state);
+
+    if !equipped && is_filtered(item.rarity, state.loot_filter_min_rarity) {
+        let materials = salvage_value(item.rarity);
+        state.salvage_materials += materials;
+        result.events.push(TickEvent::ItemSalvaged {
+            item_name: item.display_name,
+            rarity: item.rarity,
+            materials,
         });
+        return;
     }
+
+    state.add_recent_item_drop(&item, equipped, icon);
+    state.push_game_event(GameEvent::Drop {
+        item_name: item.display_name.clone(),
+        rarity: item.rarity,
+        equipped,
+    });
+    result.events.push(TickEvent::ItemDropped {
+        item_name: item.display_name.clone(),
+        rarity: item.rarity,
+        equipped,
+        slot: item.slot_name().to_string(),
+        stats: item.stat_summary(),
+        from_boss: was_boss,
+    });
 }
 
 /// Try to discover dungeon or fishing spot after killing an enemy.
@@ -819,9 +1261,13 @@ fn process_discoveries<R: Rng>(state: &mut GameState, rng: &mut R, result: &mut
     // Try dungeon discovery (only outside dungeons)
     let discovered_dungeon = state.active_dungeon.is_none() && try_discover_dungeon(state);
     if discovered_dungeon {
-        result.events.push(TickEvent::DungeonDiscovered {
-            message: "\u{1f300} You notice a dark passage leading underground...".to_string(),
-        });
+        let mut message = "\u{1f300} You notice a dark passage leading underground...".to_string();
+        if let Some(dungeon) = &state.active_dungeon {
+            if dungeon.difficulty != DungeonDifficulty::Normal {
+                message.push_str(&format!(" ({})", dungeon.difficulty.name()));
+            }
+        }
+        result.events.push(TickEvent::DungeonDiscovered { message });
     }
 
     // Try fishing spot discovery (only if no dungeon or fishing active)
@@ -845,6 +1291,10 @@ fn process_zone_achievements(
         | BossDefeatResult::ZoneCompleteButGated {
             zone_name: old_zone,
             ..
+        }
+        | BossDefeatResult::ZoneCompleteButUndergeared {
+            zone_name: old_zone,
+            ..
         } => {
             if let Some(zone) = crate::zones::get_all_zones()
                 .iter()
@@ -871,6 +1321,9 @@ mod tests {
     use rand::SeedableRng;
     use rand_chacha::ChaCha8Rng;
 
+    /// Fixed time between Elite Hunts, so spawns depend only on the seeded RNG
+    const BETWEEN_HUNTS: i64 = crate::core::constants::ELITE_HUNT_ACTIVE_SECONDS;
+
     fn test_rng() -> ChaCha8Rng {
         ChaCha8Rng::seed_from_u64(42)
     }
@@ -890,6 +1343,7 @@ mod tests {
             &mut achievements,
             false,
             &mut rng,
+            BETWEEN_HUNTS,
         );
 
         // A fresh state with no enemy should just spawn an enemy
@@ -900,6 +1354,95 @@ mod tests {
         assert!(result.leviathan_encounter.is_none());
     }
 
+    #[test]
+    fn test_game_tick_resumes_auto_fishing_after_session_ends() {
+        let mut state = GameState::new("Angler".to_string(), 0);
+        let mut tick_counter = 0u32;
+        let mut haven = Haven::default();
+        let mut achievements = Achievements::default();
+        let mut rng = test_rng();
+        state.fishing.auto_fishing = true;
+        state.fishing.last_spot = Some("Crystal Lake".to_string());
+        state.active_fishing = None;
+
+        let result = game_tick(
+            &mut state,
+            &mut tick_counter,
+            &mut haven,
+            &mut achievements,
+            false,
+            &mut rng,
+            BETWEEN_HUNTS,
+        );
+
+        let session = state.active_fishing.as_ref().expect("fishing resumed");
+        assert_eq!(session.spot_name, "Crystal Lake");
+        assert!(result
+            .events
+            .iter()
+            .any(|e| matches!(e, TickEvent::FishingMessage { message } if message.contains("Crystal Lake"))));
+    }
+
+    #[test]
+    fn test_game_tick_starts_tutorial_and_advances_on_kill() {
+        let mut state = GameState::new("Tutorial Test".to_string(), 0);
+        let mut tick_counter = 0u32;
+        let mut haven = Haven::default();
+        let mut achievements = Achievements::default();
+        let mut rng = test_rng();
+
+        let result = game_tick(
+            &mut state,
+            &mut tick_counter,
+            &mut haven,
+            &mut achievements,
+            false,
+            &mut rng,
+            BETWEEN_HUNTS,
+        );
+        assert_eq!(result.tutorial_hint, Some(TutorialStep::FirstKill));
+
+        // Weak enemy dies to the next player attack. Raise the level so the
+        // kill's XP doesn't also trigger the level-up step this tick.
+        state.character_level = 10;
+        state.combat_state.current_enemy =
+            Some(crate::combat::types::Enemy::new("Dummy".to_string(), 1, 0));
+        state.combat_state.player_attack_timer = crate::core::constants::ATTACK_INTERVAL_SECONDS;
+        let result = game_tick(
+            &mut state,
+            &mut tick_counter,
+            &mut haven,
+            &mut achievements,
+            false,
+            &mut rng,
+            BETWEEN_HUNTS,
+        );
+        assert_eq!(result.tutorial_hint, Some(TutorialStep::FirstLevelUp));
+        assert_eq!(state.tutorial.step, TutorialStep::FirstLevelUp);
+    }
+
+    #[test]
+    fn test_game_tick_completes_tutorial_silently_for_veteran() {
+        let mut state = GameState::new("Veteran".to_string(), 0);
+        state.character_level = 30;
+        let mut tick_counter = 0u32;
+        let mut haven = Haven::default();
+        let mut achievements = Achievements::default();
+        let mut rng = test_rng();
+
+        let result = game_tick(
+            &mut state,
+            &mut tick_counter,
+            &mut haven,
+            &mut achievements,
+            false,
+            &mut rng,
+            BETWEEN_HUNTS,
+        );
+        assert!(result.tutorial_hint.is_none());
+        assert!(state.tutorial.is_complete());
+    }
+
     #[test]
     fn test_game_tick_increments_play_time() {
         let mut state = GameState::new("Time Test".to_string(), 0);
@@ -918,6 +1461,7 @@ mod tests {
                 &mut achievements,
                 false,
                 &mut rng,
+                BETWEEN_HUNTS,
             );
         }
 
@@ -942,6 +1486,7 @@ mod tests {
             &mut achievements,
             false,
             &mut rng,
+            BETWEEN_HUNTS,
         );
 
         assert!(state.combat_state.current_enemy.is_some());
@@ -972,6 +1517,7 @@ mod tests {
                 &mut achievements,
                 false,
                 &mut rng,
+                BETWEEN_HUNTS,
             );
             all_events.extend(result.events);
 
@@ -991,4 +1537,272 @@ mod tests {
             "Should have an EnemyDefeated event"
         );
     }
+
+    #[test]
+    fn test_game_tick_kill_pushes_game_event() {
+        let mut state = GameState::new("Event Test".to_string(), 0);
+        let mut tick_counter = 0u32;
+        let mut haven = Haven::default();
+        let mut achievements = Achievements::default();
+        let mut rng = test_rng();
+
+        state.combat_state.current_enemy =
+            Some(crate::combat::types::Enemy::new("Dummy".to_string(), 1, 0));
+        state.combat_state.player_attack_timer = crate::core::constants::ATTACK_INTERVAL_SECONDS;
+        let result = game_tick(
+            &mut state,
+            &mut tick_counter,
+            &mut haven,
+            &mut achievements,
+            false,
+            &mut rng,
+            BETWEEN_HUNTS,
+        );
+
+        let xp = result
+            .events
+            .iter()
+            .find_map(|e| match e {
+                TickEvent::EnemyDefeated { xp_gained, .. } => Some(*xp_gained),
+                _ => None,
+            })
+            .expect("Should have an EnemyDefeated event");
+        let events = state.drain_events();
+        assert!(events.contains(&GameEvent::Kill {
+            enemy_name: "Dummy".to_string(),
+            xp_gained: xp,
+        }));
+        assert!(state.game_events.is_empty());
+    }
+
+    /// A P0 character strong enough that every Zone 1 mob is a trivial fight
+    fn farming_state() -> GameState {
+        use crate::character::attributes::AttributeType;
+
+        let mut state = GameState::new("Farmer".to_string(), 0);
+        for attr in AttributeType::all() {
+            state.attributes.set(attr, 60);
+        }
+        let derived = DerivedStats::calculate_derived_stats(&state.attributes, &state.equipment);
+        state.combat_state.update_max_hp(derived.max_hp);
+        state.combat_state.player_current_hp = derived.max_hp;
+        state
+    }
+
+    #[test]
+    fn test_fast_resolve_batches_several_kills_per_tick() {
+        let mut state = farming_state();
+        let mut tick_counter = 0u32;
+        let mut haven = Haven::default();
+        let mut achievements = Achievements::default();
+        let mut rng = test_rng();
+
+        let mut batches = 0;
+        let mut most_kills = 0;
+        for _ in 0..60 * TICKS_PER_SECOND {
+            let result = game_tick(
+                &mut state,
+                &mut tick_counter,
+                &mut haven,
+                &mut achievements,
+                false,
+                &mut rng,
+                BETWEEN_HUNTS,
+            );
+            let mut quiet_kills = 0u32;
+            let mut quiet_xp = 0u64;
+            let mut summary = None;
+            for event in &result.events {
+                match event {
+                    TickEvent::EnemyDefeated {
+                        xp_gained,
+                        quiet: true,
+                        ..
+                    } => {
+                        quiet_kills += 1;
+                        quiet_xp += xp_gained;
+                    }
+                    TickEvent::FastResolveSummary {
+                        kills, xp_gained, ..
+                    } => summary = Some((*kills, *xp_gained)),
+                    _ => {}
+                }
+            }
+            // Each summary covers exactly the kills resolved in its tick
+            assert_eq!(summary.unwrap_or((0, 0)), (quiet_kills, quiet_xp));
+            if quiet_kills > 0 {
+                batches += 1;
+                most_kills = most_kills.max(quiet_kills);
+            }
+        }
+
+        assert!(batches > 0);
+        assert!(
+            most_kills > 1,
+            "a batch should resolve several kills at once"
+        );
+    }
+
+    #[test]
+    fn test_fast_resolve_batch_stops_at_dungeon_discovery() {
+        let mut haven = Haven::default();
+        let mut achievements = Achievements::default();
+        let mut rng = test_rng();
+        let mut tick_counter = 0u32;
+        let batch_kills = crate::core::constants::KILLS_FOR_BOSS - 1;
+
+        // Discovery rolls on the thread RNG, so retry until one lands mid-batch
+        for _ in 0..2000 {
+            let mut state = farming_state();
+            state.combat_state.current_enemy = Some(crate::combat::types::Enemy::new(
+                "Field Mouse".to_string(),
+                1,
+                0,
+            ));
+            state.combat_state.fast_resolve = FastResolve {
+                kill_seconds: Some(2.0),
+                elapsed: batch_kills as f64 * 2.0,
+            };
+            let result = game_tick(
+                &mut state,
+                &mut tick_counter,
+                &mut haven,
+                &mut achievements,
+                false,
+                &mut rng,
+                BETWEEN_HUNTS,
+            );
+            let Some(discovered_at) = result
+                .events
+                .iter()
+                .position(|e| matches!(e, TickEvent::DungeonDiscovered { .. }))
+            else {
+                continue;
+            };
+            let kills = result
+                .events
+                .iter()
+                .filter(|e| matches!(e, TickEvent::EnemyDefeated { .. }))
+                .count() as u32;
+            if kills == batch_kills {
+                continue;
+            }
+
+            // No kill after the discovery, and none counted inside the dungeon
+            assert!(!result.events[discovered_at..]
+                .iter()
+                .any(|e| matches!(e, TickEvent::EnemyDefeated { .. })));
+            assert_eq!(state.zone_progression.kills_in_subzone, kills);
+            assert_eq!(state.active_dungeon.as_ref().unwrap().xp_earned, 0);
+            // The overworld stand-in is gone and the fast path is off
+            assert!(state.combat_state.current_enemy.is_none());
+            assert_eq!(state.combat_state.fast_resolve, FastResolve::default());
+            return;
+        }
+        panic!("no dungeon was discovered partway through a batch");
+    }
+
+    #[test]
+    fn test_elite_hunt_can_spawn_while_fast_resolving() {
+        let mut state = farming_state();
+        let mut tick_counter = 0u32;
+        let mut haven = Haven::default();
+        let mut achievements = Achievements::default();
+        let mut rng = test_rng();
+        // Start of an Elite Hunt window
+        let hunt_time = 0;
+
+        let mut quiet_kills = 0;
+        for _ in 0..10_000 * TICKS_PER_SECOND {
+            let result = game_tick(
+                &mut state,
+                &mut tick_counter,
+                &mut haven,
+                &mut achievements,
+                false,
+                &mut rng,
+                hunt_time,
+            );
+            // Keep the run in the overworld
+            state.active_dungeon = None;
+            state.active_fishing = None;
+            for event in &result.events {
+                match event {
+                    TickEvent::EnemyDefeated { quiet: true, .. } => quiet_kills += 1,
+                    TickEvent::EliteHuntSpawned { .. } => {
+                        assert!(quiet_kills > 0, "the elite should spawn between batches");
+                        return;
+                    }
+                    _ => {}
+                }
+            }
+        }
+        panic!("no hunt elite spawned while farming trivial content");
+    }
+
+    fn weapon(rarity: Rarity, str_bonus: u32) -> Item {
+        Item {
+            slot: crate::items::types::EquipmentSlot::Weapon,
+            rarity,
+            ilvl: 10,
+            base_name: "Sword".to_string(),
+            display_name: format!("{} Sword", rarity.name()),
+            attributes: crate::items::types::AttributeBonuses {
+                str: str_bonus,
+                ..crate::items::types::AttributeBonuses::new()
+            },
+            affixes: vec![],
+            sockets: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_loot_filter_salvages_low_rarity_and_keeps_high() {
+        let mut state = GameState::new("Test".to_string(), 0);
+        state.loot_filter_min_rarity = Some(Rarity::Rare);
+        // A strong equipped weapon so neither drop is an upgrade
+        state.equipment.set(
+            crate::items::types::EquipmentSlot::Weapon,
+            Some(weapon(Rarity::Legendary, 50)),
+        );
+        let mut result = TickResult::default();
+
+        resolve_item_drop(&mut state, weapon(Rarity::Common, 1), false, &mut result);
+        resolve_item_drop(&mut state, weapon(Rarity::Epic, 1), false, &mut result);
+
+        assert!(matches!(
+            result.events[0],
+            TickEvent::ItemSalvaged {
+                rarity: Rarity::Common,
+                ..
+            }
+        ));
+        assert!(matches!(
+            result.events[1],
+            TickEvent::ItemDropped {
+                rarity: Rarity::Epic,
+                equipped: false,
+                ..
+            }
+        ));
+        assert_eq!(state.salvage_materials, salvage_value(Rarity::Common));
+        assert_eq!(state.recent_drops.len(), 1);
+        assert_eq!(state.recent_drops[0].rarity, Rarity::Epic);
+    }
+
+    #[test]
+    fn test_loot_filter_never_salvages_equipped_drop() {
+        let mut state = GameState::new("Test".to_string(), 0);
+        state.loot_filter_min_rarity = Some(Rarity::Legendary);
+        let mut result = TickResult::default();
+
+        // Empty weapon slot: the Common drop is equipped, so it is kept
+        resolve_item_drop(&mut state, weapon(Rarity::Common, 1), false, &mut result);
+
+        assert!(matches!(
+            result.events[0],
+            TickEvent::ItemDropped { equipped: true, .. }
+        ));
+        assert_eq!(state.salvage_materials, 0);
+    }
 }
@@ -0,0 +1,179 @@
+//! First-run tutorial.
+//!
+//! New characters are walked through the core loop with a short sequence of
+//! hints. Each step waits for a game event (first kill, first level-up, first
+//! challenge discovery) and advances automatically. The tutorial can be skipped
+//! at any time and never re-triggers once complete.
+
+use serde::{Deserialize, Serialize};
+
+/// Tutorial steps, in order. Each step names the event the player is waiting on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TutorialStep {
+    /// Not yet evaluated (default for saves that predate the tutorial)
+    #[default]
+    NotStarted,
+    FirstKill,
+    FirstLevelUp,
+    FirstChallenge,
+    Complete,
+}
+
+impl TutorialStep {
+    /// Hint shown when this step is reached.
+    pub fn hint(&self) -> &'static str {
+        match self {
+            TutorialStep::NotStarted => "",
+            TutorialStep::FirstKill => {
+                "Welcome, hero! You fight automatically. Watch the combat log as you take on your first enemy."
+            }
+            TutorialStep::FirstLevelUp => {
+                "First victory! Kills grant XP. Earn enough to level up and your attributes will grow."
+            }
+            TutorialStep::FirstChallenge => {
+                "Level up! Keep fighting. Wanderers sometimes appear and offer challenges for rewards."
+            }
+            TutorialStep::Complete => {
+                "A challenge awaits! Press [Tab] to view pending challenges. Tutorial complete."
+            }
+        }
+    }
+}
+
+/// Tutorial progress for a character (saved to disk).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct TutorialState {
+    pub step: TutorialStep,
+}
+
+impl TutorialState {
+    pub fn is_complete(&self) -> bool {
+        self.step == TutorialStep::Complete
+    }
+
+    /// Starts the tutorial on a fresh character. Characters that have already
+    /// made progress (older saves) skip it silently. Returns the step reached
+    /// if a hint should be shown.
+    pub fn begin(&mut self, is_fresh_character: bool) -> Option<TutorialStep> {
+        if self.step != TutorialStep::NotStarted {
+            return None;
+        }
+        if is_fresh_character {
+            self.advance(TutorialStep::FirstKill)
+        } else {
+            self.step = TutorialStep::Complete;
+            None
+        }
+    }
+
+    pub fn on_enemy_killed(&mut self) -> Option<TutorialStep> {
+        self.advance_from(TutorialStep::FirstKill, TutorialStep::FirstLevelUp)
+    }
+
+    pub fn on_level_up(&mut self) -> Option<TutorialStep> {
+        self.advance_from(TutorialStep::FirstLevelUp, TutorialStep::FirstChallenge)
+    }
+
+    pub fn on_challenge_discovered(&mut self) -> Option<TutorialStep> {
+        self.advance_from(TutorialStep::FirstChallenge, TutorialStep::Complete)
+    }
+
+    /// Ends the tutorial immediately.
+    pub fn skip(&mut self) {
+        self.step = TutorialStep::Complete;
+    }
+
+    fn advance_from(&mut self, expected: TutorialStep, next: TutorialStep) -> Option<TutorialStep> {
+        if self.step == expected {
+            self.advance(next)
+        } else {
+            None
+        }
+    }
+
+    fn advance(&mut self, next: TutorialStep) -> Option<TutorialStep> {
+        self.step = next;
+        Some(next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn started() -> TutorialState {
+        let mut tutorial = TutorialState::default();
+        tutorial.begin(true);
+        tutorial
+    }
+
+    #[test]
+    fn test_default_is_not_started() {
+        assert_eq!(TutorialState::default().step, TutorialStep::NotStarted);
+    }
+
+    #[test]
+    fn test_begin_fresh_character_waits_for_first_kill() {
+        let mut tutorial = TutorialState::default();
+        assert_eq!(tutorial.begin(true), Some(TutorialStep::FirstKill));
+        // Beginning again is a no-op
+        assert_eq!(tutorial.begin(true), None);
+    }
+
+    #[test]
+    fn test_begin_existing_character_completes_silently() {
+        let mut tutorial = TutorialState::default();
+        assert_eq!(tutorial.begin(false), None);
+        assert!(tutorial.is_complete());
+    }
+
+    #[test]
+    fn test_enemy_killed_advances_past_first_kill() {
+        let mut tutorial = started();
+        assert_eq!(tutorial.on_enemy_killed(), Some(TutorialStep::FirstLevelUp));
+        // Further kills don't advance
+        assert_eq!(tutorial.on_enemy_killed(), None);
+        assert_eq!(tutorial.step, TutorialStep::FirstLevelUp);
+    }
+
+    #[test]
+    fn test_steps_advance_in_order() {
+        let mut tutorial = started();
+        // Out-of-order events are ignored
+        assert_eq!(tutorial.on_level_up(), None);
+        assert_eq!(tutorial.on_challenge_discovered(), None);
+
+        tutorial.on_enemy_killed();
+        assert_eq!(tutorial.on_level_up(), Some(TutorialStep::FirstChallenge));
+        assert_eq!(
+            tutorial.on_challenge_discovered(),
+            Some(TutorialStep::Complete)
+        );
+        assert!(tutorial.is_complete());
+    }
+
+    #[test]
+    fn test_completed_tutorial_never_retriggers() {
+        let mut tutorial = started();
+        tutorial.skip();
+        assert!(tutorial.is_complete());
+
+        assert_eq!(tutorial.begin(true), None);
+        assert_eq!(tutorial.on_enemy_killed(), None);
+        assert_eq!(tutorial.on_level_up(), None);
+        assert_eq!(tutorial.on_challenge_discovered(), None);
+        assert!(tutorial.is_complete());
+    }
+
+    #[test]
+    fn test_every_step_after_start_has_hint() {
+        for step in [
+            TutorialStep::FirstKill,
+            TutorialStep::FirstLevelUp,
+            TutorialStep::FirstChallenge,
+            TutorialStep::Complete,
+        ] {
+            assert!(!step.hint().is_empty());
+        }
+    }
+}
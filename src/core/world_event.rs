@@ -0,0 +1,55 @@
+//! Time-limited world events.
+//!
+//! Like fishing hotspots, events run on a fixed wall-clock schedule, so every
+//! player sees the same event at the same time.
+
+use super::constants::{ELITE_HUNT_ACTIVE_SECONDS, WORLD_EVENT_WINDOW_SECONDS};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorldEvent {
+    /// A hunted elite may spawn in the overworld. It escapes at `ends_at`
+    /// (Unix seconds) if still alive.
+    EliteHunt { ends_at: i64 },
+}
+
+/// The world event running at `timestamp` (Unix seconds).
+///
+/// Time is split into `WORLD_EVENT_WINDOW_SECONDS` windows, and an Elite Hunt
+/// runs for the first `ELITE_HUNT_ACTIVE_SECONDS` of each. Returns None
+/// between hunts.
+pub fn current_world_event(timestamp: i64) -> Option<WorldEvent> {
+    let into_window = timestamp.rem_euclid(WORLD_EVENT_WINDOW_SECONDS);
+    if into_window >= ELITE_HUNT_ACTIVE_SECONDS {
+        return None;
+    }
+    Some(WorldEvent::EliteHunt {
+        ends_at: timestamp - into_window + ELITE_HUNT_ACTIVE_SECONDS,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_elite_hunt_runs_at_start_of_each_window() {
+        let window_start = 5 * WORLD_EVENT_WINDOW_SECONDS;
+        let expected = Some(WorldEvent::EliteHunt {
+            ends_at: window_start + ELITE_HUNT_ACTIVE_SECONDS,
+        });
+
+        assert_eq!(current_world_event(window_start), expected);
+        assert_eq!(
+            current_world_event(window_start + ELITE_HUNT_ACTIVE_SECONDS - 1),
+            expected
+        );
+        assert_eq!(
+            current_world_event(window_start + ELITE_HUNT_ACTIVE_SECONDS),
+            None
+        );
+        assert_eq!(
+            current_world_event(window_start + WORLD_EVENT_WINDOW_SECONDS - 1),
+            None
+        );
+    }
+}
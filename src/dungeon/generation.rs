@@ -5,28 +5,75 @@
 #![allow(dead_code)]
 
 use super::types::{
-    Dungeon, DungeonSize, Room, RoomState, RoomType, DIR_DOWN, DIR_LEFT, DIR_OFFSETS, DIR_RIGHT,
-    DIR_UP,
+    Dungeon, DungeonDifficulty, DungeonSize, Room, RoomState, RoomType, DIR_DOWN, DIR_LEFT,
+    DIR_OFFSETS, DIR_RIGHT, DIR_UP,
 };
 use crate::core::constants::{
     DUNGEON_EXTRA_CONNECTION_CHANCE, DUNGEON_MIN_BOSS_DISTANCE, DUNGEON_MIN_ELITE_DISTANCE,
 };
 use rand::seq::{IndexedRandom, SliceRandom};
-use rand::{Rng, RngExt};
+use rand::{Rng, RngExt, SeedableRng};
+use rand_chacha::ChaCha8Rng;
 
-/// Generates a complete dungeon with rooms and connections.
+/// Generates a complete Normal-difficulty dungeon with rooms and connections.
 /// `zone_id` is the zone where the dungeon was discovered, used for enemy scaling.
 pub fn generate_dungeon(level: u32, prestige_rank: u32, zone_id: u32) -> Dungeon {
+    generate_dungeon_with_difficulty(level, prestige_rank, zone_id, DungeonDifficulty::Normal)
+}
+
+/// Generates a dungeon with a size rolled from progression and the given difficulty.
+pub fn generate_dungeon_with_difficulty(
+    level: u32,
+    prestige_rank: u32,
+    zone_id: u32,
+    difficulty: DungeonDifficulty,
+) -> Dungeon {
     let size = DungeonSize::roll_from_progression(level, prestige_rank);
+    generate_dungeon_of_size(size, zone_id, difficulty)
+}
+
+/// Generates a dungeon of a fixed size and difficulty from a fresh random seed.
+pub fn generate_dungeon_of_size(
+    size: DungeonSize,
+    zone_id: u32,
+    difficulty: DungeonDifficulty,
+) -> Dungeon {
+    generate_seeded_dungeon(rand::rng().random(), size, zone_id, difficulty)
+}
+
+/// Generates a dungeon from a shared seed. Difficulty and layout come from
+/// `seed`, so players with the same seed, prestige and rolled size get the
+/// same dungeon. The size roll itself still follows progression.
+pub fn generate_dungeon_from_seed(
+    seed: u64,
+    level: u32,
+    prestige_rank: u32,
+    zone_id: u32,
+) -> Dungeon {
+    let size = DungeonSize::roll_from_progression(level, prestige_rank);
+    let difficulty = DungeonDifficulty::roll(prestige_rank, &mut ChaCha8Rng::seed_from_u64(seed));
+    generate_seeded_dungeon(seed, size, zone_id, difficulty)
+}
+
+/// Generates a dungeon whose layout is fully determined by `seed`, `size`
+/// and `difficulty`. The seed is kept on `Dungeon::seed` for sharing.
+pub fn generate_seeded_dungeon(
+    seed: u64,
+    size: DungeonSize,
+    zone_id: u32,
+    difficulty: DungeonDifficulty,
+) -> Dungeon {
     let mut dungeon = Dungeon::new(size);
     dungeon.zone_id = zone_id;
-    let mut rng = rand::rng();
+    dungeon.difficulty = difficulty;
+    dungeon.seed = seed;
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
 
     // Generate maze structure (without extra connections yet)
-    generate_maze(&mut dungeon);
+    generate_maze(&mut dungeon, &mut rng);
 
     // Place special rooms first (boss needs to be in a dead end)
-    place_special_rooms(&mut dungeon);
+    place_special_rooms(&mut dungeon, &mut rng);
 
     // Add extra connections for variety, but skip boss room to keep it as dead end
     add_extra_connections(&mut dungeon, &mut rng);
@@ -45,10 +92,9 @@ pub fn generate_dungeon(level: u32, prestige_rank: u32, zone_id: u32) -> Dungeon
 }
 
 /// Generates maze using randomized depth-first search (recursive backtracker)
-fn generate_maze(dungeon: &mut Dungeon) {
+fn generate_maze(dungeon: &mut Dungeon, rng: &mut impl Rng) {
     let grid_size = dungeon.size.grid_size();
     let (min_rooms, max_rooms) = dungeon.size.room_count_range();
-    let mut rng = rand::rng();
 
     // Target room count
     let target_rooms = rng.random_range(min_rooms..=max_rooms);
@@ -94,7 +140,7 @@ fn generate_maze(dungeon: &mut Dungeon) {
             stack.pop();
         } else {
             // Choose random neighbor
-            let &(nx, ny, dir_idx) = neighbors.choose(&mut rng).unwrap();
+            let &(nx, ny, dir_idx) = neighbors.choose(rng).unwrap();
 
             // Create room at neighbor
             dungeon.grid[ny][nx] = Some(Room::new(RoomType::Combat, (nx, ny)));
@@ -208,9 +254,7 @@ fn find_dead_ends(dungeon: &Dungeon) -> Vec<(usize, usize)> {
 }
 
 /// Places special rooms (entrance, boss, elite, treasure)
-fn place_special_rooms(dungeon: &mut Dungeon) {
-    let mut rng = rand::rng();
-
+fn place_special_rooms(dungeon: &mut Dungeon, rng: &mut impl Rng) {
     // Collect all room positions
     let mut room_positions: Vec<(usize, usize)> = Vec::new();
     let grid_size = dungeon.size.grid_size();
@@ -342,10 +386,12 @@ fn place_special_rooms(dungeon: &mut Dungeon) {
     room_positions.retain(|&p| p != elite_pos);
 
     // Shuffle remaining rooms for random placement
-    room_positions.shuffle(&mut rng);
+    room_positions.shuffle(rng);
 
-    // Place treasure rooms based on dungeon size
-    let treasure_count = dungeon.size.treasure_room_count().min(room_positions.len());
+    // Place treasure rooms based on dungeon size, plus extras on harder difficulties
+    let treasure_count = (dungeon.size.treasure_room_count()
+        + dungeon.difficulty.extra_treasure_rooms())
+    .min(room_positions.len());
     for _ in 0..treasure_count {
         if let Some(pos) = room_positions.pop() {
             if let Some(room) = dungeon.get_room_mut(pos.0, pos.1) {
@@ -364,12 +410,17 @@ fn distance_squared(a: (usize, usize), b: (usize, usize)) -> usize {
     dx * dx + dy * dy
 }
 
-/// Reveals rooms adjacent to the given position
+/// Reveals the room at the given position and the rooms connected to it
 pub fn reveal_adjacent_rooms(dungeon: &mut Dungeon, x: usize, y: usize) {
+    if let Some(room) = dungeon.get_room_mut(x, y) {
+        room.visible = true;
+    }
+
     let neighbors = dungeon.get_connected_neighbors(x, y);
 
     for (nx, ny) in neighbors {
         if let Some(room) = dungeon.get_room_mut(nx, ny) {
+            room.visible = true;
             if room.state == RoomState::Hidden {
                 room.state = RoomState::Revealed;
             }
@@ -706,4 +757,32 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_same_seed_generates_identical_layout() {
+        let layout = |dungeon: &Dungeon| {
+            (
+                serde_json::to_value(&dungeon.grid).unwrap(),
+                dungeon.entrance_position,
+                dungeon.boss_position,
+            )
+        };
+
+        for size in [DungeonSize::Small, DungeonSize::Epic] {
+            let a = generate_seeded_dungeon(0xC0FFEE, size, 3, DungeonDifficulty::Hard);
+            let b = generate_seeded_dungeon(0xC0FFEE, size, 3, DungeonDifficulty::Hard);
+            assert_eq!(a.seed, 0xC0FFEE);
+            assert_eq!(layout(&a), layout(&b));
+        }
+    }
+
+    #[test]
+    fn test_seed_code_round_trips() {
+        let dungeon = generate_dungeon(10, 0, 1);
+        assert_eq!(
+            Dungeon::parse_seed_code(&dungeon.seed_code()),
+            Some(dungeon.seed)
+        );
+        assert_eq!(Dungeon::parse_seed_code("not a seed"), None);
+    }
 }
@@ -1,7 +1,10 @@
 //! Dungeon navigation and auto-exploration logic.
 
 use super::generation::reveal_adjacent_rooms;
-use super::types::{Dungeon, DungeonSize, RoomState, RoomType};
+use super::types::{
+    Dungeon, DungeonDifficulty, DungeonSize, DungeonStrategy, Room, RoomState, RoomType,
+    DIR_OFFSETS,
+};
 use crate::core::game_state::GameState;
 use crate::items::{
     generate_item, ilvl_for_zone, roll_random_slot, roll_rarity_for_mob, Item, Rarity,
@@ -59,8 +62,15 @@ pub fn update_dungeon(state: &mut GameState, delta_time: f64) -> Vec<DungeonEven
     // Update move timer
     dungeon.move_timer += delta_time;
 
-    // Find next room to explore
-    if let Some(next_pos) = find_next_room(dungeon) {
+    // Follow the player's chosen direction if set, otherwise auto-explore
+    let steered = dungeon
+        .target_direction
+        .and_then(|dir| room_in_direction(dungeon, dir));
+    if steered.is_none() {
+        dungeon.target_direction = None;
+    }
+
+    if let Some(next_pos) = steered.or_else(|| find_next_room(dungeon)) {
         // Check if next room is already cleared (traveling) or new (exploring)
         let is_traveling = dungeon
             .get_room(next_pos.0, next_pos.1)
@@ -80,6 +90,7 @@ pub fn update_dungeon(state: &mut GameState, delta_time: f64) -> Vec<DungeonEven
         // Check if it's time to move
         if dungeon.move_timer >= move_interval {
             dungeon.move_timer = 0.0;
+            dungeon.target_direction = None;
 
             // Move to the next room
             let move_events = move_to_room(dungeon, next_pos);
@@ -92,11 +103,49 @@ pub fn update_dungeon(state: &mut GameState, delta_time: f64) -> Vec<DungeonEven
     events
 }
 
-/// Finds the next room to explore using BFS
-/// Prioritizes: unexplored rooms, then boss (if has key)
+/// Returns the adjacent room in `dir` (a DIR_* index) if the player can move
+/// there: connected to the current room, and not a locked boss room.
+pub fn room_in_direction(dungeon: &Dungeon, dir: usize) -> Option<(usize, usize)> {
+    let (x, y) = dungeon.player_position;
+    let room = dungeon.current_room()?;
+    if !*room.connections.get(dir)? {
+        return None;
+    }
+
+    let (dx, dy) = DIR_OFFSETS[dir];
+    let nx = usize::try_from(x as i32 + dx).ok()?;
+    let ny = usize::try_from(y as i32 + dy).ok()?;
+    let target = dungeon.get_room(nx, ny)?;
+    if target.room_type == RoomType::Boss && !dungeon.has_key {
+        return None;
+    }
+    Some((nx, ny))
+}
+
+/// Steers the next move toward `dir`. Returns false (and leaves the dungeon
+/// on auto-explore) if there's a wall or locked boss room that way.
+pub fn set_target_direction(dungeon: &mut Dungeon, dir: usize) -> bool {
+    if room_in_direction(dungeon, dir).is_some() {
+        dungeon.target_direction = Some(dir);
+        true
+    } else {
+        false
+    }
+}
+
+/// Finds the next room to explore using BFS, ordered by `dungeon.strategy`:
+/// - Explore: boss (if has key), then the nearest unexplored room
+/// - TreasureFirst: known treasure rooms, then as Explore
+/// - BossRush: as Explore, but the key guardian before other rooms
 pub fn find_next_room(dungeon: &Dungeon) -> Option<(usize, usize)> {
     let current = dungeon.player_position;
 
+    if dungeon.strategy == DungeonStrategy::TreasureFirst {
+        if let Some(step) = step_toward_nearest(dungeon, |r| r.room_type == RoomType::Treasure) {
+            return Some(step);
+        }
+    }
+
     // If we have the key and boss is accessible and not yet cleared, go to boss
     if dungeon.has_key {
         // Only go to boss if it's not already cleared (beaten)
@@ -114,7 +163,22 @@ pub fn find_next_room(dungeon: &Dungeon) -> Option<(usize, usize)> {
         }
     }
 
-    // Find nearest unexplored (revealed but not cleared) room
+    if dungeon.strategy == DungeonStrategy::BossRush && !dungeon.has_key {
+        if let Some(step) = step_toward_nearest(dungeon, |r| r.room_type == RoomType::Elite) {
+            return Some(step);
+        }
+    }
+
+    step_toward_nearest(dungeon, |_| true)
+}
+
+/// First step toward the nearest unexplored (revealed but not cleared) room
+/// matching `wanted`. The boss room only counts once the key is found.
+fn step_toward_nearest(
+    dungeon: &Dungeon,
+    wanted: impl Fn(&Room) -> bool,
+) -> Option<(usize, usize)> {
+    let current = dungeon.player_position;
     let mut best_path: Option<Vec<(usize, usize)>> = None;
 
     let grid_size = dungeon.size.grid_size();
@@ -122,7 +186,7 @@ pub fn find_next_room(dungeon: &Dungeon) -> Option<(usize, usize)> {
         for x in 0..grid_size {
             if let Some(room) = dungeon.get_room(x, y) {
                 // Look for revealed rooms we haven't entered yet
-                if room.state == RoomState::Revealed {
+                if room.state == RoomState::Revealed && wanted(room) {
                     // Skip boss if we don't have key
                     if room.room_type == RoomType::Boss && !dungeon.has_key {
                         continue;
@@ -321,10 +385,11 @@ pub fn on_player_died_in_dungeon(state: &mut GameState) -> Vec<DungeonEvent> {
 }
 
 /// Calculates the XP reward for defeating a dungeon boss
-pub fn calculate_boss_xp_reward(size: DungeonSize) -> u64 {
+pub fn calculate_boss_xp_reward(size: DungeonSize, difficulty: DungeonDifficulty) -> u64 {
     let mut rng = rand::rng();
     let (min_xp, max_xp) = size.boss_xp_range();
-    rng.random_range(min_xp..=max_xp)
+    let base = rng.random_range(min_xp..=max_xp);
+    (base as f64 * difficulty.reward_multiplier()) as u64
 }
 
 /// Generates a treasure room item with rarity boost based on dungeon size.
@@ -435,7 +500,8 @@ pub fn get_enemy_stat_multiplier(dungeon: &Dungeon) -> f64 {
 
 #[cfg(test)]
 mod tests {
-    use super::super::generation::generate_dungeon;
+    use super::super::generation::{generate_dungeon, generate_dungeon_of_size};
+    use super::super::types::{Room, DIR_DOWN, DIR_LEFT, DIR_RIGHT, DIR_UP};
     use super::*;
 
     /// Finds the first room of the given type in the dungeon.
@@ -451,6 +517,117 @@ mod tests {
             })
     }
 
+    /// A straight corridor of rooms along row 2, entered from the left end.
+    fn corridor_dungeon(length: usize) -> Dungeon {
+        let mut dungeon = Dungeon::new(DungeonSize::Small);
+        for x in 0..length {
+            let room_type = if x == 0 {
+                RoomType::Entrance
+            } else {
+                RoomType::Combat
+            };
+            let mut room = Room::new(room_type, (x, 2));
+            room.connections[DIR_LEFT] = x > 0;
+            room.connections[DIR_RIGHT] = x + 1 < length;
+            dungeon.grid[2][x] = Some(room);
+        }
+        dungeon.entrance_position = (0, 2);
+        dungeon.player_position = (0, 2);
+        dungeon.get_room_mut(0, 2).unwrap().state = RoomState::Current;
+        reveal_adjacent_rooms(&mut dungeon, 0, 2);
+        dungeon
+    }
+
+    fn visible_columns(dungeon: &Dungeon) -> Vec<usize> {
+        (0..dungeon.size.grid_size())
+            .filter(|&x| dungeon.get_room(x, 2).is_some_and(|r| r.visible))
+            .collect()
+    }
+
+    #[test]
+    fn test_fog_of_war_starts_with_entrance_and_neighbors() {
+        let dungeon = corridor_dungeon(5);
+        assert_eq!(visible_columns(&dungeon), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_entering_room_reveals_neighbors_but_not_distant_rooms() {
+        let mut state = GameState::new("Test".to_string(), 0);
+        state.active_dungeon = Some(corridor_dungeon(5));
+
+        let events = update_dungeon(&mut state, ROOM_MOVE_INTERVAL);
+
+        assert!(events.iter().any(|e| matches!(
+            e,
+            DungeonEvent::EnteredRoom {
+                position: (1, 2),
+                ..
+            }
+        )));
+        let dungeon = state.active_dungeon.as_ref().unwrap();
+        // Room 2 is now adjacent to the player; rooms 3 and 4 stay shrouded
+        assert_eq!(visible_columns(dungeon), vec![0, 1, 2]);
+    }
+
+    /// Entrance at (0, 2) with exits north to (0, 1) and east to (1, 2).
+    /// Auto-explore picks north first (scan order), so steering east is observable.
+    fn junction_state() -> GameState {
+        let mut dungeon = corridor_dungeon(2);
+        let mut north = Room::new(RoomType::Combat, (0, 1));
+        north.connections[DIR_DOWN] = true;
+        dungeon.grid[1][0] = Some(north);
+        dungeon.get_room_mut(0, 2).unwrap().connections[DIR_UP] = true;
+        reveal_adjacent_rooms(&mut dungeon, 0, 2);
+
+        let mut state = GameState::new("Test".to_string(), 0);
+        state.active_dungeon = Some(dungeon);
+        state
+    }
+
+    #[test]
+    fn test_auto_explore_without_direction() {
+        let mut state = junction_state();
+        update_dungeon(&mut state, ROOM_MOVE_INTERVAL);
+        assert_eq!(state.active_dungeon.unwrap().player_position, (0, 1));
+    }
+
+    #[test]
+    fn test_valid_direction_moves_to_chosen_room() {
+        let mut state = junction_state();
+        let dungeon = state.active_dungeon.as_mut().unwrap();
+        assert!(set_target_direction(dungeon, DIR_RIGHT));
+
+        update_dungeon(&mut state, ROOM_MOVE_INTERVAL);
+
+        let dungeon = state.active_dungeon.as_ref().unwrap();
+        assert_eq!(dungeon.player_position, (1, 2));
+        // One step per input, then back to auto-explore
+        assert_eq!(dungeon.target_direction, None);
+    }
+
+    #[test]
+    fn test_direction_into_wall_is_ignored() {
+        let mut state = junction_state();
+        let dungeon = state.active_dungeon.as_mut().unwrap();
+        assert!(!set_target_direction(dungeon, DIR_LEFT));
+        assert!(!set_target_direction(dungeon, DIR_DOWN));
+        assert_eq!(dungeon.target_direction, None);
+
+        update_dungeon(&mut state, ROOM_MOVE_INTERVAL);
+        assert_eq!(state.active_dungeon.unwrap().player_position, (0, 1));
+    }
+
+    #[test]
+    fn test_direction_into_locked_boss_room_is_ignored() {
+        let mut state = junction_state();
+        let dungeon = state.active_dungeon.as_mut().unwrap();
+        dungeon.get_room_mut(1, 2).unwrap().room_type = RoomType::Boss;
+
+        assert!(!set_target_direction(dungeon, DIR_RIGHT));
+        dungeon.has_key = true;
+        assert!(set_target_direction(dungeon, DIR_RIGHT));
+    }
+
     #[test]
     fn test_find_path_same_position() {
         let dungeon = generate_dungeon(10, 0, 1);
@@ -527,19 +704,19 @@ mod tests {
 
     #[test]
     fn test_calculate_boss_xp_reward_small() {
-        let xp = calculate_boss_xp_reward(DungeonSize::Small);
+        let xp = calculate_boss_xp_reward(DungeonSize::Small, DungeonDifficulty::Normal);
         assert!((1000..=1500).contains(&xp));
     }
 
     #[test]
     fn test_calculate_boss_xp_reward_medium() {
-        let xp = calculate_boss_xp_reward(DungeonSize::Medium);
+        let xp = calculate_boss_xp_reward(DungeonSize::Medium, DungeonDifficulty::Normal);
         assert!((2000..=3000).contains(&xp));
     }
 
     #[test]
     fn test_calculate_boss_xp_reward_large() {
-        let xp = calculate_boss_xp_reward(DungeonSize::Large);
+        let xp = calculate_boss_xp_reward(DungeonSize::Large, DungeonDifficulty::Normal);
         assert!((4000..=6000).contains(&xp));
     }
 
@@ -841,6 +1018,98 @@ mod tests {
         assert!(next.is_none());
     }
 
+    /// Entrance at (2, 2) with the boss east at (3, 2), two combat rooms
+    /// west, and the key guardian south at (2, 3) with treasure behind it.
+    fn strategy_dungeon(strategy: DungeonStrategy) -> Dungeon {
+        let mut dungeon = Dungeon::new(DungeonSize::Small);
+        let rooms = [
+            (RoomType::Combat, (0, 2), [DIR_RIGHT].as_slice()),
+            (RoomType::Combat, (1, 2), &[DIR_LEFT, DIR_RIGHT]),
+            (RoomType::Entrance, (2, 2), &[DIR_LEFT, DIR_RIGHT, DIR_DOWN]),
+            (RoomType::Boss, (3, 2), &[DIR_LEFT]),
+            (RoomType::Elite, (2, 3), &[DIR_UP, DIR_DOWN]),
+            (RoomType::Treasure, (2, 4), &[DIR_UP]),
+        ];
+        for (room_type, (x, y), exits) in rooms {
+            let mut room = Room::new(room_type, (x, y));
+            for &dir in exits {
+                room.connections[dir] = true;
+            }
+            dungeon.grid[y][x] = Some(room);
+        }
+        dungeon.entrance_position = (2, 2);
+        dungeon.player_position = (2, 2);
+        dungeon.boss_position = (3, 2);
+        dungeon.strategy = strategy;
+        dungeon.get_room_mut(2, 2).unwrap().state = RoomState::Current;
+        reveal_adjacent_rooms(&mut dungeon, 2, 2);
+        dungeon
+    }
+
+    /// Auto-explores until the boss room, winning every fight. Returns the
+    /// types of newly entered rooms in order.
+    fn exploration_order(mut dungeon: Dungeon) -> Vec<RoomType> {
+        let mut order = Vec::new();
+        while let Some(next) = find_next_room(&dungeon) {
+            let room = dungeon.get_room(next.0, next.1).unwrap();
+            let (room_type, is_new) = (room.room_type, room.state != RoomState::Cleared);
+            move_to_room(&mut dungeon, next);
+            if is_new {
+                order.push(room_type);
+            }
+            match room_type {
+                RoomType::Elite => {
+                    on_elite_defeated(&mut dungeon);
+                }
+                RoomType::Boss => break,
+                _ => {}
+            }
+            assert!(order.len() <= 10, "exploration did not reach the boss");
+        }
+        order
+    }
+
+    #[test]
+    fn test_explore_strategy_rushes_boss_once_key_found() {
+        // Default behavior: the treasure behind the key guardian is skipped
+        assert_eq!(
+            exploration_order(strategy_dungeon(DungeonStrategy::Explore)),
+            vec![
+                RoomType::Combat,
+                RoomType::Combat,
+                RoomType::Elite,
+                RoomType::Boss
+            ]
+        );
+    }
+
+    #[test]
+    fn test_treasure_first_strategy_loots_before_boss() {
+        let order = exploration_order(strategy_dungeon(DungeonStrategy::TreasureFirst));
+
+        let treasure = order.iter().position(|&t| t == RoomType::Treasure);
+        let boss = order.iter().position(|&t| t == RoomType::Boss);
+        assert!(treasure.is_some());
+        assert!(treasure < boss);
+        assert_eq!(order.last(), Some(&RoomType::Boss));
+    }
+
+    #[test]
+    fn test_boss_rush_strategy_goes_straight_for_key_and_boss() {
+        assert_eq!(
+            exploration_order(strategy_dungeon(DungeonStrategy::BossRush)),
+            vec![RoomType::Elite, RoomType::Boss]
+        );
+    }
+
+    #[test]
+    fn test_strategy_defaults_to_explore_for_old_saves() {
+        let mut json = serde_json::to_value(strategy_dungeon(DungeonStrategy::BossRush)).unwrap();
+        json.as_object_mut().unwrap().remove("strategy");
+        let dungeon: Dungeon = serde_json::from_value(json).unwrap();
+        assert_eq!(dungeon.strategy, DungeonStrategy::Explore);
+    }
+
     // ============ find_path_to tests ============
 
     #[test]
@@ -1361,10 +1630,11 @@ mod tests {
         let samples = 100;
 
         for _ in 0..samples {
-            small_total += calculate_boss_xp_reward(DungeonSize::Small);
-            medium_total += calculate_boss_xp_reward(DungeonSize::Medium);
-            large_total += calculate_boss_xp_reward(DungeonSize::Large);
-            epic_total += calculate_boss_xp_reward(DungeonSize::Epic);
+            small_total += calculate_boss_xp_reward(DungeonSize::Small, DungeonDifficulty::Normal);
+            medium_total +=
+                calculate_boss_xp_reward(DungeonSize::Medium, DungeonDifficulty::Normal);
+            large_total += calculate_boss_xp_reward(DungeonSize::Large, DungeonDifficulty::Normal);
+            epic_total += calculate_boss_xp_reward(DungeonSize::Epic, DungeonDifficulty::Normal);
         }
 
         assert!(
@@ -1383,10 +1653,61 @@ mod tests {
 
     #[test]
     fn test_calculate_boss_xp_reward_epic() {
-        let xp = calculate_boss_xp_reward(DungeonSize::Epic);
+        let xp = calculate_boss_xp_reward(DungeonSize::Epic, DungeonDifficulty::Normal);
         assert!((8000..=12000).contains(&xp));
     }
 
+    #[test]
+    fn test_higher_difficulty_boss_xp_reward_is_larger() {
+        for _ in 0..50 {
+            let normal = calculate_boss_xp_reward(DungeonSize::Small, DungeonDifficulty::Normal);
+            let hard = calculate_boss_xp_reward(DungeonSize::Small, DungeonDifficulty::Hard);
+            let nightmare =
+                calculate_boss_xp_reward(DungeonSize::Small, DungeonDifficulty::Nightmare);
+            // Small rolls 1000-1500 base, so the tiers never overlap
+            assert!(normal <= 1500);
+            assert!((1600..=2400).contains(&hard));
+            assert!(nightmare >= 2500);
+        }
+    }
+
+    #[test]
+    fn test_higher_difficulty_dungeon_has_stronger_elites() {
+        use crate::combat::types::generate_dungeon_elite;
+
+        let normal = generate_dungeon_of_size(DungeonSize::Medium, 3, DungeonDifficulty::Normal);
+        let nightmare =
+            generate_dungeon_of_size(DungeonSize::Medium, 3, DungeonDifficulty::Nightmare);
+        assert_eq!(normal.size, nightmare.size);
+
+        let base = generate_dungeon_elite(3);
+        let normal_elite = normal.difficulty.scale_enemy(base.clone());
+        let nightmare_elite = nightmare.difficulty.scale_enemy(base.clone());
+
+        assert_eq!(normal_elite.max_hp, base.max_hp);
+        assert!(nightmare_elite.max_hp > normal_elite.max_hp);
+        assert!(nightmare_elite.damage > normal_elite.damage);
+        assert_eq!(nightmare_elite.current_hp, nightmare_elite.max_hp);
+    }
+
+    #[test]
+    fn test_higher_difficulty_dungeon_has_more_treasure() {
+        let count_treasure = |dungeon: &Dungeon| {
+            dungeon
+                .grid
+                .iter()
+                .flatten()
+                .flatten()
+                .filter(|r| r.room_type == RoomType::Treasure)
+                .count()
+        };
+        let normal = generate_dungeon_of_size(DungeonSize::Medium, 1, DungeonDifficulty::Normal);
+        let hard = generate_dungeon_of_size(DungeonSize::Medium, 1, DungeonDifficulty::Hard);
+
+        assert_eq!(count_treasure(&normal), 2);
+        assert_eq!(count_treasure(&hard), 3);
+    }
+
     // =========================================================================
     // PLAYER DEATH EDGE CASES
     // =========================================================================
@@ -5,10 +5,13 @@
 
 #![allow(dead_code)]
 
+use crate::combat::types::Enemy;
 use crate::core::constants::{
-    DUNGEON_LEVEL_TIER_LARGE, DUNGEON_LEVEL_TIER_MEDIUM, DUNGEON_PRESTIGE_PER_SIZE_TIER,
-    DUNGEON_SIZE_VARIATION_DOWN, DUNGEON_SIZE_VARIATION_UP,
+    DUNGEON_HARD_CHANCE, DUNGEON_HARD_PRESTIGE_REQUIREMENT, DUNGEON_LEVEL_TIER_LARGE,
+    DUNGEON_LEVEL_TIER_MEDIUM, DUNGEON_NIGHTMARE_CHANCE, DUNGEON_NIGHTMARE_PRESTIGE_REQUIREMENT,
+    DUNGEON_PRESTIGE_PER_SIZE_TIER, DUNGEON_SIZE_VARIATION_DOWN, DUNGEON_SIZE_VARIATION_UP,
 };
+use rand::{Rng, RngExt};
 use serde::{Deserialize, Serialize};
 
 /// Type of room in the dungeon
@@ -108,6 +111,15 @@ pub struct Room {
     pub position: (usize, usize),
     /// Connected room positions (up, right, down, left)
     pub connections: [bool; 4],
+    /// Whether the player has seen this room (visited or next to a visited room).
+    /// Unseen rooms are shrouded on the map.
+    #[serde(default = "default_room_visible")]
+    pub visible: bool,
+}
+
+/// Dungeons saved before fog of war load fully visible
+fn default_room_visible() -> bool {
+    true
 }
 
 impl Room {
@@ -117,6 +129,7 @@ impl Room {
             state: RoomState::Hidden,
             position,
             connections: [false; 4],
+            visible: false,
         }
     }
 
@@ -262,6 +275,111 @@ impl DungeonSize {
     }
 }
 
+/// Difficulty tier for a dungeon, rolled at discovery.
+/// Harder dungeons have deadlier enemies but more treasure and boss XP.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum DungeonDifficulty {
+    #[default]
+    Normal,
+    /// Prestige 5+
+    Hard,
+    /// Prestige 10+
+    Nightmare,
+}
+
+/// How auto-explore picks the next room. Chosen per dungeon run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum DungeonStrategy {
+    /// Nearest unexplored room; the boss as soon as the key is found
+    #[default]
+    Explore,
+    /// Loot every known treasure room before the boss
+    TreasureFirst,
+    /// Head for the key guardian, then straight to the boss
+    BossRush,
+}
+
+impl DungeonStrategy {
+    pub fn name(&self) -> &'static str {
+        match self {
+            DungeonStrategy::Explore => "Explore",
+            DungeonStrategy::TreasureFirst => "Treasure First",
+            DungeonStrategy::BossRush => "Boss Rush",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            DungeonStrategy::Explore => DungeonStrategy::TreasureFirst,
+            DungeonStrategy::TreasureFirst => DungeonStrategy::BossRush,
+            DungeonStrategy::BossRush => DungeonStrategy::Explore,
+        }
+    }
+}
+
+impl DungeonDifficulty {
+    pub fn name(&self) -> &'static str {
+        match self {
+            DungeonDifficulty::Normal => "Normal",
+            DungeonDifficulty::Hard => "Hard",
+            DungeonDifficulty::Nightmare => "Nightmare",
+        }
+    }
+
+    /// Multiplier on HP, damage, and defense of every enemy in the dungeon
+    pub fn enemy_stat_multiplier(&self) -> f64 {
+        match self {
+            DungeonDifficulty::Normal => 1.0,
+            DungeonDifficulty::Hard => 1.3,
+            DungeonDifficulty::Nightmare => 1.7,
+        }
+    }
+
+    /// Multiplier on the boss XP reward
+    pub fn reward_multiplier(&self) -> f64 {
+        match self {
+            DungeonDifficulty::Normal => 1.0,
+            DungeonDifficulty::Hard => 1.6,
+            DungeonDifficulty::Nightmare => 2.5,
+        }
+    }
+
+    /// Treasure rooms added on top of the size's base count
+    pub fn extra_treasure_rooms(&self) -> usize {
+        match self {
+            DungeonDifficulty::Normal => 0,
+            DungeonDifficulty::Hard => 1,
+            DungeonDifficulty::Nightmare => 2,
+        }
+    }
+
+    /// Scales an enemy's stats for this difficulty
+    pub fn scale_enemy(&self, mut enemy: Enemy) -> Enemy {
+        let mult = self.enemy_stat_multiplier();
+        enemy.max_hp = (enemy.max_hp as f64 * mult) as u32;
+        enemy.current_hp = enemy.max_hp;
+        enemy.damage = (enemy.damage as f64 * mult) as u32;
+        enemy.defense = (enemy.defense as f64 * mult) as u32;
+        enemy
+    }
+
+    /// Rolls a difficulty at discovery. Hard and Nightmare unlock with prestige.
+    pub fn roll(prestige_rank: u32, rng: &mut impl Rng) -> Self {
+        let roll: f64 = rng.random();
+        if prestige_rank >= DUNGEON_NIGHTMARE_PRESTIGE_REQUIREMENT
+            && roll < DUNGEON_NIGHTMARE_CHANCE
+        {
+            DungeonDifficulty::Nightmare
+        } else if prestige_rank >= DUNGEON_HARD_PRESTIGE_REQUIREMENT
+            && roll < DUNGEON_NIGHTMARE_CHANCE + DUNGEON_HARD_CHANCE
+        {
+            DungeonDifficulty::Hard
+        } else {
+            DungeonDifficulty::Normal
+        }
+    }
+}
+
 /// Main dungeon state
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Dungeon {
@@ -293,6 +411,18 @@ pub struct Dungeon {
     /// Zone where dungeon was discovered (for enemy scaling)
     #[serde(default = "default_dungeon_zone")]
     pub zone_id: u32,
+    /// Difficulty tier (scales enemies and rewards)
+    #[serde(default)]
+    pub difficulty: DungeonDifficulty,
+    /// Player-chosen direction (DIR_* index) for the next move; auto-explore when None
+    #[serde(skip)]
+    pub target_direction: Option<usize>,
+    /// Room priority used by auto-explore
+    #[serde(default)]
+    pub strategy: DungeonStrategy,
+    /// Layout seed; shared as `seed_code()` and entered with `--dungeon-seed`
+    #[serde(default)]
+    pub seed: u64,
 }
 
 fn default_dungeon_zone() -> u32 {
@@ -316,9 +446,23 @@ impl Dungeon {
             current_room_cleared: true, // Entrance starts cleared
             is_traveling: false,
             zone_id: 1,
+            difficulty: DungeonDifficulty::Normal,
+            target_direction: None,
+            strategy: DungeonStrategy::Explore,
+            seed: 0,
         }
     }
 
+    /// The layout seed as players share it (hex).
+    pub fn seed_code(&self) -> String {
+        format!("{:x}", self.seed)
+    }
+
+    /// Parses a shared seed code as shown by `seed_code()`.
+    pub fn parse_seed_code(code: &str) -> Option<u64> {
+        u64::from_str_radix(code.trim(), 16).ok()
+    }
+
     /// Get a room at position, if it exists
     pub fn get_room(&self, x: usize, y: usize) -> Option<&Room> {
         self.grid.get(y)?.get(x)?.as_ref()
@@ -668,4 +812,31 @@ mod tests {
             DungeonSize::Legendary
         );
     }
+
+    #[test]
+    fn test_difficulty_roll_gated_by_prestige() {
+        use rand::SeedableRng;
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(7);
+        let mut seen = std::collections::HashSet::new();
+        for _ in 0..500 {
+            assert_eq!(
+                DungeonDifficulty::roll(0, &mut rng),
+                DungeonDifficulty::Normal
+            );
+            assert_ne!(
+                DungeonDifficulty::roll(5, &mut rng),
+                DungeonDifficulty::Nightmare
+            );
+            seen.insert(DungeonDifficulty::roll(10, &mut rng));
+        }
+        assert_eq!(seen.len(), 3);
+    }
+
+    #[test]
+    fn test_old_dungeon_save_defaults_to_normal() {
+        let mut value = serde_json::to_value(Dungeon::new(DungeonSize::Small)).unwrap();
+        value.as_object_mut().unwrap().remove("difficulty");
+        let dungeon: Dungeon = serde_json::from_value(value).unwrap();
+        assert_eq!(dungeon.difficulty, DungeonDifficulty::Normal);
+    }
 }
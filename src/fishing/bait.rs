@@ -0,0 +1,180 @@
+//! Fishing bait: a consumable that boosts the next few catches.
+//!
+//! At most one bait is active at a time, in `GameState::active_bait`. Each
+//! completed reel uses up one cast; the bait is removed when none are left.
+//! Bait drops from fishing catches or is crafted from salvage materials.
+
+use rand::{Rng, RngExt};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::core::constants::{
+    BAIT_CASTS, BAIT_CATCH_SPEED_PERCENT, BAIT_CRAFT_COST, BAIT_DROP_CHANCE,
+    BAIT_RARE_CHANCE_PERCENT,
+};
+use crate::core::game_state::GameState;
+
+/// What a bait improves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BaitKind {
+    /// +% chance of Rare or better fish (stacks with the rod)
+    Glimmer,
+    /// -% cast, wait and reel time (stacks with the rod and Garden)
+    Quickbite,
+}
+
+impl BaitKind {
+    /// Every kind, in the order the Workshop's `[V]` cycles through them.
+    pub const ALL: [BaitKind; 2] = [BaitKind::Glimmer, BaitKind::Quickbite];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            BaitKind::Glimmer => "Glimmer Bait",
+            BaitKind::Quickbite => "Quickbite Bait",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Bait {
+    pub kind: BaitKind,
+    pub remaining_casts: u32,
+}
+
+impl Bait {
+    /// A fresh bait with the full `BAIT_CASTS` casts.
+    pub fn new(kind: BaitKind) -> Self {
+        Self {
+            kind,
+            remaining_casts: BAIT_CASTS,
+        }
+    }
+
+    pub fn rare_chance_percent(&self) -> f64 {
+        match self.kind {
+            BaitKind::Glimmer if self.remaining_casts > 0 => BAIT_RARE_CHANCE_PERCENT,
+            _ => 0.0,
+        }
+    }
+
+    pub fn catch_speed_percent(&self) -> f64 {
+        match self.kind {
+            BaitKind::Quickbite if self.remaining_casts > 0 => BAIT_CATCH_SPEED_PERCENT,
+            _ => 0.0,
+        }
+    }
+}
+
+impl GameState {
+    /// Rare-chance bonus from the active bait, 0 without one.
+    pub fn bait_rare_chance_percent(&self) -> f64 {
+        self.active_bait.map_or(0.0, |b| b.rare_chance_percent())
+    }
+
+    /// Timer reduction from the active bait, 0 without one.
+    pub fn bait_catch_speed_percent(&self) -> f64 {
+        self.active_bait.map_or(0.0, |b| b.catch_speed_percent())
+    }
+
+    /// Uses up one cast of the active bait, removing it when it runs out.
+    /// Returns the bait that just ran out, if any.
+    pub fn consume_bait_cast(&mut self) -> Option<Bait> {
+        let bait = self.active_bait.as_mut()?;
+        bait.remaining_casts = bait.remaining_casts.saturating_sub(1);
+        if bait.remaining_casts == 0 {
+            self.active_bait.take()
+        } else {
+            None
+        }
+    }
+
+    /// Puts `bait` on the hook. Bait of the same kind tops up the remaining
+    /// casts; a different kind replaces the current bait.
+    pub fn add_bait(&mut self, bait: Bait) {
+        match &mut self.active_bait {
+            Some(active) if active.kind == bait.kind => {
+                active.remaining_casts += bait.remaining_casts;
+            }
+            _ => self.active_bait = Some(bait),
+        }
+    }
+}
+
+/// Rolls for bait dropping from a catch (`BAIT_DROP_CHANCE`), of a random kind.
+pub fn try_bait_drop(rng: &mut impl Rng) -> Option<Bait> {
+    if rng.random::<f64>() < BAIT_DROP_CHANCE {
+        let kind = if rng.random_bool(0.5) {
+            BaitKind::Glimmer
+        } else {
+            BaitKind::Quickbite
+        };
+        Some(Bait::new(kind))
+    } else {
+        None
+    }
+}
+
+/// Why bait couldn't be crafted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BaitCraftError {
+    NotEnoughMaterials { needed: u64, have: u64 },
+}
+
+impl fmt::Display for BaitCraftError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BaitCraftError::NotEnoughMaterials { needed, have } => {
+                write!(f, "needs {} materials (you have {})", needed, have)
+            }
+        }
+    }
+}
+
+/// Crafts a bait of `kind` from `BAIT_CRAFT_COST` salvage materials and puts
+/// it on the hook. Offered on the Workshop panel (`[B]`).
+pub fn craft_bait(state: &mut GameState, kind: BaitKind) -> Result<Bait, BaitCraftError> {
+    if state.salvage_materials < BAIT_CRAFT_COST {
+        return Err(BaitCraftError::NotEnoughMaterials {
+            needed: BAIT_CRAFT_COST,
+            have: state.salvage_materials,
+        });
+    }
+    state.salvage_materials -= BAIT_CRAFT_COST;
+    let bait = Bait::new(kind);
+    state.add_bait(bait);
+    Ok(bait)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_kind_bait_tops_up_casts() {
+        let mut state = GameState::new("Angler".to_string(), 0);
+        state.add_bait(Bait::new(BaitKind::Glimmer));
+        state.add_bait(Bait::new(BaitKind::Glimmer));
+        assert_eq!(state.active_bait.unwrap().remaining_casts, BAIT_CASTS * 2);
+
+        state.add_bait(Bait::new(BaitKind::Quickbite));
+        assert_eq!(state.active_bait, Some(Bait::new(BaitKind::Quickbite)));
+    }
+
+    #[test]
+    fn test_craft_bait_spends_materials() {
+        let mut state = GameState::new("Angler".to_string(), 0);
+        state.salvage_materials = BAIT_CRAFT_COST - 1;
+        assert_eq!(
+            craft_bait(&mut state, BaitKind::Glimmer),
+            Err(BaitCraftError::NotEnoughMaterials {
+                needed: BAIT_CRAFT_COST,
+                have: BAIT_CRAFT_COST - 1
+            })
+        );
+
+        state.salvage_materials = BAIT_CRAFT_COST;
+        let bait = craft_bait(&mut state, BaitKind::Glimmer).unwrap();
+        assert_eq!(state.salvage_materials, 0);
+        assert_eq!(state.active_bait, Some(bait));
+    }
+}
@@ -0,0 +1,247 @@
+//! Fishing rods: gear that speeds up catches and improves rarity odds.
+//!
+//! Rods are separate from combat equipment and live in `GameState::fishing_gear`.
+//! They drop from fishing catches or are crafted from salvage materials.
+
+use rand::{Rng, RngExt};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use super::types::FishRarity;
+use crate::core::constants::{
+    FISHING_ROD_CATCH_SPEED_CAP_PERCENT, FISHING_ROD_CRAFT_COSTS, FISHING_ROD_DROP_CHANCE,
+};
+use crate::core::game_state::GameState;
+
+/// Bonus rolled on a fishing rod.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RodAffixType {
+    /// -% cast, wait and reel time (stacks with the Garden)
+    CatchSpeed,
+    /// +% chance of Rare or better fish, taken from Common
+    RareChance,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RodAffix {
+    pub affix_type: RodAffixType,
+    pub value: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FishingRod {
+    pub name: String,
+    pub rarity: FishRarity,
+    pub affixes: Vec<RodAffix>,
+}
+
+impl FishingRod {
+    fn affix_total(&self, affix_type: RodAffixType) -> f64 {
+        self.affixes
+            .iter()
+            .filter(|a| a.affix_type == affix_type)
+            .map(|a| a.value)
+            .sum()
+    }
+
+    pub fn catch_speed_percent(&self) -> f64 {
+        self.affix_total(RodAffixType::CatchSpeed)
+    }
+
+    pub fn rare_chance_percent(&self) -> f64 {
+        self.affix_total(RodAffixType::RareChance)
+    }
+
+    /// Sum of affix values, used to decide whether another rod is an upgrade.
+    pub fn power(&self) -> f64 {
+        self.affixes.iter().map(|a| a.value).sum()
+    }
+
+    /// Compact affix list, e.g. "-12% Timers +20% Rare".
+    pub fn summary(&self) -> String {
+        self.affixes
+            .iter()
+            .map(|a| match a.affix_type {
+                RodAffixType::CatchSpeed => format!("-{:.0}% Timers", a.value),
+                RodAffixType::RareChance => format!("+{:.0}% Rare", a.value),
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// Fishing equipment, saved with the character.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FishingGear {
+    #[serde(default)]
+    pub rod: Option<FishingRod>,
+}
+
+impl FishingGear {
+    /// Timer reduction from the rod, capped at `FISHING_ROD_CATCH_SPEED_CAP_PERCENT`.
+    pub fn catch_speed_percent(&self) -> f64 {
+        self.rod
+            .as_ref()
+            .map_or(0.0, |rod| rod.catch_speed_percent())
+            .min(FISHING_ROD_CATCH_SPEED_CAP_PERCENT)
+    }
+
+    pub fn rare_chance_percent(&self) -> f64 {
+        self.rod
+            .as_ref()
+            .map_or(0.0, |rod| rod.rare_chance_percent())
+    }
+
+    /// Equips `rod` if it is stronger than the current one (or none is equipped).
+    /// Returns whether it was equipped.
+    pub fn equip_if_better(&mut self, rod: FishingRod) -> bool {
+        let current_power = self.rod.as_ref().map_or(0.0, |r| r.power());
+        if rod.power() > current_power {
+            self.rod = Some(rod);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+fn rod_name(rarity: FishRarity) -> &'static str {
+    match rarity {
+        FishRarity::Common => "Willow Rod",
+        FishRarity::Uncommon => "Ash Rod",
+        FishRarity::Rare => "Ironwood Rod",
+        FishRarity::Epic => "Stormglass Rod",
+        FishRarity::Legendary => "Leviathan's Reach",
+    }
+}
+
+/// Rolls a rod of the given rarity. Common and Uncommon rods get one random
+/// affix; Rare and better get both.
+pub fn generate_rod_with_rng(rarity: FishRarity, rng: &mut impl Rng) -> FishingRod {
+    let (min, max): (f64, f64) = match rarity {
+        FishRarity::Common => (2.0, 5.0),
+        FishRarity::Uncommon => (5.0, 10.0),
+        FishRarity::Rare => (10.0, 15.0),
+        FishRarity::Epic => (15.0, 20.0),
+        FishRarity::Legendary => (20.0, 30.0),
+    };
+    let types = if rarity >= FishRarity::Rare {
+        vec![RodAffixType::CatchSpeed, RodAffixType::RareChance]
+    } else if rng.random_bool(0.5) {
+        vec![RodAffixType::CatchSpeed]
+    } else {
+        vec![RodAffixType::RareChance]
+    };
+    let affixes = types
+        .into_iter()
+        .map(|affix_type| RodAffix {
+            affix_type,
+            value: rng.random_range(min..=max).round(),
+        })
+        .collect();
+
+    FishingRod {
+        name: rod_name(rarity).to_string(),
+        rarity,
+        affixes,
+    }
+}
+
+/// Rolls for a rod dropping from a catch (`FISHING_ROD_DROP_CHANCE`), with
+/// the caught fish's rarity.
+pub fn try_rod_drop(rarity: FishRarity, rng: &mut impl Rng) -> Option<FishingRod> {
+    if rng.random::<f64>() < FISHING_ROD_DROP_CHANCE {
+        Some(generate_rod_with_rng(rarity, rng))
+    } else {
+        None
+    }
+}
+
+/// Salvage materials needed to craft a rod of this rarity.
+pub fn rod_craft_cost(rarity: FishRarity) -> u64 {
+    FISHING_ROD_CRAFT_COSTS[rarity as usize]
+}
+
+/// Why a rod couldn't be crafted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RodCraftError {
+    NotEnoughMaterials { needed: u64, have: u64 },
+}
+
+impl fmt::Display for RodCraftError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RodCraftError::NotEnoughMaterials { needed, have } => {
+                write!(f, "needs {} materials (you have {})", needed, have)
+            }
+        }
+    }
+}
+
+/// Crafts a rod of `rarity` from salvage materials and equips it, replacing
+/// the current rod. Offered on the Workshop panel (`[F]`).
+pub fn craft_fishing_rod(
+    state: &mut GameState,
+    rarity: FishRarity,
+    rng: &mut impl Rng,
+) -> Result<FishingRod, RodCraftError> {
+    let needed = rod_craft_cost(rarity);
+    if state.salvage_materials < needed {
+        return Err(RodCraftError::NotEnoughMaterials {
+            needed,
+            have: state.salvage_materials,
+        });
+    }
+    state.salvage_materials -= needed;
+    let rod = generate_rod_with_rng(rarity, rng);
+    state.fishing_gear.rod = Some(rod.clone());
+    Ok(rod)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_rare_rods_roll_both_affixes() {
+        let mut rng = ChaCha8Rng::seed_from_u64(3);
+        for rarity in [FishRarity::Rare, FishRarity::Epic, FishRarity::Legendary] {
+            let rod = generate_rod_with_rng(rarity, &mut rng);
+            assert!(rod.catch_speed_percent() > 0.0);
+            assert!(rod.rare_chance_percent() > 0.0);
+        }
+        let common = generate_rod_with_rng(FishRarity::Common, &mut rng);
+        assert_eq!(common.affixes.len(), 1);
+    }
+
+    #[test]
+    fn test_equip_if_better_keeps_stronger_rod() {
+        let mut rng = ChaCha8Rng::seed_from_u64(3);
+        let mut gear = FishingGear::default();
+        assert!(gear.equip_if_better(generate_rod_with_rng(FishRarity::Epic, &mut rng)));
+        assert!(!gear.equip_if_better(generate_rod_with_rng(FishRarity::Common, &mut rng)));
+        assert_eq!(gear.rod.as_ref().unwrap().rarity, FishRarity::Epic);
+    }
+
+    #[test]
+    fn test_craft_fishing_rod_spends_materials() {
+        let mut rng = ChaCha8Rng::seed_from_u64(3);
+        let mut state = GameState::new("Angler".to_string(), 0);
+        let cost = rod_craft_cost(FishRarity::Rare);
+        state.salvage_materials = cost - 1;
+        assert_eq!(
+            craft_fishing_rod(&mut state, FishRarity::Rare, &mut rng),
+            Err(RodCraftError::NotEnoughMaterials {
+                needed: cost,
+                have: cost - 1
+            })
+        );
+
+        state.salvage_materials = cost;
+        let rod = craft_fishing_rod(&mut state, FishRarity::Rare, &mut rng).unwrap();
+        assert_eq!(state.salvage_materials, 0);
+        assert_eq!(state.fishing_gear.rod, Some(rod));
+    }
+}
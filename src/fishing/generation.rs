@@ -6,8 +6,8 @@
 
 use super::types::{CaughtFish, FishRarity, FishingPhase, FishingSession};
 use crate::core::constants::{
-    FISHING_SESSION_MAX_FISH, FISHING_SESSION_MIN_FISH, FISH_RARITY_BONUS_INTERVAL,
-    FISH_RARITY_COMMON_FLOOR,
+    FISHING_HOTSPOT_WEIGHT_MULTIPLIER, FISHING_SESSION_MAX_FISH, FISHING_SESSION_MIN_FISH,
+    FISH_RARITY_BONUS_INTERVAL, FISH_RARITY_COMMON_FLOOR,
 };
 use crate::items::Item;
 use rand::{Rng, RngExt};
@@ -66,11 +66,14 @@ const BASE_CHANCES: [f64; 5] = [60.0, 25.0, 10.0, 4.0, 1.0];
 /// Every 5 ranks: -2% Common, +1% Uncommon, +0.5% Rare, +0.3% Epic, +0.2% Legendary
 const RANK_BONUS_PER_5: [f64; 5] = [-2.0, 1.0, 0.5, 0.3, 0.2];
 
-/// Rolls a fish rarity based on the player's fishing rank.
+/// Effective rarity chances (percent, Common..Legendary) for a fishing rank.
 ///
 /// Base chances: Common 60%, Uncommon 25%, Rare 10%, Epic 4%, Legendary 1%
 /// Every 5 ranks: -2% Common, +1% Uncommon, +0.5% Rare, +0.3% Epic, +0.2% Legendary
-pub fn roll_fish_rarity(rank: u32, rng: &mut impl Rng) -> FishRarity {
+///
+/// An active `hotspot` multiplies that rarity's chance by
+/// `FISHING_HOTSPOT_WEIGHT_MULTIPLIER`, taking the extra from Common.
+pub fn fish_rarity_chances(rank: u32, hotspot: Option<FishRarity>) -> [f64; 5] {
     // Calculate how many bonus tiers we get (1 per FISH_RARITY_BONUS_INTERVAL ranks)
     let bonus_tiers = rank.saturating_sub(1) / FISH_RARITY_BONUS_INTERVAL;
 
@@ -83,6 +86,57 @@ pub fn roll_fish_rarity(rank: u32, rng: &mut impl Rng) -> FishRarity {
     // Ensure Common doesn't go below a minimum (prevents negative)
     chances[0] = chances[0].max(FISH_RARITY_COMMON_FLOOR);
 
+    if let Some(rarity) = hotspot.filter(|r| *r != FishRarity::Common) {
+        let i = rarity as usize;
+        let extra = chances[i] * (FISHING_HOTSPOT_WEIGHT_MULTIPLIER - 1.0);
+        chances[i] += extra;
+        chances[0] -= extra;
+    }
+
+    chances
+}
+
+/// Shifts chance from Common to Rare, Epic and Legendary, raising each by
+/// `percent` of its own chance (a fishing rod's Rare Chance). Common gives up
+/// at most what it has.
+pub fn apply_rare_chance_bonus(mut chances: [f64; 5], percent: f64) -> [f64; 5] {
+    let extras: Vec<f64> = chances[2..].iter().map(|c| c * percent / 100.0).collect();
+    let total: f64 = extras.iter().sum();
+    if total <= 0.0 {
+        return chances;
+    }
+    let scale = (chances[0] / total).min(1.0);
+    for (chance, extra) in chances[2..].iter_mut().zip(&extras) {
+        *chance += extra * scale;
+    }
+    chances[0] -= total * scale;
+    chances
+}
+
+/// Rolls a fish rarity based on the player's fishing rank.
+pub fn roll_fish_rarity(rank: u32, rng: &mut impl Rng) -> FishRarity {
+    roll_fish_rarity_with_hotspot(rank, None, rng)
+}
+
+/// Rolls a fish rarity using `fish_rarity_chances`, boosted by an active hotspot.
+pub fn roll_fish_rarity_with_hotspot(
+    rank: u32,
+    hotspot: Option<FishRarity>,
+    rng: &mut impl Rng,
+) -> FishRarity {
+    roll_fish_rarity_with_bonuses(rank, hotspot, 0.0, rng)
+}
+
+/// Same as `roll_fish_rarity_with_hotspot`, with a rod's Rare Chance applied
+/// through `apply_rare_chance_bonus`.
+pub fn roll_fish_rarity_with_bonuses(
+    rank: u32,
+    hotspot: Option<FishRarity>,
+    rare_chance_percent: f64,
+    rng: &mut impl Rng,
+) -> FishRarity {
+    let chances = apply_rare_chance_bonus(fish_rarity_chances(rank, hotspot), rare_chance_percent);
+
     // Roll a number from 0.0 to 100.0
     let roll: f64 = rng.random_range(0.0..100.0);
 
@@ -357,6 +411,53 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_hotspot_boosts_rarity_weight() {
+        let normal = fish_rarity_chances(10, None);
+        let boosted = fish_rarity_chances(10, Some(FishRarity::Epic));
+
+        let epic = FishRarity::Epic as usize;
+        assert!((boosted[epic] - normal[epic] * FISHING_HOTSPOT_WEIGHT_MULTIPLIER).abs() < 1e-9);
+        // The extra weight comes out of Common; other rarities are untouched
+        assert!(boosted[0] < normal[0]);
+        for i in [1, 2, 4] {
+            assert!((boosted[i] - normal[i]).abs() < 1e-9);
+        }
+        assert!((boosted.iter().sum::<f64>() - 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_no_hotspot_uses_normal_weights() {
+        assert_eq!(fish_rarity_chances(1, None), BASE_CHANCES);
+        // A Common "hotspot" is ignored
+        assert_eq!(
+            fish_rarity_chances(1, Some(FishRarity::Common)),
+            BASE_CHANCES
+        );
+    }
+
+    #[test]
+    fn test_hotspot_increases_boosted_catches() {
+        let iterations = 20000;
+        let mut rng = create_test_rng();
+        let normal = (0..iterations)
+            .filter(|_| roll_fish_rarity(1, &mut rng) == FishRarity::Legendary)
+            .count();
+        let mut rng = create_test_rng();
+        let boosted = (0..iterations)
+            .filter(|_| {
+                roll_fish_rarity_with_hotspot(1, Some(FishRarity::Legendary), &mut rng)
+                    == FishRarity::Legendary
+            })
+            .count();
+        assert!(
+            boosted > normal,
+            "hotspot should catch more legendaries ({} vs {})",
+            boosted,
+            normal
+        );
+    }
+
     #[test]
     fn test_generate_fish_returns_correct_xp_range_common() {
         let mut rng = create_test_rng();
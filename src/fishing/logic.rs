@@ -5,13 +5,16 @@
 
 #![allow(dead_code)]
 
+use super::bait::try_bait_drop;
+use super::gear::try_rod_drop;
 use super::generation::{self as fishing_generation, is_storm_leviathan, LeviathanResult};
 use super::types::{FishRarity, FishingPhase, FishingState};
 use crate::character::prestige::get_prestige_tier;
 use crate::core::constants::{
     BASE_MAX_FISHING_RANK, FISHING_DISCOVERY_CHANCE, FISHING_DROP_CHANCE_COMMON,
     FISHING_DROP_CHANCE_EPIC, FISHING_DROP_CHANCE_LEGENDARY, FISHING_DROP_CHANCE_RARE,
-    FISHING_DROP_CHANCE_UNCOMMON, MAX_FISHING_RANK,
+    FISHING_DROP_CHANCE_UNCOMMON, FISHING_HOTSPOT_ACTIVE_SECONDS, FISHING_HOTSPOT_WINDOW_SECONDS,
+    MAX_FISHING_RANK,
 };
 use crate::core::game_state::GameState;
 use crate::items::generation as item_generation;
@@ -46,6 +49,35 @@ pub struct FishingTickResult {
     pub caught_storm_leviathan: bool,
     /// If set, a Leviathan encounter occurred (it escaped). Value is encounter number (1-10).
     pub leviathan_encounter: Option<u8>,
+    /// Rarity of every fish landed this tick
+    pub caught: Vec<FishRarity>,
+}
+
+/// Rarities a hotspot can boost, in rotation order.
+const HOTSPOT_RARITIES: [FishRarity; 4] = [
+    FishRarity::Uncommon,
+    FishRarity::Rare,
+    FishRarity::Epic,
+    FishRarity::Legendary,
+];
+
+/// The rarity boosted by the fishing hotspot at `timestamp` (Unix seconds).
+///
+/// Time is split into `FISHING_HOTSPOT_WINDOW_SECONDS` windows. A hotspot is
+/// active for the first `FISHING_HOTSPOT_ACTIVE_SECONDS` of each window, and
+/// the boosted rarity rotates through Uncommon → Rare → Epic → Legendary from
+/// one window to the next. Returns None outside the active part of a window.
+pub fn current_hotspot(timestamp: i64) -> Option<FishRarity> {
+    if timestamp.rem_euclid(FISHING_HOTSPOT_WINDOW_SECONDS) >= FISHING_HOTSPOT_ACTIVE_SECONDS {
+        return None;
+    }
+    let window = timestamp.div_euclid(FISHING_HOTSPOT_WINDOW_SECONDS);
+    Some(HOTSPOT_RARITIES[window.rem_euclid(HOTSPOT_RARITIES.len() as i64) as usize])
+}
+
+/// Total bonus chance of Rare or better fish: rod affixes plus active bait.
+pub fn fishing_rare_chance_percent(state: &GameState) -> f64 {
+    state.fishing_gear.rare_chance_percent() + state.bait_rare_chance_percent()
 }
 
 /// Processes a fishing session tick with phase-based timing.
@@ -55,13 +87,15 @@ pub struct FishingTickResult {
 /// 2. **Waiting** (2-4s) - Waiting for a bite
 /// 3. **Reeling** (1-2s) - Fish is biting, reeling in
 ///
-/// `haven` contains Haven bonuses for fishing
+/// `haven` contains Haven bonuses for fishing. `hotspot` is the rarity boosted
+/// by the current fishing hotspot (see `current_hotspot`), if any.
 ///
 /// Returns a `FishingTickResult` with messages and special catch flags.
 pub fn tick_fishing_with_haven_result(
     state: &mut GameState,
     rng: &mut impl Rng,
     haven: &HavenFishingBonuses,
+    hotspot: Option<FishRarity>,
 ) -> FishingTickResult {
     let mut result = FishingTickResult::default();
 
@@ -73,6 +107,11 @@ pub fn tick_fishing_with_haven_result(
 
     let mut session = session;
 
+    // Garden bonus, rod Catch Speed and Quickbite bait all shorten every phase
+    let timer_reduction = haven.timer_reduction_percent
+        + state.fishing_gear.catch_speed_percent()
+        + state.bait_catch_speed_percent();
+
     // Decrement tick counter
     if session.ticks_remaining > 0 {
         session.ticks_remaining -= 1;
@@ -85,9 +124,7 @@ pub fn tick_fishing_with_haven_result(
                 // Casting complete, start waiting for bite
                 session.phase = FishingPhase::Waiting;
                 let base_ticks = fishing_generation::roll_waiting_ticks(rng);
-                // Apply Garden bonus: reduce timers
-                session.ticks_remaining =
-                    apply_timer_reduction(base_ticks, haven.timer_reduction_percent);
+                session.ticks_remaining = apply_timer_reduction(base_ticks, timer_reduction);
                 result
                     .messages
                     .push("Line cast... waiting for a bite...".to_string());
@@ -96,9 +133,7 @@ pub fn tick_fishing_with_haven_result(
                 // Got a bite! Start reeling
                 session.phase = FishingPhase::Reeling;
                 let base_ticks = fishing_generation::roll_reeling_ticks(rng);
-                // Apply Garden bonus: reduce timers
-                session.ticks_remaining =
-                    apply_timer_reduction(base_ticks, haven.timer_reduction_percent);
+                session.ticks_remaining = apply_timer_reduction(base_ticks, timer_reduction);
                 result
                     .messages
                     .push("🐟 Got a bite! Reeling in...".to_string());
@@ -114,7 +149,12 @@ pub fn tick_fishing_with_haven_result(
                 };
 
                 for fish_num in 0..fish_count {
-                    let rarity = fishing_generation::roll_fish_rarity(state.fishing.rank, rng);
+                    let rarity = fishing_generation::roll_fish_rarity_with_bonuses(
+                        state.fishing.rank,
+                        hotspot,
+                        fishing_rare_chance_percent(state),
+                        rng,
+                    );
                     // Use rank-aware fish generation for Storm Leviathan hunt
                     let (fish, leviathan_result) = fishing_generation::generate_fish_with_rank(
                         rarity,
@@ -131,6 +171,12 @@ pub fn tick_fishing_with_haven_result(
                             // Increment encounters and signal modal should show
                             state.fishing.leviathan_encounters = encounter_number;
                             result.leviathan_encounter = Some(encounter_number);
+                            if apply_leviathan_escape_penalty(&mut state.fishing) {
+                                result.messages.push(format!(
+                                    "💥 The Leviathan snapped your line! Fishing rank lowered to {}",
+                                    state.fishing.rank
+                                ));
+                            }
                         }
                         LeviathanResult::None => {}
                     }
@@ -152,13 +198,7 @@ pub fn tick_fishing_with_haven_result(
                     }
 
                     // Generate catch message
-                    let rarity_name = match rarity {
-                        FishRarity::Common => "Common",
-                        FishRarity::Uncommon => "Uncommon",
-                        FishRarity::Rare => "Rare",
-                        FishRarity::Epic => "Epic",
-                        FishRarity::Legendary => "Legendary",
-                    };
+                    let rarity_name = rarity.name();
                     let double_msg = if fish_count == 2 && fish_num == 1 {
                         " (DOUBLE!)"
                     } else {
@@ -190,10 +230,38 @@ pub fn tick_fishing_with_haven_result(
                         session.items_found.push(item);
                     }
 
+                    if let Some(bait) = try_bait_drop(rng) {
+                        result
+                            .messages
+                            .push(format!("🪱 Found {}!", bait.kind.name()));
+                        state.add_bait(bait);
+                    }
+
+                    if let Some(rod) = try_rod_drop(rarity, rng) {
+                        let name = format!("{} ({})", rod.name, rod.summary());
+                        if state.fishing_gear.equip_if_better(rod) {
+                            result
+                                .messages
+                                .push(format!("🎣 Found a {} and equipped it!", name));
+                        } else {
+                            result
+                                .messages
+                                .push(format!("🎣 Found a {}, but your rod is better.", name));
+                        }
+                    }
+
                     // Add fish to session
+                    result.caught.push(rarity);
                     session.fish_caught.push(fish);
                 }
 
+                // One reel uses one cast of bait, however many fish it landed
+                if let Some(spent) = state.consume_bait_cast() {
+                    result
+                        .messages
+                        .push(format!("🪱 Your {} is used up.", spent.kind.name()));
+                }
+
                 // Check if session is complete
                 if session.fish_caught.len() >= session.total_fish as usize {
                     result.messages.push(format!(
@@ -208,8 +276,7 @@ pub fn tick_fishing_with_haven_result(
                 // Start casting again for next fish
                 session.phase = FishingPhase::Casting;
                 let base_ticks = fishing_generation::roll_casting_ticks(rng);
-                session.ticks_remaining =
-                    apply_timer_reduction(base_ticks, haven.timer_reduction_percent);
+                session.ticks_remaining = apply_timer_reduction(base_ticks, timer_reduction);
             }
         }
     }
@@ -234,7 +301,7 @@ pub fn tick_fishing_with_haven(
     rng: &mut impl Rng,
     haven: &HavenFishingBonuses,
 ) -> Vec<String> {
-    tick_fishing_with_haven_result(state, rng, haven).messages
+    tick_fishing_with_haven_result(state, rng, haven, None).messages
 }
 
 /// Legacy function without Haven bonuses (for backwards compatibility)
@@ -314,10 +381,38 @@ pub fn try_discover_fishing(state: &mut GameState, rng: &mut impl Rng) -> Option
     let spot_name = session.spot_name.clone();
 
     state.active_fishing = Some(session);
+    state.fishing.last_spot = Some(spot_name.clone());
 
     Some(format!("Discovered fishing spot: {}!", spot_name))
 }
 
+/// Fishing autopilot: with `FishingState::auto_fishing` on, starts a fresh
+/// session at the last discovered spot once the previous one has ended.
+///
+/// Returns a message if fishing resumed.
+///
+/// # Conditions
+/// - A spot has been discovered before
+/// - No active fishing session, dungeon or minigame
+/// - Not in a boss fight (bosses are never skipped by fishing)
+pub fn try_resume_fishing(state: &mut GameState, rng: &mut impl Rng) -> Option<String> {
+    if !state.fishing.auto_fishing
+        || state.active_fishing.is_some()
+        || state.active_dungeon.is_some()
+        || state.active_minigame.is_some()
+        || state.zone_progression.fighting_boss
+    {
+        return None;
+    }
+    let spot_name = state.fishing.last_spot.clone()?;
+
+    let mut session = fishing_generation::generate_fishing_session(rng);
+    session.spot_name = spot_name.clone();
+    state.active_fishing = Some(session);
+
+    Some(format!("Autopilot: back to fishing at {}.", spot_name))
+}
+
 // BASE_MAX_FISHING_RANK and MAX_FISHING_RANK are imported from core::constants
 
 /// Returns the effective maximum fishing rank based on Haven bonus.
@@ -363,6 +458,20 @@ pub fn check_rank_up_with_max(fishing_state: &mut FishingState, max_rank: u32) -
     }
 }
 
+/// Applies the hardcore fishing penalty for an escaped Leviathan.
+///
+/// In hardcore mode the line snaps: the player loses one fishing rank (never
+/// below 1) and progress toward the next rank. Normal mode has no penalty.
+/// Returns true if a rank was lost.
+pub fn apply_leviathan_escape_penalty(fishing_state: &mut FishingState) -> bool {
+    if !fishing_state.hardcore || fishing_state.rank <= 1 {
+        return false;
+    }
+    fishing_state.rank -= 1;
+    fishing_state.fish_toward_next_rank = 0;
+    true
+}
+
 /// Checks if the player should rank up in fishing (legacy, uses absolute max).
 ///
 /// Returns a rank up message if the threshold is reached.
@@ -378,8 +487,11 @@ pub fn check_rank_up(fishing_state: &mut FishingState) -> Option<String> {
 
 #[cfg(test)]
 mod tests {
+    use super::super::bait::{Bait, BaitKind};
+    use super::super::gear::{FishingRod, RodAffix, RodAffixType};
     use super::super::types::FishingSession;
     use super::*;
+    use crate::core::constants::BAIT_RARE_CHANCE_PERCENT;
     use rand::SeedableRng;
     use rand_chacha::ChaCha8Rng;
 
@@ -391,6 +503,41 @@ mod tests {
         GameState::new("Test Fisher".to_string(), 0)
     }
 
+    #[test]
+    fn test_auto_fishing_resumes_at_last_spot() {
+        let mut state = GameState::new("Angler".to_string(), 0);
+        let mut rng = rand::rng();
+        state.fishing.last_spot = Some("Crystal Lake".to_string());
+
+        // Off by default
+        assert!(try_resume_fishing(&mut state, &mut rng).is_none());
+        assert!(state.active_fishing.is_none());
+
+        state.fishing.auto_fishing = true;
+        assert!(try_resume_fishing(&mut state, &mut rng).is_some());
+        let session = state.active_fishing.as_ref().unwrap();
+        assert_eq!(session.spot_name, "Crystal Lake");
+        assert_eq!(session.phase, FishingPhase::Casting);
+
+        // Already fishing: nothing to do
+        assert!(try_resume_fishing(&mut state, &mut rng).is_none());
+    }
+
+    #[test]
+    fn test_auto_fishing_waits_for_boss_and_needs_a_spot() {
+        let mut state = GameState::new("Angler".to_string(), 0);
+        let mut rng = rand::rng();
+        state.fishing.auto_fishing = true;
+
+        // No spot discovered yet
+        assert!(try_resume_fishing(&mut state, &mut rng).is_none());
+
+        state.fishing.last_spot = Some("Crystal Lake".to_string());
+        state.zone_progression.fighting_boss = true;
+        assert!(try_resume_fishing(&mut state, &mut rng).is_none());
+        assert!(state.active_fishing.is_none());
+    }
+
     #[test]
     fn test_tick_fishing_catches_fish_and_awards_xp() {
         let mut rng = create_test_rng();
@@ -596,6 +743,10 @@ mod tests {
             fish_toward_next_rank: 100, // Exactly at threshold for rank 1 (requires 100)
             legendary_catches: 0,
             leviathan_encounters: 0,
+            hardcore: false,
+            auto_fishing: false,
+            last_spot: None,
+            tournament: None,
         };
 
         let result = check_rank_up(&mut fishing_state);
@@ -616,6 +767,10 @@ mod tests {
             fish_toward_next_rank: 120, // 20 excess
             legendary_catches: 0,
             leviathan_encounters: 0,
+            hardcore: false,
+            auto_fishing: false,
+            last_spot: None,
+            tournament: None,
         };
 
         let result = check_rank_up(&mut fishing_state);
@@ -636,6 +791,10 @@ mod tests {
             fish_toward_next_rank: 50, // Only halfway to 100
             legendary_catches: 0,
             leviathan_encounters: 0,
+            hardcore: false,
+            auto_fishing: false,
+            last_spot: None,
+            tournament: None,
         };
 
         let result = check_rank_up(&mut fishing_state);
@@ -656,6 +815,10 @@ mod tests {
             fish_toward_next_rank: 5000, // Way more than enough to rank up
             legendary_catches: 100,
             leviathan_encounters: 0,
+            hardcore: false,
+            auto_fishing: false,
+            last_spot: None,
+            tournament: None,
         };
 
         let result = check_rank_up(&mut fishing_state);
@@ -998,6 +1161,127 @@ mod tests {
         );
     }
 
+    fn rod_with(affix_type: RodAffixType, value: f64) -> FishingRod {
+        FishingRod {
+            name: "Test Rod".to_string(),
+            rarity: FishRarity::Rare,
+            affixes: vec![RodAffix { affix_type, value }],
+        }
+    }
+
+    /// Waiting ticks rolled after a cast, with the same seed every call.
+    fn waiting_ticks_after_cast(state: &mut GameState) -> u32 {
+        let mut rng = ChaCha8Rng::seed_from_u64(7);
+        state.active_fishing = Some(FishingSession {
+            spot_name: "Test Lake".to_string(),
+            total_fish: 5,
+            fish_caught: Vec::new(),
+            items_found: Vec::new(),
+            ticks_remaining: 1,
+            phase: FishingPhase::Casting,
+        });
+        tick_fishing_with_haven(state, &mut rng, &HavenFishingBonuses::default());
+        state.active_fishing.as_ref().unwrap().ticks_remaining
+    }
+
+    #[test]
+    fn test_catch_speed_rod_shortens_fishing_timer() {
+        let mut state = create_test_game_state();
+        let without_rod = waiting_ticks_after_cast(&mut state);
+
+        state.fishing_gear.rod = Some(rod_with(RodAffixType::CatchSpeed, 40.0));
+        let with_rod = waiting_ticks_after_cast(&mut state);
+
+        assert_eq!(
+            with_rod,
+            apply_timer_reduction(without_rod, 40.0),
+            "rod should cut the same roll by 40%"
+        );
+        assert!(with_rod < without_rod);
+    }
+
+    #[test]
+    fn test_rare_chance_rod_catches_more_rare_fish() {
+        let count_rare_or_better = |state: &mut GameState| {
+            let mut rng = ChaCha8Rng::seed_from_u64(11);
+            let mut rare = 0;
+            for _ in 0..2000 {
+                state.active_fishing = Some(FishingSession {
+                    spot_name: "Test Lake".to_string(),
+                    total_fish: 100,
+                    fish_caught: Vec::new(),
+                    items_found: Vec::new(),
+                    ticks_remaining: 1,
+                    phase: FishingPhase::Reeling,
+                });
+                tick_fishing_with_haven(state, &mut rng, &HavenFishingBonuses::default());
+                let session = state.active_fishing.as_ref().unwrap();
+                rare += session
+                    .fish_caught
+                    .iter()
+                    .filter(|f| f.rarity >= FishRarity::Rare)
+                    .count();
+            }
+            rare
+        };
+
+        let mut plain = create_test_game_state();
+        let mut rodded = create_test_game_state();
+        rodded.fishing_gear.rod = Some(rod_with(RodAffixType::RareChance, 100.0));
+        let base_chances = fishing_generation::fish_rarity_chances(1, None);
+        let rod_chances = fishing_generation::apply_rare_chance_bonus(base_chances, 100.0);
+        assert!(rod_chances[2..].iter().sum::<f64>() > base_chances[2..].iter().sum::<f64>());
+        assert!((rod_chances.iter().sum::<f64>() - 100.0).abs() < 1e-9);
+
+        // Rod drops may swap the rod mid-run, but never for a weaker one
+        assert!(count_rare_or_better(&mut rodded) > count_rare_or_better(&mut plain));
+    }
+
+    #[test]
+    fn test_active_bait_boosts_rare_chance() {
+        let mut state = create_test_game_state();
+        let base = fishing_rare_chance_percent(&state);
+
+        state.active_bait = Some(Bait::new(BaitKind::Glimmer));
+        assert_eq!(
+            fishing_rare_chance_percent(&state),
+            base + BAIT_RARE_CHANCE_PERCENT
+        );
+
+        // Quickbite speeds up the line instead of improving rarity
+        state.active_bait = Some(Bait::new(BaitKind::Quickbite));
+        assert_eq!(fishing_rare_chance_percent(&state), base);
+    }
+
+    #[test]
+    fn test_bait_casts_run_out_and_stop_applying() {
+        let mut rng = ChaCha8Rng::seed_from_u64(5);
+        let mut state = create_test_game_state();
+        state.active_bait = Some(Bait {
+            kind: BaitKind::Glimmer,
+            remaining_casts: 2,
+        });
+
+        for expected_left in [1, 0] {
+            state.active_fishing = Some(FishingSession {
+                spot_name: "Test Lake".to_string(),
+                total_fish: 100,
+                fish_caught: Vec::new(),
+                items_found: Vec::new(),
+                ticks_remaining: 1,
+                phase: FishingPhase::Reeling,
+            });
+            tick_fishing_with_haven(&mut state, &mut rng, &HavenFishingBonuses::default());
+            assert_eq!(
+                state.active_bait.map_or(0, |b| b.remaining_casts),
+                expected_left
+            );
+        }
+
+        assert!(state.active_bait.is_none());
+        assert_eq!(fishing_rare_chance_percent(&state), 0.0);
+    }
+
     #[test]
     fn test_haven_double_fish() {
         let mut state = create_test_game_state();
@@ -1072,7 +1356,7 @@ mod tests {
             };
             state.active_fishing = Some(session);
 
-            let result = tick_fishing_with_haven_result(&mut state, &mut rng, &haven);
+            let result = tick_fishing_with_haven_result(&mut state, &mut rng, &haven, None);
 
             if let Some(enc) = result.leviathan_encounter {
                 assert_eq!(enc, 1, "First encounter should be number 1");
@@ -1111,7 +1395,7 @@ mod tests {
             };
             state.active_fishing = Some(session);
 
-            let result = tick_fishing_with_haven_result(&mut state, &mut rng, &haven);
+            let result = tick_fishing_with_haven_result(&mut state, &mut rng, &haven, None);
 
             assert!(
                 result.leviathan_encounter.is_none(),
@@ -1146,7 +1430,7 @@ mod tests {
             };
             state.active_fishing = Some(session);
 
-            let result = tick_fishing_with_haven_result(&mut state, &mut rng, &haven);
+            let result = tick_fishing_with_haven_result(&mut state, &mut rng, &haven, None);
 
             if result.caught_storm_leviathan {
                 assert!(
@@ -1286,6 +1570,10 @@ mod tests {
             fish_toward_next_rank: 4000, // Enough for rank 31 (requires 4000)
             legendary_catches: 100,
             leviathan_encounters: 0,
+            hardcore: false,
+            auto_fishing: false,
+            last_spot: None,
+            tournament: None,
         };
 
         // With max_rank=30, should NOT rank up past 30
@@ -1317,6 +1605,10 @@ mod tests {
                 fish_toward_next_rank: required,
                 legendary_catches: 0,
                 leviathan_encounters: 0,
+                hardcore: false,
+                auto_fishing: false,
+                last_spot: None,
+                tournament: None,
             };
 
             let result = check_rank_up_with_max(&mut fishing_state, 40);
@@ -1369,7 +1661,7 @@ mod tests {
                 max_fishing_rank_bonus: 0,
             };
 
-            let result = tick_fishing_with_haven_result(&mut state, &mut rng, &haven);
+            let result = tick_fishing_with_haven_result(&mut state, &mut rng, &haven, None);
 
             // With 100% double fish and 2 total needed, should complete in one catch
             if state.active_fishing.is_none() {
@@ -1408,7 +1700,7 @@ mod tests {
         };
         state.active_fishing = Some(session);
 
-        let result = tick_fishing_with_haven_result(&mut state, &mut rng, &haven);
+        let result = tick_fishing_with_haven_result(&mut state, &mut rng, &haven, None);
 
         // Catch message should contain one of the rarity names
         let catch_msg = result
@@ -1427,4 +1719,101 @@ mod tests {
             catch_msg
         );
     }
+
+    // =========================================================================
+    // Hardcore fishing
+    // =========================================================================
+
+    #[test]
+    fn test_hardcore_leviathan_escape_costs_a_rank() {
+        let mut fishing_state = FishingState {
+            rank: 40,
+            fish_toward_next_rank: 500,
+            hardcore: true,
+            ..Default::default()
+        };
+        assert!(apply_leviathan_escape_penalty(&mut fishing_state));
+        assert_eq!(fishing_state.rank, 39);
+        assert_eq!(fishing_state.fish_toward_next_rank, 0);
+    }
+
+    #[test]
+    fn test_normal_leviathan_escape_keeps_rank() {
+        let mut fishing_state = FishingState {
+            rank: 40,
+            fish_toward_next_rank: 500,
+            ..Default::default()
+        };
+        assert!(!apply_leviathan_escape_penalty(&mut fishing_state));
+        assert_eq!(fishing_state.rank, 40);
+        assert_eq!(fishing_state.fish_toward_next_rank, 500);
+    }
+
+    #[test]
+    fn test_hardcore_penalty_never_drops_below_rank_1() {
+        let mut fishing_state = FishingState {
+            rank: 1,
+            hardcore: true,
+            ..Default::default()
+        };
+        assert!(!apply_leviathan_escape_penalty(&mut fishing_state));
+        assert_eq!(fishing_state.rank, 1);
+    }
+
+    #[test]
+    fn test_hardcore_leviathan_escape_in_fishing_tick() {
+        let haven = HavenFishingBonuses::default();
+        for seed in 0u64..5000 {
+            let mut rng = ChaCha8Rng::seed_from_u64(seed);
+            let mut state = create_test_game_state();
+            state.fishing.rank = 40;
+            state.fishing.hardcore = true;
+            state.active_fishing = Some(FishingSession {
+                spot_name: "Deep Sea".to_string(),
+                total_fish: 100,
+                fish_caught: Vec::new(),
+                items_found: Vec::new(),
+                ticks_remaining: 1,
+                phase: FishingPhase::Reeling,
+            });
+
+            let result = tick_fishing_with_haven_result(&mut state, &mut rng, &haven, None);
+
+            if result.leviathan_encounter.is_some() {
+                assert_eq!(state.fishing.rank, 39);
+                assert!(result
+                    .messages
+                    .iter()
+                    .any(|m| m.contains("snapped your line")));
+                return;
+            }
+        }
+        panic!("Should encounter Leviathan at least once in 5000 seeds at rank 40");
+    }
+
+    #[test]
+    fn test_current_hotspot_active_at_window_start() {
+        let window = FISHING_HOTSPOT_WINDOW_SECONDS;
+        assert_eq!(current_hotspot(0), Some(FishRarity::Uncommon));
+        assert_eq!(
+            current_hotspot(FISHING_HOTSPOT_ACTIVE_SECONDS - 1),
+            Some(FishRarity::Uncommon)
+        );
+        // Boosted rarity rotates each window and wraps around
+        assert_eq!(current_hotspot(window), Some(FishRarity::Rare));
+        assert_eq!(current_hotspot(2 * window + 10), Some(FishRarity::Epic));
+        assert_eq!(current_hotspot(3 * window), Some(FishRarity::Legendary));
+        assert_eq!(current_hotspot(4 * window), Some(FishRarity::Uncommon));
+    }
+
+    #[test]
+    fn test_current_hotspot_inactive_outside_window() {
+        assert_eq!(current_hotspot(FISHING_HOTSPOT_ACTIVE_SECONDS), None);
+        assert_eq!(current_hotspot(FISHING_HOTSPOT_WINDOW_SECONDS - 1), None);
+        // Deterministic: same timestamp, same answer
+        assert_eq!(
+            current_hotspot(1_700_000_000),
+            current_hotspot(1_700_000_000)
+        );
+    }
 }
@@ -2,10 +2,16 @@
 
 #![allow(unused_imports)]
 
+pub mod bait;
+pub mod gear;
 pub mod generation;
 pub mod logic;
+pub mod tournament;
 pub mod types;
 
+pub use bait::*;
+pub use gear::*;
 pub use generation::*;
 pub use logic::*;
+pub use tournament::*;
 pub use types::*;
@@ -0,0 +1,213 @@
+//! Fishing tournaments: timed events scored on rarity-weighted catches.
+//!
+//! Like hotspots, tournaments are scheduled from wall-clock time alone: every
+//! `FISHING_TOURNAMENT_WINDOW_SECONDS` window opens with a tournament lasting
+//! `FISHING_TOURNAMENT_ACTIVE_SECONDS`. Each fish caught while one runs adds
+//! `tournament_points(rarity)` to `FishingState::tournament`. Once the
+//! tournament's window has passed, `resolve_finished_tournament` pays out the
+//! reward for the tier the score reached, even if the player was away when
+//! it ended.
+
+use serde::{Deserialize, Serialize};
+
+use super::types::{FishRarity, FishingState};
+use crate::core::constants::{
+    FISHING_TOURNAMENT_ACTIVE_SECONDS, FISHING_TOURNAMENT_BRONZE_SCORE,
+    FISHING_TOURNAMENT_GOLD_SCORE, FISHING_TOURNAMENT_SILVER_SCORE,
+    FISHING_TOURNAMENT_WINDOW_SECONDS,
+};
+use crate::core::game_logic::xp_for_next_level;
+use crate::core::game_state::GameState;
+
+/// Score for a tournament, saved with the character so it survives a restart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FishingTournament {
+    /// Schedule window the tournament belongs to (see `current_tournament`)
+    pub window: i64,
+    pub score: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TournamentTier {
+    Bronze,
+    Silver,
+    Gold,
+}
+
+impl TournamentTier {
+    /// The tier reached by `score`, None below the Bronze threshold.
+    pub fn for_score(score: u32) -> Option<Self> {
+        if score >= FISHING_TOURNAMENT_GOLD_SCORE {
+            Some(Self::Gold)
+        } else if score >= FISHING_TOURNAMENT_SILVER_SCORE {
+            Some(Self::Silver)
+        } else if score >= FISHING_TOURNAMENT_BRONZE_SCORE {
+            Some(Self::Bronze)
+        } else {
+            None
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Bronze => "Bronze",
+            Self::Silver => "Silver",
+            Self::Gold => "Gold",
+        }
+    }
+
+    /// Reward as a percentage of the XP needed for the next level.
+    pub fn xp_percent(&self) -> u64 {
+        match self {
+            Self::Bronze => 25,
+            Self::Silver => 50,
+            Self::Gold => 100,
+        }
+    }
+}
+
+/// The schedule window of the tournament running at `timestamp` (Unix
+/// seconds), or None between tournaments.
+pub fn current_tournament(timestamp: i64) -> Option<i64> {
+    (timestamp.rem_euclid(FISHING_TOURNAMENT_WINDOW_SECONDS) < FISHING_TOURNAMENT_ACTIVE_SECONDS)
+        .then(|| timestamp.div_euclid(FISHING_TOURNAMENT_WINDOW_SECONDS))
+}
+
+/// Tournament points for one fish.
+pub fn tournament_points(rarity: FishRarity) -> u32 {
+    match rarity {
+        FishRarity::Common => 1,
+        FishRarity::Uncommon => 3,
+        FishRarity::Rare => 10,
+        FishRarity::Epic => 30,
+        FishRarity::Legendary => 100,
+    }
+}
+
+/// Adds a catch to the tournament running in `window`, starting its score
+/// if this is the first catch. A leftover score from an earlier window must
+/// be resolved first; it is replaced here.
+pub fn record_tournament_catch(fishing: &mut FishingState, window: i64, rarity: FishRarity) {
+    let tournament = match &mut fishing.tournament {
+        Some(t) if t.window == window => t,
+        slot => slot.insert(FishingTournament { window, score: 0 }),
+    };
+    tournament.score += tournament_points(rarity);
+}
+
+/// Ends the saved tournament if its window is no longer running at
+/// `timestamp`, awarding the reward for its tier. Returns a log message, or
+/// None if there was nothing to resolve.
+pub fn resolve_finished_tournament(state: &mut GameState, timestamp: i64) -> Option<String> {
+    let tournament = state.fishing.tournament?;
+    if current_tournament(timestamp) == Some(tournament.window) {
+        return None;
+    }
+    state.fishing.tournament = None;
+
+    let Some(tier) = TournamentTier::for_score(tournament.score) else {
+        return Some(format!(
+            "Fishing tournament over: {} points, short of Bronze ({}).",
+            tournament.score, FISHING_TOURNAMENT_BRONZE_SCORE
+        ));
+    };
+    let xp = xp_for_next_level(state.character_level.max(1)) * tier.xp_percent() / 100;
+    state.character_xp += xp;
+    Some(format!(
+        "🏆 Fishing tournament over: {} points, {} tier! +{} XP",
+        tournament.score,
+        tier.name(),
+        xp
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A timestamp inside the tournament of `window`.
+    fn during(window: i64) -> i64 {
+        window * FISHING_TOURNAMENT_WINDOW_SECONDS + 60
+    }
+
+    fn after(window: i64) -> i64 {
+        window * FISHING_TOURNAMENT_WINDOW_SECONDS + FISHING_TOURNAMENT_ACTIVE_SECONDS
+    }
+
+    #[test]
+    fn test_schedule_is_deterministic() {
+        assert_eq!(current_tournament(during(7)), Some(7));
+        assert_eq!(current_tournament(after(7) - 1), Some(7));
+        assert_eq!(current_tournament(after(7)), None);
+        assert_eq!(current_tournament(during(8)), Some(8));
+    }
+
+    #[test]
+    fn test_catches_accumulate_score() {
+        let mut fishing = FishingState::default();
+        record_tournament_catch(&mut fishing, 3, FishRarity::Common);
+        record_tournament_catch(&mut fishing, 3, FishRarity::Rare);
+        record_tournament_catch(&mut fishing, 3, FishRarity::Legendary);
+        assert_eq!(
+            fishing.tournament,
+            Some(FishingTournament {
+                window: 3,
+                score: 111
+            })
+        );
+
+        // A catch in a later tournament starts over
+        record_tournament_catch(&mut fishing, 4, FishRarity::Epic);
+        assert_eq!(fishing.tournament.unwrap().score, 30);
+    }
+
+    #[test]
+    fn test_tier_thresholds() {
+        assert_eq!(TournamentTier::for_score(0), None);
+        assert_eq!(
+            TournamentTier::for_score(FISHING_TOURNAMENT_BRONZE_SCORE),
+            Some(TournamentTier::Bronze)
+        );
+        assert_eq!(
+            TournamentTier::for_score(FISHING_TOURNAMENT_SILVER_SCORE),
+            Some(TournamentTier::Silver)
+        );
+        assert_eq!(
+            TournamentTier::for_score(FISHING_TOURNAMENT_GOLD_SCORE + 500),
+            Some(TournamentTier::Gold)
+        );
+    }
+
+    #[test]
+    fn test_resolves_with_reward_for_tier() {
+        let mut state = GameState::new("Angler".to_string(), 0);
+        state.character_level = 10;
+        state.fishing.tournament = Some(FishingTournament {
+            window: 5,
+            score: FISHING_TOURNAMENT_SILVER_SCORE,
+        });
+
+        // Still running: nothing happens
+        assert_eq!(resolve_finished_tournament(&mut state, during(5)), None);
+        assert_eq!(state.character_xp, 0);
+
+        let message = resolve_finished_tournament(&mut state, after(5)).unwrap();
+        assert!(message.contains("Silver"), "{}", message);
+        assert_eq!(state.character_xp, xp_for_next_level(10) * 50 / 100);
+        assert_eq!(state.fishing.tournament, None);
+        assert_eq!(resolve_finished_tournament(&mut state, after(5)), None);
+    }
+
+    #[test]
+    fn test_low_score_resolves_without_reward() {
+        let mut state = GameState::new("Angler".to_string(), 0);
+        state.fishing.tournament = Some(FishingTournament {
+            window: 5,
+            score: 2,
+        });
+        let message = resolve_finished_tournament(&mut state, during(6)).unwrap();
+        assert!(message.contains("short of Bronze"), "{}", message);
+        assert_eq!(state.character_xp, 0);
+        assert_eq!(state.fishing.tournament, None);
+    }
+}
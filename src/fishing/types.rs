@@ -7,6 +7,7 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::tournament::FishingTournament;
 use crate::items::Item;
 
 /// Rarity tiers for caught fish, determining XP rewards and catch difficulty.
@@ -19,6 +20,27 @@ pub enum FishRarity {
     Legendary = 4,
 }
 
+impl FishRarity {
+    /// Every rarity, lowest first.
+    pub const ALL: [FishRarity; 5] = [
+        FishRarity::Common,
+        FishRarity::Uncommon,
+        FishRarity::Rare,
+        FishRarity::Epic,
+        FishRarity::Legendary,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            FishRarity::Common => "Common",
+            FishRarity::Uncommon => "Uncommon",
+            FishRarity::Rare => "Rare",
+            FishRarity::Epic => "Epic",
+            FishRarity::Legendary => "Legendary",
+        }
+    }
+}
+
 /// Represents a single fish that has been caught.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CaughtFish {
@@ -66,6 +88,19 @@ pub struct FishingState {
     /// Storm Leviathan encounter progress (0-10). At 10, the next encounter catches it.
     #[serde(default, skip_serializing_if = "is_zero")]
     pub leviathan_encounters: u8,
+    /// Hardcore fishing: an escaped Leviathan snaps the line and costs a rank.
+    #[serde(default)]
+    pub hardcore: bool,
+    /// Autopilot: start a new session at `last_spot` whenever the last one
+    /// ends and no boss fight, dungeon or minigame is in the way.
+    #[serde(default)]
+    pub auto_fishing: bool,
+    /// Name of the most recently discovered fishing spot
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_spot: Option<String>,
+    /// Score in the fishing tournament the player last entered, until resolved
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tournament: Option<FishingTournament>,
 }
 
 impl Default for FishingState {
@@ -76,6 +111,10 @@ impl Default for FishingState {
             fish_toward_next_rank: 0,
             legendary_catches: 0,
             leviathan_encounters: 0,
+            hardcore: false,
+            auto_fishing: false,
+            last_spot: None,
+            tournament: None,
         }
     }
 }
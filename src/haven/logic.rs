@@ -1,11 +1,14 @@
 //! Haven build/upgrade logic and persistence.
 
-use super::types::{haven_discovery_chance, tier_cost, Haven, HavenRoomId};
-use crate::core::constants::STORMBREAKER_PRESTIGE_REQUIREMENT;
+use super::types::{tier_cost, Haven, HavenRoomId};
+use crate::core::constants::{
+    HAVEN_DISCOVERY_BASE_CHANCE, HAVEN_DISCOVERY_RANK_BONUS, HAVEN_MIN_PRESTIGE_RANK,
+    STORMBREAKER_PRESTIGE_REQUIREMENT,
+};
 use rand::{Rng, RngExt};
 use std::fs;
 use std::io;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Check if a character can afford to build/upgrade a room
 pub fn can_afford(room: HavenRoomId, haven: &Haven, prestige_rank: u32) -> bool {
@@ -50,13 +53,128 @@ pub fn can_forge_stormbreaker(
     (has_leviathan, has_prestige, can_forge)
 }
 
+/// Outcome of `Haven::apply_build_order`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ApplyResult {
+    /// Each tier built, in the order it was built
+    pub built: Vec<(HavenRoomId, u8)>,
+    pub ranks_spent: u32,
+    /// Stopped at a step that cost more than the remaining ranks
+    pub out_of_ranks: bool,
+    /// Entries that didn't name a room and tier
+    pub unrecognized: Vec<String>,
+}
+
+impl Haven {
+    /// Shareable build order: built rooms and their tiers in tree order,
+    /// e.g. `Hearthstone:3,Armory:1`, using the same room keys as haven.json.
+    pub fn export_build_order(&self) -> String {
+        HavenRoomId::ALL
+            .iter()
+            .filter(|room| self.room_tier(**room) >= 1)
+            .map(|room| format!("{:?}:{}", room, self.room_tier(*room)))
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    /// Build as much of a shared build order as `available_ranks` pays for.
+    ///
+    /// Rooms are built tier by tier in tree order, so parents always come
+    /// before children whatever order the entries are listed in. Rooms whose
+    /// parents the order never builds are skipped; the first step that can't
+    /// be afforded stops the whole order. The caller deducts `ranks_spent`.
+    pub fn apply_build_order(&mut self, order: &str, available_ranks: u32) -> ApplyResult {
+        let mut result = ApplyResult::default();
+        let mut targets = std::collections::HashMap::new();
+        for entry in order.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            let parsed = entry.split_once(':').and_then(|(key, tier)| {
+                let room = HavenRoomId::ALL
+                    .into_iter()
+                    .find(|room| format!("{:?}", room) == key.trim())?;
+                Some((room, tier.trim().parse::<u8>().ok()?))
+            });
+            match parsed {
+                Some((room, tier)) => {
+                    let target = targets.entry(room).or_insert(0);
+                    *target = tier.min(room.max_tier()).max(*target);
+                }
+                None => result.unrecognized.push(entry.to_string()),
+            }
+        }
+
+        let mut remaining = available_ranks;
+        for room in HavenRoomId::ALL {
+            let target = targets.get(&room).copied().unwrap_or(0);
+            while self.room_tier(room) < target && self.can_build(room) {
+                match try_build_room(room, self, &mut remaining) {
+                    Some((tier, cost)) => {
+                        result.built.push((room, tier));
+                        result.ranks_spent += cost;
+                    }
+                    None => {
+                        result.out_of_ranks = true;
+                        return result;
+                    }
+                }
+            }
+        }
+        result
+    }
+}
+
+/// Tunables for the per-tick Haven discovery roll. The chance scales with
+/// prestige rank: `base_chance` at the threshold (~2hr at P10 by default),
+/// plus `rank_bonus` per rank above it.
+///
+/// The default is the normal game rule (`HAVEN_MIN_PRESTIGE_RANK` and the
+/// `HAVEN_DISCOVERY_*` chances); difficulty modes and tests can pass their own.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HavenDiscoveryConfig {
+    /// Prestige rank below which the Haven can't be discovered
+    pub min_prestige_rank: u32,
+    /// Chance per tick at exactly `min_prestige_rank`
+    pub base_chance: f64,
+    /// Extra chance per tick for each rank above `min_prestige_rank`
+    pub rank_bonus: f64,
+}
+
+impl Default for HavenDiscoveryConfig {
+    fn default() -> Self {
+        Self {
+            min_prestige_rank: HAVEN_MIN_PRESTIGE_RANK,
+            base_chance: HAVEN_DISCOVERY_BASE_CHANCE,
+            rank_bonus: HAVEN_DISCOVERY_RANK_BONUS,
+        }
+    }
+}
+
+impl HavenDiscoveryConfig {
+    /// Whether `prestige_rank` is high enough to roll at all.
+    pub fn is_eligible(&self, prestige_rank: u32) -> bool {
+        prestige_rank >= self.min_prestige_rank
+    }
+
+    /// Discovery chance per tick at `prestige_rank`, 0 below the threshold.
+    pub fn chance(&self, prestige_rank: u32) -> f64 {
+        if !self.is_eligible(prestige_rank) {
+            return 0.0;
+        }
+        self.base_chance + (prestige_rank - self.min_prestige_rank) as f64 * self.rank_bonus
+    }
+}
+
 /// Try to discover the Haven. Independent roll per tick.
 /// Returns true if discovered this tick.
-pub fn try_discover_haven<R: Rng>(haven: &mut Haven, prestige_rank: u32, rng: &mut R) -> bool {
+pub fn try_discover_haven<R: Rng>(
+    haven: &mut Haven,
+    prestige_rank: u32,
+    config: &HavenDiscoveryConfig,
+    rng: &mut R,
+) -> bool {
     if haven.discovered {
         return false;
     }
-    let chance = haven_discovery_chance(prestige_rank);
+    let chance = config.chance(prestige_rank);
     if chance <= 0.0 {
         return false;
     }
@@ -67,15 +185,12 @@ pub fn try_discover_haven<R: Rng>(haven: &mut Haven, prestige_rank: u32, rng: &m
     false
 }
 
+/// Haven save file name inside the data directory
+pub const HAVEN_FILE: &str = "haven.json";
+
 /// Get the Haven save file path
 pub fn haven_save_path() -> io::Result<PathBuf> {
-    let home_dir = dirs::home_dir().ok_or_else(|| {
-        io::Error::new(
-            io::ErrorKind::NotFound,
-            "Could not determine home directory",
-        )
-    })?;
-    Ok(home_dir.join(".quest").join("haven.json"))
+    Ok(crate::core::paths::quest_dir()?.join(HAVEN_FILE))
 }
 
 /// Load Haven from disk, or return default if not found
@@ -92,7 +207,31 @@ pub fn load_haven() -> Haven {
 
 /// Save Haven to disk
 pub fn save_haven(haven: &Haven) -> io::Result<()> {
-    let path = haven_save_path()?;
+    write_haven_file(haven, &haven_save_path()?)
+}
+
+/// Shared build order file name inside the data directory
+pub const BUILD_ORDER_FILE: &str = "build_order.txt";
+
+/// Get the shared build order file path
+pub fn build_order_path() -> io::Result<PathBuf> {
+    Ok(crate::core::paths::quest_dir()?.join(BUILD_ORDER_FILE))
+}
+
+/// Export the Haven's build order to the shared file, returning its path
+pub fn write_build_order(haven: &Haven) -> io::Result<PathBuf> {
+    let path = build_order_path()?;
+    fs::write(&path, haven.export_build_order() + "\n")?;
+    Ok(path)
+}
+
+/// Read a build order from the shared file
+pub fn read_build_order() -> io::Result<String> {
+    Ok(fs::read_to_string(build_order_path()?)?.trim().to_string())
+}
+
+/// Write Haven to any path.
+pub fn write_haven_file(haven: &Haven, path: &Path) -> io::Result<()> {
     let json = serde_json::to_string_pretty(haven)
         .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
     fs::write(path, json)?;
@@ -162,9 +301,10 @@ mod tests {
     fn test_try_discover_haven_below_p10() {
         let mut haven = Haven::new();
         let mut rng = rand::rng();
+        let config = HavenDiscoveryConfig::default();
         // Below P10, should never discover
         for _ in 0..100_000 {
-            assert!(!try_discover_haven(&mut haven, 9, &mut rng));
+            assert!(!try_discover_haven(&mut haven, 9, &config, &mut rng));
         }
     }
 
@@ -173,16 +313,18 @@ mod tests {
         let mut haven = Haven::new();
         haven.discovered = true;
         let mut rng = rand::rng();
-        assert!(!try_discover_haven(&mut haven, 20, &mut rng));
+        let config = HavenDiscoveryConfig::default();
+        assert!(!try_discover_haven(&mut haven, 20, &config, &mut rng));
     }
 
     #[test]
     fn test_try_discover_haven_eventually_succeeds() {
         let mut haven = Haven::new();
         let mut rng = rand::rng();
+        let config = HavenDiscoveryConfig::default();
         let mut discovered = false;
         for _ in 0..1_000_000 {
-            if try_discover_haven(&mut haven, 10, &mut rng) {
+            if try_discover_haven(&mut haven, 10, &config, &mut rng) {
                 discovered = true;
                 break;
             }
@@ -191,6 +333,54 @@ mod tests {
         assert!(haven.discovered);
     }
 
+    #[test]
+    fn test_discovery_chance_below_p10() {
+        let config = HavenDiscoveryConfig::default();
+        assert_eq!(config.chance(0), 0.0);
+        assert_eq!(config.chance(9), 0.0);
+    }
+
+    #[test]
+    fn test_discovery_chance_scales_with_prestige() {
+        let config = HavenDiscoveryConfig::default();
+        let p10 = config.chance(10);
+        let p12 = config.chance(12);
+        let p20 = config.chance(20);
+        assert!(p10 > 0.0);
+        assert!(p12 > p10);
+        assert!(p20 > p12);
+        assert!((p10 - 0.000014).abs() < 0.0000001);
+    }
+
+    #[test]
+    fn test_try_discover_haven_forced_by_config() {
+        let mut haven = Haven::new();
+        let mut rng = rand::rng();
+        let config = HavenDiscoveryConfig {
+            min_prestige_rank: 0,
+            base_chance: 1.0,
+            rank_bonus: 0.0,
+        };
+        assert!(try_discover_haven(&mut haven, 0, &config, &mut rng));
+        assert!(haven.discovered);
+    }
+
+    #[test]
+    fn test_try_discover_haven_custom_threshold_blocks_below() {
+        let mut haven = Haven::new();
+        let mut rng = rand::rng();
+        let config = HavenDiscoveryConfig {
+            min_prestige_rank: 25,
+            base_chance: 1.0,
+            rank_bonus: 0.0,
+        };
+        for _ in 0..1000 {
+            assert!(!try_discover_haven(&mut haven, 24, &config, &mut rng));
+        }
+        assert!(!haven.discovered);
+        assert!(try_discover_haven(&mut haven, 25, &config, &mut rng));
+    }
+
     #[test]
     fn test_build_full_branch_costs() {
         let mut haven = Haven::new();
@@ -394,11 +584,12 @@ mod tests {
     fn test_discovery_requires_p10() {
         let mut haven = Haven::new();
         let mut rng = rand::rng();
+        let config = HavenDiscoveryConfig::default();
 
         // P0-P9 cannot discover Haven
         for p in 0..10 {
             for _ in 0..1000 {
-                if try_discover_haven(&mut haven, p, &mut rng) {
+                if try_discover_haven(&mut haven, p, &config, &mut rng) {
                     panic!("Should not discover Haven at P{}", p);
                 }
             }
@@ -558,6 +749,61 @@ mod tests {
         assert!(haven.next_tier(HavenRoomId::StormForge).is_none());
     }
 
+    #[test]
+    fn test_build_order_round_trips_full_haven() {
+        let mut full = Haven::new();
+        for room in HavenRoomId::ALL {
+            while full.build_room(room).is_some() {}
+        }
+        let total_cost: u32 = HavenRoomId::ALL
+            .iter()
+            .flat_map(|room| (1..=room.max_tier()).map(|tier| tier_cost(*room, tier)))
+            .sum();
+
+        let order = full.export_build_order();
+        assert!(order.starts_with("Hearthstone:3,Armory:3"));
+        assert!(order.ends_with("StormForge:1"));
+
+        let mut haven = Haven::new();
+        let result = haven.apply_build_order(&order, total_cost);
+        assert!(!result.out_of_ranks);
+        assert!(result.unrecognized.is_empty());
+        assert_eq!(result.ranks_spent, total_cost);
+        assert_eq!(haven.export_build_order(), order);
+        for room in HavenRoomId::ALL {
+            assert_eq!(haven.room_tier(room), room.max_tier());
+        }
+    }
+
+    #[test]
+    fn test_apply_build_order_stops_when_ranks_run_out() {
+        let mut haven = Haven::new();
+        // Child listed first: still built after its parent.
+        // Hearthstone 1+2, Armory T1 1, Armory T2 3 (unaffordable with 1 left)
+        let result = haven.apply_build_order("Armory:3, Hearthstone:2, Bedroom:1", 5);
+        assert!(result.out_of_ranks);
+        assert_eq!(
+            result.built,
+            vec![
+                (HavenRoomId::Hearthstone, 1),
+                (HavenRoomId::Hearthstone, 2),
+                (HavenRoomId::Armory, 1),
+            ]
+        );
+        assert_eq!(result.ranks_spent, 4);
+        // Stopped before Bedroom even though it would have been affordable
+        assert_eq!(haven.room_tier(HavenRoomId::Bedroom), 0);
+    }
+
+    #[test]
+    fn test_apply_build_order_skips_unmet_dependencies() {
+        let mut haven = Haven::new();
+        let result = haven.apply_build_order("Armory:1,Nowhere:2,Hearthstone:x", 100);
+        assert!(result.built.is_empty());
+        assert!(!result.out_of_ranks);
+        assert_eq!(result.unrecognized, vec!["Nowhere:2", "Hearthstone:x"]);
+    }
+
     // Helper function to build full tree to both capstones (WarRoom and Vault)
     fn build_full_tree_to_capstones(haven: &mut Haven) {
         // Root
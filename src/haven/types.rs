@@ -234,8 +234,9 @@ pub enum HavenBonusType {
     ItemRarityPercent,
     HpRegenDelayReduction,
     VaultSlots,
-    MaxFishingRank,   // FishingDock T4 bonus
-    StormForgeAccess, // StormForge enables forging
+    MaxFishingRank,      // FishingDock T4 bonus
+    StormForgeAccess,    // StormForge enables forging
+    EmergencyHealChance, // AlchemyLab secondary bonus
 }
 
 /// A specific bonus value for a room at a given tier
@@ -309,13 +310,28 @@ impl HavenRoomId {
         }
     }
 
-    /// Get the bonus value for a specific tier (0 = unbuilt)
-    pub fn bonus_value(&self, tier: u8) -> f64 {
-        let max_tier = self.max_tier();
-        if tier == 0 || tier > max_tier {
+    /// Get the second bonus this room grants alongside `bonus()`, if any
+    pub fn secondary_bonus(&self) -> Option<HavenBonus> {
+        match self {
+            HavenRoomId::AlchemyLab => Some(HavenBonus {
+                bonus_type: HavenBonusType::EmergencyHealChance,
+                values: [10.0, 20.0, 35.0, 0.0],
+            }),
+            _ => None,
+        }
+    }
+
+    /// Get the value of a bonus definition at a specific tier (0 = unbuilt)
+    fn tier_value(&self, bonus: HavenBonus, tier: u8) -> f64 {
+        if tier == 0 || tier > self.max_tier() {
             return 0.0;
         }
-        self.bonus().values[(tier - 1) as usize]
+        bonus.values[(tier - 1) as usize]
+    }
+
+    /// Get the bonus value for a specific tier (0 = unbuilt)
+    pub fn bonus_value(&self, tier: u8) -> f64 {
+        self.tier_value(self.bonus(), tier)
     }
 
     /// Format the bonus for display (e.g., "+5% DMG", "-10% Attack Interval")
@@ -327,31 +343,44 @@ impl HavenRoomId {
         if *self == HavenRoomId::FishingDock && tier == 4 {
             return "+10 Max Fishing Rank".to_string();
         }
-        let value = self.bonus_value(tier);
-        match self.bonus().bonus_type {
-            HavenBonusType::DamagePercent => format!("+{:.0}% DMG", value),
-            HavenBonusType::XpGainPercent => format!("+{:.0}% XP", value),
-            HavenBonusType::DropRatePercent => format!("+{:.0}% Drops", value),
-            HavenBonusType::CritChancePercent => format!("+{:.0}% Crit", value),
-            HavenBonusType::HpRegenPercent => format!("+{:.0}% HP Regen", value),
-            HavenBonusType::DoubleStrikeChance => format!("+{:.0}% Double Strike", value),
-            HavenBonusType::OfflineXpPercent => format!("+{:.0}% Offline XP", value),
-            HavenBonusType::ChallengeDiscoveryPercent => format!("+{:.0}% Discovery", value),
-            HavenBonusType::FishingTimerReduction => format!("-{:.0}% Fishing Timers", value),
-            HavenBonusType::DoubleFishChance => format!("+{:.0}% Double Fish", value),
-            HavenBonusType::ItemRarityPercent => format!("+{:.0}% Item Rarity", value),
-            HavenBonusType::HpRegenDelayReduction => format!("-{:.0}% Regen Delay", value),
-            HavenBonusType::VaultSlots => format!(
-                "{:.0} item{} preserved",
-                value,
-                if value > 1.0 { "s" } else { "" }
+        let primary = format_bonus_value(self.bonus().bonus_type, self.bonus_value(tier));
+        match self.secondary_bonus() {
+            Some(secondary) => format!(
+                "{}, {}",
+                primary,
+                format_bonus_value(secondary.bonus_type, self.tier_value(secondary, tier))
             ),
-            HavenBonusType::MaxFishingRank => format!("+{:.0} Max Fishing Rank", value),
-            HavenBonusType::StormForgeAccess => "Stormbreaker forging enabled".to_string(),
+            None => primary,
         }
     }
 }
 
+/// Format one bonus value for display (e.g., "+5% DMG")
+fn format_bonus_value(bonus_type: HavenBonusType, value: f64) -> String {
+    match bonus_type {
+        HavenBonusType::DamagePercent => format!("+{:.0}% DMG", value),
+        HavenBonusType::XpGainPercent => format!("+{:.0}% XP", value),
+        HavenBonusType::DropRatePercent => format!("+{:.0}% Drops", value),
+        HavenBonusType::CritChancePercent => format!("+{:.0}% Crit", value),
+        HavenBonusType::HpRegenPercent => format!("+{:.0}% HP Regen", value),
+        HavenBonusType::DoubleStrikeChance => format!("+{:.0}% Double Strike", value),
+        HavenBonusType::OfflineXpPercent => format!("+{:.0}% Offline XP", value),
+        HavenBonusType::ChallengeDiscoveryPercent => format!("+{:.0}% Discovery", value),
+        HavenBonusType::FishingTimerReduction => format!("-{:.0}% Fishing Timers", value),
+        HavenBonusType::DoubleFishChance => format!("+{:.0}% Double Fish", value),
+        HavenBonusType::ItemRarityPercent => format!("+{:.0}% Item Rarity", value),
+        HavenBonusType::HpRegenDelayReduction => format!("-{:.0}% Regen Delay", value),
+        HavenBonusType::VaultSlots => format!(
+            "{:.0} item{} preserved",
+            value,
+            if value > 1.0 { "s" } else { "" }
+        ),
+        HavenBonusType::MaxFishingRank => format!("+{:.0} Max Fishing Rank", value),
+        HavenBonusType::StormForgeAccess => "Stormbreaker forging enabled".to_string(),
+        HavenBonusType::EmergencyHealChance => format!("{:.0}% Emergency Heal", value),
+    }
+}
+
 /// Account-level Haven state, saved to ~/.quest/haven.json
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Haven {
@@ -412,6 +441,11 @@ impl Haven {
         }
     }
 
+    /// Get the emergency heal chance (%) from the Alchemy Lab (0 if unbuilt)
+    pub fn emergency_heal_chance(&self) -> f64 {
+        self.get_bonus(HavenBonusType::EmergencyHealChance)
+    }
+
     /// Check if StormForge is built
     pub fn has_storm_forge(&self) -> bool {
         self.room_tier(HavenRoomId::StormForge) >= 1
@@ -446,25 +480,16 @@ impl Haven {
     pub fn get_bonus(&self, bonus_type: HavenBonusType) -> f64 {
         HavenRoomId::ALL
             .iter()
-            .filter(|r| r.bonus().bonus_type == bonus_type)
-            .map(|r| r.bonus_value(self.room_tier(*r)))
+            .flat_map(|r| {
+                std::iter::once(r.bonus())
+                    .chain(r.secondary_bonus())
+                    .filter(|bonus| bonus.bonus_type == bonus_type)
+                    .map(|bonus| r.tier_value(bonus, self.room_tier(*r)))
+            })
             .sum()
     }
 }
 
-/// Discovery chance per tick. Scales with prestige rank.
-/// Base: HAVEN_DISCOVERY_BASE_CHANCE (~2hr at P10), +HAVEN_DISCOVERY_RANK_BONUS per rank above min.
-pub fn haven_discovery_chance(prestige_rank: u32) -> f64 {
-    use crate::core::constants::{
-        HAVEN_DISCOVERY_BASE_CHANCE, HAVEN_DISCOVERY_RANK_BONUS, HAVEN_MIN_PRESTIGE_RANK,
-    };
-    if prestige_rank < HAVEN_MIN_PRESTIGE_RANK {
-        return 0.0;
-    }
-    HAVEN_DISCOVERY_BASE_CHANCE
-        + (prestige_rank - HAVEN_MIN_PRESTIGE_RANK) as f64 * HAVEN_DISCOVERY_RANK_BONUS
-}
-
 /// Pre-computed Haven bonuses for efficient access during gameplay
 #[derive(Debug, Clone, Default)]
 #[allow(dead_code)] // Will be used for bonus application in follow-up PR
@@ -484,6 +509,7 @@ pub struct HavenBonuses {
     pub vault_slots: u8,
     pub max_fishing_rank_bonus: u32,
     pub has_storm_forge: bool,
+    pub emergency_heal_chance: f64,
 }
 
 impl Haven {
@@ -506,6 +532,7 @@ impl Haven {
             vault_slots: self.get_bonus(HavenBonusType::VaultSlots) as u8,
             max_fishing_rank_bonus: self.fishing_rank_bonus(),
             has_storm_forge: self.has_storm_forge(),
+            emergency_heal_chance: self.emergency_heal_chance(),
         }
     }
 }
@@ -649,23 +676,6 @@ mod tests {
         assert_eq!(haven.rooms_built(), 2);
     }
 
-    #[test]
-    fn test_discovery_chance_below_p10() {
-        assert_eq!(haven_discovery_chance(0), 0.0);
-        assert_eq!(haven_discovery_chance(9), 0.0);
-    }
-
-    #[test]
-    fn test_discovery_chance_scales_with_prestige() {
-        let p10 = haven_discovery_chance(10);
-        let p12 = haven_discovery_chance(12);
-        let p20 = haven_discovery_chance(20);
-        assert!(p10 > 0.0);
-        assert!(p12 > p10);
-        assert!(p20 > p12);
-        assert!((p10 - 0.000014).abs() < 0.0000001);
-    }
-
     #[test]
     fn test_serde_roundtrip() {
         let mut haven = Haven::new();
@@ -1090,6 +1100,26 @@ mod tests {
         assert_eq!(bonuses.item_rarity_percent, 10.0);
         assert_eq!(bonuses.double_strike_chance, 10.0);
         assert_eq!(bonuses.vault_slots, 1);
+        assert_eq!(bonuses.emergency_heal_chance, 10.0);
+    }
+
+    #[test]
+    fn test_emergency_heal_chance_scales_with_alchemy_lab() {
+        let mut haven = Haven::new();
+        assert_eq!(haven.emergency_heal_chance(), 0.0);
+        haven.rooms.insert(HavenRoomId::AlchemyLab, 1);
+        assert_eq!(haven.emergency_heal_chance(), 10.0);
+        haven.rooms.insert(HavenRoomId::AlchemyLab, 3);
+        assert_eq!(haven.emergency_heal_chance(), 35.0);
+        // The secondary bonus doesn't leak into the primary one
+        assert_eq!(
+            haven.get_bonus(HavenBonusType::HpRegenPercent),
+            HavenRoomId::AlchemyLab.bonus_value(3)
+        );
+        assert_eq!(
+            HavenRoomId::AlchemyLab.format_bonus(2),
+            "+50% HP Regen, 20% Emergency Heal"
+        );
     }
 
     #[test]
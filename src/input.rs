@@ -2,42 +2,32 @@
 //!
 //! Extracts the input dispatch logic from main.rs into a clean priority chain.
 
-use crate::challenges::chess::logic::{
-    apply_game_result as apply_chess_result, process_input as process_chess_input, ChessInput,
-};
-use crate::challenges::flappy::logic::{
-    apply_game_result as apply_flappy_result, process_input as process_flappy_input,
-    FlappyBirdInput,
-};
-use crate::challenges::go::{apply_go_result, process_input as process_go_input, GoInput};
-use crate::challenges::gomoku::logic::{
-    apply_game_result as apply_gomoku_result, process_input as process_gomoku_input, GomokuInput,
-};
-use crate::challenges::jezzball::logic::{
-    apply_game_result as apply_jezzball_result, process_input as process_jezzball_input,
-    JezzballInput,
-};
+use crate::challenges::chess::logic::{process_input as process_chess_input, ChessInput};
+use crate::challenges::flappy::logic::{process_input as process_flappy_input, FlappyBirdInput};
+use crate::challenges::go::{process_input as process_go_input, GoInput};
+use crate::challenges::gomoku::logic::{process_input as process_gomoku_input, GomokuInput};
+use crate::challenges::jezzball::logic::{process_input as process_jezzball_input, JezzballInput};
 use crate::challenges::menu::{process_input as process_menu_input, MenuInput};
 use crate::challenges::minesweeper::logic::{
-    apply_game_result as apply_minesweeper_result, process_input as process_minesweeper_input,
-    MinesweeperInput,
+    process_input as process_minesweeper_input, MinesweeperInput,
 };
-use crate::challenges::morris::logic::{
-    apply_game_result as apply_morris_result, process_input as process_morris_input, MorrisInput,
+use crate::challenges::morris::logic::{process_input as process_morris_input, MorrisInput};
+use crate::challenges::rune::logic::{process_input as process_rune_input, RuneInput};
+use crate::challenges::snake::logic::{process_input as process_snake_input, SnakeInput};
+use crate::challenges::{apply_minigame_result, ActiveMinigame};
+use crate::character::prestige::{
+    apply_banked_xp, can_prestige, get_prestige_tier, perform_prestige,
 };
-use crate::challenges::rune::logic::{
-    apply_game_result as apply_rune_result, process_input as process_rune_input, RuneInput,
-};
-use crate::challenges::snake::logic::{
-    apply_game_result as apply_snake_result, process_input as process_snake_input, SnakeInput,
-};
-use crate::challenges::ActiveMinigame;
-use crate::character::prestige::{can_prestige, get_prestige_tier, perform_prestige};
+use crate::core::expedition::{start_expedition, ExpeditionTier};
 use crate::core::game_logic::OfflineReport;
 use crate::core::game_state::GameState;
+use crate::core::tutorial::TutorialStep;
+use crate::dungeon::logic::set_target_direction;
+use crate::dungeon::types::{DIR_DOWN, DIR_LEFT, DIR_RIGHT, DIR_UP};
 use crate::haven;
 use crate::haven::Haven;
 use crate::items;
+use crate::ui::haven_scene::WorkshopUiState;
 use crate::utils::debug_menu::DebugMenu;
 use ratatui::crossterm::event::{KeyCode, KeyEvent};
 
@@ -47,6 +37,10 @@ pub enum HavenConfirmation {
     None,
     Build,
     Forge,
+    /// Workshop crafting panel (Game screen only)
+    Workshop,
+    /// Apply the build order read from `build_order.txt` (Game screen only)
+    ImportBuildOrder,
 }
 
 /// Haven overlay state, shared between CharacterSelect and Game screens.
@@ -54,6 +48,9 @@ pub struct HavenUiState {
     pub showing: bool,
     pub selected_room: usize,
     pub confirmation: HavenConfirmation,
+    pub workshop: WorkshopUiState,
+    /// Build order shown by `HavenConfirmation::ImportBuildOrder`
+    pub build_order: String,
 }
 
 impl HavenUiState {
@@ -62,6 +59,8 @@ impl HavenUiState {
             showing: false,
             selected_room: 0,
             confirmation: HavenConfirmation::None,
+            workshop: WorkshopUiState::default(),
+            build_order: String::new(),
         }
     }
 
@@ -82,6 +81,21 @@ pub enum GameOverlay {
     None,
     HavenDiscovery,
     PrestigeConfirm,
+    /// Confirmation before hardcore fishing is turned on
+    HardcoreFishingConfirm,
+    /// Hotkey reference opened with `?`
+    KeyHelp,
+    /// Zone codex opened with `Z`; `selected` indexes `get_all_zones()`
+    ZoneCodex {
+        selected: usize,
+    },
+    /// Confirmation before the loot filter is turned on
+    LootFilterConfirm,
+    /// Picking and confirming the auto-challenge setting; `choice` is what
+    /// [Y] will set
+    AutoChallengeConfirm {
+        choice: Option<crate::challenges::ChallengeDifficulty>,
+    },
     VaultSelection {
         selected_index: usize,
         selected_slots: Vec<items::EquipmentSlot>,
@@ -100,6 +114,10 @@ pub enum GameOverlay {
     LeviathanEncounter {
         encounter_number: u8,
     },
+    /// First-run tutorial hint
+    TutorialHint {
+        step: TutorialStep,
+    },
 }
 
 /// Result of handling a game input event.
@@ -114,6 +132,18 @@ pub enum InputResult {
     NeedsSaveAll,
     /// Toggle the update details expanded state.
     ToggleUpdateDetails,
+    /// Cycle the combat effects intensity setting.
+    CycleEffects,
+    /// Open the auto-challenge picker at the current setting.
+    OpenAutoChallenge,
+    /// Set the auto-challenge setting (confirmed in the picker).
+    SetAutoChallenge(Option<crate::challenges::ChallengeDifficulty>),
+    /// Cycle the number format setting.
+    CycleNumberFormat,
+    /// Cycle the combat log filter setting.
+    CycleLogFilter,
+    /// Toggle HP bars between exact numbers and percentages.
+    CycleHpDisplay,
 }
 
 /// Main dispatcher for Game screen input. Handles the priority chain.
@@ -165,6 +195,11 @@ pub fn handle_game_input(
         return handle_haven_discovery(key, overlay);
     }
 
+    // 1a. Tutorial hint modal (blocks all other input)
+    if matches!(overlay, GameOverlay::TutorialHint { .. }) {
+        return handle_tutorial_hint(key, state, overlay);
+    }
+
     // 1b. Achievement unlocked modal (blocks all other input)
     if matches!(overlay, GameOverlay::AchievementUnlocked { .. }) {
         return handle_achievement_unlocked(key, overlay);
@@ -185,7 +220,37 @@ pub fn handle_game_input(
         return handle_prestige_confirm(key, state, haven, overlay);
     }
 
-    // 5. Debug menu
+    // 5. Hardcore fishing, loot filter and auto-challenge confirmations
+    if matches!(overlay, GameOverlay::HardcoreFishingConfirm) {
+        return handle_hardcore_fishing_confirm(key, state, overlay);
+    }
+    if matches!(overlay, GameOverlay::LootFilterConfirm) {
+        return handle_loot_filter_confirm(key, state, overlay);
+    }
+    if matches!(overlay, GameOverlay::AutoChallengeConfirm { .. }) {
+        return handle_auto_challenge_confirm(key, overlay);
+    }
+    if matches!(overlay, GameOverlay::KeyHelp) {
+        *overlay = GameOverlay::None;
+        return InputResult::Continue;
+    }
+    if let GameOverlay::ZoneCodex { selected } = overlay {
+        match key.code {
+            KeyCode::Up => *selected = selected.saturating_sub(1),
+            KeyCode::Down => {
+                if *selected + 1 < crate::zones::get_all_zones().len() {
+                    *selected += 1;
+                }
+            }
+            KeyCode::Esc | KeyCode::Char('z') | KeyCode::Char('Z') => {
+                *overlay = GameOverlay::None;
+            }
+            _ => {}
+        }
+        return InputResult::Continue;
+    }
+
+    // 6. Debug menu
     if debug_mode {
         if key.code == KeyCode::Char('`') {
             debug_menu.toggle();
@@ -196,23 +261,23 @@ pub fn handle_game_input(
         }
     }
 
-    // 6. Active minigame
+    // 7. Active minigame
     if state.active_minigame.is_some() {
         return handle_minigame(key, state);
     }
 
-    // 7. Challenge menu
+    // 8. Challenge menu
     if state.challenge_menu.is_open {
         return handle_challenge_menu(key, state);
     }
 
-    // 8. Tab to open challenge menu
+    // 9. Tab to open challenge menu
     if key.code == KeyCode::Tab && !state.challenge_menu.challenges.is_empty() {
         state.challenge_menu.open();
         return InputResult::Continue;
     }
 
-    // 9. Base game input
+    // 10. Base game input
     handle_base_game(
         key,
         state,
@@ -232,6 +297,22 @@ fn handle_haven_discovery(key: KeyEvent, overlay: &mut GameOverlay) -> InputResu
     InputResult::Continue
 }
 
+fn handle_tutorial_hint(
+    key: KeyEvent,
+    state: &mut GameState,
+    overlay: &mut GameOverlay,
+) -> InputResult {
+    match key.code {
+        KeyCode::Enter | KeyCode::Esc => *overlay = GameOverlay::None,
+        KeyCode::Char('s') | KeyCode::Char('S') => {
+            state.tutorial.skip();
+            *overlay = GameOverlay::None;
+        }
+        _ => {}
+    }
+    InputResult::Continue
+}
+
 fn handle_achievement_unlocked(key: KeyEvent, overlay: &mut GameOverlay) -> InputResult {
     // Any key dismisses the achievement modal
     if matches!(key.code, KeyCode::Enter | KeyCode::Esc | KeyCode::Char(' ')) {
@@ -311,6 +392,37 @@ fn handle_haven(
             }
             InputResult::Continue
         }
+        HavenConfirmation::Workshop => handle_workshop(key, state, haven, haven_ui),
+        HavenConfirmation::ImportBuildOrder => {
+            match key.code {
+                KeyCode::Enter => {
+                    haven_ui.confirmation = HavenConfirmation::None;
+                    let result =
+                        haven.apply_build_order(&haven_ui.build_order, state.prestige_rank);
+                    state.prestige_rank -= result.ranks_spent;
+                    let mut message = format!(
+                        "🏠 Build order: built {} tier(s) (spent {} Prestige Ranks)",
+                        result.built.len(),
+                        result.ranks_spent
+                    );
+                    if result.out_of_ranks {
+                        message.push_str(", stopped when ranks ran out");
+                    }
+                    if !result.unrecognized.is_empty() {
+                        message.push_str(&format!(", skipped {}", result.unrecognized.join(", ")));
+                    }
+                    state.combat_state.add_log_entry(message, false, true);
+                    if !result.built.is_empty() {
+                        return InputResult::NeedsSaveAll;
+                    }
+                }
+                KeyCode::Esc => {
+                    haven_ui.confirmation = HavenConfirmation::None;
+                }
+                _ => {}
+            }
+            InputResult::Continue
+        }
         HavenConfirmation::None => {
             match key.code {
                 KeyCode::Up => {
@@ -338,6 +450,43 @@ fn handle_haven(
                         haven_ui.confirmation = HavenConfirmation::Build;
                     }
                 }
+                KeyCode::Char('w') | KeyCode::Char('W') => {
+                    if haven.room_tier(haven::HavenRoomId::Workshop) > 0 {
+                        haven_ui.workshop = WorkshopUiState::default();
+                        haven_ui.confirmation = HavenConfirmation::Workshop;
+                    }
+                }
+                KeyCode::Char('x') | KeyCode::Char('X') => {
+                    let message = match haven::write_build_order(haven) {
+                        Ok(path) => format!(
+                            "📋 Build order exported to {}: {}",
+                            path.display(),
+                            haven.export_build_order()
+                        ),
+                        Err(e) => format!("📋 Failed to export build order: {}", e),
+                    };
+                    state.combat_state.add_log_entry(message, false, true);
+                }
+                KeyCode::Char('i') | KeyCode::Char('I') => match haven::read_build_order() {
+                    Ok(order) if !order.is_empty() => {
+                        haven_ui.build_order = order;
+                        haven_ui.confirmation = HavenConfirmation::ImportBuildOrder;
+                    }
+                    Ok(_) => {
+                        state.combat_state.add_log_entry(
+                            format!("📋 {} is empty", haven::BUILD_ORDER_FILE),
+                            false,
+                            true,
+                        );
+                    }
+                    Err(e) => {
+                        state.combat_state.add_log_entry(
+                            format!("📋 Failed to read {}: {}", haven::BUILD_ORDER_FILE, e),
+                            false,
+                            true,
+                        );
+                    }
+                },
                 KeyCode::Esc => {
                     haven_ui.close();
                 }
@@ -348,6 +497,205 @@ fn handle_haven(
     }
 }
 
+/// Workshop panel: every `items::crafting` action on the selected equipped item.
+fn handle_workshop(
+    key: KeyEvent,
+    state: &mut GameState,
+    haven: &Haven,
+    haven_ui: &mut HavenUiState,
+) -> InputResult {
+    use crate::fishing::bait::BaitKind;
+    use crate::fishing::types::FishRarity;
+    use items::crafting;
+
+    let tier = haven.room_tier(haven::HavenRoomId::Workshop);
+    let workshop = &mut haven_ui.workshop;
+    let slot = items::EquipmentSlot::ALL[workshop.selected_slot];
+    let last_slot = items::EquipmentSlot::ALL.len() - 1;
+    let mut rng = rand::rng();
+
+    // Picking the slot to transmute into
+    if let Some(target) = workshop.transmute_target {
+        let result = match key.code {
+            KeyCode::Up => {
+                workshop.transmute_target = Some(target.saturating_sub(1));
+                return InputResult::Continue;
+            }
+            KeyCode::Down => {
+                workshop.transmute_target = Some((target + 1).min(last_slot));
+                return InputResult::Continue;
+            }
+            KeyCode::Enter => {
+                workshop.transmute_target = None;
+                let to = items::EquipmentSlot::ALL[target];
+                crafting::transmute_equipped(state, tier, slot, to, &mut rng)
+                    .map(|item| format!("Transmuted into {}", item.display_name))
+                    .map_err(|e| e.to_string())
+            }
+            KeyCode::Esc => {
+                workshop.transmute_target = None;
+                return InputResult::Continue;
+            }
+            _ => return InputResult::Continue,
+        };
+        return workshop_outcome(state, workshop, result);
+    }
+
+    let result = match key.code {
+        KeyCode::Up => {
+            workshop.selected_slot = workshop.selected_slot.saturating_sub(1);
+            return InputResult::Continue;
+        }
+        KeyCode::Down => {
+            workshop.selected_slot = (workshop.selected_slot + 1).min(last_slot);
+            return InputResult::Continue;
+        }
+        KeyCode::Left => {
+            workshop.selected_gem = workshop.selected_gem.saturating_sub(1);
+            return InputResult::Continue;
+        }
+        KeyCode::Right => {
+            workshop.selected_gem =
+                (workshop.selected_gem + 1).min(state.gems.len().saturating_sub(1));
+            return InputResult::Continue;
+        }
+        KeyCode::Esc => {
+            haven_ui.confirmation = HavenConfirmation::None;
+            return InputResult::Continue;
+        }
+        KeyCode::Char('t') | KeyCode::Char('T') => {
+            // Start on the next slot, since transmuting into the same one is refused
+            workshop.transmute_target = Some(if workshop.selected_slot == last_slot {
+                0
+            } else {
+                workshop.selected_slot + 1
+            });
+            return InputResult::Continue;
+        }
+        KeyCode::Char('g') | KeyCode::Char('G') => {
+            crafting::gamble_equipped_rarity(state, tier, slot, &mut rng)
+                .map(|outcome| {
+                    let rarity = state
+                        .equipment
+                        .get(slot)
+                        .as_ref()
+                        .map_or("", |item| item.rarity.name());
+                    match outcome {
+                        crafting::GambleOutcome::Upgraded => {
+                            format!("Gamble won: {} is now {}", slot.name(), rarity)
+                        }
+                        crafting::GambleOutcome::Unchanged => {
+                            format!("Gamble lost: {} is unchanged", slot.name())
+                        }
+                        crafting::GambleOutcome::Downgraded => {
+                            format!("Gamble lost: {} dropped to {}", slot.name(), rarity)
+                        }
+                    }
+                })
+                .map_err(|e| e.to_string())
+        }
+        KeyCode::Char('a') | KeyCode::Char('A') => {
+            crafting::transfer_affixes_equipped(state, tier, slot)
+                .map(|item| format!("Moved the old affixes onto {}", item.display_name))
+                .map_err(|e| e.to_string())
+        }
+        KeyCode::Char('k') | KeyCode::Char('K') => {
+            workshop.craft_kind = (workshop.craft_kind + 1) % items::GemKind::ALL.len();
+            return InputResult::Continue;
+        }
+        KeyCode::Char('c') | KeyCode::Char('C') => {
+            let kind = items::GemKind::ALL[workshop.craft_kind];
+            let gem_tier = tier.min(crate::core::constants::GEM_MAX_TIER);
+            crafting::craft_gem(state, tier, kind, gem_tier)
+                .map(|gem| format!("Crafted a {}", gem.name()))
+                .map_err(|e| e.to_string())
+        }
+        KeyCode::Char('v') | KeyCode::Char('V') => {
+            workshop.bait_kind = (workshop.bait_kind + 1) % BaitKind::ALL.len();
+            return InputResult::Continue;
+        }
+        KeyCode::Char('b') | KeyCode::Char('B') => {
+            crate::fishing::bait::craft_bait(state, BaitKind::ALL[workshop.bait_kind])
+                .map(|bait| {
+                    let casts = state.active_bait.map_or(0, |b| b.remaining_casts);
+                    format!("Crafted {} ({} casts on the hook)", bait.kind.name(), casts)
+                })
+                .map_err(|e| e.to_string())
+        }
+        KeyCode::Char('r') | KeyCode::Char('R') => {
+            workshop.rod_rarity = (workshop.rod_rarity + 1) % FishRarity::ALL.len();
+            return InputResult::Continue;
+        }
+        KeyCode::Char('f') | KeyCode::Char('F') => {
+            let rarity = FishRarity::ALL[workshop.rod_rarity];
+            crate::fishing::gear::craft_fishing_rod(state, rarity, &mut rng)
+                .map(|rod| format!("Crafted {} ({})", rod.name, rod.summary()))
+                .map_err(|e| e.to_string())
+        }
+        KeyCode::Char('s') | KeyCode::Char('S') => {
+            // Into the first empty socket (or one past the end, which reports capacity)
+            let socket = state.equipment.get(slot).as_ref().map_or(0, |item| {
+                item.sockets
+                    .iter()
+                    .position(Option::is_none)
+                    .unwrap_or(item.sockets.len())
+            });
+            let gem = state.gems.get(workshop.selected_gem).copied();
+            let result =
+                crafting::socket_gem_equipped(state, tier, slot, workshop.selected_gem, socket)
+                    .map(|()| {
+                        format!(
+                            "Socketed {} into {}",
+                            gem.map(|g| g.name()).unwrap_or_default(),
+                            slot.name()
+                        )
+                    })
+                    .map_err(|e| e.to_string());
+            // The socketed gem left the pouch; keep the selection on a gem
+            workshop.selected_gem = workshop
+                .selected_gem
+                .min(state.gems.len().saturating_sub(1));
+            result
+        }
+        KeyCode::Char('u') | KeyCode::Char('U') => {
+            // The last filled socket
+            let socket = state
+                .equipment
+                .get(slot)
+                .as_ref()
+                .and_then(|item| item.sockets.iter().rposition(Option::is_some))
+                .unwrap_or(0);
+            crafting::unsocket_gem_equipped(state, tier, slot, socket)
+                .map(|gem| format!("Took {} out of {}", gem.name(), slot.name()))
+                .map_err(|e| e.to_string())
+        }
+        _ => return InputResult::Continue,
+    };
+    workshop_outcome(state, workshop, result)
+}
+
+/// Shows a Workshop action's outcome on the panel; successful ones are also
+/// logged and saved.
+fn workshop_outcome(
+    state: &mut GameState,
+    workshop: &mut WorkshopUiState,
+    result: Result<String, String>,
+) -> InputResult {
+    match result {
+        Ok(message) => {
+            state
+                .combat_state
+                .add_log_entry(format!("\u{1f528} {}", message), false, true);
+            workshop.status = Some(message);
+            InputResult::NeedsSave
+        }
+        Err(reason) => {
+            workshop.status = Some(format!("Can't do that: {}", reason));
+            InputResult::Continue
+        }
+    }
+}
+
 fn handle_vault_selection(
     key: KeyEvent,
     state: &mut GameState,
@@ -400,6 +748,7 @@ fn handle_vault_selection(
                     false,
                     true,
                 );
+                refund_banked_xp(state);
                 return InputResult::NeedsSave;
             }
             KeyCode::Esc => {
@@ -411,6 +760,108 @@ fn handle_vault_selection(
     InputResult::Continue
 }
 
+/// Refunds XP banked by the prestige that just happened and logs it.
+fn refund_banked_xp(state: &mut GameState) {
+    let xp = apply_banked_xp(state);
+    if xp > 0 {
+        state.combat_state.add_log_entry(
+            format!(
+                "\u{1f4b0} {} banked XP carried over (now level {})",
+                xp, state.character_level
+            ),
+            false,
+            true,
+        );
+    }
+}
+
+fn handle_hardcore_fishing_confirm(
+    key: KeyEvent,
+    state: &mut GameState,
+    overlay: &mut GameOverlay,
+) -> InputResult {
+    match key.code {
+        KeyCode::Char('y') | KeyCode::Char('Y') => {
+            *overlay = GameOverlay::None;
+            state.fishing.hardcore = true;
+            state.combat_state.add_log_entry(
+                "\u{1f3a3} Hardcore fishing ON: an escaped Leviathan will cost a fishing rank"
+                    .to_string(),
+                false,
+                true,
+            );
+            InputResult::NeedsSave
+        }
+        KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+            *overlay = GameOverlay::None;
+            InputResult::Continue
+        }
+        _ => InputResult::Continue,
+    }
+}
+
+fn handle_loot_filter_confirm(
+    key: KeyEvent,
+    state: &mut GameState,
+    overlay: &mut GameOverlay,
+) -> InputResult {
+    match key.code {
+        KeyCode::Char('y') | KeyCode::Char('Y') => {
+            *overlay = GameOverlay::None;
+            cycle_loot_filter(state);
+            InputResult::NeedsSave
+        }
+        KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+            *overlay = GameOverlay::None;
+            InputResult::Continue
+        }
+        _ => InputResult::Continue,
+    }
+}
+
+/// Moves the loot filter to its next setting and logs it.
+fn cycle_loot_filter(state: &mut GameState) {
+    state.loot_filter_min_rarity =
+        crate::items::salvage::next_loot_filter(state.loot_filter_min_rarity);
+    let message = match state.loot_filter_min_rarity {
+        Some(rarity) => format!(
+            "\u{267b} Loot filter: salvaging drops below {} ({} materials)",
+            rarity.name(),
+            state.salvage_materials
+        ),
+        None => "\u{267b} Loot filter OFF: keeping all drops".to_string(),
+    };
+    state.combat_state.add_log_entry(message, false, true);
+}
+
+fn handle_auto_challenge_confirm(key: KeyEvent, overlay: &mut GameOverlay) -> InputResult {
+    use crate::challenges::menu::{next_auto_challenge, previous_auto_challenge};
+
+    let GameOverlay::AutoChallengeConfirm { choice } = overlay else {
+        return InputResult::Continue;
+    };
+    match key.code {
+        KeyCode::Left | KeyCode::Up => {
+            *choice = previous_auto_challenge(*choice);
+            InputResult::Continue
+        }
+        KeyCode::Right | KeyCode::Down => {
+            *choice = next_auto_challenge(*choice);
+            InputResult::Continue
+        }
+        KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => {
+            let choice = *choice;
+            *overlay = GameOverlay::None;
+            InputResult::SetAutoChallenge(choice)
+        }
+        KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+            *overlay = GameOverlay::None;
+            InputResult::Continue
+        }
+        _ => InputResult::Continue,
+    }
+}
+
 fn handle_prestige_confirm(
     key: KeyEvent,
     state: &mut GameState,
@@ -435,6 +886,7 @@ fn handle_prestige_confirm(
                     false,
                     true,
                 );
+                refund_banked_xp(state);
                 return InputResult::NeedsSave;
             }
         }
@@ -465,6 +917,9 @@ fn handle_debug_menu(
             if msg == "Haven discovered!" {
                 *overlay = GameOverlay::HavenDiscovery;
             }
+            if let Some(report) = debug_menu.simulated_offline.take() {
+                *overlay = GameOverlay::OfflineWelcome { report };
+            }
         }
         KeyCode::Esc => debug_menu.close(),
         _ => {}
@@ -473,11 +928,18 @@ fn handle_debug_menu(
 }
 
 fn handle_minigame(key: KeyEvent, state: &mut GameState) -> InputResult {
+    let today = chrono::Local::now().date_naive();
+    if matches!(key.code, KeyCode::Char('w') | KeyCode::Char('W'))
+        && crate::challenges::wager_rematch_difficulty(state).is_some()
+    {
+        state.last_minigame_win = crate::challenges::start_wager(state, today);
+        return InputResult::Continue;
+    }
     if let Some(ref mut minigame) = state.active_minigame {
         match minigame {
             ActiveMinigame::Rune(rune_game) => {
                 if rune_game.game_result.is_some() {
-                    state.last_minigame_win = apply_rune_result(state);
+                    state.last_minigame_win = apply_minigame_result(state, today);
                     return InputResult::Continue;
                 }
                 let input = match key.code {
@@ -495,7 +957,7 @@ fn handle_minigame(key: KeyEvent, state: &mut GameState) -> InputResult {
             }
             ActiveMinigame::Minesweeper(minesweeper_game) => {
                 if minesweeper_game.game_result.is_some() {
-                    state.last_minigame_win = apply_minesweeper_result(state);
+                    state.last_minigame_win = apply_minigame_result(state, today);
                     return InputResult::Continue;
                 }
                 let input = match key.code {
@@ -505,6 +967,7 @@ fn handle_minigame(key: KeyEvent, state: &mut GameState) -> InputResult {
                     KeyCode::Right => MinesweeperInput::Right,
                     KeyCode::Enter => MinesweeperInput::Reveal,
                     KeyCode::Char('f') | KeyCode::Char('F') => MinesweeperInput::ToggleFlag,
+                    KeyCode::Char('h') | KeyCode::Char('H') => MinesweeperInput::Hint,
                     KeyCode::Esc => MinesweeperInput::Forfeit,
                     _ => MinesweeperInput::Other,
                 };
@@ -513,7 +976,7 @@ fn handle_minigame(key: KeyEvent, state: &mut GameState) -> InputResult {
             }
             ActiveMinigame::Gomoku(gomoku_game) => {
                 if gomoku_game.game_result.is_some() {
-                    state.last_minigame_win = apply_gomoku_result(state);
+                    state.last_minigame_win = apply_minigame_result(state, today);
                     return InputResult::Continue;
                 }
                 let input = match key.code {
@@ -529,7 +992,7 @@ fn handle_minigame(key: KeyEvent, state: &mut GameState) -> InputResult {
             }
             ActiveMinigame::Chess(chess_game) => {
                 if chess_game.game_result.is_some() {
-                    state.last_minigame_win = apply_chess_result(state);
+                    state.last_minigame_win = apply_minigame_result(state, today);
                     return InputResult::Continue;
                 }
                 let input = match key.code {
@@ -545,7 +1008,7 @@ fn handle_minigame(key: KeyEvent, state: &mut GameState) -> InputResult {
             }
             ActiveMinigame::Morris(morris_game) => {
                 if morris_game.game_result.is_some() {
-                    state.last_minigame_win = apply_morris_result(state);
+                    state.last_minigame_win = apply_minigame_result(state, today);
                     return InputResult::Continue;
                 }
                 let input = match key.code {
@@ -561,7 +1024,7 @@ fn handle_minigame(key: KeyEvent, state: &mut GameState) -> InputResult {
             }
             ActiveMinigame::Go(go_game) => {
                 if go_game.game_result.is_some() {
-                    state.last_minigame_win = apply_go_result(state);
+                    state.last_minigame_win = apply_minigame_result(state, today);
                     return InputResult::Continue;
                 }
                 let input = match key.code {
@@ -578,7 +1041,7 @@ fn handle_minigame(key: KeyEvent, state: &mut GameState) -> InputResult {
             }
             ActiveMinigame::FlappyBird(flappy_game) => {
                 if flappy_game.game_result.is_some() {
-                    state.last_minigame_win = apply_flappy_result(state);
+                    state.last_minigame_win = apply_minigame_result(state, today);
                     return InputResult::Continue;
                 }
                 let input = match key.code {
@@ -590,7 +1053,7 @@ fn handle_minigame(key: KeyEvent, state: &mut GameState) -> InputResult {
             }
             ActiveMinigame::Jezzball(jezzball_game) => {
                 if jezzball_game.game_result.is_some() {
-                    state.last_minigame_win = apply_jezzball_result(state);
+                    state.last_minigame_win = apply_minigame_result(state, today);
                     return InputResult::Continue;
                 }
                 let input = match key.code {
@@ -607,7 +1070,7 @@ fn handle_minigame(key: KeyEvent, state: &mut GameState) -> InputResult {
             }
             ActiveMinigame::Snake(snake_game) => {
                 if snake_game.game_result.is_some() {
-                    state.last_minigame_win = apply_snake_result(state);
+                    state.last_minigame_win = apply_minigame_result(state, today);
                     return InputResult::Continue;
                 }
                 let input = match key.code {
@@ -630,8 +1093,11 @@ fn handle_challenge_menu(key: KeyEvent, state: &mut GameState) -> InputResult {
     let input = match key.code {
         KeyCode::Up => MenuInput::Up,
         KeyCode::Down => MenuInput::Down,
+        KeyCode::Left => MenuInput::Left,
+        KeyCode::Right => MenuInput::Right,
         KeyCode::Enter => MenuInput::Select,
         KeyCode::Char('d') | KeyCode::Char('D') => MenuInput::Decline,
+        KeyCode::Char('p') | KeyCode::Char('P') => MenuInput::Practice,
         KeyCode::Esc | KeyCode::Tab => MenuInput::Cancel,
         _ => MenuInput::Other,
     };
@@ -672,6 +1138,170 @@ fn handle_base_game(
             }
             InputResult::Continue
         }
+        KeyCode::Char('e') | KeyCode::Char('E') => {
+            let tier = ExpeditionTier::best_for_prestige(state.prestige_rank);
+            if start_expedition(state, tier, chrono::Utc::now().timestamp()) {
+                state.combat_state.add_log_entry(
+                    format!(
+                        "\u{26fa} {} dispatched! Returns in {}h (resolves on next login)",
+                        tier.name(),
+                        tier.duration_seconds() / 3600
+                    ),
+                    false,
+                    true,
+                );
+            }
+            InputResult::Continue
+        }
+        KeyCode::Char('v') | KeyCode::Char('V') => InputResult::CycleEffects,
+        KeyCode::Char('c') | KeyCode::Char('C') => InputResult::OpenAutoChallenge,
+        KeyCode::Char('n') | KeyCode::Char('N') => InputResult::CycleNumberFormat,
+        KeyCode::Char('m') | KeyCode::Char('M') => InputResult::CycleLogFilter,
+        KeyCode::Char('b') | KeyCode::Char('B') => InputResult::CycleHpDisplay,
+        KeyCode::Char('r') | KeyCode::Char('R') => {
+            for milestone in crate::core::progression::next_milestones(state) {
+                state.combat_state.add_log_entry(
+                    format!("\u{1f5fa} Next: {}", milestone.description),
+                    false,
+                    true,
+                );
+            }
+            InputResult::Continue
+        }
+        KeyCode::Char('f') | KeyCode::Char('F') => {
+            if !state.fishing.hardcore {
+                *overlay = GameOverlay::HardcoreFishingConfirm;
+                return InputResult::Continue;
+            }
+            state.fishing.hardcore = false;
+            state.combat_state.add_log_entry(
+                "\u{1f3a3} Hardcore fishing OFF".to_string(),
+                false,
+                true,
+            );
+            InputResult::NeedsSave
+        }
+        KeyCode::Char('k') | KeyCode::Char('K') => {
+            state.fishing.auto_fishing = !state.fishing.auto_fishing;
+            let message = if state.fishing.auto_fishing {
+                "\u{1f3a3} Fishing autopilot ON: recast at your last spot when a session ends"
+            } else {
+                "\u{1f3a3} Fishing autopilot OFF"
+            };
+            state
+                .combat_state
+                .add_log_entry(message.to_string(), false, true);
+            InputResult::NeedsSave
+        }
+        KeyCode::Char('l') | KeyCode::Char('L') => {
+            if state.loot_filter_min_rarity.is_none() {
+                *overlay = GameOverlay::LootFilterConfirm;
+                return InputResult::Continue;
+            }
+            cycle_loot_filter(state);
+            InputResult::NeedsSave
+        }
+        KeyCode::Char('i') | KeyCode::Char('I') => {
+            state.verbose_loot = !state.verbose_loot;
+            let message = if state.verbose_loot {
+                "\u{1f50d} Loot details: verbose (affix names and stat contributions)"
+            } else {
+                "\u{1f50d} Loot details: compact"
+            };
+            state
+                .combat_state
+                .add_log_entry(message.to_string(), false, true);
+            InputResult::NeedsSave
+        }
+        KeyCode::Char('j') | KeyCode::Char('J') => {
+            state.verbose_combat = !state.verbose_combat;
+            let message = if state.verbose_combat {
+                "\u{1f50e} Damage breakdown ON: log base damage, defense and crits per hit"
+            } else {
+                "\u{1f50e} Damage breakdown OFF"
+            };
+            state
+                .combat_state
+                .add_log_entry(message.to_string(), false, true);
+            InputResult::NeedsSave
+        }
+        KeyCode::Char('g') | KeyCode::Char('G') => {
+            let message = if state.adaptive_intensity.toggle() {
+                "\u{1f525} Adaptive intensity ON: enemies toughen while you kill quickly"
+            } else {
+                "\u{1f525} Adaptive intensity OFF"
+            };
+            state
+                .combat_state
+                .add_log_entry(message.to_string(), false, true);
+            InputResult::NeedsSave
+        }
+        KeyCode::Char('o') | KeyCode::Char('O') => {
+            let message = match state.quick_swap_gear() {
+                Some(slot) => {
+                    let name = state
+                        .equipment
+                        .get(slot)
+                        .as_ref()
+                        .map_or("nothing", |item| item.display_name.as_str());
+                    format!("\u{1f501} Swapped back to {}", name)
+                }
+                None => "\u{1f501} No replaced gear to swap back to".to_string(),
+            };
+            state.combat_state.add_log_entry(message, false, true);
+            InputResult::NeedsSave
+        }
+        KeyCode::Char('t') | KeyCode::Char('T') => {
+            state.combat_stance = state.combat_stance.next();
+            let message = format!(
+                "\u{1f93a} Combat stance: {} ({:+.0}% damage, {:+.0}% defense)",
+                state.combat_stance.name(),
+                (state.combat_stance.damage_multiplier() - 1.0) * 100.0,
+                (state.combat_stance.defense_multiplier() - 1.0) * 100.0
+            );
+            state.combat_state.add_log_entry(message, false, true);
+            InputResult::NeedsSave
+        }
+        KeyCode::Char('s') | KeyCode::Char('S') => {
+            if let Some(dungeon) = &mut state.active_dungeon {
+                dungeon.strategy = dungeon.strategy.next();
+                let message = format!("\u{1f9ed} Dungeon strategy: {}", dungeon.strategy.name());
+                state.combat_state.add_log_entry(message, false, true);
+                return InputResult::NeedsSave;
+            }
+            InputResult::Continue
+        }
+        KeyCode::Up | KeyCode::Right | KeyCode::Down | KeyCode::Left => {
+            if let Some(dungeon) = &mut state.active_dungeon {
+                let (dir, name) = match key.code {
+                    KeyCode::Up => (DIR_UP, "north"),
+                    KeyCode::Right => (DIR_RIGHT, "east"),
+                    KeyCode::Down => (DIR_DOWN, "south"),
+                    _ => (DIR_LEFT, "west"),
+                };
+                let message = if set_target_direction(dungeon, dir) {
+                    format!("\u{1f9ed} Heading {}", name)
+                } else {
+                    format!("\u{1f9f1} No way {}", name)
+                };
+                state.combat_state.add_log_entry(message, false, true);
+            }
+            InputResult::Continue
+        }
+        KeyCode::Char('?') => {
+            *overlay = GameOverlay::KeyHelp;
+            InputResult::Continue
+        }
+        KeyCode::Char('z') | KeyCode::Char('Z') => {
+            // Open on the zone the player is in
+            *overlay = GameOverlay::ZoneCodex {
+                selected: crate::zones::get_all_zones()
+                    .iter()
+                    .position(|zone| zone.id == state.zone_progression.current_zone_id)
+                    .unwrap_or(0),
+            };
+            InputResult::Continue
+        }
         KeyCode::Char('a') | KeyCode::Char('A') => {
             // Clear pending notifications when opening achievements
             achievements.clear_pending_notifications();
@@ -0,0 +1,816 @@
+//! Workshop crafting: spending salvage materials to rework equipped gear.
+//!
+//! Every action here is offered on the Workshop panel of the Haven screen.
+
+use super::generation::{roll_affix, transmute_slot};
+use super::salvage::salvage_value;
+use super::types::{EquipmentSlot, Gem, GemKind, Item, Rarity, SocketError};
+use crate::core::constants::GEM_MAX_TIER;
+use crate::core::game_state::GameState;
+use rand::{Rng, RngExt};
+use std::fmt;
+
+/// Transmuting costs this many times the item's salvage value.
+const TRANSMUTE_COST_MULTIPLIER: u64 = 10;
+
+/// Transferring affixes costs this many times the source's salvage value.
+const AFFIX_TRANSFER_COST_MULTIPLIER: u64 = 5;
+
+/// A rarity gamble costs this many times the item's salvage value.
+const RARITY_GAMBLE_COST_MULTIPLIER: u64 = 8;
+/// Chance a rarity gamble promotes the item one tier.
+const RARITY_GAMBLE_SUCCESS_CHANCE: f64 = 0.35;
+/// Chance a rarity gamble demotes the item one tier (otherwise unchanged).
+const RARITY_GAMBLE_DOWNGRADE_CHANCE: f64 = 0.15;
+
+/// Crafting a gem costs this many materials per tier.
+const GEM_CRAFT_COST_PER_TIER: u64 = 15;
+
+/// Why a transmute was refused.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransmuteError {
+    /// The Workshop hasn't been built in the Haven
+    WorkshopNotBuilt,
+    /// Source and target are the same slot
+    SameSlot,
+    /// Nothing equipped in the source slot
+    EmptySlot,
+    NotEnoughMaterials {
+        needed: u64,
+        have: u64,
+    },
+}
+
+/// Salvage materials needed to transmute an item of this rarity.
+pub fn transmute_cost(rarity: Rarity) -> u64 {
+    salvage_value(rarity) * TRANSMUTE_COST_MULTIPLIER
+}
+
+/// Transmutes the item in `from` into a `to` item and equips it there,
+/// replacing whatever was in `to` and leaving `from` empty.
+/// Requires the Workshop (`workshop_tier >= 1`) and enough salvage materials.
+pub fn transmute_equipped(
+    state: &mut GameState,
+    workshop_tier: u8,
+    from: EquipmentSlot,
+    to: EquipmentSlot,
+    rng: &mut impl Rng,
+) -> Result<Item, TransmuteError> {
+    if workshop_tier == 0 {
+        return Err(TransmuteError::WorkshopNotBuilt);
+    }
+    if from == to {
+        return Err(TransmuteError::SameSlot);
+    }
+    let source = state
+        .equipment
+        .get(from)
+        .as_ref()
+        .ok_or(TransmuteError::EmptySlot)?;
+
+    let needed = transmute_cost(source.rarity);
+    if state.salvage_materials < needed {
+        return Err(TransmuteError::NotEnoughMaterials {
+            needed,
+            have: state.salvage_materials,
+        });
+    }
+
+    let transmuted = transmute_slot(source, to, rng);
+    state.salvage_materials -= needed;
+    state.equipment.set(from, None);
+    state.equipment.set(to, Some(transmuted.clone()));
+    Ok(transmuted)
+}
+
+/// Why an affix transfer was refused.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AffixTransferError {
+    /// The Workshop hasn't been built in the Haven
+    WorkshopNotBuilt,
+    /// Source and target are for different slots
+    SlotMismatch,
+    /// Nothing equipped in the slot to move affixes onto
+    EmptySlot,
+    /// The slot has no replaced item (see `EquipmentHistory`) to take affixes from
+    NoReplacedItem,
+    /// The new base has a lower item level than the source
+    LowerItemLevel {
+        source: u32,
+        target: u32,
+    },
+    NotEnoughMaterials {
+        needed: u64,
+        have: u64,
+    },
+}
+
+/// Salvage materials needed to transfer affixes off an item of this rarity.
+pub fn affix_transfer_cost(rarity: Rarity) -> u64 {
+    salvage_value(rarity) * AFFIX_TRANSFER_COST_MULTIPLIER
+}
+
+/// Replaces `to`'s affixes with copies of `from`'s. Both must be for the
+/// same slot; everything else about `to` (base, ilvl, attributes) is kept.
+pub fn transfer_affixes(from: &Item, to: &mut Item) -> Result<(), AffixTransferError> {
+    if from.slot != to.slot {
+        return Err(AffixTransferError::SlotMismatch);
+    }
+    to.affixes = from.affixes.clone();
+    Ok(())
+}
+
+/// Moves the affixes of the item the `slot` piece replaced (kept in
+/// `GameState::equipment_history`) onto the equipped one, consuming the
+/// replaced item. This keeps a favourite affix set when a higher-ilvl base
+/// gets auto-equipped over it.
+/// Requires the Workshop (`workshop_tier >= 1`), an equipped item of at least
+/// the replaced item's ilvl, and `affix_transfer_cost(replaced rarity)`
+/// salvage materials.
+pub fn transfer_affixes_equipped(
+    state: &mut GameState,
+    workshop_tier: u8,
+    slot: EquipmentSlot,
+) -> Result<Item, AffixTransferError> {
+    if workshop_tier == 0 {
+        return Err(AffixTransferError::WorkshopNotBuilt);
+    }
+    let mut target = state
+        .equipment
+        .get(slot)
+        .clone()
+        .ok_or(AffixTransferError::EmptySlot)?;
+    let source = state
+        .equipment_history
+        .previous
+        .get(slot)
+        .as_ref()
+        .ok_or(AffixTransferError::NoReplacedItem)?;
+
+    if target.ilvl < source.ilvl {
+        return Err(AffixTransferError::LowerItemLevel {
+            source: source.ilvl,
+            target: target.ilvl,
+        });
+    }
+    let needed = affix_transfer_cost(source.rarity);
+    if state.salvage_materials < needed {
+        return Err(AffixTransferError::NotEnoughMaterials {
+            needed,
+            have: state.salvage_materials,
+        });
+    }
+
+    transfer_affixes(source, &mut target)?;
+    state.salvage_materials -= needed;
+    state.equipment_history.previous.set(slot, None);
+    if state.equipment_history.last_replaced == Some(slot) {
+        state.equipment_history.last_replaced = None;
+    }
+    state.equipment.set(slot, Some(target.clone()));
+    Ok(target)
+}
+
+/// What a rarity gamble did to the item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GambleOutcome {
+    /// One tier up, with one new affix
+    Upgraded,
+    Unchanged,
+    /// One tier down, losing its last affix (all of them at Common)
+    Downgraded,
+}
+
+/// Why a rarity gamble was refused.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GambleError {
+    /// The Workshop hasn't been built in the Haven
+    WorkshopNotBuilt,
+    /// Nothing equipped in the slot
+    EmptySlot,
+    /// Legendary items can't go any higher
+    MaxRarity,
+    NotEnoughMaterials {
+        needed: u64,
+        have: u64,
+    },
+}
+
+/// Salvage materials needed to gamble on an item of this rarity.
+pub fn rarity_gamble_cost(rarity: Rarity) -> u64 {
+    salvage_value(rarity) * RARITY_GAMBLE_COST_MULTIPLIER
+}
+
+fn rarity_above(rarity: Rarity) -> Option<Rarity> {
+    match rarity {
+        Rarity::Common => Some(Rarity::Magic),
+        Rarity::Magic => Some(Rarity::Rare),
+        Rarity::Rare => Some(Rarity::Epic),
+        Rarity::Epic => Some(Rarity::Legendary),
+        Rarity::Legendary => None,
+    }
+}
+
+fn rarity_below(rarity: Rarity) -> Option<Rarity> {
+    match rarity {
+        Rarity::Common => None,
+        Rarity::Magic => Some(Rarity::Common),
+        Rarity::Rare => Some(Rarity::Magic),
+        Rarity::Epic => Some(Rarity::Rare),
+        Rarity::Legendary => Some(Rarity::Epic),
+    }
+}
+
+/// Tries to promote `item` one rarity tier, adding an affix of the new
+/// rarity. Fails `RARITY_GAMBLE_DOWNGRADE_CHANCE` of the time by dropping a
+/// tier, otherwise leaves it alone. A Legendary item is never changed.
+pub fn gamble_rarity(item: &mut Item, rng: &mut impl Rng) -> GambleOutcome {
+    let Some(higher) = rarity_above(item.rarity) else {
+        return GambleOutcome::Unchanged;
+    };
+    let roll = rng.random::<f64>();
+    if roll < RARITY_GAMBLE_SUCCESS_CHANCE {
+        item.rarity = higher;
+        item.affixes.push(roll_affix(higher, item.ilvl, rng));
+        GambleOutcome::Upgraded
+    } else if roll < RARITY_GAMBLE_SUCCESS_CHANCE + RARITY_GAMBLE_DOWNGRADE_CHANCE {
+        let Some(lower) = rarity_below(item.rarity) else {
+            return GambleOutcome::Unchanged;
+        };
+        item.rarity = lower;
+        if lower == Rarity::Common {
+            item.affixes.clear();
+        } else {
+            item.affixes.pop();
+        }
+        GambleOutcome::Downgraded
+    } else {
+        GambleOutcome::Unchanged
+    }
+}
+
+/// Gambles on the rarity of the item equipped in `slot`.
+/// Requires the Workshop (`workshop_tier >= 1`) and
+/// `rarity_gamble_cost(rarity)` salvage materials, which are spent whatever
+/// the outcome.
+pub fn gamble_equipped_rarity(
+    state: &mut GameState,
+    workshop_tier: u8,
+    slot: EquipmentSlot,
+    rng: &mut impl Rng,
+) -> Result<GambleOutcome, GambleError> {
+    if workshop_tier == 0 {
+        return Err(GambleError::WorkshopNotBuilt);
+    }
+    let item = state
+        .equipment
+        .get(slot)
+        .as_ref()
+        .ok_or(GambleError::EmptySlot)?;
+    if item.rarity == Rarity::Legendary {
+        return Err(GambleError::MaxRarity);
+    }
+    let needed = rarity_gamble_cost(item.rarity);
+    if state.salvage_materials < needed {
+        return Err(GambleError::NotEnoughMaterials {
+            needed,
+            have: state.salvage_materials,
+        });
+    }
+
+    let mut item = item.clone();
+    let outcome = gamble_rarity(&mut item, rng);
+    state.salvage_materials -= needed;
+    state.equipment.set(slot, Some(item));
+    Ok(outcome)
+}
+
+/// Why a gem couldn't be crafted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GemCraftError {
+    /// The Workshop hasn't been built in the Haven
+    WorkshopNotBuilt,
+    /// Tier 0, above `GEM_MAX_TIER`, or above the Workshop's tier
+    TierUnavailable,
+    NotEnoughMaterials {
+        needed: u64,
+        have: u64,
+    },
+}
+
+/// Salvage materials needed to craft a gem of this tier.
+pub fn gem_craft_cost(tier: u8) -> u64 {
+    GEM_CRAFT_COST_PER_TIER * tier as u64
+}
+
+/// Crafts a `kind` gem of `tier` into `GameState::gems`.
+/// Requires the Workshop at `tier` or higher and `gem_craft_cost(tier)`
+/// salvage materials.
+pub fn craft_gem(
+    state: &mut GameState,
+    workshop_tier: u8,
+    kind: GemKind,
+    tier: u8,
+) -> Result<Gem, GemCraftError> {
+    if workshop_tier == 0 {
+        return Err(GemCraftError::WorkshopNotBuilt);
+    }
+    if tier == 0 || tier > GEM_MAX_TIER || tier > workshop_tier {
+        return Err(GemCraftError::TierUnavailable);
+    }
+    let needed = gem_craft_cost(tier);
+    if state.salvage_materials < needed {
+        return Err(GemCraftError::NotEnoughMaterials {
+            needed,
+            have: state.salvage_materials,
+        });
+    }
+
+    state.salvage_materials -= needed;
+    let gem = Gem::new(kind, tier);
+    state.gems.push(gem);
+    Ok(gem)
+}
+
+/// Why a gem couldn't be socketed or unsocketed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SocketActionError {
+    /// The Workshop hasn't been built in the Haven
+    WorkshopNotBuilt,
+    /// Nothing equipped in the slot
+    EmptySlot,
+    /// No gem at that index in `GameState::gems`
+    NoSuchGem,
+    Socket(SocketError),
+}
+
+/// Moves `GameState::gems[gem_index]` into `socket` of the item equipped in
+/// `slot`. Requires the Workshop (`workshop_tier >= 1`); socketing is free.
+pub fn socket_gem_equipped(
+    state: &mut GameState,
+    workshop_tier: u8,
+    slot: EquipmentSlot,
+    gem_index: usize,
+    socket: usize,
+) -> Result<(), SocketActionError> {
+    if workshop_tier == 0 {
+        return Err(SocketActionError::WorkshopNotBuilt);
+    }
+    let gem = *state
+        .gems
+        .get(gem_index)
+        .ok_or(SocketActionError::NoSuchGem)?;
+    let mut item = state
+        .equipment
+        .get(slot)
+        .clone()
+        .ok_or(SocketActionError::EmptySlot)?;
+
+    item.socket_gem(socket, gem)
+        .map_err(SocketActionError::Socket)?;
+    state.gems.remove(gem_index);
+    state.equipment.set(slot, Some(item));
+    Ok(())
+}
+
+/// Takes the gem out of `socket` of the item equipped in `slot` and returns
+/// it to `GameState::gems`. Requires the Workshop (`workshop_tier >= 1`).
+pub fn unsocket_gem_equipped(
+    state: &mut GameState,
+    workshop_tier: u8,
+    slot: EquipmentSlot,
+    socket: usize,
+) -> Result<Gem, SocketActionError> {
+    if workshop_tier == 0 {
+        return Err(SocketActionError::WorkshopNotBuilt);
+    }
+    let mut item = state
+        .equipment
+        .get(slot)
+        .clone()
+        .ok_or(SocketActionError::EmptySlot)?;
+
+    let gem = item
+        .unsocket_gem(socket)
+        .map_err(SocketActionError::Socket)?;
+    state.gems.push(gem);
+    state.equipment.set(slot, Some(item));
+    Ok(gem)
+}
+
+impl fmt::Display for TransmuteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TransmuteError::WorkshopNotBuilt => write!(f, "the Workshop isn't built"),
+            TransmuteError::SameSlot => write!(f, "pick a different slot to transmute into"),
+            TransmuteError::EmptySlot => write!(f, "nothing equipped in that slot"),
+            TransmuteError::NotEnoughMaterials { needed, have } => {
+                write!(f, "needs {} materials (you have {})", needed, have)
+            }
+        }
+    }
+}
+
+impl fmt::Display for AffixTransferError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AffixTransferError::WorkshopNotBuilt => write!(f, "the Workshop isn't built"),
+            AffixTransferError::SlotMismatch => write!(f, "the items are for different slots"),
+            AffixTransferError::EmptySlot => write!(f, "nothing equipped in that slot"),
+            AffixTransferError::NoReplacedItem => {
+                write!(f, "no replaced item in that slot to take affixes from")
+            }
+            AffixTransferError::LowerItemLevel { source, target } => write!(
+                f,
+                "the equipped item is ilvl {}, below the replaced ilvl {}",
+                target, source
+            ),
+            AffixTransferError::NotEnoughMaterials { needed, have } => {
+                write!(f, "needs {} materials (you have {})", needed, have)
+            }
+        }
+    }
+}
+
+impl fmt::Display for GambleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GambleError::WorkshopNotBuilt => write!(f, "the Workshop isn't built"),
+            GambleError::EmptySlot => write!(f, "nothing equipped in that slot"),
+            GambleError::MaxRarity => write!(f, "Legendary items can't go any higher"),
+            GambleError::NotEnoughMaterials { needed, have } => {
+                write!(f, "needs {} materials (you have {})", needed, have)
+            }
+        }
+    }
+}
+
+impl fmt::Display for GemCraftError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GemCraftError::WorkshopNotBuilt => write!(f, "the Workshop isn't built"),
+            GemCraftError::TierUnavailable => {
+                write!(f, "that gem tier needs a higher Workshop tier")
+            }
+            GemCraftError::NotEnoughMaterials { needed, have } => {
+                write!(f, "needs {} materials (you have {})", needed, have)
+            }
+        }
+    }
+}
+
+impl fmt::Display for SocketActionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SocketActionError::WorkshopNotBuilt => write!(f, "the Workshop isn't built"),
+            SocketActionError::EmptySlot => write!(f, "nothing equipped in that slot"),
+            SocketActionError::NoSuchGem => write!(f, "no gem selected"),
+            SocketActionError::Socket(SocketError::NoSuchSocket { capacity, .. }) => {
+                write!(f, "the item has only {} socket(s)", capacity)
+            }
+            SocketActionError::Socket(SocketError::Occupied) => {
+                write!(f, "that socket is already filled")
+            }
+            SocketActionError::Socket(SocketError::Empty) => write!(f, "that socket is empty"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::items::generation::generate_item;
+
+    fn state_with_ring(materials: u64) -> GameState {
+        let mut state = GameState::new("Crafter".to_string(), 0);
+        state.equipment.ring = Some(generate_item(EquipmentSlot::Ring, Rarity::Rare, 50));
+        state.salvage_materials = materials;
+        state
+    }
+
+    #[test]
+    fn test_transmute_moves_item_and_spends_materials() {
+        let mut state = state_with_ring(1000);
+        let mut rng = rand::rng();
+
+        let item = transmute_equipped(
+            &mut state,
+            1,
+            EquipmentSlot::Ring,
+            EquipmentSlot::Weapon,
+            &mut rng,
+        )
+        .unwrap();
+
+        assert_eq!(item.slot, EquipmentSlot::Weapon);
+        assert!(state.equipment.ring.is_none());
+        assert_eq!(state.equipment.weapon.as_ref(), Some(&item));
+        assert_eq!(state.salvage_materials, 1000 - transmute_cost(Rarity::Rare));
+    }
+
+    #[test]
+    fn test_transmute_requires_workshop() {
+        let mut state = state_with_ring(1000);
+        let result = transmute_equipped(
+            &mut state,
+            0,
+            EquipmentSlot::Ring,
+            EquipmentSlot::Weapon,
+            &mut rand::rng(),
+        );
+        assert_eq!(result, Err(TransmuteError::WorkshopNotBuilt));
+        assert!(state.equipment.ring.is_some());
+    }
+
+    #[test]
+    fn test_transmute_requires_materials() {
+        let mut state = state_with_ring(5);
+        let result = transmute_equipped(
+            &mut state,
+            1,
+            EquipmentSlot::Ring,
+            EquipmentSlot::Weapon,
+            &mut rand::rng(),
+        );
+        assert_eq!(
+            result,
+            Err(TransmuteError::NotEnoughMaterials {
+                needed: transmute_cost(Rarity::Rare),
+                have: 5,
+            })
+        );
+        assert_eq!(state.salvage_materials, 5);
+        assert!(state.equipment.weapon.is_none());
+    }
+
+    #[test]
+    fn test_transmute_rejects_empty_and_same_slot() {
+        let mut state = state_with_ring(1000);
+        let mut rng = rand::rng();
+        assert_eq!(
+            transmute_equipped(
+                &mut state,
+                1,
+                EquipmentSlot::Boots,
+                EquipmentSlot::Weapon,
+                &mut rng
+            ),
+            Err(TransmuteError::EmptySlot)
+        );
+        assert_eq!(
+            transmute_equipped(
+                &mut state,
+                1,
+                EquipmentSlot::Ring,
+                EquipmentSlot::Ring,
+                &mut rng
+            ),
+            Err(TransmuteError::SameSlot)
+        );
+    }
+
+    #[test]
+    fn test_transfer_affixes_copies_affixes() {
+        let source = generate_item(EquipmentSlot::Ring, Rarity::Legendary, 20);
+        let mut target = generate_item(EquipmentSlot::Ring, Rarity::Magic, 60);
+        let attributes = target.attributes.clone();
+
+        transfer_affixes(&source, &mut target).unwrap();
+
+        assert_eq!(target.affixes, source.affixes);
+        assert_eq!(target.ilvl, 60);
+        assert_eq!(target.attributes, attributes);
+    }
+
+    #[test]
+    fn test_transfer_affixes_requires_matching_slots() {
+        let source = generate_item(EquipmentSlot::Ring, Rarity::Legendary, 20);
+        let mut target = generate_item(EquipmentSlot::Weapon, Rarity::Magic, 60);
+        let affixes = target.affixes.clone();
+
+        assert_eq!(
+            transfer_affixes(&source, &mut target),
+            Err(AffixTransferError::SlotMismatch)
+        );
+        assert_eq!(target.affixes, affixes);
+    }
+
+    /// A Rare ilvl 50 ring that was replaced by a newer Common ring of `ilvl`
+    fn state_with_replaced_ring(materials: u64, ilvl: u32) -> GameState {
+        let mut state = state_with_ring(materials);
+        let newer = generate_item(EquipmentSlot::Ring, Rarity::Common, ilvl);
+        state
+            .equipment_history
+            .equip(&mut state.equipment, EquipmentSlot::Ring, newer);
+        state
+    }
+
+    #[test]
+    fn test_transfer_affixes_equipped_consumes_source() {
+        let mut state = state_with_replaced_ring(1000, 80);
+        let source = state.equipment_history.previous.ring.clone().unwrap();
+
+        let result = transfer_affixes_equipped(&mut state, 1, EquipmentSlot::Ring).unwrap();
+
+        assert_eq!(result.affixes, source.affixes);
+        assert_eq!(result.ilvl, 80);
+        assert_eq!(state.equipment.ring.as_ref(), Some(&result));
+        assert!(state.equipment_history.previous.ring.is_none());
+        assert_eq!(state.equipment_history.last_replaced, None);
+        assert_eq!(
+            state.salvage_materials,
+            1000 - affix_transfer_cost(Rarity::Rare)
+        );
+    }
+
+    #[test]
+    fn test_transfer_affixes_equipped_gates() {
+        let mut state = state_with_replaced_ring(1000, 80);
+        let ring = state.equipment.ring.clone();
+
+        assert_eq!(
+            transfer_affixes_equipped(&mut state, 0, EquipmentSlot::Ring),
+            Err(AffixTransferError::WorkshopNotBuilt)
+        );
+        assert_eq!(
+            transfer_affixes_equipped(&mut state, 1, EquipmentSlot::Boots),
+            Err(AffixTransferError::EmptySlot)
+        );
+        state.equipment.weapon = Some(generate_item(EquipmentSlot::Weapon, Rarity::Common, 80));
+        assert_eq!(
+            transfer_affixes_equipped(&mut state, 1, EquipmentSlot::Weapon),
+            Err(AffixTransferError::NoReplacedItem)
+        );
+        state.salvage_materials = 5;
+        assert_eq!(
+            transfer_affixes_equipped(&mut state, 1, EquipmentSlot::Ring),
+            Err(AffixTransferError::NotEnoughMaterials {
+                needed: affix_transfer_cost(Rarity::Rare),
+                have: 5,
+            })
+        );
+        assert_eq!(state.equipment.ring, ring);
+
+        let mut older_base = state_with_replaced_ring(1000, 10);
+        assert_eq!(
+            transfer_affixes_equipped(&mut older_base, 1, EquipmentSlot::Ring),
+            Err(AffixTransferError::LowerItemLevel {
+                source: 50,
+                target: 10
+            })
+        );
+    }
+
+    #[test]
+    fn test_gamble_upgrade_raises_rarity_and_adds_affix() {
+        use rand::SeedableRng;
+
+        let mut upgrades = 0;
+        for seed in 0..100 {
+            let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(seed);
+            let original = generate_item(EquipmentSlot::Ring, Rarity::Rare, 30);
+            let mut item = original.clone();
+
+            if gamble_rarity(&mut item, &mut rng) == GambleOutcome::Upgraded {
+                upgrades += 1;
+                assert_eq!(item.rarity, Rarity::Epic);
+                assert_eq!(item.affixes.len(), original.affixes.len() + 1);
+                assert_eq!(
+                    &item.affixes[..original.affixes.len()],
+                    &original.affixes[..]
+                );
+            }
+        }
+        assert!(upgrades > 0);
+    }
+
+    #[test]
+    fn test_gamble_failure_never_raises_rarity() {
+        use rand::SeedableRng;
+
+        let mut failures = 0;
+        for seed in 0..100 {
+            let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(seed);
+            let original = generate_item(EquipmentSlot::Ring, Rarity::Magic, 30);
+            let mut item = original.clone();
+
+            match gamble_rarity(&mut item, &mut rng) {
+                GambleOutcome::Upgraded => continue,
+                GambleOutcome::Unchanged => assert_eq!(item, original),
+                GambleOutcome::Downgraded => {
+                    assert_eq!(item.rarity, Rarity::Common);
+                    assert!(item.affixes.is_empty());
+                }
+            }
+            failures += 1;
+            assert!(item.rarity <= original.rarity);
+        }
+        assert!(failures > 0);
+
+        let mut legendary = generate_item(EquipmentSlot::Ring, Rarity::Legendary, 30);
+        let before = legendary.clone();
+        assert_eq!(
+            gamble_rarity(&mut legendary, &mut rand::rng()),
+            GambleOutcome::Unchanged
+        );
+        assert_eq!(legendary, before);
+    }
+
+    #[test]
+    fn test_gamble_equipped_spends_materials() {
+        let mut state = state_with_ring(1000);
+        let mut rng = rand::rng();
+
+        assert_eq!(
+            gamble_equipped_rarity(&mut state, 0, EquipmentSlot::Ring, &mut rng),
+            Err(GambleError::WorkshopNotBuilt)
+        );
+        assert_eq!(
+            gamble_equipped_rarity(&mut state, 1, EquipmentSlot::Boots, &mut rng),
+            Err(GambleError::EmptySlot)
+        );
+
+        gamble_equipped_rarity(&mut state, 1, EquipmentSlot::Ring, &mut rng).unwrap();
+        assert_eq!(
+            state.salvage_materials,
+            1000 - rarity_gamble_cost(Rarity::Rare)
+        );
+
+        state.salvage_materials = 0;
+        let ring = state.equipment.ring.clone();
+        assert!(matches!(
+            gamble_equipped_rarity(&mut state, 1, EquipmentSlot::Ring, &mut rng),
+            Err(GambleError::NotEnoughMaterials { .. }) | Err(GambleError::MaxRarity)
+        ));
+        assert_eq!(state.equipment.ring, ring);
+    }
+
+    fn state_with_socketed_ring(sockets: usize) -> GameState {
+        let mut state = state_with_ring(0);
+        let mut ring = state.equipment.ring.take().unwrap();
+        ring.sockets = vec![None; sockets];
+        state.equipment.ring = Some(ring);
+        state
+    }
+
+    #[test]
+    fn test_socketed_gem_adds_derived_stats_and_removal_reverts() {
+        use crate::character::derived_stats::DerivedStats;
+
+        let mut state = state_with_socketed_ring(2);
+        let before = DerivedStats::calculate_derived_stats(&state.attributes, &state.equipment);
+        let emerald = Gem::new(GemKind::Emerald, 2);
+        state.gems.push(emerald);
+
+        socket_gem_equipped(&mut state, 1, EquipmentSlot::Ring, 0, 1).unwrap();
+        assert!(state.gems.is_empty());
+        let socketed = DerivedStats::calculate_derived_stats(&state.attributes, &state.equipment);
+        assert_eq!(socketed.max_hp, before.max_hp + 50); // 25 flat HP per tier
+
+        assert_eq!(
+            unsocket_gem_equipped(&mut state, 1, EquipmentSlot::Ring, 1),
+            Ok(emerald)
+        );
+        assert_eq!(state.gems, vec![emerald]);
+        let after = DerivedStats::calculate_derived_stats(&state.attributes, &state.equipment);
+        assert_eq!(after.max_hp, before.max_hp);
+    }
+
+    #[test]
+    fn test_socket_beyond_capacity_rejected() {
+        let mut state = state_with_socketed_ring(1);
+        let ruby = Gem::new(GemKind::Ruby, 1);
+        state.gems = vec![ruby, ruby];
+
+        assert_eq!(
+            socket_gem_equipped(&mut state, 1, EquipmentSlot::Ring, 0, 1),
+            Err(SocketActionError::Socket(SocketError::NoSuchSocket {
+                socket: 1,
+                capacity: 1
+            }))
+        );
+        socket_gem_equipped(&mut state, 1, EquipmentSlot::Ring, 0, 0).unwrap();
+        assert_eq!(
+            socket_gem_equipped(&mut state, 1, EquipmentSlot::Ring, 0, 0),
+            Err(SocketActionError::Socket(SocketError::Occupied))
+        );
+        // The rejected gem stays in the bag
+        assert_eq!(state.gems, vec![ruby]);
+        assert_eq!(
+            state.equipment.ring.as_ref().unwrap().sockets,
+            vec![Some(ruby)]
+        );
+    }
+
+    #[test]
+    fn test_craft_gem_limited_by_workshop_tier() {
+        let mut state = state_with_ring(gem_craft_cost(2));
+        assert_eq!(
+            craft_gem(&mut state, 1, GemKind::Topaz, 2),
+            Err(GemCraftError::TierUnavailable)
+        );
+        let gem = craft_gem(&mut state, 2, GemKind::Topaz, 2).unwrap();
+        assert_eq!(state.gems, vec![gem]);
+        assert_eq!(state.salvage_materials, 0);
+    }
+}
@@ -1,5 +1,5 @@
 use super::generation::generate_item;
-use super::types::{EquipmentSlot, Item, Rarity};
+use super::types::{EquipmentSlot, Gem, GemKind, Item, Rarity};
 use crate::core::constants::*;
 use crate::core::game_state::GameState;
 use rand::{Rng, RngExt};
@@ -63,6 +63,19 @@ pub fn try_drop_from_boss(zone_id: usize, is_final_zone: bool) -> Item {
     generate_item(slot, rarity, ilvl)
 }
 
+/// Drop from an Elite Hunt target: always Epic, or Legendary with
+/// `ELITE_HUNT_LEGENDARY_CHANCE`.
+pub fn try_drop_from_hunt_elite(zone_id: usize) -> Item {
+    let mut rng = rand::rng();
+    let rarity = if rng.random::<f64>() < ELITE_HUNT_LEGENDARY_CHANCE {
+        Rarity::Legendary
+    } else {
+        Rarity::Epic
+    };
+    let slot = roll_random_slot(&mut rng);
+    generate_item(slot, rarity, ilvl_for_zone(zone_id))
+}
+
 /// Roll rarity for mob drops - caps at Epic (no legendaries).
 /// Haven Workshop bonus shifts distribution toward higher rarities.
 pub fn roll_rarity_for_mob(
@@ -142,6 +155,16 @@ pub fn roll_random_slot(rng: &mut impl Rng) -> EquipmentSlot {
     }
 }
 
+/// Rolls for a tier-1 gem of a random kind dropping from a kill (`GEM_DROP_CHANCE`).
+pub fn try_gem_drop(rng: &mut impl Rng) -> Option<Gem> {
+    if rng.random::<f64>() < GEM_DROP_CHANCE {
+        let kind = GemKind::ALL[rng.random_range(0..GemKind::ALL.len())];
+        Some(Gem::new(kind, 1))
+    } else {
+        None
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -227,6 +250,14 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_hunt_elite_always_drops_epic_or_better() {
+        for _ in 0..200 {
+            let item = try_drop_from_hunt_elite(3);
+            assert!(matches!(item.rarity, Rarity::Epic | Rarity::Legendary));
+        }
+    }
+
     #[test]
     fn test_mob_distribution_base() {
         let mut rng = rand::rng();
@@ -74,6 +74,39 @@ impl Default for Equipment {
     }
 }
 
+/// One-level undo for gear: what each slot held before it was last replaced.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EquipmentHistory {
+    /// Per-slot previous item
+    pub previous: Equipment,
+    /// Slot of the most recent replacement, which quick-swap targets
+    pub last_replaced: Option<EquipmentSlot>,
+}
+
+impl EquipmentHistory {
+    /// Equips `item` in `slot`, remembering the piece it replaces.
+    pub fn equip(&mut self, equipment: &mut Equipment, slot: EquipmentSlot, item: Item) {
+        if let Some(replaced) = equipment.get(slot).clone() {
+            self.previous.set(slot, Some(replaced));
+            self.last_replaced = Some(slot);
+        }
+        equipment.set(slot, Some(item));
+    }
+
+    /// Swaps the item in `slot` with the one it replaced. Returns false
+    /// (changing nothing) if there is no previous item for the slot.
+    pub fn swap(&mut self, equipment: &mut Equipment, slot: EquipmentSlot) -> bool {
+        let Some(previous) = self.previous.get(slot).clone() else {
+            return false;
+        };
+        let current = equipment.get(slot).clone();
+        equipment.set(slot, Some(previous));
+        self.previous.set(slot, current);
+        self.last_replaced = Some(slot);
+        true
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::types::{AttributeBonuses, Rarity};
@@ -88,6 +121,7 @@ mod tests {
             display_name: "Test Item".to_string(),
             attributes: AttributeBonuses::new(),
             affixes: vec![],
+            sockets: Vec::new(),
         }
     }
 
@@ -99,6 +133,61 @@ mod tests {
         assert_eq!(eq.iter_equipped().count(), 0);
     }
 
+    fn named_item(slot: EquipmentSlot, name: &str) -> Item {
+        Item {
+            display_name: name.to_string(),
+            ..create_test_item(slot)
+        }
+    }
+
+    #[test]
+    fn test_history_remembers_replaced_item() {
+        let mut eq = Equipment::new();
+        let mut history = EquipmentHistory::default();
+
+        history.equip(
+            &mut eq,
+            EquipmentSlot::Ring,
+            named_item(EquipmentSlot::Ring, "Old"),
+        );
+        assert_eq!(history.last_replaced, None);
+        assert!(history.previous.ring.is_none());
+
+        history.equip(
+            &mut eq,
+            EquipmentSlot::Ring,
+            named_item(EquipmentSlot::Ring, "New"),
+        );
+        assert_eq!(history.last_replaced, Some(EquipmentSlot::Ring));
+        assert_eq!(history.previous.ring.as_ref().unwrap().display_name, "Old");
+        assert_eq!(eq.ring.as_ref().unwrap().display_name, "New");
+    }
+
+    #[test]
+    fn test_history_swap_exchanges_current_and_previous() {
+        let mut eq = Equipment::new();
+        let mut history = EquipmentHistory::default();
+        assert!(!history.swap(&mut eq, EquipmentSlot::Boots));
+
+        history.equip(
+            &mut eq,
+            EquipmentSlot::Boots,
+            named_item(EquipmentSlot::Boots, "Old"),
+        );
+        history.equip(
+            &mut eq,
+            EquipmentSlot::Boots,
+            named_item(EquipmentSlot::Boots, "New"),
+        );
+
+        assert!(history.swap(&mut eq, EquipmentSlot::Boots));
+        assert_eq!(eq.boots.as_ref().unwrap().display_name, "Old");
+        assert_eq!(history.previous.boots.as_ref().unwrap().display_name, "New");
+
+        assert!(history.swap(&mut eq, EquipmentSlot::Boots));
+        assert_eq!(eq.boots.as_ref().unwrap().display_name, "New");
+    }
+
     #[test]
     fn test_equipment_get_set() {
         let mut eq = Equipment::new();
@@ -161,6 +250,7 @@ mod tests {
                 ..AttributeBonuses::new()
             },
             affixes: vec![],
+            sockets: Vec::new(),
         };
         let item2 = Item {
             slot: EquipmentSlot::Weapon,
@@ -173,6 +263,7 @@ mod tests {
                 ..AttributeBonuses::new()
             },
             affixes: vec![],
+            sockets: Vec::new(),
         };
 
         eq.set(EquipmentSlot::Weapon, Some(item1));
@@ -226,6 +317,7 @@ mod tests {
                 ..AttributeBonuses::new()
             },
             affixes: vec![],
+            sockets: Vec::new(),
         };
         let armor = Item {
             slot: EquipmentSlot::Armor,
@@ -238,6 +330,7 @@ mod tests {
                 ..AttributeBonuses::new()
             },
             affixes: vec![],
+            sockets: Vec::new(),
         };
 
         eq.set(EquipmentSlot::Weapon, Some(weapon));
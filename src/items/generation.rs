@@ -1,18 +1,28 @@
-use super::names::generate_display_name;
-use super::types::{Affix, AffixType, AttributeBonuses, EquipmentSlot, Item, Rarity};
-use crate::core::constants::{ILVL_SCALING_BASE, ILVL_SCALING_DIVISOR};
+use super::names::generate_display_name_with_rng;
+use super::types::{Affix, AffixType, AttributeBonuses, EquipmentSlot, Gem, Item, Rarity};
+use crate::core::constants::{
+    CURSE_AFFIX_CHANCE, ILVL_SCALING_BASE, ILVL_SCALING_DIVISOR, SOCKET_ROLL_CHANCE,
+};
 use rand::{Rng, RngExt};
 
 /// Generate an item with the given slot, rarity, and item level.
 /// ilvl determines stat scaling: ilvl 10 (zone 1) to ilvl 100 (zone 10).
 pub fn generate_item(slot: EquipmentSlot, rarity: Rarity, ilvl: u32) -> Item {
-    let mut rng = rand::rng();
+    generate_item_with_rng(slot, rarity, ilvl, &mut rand::rng())
+}
 
+/// Same as `generate_item`, rolling stats, affixes, and name with the given RNG.
+pub fn generate_item_with_rng(
+    slot: EquipmentSlot,
+    rarity: Rarity,
+    ilvl: u32,
+    rng: &mut impl Rng,
+) -> Item {
     // Generate attribute bonuses based on rarity and ilvl
-    let attributes = generate_attributes(rarity, ilvl, &mut rng);
+    let attributes = generate_attributes(rarity, ilvl, rng);
 
     // Generate affixes based on rarity and ilvl
-    let affixes = generate_affixes(rarity, ilvl, &mut rng);
+    let affixes = generate_affixes(rarity, ilvl, rng);
 
     let mut item = Item {
         slot,
@@ -22,14 +32,54 @@ pub fn generate_item(slot: EquipmentSlot, rarity: Rarity, ilvl: u32) -> Item {
         display_name: String::new(),
         attributes,
         affixes,
+        sockets: Vec::new(),
     };
 
-    item.display_name = generate_display_name(&item);
+    item.display_name = generate_display_name_with_rng(&item, rng);
     item.base_name = item.display_name.clone();
+    item.sockets = roll_sockets(rarity, rng);
 
     item
 }
 
+/// Rolls empty gem sockets: Rare, Epic and Legendary items get up to 1, 2
+/// and 3, each rolling in with `SOCKET_ROLL_CHANCE`. Lower rarities never
+/// have sockets and don't consume the RNG.
+fn roll_sockets(rarity: Rarity, rng: &mut impl Rng) -> Vec<Option<Gem>> {
+    let max_sockets = match rarity {
+        Rarity::Common | Rarity::Magic => 0,
+        Rarity::Rare => 1,
+        Rarity::Epic => 2,
+        Rarity::Legendary => 3,
+    };
+    let count = (0..max_sockets)
+        .filter(|_| rng.random_bool(SOCKET_ROLL_CHANCE))
+        .count();
+    vec![None; count]
+}
+
+/// Transmute an item into another slot, keeping its rarity, ilvl, and
+/// attribute bonuses. Affixes are re-rolled (same rarity-based count) and the
+/// name is regenerated for the new slot. Every affix type can roll on every
+/// slot, so the new affixes are drawn from the same pool as drops.
+pub fn transmute_slot(item: &Item, target: EquipmentSlot, rng: &mut impl Rng) -> Item {
+    let mut transmuted = Item {
+        slot: target,
+        rarity: item.rarity,
+        ilvl: item.ilvl,
+        base_name: String::new(),
+        display_name: String::new(),
+        attributes: item.attributes.clone(),
+        affixes: generate_affixes(item.rarity, item.ilvl, rng),
+        sockets: item.sockets.clone(),
+    };
+
+    transmuted.display_name = generate_display_name_with_rng(&transmuted, rng);
+    transmuted.base_name = transmuted.display_name.clone();
+
+    transmuted
+}
+
 /// Calculate the ilvl multiplier for scaling stats.
 /// ilvl 10: 1.0x, ilvl 50: 2.33x, ilvl 100: 4.0x
 fn ilvl_multiplier(ilvl: u32) -> f64 {
@@ -80,7 +130,22 @@ fn generate_affixes(rarity: Rarity, ilvl: u32, rng: &mut impl Rng) -> Vec<Affix>
         Rarity::Legendary => rng.random_range(4..=5),
     };
 
-    let mut affixes = Vec::new();
+    let mut affixes: Vec<Affix> = (0..count).map(|_| roll_affix(rarity, ilvl, rng)).collect();
+
+    // Strong items occasionally trade one affix slot for a curse
+    if matches!(rarity, Rarity::Epic | Rarity::Legendary) && rng.random_bool(CURSE_AFFIX_CHANCE) {
+        let affix_type = AffixType::FragileArmor;
+        let value = generate_affix_value(affix_type, rarity, ilvl, rng);
+        if let Some(last) = affixes.last_mut() {
+            *last = Affix { affix_type, value };
+        }
+    }
+
+    affixes
+}
+
+/// Rolls one non-curse affix with a value for this rarity and ilvl.
+pub(crate) fn roll_affix(rarity: Rarity, ilvl: u32, rng: &mut impl Rng) -> Affix {
     let all_affix_types = [
         AffixType::DamagePercent,
         AffixType::CritChance,
@@ -91,15 +156,13 @@ fn generate_affixes(rarity: Rarity, ilvl: u32, rng: &mut impl Rng) -> Vec<Affix>
         AffixType::HPRegen,
         AffixType::DamageReflection,
         AffixType::XPGain,
+        AffixType::OnKillHeal,
+        AffixType::OnKillRage,
     ];
 
-    for _ in 0..count {
-        let affix_type = all_affix_types[rng.random_range(0..all_affix_types.len())];
-        let value = generate_affix_value(affix_type, rarity, ilvl, rng);
-        affixes.push(Affix { affix_type, value });
-    }
-
-    affixes
+    let affix_type = all_affix_types[rng.random_range(0..all_affix_types.len())];
+    let value = generate_affix_value(affix_type, rarity, ilvl, rng);
+    Affix { affix_type, value }
 }
 
 fn generate_affix_value(
@@ -156,6 +219,39 @@ fn generate_affix_value(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_transmute_slot_keeps_rarity_and_ilvl() {
+        let mut rng = rand::rng();
+        for rarity in [
+            Rarity::Common,
+            Rarity::Magic,
+            Rarity::Rare,
+            Rarity::Legendary,
+        ] {
+            let ring = generate_item(EquipmentSlot::Ring, rarity, 60);
+            let weapon = transmute_slot(&ring, EquipmentSlot::Weapon, &mut rng);
+
+            assert_eq!(weapon.slot, EquipmentSlot::Weapon);
+            assert_eq!(weapon.rarity, ring.rarity);
+            assert_eq!(weapon.ilvl, ring.ilvl);
+            assert_eq!(weapon.attributes, ring.attributes);
+            assert!(!weapon.display_name.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_transmute_slot_rerolls_valid_affixes() {
+        let mut rng = rand::rng();
+        let amulet = generate_item(EquipmentSlot::Amulet, Rarity::Epic, 100);
+
+        for _ in 0..20 {
+            let boots = transmute_slot(&amulet, EquipmentSlot::Boots, &mut rng);
+            // Same count an Epic drop would roll, with positive ilvl-scaled values
+            assert!((3..=4).contains(&boots.affixes.len()));
+            assert!(boots.affixes.iter().all(|a| a.value > 0.0));
+        }
+    }
+
     #[test]
     fn test_ilvl_multiplier() {
         assert!((ilvl_multiplier(10) - 1.0).abs() < 0.01);
@@ -313,4 +409,25 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_curses_only_roll_on_strong_items() {
+        use rand::SeedableRng;
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(7);
+        let cursed = |item: &Item| item.affixes.iter().any(|a| a.affix_type.is_curse());
+
+        let legendaries: Vec<Item> = (0..200)
+            .map(|_| generate_item_with_rng(EquipmentSlot::Armor, Rarity::Legendary, 50, &mut rng))
+            .collect();
+        assert!(legendaries.iter().any(cursed));
+        // A curse replaces an affix slot rather than adding one
+        assert!(legendaries
+            .iter()
+            .all(|i| (4..=5).contains(&i.affixes.len())));
+
+        for _ in 0..200 {
+            let rare = generate_item_with_rng(EquipmentSlot::Armor, Rarity::Rare, 50, &mut rng);
+            assert!(!cursed(&rare));
+        }
+    }
 }
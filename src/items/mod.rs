@@ -1,9 +1,11 @@
-//! Item system: types, equipment, generation, and scoring.
+//! Item system: types, equipment, generation, salvage, crafting, and scoring.
 
+pub mod crafting;
 pub mod drops;
 pub mod equipment;
 pub mod generation;
 pub mod names;
+pub mod salvage;
 pub mod scoring;
 pub mod types;
 
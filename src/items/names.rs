@@ -1,5 +1,5 @@
 use super::types::{AffixType, EquipmentSlot, Item, Rarity};
-use rand::RngExt;
+use rand::{Rng, RngExt};
 
 pub fn get_base_name(slot: EquipmentSlot) -> &'static [&'static str] {
     match slot {
@@ -32,6 +32,9 @@ pub fn get_affix_prefix(affix_type: AffixType) -> &'static str {
         AffixType::HPRegen => "Regenerating",
         AffixType::DamageReflection => "Thorned",
         AffixType::XPGain => "Wise",
+        AffixType::OnKillHeal => "Vampiric",
+        AffixType::OnKillRage => "Frenzied",
+        AffixType::FragileArmor => "Brittle",
     }
 }
 
@@ -46,11 +49,20 @@ pub fn get_affix_suffix(affix_type: AffixType) -> &'static str {
         AffixType::HPRegen => "of Renewal",
         AffixType::DamageReflection => "of Thorns",
         AffixType::XPGain => "of Learning",
+        AffixType::OnKillHeal => "of the Feast",
+        AffixType::OnKillRage => "of Fury",
+        AffixType::FragileArmor => "of Recklessness",
     }
 }
 
+#[allow(dead_code)]
 pub fn generate_display_name(item: &Item) -> String {
-    let mut rng = rand::rng();
+    generate_display_name_with_rng(item, &mut rand::rng())
+}
+
+/// Same as `generate_display_name`, picking the base name and affix placement
+/// with the given RNG.
+pub fn generate_display_name_with_rng(item: &Item, rng: &mut impl Rng) -> String {
     let base_names = get_base_name(item.slot);
     let base = base_names[rng.random_range(0..base_names.len())];
 
@@ -93,6 +105,7 @@ mod tests {
             display_name: String::new(),
             attributes: AttributeBonuses::new(),
             affixes: vec![],
+            sockets: Vec::new(),
         };
         let name = generate_display_name(&item);
         // Should be just base name
@@ -110,6 +123,7 @@ mod tests {
             display_name: String::new(),
             attributes: AttributeBonuses::new(),
             affixes: vec![],
+            sockets: Vec::new(),
         };
         let name = generate_display_name(&item);
         assert!(name.starts_with("Fine"));
@@ -128,6 +142,7 @@ mod tests {
                 affix_type: AffixType::DamagePercent,
                 value: 15.0,
             }],
+            sockets: Vec::new(),
         };
         let name = generate_display_name(&item);
         // Should contain either "Cruel" or "of Power"
@@ -163,6 +178,9 @@ mod tests {
             AffixType::HPRegen,
             AffixType::DamageReflection,
             AffixType::XPGain,
+            AffixType::OnKillHeal,
+            AffixType::OnKillRage,
+            AffixType::FragileArmor,
         ];
         for affix_type in affix_types {
             let prefix = get_affix_prefix(affix_type);
@@ -186,6 +204,9 @@ mod tests {
             AffixType::HPRegen,
             AffixType::DamageReflection,
             AffixType::XPGain,
+            AffixType::OnKillHeal,
+            AffixType::OnKillRage,
+            AffixType::FragileArmor,
         ];
         for affix_type in affix_types {
             let suffix = get_affix_suffix(affix_type);
@@ -215,6 +236,7 @@ mod tests {
                 affix_type: AffixType::HPBonus,
                 value: 50.0,
             }],
+            sockets: Vec::new(),
         };
         let name = generate_display_name(&item);
         // Should use affix prefix "Sturdy" or suffix "of Vitality"
@@ -238,6 +260,7 @@ mod tests {
                 affix_type: AffixType::CritChance,
                 value: 30.0,
             }],
+            sockets: Vec::new(),
         };
         let name = generate_display_name(&item);
         assert!(
@@ -257,6 +280,7 @@ mod tests {
             display_name: String::new(),
             attributes: AttributeBonuses::new(),
             affixes: vec![],
+            sockets: Vec::new(),
         };
         let name = generate_display_name(&item);
         // Should be a plain base name since there are no affixes
@@ -280,6 +304,7 @@ mod tests {
                 display_name: String::new(),
                 attributes: AttributeBonuses::new(),
                 affixes: vec![],
+                sockets: Vec::new(),
             };
             let name = generate_display_name(&item);
             let base_names = get_base_name(EquipmentSlot::Boots);
@@ -342,6 +367,7 @@ mod tests {
                         } else {
                             vec![]
                         },
+                        sockets: Vec::new(),
                     };
 
                     let name = generate_display_name(&item);
@@ -381,6 +407,7 @@ mod tests {
                     display_name: String::new(),
                     attributes: AttributeBonuses::new(),
                     affixes: vec![],
+                    sockets: Vec::new(),
                 };
 
                 let name = generate_display_name(&item);
@@ -0,0 +1,79 @@
+//! Salvaging unwanted drops into materials, and the rarity-based loot filter.
+
+use super::types::Rarity;
+
+/// Materials gained from salvaging an item of the given rarity.
+pub fn salvage_value(rarity: Rarity) -> u64 {
+    match rarity {
+        Rarity::Common => 1,
+        Rarity::Magic => 3,
+        Rarity::Rare => 8,
+        Rarity::Epic => 20,
+        Rarity::Legendary => 50,
+    }
+}
+
+/// Returns true if a drop of this rarity should be salvaged under the filter.
+/// Items below the minimum rarity are salvaged; `None` keeps everything.
+pub fn is_filtered(rarity: Rarity, min_rarity: Option<Rarity>) -> bool {
+    min_rarity.is_some_and(|min| rarity < min)
+}
+
+/// Next loot filter setting: Off → Magic → Rare → Epic → Legendary → Off.
+pub fn next_loot_filter(current: Option<Rarity>) -> Option<Rarity> {
+    match current {
+        None => Some(Rarity::Magic),
+        Some(Rarity::Common) | Some(Rarity::Magic) => Some(Rarity::Rare),
+        Some(Rarity::Rare) => Some(Rarity::Epic),
+        Some(Rarity::Epic) => Some(Rarity::Legendary),
+        Some(Rarity::Legendary) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_salvage_value_increases_with_rarity() {
+        let values: Vec<u64> = [
+            Rarity::Common,
+            Rarity::Magic,
+            Rarity::Rare,
+            Rarity::Epic,
+            Rarity::Legendary,
+        ]
+        .iter()
+        .map(|r| salvage_value(*r))
+        .collect();
+        assert!(values.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn test_is_filtered() {
+        assert!(!is_filtered(Rarity::Common, None));
+        assert!(is_filtered(Rarity::Magic, Some(Rarity::Rare)));
+        assert!(!is_filtered(Rarity::Rare, Some(Rarity::Rare)));
+        assert!(!is_filtered(Rarity::Legendary, Some(Rarity::Rare)));
+    }
+
+    #[test]
+    fn test_next_loot_filter_cycles_back_to_off() {
+        let mut filter = None;
+        let mut seen = Vec::new();
+        for _ in 0..5 {
+            filter = next_loot_filter(filter);
+            seen.push(filter);
+        }
+        assert_eq!(
+            seen,
+            vec![
+                Some(Rarity::Magic),
+                Some(Rarity::Rare),
+                Some(Rarity::Epic),
+                Some(Rarity::Legendary),
+                None
+            ]
+        );
+    }
+}
@@ -1,4 +1,6 @@
+use super::equipment::Equipment;
 use super::types::{AffixType, AttributeBonuses, Item};
+use crate::core::constants::FRAGILE_ARMOR_DAMAGE_RATIO;
 use crate::core::game_state::GameState;
 
 pub fn score_item(item: &Item, game_state: &GameState) -> f64 {
@@ -29,6 +31,10 @@ pub fn score_item(item: &Item, game_state: &GameState) -> f64 {
             AffixType::HPRegen => affix.value * 1.0,
             AffixType::DamageReflection => affix.value * 0.8,
             AffixType::XPGain => affix.value * 1.0,
+            AffixType::OnKillHeal => affix.value * 0.8,
+            AffixType::OnKillRage => affix.value * 0.6,
+            // Damage gained minus defense lost, at their own weights
+            AffixType::FragileArmor => affix.value * (FRAGILE_ARMOR_DAMAGE_RATIO * 2.0 - 1.3),
         };
         score += affix_score;
     }
@@ -36,6 +42,24 @@ pub fn score_item(item: &Item, game_state: &GameState) -> f64 {
     score
 }
 
+/// Build-independent strength of an item as a single comparable number.
+///
+/// Item level scaled by rarity sets the budget; attribute points and affix
+/// values add on top. Unlike `score_item`, this ignores the character's build,
+/// so it is stable enough to show to players ("Power: N").
+pub fn item_power(item: &Item) -> u64 {
+    let budget = item.ilvl as u64 * (item.rarity as u64 + 1);
+    let attributes: u64 = item.attributes.as_array().iter().map(|&v| v as u64).sum();
+    let affixes: f64 = item.affixes.iter().map(|a| a.value).sum();
+    budget + attributes * 10 + affixes.round() as u64
+}
+
+/// Overall strength of the equipped gear: the sum of each item's `item_power`.
+/// Hard zones gate entry on this (`Zone::min_gear_score`).
+pub fn gear_score(equipment: &Equipment) -> u64 {
+    equipment.iter_equipped().map(item_power).sum()
+}
+
 fn calculate_attribute_weights(game_state: &GameState) -> AttributeBonuses {
     // Weight attributes based on current values (specialization bonus)
     // Higher existing attributes get higher weights
@@ -60,15 +84,16 @@ fn calculate_attribute_weights(game_state: &GameState) -> AttributeBonuses {
 
 pub fn auto_equip_if_better(item: Item, game_state: &mut GameState) -> bool {
     let new_score = score_item(&item, game_state);
-    let current_score = game_state
-        .equipment
-        .get(item.slot)
-        .as_ref()
+    let current = game_state.equipment.get(item.slot).as_ref();
+    let current_score = current
         .map(|current| score_item(current, game_state))
         .unwrap_or(0.0);
+    // Equal build scores fall back to raw item power
+    let wins_tiebreak = new_score == current_score
+        && current.is_some_and(|current| item_power(&item) > item_power(current));
 
-    if new_score > current_score {
-        game_state.equipment.set(item.slot, Some(item));
+    if new_score > current_score || wins_tiebreak {
+        game_state.equip(item);
         true
     } else {
         false
@@ -93,6 +118,7 @@ mod tests {
                 ..AttributeBonuses::new()
             },
             affixes: vec![],
+            sockets: Vec::new(),
         }
     }
 
@@ -119,6 +145,7 @@ mod tests {
                 affix_type: AffixType::DamagePercent,
                 value: 15.0,
             }],
+            sockets: Vec::new(),
         };
 
         let score = score_item(&item, &game_state);
@@ -161,6 +188,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_auto_equip_keeps_replaced_item_for_quick_swap() {
+        let mut game_state = GameState::new("Test Hero".to_string(), Utc::now().timestamp());
+        let weapon_str = |state: &GameState| {
+            state
+                .equipment
+                .get(EquipmentSlot::Weapon)
+                .as_ref()
+                .unwrap()
+                .attributes
+                .str
+        };
+        assert_eq!(game_state.quick_swap_gear(), None);
+
+        auto_equip_if_better(
+            create_test_item(EquipmentSlot::Weapon, Rarity::Common, 1),
+            &mut game_state,
+        );
+        auto_equip_if_better(
+            create_test_item(EquipmentSlot::Weapon, Rarity::Rare, 10),
+            &mut game_state,
+        );
+        let previous = game_state.equipment_history.previous.weapon.as_ref();
+        assert_eq!(previous.unwrap().attributes.str, 1);
+
+        assert_eq!(game_state.quick_swap_gear(), Some(EquipmentSlot::Weapon));
+        assert_eq!(weapon_str(&game_state), 1);
+        assert_eq!(game_state.quick_swap_gear(), Some(EquipmentSlot::Weapon));
+        assert_eq!(weapon_str(&game_state), 10);
+    }
+
     #[test]
     fn test_auto_equip_rejects_worse_item() {
         let mut game_state = GameState::new("Test Hero".to_string(), Utc::now().timestamp());
@@ -209,6 +267,7 @@ mod tests {
                 ..AttributeBonuses::new()
             },
             affixes: vec![],
+            sockets: Vec::new(),
         };
         let dex_item = Item {
             slot: EquipmentSlot::Weapon,
@@ -221,6 +280,7 @@ mod tests {
                 ..AttributeBonuses::new()
             },
             affixes: vec![],
+            sockets: Vec::new(),
         };
 
         let str_score = score_item(&str_item, &game_state);
@@ -241,6 +301,7 @@ mod tests {
             display_name: "Test".to_string(),
             attributes: AttributeBonuses::new(),
             affixes: vec![],
+            sockets: Vec::new(),
         };
 
         let score = score_item(&item, &game_state);
@@ -266,6 +327,7 @@ mod tests {
                     affix_type,
                     value: 10.0,
                 }],
+                sockets: Vec::new(),
             }
         };
 
@@ -298,6 +360,7 @@ mod tests {
                 ..AttributeBonuses::new()
             },
             affixes: vec![],
+            sockets: Vec::new(),
         };
         let combined = Item {
             slot: EquipmentSlot::Weapon,
@@ -313,6 +376,7 @@ mod tests {
                 affix_type: AffixType::DamagePercent,
                 value: 10.0,
             }],
+            sockets: Vec::new(),
         };
 
         let attr_score = score_item(&attr_only, &game_state);
@@ -338,6 +402,7 @@ mod tests {
                 ..AttributeBonuses::new()
             },
             affixes: vec![],
+            sockets: Vec::new(),
         };
 
         assert!(auto_equip_if_better(weapon, &mut game_state));
@@ -370,6 +435,7 @@ mod tests {
                 affix_type: AffixType::DamagePercent,
                 value: 20.0,
             }],
+            sockets: Vec::new(),
         };
 
         let equipped = auto_equip_if_better(affix_item, &mut game_state);
@@ -378,4 +444,41 @@ mod tests {
             "Item with strong affix should replace weak attribute-only item"
         );
     }
+
+    #[test]
+    fn test_item_power_higher_rarity_same_ilvl_is_stronger() {
+        let magic = create_test_item(EquipmentSlot::Weapon, Rarity::Magic, 5);
+        let epic = create_test_item(EquipmentSlot::Weapon, Rarity::Epic, 5);
+
+        assert!(item_power(&epic) > item_power(&magic));
+    }
+
+    #[test]
+    fn test_auto_equip_agrees_with_item_power_on_equal_scores() {
+        let mut game_state = GameState::new("Test Hero".to_string(), Utc::now().timestamp());
+
+        // Same attributes score identically; rarity only shows up in item_power
+        let common = create_test_item(EquipmentSlot::Weapon, Rarity::Common, 5);
+        let rare = create_test_item(EquipmentSlot::Weapon, Rarity::Rare, 5);
+        assert_eq!(
+            score_item(&common, &game_state),
+            score_item(&rare, &game_state)
+        );
+        assert!(item_power(&rare) > item_power(&common));
+
+        assert!(auto_equip_if_better(common.clone(), &mut game_state));
+        assert!(auto_equip_if_better(rare, &mut game_state));
+        assert_eq!(
+            game_state
+                .equipment
+                .get(EquipmentSlot::Weapon)
+                .as_ref()
+                .unwrap()
+                .rarity,
+            Rarity::Rare
+        );
+
+        // And the weaker item does not win the tiebreak back
+        assert!(!auto_equip_if_better(common, &mut game_state));
+    }
 }
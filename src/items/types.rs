@@ -1,3 +1,8 @@
+use crate::core::constants::{
+    AFFIX_PERCENT_DIVISOR, DAMAGE_PER_INT_MODIFIER, DAMAGE_PER_STR_MODIFIER,
+    FRAGILE_ARMOR_DAMAGE_RATIO, HP_PER_CON_MODIFIER, PRESTIGE_MULT_PER_CHA_MODIFIER,
+    XP_MULT_PER_WIS_MODIFIER,
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -12,6 +17,16 @@ pub enum EquipmentSlot {
 }
 
 impl EquipmentSlot {
+    pub const ALL: [EquipmentSlot; 7] = [
+        EquipmentSlot::Weapon,
+        EquipmentSlot::Armor,
+        EquipmentSlot::Helmet,
+        EquipmentSlot::Gloves,
+        EquipmentSlot::Boots,
+        EquipmentSlot::Amulet,
+        EquipmentSlot::Ring,
+    ];
+
     pub fn name(&self) -> &'static str {
         match self {
             EquipmentSlot::Weapon => "Weapon",
@@ -107,6 +122,39 @@ pub enum AffixType {
     DamageReflection,
     // Progression
     XPGain,
+    // On-kill procs
+    /// Heal this percent of max HP when an enemy dies to a player attack
+    OnKillHeal,
+    /// Gain this percent attack speed for a few seconds after a kill
+    OnKillRage,
+    // Curses
+    /// Lose this percent of defense, gain `FRAGILE_ARMOR_DAMAGE_RATIO` times as much damage
+    FragileArmor,
+}
+
+impl AffixType {
+    /// Full display name, used where there is room for more than the short label.
+    pub fn name(&self) -> &'static str {
+        match self {
+            AffixType::DamagePercent => "Damage",
+            AffixType::CritChance => "Crit Chance",
+            AffixType::CritMultiplier => "Crit Damage",
+            AffixType::AttackSpeed => "Attack Speed",
+            AffixType::HPBonus => "Max HP",
+            AffixType::DamageReduction => "Damage Reduction",
+            AffixType::HPRegen => "HP Regen",
+            AffixType::DamageReflection => "Damage Reflection",
+            AffixType::XPGain => "XP Gain",
+            AffixType::OnKillHeal => "Heal on Kill",
+            AffixType::OnKillRage => "Rage on Kill",
+            AffixType::FragileArmor => "Fragile Armor",
+        }
+    }
+
+    /// Curses trade a penalty for a compensating bonus and never roll in the normal pool.
+    pub fn is_curse(&self) -> bool {
+        matches!(self, AffixType::FragileArmor)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -115,6 +163,96 @@ pub struct Affix {
     pub value: f64,
 }
 
+/// Gem kinds, each granting one affix-style bonus while socketed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GemKind {
+    /// Percent damage
+    Ruby,
+    /// Percent crit chance
+    Sapphire,
+    /// Flat max HP
+    Emerald,
+    /// Percent defense
+    Topaz,
+}
+
+impl GemKind {
+    pub const ALL: [GemKind; 4] = [
+        GemKind::Ruby,
+        GemKind::Sapphire,
+        GemKind::Emerald,
+        GemKind::Topaz,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            GemKind::Ruby => "Ruby",
+            GemKind::Sapphire => "Sapphire",
+            GemKind::Emerald => "Emerald",
+            GemKind::Topaz => "Topaz",
+        }
+    }
+
+    /// The affix this gem's bonus is applied as in `DerivedStats`.
+    pub fn affix_type(&self) -> AffixType {
+        match self {
+            GemKind::Ruby => AffixType::DamagePercent,
+            GemKind::Sapphire => AffixType::CritChance,
+            GemKind::Emerald => AffixType::HPBonus,
+            GemKind::Topaz => AffixType::DamageReduction,
+        }
+    }
+
+    /// Bonus granted per gem tier.
+    fn value_per_tier(&self) -> f64 {
+        match self {
+            GemKind::Ruby => 5.0,
+            GemKind::Sapphire => 3.0,
+            GemKind::Emerald => 25.0,
+            GemKind::Topaz => 5.0,
+        }
+    }
+}
+
+/// A gem that can be socketed into an item, dropped or crafted at the Workshop
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Gem {
+    pub kind: GemKind,
+    /// 1..=`GEM_MAX_TIER`
+    pub tier: u8,
+}
+
+impl Gem {
+    pub fn new(kind: GemKind, tier: u8) -> Self {
+        Self { kind, tier }
+    }
+
+    /// The bonus this gem grants, as an affix.
+    pub fn affix(&self) -> Affix {
+        Affix {
+            affix_type: self.kind.affix_type(),
+            value: self.kind.value_per_tier() * self.tier as f64,
+        }
+    }
+
+    /// e.g. "Ruby (T2)"
+    pub fn name(&self) -> String {
+        format!("{} (T{})", self.kind.name(), self.tier)
+    }
+}
+
+/// Why a gem couldn't be put into or taken out of a socket.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SocketError {
+    /// The item has fewer sockets than the index asked for
+    NoSuchSocket {
+        socket: usize,
+        capacity: usize,
+    },
+    Occupied,
+    Empty,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Item {
     pub slot: EquipmentSlot,
@@ -125,6 +263,9 @@ pub struct Item {
     pub display_name: String,
     pub attributes: AttributeBonuses,
     pub affixes: Vec<Affix>,
+    /// Gem sockets rolled with the item; `None` is an empty socket
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sockets: Vec<Option<Gem>>,
 }
 
 fn default_ilvl() -> u32 {
@@ -132,6 +273,34 @@ fn default_ilvl() -> u32 {
 }
 
 impl Item {
+    /// Bonuses from socketed gems, applied alongside the item's affixes.
+    pub fn gem_affixes(&self) -> impl Iterator<Item = Affix> + '_ {
+        self.sockets.iter().flatten().map(Gem::affix)
+    }
+
+    /// Puts `gem` into the empty socket at `socket`.
+    pub fn socket_gem(&mut self, socket: usize, gem: Gem) -> Result<(), SocketError> {
+        let capacity = self.sockets.len();
+        match self.sockets.get_mut(socket) {
+            None => Err(SocketError::NoSuchSocket { socket, capacity }),
+            Some(Some(_)) => Err(SocketError::Occupied),
+            Some(slot) => {
+                *slot = Some(gem);
+                Ok(())
+            }
+        }
+    }
+
+    /// Takes the gem out of the socket at `socket`, leaving it empty.
+    pub fn unsocket_gem(&mut self, socket: usize) -> Result<Gem, SocketError> {
+        let capacity = self.sockets.len();
+        self.sockets
+            .get_mut(socket)
+            .ok_or(SocketError::NoSuchSocket { socket, capacity })?
+            .take()
+            .ok_or(SocketError::Empty)
+    }
+
     /// Returns a short stat summary string like "+8 STR +3 DEX +Crit"
     pub fn stat_summary(&self) -> String {
         const ATTR_LABELS: [&str; 6] = ["STR", "DEX", "CON", "INT", "WIS", "CHA"];
@@ -143,7 +312,7 @@ impl Item {
             }
         }
 
-        for affix in &self.affixes {
+        for affix in self.affixes.iter().cloned().chain(self.gem_affixes()) {
             let label = match affix.affix_type {
                 AffixType::DamagePercent => format!("+{:.0}% Dmg", affix.value),
                 AffixType::CritChance => format!("+{:.0}% Crit", affix.value),
@@ -154,6 +323,13 @@ impl Item {
                 AffixType::HPRegen => format!("+{:.0} Regen", affix.value),
                 AffixType::DamageReflection => format!("+{:.0}% Reflect", affix.value),
                 AffixType::XPGain => format!("+{:.0}% XP", affix.value),
+                AffixType::OnKillHeal => format!("+{:.0}% HealOnKill", affix.value),
+                AffixType::OnKillRage => format!("+{:.0}% RageOnKill", affix.value),
+                AffixType::FragileArmor => format!(
+                    "-{:.0}% Def +{:.0}% Dmg",
+                    affix.value,
+                    affix.value * FRAGILE_ARMOR_DAMAGE_RATIO
+                ),
             };
             parts.push(label);
         }
@@ -161,6 +337,88 @@ impl Item {
         parts.join(" ")
     }
 
+    /// Returns a detailed summary listing every attribute and affix by name,
+    /// with the derived stats each one implies, e.g.
+    /// "+8 STR (~+8 Phys Dmg), Crit Chance +5% (+5% crit chance)".
+    ///
+    /// Attribute contributions are approximate: modifiers are computed from the
+    /// character's total, so an odd bonus may round either way.
+    pub fn stat_summary_verbose(&self) -> String {
+        const ATTR_LABELS: [&str; 6] = ["STR", "DEX", "CON", "INT", "WIS", "CHA"];
+        let mut parts = Vec::new();
+
+        for (value, label) in self.attributes.as_array().iter().zip(ATTR_LABELS.iter()) {
+            if *value == 0 {
+                continue;
+            }
+            let modifier = (*value / 2) as i32;
+            let implied = match *label {
+                "STR" => format!("~+{} Phys Dmg", modifier * DAMAGE_PER_STR_MODIFIER),
+                "DEX" => format!("~+{} Def, +{}% Crit", modifier, modifier),
+                "CON" => format!("~+{} HP", modifier * HP_PER_CON_MODIFIER),
+                "INT" => format!("~+{} Magic Dmg", modifier * DAMAGE_PER_INT_MODIFIER),
+                "WIS" => format!(
+                    "~+{:.0}% XP",
+                    modifier as f64 * XP_MULT_PER_WIS_MODIFIER * 100.0
+                ),
+                // CHA
+                _ => format!(
+                    "~+{:.1}x Prestige Mult",
+                    modifier as f64 * PRESTIGE_MULT_PER_CHA_MODIFIER
+                ),
+            };
+            parts.push(format!("+{} {} ({})", value, label, implied));
+        }
+
+        for affix in self.affixes.iter().cloned().chain(self.gem_affixes()) {
+            let value = affix.value;
+            let detail = match affix.affix_type {
+                AffixType::DamagePercent => format!(
+                    "+{:.0}% (x{:.2} damage)",
+                    value,
+                    1.0 + value / AFFIX_PERCENT_DIVISOR
+                ),
+                AffixType::CritChance => format!("+{:.0}% (+{:.0}% crit chance)", value, value),
+                AffixType::CritMultiplier => format!(
+                    "+{:.0}% (+{:.2}x crit multiplier)",
+                    value,
+                    value / AFFIX_PERCENT_DIVISOR
+                ),
+                AffixType::AttackSpeed => format!("+{:.0}% (attacks {:.0}% faster)", value, value),
+                AffixType::HPBonus => format!("+{:.0} (+{:.0} max HP)", value, value),
+                AffixType::DamageReduction => format!(
+                    "+{:.0}% (x{:.2} defense)",
+                    value,
+                    1.0 + value / AFFIX_PERCENT_DIVISOR
+                ),
+                AffixType::HPRegen => format!("+{:.0}% (regenerates {:.0}% faster)", value, value),
+                AffixType::DamageReflection => {
+                    format!("+{:.0}% (reflects {:.0}% of damage taken)", value, value)
+                }
+                AffixType::XPGain => format!(
+                    "+{:.0}% (x{:.2} XP)",
+                    value,
+                    1.0 + value / AFFIX_PERCENT_DIVISOR
+                ),
+                AffixType::OnKillHeal => {
+                    format!("+{:.0}% (heals {:.0}% max HP per kill)", value, value)
+                }
+                AffixType::OnKillRage => {
+                    format!("+{:.0}% (+{:.0}% attack speed after a kill)", value, value)
+                }
+                AffixType::FragileArmor => format!(
+                    "+{:.0}% (-{:.0}% defense, +{:.0}% damage)",
+                    value,
+                    value,
+                    value * FRAGILE_ARMOR_DAMAGE_RATIO
+                ),
+            };
+            parts.push(format!("{} {}", affix.affix_type.name(), detail));
+        }
+
+        parts.join(", ")
+    }
+
     /// Returns the slot name as a string
     pub fn slot_name(&self) -> &'static str {
         self.slot.name()
@@ -205,6 +463,7 @@ mod tests {
                 ..AttributeBonuses::new()
             },
             affixes: vec![],
+            sockets: Vec::new(),
         };
         assert_eq!(item.slot, EquipmentSlot::Weapon);
         assert_eq!(item.rarity, Rarity::Common);
@@ -286,9 +545,104 @@ mod tests {
                     value: 50.0,
                 },
             ],
+            sockets: Vec::new(),
         };
         assert_eq!(item.affixes.len(), 3);
         assert_eq!(item.affixes[0].affix_type, AffixType::DamagePercent);
         assert!((item.affixes[0].value - 15.0).abs() < f64::EPSILON);
     }
+
+    const ALL_AFFIX_TYPES: [AffixType; 12] = [
+        AffixType::DamagePercent,
+        AffixType::CritChance,
+        AffixType::CritMultiplier,
+        AffixType::AttackSpeed,
+        AffixType::HPBonus,
+        AffixType::DamageReduction,
+        AffixType::HPRegen,
+        AffixType::DamageReflection,
+        AffixType::XPGain,
+        AffixType::OnKillHeal,
+        AffixType::OnKillRage,
+        AffixType::FragileArmor,
+    ];
+
+    fn summary_item() -> Item {
+        Item {
+            slot: EquipmentSlot::Weapon,
+            rarity: Rarity::Rare,
+            ilvl: 10,
+            base_name: "Sword".to_string(),
+            display_name: "Keen Sword".to_string(),
+            attributes: AttributeBonuses {
+                str: 8,
+                dex: 3,
+                ..AttributeBonuses::new()
+            },
+            affixes: vec![
+                Affix {
+                    affix_type: AffixType::DamagePercent,
+                    value: 15.0,
+                },
+                Affix {
+                    affix_type: AffixType::CritChance,
+                    value: 5.0,
+                },
+            ],
+            sockets: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_stat_summary_compact_format_unchanged() {
+        assert_eq!(
+            summary_item().stat_summary(),
+            "+8 STR +3 DEX +15% Dmg +5% Crit"
+        );
+    }
+
+    #[test]
+    fn test_stat_summary_verbose_includes_every_affix() {
+        let item = Item {
+            affixes: ALL_AFFIX_TYPES
+                .iter()
+                .map(|&affix_type| Affix {
+                    affix_type,
+                    value: 10.0,
+                })
+                .collect(),
+            ..summary_item()
+        };
+        let verbose = item.stat_summary_verbose();
+        for affix_type in ALL_AFFIX_TYPES {
+            assert!(
+                verbose.contains(&format!("{} +10", affix_type.name())),
+                "verbose summary missing {}: {}",
+                affix_type.name(),
+                verbose
+            );
+        }
+    }
+
+    #[test]
+    fn test_stat_summary_verbose_shows_derived_contributions() {
+        let verbose = summary_item().stat_summary_verbose();
+        // +8 STR is four modifier points at 2 damage each
+        assert!(verbose.contains("+8 STR (~+8 Phys Dmg)"), "{}", verbose);
+        assert!(
+            verbose.contains("+3 DEX (~+1 Def, +1% Crit)"),
+            "{}",
+            verbose
+        );
+        assert!(
+            verbose.contains("Damage +15% (x1.15 damage)"),
+            "{}",
+            verbose
+        );
+        assert!(
+            verbose.contains("Crit Chance +5% (+5% crit chance)"),
+            "{}",
+            verbose
+        );
+    }
 }
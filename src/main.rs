@@ -18,7 +18,7 @@ use character::input::{
     CreationInput, CreationResult, DeleteInput, DeleteResult, RenameInput, RenameResult,
     SelectInput, SelectResult,
 };
-use character::manager::CharacterManager;
+use character::manager::{CharacterManager, LoadError};
 use chrono::{Local, Utc};
 use core::constants::*;
 use core::game_logic::*;
@@ -40,10 +40,14 @@ use ui::character_delete::CharacterDeleteScreen;
 use ui::character_rename::CharacterRenameScreen;
 use ui::character_select::CharacterSelectScreen;
 use ui::draw_ui_with_update;
-use utils::updater::UpdateInfo;
+use utils::updater::{CompletedUpdateCheck, UpdateBackoff, UpdateCheckResult, UpdateInfo};
 
 /// Process offline XP and add combat log entries. Returns the report if XP was gained.
-fn apply_offline_xp(state: &mut GameState, haven: &haven::Haven) -> Option<OfflineReport> {
+fn apply_offline_xp(
+    state: &mut GameState,
+    haven: &haven::Haven,
+    number_format: utils::settings::NumberFormat,
+) -> Option<OfflineReport> {
     let haven_offline_bonus = haven.get_bonus(haven::HavenBonusType::OfflineXpPercent);
     let report = process_offline_progression(state, haven_offline_bonus);
     if report.xp_gained > 0 {
@@ -62,7 +66,7 @@ fn apply_offline_xp(state: &mut GameState, haven: &haven::Haven) -> Option<Offli
         state.combat_state.add_log_entry(
             format!(
                 "⚔️ +{} XP gained offline",
-                ui::game_common::format_number_short(report.xp_gained)
+                ui::game_common::format_number(report.xp_gained, number_format)
             ),
             false,
             true,
@@ -77,12 +81,46 @@ fn apply_offline_xp(state: &mut GameState, haven: &haven::Haven) -> Option<Offli
                 true,
             );
         }
+        log_expedition_report(state, &report, number_format);
         Some(report)
     } else {
+        log_expedition_report(state, &report, number_format);
         None
     }
 }
 
+/// Add combat log entries for an expedition that returned while offline.
+fn log_expedition_report(
+    state: &mut GameState,
+    report: &OfflineReport,
+    number_format: utils::settings::NumberFormat,
+) {
+    if let Some(expedition) = &report.expedition {
+        state.combat_state.add_log_entry(
+            format!(
+                "⛺ {} returned! +{} XP, {} item{} ({} equipped)",
+                expedition.tier.name(),
+                ui::game_common::format_number(expedition.xp_gained, number_format),
+                expedition.items.len(),
+                if expedition.items.len() == 1 { "" } else { "s" },
+                expedition.items_equipped,
+            ),
+            false,
+            true,
+        );
+        if expedition.level_ups > 0 {
+            state.combat_state.add_log_entry(
+                format!(
+                    "📈 Expedition leveled you up {} times!",
+                    expedition.level_ups
+                ),
+                false,
+                true,
+            );
+        }
+    }
+}
+
 #[derive(Clone, Copy, PartialEq)]
 enum Screen {
     CharacterSelect,
@@ -171,6 +209,7 @@ fn draw_game_overlays(
     global_achievements: &achievements::Achievements,
     debug_mode: bool,
     debug_menu: &utils::debug_menu::DebugMenu,
+    number_format: utils::settings::NumberFormat,
     last_save_instant: Option<Instant>,
     last_save_time: Option<chrono::DateTime<chrono::Local>>,
     ctx: &ui::responsive::LayoutContext,
@@ -178,11 +217,26 @@ fn draw_game_overlays(
     let area = frame.area();
     match overlay {
         GameOverlay::OfflineWelcome { report } => {
-            ui::game_common::render_offline_welcome(frame, area, report, ctx);
+            ui::game_common::render_offline_welcome(frame, area, report, number_format, ctx);
         }
         GameOverlay::PrestigeConfirm => {
             ui::prestige_confirm::draw_prestige_confirm(frame, state, ctx);
         }
+        GameOverlay::HardcoreFishingConfirm => {
+            ui::hardcore_fishing_confirm::draw_hardcore_fishing_confirm(frame, ctx);
+        }
+        GameOverlay::KeyHelp => {
+            ui::key_help::draw_key_help(frame, ctx);
+        }
+        GameOverlay::ZoneCodex { selected } => {
+            ui::zone_codex::draw_zone_codex(frame, &state.zone_progression, *selected, ctx);
+        }
+        GameOverlay::LootFilterConfirm => {
+            ui::loot_filter_confirm::draw_loot_filter_confirm(frame, ctx);
+        }
+        GameOverlay::AutoChallengeConfirm { choice } => {
+            ui::auto_challenge_confirm::draw_auto_challenge_confirm(frame, *choice, ctx);
+        }
         GameOverlay::HavenDiscovery => {
             ui::haven_scene::render_haven_discovery_modal(frame, area, ctx);
         }
@@ -225,6 +279,9 @@ fn draw_game_overlays(
                 ctx,
             );
         }
+        GameOverlay::TutorialHint { step } => {
+            ui::game_common::render_tutorial_hint(frame, area, *step, ctx);
+        }
         GameOverlay::None => {}
     }
 
@@ -260,6 +317,25 @@ fn draw_game_overlays(
                     ctx,
                 );
             }
+            input::HavenConfirmation::Workshop => {
+                ui::haven_scene::render_workshop(
+                    frame,
+                    area,
+                    state,
+                    haven,
+                    &haven_ui.workshop,
+                    ctx,
+                );
+            }
+            input::HavenConfirmation::ImportBuildOrder => {
+                ui::haven_scene::render_build_order_confirmation(
+                    frame,
+                    area,
+                    &haven_ui.build_order,
+                    state.prestige_rank,
+                    ctx,
+                );
+            }
             input::HavenConfirmation::None => {}
         }
     }
@@ -344,6 +420,9 @@ fn main() -> io::Result<()> {
     // Handle CLI arguments
     let args: Vec<String> = std::env::args().collect();
     let mut debug_mode = false;
+    let mut demo_mode = false;
+    let mut stats_file: Option<std::path::PathBuf> = None;
+    let mut dungeon_seed: Option<u64> = None;
 
     if args.len() > 1 {
         match args[1].as_str() {
@@ -351,6 +430,20 @@ fn main() -> io::Result<()> {
                 Ok(_) => std::process::exit(0),
                 Err(_) => std::process::exit(1),
             },
+            "doctor" => match utils::doctor::run_doctor_command(&args[2..]) {
+                Ok(_) => std::process::exit(0),
+                Err(e) => {
+                    eprintln!("quest doctor failed: {}", e);
+                    std::process::exit(1);
+                }
+            },
+            "card" => match utils::card::run_card_command() {
+                Ok(_) => std::process::exit(0),
+                Err(e) => {
+                    eprintln!("quest card failed: {}", e);
+                    std::process::exit(1);
+                }
+            },
             "--version" | "-v" => {
                 println!(
                     "quest {} ({})",
@@ -364,7 +457,17 @@ fn main() -> io::Result<()> {
                 println!("Usage: quest [command]\n");
                 println!("Commands:");
                 println!("  update     Check for and install updates");
+                println!("  doctor <save> [--write <out>]");
+                println!(
+                    "             Check a save for problems, optionally writing a repaired copy"
+                );
+                println!("  card       Print a shareable Markdown card for your latest character");
                 println!("  --debug    Enable debug menu (press ` to toggle)");
+                println!("  --demo     Auto-play a pre-built character (no saves)");
+                println!("  --stats-file <path>");
+                println!("             Keep a JSON snapshot of the active character at <path>");
+                println!("  --dungeon-seed <seed>");
+                println!("             Use a shared seed for the next dungeon you discover");
                 println!("  --version  Show version information");
                 println!("  --help     Show this help message");
                 std::process::exit(0);
@@ -373,6 +476,27 @@ fn main() -> io::Result<()> {
                 debug_mode = true;
                 eprintln!("=== DEBUG MODE ENABLED - SAVES DISABLED ===");
             }
+            "--demo" => {
+                demo_mode = true;
+                eprintln!("=== DEMO MODE - SAVES DISABLED ===");
+            }
+            "--stats-file" => match args.get(2) {
+                Some(path) => stats_file = Some(path.into()),
+                None => {
+                    eprintln!("Usage: quest --stats-file <path>");
+                    std::process::exit(1);
+                }
+            },
+            "--dungeon-seed" => match args
+                .get(2)
+                .and_then(|code| dungeon::types::Dungeon::parse_seed_code(code))
+            {
+                Some(seed) => dungeon_seed = Some(seed),
+                None => {
+                    eprintln!("Usage: quest --dungeon-seed <seed>");
+                    std::process::exit(1);
+                }
+            },
             other => {
                 eprintln!("Unknown command: {}", other);
                 eprintln!("Run 'quest --help' for usage.");
@@ -381,17 +505,35 @@ fn main() -> io::Result<()> {
         }
     }
 
+    // Debug and demo sessions never write saves
+    let saves_disabled = debug_mode || demo_mode;
+
     // Check for updates in background (non-blocking notification)
     let update_available = std::thread::spawn(utils::updater::check_update_info);
 
     // Initialize CharacterManager
     let character_manager = CharacterManager::new()?;
+    character_manager
+        .purge_deleted(Duration::from_secs(DELETED_CHARACTER_RETENTION_SECONDS))
+        .ok();
 
-    // Load account-level Haven state
-    let mut haven = haven::load_haven();
+    // Load account-level settings
+    // Any warning is shown in the combat log once a character is loaded
+    let (mut settings, mut settings_warning) = utils::settings::Settings::load();
+
+    // Load account-level Haven state (the demo starts from a blank account)
+    let mut haven = if demo_mode {
+        haven::Haven::default()
+    } else {
+        haven::load_haven()
+    };
 
     // Load global achievements (shared across all characters)
-    let mut global_achievements = achievements::load_achievements();
+    let mut global_achievements = if demo_mode {
+        achievements::Achievements::default()
+    } else {
+        achievements::load_achievements()
+    };
     global_achievements.refresh_progress();
 
     // List existing characters
@@ -410,11 +552,18 @@ fn main() -> io::Result<()> {
     let mut delete_screen = CharacterDeleteScreen::new();
     let mut rename_screen = CharacterRenameScreen::new();
     let mut game_state: Option<GameState> = None;
+    if demo_mode {
+        game_state = Some(core::demo::build_demo_state(Utc::now().timestamp()));
+        current_screen = Screen::Game;
+    }
     let mut pending_offline_report: Option<core::game_logic::OfflineReport> = None;
 
     let mut haven_ui = HavenUiState::new();
     let mut achievement_browser = AchievementBrowserState::new();
 
+    // Save and exit cleanly on SIGINT/SIGTERM instead of dying mid-session
+    let shutdown = utils::shutdown::ShutdownSignal::install()?;
+
     // Setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
@@ -423,14 +572,22 @@ fn main() -> io::Result<()> {
     let mut terminal = Terminal::new(backend)?;
 
     // Show update notification if available
-    if let Ok(Some(update_info)) = update_available.join() {
+    if let Ok(UpdateCheckResult::Available(update_info)) = update_available.join() {
         show_startup_update_notification(&mut terminal, &update_info)?;
+        let _ = utils::updater::mark_changelog_seen(&update_info.new_commit);
     }
 
     // Main loop — clear terminal on screen transitions to prevent
     // stale cells from wide characters (emoji) in the ratatui diff.
     let mut prev_screen = current_screen;
-    loop {
+    'main_loop: loop {
+        if shutdown.requested() {
+            break;
+        }
+        // Leaving the demo character exits instead of showing the real saves
+        if demo_mode && current_screen != Screen::Game {
+            break;
+        }
         if current_screen != prev_screen {
             terminal.clear()?;
             prev_screen = current_screen;
@@ -481,12 +638,13 @@ fn main() -> io::Result<()> {
             Screen::CharacterSelect => {
                 // Refresh character list
                 let characters = character_manager.list_characters()?;
+                let account_stats = character::manager::AccountStats::from_characters(&characters);
 
                 // Draw character select screen (includes Haven tree visualization)
                 terminal.draw(|f| {
                     let area = f.area();
                     let ctx = ui::responsive::LayoutContext::from_frame(f);
-                    select_screen.draw(f, area, &characters, &haven, &ctx);
+                    select_screen.draw(f, area, &characters, &account_stats, &haven, &ctx);
                     // Draw Haven management overlay if open
                     if haven_ui.showing {
                         ui::haven_scene::render_haven_tree(
@@ -600,6 +758,7 @@ fn main() -> io::Result<()> {
                             KeyCode::Char('n') | KeyCode::Char('N') => SelectInput::New,
                             KeyCode::Char('d') | KeyCode::Char('D') => SelectInput::Delete,
                             KeyCode::Char('r') | KeyCode::Char('R') => SelectInput::Rename,
+                            KeyCode::Char('u') | KeyCode::Char('U') => SelectInput::Restore,
                             KeyCode::Esc => SelectInput::Quit,
                             _ => SelectInput::Other,
                         };
@@ -611,20 +770,39 @@ fn main() -> io::Result<()> {
                                 current_screen = Screen::CharacterCreation;
                             }
                             SelectResult::LoadCharacter(filename) => {
-                                match character_manager.load_character(&filename) {
+                                // Hand-edited or corrupted saves still load, with a warning
+                                let mut checksum_mismatch = false;
+                                let loaded = match character_manager.load_character(&filename) {
+                                    Err(LoadError::ChecksumMismatch) => {
+                                        checksum_mismatch = true;
+                                        character_manager.force_load_character(&filename)
+                                    }
+                                    other => other,
+                                };
+                                match loaded {
                                     Ok(mut state) => {
-                                        // Sanity check: clear stale enemy if HP is impossibly high
+                                        if let Some(warning) = settings_warning.take() {
+                                            state.combat_state.add_log_entry(
+                                                format!("\u{26a0} {}", warning),
+                                                false,
+                                                false,
+                                            );
+                                        }
+                                        if checksum_mismatch {
+                                            state.combat_state.add_log_entry(
+                                                "\u{26a0} Save checksum mismatch: this file was edited or corrupted".to_string(),
+                                                false,
+                                                false,
+                                            );
+                                        }
+                                        // Sanity check: clear stale enemies, clamp HP and zones
                                         // (can happen if save was from before prestige reset)
-                                        let derived = character::derived_stats::DerivedStats::calculate_derived_stats(
-                                            &state.attributes,
-                                            &state.equipment,
-                                        );
-                                        if let Some(enemy) = &state.combat_state.current_enemy {
-                                            // Max possible enemy HP is 2.4x player HP (boss with max variance)
-                                            // If enemy HP is > 2.5x, it's stale from before a stat reset
-                                            if enemy.max_hp > (derived.max_hp as f64 * 2.5) as u32 {
-                                                state.combat_state.current_enemy = None;
-                                            }
+                                        for action in state.validate_and_repair() {
+                                            state.combat_state.add_log_entry(
+                                                format!("\u{1f527} Save repaired: {}", action),
+                                                false,
+                                                false,
+                                            );
                                         }
 
                                         // Sync achievements from character state (retroactive unlocks)
@@ -654,9 +832,11 @@ fn main() -> io::Result<()> {
                                         let elapsed_seconds = current_time - state.last_save_time;
 
                                         if elapsed_seconds > 60 {
-                                            if let Some(report) =
-                                                apply_offline_xp(&mut state, &haven)
-                                            {
+                                            if let Some(report) = apply_offline_xp(
+                                                &mut state,
+                                                &haven,
+                                                settings.number_format,
+                                            ) {
                                                 pending_offline_report = Some(report);
                                             }
                                         }
@@ -685,6 +865,25 @@ fn main() -> io::Result<()> {
                                 rename_screen = CharacterRenameScreen::new();
                                 current_screen = Screen::CharacterRename;
                             }
+                            SelectResult::RestoreDeleted => {
+                                match character_manager.list_deleted() {
+                                    Ok(deleted) => {
+                                        if let Some(latest) = deleted.into_iter().next() {
+                                            if let Err(e) = character_manager
+                                                .restore_character(&latest.filename)
+                                            {
+                                                select_screen.status_message = Some(e.to_string());
+                                            }
+                                        }
+                                    }
+                                    Err(e) => {
+                                        select_screen.status_message = Some(format!(
+                                            "Failed to list deleted characters: {}",
+                                            e
+                                        ));
+                                    }
+                                }
+                            }
                             SelectResult::Quit => {
                                 break;
                             }
@@ -799,10 +998,14 @@ fn main() -> io::Result<()> {
                 let mut state = game_state
                     .take()
                     .expect("Game state should be initialized when entering Game screen");
+                if let Some(seed) = dungeon_seed.take() {
+                    state.queued_dungeon_seed = Some(seed);
+                }
 
                 // Run the game loop
                 let mut last_tick = Instant::now();
                 let mut last_autosave = Instant::now();
+                let mut last_stats_write: Option<Instant> = None;
                 let mut last_update_check = Instant::now();
                 let mut next_update_check_interval = jittered_update_interval();
                 let mut tick_counter: u32 = 0;
@@ -822,19 +1025,46 @@ fn main() -> io::Result<()> {
 
                 // Update check state - start initial background check immediately
                 let mut update_info: Option<UpdateInfo> = None;
-                let mut update_check_completed = false;
+                let mut last_completed_check: Option<CompletedUpdateCheck> = None;
+                let mut update_backoff = UpdateBackoff::default();
                 let mut update_expanded = false;
-                let mut update_check_handle: Option<std::thread::JoinHandle<Option<UpdateInfo>>> =
+
+                // External notification hooks (accessibility, overlays, audio)
+                let mut notification_sink = core::notifications::NoopNotificationSink;
+                let mut update_check_handle: Option<std::thread::JoinHandle<UpdateCheckResult>> =
                     Some(std::thread::spawn(utils::updater::check_update_info));
 
                 'game_loop: loop {
+                    if shutdown.requested() {
+                        if !saves_disabled {
+                            if let Err(e) = utils::shutdown::save_session(
+                                &character_manager,
+                                Some(&state),
+                                &haven,
+                                &global_achievements,
+                            ) {
+                                eprintln!("Failed to save on shutdown: {}", e);
+                            }
+                        }
+                        break 'main_loop;
+                    }
+
                     // Check if background update check completed
                     if let Some(handle) = update_check_handle.take() {
                         if handle.is_finished() {
-                            if let Ok(info) = handle.join() {
-                                update_info = info;
+                            let result = handle.join().unwrap_or_else(|_| {
+                                UpdateCheckResult::Error("update check panicked".to_string())
+                            });
+                            update_backoff.record(&result);
+                            next_update_check_interval =
+                                update_backoff.next_interval(jittered_update_interval());
+                            if let UpdateCheckResult::Available(info) = &result {
+                                update_info = Some(info.clone());
                             }
-                            update_check_completed = true;
+                            last_completed_check = Some(CompletedUpdateCheck {
+                                result,
+                                checked_at: chrono::Local::now(),
+                            });
                         } else {
                             // Not finished yet, put it back
                             update_check_handle = Some(handle);
@@ -859,7 +1089,7 @@ fn main() -> io::Result<()> {
                             &state,
                             update_info.as_ref(),
                             update_expanded,
-                            update_check_completed,
+                            last_completed_check.as_ref(),
                             haven.discovered,
                             &global_achievements,
                         );
@@ -872,6 +1102,7 @@ fn main() -> io::Result<()> {
                             &global_achievements,
                             debug_mode,
                             &debug_menu,
+                            settings.number_format,
                             last_save_instant,
                             last_save_time,
                             &ctx,
@@ -922,13 +1153,13 @@ fn main() -> io::Result<()> {
                                 &mut state,
                                 &mut global_achievements,
                                 prestige_before,
-                                debug_mode,
+                                saves_disabled,
                             );
 
                             match result {
                                 InputResult::Continue => {}
                                 InputResult::QuitToSelect => {
-                                    if !debug_mode {
+                                    if !saves_disabled {
                                         character_manager.save_character(&state)?;
                                         // Save achievements when quitting to character select
                                         achievements::save_achievements(&global_achievements)?;
@@ -938,14 +1169,14 @@ fn main() -> io::Result<()> {
                                     break 'game_loop;
                                 }
                                 InputResult::NeedsSave => {
-                                    if !debug_mode {
+                                    if !saves_disabled {
                                         let _ = character_manager.save_character(&state);
                                         last_save_instant = Some(Instant::now());
                                         last_save_time = Some(Local::now());
                                     }
                                 }
                                 InputResult::NeedsSaveAll => {
-                                    if !debug_mode {
+                                    if !saves_disabled {
                                         let _ = character_manager.save_character(&state);
                                         // Only save Haven if it has been discovered
                                         if haven.discovered {
@@ -957,6 +1188,81 @@ fn main() -> io::Result<()> {
                                 }
                                 InputResult::ToggleUpdateDetails => {
                                     update_expanded = !update_expanded;
+                                    if let (true, Some(info)) = (update_expanded, &update_info) {
+                                        let _ =
+                                            utils::updater::mark_changelog_seen(&info.new_commit);
+                                    }
+                                }
+                                InputResult::CycleEffects => {
+                                    settings.effects_intensity = settings.effects_intensity.next();
+                                    state.combat_state.visual_effects.clear();
+                                    state.combat_state.add_log_entry(
+                                        format!(
+                                            "Combat effects: {}",
+                                            settings.effects_intensity.name()
+                                        ),
+                                        false,
+                                        true,
+                                    );
+                                    if let Err(e) = settings.save() {
+                                        eprintln!("Failed to save settings: {}", e);
+                                    }
+                                }
+                                InputResult::CycleLogFilter => {
+                                    settings.log_filter = settings.log_filter.next();
+                                    state.combat_state.add_log_entry(
+                                        format!("Combat log: {}", settings.log_filter.name()),
+                                        false,
+                                        true,
+                                    );
+                                    if let Err(e) = settings.save() {
+                                        eprintln!("Failed to save settings: {}", e);
+                                    }
+                                }
+                                InputResult::CycleHpDisplay => {
+                                    settings.hp_display = settings.hp_display.next();
+                                    state.combat_state.hp_display = settings.hp_display;
+                                    state.combat_state.add_log_entry(
+                                        format!("HP bars: {}", settings.hp_display.name()),
+                                        false,
+                                        true,
+                                    );
+                                    if let Err(e) = settings.save() {
+                                        eprintln!("Failed to save settings: {}", e);
+                                    }
+                                }
+                                InputResult::CycleNumberFormat => {
+                                    settings.number_format = settings.number_format.next();
+                                    state.combat_state.add_log_entry(
+                                        format!("Number format: {}", settings.number_format.name()),
+                                        false,
+                                        true,
+                                    );
+                                    if let Err(e) = settings.save() {
+                                        eprintln!("Failed to save settings: {}", e);
+                                    }
+                                }
+                                InputResult::OpenAutoChallenge => {
+                                    // Start on the current setting, or Novice when off
+                                    overlay = GameOverlay::AutoChallengeConfirm {
+                                        choice: settings
+                                            .auto_challenge
+                                            .or(Some(challenges::ChallengeDifficulty::Novice)),
+                                    };
+                                }
+                                InputResult::SetAutoChallenge(choice) => {
+                                    settings.auto_challenge = choice;
+                                    state.combat_state.add_log_entry(
+                                        format!(
+                                            "Auto-challenge: {}",
+                                            settings.auto_challenge.map_or("Off", |d| d.name())
+                                        ),
+                                        false,
+                                        true,
+                                    );
+                                    if let Err(e) = settings.save() {
+                                        eprintln!("Failed to save settings: {}", e);
+                                    }
                                 }
                             }
                         }
@@ -1006,7 +1312,9 @@ fn main() -> io::Result<()> {
                         if elapsed_since_save > 60
                             && !matches!(overlay, GameOverlay::OfflineWelcome { .. })
                         {
-                            if let Some(report) = apply_offline_xp(&mut state, &haven) {
+                            if let Some(report) =
+                                apply_offline_xp(&mut state, &haven, settings.number_format)
+                            {
                                 overlay = GameOverlay::OfflineWelcome { report };
                             }
                             // Reset tick timers to prevent stale Instant from
@@ -1014,7 +1322,7 @@ fn main() -> io::Result<()> {
                             last_tick = Instant::now();
                             last_autosave = Instant::now();
                             // Immediate save with updated last_save_time
-                            if !debug_mode {
+                            if !saves_disabled {
                                 character_manager.save_character(&state)?;
                                 if haven.discovered {
                                     haven::save_haven(&haven).ok();
@@ -1035,12 +1343,32 @@ fn main() -> io::Result<()> {
                                 &mut tick_counter,
                                 &mut haven,
                                 &mut global_achievements,
-                                debug_mode,
+                                saves_disabled,
                                 &mut rng,
+                                chrono::Utc::now().timestamp(),
+                            );
+                            core::notifications::notify_tick_events(
+                                &tick_result.events,
+                                &mut notification_sink,
                             );
 
-                            let haven_discovered =
-                                apply_tick_events(&mut state, &tick_result.events);
+                            state.combat_state.log_filter = settings.log_filter;
+                            state.combat_state.hp_display = settings.hp_display;
+                            state.combat_state.max_visual_effects = settings.max_visual_effects;
+                            let haven_discovered = apply_tick_events(
+                                &mut state,
+                                &tick_result.events,
+                                settings.effects_intensity,
+                            );
+
+                            if let Some(difficulty) = settings.auto_challenge {
+                                challenges::menu::auto_resolve_challenge(
+                                    &mut state,
+                                    difficulty,
+                                    Local::now().date_naive(),
+                                    &mut rng,
+                                );
+                            }
 
                             // Update visual effect lifetimes
                             let delta_time = TICK_INTERVAL_MS as f64 / 1000.0;
@@ -1050,7 +1378,7 @@ fn main() -> io::Result<()> {
                                 .retain_mut(|effect| effect.update(delta_time));
 
                             // Persist achievements if changed
-                            if tick_result.achievements_changed && !debug_mode {
+                            if tick_result.achievements_changed && !saves_disabled {
                                 if let Err(e) =
                                     achievements::save_achievements(&global_achievements)
                                 {
@@ -1062,13 +1390,25 @@ fn main() -> io::Result<()> {
                                 overlay = GameOverlay::LeviathanEncounter { encounter_number };
                             }
 
-                            if tick_result.haven_changed && !debug_mode {
+                            if tick_result.haven_changed && !saves_disabled {
                                 haven::save_haven(&haven).ok();
                             }
                             if haven_discovered {
                                 overlay = GameOverlay::HavenDiscovery;
                             }
 
+                            if let Some(step) = tick_result.tutorial_hint {
+                                if matches!(overlay, GameOverlay::None) {
+                                    overlay = GameOverlay::TutorialHint { step };
+                                } else {
+                                    state.combat_state.add_log_entry(
+                                        format!("\u{1f4d6} {}", step.hint()),
+                                        false,
+                                        false,
+                                    );
+                                }
+                            }
+
                             if matches!(overlay, GameOverlay::None)
                                 && !tick_result.achievement_modal_ready.is_empty()
                             {
@@ -1080,6 +1420,25 @@ fn main() -> io::Result<()> {
                         last_tick = Instant::now();
                     }
 
+                    // Overlay snapshot for --stats-file. A failed write is reported
+                    // once in the combat log (stderr would garble the TUI) and the
+                    // file is not retried.
+                    if let Some(path) = &stats_file {
+                        if last_stats_write.is_none_or(|t| {
+                            t.elapsed() >= Duration::from_secs(STATS_FILE_INTERVAL_SECONDS)
+                        }) {
+                            if let Err(e) = core::snapshot::write_stats_file(&state, path) {
+                                state.combat_state.add_log_entry(
+                                    format!("Stats file disabled: {}", e),
+                                    false,
+                                    true,
+                                );
+                                stats_file = None;
+                            }
+                            last_stats_write = Some(Instant::now());
+                        }
+                    }
+
                     // Auto-save every 30 seconds
                     if last_autosave.elapsed() >= Duration::from_secs(AUTOSAVE_INTERVAL_SECONDS) {
                         // Sync in-memory last_save_time so suspension detection
@@ -1089,7 +1448,7 @@ fn main() -> io::Result<()> {
                         last_save_time = Some(Local::now());
 
                         // Skip file I/O in debug mode
-                        if !debug_mode {
+                        if !saves_disabled {
                             character_manager.save_character(&state)?;
                             if haven.discovered {
                                 haven::save_haven(&haven)?;
@@ -1099,7 +1458,8 @@ fn main() -> io::Result<()> {
                         }
                     }
 
-                    // Periodic update check (every ~30 minutes with jitter)
+                    // Periodic update check (every ~30 minutes with jitter, backing
+                    // off exponentially after consecutive failures)
                     // Only start a new check if we don't have one running and haven't found an update
                     if update_info.is_none()
                         && update_check_handle.is_none()
@@ -1107,9 +1467,8 @@ fn main() -> io::Result<()> {
                     {
                         update_check_handle =
                             Some(std::thread::spawn(utils::updater::check_update_info));
-                        update_check_completed = false; // Reset to show "Checking..." again
+                        last_completed_check = None; // Reset to show "Checking..." again
                         last_update_check = Instant::now();
-                        next_update_check_interval = jittered_update_interval();
                     }
                 }
             }
@@ -0,0 +1,172 @@
+//! AFK pacing analysis for balance review.
+//!
+//! `afk_survival` plays a zone's regular enemies with no input at all: no
+//! gear, Haven, potions or stance changes, and the character never levels,
+//! travels or fights the subzone boss. HP only comes back through the normal
+//! regen after each kill, so a death means some fight in the zone deals more
+//! damage than a full HP bar can absorb. Zones where reasonable builds die
+//! here quietly require active play. Rolls come from a fixed-seed ChaCha8
+//! RNG, so a report is reproducible. `simulator --afk N` prints a report per
+//! zone.
+
+use crate::achievements::Achievements;
+use crate::character::attributes::Attributes;
+use crate::character::derived_stats::DerivedStats;
+use crate::character::prestige::PrestigeCombatBonuses;
+use crate::combat::logic::{update_combat_with_rng, CombatEvent, HavenCombatBonuses};
+use crate::core::constants::TICK_INTERVAL_MS;
+use crate::core::game_logic::spawn_enemy_if_needed_with_rng;
+use crate::core::game_state::GameState;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+const AFK_SEED: u64 = 0xAF;
+
+/// The character sent AFK into a zone. Level is left out: combat only
+/// reads attributes and prestige.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AfkBuild {
+    pub prestige_rank: u32,
+    pub attributes: Attributes,
+}
+
+impl AfkBuild {
+    /// A build with every attribute at `attribute_value`.
+    pub fn uniform(prestige_rank: u32, attribute_value: u32) -> Self {
+        let v = attribute_value;
+        Self {
+            prestige_rank,
+            attributes: Attributes::from_bonuses(v, v, v, v, v, v),
+        }
+    }
+}
+
+/// Outcome of an AFK run.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AfkReport {
+    pub zone_id: u32,
+    pub ticks: u64,
+    pub kills: u64,
+    pub deaths: u64,
+    /// Tick of the first death, None if the character never died
+    pub first_death_tick: Option<u64>,
+    /// Lowest HP reached, as a fraction of max HP
+    pub lowest_hp_fraction: f64,
+}
+
+impl AfkReport {
+    /// True if the character got through the whole run without dying.
+    pub fn survives_indefinitely(&self) -> bool {
+        self.deaths == 0
+    }
+
+    pub fn summary(&self) -> String {
+        let outcome = match self.first_death_tick {
+            None => "survives".to_string(),
+            Some(tick) => format!("dies (first at tick {})", tick),
+        };
+        format!(
+            "zone={} ticks={} kills={} deaths={} lowest_hp={:.0}% {}",
+            self.zone_id,
+            self.ticks,
+            self.kills,
+            self.deaths,
+            self.lowest_hp_fraction * 100.0,
+            outcome
+        )
+    }
+}
+
+/// Leaves `build` fighting regular enemies in the first subzone of `zone_id`
+/// for `ticks` game ticks (10 per second) and reports whether it survives.
+pub fn afk_survival(zone_id: u32, build: AfkBuild, ticks: u64) -> AfkReport {
+    let mut rng = ChaCha8Rng::seed_from_u64(AFK_SEED);
+    let mut state = GameState::new("AFK".to_string(), 0);
+    state.prestige_rank = build.prestige_rank;
+    state.attributes = build.attributes;
+    state.zone_progression.current_zone_id = zone_id;
+    state.zone_progression.current_subzone_id = 1;
+
+    let mut achievements = Achievements::default();
+    let haven = HavenCombatBonuses::default();
+    let prestige = PrestigeCombatBonuses::from_rank(build.prestige_rank);
+    let derived = DerivedStats::calculate_derived_stats(&state.attributes, &state.equipment);
+    state.combat_state.player_max_hp = derived.max_hp + prestige.flat_hp;
+    state.combat_state.player_current_hp = state.combat_state.player_max_hp;
+    let delta_time = TICK_INTERVAL_MS as f64 / 1000.0;
+
+    let mut kills = 0;
+    let mut deaths = 0;
+    let mut first_death_tick = None;
+    let mut lowest_hp_fraction: f64 = 1.0;
+
+    for tick in 0..ticks {
+        // Never reach the boss, so the character stays in this subzone
+        state.zone_progression.kills_in_subzone = 0;
+        spawn_enemy_if_needed_with_rng(&mut state, None, &mut rng);
+        let events = update_combat_with_rng(
+            &mut state,
+            delta_time,
+            &haven,
+            &prestige,
+            &mut achievements,
+            &derived,
+            &mut rng,
+        );
+
+        let combat = &state.combat_state;
+        lowest_hp_fraction = lowest_hp_fraction
+            .min(combat.player_current_hp as f64 / combat.player_max_hp.max(1) as f64);
+
+        for event in events {
+            match event {
+                CombatEvent::EnemyDied { .. } => kills += 1,
+                CombatEvent::PlayerDied => {
+                    deaths += 1;
+                    first_death_tick.get_or_insert(tick);
+                    lowest_hp_fraction = 0.0;
+                }
+                _ => {}
+            }
+        }
+    }
+
+    AfkReport {
+        zone_id,
+        ticks,
+        kills,
+        deaths,
+        first_death_tick,
+        lowest_hp_fraction,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// One hour of play
+    const AFK_TICKS: u64 = 36_000;
+
+    #[test]
+    fn test_strong_build_survives_indefinitely() {
+        let report = afk_survival(1, AfkBuild::uniform(20, 60), AFK_TICKS);
+        assert!(report.survives_indefinitely(), "{}", report.summary());
+        assert!(report.kills > 0);
+        assert_eq!(report.first_death_tick, None);
+    }
+
+    #[test]
+    fn test_weak_build_eventually_dies() {
+        let report = afk_survival(8, AfkBuild::uniform(0, 10), AFK_TICKS);
+        assert!(!report.survives_indefinitely(), "{}", report.summary());
+        assert!(report.first_death_tick.is_some());
+        assert_eq!(report.lowest_hp_fraction, 0.0);
+    }
+
+    #[test]
+    fn test_report_is_reproducible() {
+        let build = AfkBuild::uniform(2, 16);
+        assert_eq!(afk_survival(3, build, 6_000), afk_survival(3, build, 6_000));
+    }
+}
@@ -0,0 +1,145 @@
+//! Fixed combat benchmark for catching balance drift.
+//!
+//! `run_fixed_scenario` plays overworld combat for a fresh prestige-0 character
+//! with no gear, Haven or prestige bonuses. Every roll (enemy stats, crits,
+//! kill XP, level-up points) comes from a ChaCha8 RNG seeded by the caller, and
+//! loot, dungeons and discoveries are left out, so the same seed and tick count
+//! always produce the same result. Pin the output for a seed to detect any
+//! change to combat or XP tuning.
+
+use crate::achievements::Achievements;
+use crate::character::derived_stats::DerivedStats;
+use crate::character::prestige::PrestigeCombatBonuses;
+use crate::combat::logic::{update_combat_with_rng, CombatEvent, HavenCombatBonuses};
+use crate::core::constants::TICK_INTERVAL_MS;
+use crate::core::game_logic::{apply_tick_xp_with_rng, spawn_enemy_if_needed_with_rng};
+use crate::core::game_state::GameState;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+const SCENARIO_CHARACTER_NAME: &str = "Benchmark";
+
+/// End state of a fixed scenario run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScenarioResult {
+    pub seed: u64,
+    pub ticks: u64,
+    pub level: u32,
+    /// XP toward the next level
+    pub xp: u64,
+    /// Total XP earned from kills over the run
+    pub xp_earned: u64,
+    pub kills: u64,
+    pub deaths: u64,
+    pub zone_id: u32,
+    pub subzone_id: u32,
+}
+
+impl ScenarioResult {
+    pub fn summary(&self) -> String {
+        format!(
+            "seed={} ticks={} level={} xp={} xp_earned={} kills={} deaths={} zone={}-{}",
+            self.seed,
+            self.ticks,
+            self.level,
+            self.xp,
+            self.xp_earned,
+            self.kills,
+            self.deaths,
+            self.zone_id,
+            self.subzone_id
+        )
+    }
+}
+
+/// Runs the fixed combat scenario for `ticks` game ticks (10 per second).
+pub fn run_fixed_scenario(seed: u64, ticks: u64) -> ScenarioResult {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let mut state = GameState::new(SCENARIO_CHARACTER_NAME.to_string(), 0);
+    let mut achievements = Achievements::default();
+    let haven = HavenCombatBonuses::default();
+    let prestige = PrestigeCombatBonuses::default();
+    let delta_time = TICK_INTERVAL_MS as f64 / 1000.0;
+
+    let mut xp_earned = 0;
+    let mut kills = 0;
+    let mut deaths = 0;
+
+    for _ in 0..ticks {
+        spawn_enemy_if_needed_with_rng(&mut state, None, &mut rng);
+        let derived = DerivedStats::calculate_derived_stats(&state.attributes, &state.equipment);
+        let events = update_combat_with_rng(
+            &mut state,
+            delta_time,
+            &haven,
+            &prestige,
+            &mut achievements,
+            &derived,
+            &mut rng,
+        );
+
+        for event in events {
+            match event {
+                CombatEvent::EnemyDied { xp_gained }
+                | CombatEvent::SubzoneBossDefeated { xp_gained, .. } => {
+                    kills += 1;
+                    xp_earned += xp_gained;
+                    apply_tick_xp_with_rng(&mut state, xp_gained as f64, &mut rng);
+                }
+                CombatEvent::PlayerDied => deaths += 1,
+                _ => {}
+            }
+        }
+    }
+
+    ScenarioResult {
+        seed,
+        ticks,
+        level: state.character_level,
+        xp: state.character_xp,
+        xp_earned,
+        kills,
+        deaths,
+        zone_id: state.zone_progression.current_zone_id,
+        subzone_id: state.zone_progression.current_subzone_id,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BENCH_TICKS: u64 = 18_000;
+
+    #[test]
+    fn test_fixed_scenario_is_byte_stable() {
+        let first = run_fixed_scenario(42, BENCH_TICKS);
+        let second = run_fixed_scenario(42, BENCH_TICKS);
+        assert_eq!(first, second);
+        assert_eq!(format!("{:?}", first), format!("{:?}", second));
+    }
+
+    #[test]
+    fn test_fixed_scenario_makes_progress() {
+        let result = run_fixed_scenario(42, BENCH_TICKS);
+        assert!(result.kills > 0);
+        assert!(result.level > 1);
+        assert!(result.xp_earned > 0);
+    }
+
+    #[test]
+    fn test_fixed_scenario_depends_on_seed() {
+        let a = run_fixed_scenario(1, BENCH_TICKS);
+        let b = run_fixed_scenario(2, BENCH_TICKS);
+        assert_ne!((a.xp, a.xp_earned), (b.xp, b.xp_earned));
+    }
+
+    #[test]
+    fn test_zero_ticks_is_fresh_character() {
+        let result = run_fixed_scenario(7, 0);
+        assert_eq!(result.level, 1);
+        assert_eq!(result.kills, 0);
+        assert_eq!(result.xp, 0);
+        assert_eq!((result.zone_id, result.subzone_id), (1, 1));
+    }
+}
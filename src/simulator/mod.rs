@@ -0,0 +1,4 @@
+//! Simulation helpers shared by tests and the `simulator` binary.
+
+pub mod analysis;
+pub mod bench;
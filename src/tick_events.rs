@@ -4,16 +4,25 @@
 //! pure game-logic events from [`core::tick`] to UI types like
 //! [`VisualEffect`] and [`EffectType`].
 
+use crate::character::derived_stats::DerivedStats;
 use crate::core::game_state::GameState;
 use crate::core::tick::TickEvent;
-use crate::ui::combat_effects::{EffectType, VisualEffect};
+use crate::ui::combat_effects::{EffectType, ProjectileKind, VisualEffect};
+use crate::utils::settings::EffectsIntensity;
 
 /// Maps tick events to combat log entries and visual effects.
+/// `effects` limits which visual effects are spawned; the log is always written.
 /// Returns true if the HavenDiscovered event was present.
-pub fn apply_tick_events(game_state: &mut GameState, events: &[TickEvent]) -> bool {
+pub fn apply_tick_events(
+    game_state: &mut GameState,
+    events: &[TickEvent],
+    effects: EffectsIntensity,
+) -> bool {
     let mut haven_discovered = false;
     for event in events {
         match event {
+            // Fast-path kills are covered by their batch's summary line
+            TickEvent::EnemyDefeated { quiet: true, .. } => {}
             TickEvent::PlayerAttack {
                 damage,
                 was_crit,
@@ -21,25 +30,49 @@ pub fn apply_tick_events(game_state: &mut GameState, events: &[TickEvent]) -> bo
             } => {
                 game_state
                     .combat_state
-                    .add_log_entry(message.clone(), *was_crit, true);
+                    .add_hit_log_entry(message.clone(), *was_crit);
 
                 // Spawn damage number effect
-                let damage_effect = VisualEffect::new(
-                    EffectType::DamageNumber {
-                        value: *damage,
-                        is_crit: *was_crit,
-                    },
-                    0.8,
-                );
-                game_state.combat_state.visual_effects.push(damage_effect);
+                if effects.shows_damage_numbers() {
+                    let damage_effect = VisualEffect::new(
+                        EffectType::DamageNumber {
+                            value: *damage,
+                            is_crit: *was_crit,
+                        },
+                        0.8,
+                    );
+                    game_state.combat_state.push_visual_effect(damage_effect);
+                }
 
-                // Spawn attack flash
-                let flash_effect = VisualEffect::new(EffectType::AttackFlash, 0.2);
-                game_state.combat_state.visual_effects.push(flash_effect);
+                if effects.shows_hit_flourishes() {
+                    // Spawn attack flash
+                    let flash_effect = VisualEffect::new(EffectType::AttackFlash, 0.2);
+                    game_state.combat_state.push_visual_effect(flash_effect);
 
-                // Spawn impact effect
-                let impact_effect = VisualEffect::new(EffectType::HitImpact, 0.3);
-                game_state.combat_state.visual_effects.push(impact_effect);
+                    // Spawn a projectile styled for a weapon or spell build
+                    let derived = DerivedStats::calculate_derived_stats(
+                        &game_state.attributes,
+                        &game_state.equipment,
+                    );
+                    let projectile_effect = VisualEffect::new(
+                        EffectType::Projectile {
+                            kind: ProjectileKind::for_build(&derived),
+                        },
+                        0.25,
+                    );
+                    game_state
+                        .combat_state
+                        .push_visual_effect(projectile_effect);
+
+                    // Spawn impact effect
+                    let impact_effect = VisualEffect::new(EffectType::HitImpact, 0.3);
+                    game_state.combat_state.push_visual_effect(impact_effect);
+                }
+            }
+            TickEvent::DamageBreakdown { message } => {
+                game_state
+                    .combat_state
+                    .add_log_entry(message.clone(), false, true);
             }
             TickEvent::PlayerAttackBlocked { message, .. } => {
                 game_state
@@ -51,11 +84,53 @@ pub fn apply_tick_events(game_state: &mut GameState, events: &[TickEvent]) -> bo
                     .combat_state
                     .add_log_entry(message.clone(), false, false);
             }
-            TickEvent::EnemyDefeated { message, .. } => {
+            TickEvent::Parry { message, .. } => {
+                game_state
+                    .combat_state
+                    .add_log_entry(message.clone(), false, true);
+            }
+            TickEvent::SegmentDestroyed { message, .. } => {
+                game_state
+                    .combat_state
+                    .add_log_entry(message.clone(), false, true);
+            }
+            TickEvent::BossPhaseChanged { message, .. } => {
+                game_state
+                    .combat_state
+                    .add_log_entry(message.clone(), false, false);
+            }
+            TickEvent::ComboMilestone { message, .. } => {
+                game_state
+                    .combat_state
+                    .add_log_entry(message.clone(), false, true);
+            }
+            TickEvent::EmergencyHeal { message, .. } => {
+                game_state
+                    .combat_state
+                    .add_log_entry(message.clone(), false, true);
+            }
+            TickEvent::GemFound { message } => {
+                game_state
+                    .combat_state
+                    .add_log_entry(message.clone(), false, true);
+            }
+            TickEvent::KillXpClamped { message, .. } => {
+                game_state
+                    .combat_state
+                    .add_log_entry(message.clone(), false, false);
+            }
+            TickEvent::EnemyDefeated { message, .. }
+            | TickEvent::FastResolveSummary { message, .. } => {
                 game_state
                     .combat_state
                     .add_log_entry(message.clone(), false, true);
             }
+            TickEvent::EliteHuntSpawned { message, .. }
+            | TickEvent::EliteHuntEscaped { message, .. } => {
+                game_state
+                    .combat_state
+                    .add_log_entry(message.clone(), false, false);
+            }
             TickEvent::PlayerDied { message } | TickEvent::PlayerDiedInDungeon { message } => {
                 game_state
                     .combat_state
@@ -64,6 +139,9 @@ pub fn apply_tick_events(game_state: &mut GameState, events: &[TickEvent]) -> bo
             TickEvent::ItemDropped { .. } => {
                 // Item drops and recent_drops tracking are handled inside game_tick
             }
+            TickEvent::ItemSalvaged { .. } => {
+                // Filtered drops are salvaged silently to keep the log uncluttered
+            }
             TickEvent::SubzoneBossDefeated { message, .. } => {
                 game_state
                     .combat_state
@@ -112,7 +190,8 @@ pub fn apply_tick_events(game_state: &mut GameState, events: &[TickEvent]) -> bo
                     .combat_state
                     .add_log_entry(message.clone(), false, true);
             }
-            TickEvent::AchievementUnlocked { message, .. } => {
+            TickEvent::AchievementUnlocked { message, .. }
+            | TickEvent::AchievementCategoryCompleted { message, .. } => {
                 game_state
                     .combat_state
                     .add_log_entry(message.clone(), false, true);
@@ -127,3 +206,77 @@ pub fn apply_tick_events(game_state: &mut GameState, events: &[TickEvent]) -> bo
     }
     haven_discovered
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::character::attributes::AttributeType;
+
+    fn attack_events() -> Vec<TickEvent> {
+        vec![TickEvent::PlayerAttack {
+            damage: 12,
+            was_crit: false,
+            message: "You hit for 12 damage".to_string(),
+        }]
+    }
+
+    fn apply_with(effects: EffectsIntensity) -> GameState {
+        let mut state = GameState::new("Effects".to_string(), 0);
+        apply_tick_events(&mut state, &attack_events(), effects);
+        state
+    }
+
+    #[test]
+    fn test_full_effects_spawn_all_hit_effects() {
+        let state = apply_with(EffectsIntensity::Full);
+        assert_eq!(state.combat_state.visual_effects.len(), 4);
+    }
+
+    fn projectile_kind(state: &GameState) -> Option<ProjectileKind> {
+        state
+            .combat_state
+            .visual_effects
+            .iter()
+            .find_map(|effect| match effect.effect_type {
+                EffectType::Projectile { kind } => Some(kind),
+                _ => None,
+            })
+    }
+
+    #[test]
+    fn test_high_int_build_fires_magical_projectile() {
+        let mut state = GameState::new("Mage".to_string(), 0);
+        state.attributes.set(AttributeType::Intelligence, 20);
+        apply_tick_events(&mut state, &attack_events(), EffectsIntensity::Full);
+        assert_eq!(projectile_kind(&state), Some(ProjectileKind::Magical));
+    }
+
+    #[test]
+    fn test_high_str_build_fires_physical_projectile() {
+        let mut state = GameState::new("Warrior".to_string(), 0);
+        state.attributes.set(AttributeType::Strength, 20);
+        apply_tick_events(&mut state, &attack_events(), EffectsIntensity::Full);
+        assert_eq!(projectile_kind(&state), Some(ProjectileKind::Physical));
+    }
+
+    #[test]
+    fn test_reduced_effects_spawn_only_damage_numbers() {
+        let state = apply_with(EffectsIntensity::Reduced);
+        assert_eq!(state.combat_state.visual_effects.len(), 1);
+        assert!(matches!(
+            state.combat_state.visual_effects[0].effect_type,
+            EffectType::DamageNumber { value: 12, .. }
+        ));
+    }
+
+    #[test]
+    fn test_effects_off_spawns_nothing_but_still_logs() {
+        let state = apply_with(EffectsIntensity::Off);
+        assert!(state.combat_state.visual_effects.is_empty());
+        assert!(state
+            .combat_state
+            .combat_log
+            .iter()
+            .any(|entry| entry.message == "You hit for 12 damage"));
+    }
+}
@@ -160,14 +160,27 @@ fn render_category_tabs(
         } else {
             let (unlocked, total) = achievements.count_by_category(cat);
             let new_count = achievements.count_recently_unlocked_by_category(cat);
+            // A star marks categories whose completion title was earned
+            let star = if achievements.category_complete(cat) {
+                "\u{2605}"
+            } else {
+                ""
+            };
             if new_count > 0 {
                 spans.push(Span::styled(
-                    format!(" {} ({}/{}) +{} ", cat.name(), unlocked, total, new_count),
+                    format!(
+                        " {} ({}/{}){} +{} ",
+                        cat.name(),
+                        unlocked,
+                        total,
+                        star,
+                        new_count
+                    ),
                     style,
                 ));
             } else {
                 spans.push(Span::styled(
-                    format!(" {} ({}/{}) ", cat.name(), unlocked, total),
+                    format!(" {} ({}/{}){} ", cat.name(), unlocked, total, star),
                     style,
                 ));
             }
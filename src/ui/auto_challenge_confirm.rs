@@ -0,0 +1,96 @@
+use crate::challenges::ChallengeDifficulty;
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// Draws the picker shown before the auto-challenge setting is changed
+pub fn draw_auto_challenge_confirm(
+    frame: &mut Frame,
+    choice: Option<ChallengeDifficulty>,
+    _ctx: &super::responsive::LayoutContext,
+) {
+    let size = frame.area();
+
+    let dialog_width = 56.min(size.width.saturating_sub(4));
+    let dialog_height = 13.min(size.height.saturating_sub(4));
+
+    let x = (size.width.saturating_sub(dialog_width)) / 2;
+    let y = (size.height.saturating_sub(dialog_height)) / 2;
+
+    let dialog_area = Rect::new(x, y, dialog_width, dialog_height);
+
+    frame.render_widget(Clear, dialog_area);
+
+    let title = Line::from(vec![Span::styled(
+        " Auto-Challenge ",
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
+    )]);
+
+    let explanation = match choice {
+        Some(_) => vec![
+            Line::from("Chess, Morris, Gomoku and Go are played for you"),
+            Line::from("in the background: a win pays the normal reward,"),
+            Line::from("a loss pays nothing. Skill games are forfeited."),
+        ],
+        None => vec![
+            Line::from(""),
+            Line::from("Challenges wait in the menu for you to play."),
+            Line::from(""),
+        ],
+    };
+
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(vec![
+            Span::raw("◀ "),
+            Span::styled(
+                choice.map_or("Off", |d| d.name()),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(" ▶"),
+        ]),
+        Line::from(""),
+    ];
+    lines.extend(explanation);
+    lines.extend([
+        Line::from(""),
+        Line::from(Span::styled(
+            "[←/→] Change difficulty",
+            Style::default().fg(Color::DarkGray),
+        )),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled(
+                "[Y] Confirm",
+                Style::default()
+                    .fg(Color::Green)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw("    "),
+            Span::styled(
+                "[N] Cancel",
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            ),
+        ]),
+    ]);
+
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .title(title)
+                .title_alignment(Alignment::Center)
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Yellow)),
+        )
+        .alignment(Alignment::Center);
+
+    frame.render_widget(paragraph, dialog_area);
+}
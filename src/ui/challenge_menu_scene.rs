@@ -5,7 +5,9 @@ use crate::challenges::flappy::FlappyBirdDifficulty;
 use crate::challenges::go::GoDifficulty;
 use crate::challenges::gomoku::GomokuDifficulty;
 use crate::challenges::jezzball::JezzballDifficulty;
-use crate::challenges::menu::{ChallengeMenu, ChallengeType, DifficultyInfo};
+use crate::challenges::menu::{
+    todays_featured_challenge, ChallengeMenu, ChallengeType, DifficultyInfo,
+};
 use crate::challenges::minesweeper::MinesweeperDifficulty;
 use crate::challenges::morris::MorrisDifficulty;
 use crate::challenges::rune::RuneDifficulty;
@@ -23,12 +25,13 @@ pub fn render_challenge_menu(
     frame: &mut Frame,
     area: Rect,
     menu: &ChallengeMenu,
+    prestige_rank: u32,
     _ctx: &super::responsive::LayoutContext,
 ) {
     frame.render_widget(Clear, area);
 
     if menu.viewing_detail && !menu.challenges.is_empty() {
-        render_detail_view(frame, area, menu);
+        render_detail_view(frame, area, menu, prestige_rank);
     } else {
         render_list_view(frame, area, menu);
     }
@@ -50,6 +53,7 @@ fn render_list_view(frame: &mut Frame, area: Rect, menu: &ChallengeMenu) {
         return;
     }
 
+    let featured = todays_featured_challenge();
     let items: Vec<ListItem> = menu
         .challenges
         .iter()
@@ -63,7 +67,17 @@ fn render_list_view(frame: &mut Frame, area: Rect, menu: &ChallengeMenu) {
             } else {
                 Style::default().fg(Color::White)
             };
-            ListItem::new(format!("{}{} {}", prefix, challenge.icon, challenge.title)).style(style)
+            let mut spans = vec![Span::styled(
+                format!("{}{} {}", prefix, challenge.icon, challenge.title),
+                style,
+            )];
+            if challenge.challenge_type == featured {
+                spans.push(Span::styled(
+                    " \u{2605} Featured",
+                    Style::default().fg(Color::LightYellow),
+                ));
+            }
+            ListItem::new(Line::from(spans))
         })
         .collect();
 
@@ -84,7 +98,7 @@ fn render_list_view(frame: &mut Frame, area: Rect, menu: &ChallengeMenu) {
     }
 }
 
-fn render_detail_view(frame: &mut Frame, area: Rect, menu: &ChallengeMenu) {
+fn render_detail_view(frame: &mut Frame, area: Rect, menu: &ChallengeMenu, prestige_rank: u32) {
     let challenge = &menu.challenges[menu.selected_index];
 
     let block = Block::default()
@@ -102,7 +116,13 @@ fn render_detail_view(frame: &mut Frame, area: Rect, menu: &ChallengeMenu) {
         .split(inner);
 
     const DIFFICULTY_HEIGHT: u16 = 12;
-    const TAIL_HEIGHT_WITHOUT_DIFFICULTY: u16 = 3; // spacer + spacer + outcomes
+    // Go adds a handicap line under the difficulty selector
+    let options_height: u16 = if challenge.challenge_type == ChallengeType::Go {
+        1
+    } else {
+        0
+    };
+    let tail_height_without_difficulty = 3 + options_height; // spacer + spacer + options + outcomes
 
     // Size description to actual wrapped text height so difficulty options sit
     // directly below it, instead of being visually pinned near the bottom.
@@ -110,14 +130,14 @@ fn render_detail_view(frame: &mut Frame, area: Rect, menu: &ChallengeMenu) {
         estimate_wrapped_line_count(&challenge.description, outer_chunks[0].width.max(1));
     let max_desc_for_full_difficulty = outer_chunks[0]
         .height
-        .saturating_sub(DIFFICULTY_HEIGHT + TAIL_HEIGHT_WITHOUT_DIFFICULTY)
+        .saturating_sub(DIFFICULTY_HEIGHT + tail_height_without_difficulty)
         .max(1);
     let description_height = wrapped_lines.clamp(1, max_desc_for_full_difficulty);
 
     let difficulty_height = DIFFICULTY_HEIGHT.min(
         outer_chunks[0]
             .height
-            .saturating_sub(description_height + TAIL_HEIGHT_WITHOUT_DIFFICULTY),
+            .saturating_sub(description_height + tail_height_without_difficulty),
     );
 
     let chunks = Layout::default()
@@ -127,6 +147,7 @@ fn render_detail_view(frame: &mut Frame, area: Rect, menu: &ChallengeMenu) {
             Constraint::Length(1),                  // Spacer
             Constraint::Length(difficulty_height),  // Difficulty selector
             Constraint::Length(1),                  // Spacer
+            Constraint::Length(options_height),     // Go handicap
             Constraint::Length(1),                  // Outcomes
         ])
         .split(outer_chunks[0]);
@@ -145,6 +166,7 @@ fn render_detail_view(frame: &mut Frame, area: Rect, menu: &ChallengeMenu) {
                 chunks[2],
                 &ChessDifficulty::ALL,
                 menu.selected_difficulty,
+                prestige_rank,
             );
         }
         ChallengeType::Morris => {
@@ -153,6 +175,7 @@ fn render_detail_view(frame: &mut Frame, area: Rect, menu: &ChallengeMenu) {
                 chunks[2],
                 &MorrisDifficulty::ALL,
                 menu.selected_difficulty,
+                prestige_rank,
             );
         }
         ChallengeType::Gomoku => {
@@ -161,6 +184,7 @@ fn render_detail_view(frame: &mut Frame, area: Rect, menu: &ChallengeMenu) {
                 chunks[2],
                 &GomokuDifficulty::ALL,
                 menu.selected_difficulty,
+                prestige_rank,
             );
         }
         ChallengeType::Minesweeper => {
@@ -169,6 +193,7 @@ fn render_detail_view(frame: &mut Frame, area: Rect, menu: &ChallengeMenu) {
                 chunks[2],
                 &MinesweeperDifficulty::ALL,
                 menu.selected_difficulty,
+                prestige_rank,
             );
         }
         ChallengeType::Rune => {
@@ -177,6 +202,7 @@ fn render_detail_view(frame: &mut Frame, area: Rect, menu: &ChallengeMenu) {
                 chunks[2],
                 &RuneDifficulty::ALL,
                 menu.selected_difficulty,
+                prestige_rank,
             );
         }
         ChallengeType::Go => {
@@ -185,6 +211,7 @@ fn render_detail_view(frame: &mut Frame, area: Rect, menu: &ChallengeMenu) {
                 chunks[2],
                 &GoDifficulty::ALL,
                 menu.selected_difficulty,
+                prestige_rank,
             );
         }
         ChallengeType::FlappyBird => {
@@ -193,6 +220,7 @@ fn render_detail_view(frame: &mut Frame, area: Rect, menu: &ChallengeMenu) {
                 chunks[2],
                 &FlappyBirdDifficulty::ALL,
                 menu.selected_difficulty,
+                prestige_rank,
             );
         }
         ChallengeType::Jezzball => {
@@ -201,6 +229,7 @@ fn render_detail_view(frame: &mut Frame, area: Rect, menu: &ChallengeMenu) {
                 chunks[2],
                 &JezzballDifficulty::ALL,
                 menu.selected_difficulty,
+                prestige_rank,
             );
         }
         ChallengeType::Snake => {
@@ -209,10 +238,25 @@ fn render_detail_view(frame: &mut Frame, area: Rect, menu: &ChallengeMenu) {
                 chunks[2],
                 &SnakeDifficulty::ALL,
                 menu.selected_difficulty,
+                prestige_rank,
             );
         }
     }
 
+    if challenge.challenge_type == ChallengeType::Go {
+        let handicap = if menu.go_handicap == 0 {
+            "Even game".to_string()
+        } else {
+            format!("{} stones, White moves first", menu.go_handicap)
+        };
+        let options = Paragraph::new(Line::from(vec![
+            Span::styled("Handicap: ", Style::default().fg(Color::Gray)),
+            Span::styled(handicap, Style::default().fg(Color::Yellow)),
+            Span::styled("  [←/→] Change", Style::default().fg(Color::DarkGray)),
+        ]));
+        frame.render_widget(options, chunks[4]);
+    }
+
     // Outcomes
     let outcomes = Paragraph::new(vec![Line::from(vec![
         Span::styled("✓ ", Style::default().fg(Color::Green)),
@@ -220,11 +264,12 @@ fn render_detail_view(frame: &mut Frame, area: Rect, menu: &ChallengeMenu) {
         Span::styled("    ✓ ", Style::default().fg(Color::Green)),
         Span::styled("Draw grants bonus XP", Style::default().fg(Color::Gray)),
     ])]);
-    frame.render_widget(outcomes, chunks[4]);
+    frame.render_widget(outcomes, chunks[5]);
 
     // Help text
-    let help = Paragraph::new("[↑/↓] Difficulty  [Enter] Play  [D] Walk away  [Esc] Back")
-        .style(Style::default().fg(Color::DarkGray));
+    let help =
+        Paragraph::new("[↑/↓] Difficulty  [Enter] Play  [P] Practice  [D] Walk away  [Esc] Back")
+            .style(Style::default().fg(Color::DarkGray));
     frame.render_widget(help, outer_chunks[1]);
 }
 
@@ -281,6 +326,7 @@ fn render_difficulty_selector<D: DifficultyInfo>(
     area: Rect,
     options: &[D],
     selected: usize,
+    prestige_rank: u32,
 ) {
     let title = Paragraph::new("Select difficulty:").style(
         Style::default()
@@ -340,10 +386,15 @@ fn render_difficulty_selector<D: DifficultyInfo>(
         };
         frame.render_widget(name_line, name_area);
 
-        // Line 2: reward (indented)
+        // Line 2: reward as it would be paid at the current prestige rank (indented)
         let reward_line = Paragraph::new(Line::from(vec![
             Span::styled("    ", Style::default()),
-            Span::styled(diff.reward().description(), reward_style),
+            Span::styled(
+                diff.reward()
+                    .scaled_for_prestige(prestige_rank)
+                    .description(),
+                reward_style,
+            ),
         ]));
         let reward_area = Rect {
             x: area.x,
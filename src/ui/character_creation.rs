@@ -26,7 +26,7 @@ impl CharacterCreationScreen {
 
     pub fn draw(&self, f: &mut Frame, area: Rect, ctx: &super::responsive::LayoutContext) {
         match ctx.tier {
-            SizeTier::S | SizeTier::TooSmall => self.draw_small(f, area),
+            SizeTier::S | SizeTier::Tiny | SizeTier::TooSmall => self.draw_small(f, area),
             SizeTier::M => self.draw_medium(f, area),
             _ => self.draw_large(f, area),
         }
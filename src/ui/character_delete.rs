@@ -1,5 +1,6 @@
 use crate::character::manager::CharacterInfo;
 use crate::character::prestige::get_prestige_tier;
+use crate::core::constants::DELETED_CHARACTER_RETENTION_SECONDS;
 use crate::ui::responsive::SizeTier;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
@@ -9,6 +10,9 @@ use ratatui::{
     Frame,
 };
 
+/// Whole days a deleted save is kept before it is purged.
+const RETENTION_DAYS: u64 = DELETED_CHARACTER_RETENTION_SECONDS / (24 * 3600);
+
 #[allow(dead_code)]
 pub struct CharacterDeleteScreen {
     pub confirmation_input: String,
@@ -32,7 +36,9 @@ impl CharacterDeleteScreen {
         ctx: &super::responsive::LayoutContext,
     ) {
         match ctx.tier {
-            SizeTier::S | SizeTier::TooSmall => self.draw_small(f, area, character),
+            SizeTier::S | SizeTier::Tiny | SizeTier::TooSmall => {
+                self.draw_small(f, area, character)
+            }
             SizeTier::M => self.draw_medium(f, area, character),
             _ => self.draw_large(f, area, character),
         }
@@ -177,7 +183,7 @@ impl CharacterDeleteScreen {
 
         // Warning (single line)
         let warning = Paragraph::new(Span::styled(
-            "PERMANENT! All progress lost.",
+            format!("Kept {} days. [U] on select to undo.", RETENTION_DAYS),
             Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
         ))
         .alignment(Alignment::Center);
@@ -205,8 +211,11 @@ impl CharacterDeleteScreen {
                 Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
             )),
             Line::from(""),
-            Line::from("This action is PERMANENT and IRREVERSIBLE."),
-            Line::from("All progress will be lost forever."),
+            Line::from(format!(
+                "The save is kept for {} days, then removed for good.",
+                RETENTION_DAYS
+            )),
+            Line::from("Press [U] on the select screen to undo."),
         ];
         let warning_widget = Paragraph::new(warning_lines)
             .alignment(Alignment::Center)
@@ -34,7 +34,9 @@ impl CharacterRenameScreen {
         ctx: &super::responsive::LayoutContext,
     ) {
         match ctx.tier {
-            SizeTier::S | SizeTier::TooSmall => self.draw_small(f, area, character),
+            SizeTier::S | SizeTier::Tiny | SizeTier::TooSmall => {
+                self.draw_small(f, area, character)
+            }
             SizeTier::M => self.draw_medium(f, area, character),
             _ => self.draw_large(f, area, character),
         }
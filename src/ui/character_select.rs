@@ -1,4 +1,4 @@
-use crate::character::manager::CharacterInfo;
+use crate::character::manager::{AccountStats, CharacterInfo, MAX_CHARACTERS};
 use crate::character::prestige::get_prestige_tier;
 use crate::haven::{Haven, HavenRoomId};
 use crate::items::types::EquipmentSlot;
@@ -14,12 +14,17 @@ use ratatui::{
 #[allow(dead_code)]
 pub struct CharacterSelectScreen {
     pub selected_index: usize,
+    /// Error shown in place of the account summary (e.g. a failed restore)
+    pub status_message: Option<String>,
 }
 
 #[allow(dead_code)]
 impl CharacterSelectScreen {
     pub fn new() -> Self {
-        Self { selected_index: 0 }
+        Self {
+            selected_index: 0,
+            status_message: None,
+        }
     }
 
     pub fn draw(
@@ -27,23 +32,63 @@ impl CharacterSelectScreen {
         f: &mut Frame,
         area: Rect,
         characters: &[CharacterInfo],
+        account: &AccountStats,
         haven: &Haven,
         ctx: &super::responsive::LayoutContext,
     ) {
         match ctx.tier {
-            SizeTier::S | SizeTier::TooSmall => {
+            SizeTier::S | SizeTier::Tiny | SizeTier::TooSmall => {
                 self.draw_small(f, area, characters, haven);
             }
             SizeTier::M => {
-                self.draw_medium(f, area, characters, haven);
+                self.draw_medium(f, area, characters, account, haven);
             }
             _ => {
-                self.draw_large(f, area, characters, haven);
+                self.draw_large(f, area, characters, account, haven);
             }
         }
     }
 
-    fn draw_large(&self, f: &mut Frame, area: Rect, characters: &[CharacterInfo], haven: &Haven) {
+    /// Title line, followed by the account summary when any characters exist.
+    fn draw_title(&self, f: &mut Frame, area: Rect, account: &AccountStats) {
+        let mut lines = vec![Line::from(Span::styled(
+            "Select Your Hero",
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ))];
+        if let Some(message) = &self.status_message {
+            lines.push(Line::from(Span::styled(
+                message.clone(),
+                Style::default().fg(Color::Red),
+            )));
+        } else if account.total_characters > 0 {
+            let hours = account.total_play_time_seconds / 3600;
+            let minutes = (account.total_play_time_seconds % 3600) / 60;
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "{} heroes \u{2022} {} kills \u{2022} {}h {}m played \u{2022} Best prestige P{}",
+                    account.total_characters,
+                    account.total_kills,
+                    hours,
+                    minutes,
+                    account.highest_prestige
+                ),
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+        let title = Paragraph::new(lines).alignment(Alignment::Center);
+        f.render_widget(title, area);
+    }
+
+    fn draw_large(
+        &self,
+        f: &mut Frame,
+        area: Rect,
+        characters: &[CharacterInfo],
+        account: &AccountStats,
+        haven: &Haven,
+    ) {
         // Only show Haven section if discovered (keep it secret otherwise!)
         let constraints = if haven.discovered {
             vec![
@@ -67,14 +112,7 @@ impl CharacterSelectScreen {
             .split(area);
 
         // Title
-        let title = Paragraph::new("Select Your Hero")
-            .style(
-                Style::default()
-                    .fg(Color::Cyan)
-                    .add_modifier(Modifier::BOLD),
-            )
-            .alignment(Alignment::Center);
-        f.render_widget(title, chunks[0]);
+        self.draw_title(f, chunks[0], account);
 
         // Main content - split horizontally
         let main_chunks = Layout::default()
@@ -103,7 +141,14 @@ impl CharacterSelectScreen {
         self.draw_controls(f, chunks[controls_idx], characters, haven, false);
     }
 
-    fn draw_medium(&self, f: &mut Frame, area: Rect, characters: &[CharacterInfo], haven: &Haven) {
+    fn draw_medium(
+        &self,
+        f: &mut Frame,
+        area: Rect,
+        characters: &[CharacterInfo],
+        account: &AccountStats,
+        haven: &Haven,
+    ) {
         // M tier: reduced margins, no Haven tree, compact layout
         let constraints = vec![
             Constraint::Length(2), // Title
@@ -118,14 +163,7 @@ impl CharacterSelectScreen {
             .split(area);
 
         // Title
-        let title = Paragraph::new("Select Your Hero")
-            .style(
-                Style::default()
-                    .fg(Color::Cyan)
-                    .add_modifier(Modifier::BOLD),
-            )
-            .alignment(Alignment::Center);
-        f.render_widget(title, chunks[0]);
+        self.draw_title(f, chunks[0], account);
 
         // Main content - split horizontally with less space for details
         let main_chunks = Layout::default()
@@ -161,14 +199,16 @@ impl CharacterSelectScreen {
             .constraints(constraints)
             .split(area);
 
-        // Title
-        let title = Paragraph::new("Select Your Hero")
-            .style(
+        // Title, or the status message when there is one
+        let title = match &self.status_message {
+            Some(message) => Paragraph::new(message.clone()).style(Style::default().fg(Color::Red)),
+            None => Paragraph::new("Select Your Hero").style(
                 Style::default()
                     .fg(Color::Cyan)
                     .add_modifier(Modifier::BOLD),
-            )
-            .alignment(Alignment::Center);
+            ),
+        }
+        .alignment(Alignment::Center);
         f.render_widget(title, chunks[0]);
 
         // Character list only (no details panel)
@@ -186,7 +226,7 @@ impl CharacterSelectScreen {
         haven: &Haven,
         compact: bool,
     ) {
-        let new_button = if characters.len() >= 3 {
+        let new_button = if characters.len() >= MAX_CHARACTERS {
             "[N] New (Max 3)"
         } else {
             "[N] New"
@@ -195,7 +235,7 @@ impl CharacterSelectScreen {
         if compact {
             // Single-line or two tight lines
             let mut control_lines = vec![Line::from(format!(
-                "[Enter] Play  [R] Rename  [D] Del  {}  [Esc] Quit",
+                "[Enter] Play  [R] Rename  [D] Del  [U] Undo Del  {}  [Esc] Quit",
                 new_button
             ))];
             let mut second_row_spans = vec![Span::styled(
@@ -220,7 +260,7 @@ impl CharacterSelectScreen {
             f.render_widget(controls, area);
         } else {
             let mut control_lines = vec![Line::from(format!(
-                "[Enter] Play    [R] Rename    [D] Delete    {}    [Esc] Quit",
+                "[Enter] Play    [R] Rename    [D] Delete    [U] Undo Delete    {}    [Esc] Quit",
                 new_button
             ))];
             let mut second_row_spans = vec![Span::styled(
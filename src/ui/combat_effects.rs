@@ -1,3 +1,4 @@
+use crate::character::derived_stats::DerivedStats;
 use ratatui::{
     style::{Color, Modifier, Style},
     text::{Line, Span},
@@ -21,6 +22,28 @@ pub enum EffectType {
     },
     AttackFlash,
     HitImpact,
+    /// Bolt that travels toward the enemy, styled by the build's damage type
+    Projectile {
+        kind: ProjectileKind,
+    },
+}
+
+/// Whether a build attacks with weapons or spells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProjectileKind {
+    Physical,
+    Magical,
+}
+
+impl ProjectileKind {
+    /// Picks the dominant damage component; ties count as physical.
+    pub fn for_build(derived: &DerivedStats) -> Self {
+        if derived.magic_damage > derived.physical_damage {
+            ProjectileKind::Magical
+        } else {
+            ProjectileKind::Physical
+        }
+    }
 }
 
 impl VisualEffect {
@@ -90,6 +113,26 @@ impl VisualEffect {
                     Style::default().fg(Color::Red),
                 )))
             }
+            EffectType::Projectile { kind } => {
+                // Advance the bolt across a short track as the effect ages
+                const TRACK: usize = 12;
+                let progress = (self.lifetime / self.max_lifetime).min(1.0);
+                let position = (progress * TRACK as f64) as usize;
+                let (head, trail, color) = match kind {
+                    ProjectileKind::Physical => ("➤", "─", Color::White),
+                    ProjectileKind::Magical => ("✦", "~", Color::Magenta),
+                };
+                let text = format!(
+                    "{}{}{}",
+                    " ".repeat(position.saturating_sub(2)),
+                    trail.repeat(position.min(2)),
+                    head
+                );
+                Some(Line::from(Span::styled(
+                    text,
+                    Style::default().fg(color).add_modifier(Modifier::BOLD),
+                )))
+            }
         }
     }
 }
@@ -131,4 +174,18 @@ mod tests {
         let rendered = effect.render();
         assert!(rendered.is_some());
     }
+
+    #[test]
+    fn test_projectile_kind_follows_dominant_damage() {
+        let mut derived = DerivedStats::from_attributes(&Default::default());
+        derived.physical_damage = 10;
+        derived.magic_damage = 20;
+        assert_eq!(ProjectileKind::for_build(&derived), ProjectileKind::Magical);
+
+        derived.magic_damage = 10;
+        assert_eq!(
+            ProjectileKind::for_build(&derived),
+            ProjectileKind::Physical
+        );
+    }
 }
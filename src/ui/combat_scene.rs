@@ -2,6 +2,8 @@ use super::responsive::{LayoutContext, SizeTier};
 use crate::combat::logic::effective_enemy_attack_interval;
 use crate::core::constants::ATTACK_INTERVAL_SECONDS;
 use crate::core::game_state::GameState;
+use crate::zones::data::ZoneTheme;
+use crate::zones::get_zone;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
@@ -12,6 +14,7 @@ use ratatui::{
 
 use super::combat_3d::render_combat_3d;
 use super::enemy_sprites::zone_palette;
+use super::game_common::format_hp;
 
 /// Draws the combat scene with 3D first-person view
 pub fn draw_combat_scene(
@@ -54,8 +57,10 @@ fn draw_combat_full(frame: &mut Frame, area: Rect, game_state: &GameState) {
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(1), // Player HP
+            Constraint::Length(1), // Attack progress
             Constraint::Min(5),    // Sprite + Combat log
             Constraint::Length(1), // Enemy HP
+            Constraint::Length(1), // Enemy stats
             Constraint::Length(1), // Status
         ])
         .split(inner);
@@ -63,14 +68,21 @@ fn draw_combat_full(frame: &mut Frame, area: Rect, game_state: &GameState) {
     // Draw player HP bar (borderless)
     draw_player_hp(frame, chunks[0], game_state);
 
-    // Draw 3D combat scene (borderless)
-    render_combat_3d(frame, chunks[1], game_state);
+    // Draw the gauge filling toward the next player attack
+    draw_attack_progress(frame, chunks[1], game_state);
+
+    // Draw zone backdrop, then the 3D combat scene over it (borderless)
+    draw_zone_backdrop(frame, chunks[2], game_state);
+    render_combat_3d(frame, chunks[2], game_state);
 
     // Draw enemy HP bar (borderless)
-    draw_enemy_hp(frame, chunks[2], game_state);
+    draw_enemy_hp(frame, chunks[3], game_state);
+
+    // Draw the enemy's damage/defense/attack speed readout
+    draw_enemy_info(frame, chunks[4], game_state);
 
     // Draw combat status
-    draw_combat_status(frame, chunks[3], game_state);
+    draw_combat_status(frame, chunks[5], game_state);
 }
 
 /// Compact combat scene for M tier: HP bars + sprite + status.
@@ -94,19 +106,84 @@ fn draw_combat_compact(frame: &mut Frame, area: Rect, game_state: &GameState) {
         .split(inner);
 
     draw_player_hp(frame, chunks[0], game_state);
+    draw_zone_backdrop(frame, chunks[1], game_state);
     render_combat_3d(frame, chunks[1], game_state);
     draw_enemy_hp(frame, chunks[2], game_state);
     draw_combat_status(frame, chunks[3], game_state);
 }
 
+/// Repeating sky and ground patterns drawn behind the enemy sprite.
+struct Backdrop {
+    sky: &'static str,
+    ground: &'static str,
+    color: Color,
+}
+
+fn backdrop_for(theme: ZoneTheme) -> Backdrop {
+    let (sky, ground, color) = match theme {
+        ZoneTheme::Grassland => ("  ☁     ", "\"'\"',\"'", Color::Green),
+        ZoneTheme::Forest => ("▲ ▲▲  ▲ ", "♣ ♠ ♣♣ ", Color::DarkGray),
+        ZoneTheme::Mountain => ("  /\\   /\\ ", "▁▂▁▃▁▂", Color::Gray),
+        ZoneTheme::Ruins => (" ╥   ╥  ", "▚ ▞ ▚▞ ", Color::Magenta),
+        ZoneTheme::Volcanic => (" ⁂   ⁂  ", "~≈~≈~≈", Color::LightRed),
+        ZoneTheme::Tundra => (" *  ·  * ", "▁▁▂▁▁▁", Color::Cyan),
+        ZoneTheme::Crystal => (" ◇   ◆  ", "◢◣ ◢◣ ", Color::LightMagenta),
+        ZoneTheme::Sunken => (" °  o  ° ", "≋≋≈≋≋≈", Color::Blue),
+        ZoneTheme::Sky => ("  ☁   ☁  ", " ▔▔ ▔▔ ", Color::White),
+        ZoneTheme::Storm => (" ϟ    ϟ  ", "▀▄▀▄▀▄", Color::Yellow),
+        ZoneTheme::Void => (" ·   ✧   ", "░ ░░ ░ ", Color::DarkGray),
+    };
+    Backdrop { sky, ground, color }
+}
+
+/// Draws the current zone's themed backdrop: a sky row at the top of the area
+/// and a ground row at the bottom. The sprite is rendered on top.
+fn draw_zone_backdrop(frame: &mut Frame, area: Rect, game_state: &GameState) {
+    if area.height < 3 {
+        return;
+    }
+    let zone_id = game_state
+        .active_dungeon
+        .as_ref()
+        .map(|d| d.zone_id)
+        .unwrap_or(game_state.zone_progression.current_zone_id);
+    let Some(zone) = get_zone(zone_id) else {
+        return;
+    };
+    let backdrop = backdrop_for(zone.theme);
+    let style = Style::default()
+        .fg(backdrop.color)
+        .add_modifier(Modifier::DIM);
+    let width = area.width as usize;
+    let tile = |pattern: &str| pattern.chars().cycle().take(width).collect::<String>();
+
+    let sky = Paragraph::new(Line::from(Span::styled(tile(backdrop.sky), style)));
+    frame.render_widget(sky, Rect { height: 1, ..area });
+
+    let ground = Paragraph::new(Line::from(Span::styled(tile(backdrop.ground), style)));
+    frame.render_widget(
+        ground,
+        Rect {
+            y: area.y + area.height - 1,
+            height: 1,
+            ..area
+        },
+    );
+}
+
 /// Draws the player HP bar (borderless, single line)
 pub(super) fn draw_player_hp(frame: &mut Frame, area: Rect, game_state: &GameState) {
     let hp_ratio = game_state.combat_state.player_current_hp as f64
         / game_state.combat_state.player_max_hp as f64;
 
+    let combat = &game_state.combat_state;
     let label = format!(
-        "Player HP: {}/{}",
-        game_state.combat_state.player_current_hp, game_state.combat_state.player_max_hp
+        "Player HP: {}",
+        format_hp(
+            combat.player_current_hp,
+            combat.player_max_hp,
+            combat.hp_display
+        )
     );
 
     let gauge = Gauge::default()
@@ -117,12 +194,42 @@ pub(super) fn draw_player_hp(frame: &mut Frame, area: Rect, game_state: &GameSta
     frame.render_widget(gauge, area);
 }
 
+/// Draws the player's attack cadence as a thin gauge that fills toward the
+/// next swing. Left empty while no enemy is present.
+fn draw_attack_progress(frame: &mut Frame, area: Rect, game_state: &GameState) {
+    use crate::character::derived_stats::DerivedStats;
+
+    let combat = &game_state.combat_state;
+    let progress = if combat.current_enemy.is_some() {
+        let derived =
+            DerivedStats::calculate_derived_stats(&game_state.attributes, &game_state.equipment);
+        combat.attack_progress(derived.attack_speed_multiplier)
+    } else {
+        0.0
+    };
+
+    let gauge = Gauge::default()
+        .gauge_style(Style::default().fg(Color::Green))
+        .label("")
+        .ratio(progress);
+
+    frame.render_widget(gauge, area);
+}
+
 /// Draws the enemy HP bar (borderless, single line) with zone-aware coloring
 pub(super) fn draw_enemy_hp(frame: &mut Frame, area: Rect, game_state: &GameState) {
     if let Some(enemy) = &game_state.combat_state.current_enemy {
         let hp_ratio = enemy.current_hp as f64 / enemy.max_hp as f64;
 
-        let label = format!("{}: {}/{}", enemy.name, enemy.current_hp, enemy.max_hp);
+        let label = format!(
+            "{}: {}",
+            enemy.name,
+            format_hp(
+                enemy.current_hp,
+                enemy.max_hp,
+                game_state.combat_state.hp_display
+            )
+        );
 
         let is_boss = game_state.zone_progression.fighting_boss;
         let is_dungeon_boss = enemy.name.starts_with("Boss ");
@@ -166,6 +273,20 @@ pub(super) fn draw_enemy_hp(frame: &mut Frame, area: Rect, game_state: &GameStat
     }
 }
 
+/// Draws the current enemy's `info_summary()` under its HP bar.
+fn draw_enemy_info(frame: &mut Frame, area: Rect, game_state: &GameState) {
+    let Some(enemy) = &game_state.combat_state.current_enemy else {
+        return;
+    };
+    let summary = enemy.info_summary(effective_enemy_attack_interval(game_state));
+    let paragraph = Paragraph::new(Line::from(Span::styled(
+        summary,
+        Style::default().fg(Color::DarkGray),
+    )))
+    .alignment(Alignment::Center);
+    frame.render_widget(paragraph, area);
+}
+
 /// Draws the combat status information with DPS
 pub(super) fn draw_combat_status(frame: &mut Frame, area: Rect, game_state: &GameState) {
     use super::throbber::{spinner_char, waiting_message};
@@ -184,6 +305,21 @@ pub(super) fn draw_combat_status(frame: &mut Frame, area: Rect, game_state: &Gam
         Style::default().fg(Color::DarkGray),
     );
 
+    // Kill streak without taking damage; bold while the milestone bonus is up
+    let combo = game_state.combat_state.combo;
+    let combo_span = if combo > 0 {
+        let style = if game_state.combat_state.combo_bonus_timer > 0.0 {
+            Style::default()
+                .fg(Color::LightYellow)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::Yellow)
+        };
+        Span::styled(format!(" | Combo x{}", combo), style)
+    } else {
+        Span::raw("")
+    };
+
     let status_text = if game_state.combat_state.is_regenerating {
         let message = waiting_message(game_state.character_xp);
         vec![Line::from(vec![
@@ -192,9 +328,12 @@ pub(super) fn draw_combat_status(frame: &mut Frame, area: Rect, game_state: &Gam
                 Style::default().fg(Color::Yellow),
             ),
             dps_span,
+            combo_span,
         ])]
     } else if game_state.combat_state.current_enemy.is_some() {
-        let player_interval = ATTACK_INTERVAL_SECONDS / derived.attack_speed_multiplier;
+        let player_interval = game_state
+            .combat_state
+            .player_attack_interval(derived.attack_speed_multiplier);
         let player_next = (player_interval - game_state.combat_state.player_attack_timer).max(0.0);
         let enemy_interval = effective_enemy_attack_interval(game_state);
         let enemy_next = (enemy_interval - game_state.combat_state.enemy_attack_timer).max(0.0);
@@ -222,6 +361,7 @@ pub(super) fn draw_combat_status(frame: &mut Frame, area: Rect, game_state: &Gam
             Span::raw("  "),
             Span::styled(format!("Foe: {:.1}s", enemy_next), enemy_style),
             dps_span,
+            combo_span,
         ])]
     } else {
         let message = waiting_message(game_state.character_xp);
@@ -231,6 +371,7 @@ pub(super) fn draw_combat_status(frame: &mut Frame, area: Rect, game_state: &Gam
                 Style::default().fg(Color::Yellow),
             ),
             dps_span,
+            combo_span,
         ])]
     };
 
@@ -2,7 +2,9 @@
 
 #![allow(dead_code)]
 
-use crate::dungeon::types::{Dungeon, RoomState, RoomType, DIR_DOWN, DIR_RIGHT};
+use crate::dungeon::types::{
+    Dungeon, DungeonDifficulty, DungeonStrategy, RoomState, RoomType, DIR_DOWN, DIR_RIGHT,
+};
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
@@ -21,6 +23,7 @@ mod symbols {
     pub const BOSS: &str = "👹";
     pub const CLEARED: &str = "✓ ";
     pub const HIDDEN: &str = "  "; // Not visible at all
+    pub const SHROUDED: &str = "░░"; // Fog of war: not yet seen
     pub const UNEXPLORED: &str = "❓"; // Revealed but not entered
     pub const H_CORRIDOR: &str = "──";
     pub const V_CORRIDOR: &str = "│ ";
@@ -132,7 +135,22 @@ impl Widget for DungeonMapWidget<'_> {
                     continue;
                 }
 
-                if let Some(room) = self.dungeon.get_room(gx, gy) {
+                let room = self.dungeon.get_room(gx, gy);
+
+                // Fog of war: unseen rooms and empty cells look the same, so the
+                // layout isn't given away. The boss room stays marked as the goal.
+                let seen = room.is_some_and(|r| r.visible || r.room_type == RoomType::Boss);
+                if !seen {
+                    if screen_x + 1 < area.x + area.width {
+                        if let Some(cell) = buf.cell_mut((screen_x, screen_y)) {
+                            cell.set_symbol(symbols::SHROUDED)
+                                .set_style(Style::default().fg(Color::DarkGray));
+                        }
+                    }
+                    continue;
+                }
+
+                if let Some(room) = room {
                     let is_current = self.dungeon.player_position == (gx, gy);
                     let (sym, style) = self.room_display(room.room_type, room.state, is_current);
 
@@ -145,12 +163,15 @@ impl Widget for DungeonMapWidget<'_> {
                         }
                     }
 
-                    // Render corridors (always show full dungeon layout)
-                    {
+                    // Render corridors between rooms the player has seen
+                    if room.visible {
                         let corridor_style = Style::default().fg(Color::DarkGray);
+                        let neighbor_visible = |nx: usize, ny: usize| {
+                            self.dungeon.get_room(nx, ny).is_some_and(|r| r.visible)
+                        };
 
                         // Right corridor
-                        if room.connections[DIR_RIGHT] {
+                        if room.connections[DIR_RIGHT] && neighbor_visible(gx + 1, gy) {
                             let cx = screen_x + 2;
                             if cx + 1 < area.x + area.width && ry < area.y + area.height {
                                 if let Some(cell) = buf.cell_mut((cx, ry)) {
@@ -161,7 +182,7 @@ impl Widget for DungeonMapWidget<'_> {
                         }
 
                         // Down corridor
-                        if room.connections[DIR_DOWN] {
+                        if room.connections[DIR_DOWN] && neighbor_visible(gx, gy + 1) {
                             let cy = screen_y + 1;
                             if rx + 1 < area.x + area.width && cy < area.y + area.height {
                                 if let Some(cell) = buf.cell_mut((rx, cy)) {
@@ -208,11 +229,24 @@ impl Widget for DungeonStatusWidget<'_> {
             "[---]"
         };
 
+        let difficulty = match self.dungeon.difficulty {
+            DungeonDifficulty::Normal => String::new(),
+            other => format!(" ({})", other.name()),
+        };
+
+        let strategy = match self.dungeon.strategy {
+            DungeonStrategy::Explore => String::new(),
+            other => format!("{} | ", other.name()),
+        };
+
         let status = format!(
-            "{} Dungeon | Rooms: {}/{} | {}",
+            "{} Dungeon{} | Rooms: {}/{} | Seed: {} | {}{}",
             size_name,
+            difficulty,
             self.dungeon.rooms_cleared,
             self.dungeon.room_count(),
+            self.dungeon.seed_code(),
+            strategy,
             key_status
         );
 
@@ -297,7 +331,8 @@ impl Widget for DungeonLegendWidget {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::dungeon::generation::generate_dungeon;
+    use crate::dungeon::generation::{generate_dungeon, generate_dungeon_of_size};
+    use crate::dungeon::types::DungeonSize;
 
     #[test]
     fn test_dungeon_map_widget_creation() {
@@ -332,6 +367,28 @@ mod tests {
         assert_eq!(sym, symbols::COMBAT);
     }
 
+    #[test]
+    fn test_unseen_rooms_render_shrouded() {
+        let dungeon = generate_dungeon_of_size(DungeonSize::Small, 1, DungeonDifficulty::Normal);
+        let area = Rect::new(0, 0, 20, 10);
+        let mut buf = Buffer::empty(area);
+        DungeonMapWidget::new(&dungeon, 0.75).render(area, &mut buf);
+
+        for gy in 0..5 {
+            for gx in 0..5 {
+                let Some(room) = dungeon.get_room(gx, gy) else {
+                    continue;
+                };
+                let symbol = buf[(gx as u16 * 4, gy as u16 * 2)].symbol().to_string();
+                if room.visible {
+                    assert_ne!(symbol, symbols::SHROUDED);
+                } else if room.room_type != RoomType::Boss {
+                    assert_eq!(symbol, symbols::SHROUDED);
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_dungeon_status_widget() {
         let dungeon = generate_dungeon(10, 0, 1);
@@ -40,7 +40,7 @@ pub fn render_fishing_scene(
     frame: &mut Frame,
     area: Rect,
     session: &FishingSession,
-    _fishing_state: &FishingState,
+    fishing_state: &FishingState,
     _ctx: &super::responsive::LayoutContext,
 ) {
     // Main vertical layout (recent catches now shown in the Loot panel)
@@ -54,7 +54,7 @@ pub fn render_fishing_scene(
         .split(area);
 
     // Draw header with spot name
-    draw_header(frame, chunks[0], session);
+    draw_header(frame, chunks[0], session, fishing_state.hardcore);
 
     // Draw water animation with bobber
     draw_water_scene(frame, chunks[1], session);
@@ -64,15 +64,22 @@ pub fn render_fishing_scene(
 }
 
 /// Draws the header with fishing spot name.
-fn draw_header(frame: &mut Frame, area: Rect, session: &FishingSession) {
+fn draw_header(frame: &mut Frame, area: Rect, session: &FishingSession, hardcore: bool) {
     let title = format!(" FISHING - {} ", session.spot_name);
 
-    let header_text = vec![Line::from(vec![Span::styled(
+    let mut spans = vec![Span::styled(
         format!("Fishing at {}", session.spot_name),
         Style::default()
             .fg(Color::Cyan)
             .add_modifier(Modifier::BOLD),
-    )])];
+    )];
+    if hardcore {
+        spans.push(Span::styled(
+            " [Hardcore]",
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        ));
+    }
+    let header_text = vec![Line::from(spans)];
 
     let header = Paragraph::new(header_text)
         .block(Block::default().borders(Borders::ALL).title(title))
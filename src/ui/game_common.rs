@@ -1,6 +1,8 @@
 //! Shared UI components for minigames.
 
 use crate::core::game_logic::OfflineReport;
+use crate::core::tutorial::TutorialStep;
+use crate::utils::settings::{HpDisplay, NumberFormat};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
@@ -324,6 +326,7 @@ pub fn render_offline_welcome(
     frame: &mut Frame,
     area: Rect,
     report: &OfflineReport,
+    number_format: NumberFormat,
     _ctx: &super::responsive::LayoutContext,
 ) {
     // Centered modal box
@@ -381,7 +384,7 @@ pub fn render_offline_welcome(
         Line::from(Span::styled(
             format!(
                 "  ⚔️  XP Gained:  {:>10}",
-                format_number_short(report.xp_gained)
+                format_number(report.xp_gained, number_format)
             ),
             Style::default().fg(Color::Cyan),
         )),
@@ -412,6 +415,68 @@ pub fn render_offline_welcome(
     frame.render_widget(text, inner);
 }
 
+/// Render a first-run tutorial hint modal.
+pub fn render_tutorial_hint(
+    frame: &mut Frame,
+    area: Rect,
+    step: TutorialStep,
+    _ctx: &LayoutContext,
+) {
+    let modal_width = 54u16.min(area.width.saturating_sub(4));
+    let modal_height = 8u16.min(area.height.saturating_sub(4));
+    let x = area.x + (area.width.saturating_sub(modal_width)) / 2;
+    let y = area.y + (area.height.saturating_sub(modal_height)) / 2;
+    let modal_area = Rect::new(x, y, modal_width, modal_height);
+
+    frame.render_widget(Clear, modal_area);
+
+    let block = Block::default()
+        .title(" Tutorial ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+
+    let inner = block.inner(modal_area);
+    frame.render_widget(block, modal_area);
+
+    let controls = if step == TutorialStep::Complete {
+        "[Enter] Continue"
+    } else {
+        "[Enter] Continue  [S] Skip tutorial"
+    };
+    let text = Paragraph::new(vec![
+        Line::from(""),
+        Line::from(Span::styled(step.hint(), Style::default().fg(Color::White))),
+        Line::from(""),
+        Line::from(Span::styled(controls, Style::default().fg(Color::DarkGray))),
+    ])
+    .alignment(Alignment::Center)
+    .wrap(ratatui::widgets::Wrap { trim: true });
+    frame.render_widget(text, inner);
+}
+
+/// Formats `n` in the player's chosen style. Below 10,000 every style
+/// writes the plain number.
+pub fn format_number(n: u64, fmt: NumberFormat) -> String {
+    match fmt {
+        NumberFormat::Short => format_number_short(n),
+        NumberFormat::Scientific if n >= 10_000 => format!("{:.2e}", n as f64),
+        NumberFormat::Scientific | NumberFormat::Full => n.to_string(),
+    }
+}
+
+/// HP bar label value, shared by every HP gauge: "730/1000", or "73%" in
+/// percent mode. Percentages round down, but a living target never shows 0%.
+pub fn format_hp(current: u32, max: u32, display: HpDisplay) -> String {
+    match display {
+        HpDisplay::Numbers => format!("{}/{}", current, max),
+        HpDisplay::Percent => {
+            let percent = current as u64 * 100 / max.max(1) as u64;
+            let percent = if current > 0 { percent.max(1) } else { 0 };
+            format!("{}%", percent)
+        }
+    }
+}
+
 /// Format a number with abbreviated suffixes (K, M, B, T, Q).
 pub fn format_number_short(n: u64) -> String {
     // (threshold, divisor, suffix)
@@ -513,3 +578,37 @@ pub fn render_minigame_too_small(
     let text = Paragraph::new(lines).alignment(Alignment::Center);
     frame.render_widget(text, area);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_number_styles() {
+        assert_eq!(format_number(1_500_000, NumberFormat::Short), "1.5M");
+        assert_eq!(format_number(1_500_000, NumberFormat::Scientific), "1.50e6");
+        assert_eq!(format_number(1_500_000, NumberFormat::Full), "1500000");
+    }
+
+    #[test]
+    fn test_format_hp_modes() {
+        assert_eq!(format_hp(730_000, 1_000_000, HpDisplay::Percent), "73%");
+        assert_eq!(
+            format_hp(730_000, 1_000_000, HpDisplay::Numbers),
+            "730000/1000000"
+        );
+        assert_eq!(format_hp(1, 1_000_000, HpDisplay::Percent), "1%");
+        assert_eq!(format_hp(0, 100, HpDisplay::Percent), "0%");
+    }
+
+    #[test]
+    fn test_small_numbers_are_plain_in_every_style() {
+        for fmt in [
+            NumberFormat::Short,
+            NumberFormat::Scientific,
+            NumberFormat::Full,
+        ] {
+            assert_eq!(format_number(9_999, fmt), "9999");
+        }
+    }
+}
@@ -5,7 +5,7 @@ use super::game_common::{
     render_info_panel_frame, render_minigame_too_small, render_status_bar,
     render_thinking_status_bar, GameResultType,
 };
-use crate::challenges::go::{GoGame, GoMove, GoResult, Stone, BOARD_SIZE};
+use crate::challenges::go::{GoGame, GoMove, GoResult, Owner, Stone, BOARD_SIZE};
 use crate::challenges::menu::DifficultyInfo;
 use ratatui::{
     layout::Rect,
@@ -55,6 +55,9 @@ fn render_board(frame: &mut Frame, area: Rect, game: &GoGame) {
     let last_move_color = Color::Green;
     let grid_color = Color::DarkGray;
 
+    // Shade territory once the game is scored
+    let ownership = game.game_result.map(|_| game.ownership_map());
+
     for row in 0..BOARD_SIZE {
         let mut spans = Vec::new();
         for col in 0..BOARD_SIZE {
@@ -96,6 +99,15 @@ fn render_board(frame: &mut Frame, area: Rect, game: &GoGame) {
                         )
                     } else if is_ko {
                         ("×", Style::default().fg(Color::Red))
+                    } else if let Some(owner) = ownership.as_ref().map(|map| map[row][col]) {
+                        match owner {
+                            Owner::Black => ("▪", Style::default().fg(human_color)),
+                            Owner::White => ("▪", Style::default().fg(ai_color)),
+                            Owner::Neutral => (
+                                get_intersection_char(row, col),
+                                Style::default().fg(grid_color),
+                            ),
+                        }
                     } else {
                         // Grid intersection
                         let ch = get_intersection_char(row, col);
@@ -232,6 +244,13 @@ fn render_info_panel(frame: &mut Frame, area: Rect, game: &GoGame) {
         )]),
     ];
 
+    if game.handicap > 0 {
+        lines.push(Line::from(Span::styled(
+            format!("Handicap {}", game.handicap),
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+
     // Show pass indicator when opponent passed
     if game.last_move == Some(GoMove::Pass) {
         lines.push(Line::from(""));
@@ -269,7 +288,7 @@ fn render_go_game_over(
     let result = game.game_result.as_ref().unwrap();
 
     // Get final scores for message (human=Black, AI=White)
-    let (black_score, white_score) = calculate_score(&game.board);
+    let (black_score, white_score) = calculate_score(&game.board, game.komi);
     let score_msg = format!("You: {} vs AI: {}", black_score, white_score);
 
     let (result_type, title, message) = match result {
@@ -0,0 +1,68 @@
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// Draws the confirmation dialog shown before hardcore fishing is turned on
+pub fn draw_hardcore_fishing_confirm(frame: &mut Frame, _ctx: &super::responsive::LayoutContext) {
+    let size = frame.area();
+
+    let dialog_width = 50.min(size.width.saturating_sub(4));
+    let dialog_height = 10.min(size.height.saturating_sub(4));
+
+    let x = (size.width.saturating_sub(dialog_width)) / 2;
+    let y = (size.height.saturating_sub(dialog_height)) / 2;
+
+    let dialog_area = Rect::new(x, y, dialog_width, dialog_height);
+
+    frame.render_widget(Clear, dialog_area);
+
+    let title = Line::from(vec![Span::styled(
+        " Hardcore Fishing ",
+        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+    )]);
+
+    let lines = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            "An escaped Storm Leviathan will snap the line",
+            Style::default().fg(Color::Red),
+        )),
+        Line::from(Span::styled(
+            "and cost you a fishing rank.",
+            Style::default().fg(Color::Red),
+        )),
+        Line::from(""),
+        Line::from("Press [F] again at any time to turn it off."),
+        Line::from(""),
+        Line::from(vec![
+            Span::raw("   "),
+            Span::styled(
+                "[Y] Enable Hardcore",
+                Style::default()
+                    .fg(Color::Green)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw("    "),
+            Span::styled(
+                "[N] Cancel",
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            ),
+        ]),
+    ];
+
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .title(title)
+                .title_alignment(Alignment::Center)
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Red)),
+        )
+        .alignment(Alignment::Center);
+
+    frame.render_widget(paragraph, dialog_area);
+}
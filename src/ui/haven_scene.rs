@@ -1,8 +1,15 @@
 //! Haven skill tree UI rendering.
 
+use crate::core::constants::{BAIT_CASTS, BAIT_CRAFT_COST};
 use crate::core::game_state::GameState;
+use crate::fishing::bait::BaitKind;
+use crate::fishing::gear::rod_craft_cost;
+use crate::fishing::types::FishRarity;
 use crate::haven::{can_afford, tier_cost, Haven, HavenBonusType, HavenRoomId};
-use crate::items::EquipmentSlot;
+use crate::items::crafting::{
+    affix_transfer_cost, gem_craft_cost, rarity_gamble_cost, transmute_cost,
+};
+use crate::items::{EquipmentSlot, Gem, GemKind, Item, Rarity};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
@@ -94,8 +101,10 @@ pub fn render_haven_tree(
     );
 
     // Help bar
-    let help = Paragraph::new("[↑/↓] Navigate  [Enter] Build/Forge  [Esc] Close")
-        .style(Style::default().fg(Color::DarkGray));
+    let help = Paragraph::new(
+        "[↑/↓] Navigate  [Enter] Build/Forge  [W] Workshop  [X/I] Export/Import build  [Esc] Close",
+    )
+    .style(Style::default().fg(Color::DarkGray));
     frame.render_widget(help, chunks[2]);
 }
 
@@ -116,6 +125,7 @@ fn render_summary_bar(frame: &mut Frame, area: Rect, haven: &Haven) {
         (HavenBonusType::DropRatePercent, "+{}% Drops"),
         (HavenBonusType::CritChancePercent, "+{}% Crit"),
         (HavenBonusType::HpRegenPercent, "+{}% HP Regen"),
+        (HavenBonusType::EmergencyHealChance, "{}% Emergency Heal"),
         (HavenBonusType::DoubleStrikeChance, "+{}% Double Strike"),
         (HavenBonusType::OfflineXpPercent, "+{}% Offline XP"),
         (HavenBonusType::ChallengeDiscoveryPercent, "+{}% Discovery"),
@@ -544,6 +554,64 @@ pub fn render_build_confirmation(
     frame.render_widget(text, inner);
 }
 
+/// Render the confirmation overlay for importing a shared build order
+pub fn render_build_order_confirmation(
+    frame: &mut Frame,
+    area: Rect,
+    order: &str,
+    prestige_rank: u32,
+    _ctx: &super::responsive::LayoutContext,
+) {
+    let modal_width = 60u16.min(area.width.saturating_sub(4));
+    let modal_height = 12u16.min(area.height.saturating_sub(4));
+    let x = area.x + (area.width.saturating_sub(modal_width)) / 2;
+    let y = area.y + (area.height.saturating_sub(modal_height)) / 2;
+    let modal_area = Rect::new(x, y, modal_width, modal_height);
+
+    frame.render_widget(Clear, modal_area);
+
+    let block = Block::default()
+        .title(" Import Build Order? ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow));
+
+    let inner = block.inner(modal_area);
+    frame.render_widget(block, modal_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1),
+            Constraint::Min(0),
+            Constraint::Length(3),
+        ])
+        .split(inner);
+
+    frame.render_widget(
+        Paragraph::new(order.to_string())
+            .style(Style::default().fg(Color::Yellow))
+            .wrap(Wrap { trim: true }),
+        chunks[1],
+    );
+
+    let text = Paragraph::new(vec![
+        Line::from(Span::styled(
+            format!(
+                "Builds in tree order with your {} Prestige Ranks",
+                prestige_rank
+            ),
+            Style::default().fg(Color::White),
+        )),
+        Line::from(""),
+        Line::from(Span::styled(
+            "[Enter] Apply  [Esc] Cancel",
+            Style::default().fg(Color::DarkGray),
+        )),
+    ])
+    .alignment(ratatui::layout::Alignment::Center);
+    frame.render_widget(text, chunks[2]);
+}
+
 /// Render the Storm Forge confirmation overlay
 pub fn render_forge_confirmation(
     frame: &mut Frame,
@@ -635,6 +703,277 @@ pub fn render_forge_confirmation(
     frame.render_widget(text, inner);
 }
 
+/// Workshop crafting panel state (see `items::crafting`).
+#[derive(Debug, Clone, Default)]
+pub struct WorkshopUiState {
+    /// Index into `EquipmentSlot::ALL` of the item being worked on
+    pub selected_slot: usize,
+    /// Set while picking the slot to transmute into
+    pub transmute_target: Option<usize>,
+    /// Index into `GameState::gems` of the gem `[S]` sockets
+    pub selected_gem: usize,
+    /// Index into `GemKind::ALL` of the gem `[C]` crafts
+    pub craft_kind: usize,
+    /// Index into `BaitKind::ALL` of the bait `[B]` crafts
+    pub bait_kind: usize,
+    /// Index into `FishRarity::ALL` of the rod `[F]` crafts
+    pub rod_rarity: usize,
+    /// Outcome of the last action
+    pub status: Option<String>,
+}
+
+fn rarity_color(rarity: Rarity) -> Color {
+    match rarity {
+        Rarity::Common => Color::White,
+        Rarity::Magic => Color::Green,
+        Rarity::Rare => Color::Blue,
+        Rarity::Epic => Color::Magenta,
+        Rarity::Legendary => Color::Yellow,
+    }
+}
+
+/// "◆◇" for one filled and one empty socket
+fn socket_marks(item: &Item) -> String {
+    item.sockets
+        .iter()
+        .map(|socket| if socket.is_some() { "◆" } else { "◇" })
+        .collect()
+}
+
+/// Render the Workshop crafting panel (opened with [W] from the Haven screen)
+pub fn render_workshop(
+    frame: &mut Frame,
+    area: Rect,
+    game_state: &GameState,
+    haven: &Haven,
+    workshop: &WorkshopUiState,
+    _ctx: &super::responsive::LayoutContext,
+) {
+    let modal_width = 70u16.min(area.width.saturating_sub(4));
+    let modal_height = 29u16.min(area.height.saturating_sub(2));
+    let x = area.x + (area.width.saturating_sub(modal_width)) / 2;
+    let y = area.y + (area.height.saturating_sub(modal_height)) / 2;
+    let modal_area = Rect::new(x, y, modal_width, modal_height);
+
+    frame.render_widget(Clear, modal_area);
+
+    let tier = haven.room_tier(HavenRoomId::Workshop);
+    let block = Block::default()
+        .title(format!(" Workshop (T{}) ", tier))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow));
+
+    let inner = block.inner(modal_area);
+    frame.render_widget(block, modal_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2),                               // Materials
+            Constraint::Length(EquipmentSlot::ALL.len() as u16), // Equipped items
+            Constraint::Length(1),                               // Spacer
+            Constraint::Min(0),                                  // Actions
+            Constraint::Length(GEM_ROWS as u16 + 1),             // Gem pouch
+            Constraint::Length(1),                               // Status
+            Constraint::Length(1),                               // Help
+        ])
+        .split(inner);
+
+    let header = Paragraph::new(Line::from(vec![
+        Span::styled("Salvage materials: ", Style::default().fg(Color::DarkGray)),
+        Span::styled(
+            game_state.salvage_materials.to_string(),
+            Style::default().fg(Color::White),
+        ),
+    ]));
+    frame.render_widget(header, chunks[0]);
+
+    // Equipped items; while transmuting, the target slot is marked instead
+    let items: Vec<ListItem> = EquipmentSlot::ALL
+        .iter()
+        .enumerate()
+        .map(|(i, slot)| {
+            let (prefix, prefix_style) = match workshop.transmute_target {
+                Some(target) if target == i => ("→ ", Style::default().fg(Color::Yellow)),
+                _ if i == workshop.selected_slot => ("▶ ", Style::default().fg(Color::Cyan)),
+                _ => ("  ", Style::default()),
+            };
+            let mut spans = vec![
+                Span::styled(prefix, prefix_style),
+                Span::styled(
+                    format!("{:8}", slot.name()),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ];
+            match game_state.equipment.get(*slot) {
+                Some(item) => {
+                    spans.push(Span::styled(
+                        item.display_name.clone(),
+                        Style::default().fg(rarity_color(item.rarity)),
+                    ));
+                    if !item.sockets.is_empty() {
+                        spans.push(Span::styled(
+                            format!(" {}", socket_marks(item)),
+                            Style::default().fg(Color::Cyan),
+                        ));
+                    }
+                }
+                None => spans.push(Span::styled(
+                    "(empty)",
+                    Style::default().fg(Color::DarkGray),
+                )),
+            }
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+    frame.render_widget(List::new(items), chunks[1]);
+
+    let slot = EquipmentSlot::ALL[workshop.selected_slot];
+    let key = Style::default().fg(Color::Yellow);
+    let text = Style::default().fg(Color::White);
+    let dim = Style::default().fg(Color::DarkGray);
+    let cost = |materials: u64| Span::styled(format!("  ({} materials)", materials), dim);
+
+    let mut lines = Vec::new();
+    if let Some(target) = workshop.transmute_target {
+        lines.push(Line::from(Span::styled(
+            format!(
+                "Transmute {} into {}? Affixes are re-rolled for the new slot.",
+                slot.name(),
+                EquipmentSlot::ALL[target].name()
+            ),
+            text,
+        )));
+        lines.push(Line::from(Span::styled(
+            "[↑/↓] Pick slot  [Enter] Transmute  [Esc] Cancel",
+            dim,
+        )));
+    } else if let Some(item) = game_state.equipment.get(slot) {
+        lines.push(Line::from(vec![
+            Span::styled("[T] ", key),
+            Span::styled("Transmute into another slot", text),
+            cost(transmute_cost(item.rarity)),
+        ]));
+        if item.rarity == Rarity::Legendary {
+            lines.push(Line::from(vec![
+                Span::styled("[G] ", dim),
+                Span::styled("Gamble rarity (already Legendary)", dim),
+            ]));
+        } else {
+            lines.push(Line::from(vec![
+                Span::styled("[G] ", key),
+                Span::styled("Gamble for a higher rarity (may drop one)", text),
+                cost(rarity_gamble_cost(item.rarity)),
+            ]));
+        }
+        match game_state.equipment_history.previous.get(slot) {
+            Some(replaced) => lines.push(Line::from(vec![
+                Span::styled("[A] ", key),
+                Span::styled(format!("Take affixes from {}", replaced.display_name), text),
+                cost(affix_transfer_cost(replaced.rarity)),
+            ])),
+            None => lines.push(Line::from(vec![
+                Span::styled("[A] ", dim),
+                Span::styled("Take affixes (no replaced item in this slot)", dim),
+            ])),
+        }
+        lines.push(Line::from(vec![
+            Span::styled("[S] ", key),
+            Span::styled("Socket a gem  ", text),
+            Span::styled("[U] ", key),
+            Span::styled("Unsocket", text),
+            Span::styled(format!("  ({} socket(s))", item.sockets.len()), dim),
+        ]));
+    } else {
+        lines.push(Line::from(Span::styled(
+            "Nothing equipped in this slot",
+            dim,
+        )));
+    }
+
+    let gem_tier = tier.min(crate::core::constants::GEM_MAX_TIER);
+    let kind = GemKind::ALL[workshop.craft_kind];
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled("[C] ", key),
+        Span::styled(format!("Craft a {} (T{})", kind.name(), gem_tier), text),
+        cost(gem_craft_cost(gem_tier)),
+        Span::styled("  [K] ", key),
+        Span::styled("Change gem", text),
+    ]));
+    let bait = BaitKind::ALL[workshop.bait_kind];
+    lines.push(Line::from(vec![
+        Span::styled("[B] ", key),
+        Span::styled(
+            format!("Craft {} ({} casts)", bait.name(), BAIT_CASTS),
+            text,
+        ),
+        cost(BAIT_CRAFT_COST),
+        Span::styled("  [V] ", key),
+        Span::styled("Change bait", text),
+    ]));
+    let rod_rarity = FishRarity::ALL[workshop.rod_rarity];
+    lines.push(Line::from(vec![
+        Span::styled("[F] ", key),
+        Span::styled(format!("Craft a {} fishing rod", rod_rarity.name()), text),
+        cost(rod_craft_cost(rod_rarity)),
+        Span::styled("  [R] ", key),
+        Span::styled("Change rarity", text),
+    ]));
+    // Crafting replaces the current rod, so show what would be lost
+    lines.push(Line::from(Span::styled(
+        match &game_state.fishing_gear.rod {
+            Some(rod) => format!("    Replaces {} ({})", rod.name, rod.summary()),
+            None => "    No rod equipped".to_string(),
+        },
+        dim,
+    )));
+    frame.render_widget(Paragraph::new(lines), chunks[3]);
+
+    render_gem_pouch(frame, chunks[4], &game_state.gems, workshop.selected_gem);
+
+    if let Some(status) = &workshop.status {
+        let status = Paragraph::new(status.as_str()).style(Style::default().fg(Color::Yellow));
+        frame.render_widget(status, chunks[5]);
+    }
+
+    let help =
+        Paragraph::new("[↑/↓] Select item  [←/→] Select gem  [Esc] Back to Haven").style(dim);
+    frame.render_widget(help, chunks[6]);
+}
+
+/// Gems shown at once in the Workshop's gem pouch; the list scrolls with the selection
+const GEM_ROWS: usize = 4;
+
+/// The unsocketed gems, with the one `[S]` will socket marked
+fn render_gem_pouch(frame: &mut Frame, area: Rect, gems: &[Gem], selected: usize) {
+    let dim = Style::default().fg(Color::DarkGray);
+    let mut lines = vec![Line::from(Span::styled(
+        format!("Gem pouch ({})", gems.len()),
+        Style::default().fg(Color::White),
+    ))];
+    if gems.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  No gems yet. They drop from kills or can be crafted.",
+            dim,
+        )));
+    }
+    let start = selected.saturating_sub(GEM_ROWS - 1);
+    for (i, gem) in gems.iter().enumerate().skip(start).take(GEM_ROWS) {
+        let (prefix, style) = if i == selected {
+            ("▶ ", Style::default().fg(Color::Cyan))
+        } else {
+            ("  ", Style::default().fg(Color::White))
+        };
+        lines.push(Line::from(vec![
+            Span::styled(prefix, Style::default().fg(Color::Cyan)),
+            Span::styled(format!("{:16}", gem.name()), style),
+            Span::styled(super::stats_panel::format_affix(&gem.affix()), dim),
+        ]));
+    }
+    frame.render_widget(Paragraph::new(lines), area);
+}
+
 /// Render the Vault item selection screen (shown during prestige when Vault is built)
 pub fn render_vault_selection(
     frame: &mut Frame,
@@ -1,11 +1,11 @@
 use super::responsive::{LayoutContext, SizeTier};
-use crate::core::game_state::GameState;
+use crate::core::game_state::{GameState, RecentDrop};
 use crate::items::types::Rarity;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph},
+    widgets::{Block, Borders, Paragraph, Sparkline},
     Frame,
 };
 
@@ -19,6 +19,16 @@ fn truncate_to_width(s: &str, max_width: usize) -> String {
     format!("{}…", &s[..boundary])
 }
 
+/// Picks the compact or verbose stat summary for a drop. Drops recorded
+/// without a verbose summary (fish, crafted rewards) fall back to compact.
+fn drop_stats(drop: &RecentDrop, verbose: bool) -> &str {
+    if verbose && !drop.stats_verbose.is_empty() {
+        &drop.stats_verbose
+    } else {
+        &drop.stats
+    }
+}
+
 /// Draws the full-width bottom section: loot (left) and combat log (right) side by side
 pub fn draw_info_panel(frame: &mut Frame, area: Rect, game_state: &GameState, ctx: &LayoutContext) {
     match ctx.tier {
@@ -40,7 +50,7 @@ pub fn draw_info_panel(frame: &mut Frame, area: Rect, game_state: &GameState, ct
             // Merged chronological feed
             draw_merged_feed(frame, area, game_state);
         }
-        SizeTier::TooSmall => {}
+        SizeTier::Tiny | SizeTier::TooSmall => {}
     }
 }
 
@@ -108,9 +118,19 @@ fn draw_recent_gains(frame: &mut Frame, area: Rect, game_state: &GameState) {
         .border_style(Style::default().fg(Color::Cyan))
         .title(" Loot ");
 
-    let inner = block.inner(area);
+    let mut inner = block.inner(area);
     frame.render_widget(block, area);
 
+    // Bottom row: XP/min sparkline once there is history and room for it
+    if inner.height >= 6 && game_state.metrics.latest().is_some() {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(1)])
+            .split(inner);
+        inner = chunks[0];
+        draw_xp_rate_sparkline(frame, chunks[1], game_state);
+    }
+
     let mut lines: Vec<Line> = Vec::new();
     let max_lines = inner.height as usize;
 
@@ -146,13 +166,20 @@ fn draw_recent_gains(frame: &mut Frame, area: Rect, game_state: &GameState) {
                     Style::default().fg(Color::DarkGray),
                 ));
             }
+            if drop.power > 0 {
+                spans.push(Span::styled(
+                    format!("  Power: {}", drop.power),
+                    Style::default().fg(Color::DarkGray),
+                ));
+            }
 
             lines.push(Line::from(spans));
 
             // Line 2: stat summary (only for equipment with stats)
-            if !drop.stats.is_empty() && lines.len() < max_lines {
+            let stats = drop_stats(drop, game_state.verbose_loot);
+            if !stats.is_empty() && lines.len() < max_lines {
                 lines.push(Line::from(Span::styled(
-                    format!("  {}", drop.stats),
+                    format!("  {}", stats),
                     Style::default().fg(Color::DarkGray),
                 )));
             }
@@ -168,6 +195,39 @@ fn draw_recent_gains(frame: &mut Frame, area: Rect, game_state: &GameState) {
     frame.render_widget(paragraph, inner);
 }
 
+/// Draws a one-row "XP/min" label and sparkline of the recent XP rate history.
+fn draw_xp_rate_sparkline(frame: &mut Frame, area: Rect, game_state: &GameState) {
+    let Some(latest) = game_state.metrics.latest() else {
+        return;
+    };
+    let label = format!(
+        " XP/min {:.0} \u{b7} kills/min {:.1} ",
+        latest.xp_per_minute, latest.kills_per_minute
+    );
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Length(label.chars().count() as u16),
+            Constraint::Min(0),
+        ])
+        .split(area);
+
+    frame.render_widget(
+        Paragraph::new(Span::styled(label, Style::default().fg(Color::DarkGray))),
+        chunks[0],
+    );
+
+    // Show the most recent samples that fit the available width
+    let series = game_state.metrics.xp_per_minute_series();
+    let visible = &series[series.len().saturating_sub(chunks[1].width as usize)..];
+    frame.render_widget(
+        Sparkline::default()
+            .data(visible)
+            .style(Style::default().fg(Color::Cyan)),
+        chunks[1],
+    );
+}
+
 /// Compact side-by-side loot + combat log for M tier (no borders, minimal padding).
 fn draw_loot_combat_compact(frame: &mut Frame, area: Rect, game_state: &GameState) {
     let chunks = Layout::default()
@@ -0,0 +1,104 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// In-game hotkeys shown by the `[?]` help overlay, in display order.
+pub const GAME_KEYS: &[(&str, &str)] = &[
+    ("Esc", "Quit to character select"),
+    ("P", "Prestige"),
+    ("H", "Haven"),
+    ("A", "Achievements"),
+    ("Tab", "Challenges"),
+    ("E", "Send an expedition"),
+    ("U", "Update details"),
+    ("R", "Show next milestones"),
+    ("Z", "Zone codex"),
+    ("T", "Cycle combat stance"),
+    ("G", "Adaptive intensity"),
+    ("O", "Swap back replaced gear"),
+    ("S", "Dungeon strategy"),
+    ("Arrows", "Steer in a dungeon"),
+    ("L", "Loot filter"),
+    ("I", "Verbose loot details"),
+    ("J", "Damage breakdown"),
+    ("F", "Hardcore fishing"),
+    ("K", "Fishing autopilot"),
+    ("C", "Auto-challenge"),
+    ("V", "Visual effects"),
+    ("N", "Number format"),
+    ("M", "Combat log filter"),
+    ("B", "HP as numbers/percent"),
+    ("?", "This help"),
+];
+
+fn key_lines(keys: &[(&str, &str)]) -> Vec<Line<'static>> {
+    keys.iter()
+        .map(|(key, action)| {
+            Line::from(vec![
+                Span::styled(
+                    format!("{:>8} ", format!("[{}]", key)),
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(action.to_string()),
+            ])
+        })
+        .collect()
+}
+
+/// Draws the in-game hotkey reference opened with `[?]`
+pub fn draw_key_help(frame: &mut Frame, _ctx: &super::responsive::LayoutContext) {
+    let size = frame.area();
+
+    let rows = GAME_KEYS.len().div_ceil(2) as u16;
+    let dialog_width = 72.min(size.width.saturating_sub(4));
+    let dialog_height = (rows + 4).min(size.height.saturating_sub(2));
+
+    let x = (size.width.saturating_sub(dialog_width)) / 2;
+    let y = (size.height.saturating_sub(dialog_height)) / 2;
+
+    let dialog_area = Rect::new(x, y, dialog_width, dialog_height);
+
+    frame.render_widget(Clear, dialog_area);
+
+    let title = Line::from(vec![Span::styled(
+        " Keys ",
+        Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD),
+    )]);
+    let block = Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+    let inner = block.inner(dialog_area);
+    frame.render_widget(block, dialog_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(inner);
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(chunks[0]);
+
+    let (left, right) = GAME_KEYS.split_at(rows as usize);
+    frame.render_widget(Paragraph::new(key_lines(left)), columns[0]);
+    frame.render_widget(Paragraph::new(key_lines(right)), columns[1]);
+
+    frame.render_widget(
+        Paragraph::new(Line::from(Span::styled(
+            "Press any key to close",
+            Style::default().fg(Color::DarkGray),
+        )))
+        .alignment(Alignment::Center),
+        chunks[1],
+    );
+}
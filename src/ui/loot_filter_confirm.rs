@@ -0,0 +1,71 @@
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// Draws the confirmation dialog shown before the loot filter is turned on
+pub fn draw_loot_filter_confirm(frame: &mut Frame, _ctx: &super::responsive::LayoutContext) {
+    let size = frame.area();
+
+    let dialog_width = 50.min(size.width.saturating_sub(4));
+    let dialog_height = 11.min(size.height.saturating_sub(4));
+
+    let x = (size.width.saturating_sub(dialog_width)) / 2;
+    let y = (size.height.saturating_sub(dialog_height)) / 2;
+
+    let dialog_area = Rect::new(x, y, dialog_width, dialog_height);
+
+    frame.render_widget(Clear, dialog_area);
+
+    let title = Line::from(vec![Span::styled(
+        " Loot Filter ",
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
+    )]);
+
+    let lines = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            "Common drops that aren't equipped will be",
+            Style::default().fg(Color::Yellow),
+        )),
+        Line::from(Span::styled(
+            "salvaged into materials and lost for good.",
+            Style::default().fg(Color::Yellow),
+        )),
+        Line::from(""),
+        Line::from("Press [L] again to raise the minimum rarity;"),
+        Line::from("past Legendary it turns off."),
+        Line::from(""),
+        Line::from(vec![
+            Span::raw("   "),
+            Span::styled(
+                "[Y] Enable Filter",
+                Style::default()
+                    .fg(Color::Green)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw("    "),
+            Span::styled(
+                "[N] Cancel",
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            ),
+        ]),
+    ];
+
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .title(title)
+                .title_alignment(Alignment::Center)
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Yellow)),
+        )
+        .alignment(Alignment::Center);
+
+    frame.render_widget(paragraph, dialog_area);
+}
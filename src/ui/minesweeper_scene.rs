@@ -4,7 +4,9 @@ use super::game_common::{
     create_game_layout, render_forfeit_status_bar, render_game_over_overlay,
     render_info_panel_frame, render_minigame_too_small, render_status_bar, GameResultType,
 };
-use crate::challenges::minesweeper::{Cell, MinesweeperGame, MinesweeperResult};
+use crate::challenges::minesweeper::{
+    Cell, HintKind, MinesweeperDifficulty, MinesweeperGame, MinesweeperResult,
+};
 use ratatui::{
     layout::Rect,
     style::{Color, Modifier, Style},
@@ -68,6 +70,15 @@ fn render_grid(frame: &mut Frame, area: Rect, game: &MinesweeperGame) {
             if is_cursor && !game_over {
                 style = style.bg(Color::DarkGray);
             }
+            match game.highlighted_hint {
+                Some((r, c, HintKind::Safe)) if (r, c) == (row, col) => {
+                    style = style.bg(Color::Green);
+                }
+                Some((r, c, HintKind::Mine)) if (r, c) == (row, col) => {
+                    style = style.bg(Color::Red);
+                }
+                _ => {}
+            }
 
             spans.push(Span::styled(text, style));
         }
@@ -122,18 +133,25 @@ fn render_status_bar_content(frame: &mut Frame, area: Rect, game: &MinesweeperGa
         ("Detecting...", Color::Green)
     };
 
-    render_status_bar(
-        frame,
-        area,
-        status_text,
-        status_color,
+    // Solver hints are a Novice-only training aid
+    let controls: &[(&str, &str)] = if game.difficulty == MinesweeperDifficulty::Novice {
         &[
             ("[Arrows]", "Move"),
-            ("[Enter]", "Reveal"),
+            ("[Enter]", "Reveal/Chord"),
             ("[F]", "Flag"),
+            ("[H]", "Hint"),
             ("[Esc]", "Forfeit"),
-        ],
-    );
+        ]
+    } else {
+        &[
+            ("[Arrows]", "Move"),
+            ("[Enter]", "Reveal/Chord"),
+            ("[F]", "Flag"),
+            ("[Esc]", "Forfeit"),
+        ]
+    };
+
+    render_status_bar(frame, area, status_text, status_color, controls);
 }
 
 /// Render the info panel on the right side.
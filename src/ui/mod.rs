@@ -1,4 +1,5 @@
 pub mod achievement_browser_scene;
+pub mod auto_challenge_confirm;
 pub mod challenge_menu_scene;
 pub mod character_creation;
 pub mod character_delete;
@@ -16,9 +17,12 @@ pub mod flappy_scene;
 pub mod game_common;
 pub mod go_scene;
 pub mod gomoku_scene;
+pub mod hardcore_fishing_confirm;
 pub mod haven_scene;
 mod info_panel;
 pub mod jezzball_scene;
+pub mod key_help;
+pub mod loot_filter_confirm;
 pub mod minesweeper_scene;
 pub mod morris_scene;
 pub mod prestige_confirm;
@@ -27,10 +31,11 @@ pub mod rune_scene;
 pub mod snake_scene;
 mod stats_panel;
 mod throbber;
+pub mod zone_codex;
 
 use crate::challenges::ActiveMinigame;
 use crate::core::game_state::GameState;
-use crate::utils::updater::UpdateInfo;
+use crate::utils::updater::{CompletedUpdateCheck, UpdateInfo};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
@@ -47,7 +52,7 @@ pub fn draw_ui_with_update(
     game_state: &GameState,
     update_info: Option<&UpdateInfo>,
     update_expanded: bool,
-    update_check_completed: bool,
+    last_completed_check: Option<&CompletedUpdateCheck>,
     haven_discovered: bool,
     achievements: &crate::achievements::Achievements,
 ) {
@@ -66,7 +71,7 @@ pub fn draw_ui_with_update(
                 game_state,
                 update_info,
                 update_expanded,
-                update_check_completed,
+                last_completed_check,
                 haven_discovered,
                 achievements,
             );
@@ -77,6 +82,9 @@ pub fn draw_ui_with_update(
         SizeTier::S => {
             draw_s_layout(frame, &ctx, game_state, achievements);
         }
+        SizeTier::Tiny => {
+            draw_tiny_layout(frame, game_state);
+        }
         SizeTier::TooSmall => {
             // Already handled above
         }
@@ -91,7 +99,7 @@ fn draw_xl_l_layout(
     game_state: &GameState,
     update_info: Option<&UpdateInfo>,
     update_expanded: bool,
-    update_check_completed: bool,
+    last_completed_check: Option<&CompletedUpdateCheck>,
     haven_discovered: bool,
     achievements: &crate::achievements::Achievements,
 ) {
@@ -186,7 +194,7 @@ fn draw_xl_l_layout(
         game_state,
         update_info,
         update_expanded,
-        update_check_completed,
+        last_completed_check,
         haven_discovered,
         achievements.pending_count(),
         ctx,
@@ -328,14 +336,67 @@ fn draw_s_layout(
     stats_panel::draw_footer_minimal(frame, chunks[6], game_state);
 }
 
+/// Tiny tier: a single status line (level, HP %, current activity) for
+/// terminals too small for any panel, e.g. narrow phone SSH clients.
+fn draw_tiny_layout(frame: &mut Frame, game_state: &GameState) {
+    let area = frame.area();
+    let hp_percent = game_state.combat_state.player_current_hp as u64 * 100
+        / game_state.combat_state.player_max_hp.max(1) as u64;
+    let hp_color = if hp_percent > 50 {
+        Color::Green
+    } else if hp_percent > 25 {
+        Color::Yellow
+    } else {
+        Color::Red
+    };
+
+    let line = Line::from(vec![
+        Span::styled(
+            format!("Lv{} ", game_state.character_level),
+            Style::default().add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!("HP {}% ", hp_percent),
+            Style::default().fg(hp_color),
+        ),
+        Span::styled(tiny_activity(game_state), Style::default().fg(Color::Cyan)),
+    ]);
+    frame.render_widget(Paragraph::new(line), Rect { height: 1, ..area });
+}
+
+/// Short label for what the character is doing right now.
+fn tiny_activity(game_state: &GameState) -> String {
+    if game_state.active_minigame.is_some() {
+        "Challenge".to_string()
+    } else if game_state.active_fishing.is_some() {
+        "Fishing".to_string()
+    } else if game_state.combat_state.is_regenerating {
+        "Resting".to_string()
+    } else if let Some(enemy) = &game_state.combat_state.current_enemy {
+        let prefix = if game_state.active_dungeon.is_some() {
+            "Dungeon: "
+        } else {
+            ""
+        };
+        format!("{}vs {}", prefix, enemy.name)
+    } else {
+        "Exploring".to_string()
+    }
+}
+
 /// Draws player HP bar for S tier (borderless, single line).
 fn draw_s_player_hp(frame: &mut Frame, area: Rect, game_state: &GameState) {
     let hp_ratio = game_state.combat_state.player_current_hp as f64
         / game_state.combat_state.player_max_hp as f64;
 
+    let combat = &game_state.combat_state;
     let label = format!(
-        "HP: {}/{}",
-        game_state.combat_state.player_current_hp, game_state.combat_state.player_max_hp
+        "HP: {}",
+        game_common::format_hp(
+            combat.player_current_hp,
+            combat.player_max_hp,
+            combat.hp_display
+        )
     );
 
     let gauge = Gauge::default()
@@ -354,7 +415,15 @@ fn draw_s_player_hp(frame: &mut Frame, area: Rect, game_state: &GameState) {
 fn draw_s_enemy_hp(frame: &mut Frame, area: Rect, game_state: &GameState) {
     if let Some(enemy) = &game_state.combat_state.current_enemy {
         let hp_ratio = enemy.current_hp as f64 / enemy.max_hp as f64;
-        let label = format!("{}: {}/{}", enemy.name, enemy.current_hp, enemy.max_hp);
+        let label = format!(
+            "{}: {}",
+            enemy.name,
+            game_common::format_hp(
+                enemy.current_hp,
+                enemy.max_hp,
+                game_state.combat_state.hp_display
+            )
+        );
 
         let gauge = Gauge::default()
             .gauge_style(Style::default().fg(Color::Red).add_modifier(Modifier::BOLD))
@@ -491,6 +560,7 @@ fn draw_right_content(frame: &mut Frame, area: Rect, game_state: &GameState, ctx
                     frame,
                     area,
                     &game_state.challenge_menu,
+                    game_state.prestige_rank,
                     ctx,
                 );
             } else if let Some(ref session) = game_state.active_fishing {
@@ -502,6 +572,37 @@ fn draw_right_content(frame: &mut Frame, area: Rect, game_state: &GameState, ctx
             }
         }
     }
+
+    if game_state.active_minigame.is_some() {
+        draw_wager_line(frame, area, game_state);
+    }
+}
+
+/// One-line double-or-nothing prompt over the top of a minigame: the offer
+/// after a wagerable win, or the stake riding on the rematch.
+fn draw_wager_line(frame: &mut Frame, area: Rect, game_state: &GameState) {
+    use crate::challenges::{wager_rematch_difficulty, ChallengeDifficulty, Wager};
+
+    let text = if let Some(difficulty) = wager_rematch_difficulty(game_state) {
+        format!(
+            " [W] Double or nothing: stake this reward on a {} rematch ",
+            ChallengeDifficulty::from_index(difficulty).name()
+        )
+    } else if let Wager::Pending { stake } = game_state.wager {
+        format!(" Wager: win for {} ", stake.doubled().description())
+    } else {
+        return;
+    };
+
+    let line = Paragraph::new(Line::from(Span::styled(
+        text,
+        Style::default()
+            .fg(Color::Black)
+            .bg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
+    )))
+    .alignment(Alignment::Center);
+    frame.render_widget(line, Rect { height: 1, ..area });
 }
 
 /// Draws the dungeon view with combat HUD overlay on the map.
@@ -1,5 +1,7 @@
 use crate::character::prestige::{get_next_prestige_tier, get_prestige_tier};
 use crate::core::game_state::GameState;
+use crate::core::progression::zones_unlocked_by_prestige;
+use crate::zones::get_zone;
 use ratatui::{
     layout::{Alignment, Rect},
     style::{Color, Modifier, Style},
@@ -18,7 +20,7 @@ pub fn draw_prestige_confirm(
 
     // Calculate dialog size and position (centered)
     let dialog_width = 50.min(size.width.saturating_sub(4));
-    let dialog_height = 18.min(size.height.saturating_sub(4));
+    let dialog_height = 19.min(size.height.saturating_sub(4));
 
     let x = (size.width.saturating_sub(dialog_width)) / 2;
     let y = (size.height.saturating_sub(dialog_height)) / 2;
@@ -93,10 +95,27 @@ pub fn draw_prestige_confirm(
                     .add_modifier(Modifier::BOLD),
             ),
         ]),
-        Line::from(""),
-        Line::from(""),
     ];
 
+    let new_zones: Vec<&str> =
+        zones_unlocked_by_prestige(game_state.prestige_rank, game_state.prestige_rank + 1)
+            .into_iter()
+            .filter_map(|id| get_zone(id).map(|z| z.name))
+            .collect();
+    if !new_zones.is_empty() {
+        lines.push(Line::from(vec![
+            Span::raw("  - New zones: "),
+            Span::styled(
+                new_zones.join(", "),
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(""));
+
     // Add button hints
     lines.push(Line::from(vec![
         Span::raw("      "),
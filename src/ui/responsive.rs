@@ -15,10 +15,11 @@ use ratatui::{
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum SizeTier {
     TooSmall,
-    S,  // 40x16+
-    M,  // 60x24+
-    L,  // 80x30+
-    XL, // 120x40+
+    Tiny, // 20x1+ (one-line status only)
+    S,    // 40x16+
+    M,    // 60x24+
+    L,    // 80x30+
+    XL,   // 120x40+
 }
 
 // Threshold constants
@@ -30,6 +31,8 @@ const M_MIN_COLS: u16 = 60;
 const M_MIN_ROWS: u16 = 24;
 const S_MIN_COLS: u16 = 40;
 const S_MIN_ROWS: u16 = 16;
+const TINY_MIN_COLS: u16 = 20;
+const TINY_MIN_ROWS: u16 = 1;
 
 /// Independent width/height tier — allows "L-width but M-height" combinations.
 #[derive(Debug, Clone, Copy)]
@@ -53,8 +56,26 @@ impl LayoutContext {
 
     /// Create a LayoutContext from explicit dimensions (for testing).
     pub fn from_size(cols: u16, rows: u16) -> Self {
-        let width_tier = classify(cols, XL_MIN_COLS, L_MIN_COLS, M_MIN_COLS, S_MIN_COLS);
-        let height_tier = classify(rows, XL_MIN_ROWS, L_MIN_ROWS, M_MIN_ROWS, S_MIN_ROWS);
+        let width_tier = classify(
+            cols,
+            [
+                XL_MIN_COLS,
+                L_MIN_COLS,
+                M_MIN_COLS,
+                S_MIN_COLS,
+                TINY_MIN_COLS,
+            ],
+        );
+        let height_tier = classify(
+            rows,
+            [
+                XL_MIN_ROWS,
+                L_MIN_ROWS,
+                M_MIN_ROWS,
+                S_MIN_ROWS,
+                TINY_MIN_ROWS,
+            ],
+        );
         let tier = width_tier.min(height_tier);
 
         LayoutContext {
@@ -67,7 +88,8 @@ impl LayoutContext {
     }
 }
 
-fn classify(val: u16, xl: u16, l: u16, m: u16, s: u16) -> SizeTier {
+/// Classifies a dimension against minimums for XL, L, M, S and Tiny (in that order).
+fn classify(val: u16, [xl, l, m, s, tiny]: [u16; 5]) -> SizeTier {
     if val >= xl {
         SizeTier::XL
     } else if val >= l {
@@ -76,12 +98,14 @@ fn classify(val: u16, xl: u16, l: u16, m: u16, s: u16) -> SizeTier {
         SizeTier::M
     } else if val >= s {
         SizeTier::S
+    } else if val >= tiny {
+        SizeTier::Tiny
     } else {
         SizeTier::TooSmall
     }
 }
 
-/// Render a "terminal too small" message when below minimum size (40x16).
+/// Render a "terminal too small" message when below minimum size (20x1).
 pub fn render_too_small(frame: &mut Frame, ctx: &LayoutContext) {
     let area = frame.area();
     frame.render_widget(Clear, area);
@@ -98,7 +122,7 @@ pub fn render_too_small(frame: &mut Frame, ctx: &LayoutContext) {
         Line::from(Span::styled(
             format!(
                 "Need: {}x{}   Have: {}x{}",
-                S_MIN_COLS, S_MIN_ROWS, ctx.cols, ctx.rows
+                TINY_MIN_COLS, TINY_MIN_ROWS, ctx.cols, ctx.rows
             ),
             Style::default().fg(Color::DarkGray),
         )),
@@ -150,19 +174,39 @@ mod tests {
     }
 
     #[test]
-    fn test_too_small() {
+    fn test_tiny_width() {
         let ctx = LayoutContext::from_size(39, 20);
-        assert_eq!(ctx.width_tier, SizeTier::TooSmall);
+        assert_eq!(ctx.width_tier, SizeTier::Tiny);
         assert_eq!(ctx.height_tier, SizeTier::S);
-        assert_eq!(ctx.tier, SizeTier::TooSmall);
+        assert_eq!(ctx.tier, SizeTier::Tiny);
     }
 
     #[test]
-    fn test_too_small_height() {
+    fn test_tiny_height() {
         let ctx = LayoutContext::from_size(100, 15);
         assert_eq!(ctx.width_tier, SizeTier::L);
-        assert_eq!(ctx.height_tier, SizeTier::TooSmall);
+        assert_eq!(ctx.height_tier, SizeTier::Tiny);
+        assert_eq!(ctx.tier, SizeTier::Tiny);
+    }
+
+    #[test]
+    fn test_tiny_range() {
+        for (cols, rows) in [(20, 1), (39, 15), (30, 4), (120, 1), (20, 40)] {
+            let ctx = LayoutContext::from_size(cols, rows);
+            assert_eq!(ctx.tier, SizeTier::Tiny, "{}x{}", cols, rows);
+        }
+    }
+
+    #[test]
+    fn test_too_small() {
+        let ctx = LayoutContext::from_size(19, 20);
+        assert_eq!(ctx.width_tier, SizeTier::TooSmall);
+        assert_eq!(ctx.height_tier, SizeTier::S);
         assert_eq!(ctx.tier, SizeTier::TooSmall);
+
+        // Only below the Tiny minimums
+        assert_eq!(LayoutContext::from_size(19, 1).tier, SizeTier::TooSmall);
+        assert_eq!(LayoutContext::from_size(20, 1).tier, SizeTier::Tiny);
     }
 
     #[test]
@@ -1,12 +1,16 @@
 use super::responsive::{LayoutContext, SizeTier};
 use crate::character::attributes::AttributeType;
 use crate::character::derived_stats::DerivedStats;
-use crate::character::prestige::{get_adventurer_rank, get_prestige_tier};
+use crate::character::prestige::{
+    get_adventurer_rank, get_prestige_tier, prestige_streak_damage_percent, PrestigeTier,
+};
 use crate::core::game_logic::xp_for_next_level;
 use crate::core::game_state::GameState;
+use crate::fishing::logic::current_hotspot;
+use crate::fishing::tournament::current_tournament;
 use crate::fishing::types::FishingState;
 use crate::items::types::{Affix, AffixType, Rarity};
-use crate::utils::updater::UpdateInfo;
+use crate::utils::updater::{CompletedUpdateCheck, UpdateCheckResult, UpdateInfo};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
@@ -16,7 +20,7 @@ use ratatui::{
 };
 
 /// Formats an affix for display in the equipment panel.
-fn format_affix(affix: &Affix) -> String {
+pub(super) fn format_affix(affix: &Affix) -> String {
     match affix.affix_type {
         AffixType::DamagePercent => format!("+{:.0}% DMG", affix.value),
         AffixType::CritChance => format!("+{:.0}% CRIT", affix.value),
@@ -27,6 +31,9 @@ fn format_affix(affix: &Affix) -> String {
         AffixType::HPRegen => format!("+{:.0}% Regen", affix.value),
         AffixType::DamageReflection => format!("+{:.0}% Reflect", affix.value),
         AffixType::XPGain => format!("+{:.0}% XP", affix.value),
+        AffixType::OnKillHeal => format!("+{:.0}% Kill Heal", affix.value),
+        AffixType::OnKillRage => format!("+{:.0}% Kill Rage", affix.value),
+        AffixType::FragileArmor => format!("-{:.0}% DEF (Cursed)", affix.value),
     }
 }
 
@@ -200,6 +207,7 @@ pub(super) fn draw_zone_info(
     achievements: &crate::achievements::Achievements,
     _ctx: &LayoutContext,
 ) {
+    use crate::combat::combat_math::{difficulty_label, forecast, DifficultyLabel};
     use crate::zones::get_all_zones;
 
     let zones = get_all_zones();
@@ -233,8 +241,18 @@ pub(super) fn draw_zone_info(
                 .add_modifier(Modifier::BOLD | Modifier::SLOW_BLINK),
         )
     } else if prog.fighting_boss {
+        // Forecast against the live boss (it spawns at full HP, so this shows before the first swing)
+        let risky = game_state
+            .combat_state
+            .current_enemy
+            .as_ref()
+            .is_some_and(|boss| forecast(game_state, boss).is_risky());
         Span::styled(
-            format!(" ⚔️ BOSS: {} ", boss_name),
+            format!(
+                " ⚔️ BOSS: {} {}",
+                boss_name,
+                if risky { "⚠ Risky " } else { "" }
+            ),
             Style::default()
                 .fg(Color::Red)
                 .add_modifier(Modifier::BOLD | Modifier::SLOW_BLINK),
@@ -247,6 +265,14 @@ pub(super) fn draw_zone_info(
         )
     };
 
+    let difficulty = difficulty_label(game_state);
+    let difficulty_color = match difficulty {
+        DifficultyLabel::Trivial => Color::DarkGray,
+        DifficultyLabel::Fair => Color::Green,
+        DifficultyLabel::Risky => Color::Yellow,
+        DifficultyLabel::Deadly => Color::Red,
+    };
+
     let mut zone_lines = vec![Line::from(vec![
         Span::styled(
             format!("Zone {}: ", prog.current_zone_id),
@@ -262,6 +288,10 @@ pub(super) fn draw_zone_info(
             format!(" ({}/{})", prog.current_subzone_id, total_subzones),
             Style::default().fg(Color::DarkGray),
         ),
+        Span::styled(
+            format!(" {}", difficulty.name()),
+            Style::default().fg(difficulty_color),
+        ),
         boss_progress,
     ])];
 
@@ -415,7 +445,11 @@ fn draw_prestige_info(frame: &mut Frame, area: Rect, game_state: &GameState) {
         Line::from(vec![
             Span::styled("🏆 Rank: ", Style::default().add_modifier(Modifier::BOLD)),
             Span::styled(
-                format!("{} ({})", game_state.prestige_rank, tier.name),
+                format!(
+                    "{} ({})",
+                    game_state.prestige_rank,
+                    PrestigeTier::display_name(game_state.prestige_rank)
+                ),
                 Style::default().fg(Color::Yellow),
             ),
         ]),
@@ -444,6 +478,15 @@ fn draw_prestige_info(frame: &mut Frame, area: Rect, game_state: &GameState) {
                 format!("{}", game_state.total_prestige_count),
                 Style::default().fg(Color::Magenta),
             ),
+            Span::raw(if game_state.prestige_streak > 0 {
+                format!(
+                    "  🔥 Streak {} (+{:.0}% dmg)",
+                    game_state.prestige_streak,
+                    prestige_streak_damage_percent(game_state.prestige_streak)
+                )
+            } else {
+                String::new()
+            }),
         ]),
     ];
 
@@ -469,7 +512,7 @@ fn draw_fishing_panel(frame: &mut Frame, area: Rect, game_state: &GameState) {
         0.0
     };
 
-    let rank_line = Line::from(vec![
+    let mut rank_spans = vec![
         Span::styled("🎣 Rank: ", Style::default().add_modifier(Modifier::BOLD)),
         Span::styled(
             format!(
@@ -479,7 +522,37 @@ fn draw_fishing_panel(frame: &mut Frame, area: Rect, game_state: &GameState) {
             ),
             Style::default().fg(Color::Cyan),
         ),
-    ]);
+    ];
+    if let Some(rod) = &game_state.fishing_gear.rod {
+        rank_spans.push(Span::styled(
+            format!("  {}", rod.name),
+            Style::default().fg(Color::Gray),
+        ));
+    }
+    if let Some(bait) = &game_state.active_bait {
+        rank_spans.push(Span::styled(
+            format!("  🪱 {} x{}", bait.kind.name(), bait.remaining_casts),
+            Style::default().fg(Color::Yellow),
+        ));
+    }
+    if let Some(hotspot) = current_hotspot(chrono::Utc::now().timestamp()) {
+        rank_spans.push(Span::styled(
+            format!("  🔥 {} hotspot", hotspot.name()),
+            Style::default().fg(Color::LightRed),
+        ));
+    }
+    if let Some(window) = current_tournament(chrono::Utc::now().timestamp()) {
+        let score = game_state
+            .fishing
+            .tournament
+            .filter(|t| t.window == window)
+            .map_or(0, |t| t.score);
+        rank_spans.push(Span::styled(
+            format!("  🏆 Tournament: {} pts", score),
+            Style::default().fg(Color::LightYellow),
+        ));
+    }
+    let rank_line = Line::from(rank_spans);
 
     let fish_label = format!("{}/{}", fish_progress, fish_required);
     let fish_gauge = Gauge::default()
@@ -508,8 +581,22 @@ fn draw_fishing_panel(frame: &mut Frame, area: Rect, game_state: &GameState) {
 }
 
 /// Draws equipment section with all 7 equipment slots
+/// Equipment block title, with the loot filter setting set by `[L]`.
+fn equipment_title(game_state: &GameState) -> Line<'static> {
+    let filter = match game_state.loot_filter_min_rarity {
+        Some(rarity) => Span::styled(
+            format!("Filter: <{} ", rarity.name()),
+            Style::default().fg(Color::Yellow),
+        ),
+        None => Span::styled("Filter: Off ", Style::default().fg(Color::DarkGray)),
+    };
+    Line::from(vec![Span::raw("Equipment · "), filter])
+}
+
 fn draw_equipment_section(frame: &mut Frame, area: Rect, game_state: &GameState) {
-    let equipment_block = Block::default().borders(Borders::ALL).title("Equipment");
+    let equipment_block = Block::default()
+        .borders(Borders::ALL)
+        .title(equipment_title(game_state));
 
     let inner = equipment_block.inner(area);
     frame.render_widget(equipment_block, area);
@@ -593,9 +680,14 @@ fn draw_equipment_section(frame: &mut Frame, area: Rect, game_state: &GameState)
                     if i > 0 {
                         affix_spans.push(Span::styled(" ", Style::default()));
                     }
+                    let color = if affix.affix_type.is_curse() {
+                        Color::Red
+                    } else {
+                        Color::Gray
+                    };
                     affix_spans.push(Span::styled(
                         format_affix(affix),
-                        Style::default().fg(Color::Gray),
+                        Style::default().fg(color),
                     ));
                 }
                 lines.push(Line::from(affix_spans));
@@ -669,7 +761,9 @@ fn draw_attributes_compact(frame: &mut Frame, area: Rect, game_state: &GameState
 
 /// Draws equipment with name + rarity color only, one line per slot (L tier).
 fn draw_equipment_names_only(frame: &mut Frame, area: Rect, game_state: &GameState) {
-    let block = Block::default().borders(Borders::ALL).title("Equipment");
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(equipment_title(game_state));
     let inner = block.inner(area);
     frame.render_widget(block, area);
 
@@ -841,8 +935,29 @@ pub(super) fn draw_xp_bar_compact(frame: &mut Frame, area: Rect, game_state: &Ga
     frame.render_widget(xp_gauge, area);
 }
 
+/// Expedition hint: dispatch key when idle, countdown while away.
+fn expedition_span(game_state: &GameState, compact: bool) -> Span<'static> {
+    match &game_state.expedition {
+        None if compact => Span::styled(" [E]Exped", Style::default().fg(Color::Green)),
+        None => Span::styled("    [E] Expedition", Style::default().fg(Color::Green)),
+        Some(expedition) => {
+            let remaining = expedition.remaining_seconds(chrono::Utc::now().timestamp());
+            let status = if remaining == 0 {
+                "back next login".to_string()
+            } else {
+                format!("{}h {}m", remaining / 3600, (remaining % 3600) / 60)
+            };
+            let sep = if compact { " " } else { "    " };
+            Span::styled(
+                format!("{}\u{26fa} {}", sep, status),
+                Style::default().fg(Color::DarkGray),
+            )
+        }
+    }
+}
+
 /// Draws a compact footer for M tier (1 row, no borders).
-/// Format: "[Esc]Quit [P]Prestige [H]Haven [A]Ach [Tab]Chall"
+/// Format: "[Esc]Quit [?]Keys [P]Prestige [H]Haven [A]Ach [Tab]Chall"
 pub(super) fn draw_footer_compact(
     frame: &mut Frame,
     area: Rect,
@@ -895,10 +1010,12 @@ pub(super) fn draw_footer_compact(
 
     let line = Line::from(vec![
         Span::styled("[Esc]Quit", Style::default().fg(Color::Red)),
+        Span::styled(" [?]Keys", Style::default().fg(Color::White)),
         Span::raw(" "),
         prestige_span,
         haven_span,
         ach_span,
+        expedition_span(game_state, true),
         challenge_span,
     ]);
 
@@ -907,7 +1024,7 @@ pub(super) fn draw_footer_compact(
 }
 
 /// Draws a minimal footer for S tier (1 row, minimal keybindings).
-/// Format: "Esc:Quit P:Prestige Tab:More"
+/// Format: "Esc:Quit ?:Keys P:Prestige Tab:More"
 pub(super) fn draw_footer_minimal(frame: &mut Frame, area: Rect, game_state: &GameState) {
     use crate::character::prestige::can_prestige;
 
@@ -925,6 +1042,7 @@ pub(super) fn draw_footer_minimal(frame: &mut Frame, area: Rect, game_state: &Ga
 
     let line = Line::from(vec![
         Span::styled("Esc:Quit", Style::default().fg(Color::Red)),
+        Span::styled(" ?:Keys", Style::default().fg(Color::White)),
         prestige_span,
         Span::styled(" Tab:More", Style::default().fg(Color::DarkGray)),
     ]);
@@ -1056,6 +1174,18 @@ pub fn draw_update_drawer(frame: &mut Frame, area: Rect, info: &UpdateInfo) {
     frame.render_widget(drawer, area);
 }
 
+/// Longest update-check error shown in the footer, in characters
+const FOOTER_ERROR_MAX_CHARS: usize = 40;
+
+/// Shortens an update-check error so it fits on the footer line.
+fn footer_error_text(message: &str) -> String {
+    if message.chars().count() <= FOOTER_ERROR_MAX_CHARS {
+        return message.to_string();
+    }
+    let kept: String = message.chars().take(FOOTER_ERROR_MAX_CHARS - 1).collect();
+    format!("{kept}…")
+}
+
 /// Draws the footer with control instructions and version info
 #[allow(clippy::too_many_arguments)]
 pub fn draw_footer(
@@ -1064,7 +1194,7 @@ pub fn draw_footer(
     game_state: &GameState,
     update_info: Option<&UpdateInfo>,
     _update_expanded: bool,
-    update_check_completed: bool,
+    last_completed_check: Option<&CompletedUpdateCheck>,
     haven_discovered: bool,
     pending_achievements: usize,
     _ctx: &LayoutContext,
@@ -1100,8 +1230,26 @@ pub fn draw_footer(
                 .fg(Color::Yellow)
                 .add_modifier(Modifier::BOLD),
         )
-    } else if update_check_completed {
-        Span::styled("    ✓ Up to date", Style::default().fg(Color::Green))
+    } else if let Some(check) = last_completed_check {
+        let checked_at = check.checked_at.format("%H:%M");
+        match &check.result {
+            UpdateCheckResult::Offline => Span::styled(
+                format!("    ⚠ Offline (checked {})", checked_at),
+                Style::default().fg(Color::Yellow),
+            ),
+            UpdateCheckResult::Error(message) => Span::styled(
+                format!(
+                    "    ✗ Update check failed: {} ({})",
+                    footer_error_text(message),
+                    checked_at
+                ),
+                Style::default().fg(Color::Red),
+            ),
+            _ => Span::styled(
+                format!("    ✓ Up to date (checked {})", checked_at),
+                Style::default().fg(Color::Green),
+            ),
+        }
     } else {
         use super::throbber::spinner_char;
         Span::styled(
@@ -1144,10 +1292,12 @@ pub fn draw_footer(
 
     let footer_text = vec![Line::from(vec![
         Span::styled("[Esc] Quit", Style::default().fg(Color::Red)),
+        Span::styled("    [?] Keys", Style::default().fg(Color::White)),
         Span::raw("    "),
         prestige_text,
         haven_text,
         achievements_text,
+        expedition_span(game_state, false),
         challenge_text,
         update_status_text,
     ])];
@@ -0,0 +1,138 @@
+use crate::zones::codex::{CodexZone, ZoneCodex};
+use crate::zones::ZoneProgression;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+/// Draws the zone codex opened with `[Z]`: every zone on the left, the
+/// selected zone's lore and bosses on the right
+pub fn draw_zone_codex(
+    frame: &mut Frame,
+    progression: &ZoneProgression,
+    selected: usize,
+    _ctx: &super::responsive::LayoutContext,
+) {
+    let codex = ZoneCodex::from_progression(progression);
+    let size = frame.area();
+
+    let dialog_width = 76.min(size.width.saturating_sub(4));
+    let dialog_height = 20.min(size.height.saturating_sub(2));
+
+    let x = (size.width.saturating_sub(dialog_width)) / 2;
+    let y = (size.height.saturating_sub(dialog_height)) / 2;
+
+    let dialog_area = Rect::new(x, y, dialog_width, dialog_height);
+
+    frame.render_widget(Clear, dialog_area);
+
+    let title = Line::from(vec![Span::styled(
+        format!(
+            " Zone Codex ({}/{} discovered) ",
+            codex.discovered_count(),
+            codex.zones.len()
+        ),
+        Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD),
+    )]);
+    let block = Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+    let inner = block.inner(dialog_area);
+    frame.render_widget(block, dialog_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(inner);
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Length(26), Constraint::Min(0)])
+        .split(chunks[0]);
+
+    let list: Vec<Line> = codex
+        .zones
+        .iter()
+        .enumerate()
+        .map(|(i, zone)| {
+            let marker = if i == selected { "> " } else { "  " };
+            let style = if i == selected {
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD)
+            } else if zone.discovered {
+                Style::default().fg(Color::White)
+            } else {
+                Style::default().fg(Color::DarkGray)
+            };
+            Line::from(Span::styled(
+                format!("{}{:>2}. {}", marker, zone.zone_id, zone.name),
+                style,
+            ))
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(list), columns[0]);
+
+    if let Some(zone) = codex.zones.get(selected) {
+        frame.render_widget(
+            Paragraph::new(zone_detail(zone)).wrap(Wrap { trim: true }),
+            columns[1],
+        );
+    }
+
+    frame.render_widget(
+        Paragraph::new(Line::from(Span::styled(
+            "[↑/↓] Select zone  [Esc] Close",
+            Style::default().fg(Color::DarkGray),
+        )))
+        .alignment(Alignment::Center),
+        chunks[1],
+    );
+}
+
+fn zone_detail(zone: &CodexZone) -> Vec<Line<'static>> {
+    if !zone.discovered {
+        return vec![Line::from(Span::styled(
+            "Reach this zone to reveal it.",
+            Style::default().fg(Color::DarkGray),
+        ))];
+    }
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            zone.name,
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::styled(zone.lore, Style::default().fg(Color::Gray))),
+        Line::from(""),
+        Line::from(format!(
+            "Bosses ({}/{} defeated)",
+            zone.bosses_defeated(),
+            zone.bosses.len()
+        )),
+    ];
+    for boss in &zone.bosses {
+        let (mark, color) = if boss.defeated {
+            ("✓", Color::Green)
+        } else {
+            ("·", Color::DarkGray)
+        };
+        let crown = if boss.is_zone_boss { " 👑" } else { "" };
+        lines.push(Line::from(vec![
+            Span::styled(format!(" {} ", mark), Style::default().fg(color)),
+            Span::raw(format!(
+                "{}: {}{}",
+                boss.subzone_name, boss.boss_name, crown
+            )),
+        ]));
+    }
+    lines
+}
@@ -0,0 +1,21 @@
+//! `quest card`: print a shareable progress card for the most recent character.
+
+use std::error::Error;
+
+use crate::achievements::load_achievements;
+use crate::character::manager::CharacterManager;
+
+/// Run the card command (quest card).
+/// Loads the most recently saved character and prints its Markdown card.
+pub fn run_card_command() -> Result<(), Box<dyn Error>> {
+    let manager = CharacterManager::new()?;
+    let latest = manager
+        .list_characters()?
+        .into_iter()
+        .find(|info| !info.is_corrupted)
+        .ok_or("no characters found")?;
+
+    let state = manager.force_load_character(&latest.filename)?;
+    print!("{}", load_achievements().to_share_card(&state));
+    Ok(())
+}
@@ -3,10 +3,15 @@
 //! Activated with `--debug` flag. Press backtick to toggle menu.
 
 use crate::challenges::menu::{create_challenge, ChallengeType};
+use crate::combat::training_dummy::TrainingDummy;
 use crate::core::game_state::GameState;
+use crate::core::offline::{process_offline_progression_at, OfflineReport};
 use crate::dungeon::generation::generate_dungeon;
 use crate::fishing::generation::generate_fishing_session;
-use crate::haven::Haven;
+use crate::haven::{Haven, HavenBonusType};
+
+/// Time away used by the "Simulate Offline" option
+pub const DEBUG_OFFLINE_SECONDS: i64 = 8 * 60 * 60;
 
 /// Menu options available in debug mode
 pub const DEBUG_OPTIONS: &[&str] = &[
@@ -22,6 +27,8 @@ pub const DEBUG_OPTIONS: &[&str] = &[
     "Trigger JezzBall Challenge",
     "Trigger Snake Challenge",
     "Trigger Haven Discovery",
+    "Toggle Training Dummy",
+    "Simulate 8h Offline",
 ];
 
 /// Debug menu state
@@ -29,6 +36,8 @@ pub const DEBUG_OPTIONS: &[&str] = &[
 pub struct DebugMenu {
     pub is_open: bool,
     pub selected_index: usize,
+    /// Report from the last "Simulate Offline", waiting to be shown
+    pub simulated_offline: Option<OfflineReport>,
 }
 
 impl DebugMenu {
@@ -80,6 +89,12 @@ impl DebugMenu {
             9 => trigger_jezzball_challenge(state),
             10 => trigger_snake_challenge(state),
             11 => trigger_haven_discovery(haven),
+            12 => toggle_training_dummy(state),
+            13 => {
+                self.simulated_offline =
+                    Some(simulate_offline(state, haven, DEBUG_OFFLINE_SECONDS));
+                "Simulated 8h offline (preview only, state unchanged)"
+            }
             _ => "Unknown option",
         };
         self.close();
@@ -87,6 +102,15 @@ impl DebugMenu {
     }
 }
 
+/// Runs offline progression for `elapsed_seconds` on a copy of `state` and
+/// returns the welcome-back report. The real state and clock are untouched.
+pub fn simulate_offline(state: &GameState, haven: &Haven, elapsed_seconds: i64) -> OfflineReport {
+    let mut preview = state.clone();
+    let haven_offline_bonus = haven.get_bonus(HavenBonusType::OfflineXpPercent);
+    let now = preview.last_save_time + elapsed_seconds;
+    process_offline_progression_at(&mut preview, haven_offline_bonus, now)
+}
+
 fn trigger_dungeon(state: &mut GameState) -> &'static str {
     if state.active_dungeon.is_some() {
         return "Already in a dungeon!";
@@ -216,6 +240,30 @@ fn trigger_haven_discovery(haven: &mut Haven) -> &'static str {
     "Haven discovered!"
 }
 
+/// Place or remove the training dummy. Removing it logs the session's DPS report.
+fn toggle_training_dummy(state: &mut GameState) -> &'static str {
+    if let Some(dummy) = state.training_dummy.take() {
+        state.combat_state.add_log_entry(
+            format!("[DEBUG] {}", dummy.report().summary()),
+            false,
+            true,
+        );
+        state.combat_state.current_enemy = None;
+        return "Training dummy removed!";
+    }
+    if state.active_dungeon.is_some() {
+        return "Cannot train while in dungeon!";
+    }
+    if state.active_fishing.is_some() {
+        return "Cannot train while fishing!";
+    }
+    state.training_dummy = Some(TrainingDummy::new());
+    state.combat_state.current_enemy = Some(TrainingDummy::enemy());
+    state.combat_state.player_attack_timer = 0.0;
+    state.combat_state.enemy_attack_timer = 0.0;
+    "Training dummy placed!"
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -239,14 +287,16 @@ mod tests {
         menu.navigate_down();
         menu.navigate_down();
         menu.navigate_down();
-        assert_eq!(menu.selected_index, 11);
+        menu.navigate_down();
+        menu.navigate_down();
+        assert_eq!(menu.selected_index, 13);
 
         // Can't go past end
         menu.navigate_down();
-        assert_eq!(menu.selected_index, 11);
+        assert_eq!(menu.selected_index, 13);
 
         menu.navigate_up();
-        assert_eq!(menu.selected_index, 10);
+        assert_eq!(menu.selected_index, 12);
 
         // Can't go before start
         menu.navigate_up();
@@ -259,6 +309,8 @@ mod tests {
         menu.navigate_up();
         menu.navigate_up();
         menu.navigate_up();
+        menu.navigate_up();
+        menu.navigate_up();
         assert_eq!(menu.selected_index, 0);
     }
 
@@ -274,6 +326,29 @@ mod tests {
         assert!(!menu.is_open);
     }
 
+    #[test]
+    fn test_simulate_offline_matches_real_progression() {
+        let mut state = GameState::new("Test".to_string(), 0);
+        state.character_level = 10;
+        let haven = Haven::default();
+        let elapsed = 2 * 60 * 60;
+
+        let simulated = simulate_offline(&state, &haven, elapsed);
+        // The simulation leaves the real state alone
+        assert_eq!(state.character_level, 10);
+
+        let now = state.last_save_time + elapsed;
+        let direct = process_offline_progression_at(&mut state, 0.0, now);
+        assert_eq!(simulated.elapsed_seconds, elapsed);
+        assert_eq!(simulated.elapsed_seconds, direct.elapsed_seconds);
+        assert_eq!(simulated.xp_gained, direct.xp_gained);
+        assert_eq!(simulated.total_level_ups, direct.total_level_ups);
+        assert_eq!(simulated.level_before, direct.level_before);
+        assert_eq!(simulated.level_after, direct.level_after);
+        assert_eq!(simulated.offline_rate_percent, direct.offline_rate_percent);
+        assert!(simulated.xp_gained > 0);
+    }
+
     #[test]
     fn test_trigger_dungeon() {
         let mut state = GameState::new("Test".to_string(), 0);
@@ -422,4 +497,30 @@ mod tests {
         let msg = trigger_haven_discovery(&mut haven);
         assert_eq!(msg, "Haven already discovered!");
     }
+
+    #[test]
+    fn test_toggle_training_dummy() {
+        let mut state = GameState::new("Test".to_string(), 0);
+        let msg = toggle_training_dummy(&mut state);
+        assert_eq!(msg, "Training dummy placed!");
+        assert!(state.training_dummy.is_some());
+        assert_eq!(
+            state.combat_state.current_enemy.as_ref().unwrap().name,
+            crate::combat::training_dummy::TRAINING_DUMMY_NAME
+        );
+
+        let msg = toggle_training_dummy(&mut state);
+        assert_eq!(msg, "Training dummy removed!");
+        assert!(state.training_dummy.is_none());
+        assert!(state.combat_state.current_enemy.is_none());
+    }
+
+    #[test]
+    fn test_training_dummy_blocked_in_dungeon() {
+        let mut state = GameState::new("Test".to_string(), 0);
+        trigger_dungeon(&mut state);
+        let msg = toggle_training_dummy(&mut state);
+        assert_eq!(msg, "Cannot train while in dungeon!");
+        assert!(state.training_dummy.is_none());
+    }
 }
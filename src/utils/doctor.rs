@@ -0,0 +1,51 @@
+//! `quest doctor`: offline save validation for support.
+
+use std::error::Error;
+use std::path::Path;
+
+use crate::character::manager::{read_save_file, write_save_file, LoadError};
+
+/// Run the doctor command (quest doctor <save-path> [--write <out-path>]).
+/// Reports problems found in the save and, with `--write`, saves a repaired
+/// copy to `out-path`. The original file is never modified.
+/// Returns Ok(true) if the save was healthy.
+pub fn run_doctor_command(args: &[String]) -> Result<bool, Box<dyn Error>> {
+    let (save_path, out_path) = match args {
+        [save] => (save, None),
+        [save, flag, out] if flag == "--write" => (save, Some(out)),
+        _ => {
+            eprintln!("Usage: quest doctor <save-path> [--write <out-path>]");
+            return Err("expected a save path".into());
+        }
+    };
+
+    println!("Checking {}...\n", save_path);
+
+    let path = Path::new(save_path);
+    let mut healthy = true;
+    let mut state = match read_save_file(path, true) {
+        Err(LoadError::ChecksumMismatch) => {
+            healthy = false;
+            println!("  \u{26a0} Checksum mismatch: this file was edited or corrupted");
+            read_save_file(path, false)?
+        }
+        other => other?,
+    };
+
+    let actions = state.validate_and_repair();
+    for action in &actions {
+        println!("  \u{26a0} {}", action);
+    }
+    healthy &= actions.is_empty();
+
+    if healthy {
+        println!("  No problems found.");
+    }
+
+    if let Some(out) = out_path {
+        write_save_file(&state, Path::new(out))?;
+        println!("\nRepaired copy written to {}", out);
+    }
+
+    Ok(healthy)
+}
@@ -1,11 +1,17 @@
-//! Utility modules: build info, updater, debug menu.
+//! Utility modules: build info, updater, debug menu, save doctor, share card, settings,
+//! shutdown.
 
 #![allow(unused_imports)]
 
 pub mod build_info;
+pub mod card;
 pub mod debug_menu;
+pub mod doctor;
+pub mod settings;
+pub mod shutdown;
 pub mod updater;
 
 pub use build_info::*;
 pub use debug_menu::*;
+pub use settings::*;
 pub use updater::*;
@@ -0,0 +1,349 @@
+//! Account-level player settings, saved to `~/.quest/settings.toml`.
+//!
+//! Missing settings fall back to defaults, and unknown, missing, or invalid
+//! fields default individually so one bad hand edit doesn't reset the rest.
+//! A file that isn't valid TOML at all is copied to `settings.toml.bak`
+//! before defaults are used, so the next save doesn't lose it.
+
+use crate::challenges::menu::ChallengeDifficulty;
+use crate::core::constants::MAX_VISUAL_EFFECTS;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// How many combat visual effects are spawned per hit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum EffectsIntensity {
+    /// Damage numbers, attack flash, and hit impact
+    #[default]
+    Full,
+    /// Damage numbers only (for slow terminals and SSH sessions)
+    Reduced,
+    /// No visual effects; the combat log is unaffected
+    Off,
+}
+
+impl EffectsIntensity {
+    pub fn name(&self) -> &'static str {
+        match self {
+            EffectsIntensity::Full => "Full",
+            EffectsIntensity::Reduced => "Reduced",
+            EffectsIntensity::Off => "Off",
+        }
+    }
+
+    /// Next level in the Full → Reduced → Off cycle.
+    pub fn next(&self) -> Self {
+        match self {
+            EffectsIntensity::Full => EffectsIntensity::Reduced,
+            EffectsIntensity::Reduced => EffectsIntensity::Off,
+            EffectsIntensity::Off => EffectsIntensity::Full,
+        }
+    }
+
+    pub fn shows_damage_numbers(&self) -> bool {
+        !matches!(self, EffectsIntensity::Off)
+    }
+
+    pub fn shows_hit_flourishes(&self) -> bool {
+        matches!(self, EffectsIntensity::Full)
+    }
+}
+
+/// How large numbers (XP totals and gains) are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum NumberFormat {
+    /// Suffixed: 1.5M, 2.3B
+    #[default]
+    Short,
+    /// Scientific notation: 1.50e6
+    Scientific,
+    /// Every digit: 1500000
+    Full,
+}
+
+impl NumberFormat {
+    pub fn name(&self) -> &'static str {
+        match self {
+            NumberFormat::Short => "Short",
+            NumberFormat::Scientific => "Scientific",
+            NumberFormat::Full => "Full",
+        }
+    }
+
+    /// Next format in the Short → Scientific → Full cycle.
+    pub fn next(&self) -> Self {
+        match self {
+            NumberFormat::Short => NumberFormat::Scientific,
+            NumberFormat::Scientific => NumberFormat::Full,
+            NumberFormat::Full => NumberFormat::Short,
+        }
+    }
+}
+
+/// Which combat log entries are kept.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum LogFilter {
+    /// Every entry
+    #[default]
+    All,
+    /// Drops routine (non-crit) player hits; crits, kills, loot, and
+    /// everything else stay
+    Important,
+}
+
+impl LogFilter {
+    pub fn name(&self) -> &'static str {
+        match self {
+            LogFilter::All => "All",
+            LogFilter::Important => "Important only",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            LogFilter::All => LogFilter::Important,
+            LogFilter::Important => LogFilter::All,
+        }
+    }
+}
+
+/// How HP bars label current and max HP.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum HpDisplay {
+    /// Exact values: 730/1000
+    #[default]
+    Numbers,
+    /// Percentage of max: 73%
+    Percent,
+}
+
+impl HpDisplay {
+    pub fn name(&self) -> &'static str {
+        match self {
+            HpDisplay::Numbers => "Numbers",
+            HpDisplay::Percent => "Percent",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            HpDisplay::Numbers => HpDisplay::Percent,
+            HpDisplay::Percent => HpDisplay::Numbers,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Settings {
+    #[serde(default)]
+    pub effects_intensity: EffectsIntensity,
+    /// When set, discovered challenges are resolved at this difficulty
+    /// instead of waiting in the challenge menu: board games are auto-played,
+    /// skill games forfeited.
+    #[serde(default)]
+    pub auto_challenge: Option<ChallengeDifficulty>,
+    #[serde(default)]
+    pub number_format: NumberFormat,
+    #[serde(default)]
+    pub log_filter: LogFilter,
+    #[serde(default)]
+    pub hp_display: HpDisplay,
+    /// Most combat visual effects alive at once; the oldest are dropped past it
+    #[serde(default = "default_max_visual_effects")]
+    pub max_visual_effects: usize,
+}
+
+fn default_max_visual_effects() -> usize {
+    MAX_VISUAL_EFFECTS
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            effects_intensity: EffectsIntensity::default(),
+            auto_challenge: None,
+            number_format: NumberFormat::default(),
+            log_filter: LogFilter::default(),
+            hp_display: HpDisplay::default(),
+            max_visual_effects: MAX_VISUAL_EFFECTS,
+        }
+    }
+}
+
+const SETTINGS_FILE: &str = "settings.toml";
+
+/// Where an unparseable `settings.toml` is copied before defaults are used
+const BACKUP_SETTINGS_FILE: &str = "settings.toml.bak";
+
+impl Settings {
+    /// Load settings from disk, or return defaults if not found. The second
+    /// value is a warning for the player when the file was unreadable TOML.
+    pub fn load() -> (Self, Option<String>) {
+        let Ok(dir) = crate::core::paths::quest_dir() else {
+            return (Self::default(), None);
+        };
+        let path = dir.join(SETTINGS_FILE);
+        let Ok(text) = fs::read_to_string(&path) else {
+            return (Self::default(), None);
+        };
+        match Self::from_toml(&text) {
+            Ok(settings) => (settings, None),
+            Err(e) => {
+                fs::copy(&path, dir.join(BACKUP_SETTINGS_FILE)).ok();
+                let warning = format!(
+                    "Ignoring invalid {} (copied to {}): {}",
+                    SETTINGS_FILE,
+                    BACKUP_SETTINGS_FILE,
+                    e.message()
+                );
+                (Self::default(), Some(warning))
+            }
+        }
+    }
+
+    /// Save settings to disk
+    pub fn save(&self) -> io::Result<()> {
+        let dir = crate::core::paths::quest_dir()?;
+        fs::create_dir_all(&dir)?;
+        fs::write(dir.join(SETTINGS_FILE), self.to_toml()?)
+    }
+
+    /// Parses settings TOML. Each missing or invalid field takes its default
+    /// on its own; only a file that isn't valid TOML is an error.
+    pub fn from_toml(text: &str) -> Result<Self, toml::de::Error> {
+        text.parse::<toml::Table>()
+            .map(|table| Self::from_table(&table))
+    }
+
+    fn from_table(table: &toml::Table) -> Self {
+        // The whole file usually parses; only a bad hand edit needs the
+        // field-by-field fallback below.
+        if let Ok(settings) = table.clone().try_into() {
+            return settings;
+        }
+
+        fn field<T: DeserializeOwned>(table: &toml::Table, key: &str, default: T) -> T {
+            table
+                .get(key)
+                .and_then(|value| value.clone().try_into().ok())
+                .unwrap_or(default)
+        }
+
+        let defaults = Self::default();
+        Self {
+            effects_intensity: field(table, "effects_intensity", defaults.effects_intensity),
+            auto_challenge: field(table, "auto_challenge", defaults.auto_challenge),
+            number_format: field(table, "number_format", defaults.number_format),
+            log_filter: field(table, "log_filter", defaults.log_filter),
+            hp_display: field(table, "hp_display", defaults.hp_display),
+            max_visual_effects: field(table, "max_visual_effects", defaults.max_visual_effects),
+        }
+    }
+
+    pub fn to_toml(&self) -> io::Result<String> {
+        toml::to_string_pretty(self).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_is_full_effects() {
+        assert_eq!(
+            Settings::default().effects_intensity,
+            EffectsIntensity::Full
+        );
+    }
+
+    #[test]
+    fn test_missing_fields_use_defaults() {
+        assert_eq!(Settings::from_toml("").unwrap(), Settings::default());
+
+        let settings = Settings::from_toml("number_format = \"Full\"\n").unwrap();
+        assert_eq!(
+            settings,
+            Settings {
+                number_format: NumberFormat::Full,
+                ..Settings::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_malformed_toml_is_an_error() {
+        assert!(Settings::from_toml("effects_intensity = [oops").is_err());
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let settings = Settings {
+            effects_intensity: EffectsIntensity::Reduced,
+            auto_challenge: Some(ChallengeDifficulty::Journeyman),
+            number_format: NumberFormat::Scientific,
+            log_filter: LogFilter::Important,
+            hp_display: HpDisplay::Percent,
+            max_visual_effects: 50,
+        };
+        let text = settings.to_toml().unwrap();
+        assert_eq!(Settings::from_toml(&text).unwrap(), settings);
+
+        // Every field is changed from its default, so a field the loader
+        // forgets would fail the round trip above
+        let table: toml::Table = text.parse().unwrap();
+        let default_table: toml::Table = Settings::default().to_toml().unwrap().parse().unwrap();
+        for (key, value) in &table {
+            assert_ne!(
+                default_table.get(key),
+                Some(value),
+                "{} is its default",
+                key
+            );
+        }
+        assert!(default_table.keys().all(|key| table.contains_key(key)));
+
+        let defaults = Settings::default();
+        assert_eq!(
+            Settings::from_toml(&defaults.to_toml().unwrap()).unwrap(),
+            defaults
+        );
+    }
+
+    #[test]
+    fn test_invalid_field_defaults_only_that_field() {
+        let settings = Settings::from_toml(
+            "effects_intensity = \"Loud\"\nnumber_format = \"Full\"\nhp_display = \"Percent\"\n",
+        )
+        .unwrap();
+        assert_eq!(
+            settings,
+            Settings {
+                number_format: NumberFormat::Full,
+                hp_display: HpDisplay::Percent,
+                ..Settings::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_intensity_cycle_returns_to_start() {
+        let start = EffectsIntensity::Full;
+        assert_eq!(start.next(), EffectsIntensity::Reduced);
+        assert_eq!(start.next().next(), EffectsIntensity::Off);
+        assert_eq!(start.next().next().next(), start);
+    }
+
+    #[test]
+    fn test_intensity_levels() {
+        assert!(EffectsIntensity::Full.shows_damage_numbers());
+        assert!(EffectsIntensity::Full.shows_hit_flourishes());
+        assert!(EffectsIntensity::Reduced.shows_damage_numbers());
+        assert!(!EffectsIntensity::Reduced.shows_hit_flourishes());
+        assert!(!EffectsIntensity::Off.shows_damage_numbers());
+        assert!(!EffectsIntensity::Off.shows_hit_flourishes());
+    }
+}
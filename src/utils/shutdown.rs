@@ -0,0 +1,90 @@
+//! Graceful shutdown on termination signals.
+//!
+//! `ShutdownSignal::install()` turns SIGINT and SIGTERM (plus SIGHUP on Unix,
+//! sent when an SSH session closes) into a flag instead of killing the
+//! process. main.rs checks it every loop iteration; when set, it calls
+//! `save_session()` (unless saves are disabled), restores the terminal and
+//! exits, so nothing since the last autosave is lost.
+
+use crate::achievements::persistence::{write_achievements_file, ACHIEVEMENTS_FILE};
+use crate::achievements::Achievements;
+use crate::character::manager::CharacterManager;
+use crate::core::game_state::GameState;
+use crate::haven::{write_haven_file, Haven, HAVEN_FILE};
+use std::io;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+pub struct ShutdownSignal {
+    requested: Arc<AtomicBool>,
+}
+
+impl ShutdownSignal {
+    /// Registers the signal handlers. From here on the signals no longer
+    /// terminate the process; the caller must poll `requested()`.
+    pub fn install() -> io::Result<Self> {
+        let requested = Arc::new(AtomicBool::new(false));
+        signal_hook::flag::register(signal_hook::consts::SIGINT, Arc::clone(&requested))?;
+        signal_hook::flag::register(signal_hook::consts::SIGTERM, Arc::clone(&requested))?;
+        #[cfg(unix)]
+        signal_hook::flag::register(signal_hook::consts::SIGHUP, Arc::clone(&requested))?;
+        Ok(Self { requested })
+    }
+
+    pub fn requested(&self) -> bool {
+        self.requested.load(Ordering::Relaxed)
+    }
+}
+
+/// Saves the character being played (if any), Haven (once discovered) and
+/// achievements into `manager`'s directory.
+pub fn save_session(
+    manager: &CharacterManager,
+    state: Option<&GameState>,
+    haven: &Haven,
+    achievements: &Achievements,
+) -> io::Result<()> {
+    if let Some(state) = state {
+        manager.save_character(state)?;
+    }
+    if haven.discovered {
+        write_haven_file(haven, &manager.quest_dir().join(HAVEN_FILE))?;
+    }
+    write_achievements_file(achievements, &manager.quest_dir().join(ACHIEVEMENTS_FILE))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_save_session_writes_current_state() {
+        let dir = std::env::temp_dir().join(format!("quest-shutdown-{}", std::process::id()));
+        std::fs::remove_dir_all(&dir).ok();
+        let manager = CharacterManager::in_dir(dir.clone()).unwrap();
+
+        let mut state = GameState::new("Insomniac".to_string(), 0);
+        state.character_level = 37;
+        state.character_xp = 1234;
+        let haven = Haven {
+            discovered: true,
+            ..Default::default()
+        };
+        let achievements = Achievements {
+            total_kills: 99,
+            ..Default::default()
+        };
+
+        save_session(&manager, Some(&state), &haven, &achievements).unwrap();
+
+        let loaded = manager.load_character("insomniac.json").unwrap();
+        assert_eq!(loaded.character_level, 37);
+        assert_eq!(loaded.character_xp, 1234);
+        assert!(dir.join(HAVEN_FILE).exists());
+        let saved =
+            crate::achievements::persistence::read_achievements_file(&dir.join(ACHIEVEMENTS_FILE))
+                .unwrap();
+        assert_eq!(saved.total_kills, 99);
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -7,6 +7,7 @@ use std::error::Error;
 use std::fs::{self, File};
 use std::io::{self, Read, Write};
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 /// Repository owner and name for GitHub API
 const GITHUB_OWNER: &str = "stphung";
@@ -23,6 +24,7 @@ pub struct ReleaseInfo {
 /// A commit in the changelog
 #[derive(Debug, Clone)]
 pub struct ChangelogEntry {
+    pub commit: String,
     pub message: String,
 }
 
@@ -38,7 +40,9 @@ pub enum UpdateCheck {
         latest: ReleaseInfo,
         changelog: Vec<ChangelogEntry>,
     },
-    /// Failed to check (network error, etc.)
+    /// GitHub couldn't be reached (no connection, DNS failure, timeout)
+    Offline,
+    /// Failed to check (bad response, rate limit, etc.)
     CheckFailed(String),
 }
 
@@ -62,6 +66,7 @@ struct GitHubCompare {
 
 #[derive(Deserialize)]
 struct GitHubCommit {
+    sha: String,
     commit: GitHubCommitDetail,
 }
 
@@ -120,6 +125,20 @@ fn get_platform_asset_name() -> &'static str {
     }
 }
 
+/// Whether a request error means the network is unreachable rather than
+/// GitHub answering with something unexpected.
+fn is_offline_error(error: &(dyn Error + 'static)) -> bool {
+    matches!(
+        error.downcast_ref::<ureq::Error>(),
+        Some(
+            ureq::Error::HostNotFound
+                | ureq::Error::ConnectionFailed
+                | ureq::Error::Timeout(_)
+                | ureq::Error::Io(_)
+        )
+    )
+}
+
 /// Fetch the latest release from GitHub.
 fn fetch_latest_release() -> Result<GitHubRelease, Box<dyn Error>> {
     let url = format!(
@@ -156,7 +175,10 @@ fn fetch_changelog(from: &str, to: &str) -> Result<Vec<ChangelogEntry>, Box<dyn
         .map(|c| {
             // Take first line of commit message
             let message = c.commit.message.lines().next().unwrap_or("").to_string();
-            ChangelogEntry { message }
+            ChangelogEntry {
+                commit: c.sha,
+                message,
+            }
         })
         .collect();
 
@@ -168,6 +190,7 @@ pub fn check_for_updates(current_commit: &str, current_date: &str) -> UpdateChec
     // Fetch latest release
     let release = match fetch_latest_release() {
         Ok(r) => r,
+        Err(e) if is_offline_error(e.as_ref()) => return UpdateCheck::Offline,
         Err(e) => return UpdateCheck::CheckFailed(e.to_string()),
     };
 
@@ -219,9 +242,45 @@ pub fn check_for_updates(current_commit: &str, current_date: &str) -> UpdateChec
     }
 }
 
-/// Get the Quest data directory (~/.quest)
+/// Get the Quest data directory (~/.quest, or `QUEST_SAVE_DIR`)
 fn get_quest_dir() -> Option<PathBuf> {
-    dirs::home_dir().map(|h| h.join(".quest"))
+    crate::core::paths::quest_dir().ok()
+}
+
+/// File in the Quest data directory holding the release commit whose
+/// changelog the player last saw.
+const CHANGELOG_SEEN_FILE: &str = "last_seen_changelog";
+
+/// The release commit whose changelog was last shown, if any.
+pub fn load_last_seen_commit() -> Option<String> {
+    let path = get_quest_dir()?.join(CHANGELOG_SEEN_FILE);
+    let commit = fs::read_to_string(path).ok()?.trim().to_string();
+    (!commit.is_empty()).then_some(commit)
+}
+
+/// Remember that the changelog up to `commit` has been shown, so the next
+/// notification only lists what came after it.
+pub fn mark_changelog_seen(commit: &str) -> io::Result<()> {
+    let quest_dir = get_quest_dir()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no home directory"))?;
+    fs::create_dir_all(&quest_dir)?;
+    fs::write(quest_dir.join(CHANGELOG_SEEN_FILE), short_commit(commit))
+}
+
+/// Drop entries the player has already seen. The changelog is newest first,
+/// so everything from the last-seen commit onward is old news. If that
+/// commit isn't in the list, every entry is new.
+fn filter_unseen_changelog(
+    changelog: Vec<ChangelogEntry>,
+    last_seen: Option<&str>,
+) -> Vec<ChangelogEntry> {
+    let Some(last_seen) = last_seen.map(short_commit) else {
+        return changelog;
+    };
+    changelog
+        .into_iter()
+        .take_while(|entry| short_commit(&entry.commit) != last_seen)
+        .collect()
 }
 
 /// Backup all character saves to a timestamped directory.
@@ -374,17 +433,82 @@ pub struct UpdateInfo {
     pub changelog_total: usize,
 }
 
+/// Outcome of a background update check for in-game display
+#[derive(Debug, Clone)]
+pub enum UpdateCheckResult {
+    Available(UpdateInfo),
+    NoUpdate,
+    Offline,
+    /// Check failed; the message is shown in the footer
+    Error(String),
+}
+
+impl UpdateCheckResult {
+    /// Offline and errors count as failures for backoff.
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self,
+            UpdateCheckResult::Offline | UpdateCheckResult::Error(_)
+        )
+    }
+}
+
+/// The last completed update check, shown in the footer
+#[derive(Debug, Clone)]
+pub struct CompletedUpdateCheck {
+    pub result: UpdateCheckResult,
+    pub checked_at: chrono::DateTime<chrono::Local>,
+}
+
+/// Exponential backoff for periodic update checks.
+///
+/// Each consecutive failed check doubles the wait before the next one, up to
+/// `UPDATE_CHECK_MAX_BACKOFF_SECONDS`, so a flaky network isn't hammered with
+/// requests. Any successful check resets it.
+#[derive(Debug, Clone, Default)]
+pub struct UpdateBackoff {
+    consecutive_failures: u32,
+}
+
+impl UpdateBackoff {
+    pub fn record(&mut self, result: &UpdateCheckResult) {
+        if result.is_failure() {
+            self.consecutive_failures = self.consecutive_failures.saturating_add(1);
+        } else {
+            self.consecutive_failures = 0;
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn consecutive_failures(&self) -> u32 {
+        self.consecutive_failures
+    }
+
+    /// Wait before the next check: `base` doubled once per consecutive failure.
+    pub fn next_interval(&self, base: Duration) -> Duration {
+        use crate::core::constants::UPDATE_CHECK_MAX_BACKOFF_SECONDS;
+
+        let max = Duration::from_secs(UPDATE_CHECK_MAX_BACKOFF_SECONDS);
+        if base >= max {
+            return base;
+        }
+        let factor = 2u32.saturating_pow(self.consecutive_failures);
+        base.saturating_mul(factor).min(max)
+    }
+}
+
 /// Check for updates and return full info including changelog.
-/// Returns Some(UpdateInfo) if update available, None otherwise.
-pub fn check_update_info() -> Option<UpdateInfo> {
+pub fn check_update_info() -> UpdateCheckResult {
     use crate::utils::build_info::{BUILD_COMMIT, BUILD_DATE};
 
     match check_for_updates(BUILD_COMMIT, BUILD_DATE) {
         UpdateCheck::UpdateAvailable {
             latest, changelog, ..
         } => {
+            let last_seen = load_last_seen_commit();
+            let changelog = filter_unseen_changelog(changelog, last_seen.as_deref());
             let total = changelog.len();
-            Some(UpdateInfo {
+            UpdateCheckResult::Available(UpdateInfo {
                 new_version: latest.date,
                 new_commit: short_commit(&latest.commit),
                 changelog: changelog
@@ -403,7 +527,9 @@ pub fn check_update_info() -> Option<UpdateInfo> {
                 changelog_total: total,
             })
         }
-        _ => None,
+        UpdateCheck::UpToDate => UpdateCheckResult::NoUpdate,
+        UpdateCheck::Offline => UpdateCheckResult::Offline,
+        UpdateCheck::CheckFailed(err) => UpdateCheckResult::Error(err),
     }
 }
 
@@ -423,6 +549,10 @@ pub fn run_update_command() -> Result<bool, Box<dyn Error>> {
             println!("\nYou're running the latest version!");
             Ok(false)
         }
+        UpdateCheck::Offline => {
+            eprintln!("Failed to check for updates: GitHub is unreachable (offline?)");
+            Err("offline".into())
+        }
         UpdateCheck::CheckFailed(err) => {
             eprintln!("Failed to check for updates: {}", err);
             Err(err.into())
@@ -583,6 +713,73 @@ mod tests {
         assert_eq!(parse_release_date("2025-12-31T00:00:00Z"), "2025-12-31");
     }
 
+    #[test]
+    fn test_filter_unseen_changelog_drops_seen_entries() {
+        let entry = |commit: &str| ChangelogEntry {
+            commit: commit.to_string(),
+            message: format!("commit {}", commit),
+        };
+        // Newest first, as returned by fetch_changelog
+        let changelog = vec![
+            entry("ccccccc1111"),
+            entry("bbbbbbb2222"),
+            entry("aaaaaaa3333"),
+        ];
+
+        let filtered = filter_unseen_changelog(changelog.clone(), Some("bbbbbbb"));
+        let commits: Vec<_> = filtered.iter().map(|e| e.commit.as_str()).collect();
+        assert_eq!(commits, vec!["ccccccc1111"]);
+
+        assert_eq!(filter_unseen_changelog(changelog.clone(), None).len(), 3);
+        assert_eq!(
+            filter_unseen_changelog(changelog, Some("ddddddd")).len(),
+            3,
+            "unknown last-seen commit keeps everything"
+        );
+    }
+
+    #[test]
+    fn test_backoff_grows_with_consecutive_failures() {
+        let base = Duration::from_secs(60);
+        let mut backoff = UpdateBackoff::default();
+        assert_eq!(backoff.next_interval(base), base);
+
+        backoff.record(&UpdateCheckResult::Offline);
+        assert_eq!(backoff.next_interval(base), base * 2);
+        backoff.record(&UpdateCheckResult::Error("rate limited".to_string()));
+        assert_eq!(backoff.next_interval(base), base * 4);
+
+        // Many failures stop at the cap
+        for _ in 0..40 {
+            backoff.record(&UpdateCheckResult::Offline);
+        }
+        assert_eq!(
+            backoff.next_interval(base),
+            Duration::from_secs(crate::core::constants::UPDATE_CHECK_MAX_BACKOFF_SECONDS)
+        );
+    }
+
+    #[test]
+    fn test_backoff_resets_on_success() {
+        let base = Duration::from_secs(60);
+        let mut backoff = UpdateBackoff::default();
+        backoff.record(&UpdateCheckResult::Offline);
+        backoff.record(&UpdateCheckResult::Offline);
+        assert_eq!(backoff.consecutive_failures(), 2);
+
+        backoff.record(&UpdateCheckResult::NoUpdate);
+        assert_eq!(backoff.consecutive_failures(), 0);
+        assert_eq!(backoff.next_interval(base), base);
+    }
+
+    #[test]
+    fn test_is_offline_error() {
+        assert!(is_offline_error(&ureq::Error::HostNotFound));
+        assert!(is_offline_error(&ureq::Error::ConnectionFailed));
+        assert!(!is_offline_error(&ureq::Error::StatusCode(403)));
+        assert!(!is_offline_error(&io::Error::other("not ureq")));
+    }
+
     #[test]
     fn test_get_platform_asset_name() {
         let name = get_platform_asset_name();
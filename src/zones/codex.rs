@@ -0,0 +1,147 @@
+//! Zone codex: a read-only view of the world for the codex overlay.
+//!
+//! Built from `ZoneProgression` and the static zone data. Zones up to the
+//! furthest one the player has reached (unlocked or currently in) are
+//! discovered and show their name, lore and bosses; later zones are listed
+//! as "???" with nothing else revealed. Opened in game with `[Z]` and drawn
+//! by `ui::zone_codex`.
+
+use super::data::{get_all_zones, Zone};
+use super::progression::ZoneProgression;
+
+/// Name shown for a zone the player hasn't reached yet.
+pub const UNDISCOVERED_NAME: &str = "???";
+
+/// A subzone boss as shown in the codex.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodexBoss {
+    pub subzone_id: u32,
+    pub subzone_name: &'static str,
+    pub boss_name: &'static str,
+    pub is_zone_boss: bool,
+    pub defeated: bool,
+}
+
+/// One zone in the codex. Undiscovered zones carry only their id.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodexZone {
+    pub zone_id: u32,
+    pub discovered: bool,
+    /// Zone name, or `UNDISCOVERED_NAME`
+    pub name: &'static str,
+    /// Zone flavor text, empty when undiscovered
+    pub lore: &'static str,
+    /// Bosses in subzone order, empty when undiscovered
+    pub bosses: Vec<CodexBoss>,
+}
+
+impl CodexZone {
+    fn discovered(zone: &Zone, progression: &ZoneProgression) -> Self {
+        let bosses = zone
+            .subzones
+            .iter()
+            .map(|subzone| CodexBoss {
+                subzone_id: subzone.id,
+                subzone_name: subzone.name,
+                boss_name: subzone.boss.name,
+                is_zone_boss: subzone.boss.is_zone_boss,
+                defeated: progression.is_boss_defeated(zone.id, subzone.id),
+            })
+            .collect();
+        Self {
+            zone_id: zone.id,
+            discovered: true,
+            name: zone.name,
+            lore: zone.description,
+            bosses,
+        }
+    }
+
+    fn hidden(zone: &Zone) -> Self {
+        Self {
+            zone_id: zone.id,
+            discovered: false,
+            name: UNDISCOVERED_NAME,
+            lore: "",
+            bosses: Vec::new(),
+        }
+    }
+
+    pub fn bosses_defeated(&self) -> usize {
+        self.bosses.iter().filter(|b| b.defeated).count()
+    }
+}
+
+/// Every zone in world order, discovered or not.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZoneCodex {
+    pub zones: Vec<CodexZone>,
+}
+
+impl ZoneCodex {
+    pub fn from_progression(progression: &ZoneProgression) -> Self {
+        let furthest = progression
+            .unlocked_zones
+            .iter()
+            .copied()
+            .chain(std::iter::once(progression.current_zone_id))
+            .max()
+            .unwrap_or(1);
+
+        let zones = get_all_zones()
+            .iter()
+            .map(|zone| {
+                if zone.id <= furthest {
+                    CodexZone::discovered(zone, progression)
+                } else {
+                    CodexZone::hidden(zone)
+                }
+            })
+            .collect();
+        Self { zones }
+    }
+
+    pub fn discovered_count(&self) -> usize {
+        self.zones.iter().filter(|z| z.discovered).count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codex_marks_defeated_bosses() {
+        let mut prog = ZoneProgression::new();
+        prog.defeat_boss(1, 1);
+        prog.defeat_boss(1, 2);
+
+        let codex = ZoneCodex::from_progression(&prog);
+        let meadow = &codex.zones[0];
+        assert_eq!(meadow.name, "Meadow");
+        assert_eq!(meadow.bosses_defeated(), 2);
+        assert!(meadow.bosses[0].defeated);
+        assert!(meadow.bosses[1].defeated);
+        assert!(!meadow.bosses[2].defeated);
+        assert!(meadow.bosses[2].is_zone_boss);
+        assert_eq!(codex.zones[1].bosses_defeated(), 0);
+    }
+
+    #[test]
+    fn test_codex_hides_zones_beyond_furthest_reached() {
+        let mut prog = ZoneProgression::new();
+        prog.unlock_zone(4);
+
+        let codex = ZoneCodex::from_progression(&prog);
+        assert_eq!(codex.zones.len(), get_all_zones().len());
+        assert_eq!(codex.discovered_count(), 4);
+        assert_eq!(codex.zones[3].name, "Ancient Ruins");
+
+        for zone in &codex.zones[4..] {
+            assert!(!zone.discovered);
+            assert_eq!(zone.name, UNDISCOVERED_NAME);
+            assert!(zone.lore.is_empty());
+            assert!(zone.bosses.is_empty());
+        }
+    }
+}
@@ -2,6 +2,8 @@
 
 #![allow(dead_code)]
 
+use crate::achievements::{AchievementId, Achievements};
+use crate::core::constants::{ENEMY_STAT_VARIANCE_MAX, ENEMY_STAT_VARIANCE_MIN, ZONE_ENEMY_STATS};
 use std::sync::LazyLock;
 
 /// Represents a zone in the game world.
@@ -13,12 +15,60 @@ pub struct Zone {
     pub description: &'static str,
     pub subzones: Vec<Subzone>,
     pub prestige_requirement: u32,
+    /// Minimum `gear_score` (summed `item_power` of equipped items) needed to
+    /// enter the zone; 0 for no gear gate
+    pub min_gear_score: u64,
     pub min_level: u32,
     pub max_level: u32,
-    /// If true, completing this zone requires forging a legendary weapon (see issue #20)
-    pub requires_weapon: bool,
-    /// Name of the legendary weapon for this zone (if requires_weapon is true)
-    pub weapon_name: Option<&'static str>,
+    /// What the player must hold before the zone's final boss can be damaged
+    /// (None for an unrestricted zone; see issue #20)
+    pub boss_requirement: Option<BossRequirement>,
+    /// Visual theme for the combat backdrop
+    pub theme: ZoneTheme,
+}
+
+/// An item a zone's final boss demands, unlocked by an achievement.
+///
+/// Until the achievement is unlocked the boss takes no damage and the player
+/// is told which item they are missing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BossRequirement {
+    /// Item named in the "required to damage this foe" message
+    pub item_name: &'static str,
+    /// Achievement that marks the item as obtained
+    pub achievement: AchievementId,
+}
+
+impl Zone {
+    /// Returns the item the player still lacks to damage the boss of
+    /// `subzone_id`, or None if the boss is unrestricted. Only the zone's
+    /// final boss is ever gated.
+    pub fn boss_blocked_by(
+        &self,
+        subzone_id: u32,
+        achievements: &Achievements,
+    ) -> Option<&'static str> {
+        let requirement = self.boss_requirement?;
+        let is_zone_boss = subzone_id == self.subzones.len() as u32;
+        (is_zone_boss && !achievements.is_unlocked(requirement.achievement))
+            .then_some(requirement.item_name)
+    }
+}
+
+/// Visual theme of a zone, used to draw its combat backdrop. One per zone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ZoneTheme {
+    Grassland,
+    Forest,
+    Mountain,
+    Ruins,
+    Volcanic,
+    Tundra,
+    Crystal,
+    Sunken,
+    Sky,
+    Storm,
+    Void,
 }
 
 /// Represents a subzone within a zone.
@@ -48,10 +98,11 @@ static ALL_ZONES: LazyLock<Vec<Zone>> = LazyLock::new(|| {
             name: "Meadow",
             description: "Rolling grasslands where wildflowers hide teeth. Many adventurers begin here. Fewer leave than you'd think.",
             prestige_requirement: 0,
+            min_gear_score: 0,
             min_level: 1,
             max_level: 10,
-            requires_weapon: false,
-            weapon_name: None,
+            boss_requirement: None,
+            theme: ZoneTheme::Grassland,
             subzones: vec![
                 Subzone {
                     id: 1,
@@ -90,10 +141,11 @@ static ALL_ZONES: LazyLock<Vec<Zone>> = LazyLock::new(|| {
             name: "Dark Forest",
             description: "Ancient trees twist toward a sky they'll never reach. The canopy hasn't let sunlight through in centuries.",
             prestige_requirement: 0,
+            min_gear_score: 0,
             min_level: 10,
             max_level: 25,
-            requires_weapon: false,
-            weapon_name: None,
+            boss_requirement: None,
+            theme: ZoneTheme::Forest,
             subzones: vec![
                 Subzone {
                     id: 1,
@@ -133,10 +185,11 @@ static ALL_ZONES: LazyLock<Vec<Zone>> = LazyLock::new(|| {
             name: "Mountain Pass",
             description: "A trade route abandoned after the last war. Bandits and worse things have claimed the heights.",
             prestige_requirement: 5,
+            min_gear_score: 0,
             min_level: 25,
             max_level: 40,
-            requires_weapon: false,
-            weapon_name: None,
+            boss_requirement: None,
+            theme: ZoneTheme::Mountain,
             subzones: vec![
                 Subzone {
                     id: 1,
@@ -175,10 +228,11 @@ static ALL_ZONES: LazyLock<Vec<Zone>> = LazyLock::new(|| {
             name: "Ancient Ruins",
             description: "A civilization that mastered death and was consumed by it. Their wards still flicker in the dark.",
             prestige_requirement: 5,
+            min_gear_score: 0,
             min_level: 40,
             max_level: 55,
-            requires_weapon: false,
-            weapon_name: None,
+            boss_requirement: None,
+            theme: ZoneTheme::Ruins,
             subzones: vec![
                 Subzone {
                     id: 1,
@@ -218,10 +272,11 @@ static ALL_ZONES: LazyLock<Vec<Zone>> = LazyLock::new(|| {
             name: "Volcanic Wastes",
             description: "The earth split open here long ago and never healed. Ash falls like grey snow on a land that remembers fire.",
             prestige_requirement: 10,
+            min_gear_score: 0,
             min_level: 55,
             max_level: 70,
-            requires_weapon: false,
-            weapon_name: None,
+            boss_requirement: None,
+            theme: ZoneTheme::Volcanic,
             subzones: vec![
                 Subzone {
                     id: 1,
@@ -270,10 +325,11 @@ static ALL_ZONES: LazyLock<Vec<Zone>> = LazyLock::new(|| {
             name: "Frozen Tundra",
             description: "An endless white silence where the cold has a patience that outlasts everything.",
             prestige_requirement: 10,
+            min_gear_score: 0,
             min_level: 70,
             max_level: 85,
-            requires_weapon: false,
-            weapon_name: None,
+            boss_requirement: None,
+            theme: ZoneTheme::Tundra,
             subzones: vec![
                 Subzone {
                     id: 1,
@@ -323,10 +379,11 @@ static ALL_ZONES: LazyLock<Vec<Zone>> = LazyLock::new(|| {
             name: "Crystal Caverns",
             description: "The crystals sing at frequencies that rearrange thought. Miners went in for gems and came out as prophets.",
             prestige_requirement: 15,
+            min_gear_score: 0,
             min_level: 85,
             max_level: 100,
-            requires_weapon: false,
-            weapon_name: None,
+            boss_requirement: None,
+            theme: ZoneTheme::Crystal,
             subzones: vec![
                 Subzone {
                     id: 1,
@@ -375,10 +432,11 @@ static ALL_ZONES: LazyLock<Vec<Zone>> = LazyLock::new(|| {
             name: "Sunken Kingdom",
             description: "A drowned empire that refused to die. The sea took their land but not their pride, or their army.",
             prestige_requirement: 15,
+            min_gear_score: 0,
             min_level: 100,
             max_level: 115,
-            requires_weapon: false,
-            weapon_name: None,
+            boss_requirement: None,
+            theme: ZoneTheme::Sunken,
             subzones: vec![
                 Subzone {
                     id: 1,
@@ -428,10 +486,11 @@ static ALL_ZONES: LazyLock<Vec<Zone>> = LazyLock::new(|| {
             name: "Floating Isles",
             description: "Shattered fragments of earth hang in an open sky. The ground fell away long ago. Only the stubborn parts remain.",
             prestige_requirement: 20,
+            min_gear_score: 600,
             min_level: 115,
             max_level: 130,
-            requires_weapon: false,
-            weapon_name: None,
+            boss_requirement: None,
+            theme: ZoneTheme::Sky,
             subzones: vec![
                 Subzone {
                     id: 1,
@@ -480,10 +539,14 @@ static ALL_ZONES: LazyLock<Vec<Zone>> = LazyLock::new(|| {
             name: "Storm Citadel",
             description: "A fortress built from living lightning, older than the sky itself. It was not made to be entered.",
             prestige_requirement: 20,
+            min_gear_score: 800,
             min_level: 130,
             max_level: 150,
-            requires_weapon: true,
-            weapon_name: Some("Stormbreaker"),
+            boss_requirement: Some(BossRequirement {
+                item_name: "Stormbreaker",
+                achievement: AchievementId::TheStormbreaker,
+            }),
+            theme: ZoneTheme::Storm,
             subzones: vec![
                 Subzone {
                     id: 1,
@@ -533,10 +596,11 @@ static ALL_ZONES: LazyLock<Vec<Zone>> = LazyLock::new(|| {
             name: "The Expanse",
             description: "Beyond the storm lies what was always there. Raw, unformed reality stretching past the edges of the world.",
             prestige_requirement: 0, // Unlocked by achievement, not prestige
+            min_gear_score: 0,
             min_level: 150,
             max_level: u32::MAX,
-            requires_weapon: false,
-            weapon_name: None,
+            boss_requirement: None,
+            theme: ZoneTheme::Void,
             subzones: vec![
                 Subzone {
                     id: 1,
@@ -601,10 +665,81 @@ pub fn get_subzone(zone_id: u32, subzone_id: u32) -> Option<(&'static Zone, &'st
     Some((zone, subzone))
 }
 
+/// Inclusive min/max stats a regular enemy can roll in a zone/subzone.
+/// Bosses, elites, and dungeon variants apply multipliers on top of these.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EnemyStatRange {
+    pub hp_min: u32,
+    pub hp_max: u32,
+    pub damage_min: u32,
+    pub damage_max: u32,
+    pub defense_min: u32,
+    pub defense_max: u32,
+}
+
+/// Returns the stat ranges used by enemy generation for a zone and subzone depth (1-based).
+///
+/// Stats come from `ZONE_ENEMY_STATS`: `base + (depth - 1) * step`, then HP and damage
+/// are rolled within `ENEMY_STAT_VARIANCE_MIN..ENEMY_STAT_VARIANCE_MAX`. Defense has no variance.
+/// Invalid zone IDs fall back to the nearest table entry (Zone 1 for 0, The Expanse for >11).
+pub fn enemy_stats_for(zone_id: u32, subzone_depth: u32) -> EnemyStatRange {
+    let index = (zone_id.saturating_sub(1) as usize).min(ZONE_ENEMY_STATS.len() - 1);
+    let (base_hp, hp_step, base_dmg, dmg_step, base_def, def_step) = ZONE_ENEMY_STATS[index];
+
+    let depth_offset = subzone_depth.saturating_sub(1);
+    let raw_hp = (base_hp + depth_offset * hp_step) as f64;
+    let raw_dmg = (base_dmg + depth_offset * dmg_step) as f64;
+    let raw_def = base_def + depth_offset * def_step;
+
+    EnemyStatRange {
+        hp_min: (raw_hp * ENEMY_STAT_VARIANCE_MIN).max(1.0) as u32,
+        hp_max: (raw_hp * ENEMY_STAT_VARIANCE_MAX).max(1.0) as u32,
+        damage_min: (raw_dmg * ENEMY_STAT_VARIANCE_MIN).max(1.0) as u32,
+        damage_max: (raw_dmg * ENEMY_STAT_VARIANCE_MAX).max(1.0) as u32,
+        defense_min: raw_def,
+        defense_max: raw_def,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_enemy_stats_for_later_zone_dominates_earlier() {
+        for zone_id in 1..ZONE_ENEMY_STATS.len() as u32 {
+            let earlier = enemy_stats_for(zone_id, 1);
+            let later = enemy_stats_for(zone_id + 1, 1);
+            assert!(later.hp_min > earlier.hp_min && later.hp_max > earlier.hp_max);
+            assert!(later.damage_min > earlier.damage_min && later.damage_max > earlier.damage_max);
+            assert!(
+                later.defense_min > earlier.defense_min && later.defense_max > earlier.defense_max,
+                "Zone {} defense should exceed zone {}",
+                zone_id + 1,
+                zone_id
+            );
+        }
+    }
+
+    #[test]
+    fn test_enemy_stats_for_min_not_above_max() {
+        for zone in get_all_zones() {
+            for subzone in &zone.subzones {
+                let range = enemy_stats_for(zone.id, subzone.depth);
+                assert!(range.hp_min <= range.hp_max);
+                assert!(range.damage_min <= range.damage_max);
+                assert!(range.defense_min <= range.defense_max);
+                assert!(range.hp_min >= 1 && range.damage_min >= 1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_enemy_stats_for_invalid_zone_clamps() {
+        assert_eq!(enemy_stats_for(0, 1), enemy_stats_for(1, 1));
+        assert_eq!(enemy_stats_for(99, 1), enemy_stats_for(11, 1));
+    }
+
     #[test]
     fn test_zone_count() {
         let zones = get_all_zones();
@@ -726,6 +861,16 @@ mod tests {
         assert!(get_subzone(1, 5).is_none());
     }
 
+    #[test]
+    fn test_every_zone_has_distinct_theme() {
+        let themes: std::collections::HashSet<ZoneTheme> = (1..=11)
+            .map(|id| get_zone(id).expect("zones 1-11 exist").theme)
+            .collect();
+        assert_eq!(themes.len(), 11, "each zone should have its own theme");
+        assert_eq!(get_zone(1).unwrap().theme, ZoneTheme::Grassland);
+        assert_eq!(get_zone(11).unwrap().theme, ZoneTheme::Void);
+    }
+
     #[test]
     fn test_weapon_zones() {
         let zones = get_all_zones();
@@ -733,16 +878,59 @@ mod tests {
         // Zones 1-9 don't require weapons
         for zone in &zones[0..9] {
             assert!(
-                !zone.requires_weapon,
+                zone.boss_requirement.is_none(),
                 "Zone {} should not require weapon",
                 zone.name
             );
-            assert!(zone.weapon_name.is_none());
         }
 
         // Zone 10 requires the Stormbreaker weapon
         let zone10 = &zones[9];
-        assert!(zone10.requires_weapon, "Zone 10 should require weapon");
-        assert_eq!(zone10.weapon_name, Some("Stormbreaker"));
+        let requirement = zone10
+            .boss_requirement
+            .expect("Zone 10 should require weapon");
+        assert_eq!(requirement.item_name, "Stormbreaker");
+        assert_eq!(requirement.achievement, AchievementId::TheStormbreaker);
+    }
+
+    #[test]
+    fn test_declared_boss_requirement_blocks_until_met() {
+        // Any zone can gate its final boss by declaring a requirement
+        let mut zone = get_zone(5).unwrap().clone();
+        zone.boss_requirement = Some(BossRequirement {
+            item_name: "Ember Key",
+            achievement: AchievementId::Zone4Complete,
+        });
+        let final_subzone = zone.subzones.len() as u32;
+        let mut achievements = Achievements::default();
+
+        assert_eq!(
+            zone.boss_blocked_by(final_subzone, &achievements),
+            Some("Ember Key")
+        );
+        // Earlier subzone bosses stay unrestricted
+        assert_eq!(zone.boss_blocked_by(1, &achievements), None);
+
+        achievements.unlock(AchievementId::Zone4Complete, None);
+        assert_eq!(zone.boss_blocked_by(final_subzone, &achievements), None);
+    }
+
+    #[test]
+    fn test_unrestricted_zone_boss_never_blocked() {
+        let achievements = Achievements::default();
+        for zone in get_all_zones()
+            .iter()
+            .filter(|z| z.boss_requirement.is_none())
+        {
+            for subzone in &zone.subzones {
+                assert_eq!(
+                    zone.boss_blocked_by(subzone.id, &achievements),
+                    None,
+                    "{} subzone {} should not be gated",
+                    zone.name,
+                    subzone.id
+                );
+            }
+        }
     }
 }
@@ -0,0 +1,119 @@
+//! Boss ledger: per-boss attempts, deaths and fastest kill for players
+//! chasing personal bests.
+//!
+//! Covers overworld subzone bosses only, keyed by (zone, subzone). Kill time
+//! is `CombatState::boss_fight_ticks` at the killing blow. The ledger is
+//! saved and survives prestige.
+
+use serde::{Deserialize, Serialize};
+
+/// Stats for one subzone boss.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BossRecord {
+    pub zone_id: u32,
+    pub subzone_id: u32,
+    /// Finished fights, won or lost
+    pub attempts: u32,
+    pub deaths: u32,
+    pub kills: u32,
+    /// Fewest ticks from spawn to kill, None until the boss has been killed
+    pub fastest_kill_ticks: Option<u32>,
+}
+
+impl BossRecord {
+    fn new(zone_id: u32, subzone_id: u32) -> Self {
+        Self {
+            zone_id,
+            subzone_id,
+            attempts: 0,
+            deaths: 0,
+            kills: 0,
+            fastest_kill_ticks: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BossLedger {
+    pub records: Vec<BossRecord>,
+}
+
+impl BossLedger {
+    fn record_mut(&mut self, zone_id: u32, subzone_id: u32) -> &mut BossRecord {
+        let index = match self
+            .records
+            .iter()
+            .position(|r| r.zone_id == zone_id && r.subzone_id == subzone_id)
+        {
+            Some(index) => index,
+            None => {
+                self.records.push(BossRecord::new(zone_id, subzone_id));
+                self.records.len() - 1
+            }
+        };
+        &mut self.records[index]
+    }
+
+    /// Records a kill taking `ticks`. Returns true if it is a new fastest kill.
+    pub fn record_kill(&mut self, zone_id: u32, subzone_id: u32, ticks: u32) -> bool {
+        let record = self.record_mut(zone_id, subzone_id);
+        record.attempts += 1;
+        record.kills += 1;
+        let is_best = record.fastest_kill_ticks.is_none_or(|best| ticks < best);
+        if is_best {
+            record.fastest_kill_ticks = Some(ticks);
+        }
+        is_best
+    }
+
+    /// Records a fight the player died in.
+    pub fn record_death(&mut self, zone_id: u32, subzone_id: u32) {
+        let record = self.record_mut(zone_id, subzone_id);
+        record.attempts += 1;
+        record.deaths += 1;
+    }
+
+    pub fn get(&self, zone_id: u32, subzone_id: u32) -> Option<&BossRecord> {
+        self.records
+            .iter()
+            .find(|r| r.zone_id == zone_id && r.subzone_id == subzone_id)
+    }
+
+    /// Fastest kill of the boss guarding `subzone_id` in `zone_id`, in ticks.
+    #[allow(dead_code)]
+    pub fn fastest_kill(&self, zone_id: u32, subzone_id: u32) -> Option<u32> {
+        self.get(zone_id, subzone_id)
+            .and_then(|r| r.fastest_kill_ticks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kill_keeps_fastest_time() {
+        let mut ledger = BossLedger::default();
+        assert!(ledger.record_kill(1, 3, 120));
+        assert!(!ledger.record_kill(1, 3, 150));
+        assert!(ledger.record_kill(1, 3, 90));
+
+        let record = ledger.get(1, 3).unwrap();
+        assert_eq!(record.attempts, 3);
+        assert_eq!(record.kills, 3);
+        assert_eq!(ledger.fastest_kill(1, 3), Some(90));
+        assert_eq!(ledger.fastest_kill(1, 2), None);
+    }
+
+    #[test]
+    fn test_death_counts_attempt_without_kill_time() {
+        let mut ledger = BossLedger::default();
+        ledger.record_death(2, 1);
+
+        let record = ledger.get(2, 1).unwrap();
+        assert_eq!(record.attempts, 1);
+        assert_eq!(record.deaths, 1);
+        assert_eq!(record.kills, 0);
+        assert_eq!(ledger.fastest_kill(2, 1), None);
+    }
+}
@@ -3,8 +3,11 @@
 //! The zone system provides a sense of traveling through themed areas,
 //! with boss gates between subzones and prestige gates between zone tiers.
 
-mod data;
+pub mod codex;
+pub mod data;
+mod ledger;
 mod progression;
 
 pub use data::{get_all_zones, get_zone, Subzone, Zone};
+pub use ledger::BossLedger;
 pub use progression::{BossDefeatResult, ZoneProgression};
@@ -84,7 +84,8 @@ impl ZoneProgression {
     /// Checks if the current boss requires a weapon the player doesn't have.
     /// Returns Some(weapon_name) if blocked, None if can proceed.
     ///
-    /// Uses the TheStormbreaker achievement to check if the player has forged Stormbreaker.
+    /// The zone's `boss_requirement` decides what is needed; the achievement it
+    /// names marks the item as obtained.
     pub fn boss_weapon_blocked(&self, achievements: &Achievements) -> Option<&'static str> {
         if !self.fighting_boss {
             return None;
@@ -92,18 +93,7 @@ impl ZoneProgression {
 
         let zones = get_all_zones();
         let zone = zones.iter().find(|z| z.id == self.current_zone_id)?;
-
-        // Only the zone's final boss requires the weapon
-        let is_zone_boss = self.current_subzone_id == zone.subzones.len() as u32;
-        // Check achievement instead of has_stormbreaker flag
-        let has_stormbreaker = achievements.is_unlocked(AchievementId::TheStormbreaker);
-        let needs_weapon = zone.requires_weapon && is_zone_boss && !has_stormbreaker;
-
-        if needs_weapon {
-            zone.weapon_name
-        } else {
-            None
-        }
+        zone.boss_blocked_by(self.current_subzone_id, achievements)
     }
 
     /// Checks if a boss has been defeated.
@@ -116,13 +106,18 @@ impl ZoneProgression {
         self.unlocked_zones.contains(&zone_id)
     }
 
-    /// Checks if a zone can be unlocked based on prestige rank.
-    pub fn can_unlock_zone(&self, zone: &Zone, prestige_rank: u32) -> bool {
+    /// Checks if a zone can be unlocked based on prestige rank and gear score.
+    pub fn can_unlock_zone(&self, zone: &Zone, prestige_rank: u32, gear_score: u64) -> bool {
         // Check prestige requirement
         if prestige_rank < zone.prestige_requirement {
             return false;
         }
 
+        // Check gear requirement (hard zones only)
+        if gear_score < zone.min_gear_score {
+            return false;
+        }
+
         // Check if previous zone's final boss is defeated (if not first zone)
         if zone.id > 1 {
             let prev_zone_id = zone.id - 1;
@@ -162,6 +157,7 @@ impl ZoneProgression {
     pub fn on_boss_defeated(
         &mut self,
         prestige_rank: u32,
+        gear_score: u64,
         achievements: &mut Achievements,
     ) -> BossDefeatResult {
         let zone_id = self.current_zone_id;
@@ -176,15 +172,14 @@ impl ZoneProgression {
 
         let is_zone_boss = subzone_id == zone.subzones.len() as u32;
 
-        // Check for Zone 10 final boss weapon requirement (use achievement)
-        let has_stormbreaker = achievements.is_unlocked(AchievementId::TheStormbreaker);
-        if zone.requires_weapon && is_zone_boss && !has_stormbreaker {
+        // Check the zone's final boss requirement (e.g. Zone 10 Stormbreaker)
+        if let Some(weapon_name) = zone.boss_blocked_by(subzone_id, achievements) {
             // Can't defeat this boss without the weapon - boss survives!
             // Reset fighting state so player can try again (after getting weapon)
             self.fighting_boss = false;
             self.kills_in_subzone = 0;
             return BossDefeatResult::WeaponRequired {
-                weapon_name: zone.weapon_name.unwrap_or("legendary weapon").to_string(),
+                weapon_name: weapon_name.to_string(),
             };
         }
 
@@ -211,16 +206,23 @@ impl ZoneProgression {
             }
 
             // Try to advance to next zone
-            if self.advance_to_next_zone(prestige_rank) {
+            if self.advance_to_next_zone(prestige_rank, gear_score) {
                 return BossDefeatResult::ZoneComplete {
                     old_zone: zone.name.to_string(),
                     new_zone_id: self.current_zone_id,
                 };
             }
 
-            // Can't advance - either no more zones or prestige-gated
+            // Can't advance - either no more zones, prestige-gated or under-geared
             let next_zone = zones.iter().find(|z| z.id == zone_id + 1);
             if let Some(next) = next_zone {
+                if prestige_rank >= next.prestige_requirement && gear_score < next.min_gear_score {
+                    return BossDefeatResult::ZoneCompleteButUndergeared {
+                        zone_name: zone.name.to_string(),
+                        required_gear_score: next.min_gear_score,
+                        gear_score,
+                    };
+                }
                 return BossDefeatResult::ZoneCompleteButGated {
                     zone_name: zone.name.to_string(),
                     required_prestige: next.prestige_requirement,
@@ -273,12 +275,12 @@ impl ZoneProgression {
 
     /// Advances to the next zone.
     /// Returns true if successful.
-    pub fn advance_to_next_zone(&mut self, prestige_rank: u32) -> bool {
+    pub fn advance_to_next_zone(&mut self, prestige_rank: u32, gear_score: u64) -> bool {
         let zones = get_all_zones();
         let next_zone_id = self.current_zone_id + 1;
 
         if let Some(next_zone) = zones.iter().find(|z| z.id == next_zone_id) {
-            if self.can_unlock_zone(next_zone, prestige_rank) {
+            if self.can_unlock_zone(next_zone, prestige_rank, gear_score) {
                 self.unlock_zone(next_zone_id);
                 self.current_zone_id = next_zone_id;
                 self.current_subzone_id = 1;
@@ -351,6 +353,12 @@ pub enum BossDefeatResult {
         zone_name: String,
         required_prestige: u32,
     },
+    /// Completed zone but the gear is too weak for the next one
+    ZoneCompleteButUndergeared {
+        zone_name: String,
+        required_gear_score: u64,
+        gear_score: u64,
+    },
     /// Completed the final zone (Zone 10)
     StormsEnd,
     /// Boss requires a legendary weapon to defeat (Zone 10)
@@ -416,8 +424,8 @@ mod tests {
         let zones = get_all_zones();
 
         // Zone 3 requires prestige 5
-        assert!(!prog.can_unlock_zone(&zones[2], 0));
-        assert!(!prog.can_unlock_zone(&zones[2], 4));
+        assert!(!prog.can_unlock_zone(&zones[2], 0, 0));
+        assert!(!prog.can_unlock_zone(&zones[2], 4, 0));
         // Note: Also needs zone 2's boss defeated
     }
 
@@ -428,7 +436,7 @@ mod tests {
 
         // Zone 3 requires P5 AND zone 2's final boss defeated
         // With P5 but no boss defeated
-        assert!(!prog.can_unlock_zone(&zones[2], 5));
+        assert!(!prog.can_unlock_zone(&zones[2], 5, 0));
 
         // Defeat zone 2's bosses
         prog.defeat_boss(2, 1);
@@ -436,7 +444,55 @@ mod tests {
         prog.defeat_boss(2, 3);
 
         // Now should be able to unlock
-        assert!(prog.can_unlock_zone(&zones[2], 5));
+        assert!(prog.can_unlock_zone(&zones[2], 5, 0));
+    }
+
+    #[test]
+    fn test_zone_unlock_gear_gate() {
+        let mut prog = ZoneProgression::new();
+        let zones = get_all_zones();
+        let zone9 = &zones[8];
+        assert!(zone9.min_gear_score > 0);
+
+        for subzone in 1..=zones[7].subzones.len() as u32 {
+            prog.defeat_boss(8, subzone);
+        }
+
+        // Prestige is enough, but the gear isn't
+        assert!(!prog.can_unlock_zone(zone9, 20, zone9.min_gear_score - 1));
+        assert!(prog.can_unlock_zone(zone9, 20, zone9.min_gear_score));
+    }
+
+    #[test]
+    fn test_undergeared_zone_boss_reports_gear_gate() {
+        let mut prog = ZoneProgression::new();
+        let mut achievements = Achievements::default();
+        let zones = get_all_zones();
+        let required = zones[8].min_gear_score;
+
+        prog.unlock_zone(8);
+        prog.current_zone_id = 8;
+        prog.current_subzone_id = zones[7].subzones.len() as u32;
+        prog.fighting_boss = true;
+
+        let result = prog.on_boss_defeated(20, 10, &mut achievements);
+        assert_eq!(
+            result,
+            BossDefeatResult::ZoneCompleteButUndergeared {
+                zone_name: zones[7].name.to_string(),
+                required_gear_score: required,
+                gear_score: 10,
+            }
+        );
+        assert_eq!(prog.current_zone_id, 8);
+
+        // Better gear gets through on the next kill of the zone boss
+        prog.fighting_boss = true;
+        let result = prog.on_boss_defeated(20, required, &mut achievements);
+        assert!(matches!(
+            result,
+            BossDefeatResult::ZoneComplete { new_zone_id: 9, .. }
+        ));
     }
 
     #[test]
@@ -463,7 +519,7 @@ mod tests {
         prog.defeat_boss(1, 3);
 
         // Should advance to zone 2 (P0 requirement met)
-        assert!(prog.advance_to_next_zone(0));
+        assert!(prog.advance_to_next_zone(0, 0));
         assert_eq!(prog.current_zone_id, 2);
         assert_eq!(prog.current_subzone_id, 1);
     }
@@ -476,18 +532,18 @@ mod tests {
         for subzone in 1..=3 {
             prog.defeat_boss(1, subzone);
         }
-        prog.advance_to_next_zone(0);
+        prog.advance_to_next_zone(0, 0);
 
         for subzone in 1..=3 {
             prog.defeat_boss(2, subzone);
         }
 
         // Try to advance to zone 3 with P0 (needs P5)
-        assert!(!prog.advance_to_next_zone(0));
+        assert!(!prog.advance_to_next_zone(0, 0));
         assert_eq!(prog.current_zone_id, 2);
 
         // With P5, should work
-        assert!(prog.advance_to_next_zone(5));
+        assert!(prog.advance_to_next_zone(5, 0));
         assert_eq!(prog.current_zone_id, 3);
     }
 
@@ -566,7 +622,7 @@ mod tests {
         assert_eq!(prog.current_subzone_id, 3);
 
         // Advance to Zone 2 (no prestige required)
-        assert!(prog.advance_to_next_zone(0));
+        assert!(prog.advance_to_next_zone(0, 0));
         assert_eq!(prog.current_zone_id, 2);
         assert_eq!(prog.current_subzone_id, 1);
 
@@ -579,12 +635,12 @@ mod tests {
         }
 
         // Try to advance to Zone 3 - BLOCKED by P5 requirement
-        assert!(!prog.advance_to_next_zone(0));
-        assert!(!prog.advance_to_next_zone(4));
+        assert!(!prog.advance_to_next_zone(0, 0));
+        assert!(!prog.advance_to_next_zone(4, 0));
         assert_eq!(prog.current_zone_id, 2);
 
         // With P5, can advance to Zone 3
-        assert!(prog.advance_to_next_zone(5));
+        assert!(prog.advance_to_next_zone(5, 0));
         assert_eq!(prog.current_zone_id, 3);
 
         // === Simulate Prestige ===
@@ -620,8 +676,10 @@ mod tests {
         // Zone 10 is the endgame zone requiring a weapon
         assert_eq!(zone10.id, 10);
         assert_eq!(zone10.name, "Storm Citadel");
-        assert!(zone10.requires_weapon);
-        assert_eq!(zone10.weapon_name, Some("Stormbreaker"));
+        assert_eq!(
+            zone10.boss_requirement.map(|r| r.item_name),
+            Some("Stormbreaker")
+        );
         assert_eq!(zone10.prestige_requirement, 20);
     }
 
@@ -649,6 +707,29 @@ mod tests {
         assert_eq!(prog.kills_until_boss(), 0);
     }
 
+    #[test]
+    fn test_kills_until_boss_after_boss_death_retry() {
+        use crate::core::constants::KILLS_FOR_BOSS_RETRY;
+
+        let mut prog = ZoneProgression::default();
+        for _ in 0..KILLS_FOR_BOSS {
+            prog.record_kill();
+        }
+        assert!(prog.fighting_boss);
+        assert_eq!(prog.kills_until_boss(), 0);
+
+        // What combat does when the player dies to the boss
+        prog.fighting_boss = false;
+        prog.kills_in_subzone = KILLS_FOR_BOSS.saturating_sub(KILLS_FOR_BOSS_RETRY);
+        assert_eq!(prog.kills_until_boss(), KILLS_FOR_BOSS_RETRY);
+
+        for i in 1..=KILLS_FOR_BOSS_RETRY {
+            prog.record_kill();
+            assert_eq!(prog.kills_until_boss(), KILLS_FOR_BOSS_RETRY - i);
+        }
+        assert!(prog.fighting_boss);
+    }
+
     #[test]
     fn test_record_kill_during_boss_fight() {
         let mut prog = ZoneProgression::new();
@@ -678,7 +759,7 @@ mod tests {
         assert_eq!(prog.current_subzone_id, 1);
 
         // Defeat boss
-        let result = prog.on_boss_defeated(0, &mut achievements);
+        let result = prog.on_boss_defeated(0, 0, &mut achievements);
         assert!(matches!(
             result,
             BossDefeatResult::SubzoneComplete { new_subzone_id: 2 }
@@ -698,7 +779,7 @@ mod tests {
             for _ in 0..KILLS_FOR_BOSS {
                 prog.record_kill();
             }
-            prog.on_boss_defeated(0, &mut achievements);
+            prog.on_boss_defeated(0, 0, &mut achievements);
         }
         assert_eq!(prog.current_subzone_id, 3);
 
@@ -707,7 +788,7 @@ mod tests {
             prog.record_kill();
         }
 
-        let result = prog.on_boss_defeated(0, &mut achievements);
+        let result = prog.on_boss_defeated(0, 0, &mut achievements);
         match result {
             BossDefeatResult::ZoneComplete {
                 old_zone,
@@ -732,7 +813,7 @@ mod tests {
             for _ in 0..KILLS_FOR_BOSS {
                 prog.record_kill();
             }
-            prog.on_boss_defeated(0, &mut achievements);
+            prog.on_boss_defeated(0, 0, &mut achievements);
         }
         assert_eq!(prog.current_zone_id, 2);
 
@@ -741,11 +822,11 @@ mod tests {
             for _ in 0..KILLS_FOR_BOSS {
                 prog.record_kill();
             }
-            prog.on_boss_defeated(0, &mut achievements);
+            prog.on_boss_defeated(0, 0, &mut achievements);
         }
 
         // Should be gated at zone 3 (needs P5)
-        match prog.on_boss_defeated(0, &mut achievements) {
+        match prog.on_boss_defeated(0, 0, &mut achievements) {
             BossDefeatResult::ZoneCompleteButGated {
                 zone_name,
                 required_prestige,
@@ -775,7 +856,7 @@ mod tests {
 
         // Try to defeat boss without Stormbreaker achievement
         assert!(!achievements.is_unlocked(AchievementId::TheStormbreaker));
-        let result = prog.on_boss_defeated(20, &mut achievements);
+        let result = prog.on_boss_defeated(20, 0, &mut achievements);
 
         match result {
             BossDefeatResult::WeaponRequired { weapon_name } => {
@@ -794,7 +875,7 @@ mod tests {
         achievements.unlock(AchievementId::TheStormbreaker, None);
         prog.fighting_boss = true;
 
-        let result = prog.on_boss_defeated(20, &mut achievements);
+        let result = prog.on_boss_defeated(20, 0, &mut achievements);
 
         // Should complete the game
         assert!(matches!(result, BossDefeatResult::StormsEnd));
@@ -837,7 +918,7 @@ mod tests {
         prog.unlock_zone(EXPANSE_ZONE_ID);
         prog.fighting_boss = true;
 
-        let result = prog.on_boss_defeated(20, &mut achievements);
+        let result = prog.on_boss_defeated(20, 0, &mut achievements);
         assert_eq!(result, BossDefeatResult::ExpanseCycle);
         assert_eq!(prog.current_zone_id, EXPANSE_ZONE_ID);
         assert_eq!(prog.current_subzone_id, 1);
@@ -859,7 +940,7 @@ mod tests {
                 for _ in 0..KILLS_FOR_BOSS {
                     prog.record_kill();
                 }
-                let result = prog.on_boss_defeated(20, &mut achievements);
+                let result = prog.on_boss_defeated(20, 0, &mut achievements);
                 assert!(
                     matches!(result, BossDefeatResult::SubzoneComplete { .. }),
                     "Cycle {cycle}, subzone {subzone}: expected SubzoneComplete, got {:?}",
@@ -872,7 +953,7 @@ mod tests {
             for _ in 0..KILLS_FOR_BOSS {
                 prog.record_kill();
             }
-            let result = prog.on_boss_defeated(20, &mut achievements);
+            let result = prog.on_boss_defeated(20, 0, &mut achievements);
             assert_eq!(
                 result,
                 BossDefeatResult::ExpanseCycle,
@@ -892,7 +973,7 @@ mod tests {
         prog.unlock_zone(EXPANSE_ZONE_ID);
         prog.fighting_boss = true;
 
-        prog.on_boss_defeated(20, &mut achievements);
+        prog.on_boss_defeated(20, 0, &mut achievements);
 
         // Boss defeat should be recorded (but the same boss can be defeated again)
         assert!(prog.is_boss_defeated(EXPANSE_ZONE_ID, 4));
@@ -1126,7 +1207,7 @@ mod tests {
         prog.defeat_boss(10, 2);
         prog.defeat_boss(10, 3);
 
-        let result = prog.on_boss_defeated(20, &mut achievements);
+        let result = prog.on_boss_defeated(20, 0, &mut achievements);
         assert_eq!(result, BossDefeatResult::StormsEnd);
         assert!(achievements.is_unlocked(AchievementId::StormsEnd));
         assert!(prog.is_zone_unlocked(EXPANSE_ZONE_ID));
@@ -1143,7 +1224,7 @@ mod tests {
         prog.unlock_zone(10);
         prog.fighting_boss = true;
 
-        let result = prog.on_boss_defeated(20, &mut achievements);
+        let result = prog.on_boss_defeated(20, 0, &mut achievements);
         match result {
             BossDefeatResult::WeaponRequired { weapon_name } => {
                 assert_eq!(weapon_name, "Stormbreaker");
@@ -7,6 +7,11 @@ use quest::challenges::ActiveMinigame;
 use quest::GameState;
 use quest::{ChessDifficulty, ChessResult};
 
+/// A fixed date, so results don't depend on today's challenge of the day
+fn today() -> chrono::NaiveDate {
+    chrono::NaiveDate::from_ymd_opt(2024, 1, 3).unwrap()
+}
+
 #[test]
 fn test_complete_chess_win_flow() {
     let mut state = GameState::new("Chess Master".to_string(), 0);
@@ -27,7 +32,7 @@ fn test_complete_chess_win_flow() {
     }
 
     // Apply result
-    let processed = apply_game_result(&mut state);
+    let processed = apply_game_result(&mut state, today());
     assert!(processed.is_some()); // Win returns Some(MinigameWinInfo)
     assert_eq!(state.prestige_rank, 10); // 5 + 5 (Master reward)
     assert!(state.active_minigame.is_none());
@@ -45,7 +50,7 @@ fn test_chess_loss_no_penalty() {
         panic!("expected chess");
     }
 
-    let processed = apply_game_result(&mut state);
+    let processed = apply_game_result(&mut state, today());
     assert!(processed.is_none()); // Loss returns None
     assert_eq!(state.prestige_rank, 3); // Unchanged
 }
@@ -62,7 +67,7 @@ fn test_chess_draw_no_penalty() {
         panic!("expected chess");
     }
 
-    let processed = apply_game_result(&mut state);
+    let processed = apply_game_result(&mut state, today());
     assert!(processed.is_none()); // Draw returns None
     assert_eq!(state.prestige_rank, 7); // Unchanged
 }
@@ -79,7 +84,7 @@ fn test_chess_forfeit_counts_as_loss() {
         panic!("expected chess");
     }
 
-    let processed = apply_game_result(&mut state);
+    let processed = apply_game_result(&mut state, today());
     assert!(processed.is_none()); // Forfeit returns None
     assert_eq!(state.prestige_rank, 2); // Unchanged
     assert_eq!(state.chess_stats.games_lost, 1);
@@ -113,7 +118,7 @@ fn test_chess_stats_tracking() {
     } else {
         panic!("expected chess");
     }
-    apply_game_result(&mut state);
+    apply_game_result(&mut state, today());
 
     // Lose a game
     start_chess_game(&mut state, ChessDifficulty::Master);
@@ -122,7 +127,7 @@ fn test_chess_stats_tracking() {
     } else {
         panic!("expected chess");
     }
-    apply_game_result(&mut state);
+    apply_game_result(&mut state, today());
 
     // Draw a game
     start_chess_game(&mut state, ChessDifficulty::Apprentice);
@@ -131,7 +136,7 @@ fn test_chess_stats_tracking() {
     } else {
         panic!("expected chess");
     }
-    apply_game_result(&mut state);
+    apply_game_result(&mut state, today());
 
     assert_eq!(state.chess_stats.games_played, 3);
     assert_eq!(state.chess_stats.games_won, 1);
@@ -153,7 +158,7 @@ fn test_prestige_accumulates_from_multiple_wins() {
         } else {
             panic!("expected chess");
         }
-        apply_game_result(&mut state);
+        apply_game_result(&mut state, today());
     }
 
     // Total prestige: 1 + 2 + 3 + 5 = 11
@@ -171,6 +171,10 @@ fn test_max_rank_behavior() {
         fish_toward_next_rank: 10000, // Way over requirement
         legendary_catches: 50,
         leviathan_encounters: 0,
+        hardcore: false,
+        auto_fishing: false,
+        last_spot: None,
+        tournament: None,
     };
 
     // At max rank, should still track fish but not rank up
@@ -195,6 +199,10 @@ fn test_multiple_rank_ups_in_sequence() {
         fish_toward_next_rank: 250, // Enough for 2 rank ups (100 + 100)
         legendary_catches: 0,
         leviathan_encounters: 0,
+        hardcore: false,
+        auto_fishing: false,
+        last_spot: None,
+        tournament: None,
     };
 
     // First rank up (100 fish, 150 remaining)
@@ -716,7 +724,7 @@ fn test_leviathan_tick_result_returns_encounter() {
         };
         state.active_fishing = Some(session);
 
-        let result = tick_fishing_with_haven_result(&mut state, &mut rng, &haven_bonuses);
+        let result = tick_fishing_with_haven_result(&mut state, &mut rng, &haven_bonuses, None);
 
         if result.leviathan_encounter.is_some() {
             assert_eq!(
@@ -774,6 +782,10 @@ fn test_fishing_state_serialization_skips_zero_encounters() {
         fish_toward_next_rank: 50,
         legendary_catches: 3,
         leviathan_encounters: 0,
+        hardcore: false,
+        auto_fishing: false,
+        last_spot: None,
+        tournament: None,
     };
 
     let json = serde_json::to_string(&state).unwrap();
@@ -794,6 +806,10 @@ fn test_fishing_state_serialization_includes_nonzero_encounters() {
         fish_toward_next_rank: 200,
         legendary_catches: 50,
         leviathan_encounters: 7,
+        hardcore: false,
+        auto_fishing: false,
+        last_spot: None,
+        tournament: None,
     };
 
     let json = serde_json::to_string(&state).unwrap();
@@ -830,6 +846,10 @@ fn test_fishing_state_roundtrip_with_encounters() {
         fish_toward_next_rank: 500,
         legendary_catches: 100,
         leviathan_encounters: 9,
+        hardcore: false,
+        auto_fishing: false,
+        last_spot: None,
+        tournament: None,
     };
 
     let json = serde_json::to_string(&original).unwrap();
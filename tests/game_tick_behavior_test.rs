@@ -424,7 +424,8 @@ fn test_fishing_tick_produces_messages() {
     state.active_fishing = Some(session);
 
     // Tick the fishing
-    let result = tick_fishing_with_haven_result(&mut state, &mut rng, &default_fishing_bonuses());
+    let result =
+        tick_fishing_with_haven_result(&mut state, &mut rng, &default_fishing_bonuses(), None);
 
     // game_tick processes these messages (lines 1137-1188)
     // Messages get added to combat_log with 🎣 prefix
@@ -456,7 +457,8 @@ fn test_fishing_tick_skips_combat() {
 
     // When fishing is active, game_tick returns early at line 1207
     // Combat should NOT be processed
-    let result = tick_fishing_with_haven_result(&mut state, &mut rng, &default_fishing_bonuses());
+    let result =
+        tick_fishing_with_haven_result(&mut state, &mut rng, &default_fishing_bonuses(), None);
 
     // After fishing tick returns, game_tick skips combat (line 1207: return)
     // Verify that combat would not have run
@@ -490,7 +492,7 @@ fn test_fishing_complete_session_updates_state() {
     // Tick until session completes
     let mut ticks = 0;
     while state.active_fishing.is_some() && ticks < 500 {
-        tick_fishing_with_haven_result(&mut state, &mut rng, &default_fishing_bonuses());
+        tick_fishing_with_haven_result(&mut state, &mut rng, &default_fishing_bonuses(), None);
         ticks += 1;
     }
 
@@ -869,6 +871,7 @@ fn test_haven_combat_bonuses_passed_to_update_combat() {
         crit_chance_percent: 5.0,
         double_strike_chance: 3.0,
         xp_gain_percent: 10.0,
+        emergency_heal_chance: 10.0,
     };
 
     // Sync derived stats
@@ -1036,7 +1039,7 @@ fn test_fishing_with_haven_bonuses() {
         max_fishing_rank_bonus: 0,
     };
 
-    let result = tick_fishing_with_haven_result(&mut state, &mut rng, &haven_fishing);
+    let result = tick_fishing_with_haven_result(&mut state, &mut rng, &haven_fishing, None);
 
     // Verify the tick processed (no panic, correct return type)
     let _ = result.messages;
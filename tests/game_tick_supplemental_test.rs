@@ -16,6 +16,9 @@ use quest::{GameState, TickEvent};
 use rand::SeedableRng;
 use rand_chacha::ChaCha8Rng;
 
+/// Fixed time between Elite Hunts, so spawns depend only on the seeded RNG
+const BETWEEN_HUNTS: i64 = quest::core::constants::ELITE_HUNT_ACTIVE_SECONDS;
+
 // =============================================================================
 // Helpers
 // =============================================================================
@@ -55,7 +58,16 @@ fn tick(
     ach: &mut Achievements,
     r: &mut ChaCha8Rng,
 ) -> Vec<TickEvent> {
-    game_tick(state, tc, &mut Haven::default(), ach, false, r).events
+    game_tick(
+        state,
+        tc,
+        &mut Haven::default(),
+        ach,
+        false,
+        r,
+        BETWEEN_HUNTS,
+    )
+    .events
 }
 
 fn has<F: Fn(&TickEvent) -> bool>(events: &[TickEvent], f: F) -> bool {
@@ -116,6 +128,7 @@ fn test_storm_leviathan_achievement_via_game_tick() {
             &mut ach,
             false,
             &mut r,
+            BETWEEN_HUNTS,
         );
         if has(&result.events, |e| {
             matches!(e, TickEvent::StormLeviathanCaught)
@@ -235,6 +248,7 @@ fn test_dungeon_boss_completion_triggers_achievement() {
             &mut ach,
             false,
             &mut r,
+            BETWEEN_HUNTS,
         );
         all_events.extend(result.events);
         if state.active_dungeon.is_none() {
@@ -337,6 +337,7 @@ fn test_score_reflects_attribute_specialization() {
             ..AttributeBonuses::new()
         },
         affixes: vec![],
+        sockets: Vec::new(),
     };
 
     let dex_item = Item {
@@ -350,6 +351,7 @@ fn test_score_reflects_attribute_specialization() {
             ..AttributeBonuses::new()
         },
         affixes: vec![],
+        sockets: Vec::new(),
     };
 
     let str_score = score_item(&str_item, &game_state);
@@ -416,6 +418,7 @@ fn test_auto_equip_higher_scored_item_replaces_lower() {
             ..AttributeBonuses::new()
         },
         affixes: vec![],
+        sockets: Vec::new(),
     };
     auto_equip_if_better(weak, &mut game_state);
 
@@ -450,6 +453,7 @@ fn test_auto_equip_higher_scored_item_replaces_lower() {
                 value: 30.0,
             },
         ],
+        sockets: Vec::new(),
     };
     let strong_score = score_item(&strong, &game_state);
     let replaced = auto_equip_if_better(strong, &mut game_state);
@@ -489,6 +493,7 @@ fn test_auto_equip_lower_scored_item_does_not_replace() {
             affix_type: AffixType::DamageReduction,
             value: 40.0,
         }],
+        sockets: Vec::new(),
     };
     auto_equip_if_better(strong, &mut game_state);
 
@@ -504,6 +509,7 @@ fn test_auto_equip_lower_scored_item_does_not_replace() {
             ..AttributeBonuses::new()
         },
         affixes: vec![],
+        sockets: Vec::new(),
     };
     let replaced = auto_equip_if_better(weak, &mut game_state);
 
@@ -538,6 +544,7 @@ fn test_auto_equip_across_different_slots_is_independent() {
             ..AttributeBonuses::new()
         },
         affixes: vec![],
+        sockets: Vec::new(),
     };
     let helmet = Item {
         slot: EquipmentSlot::Helmet,
@@ -550,6 +557,7 @@ fn test_auto_equip_across_different_slots_is_independent() {
             ..AttributeBonuses::new()
         },
         affixes: vec![],
+        sockets: Vec::new(),
     };
     let boots = Item {
         slot: EquipmentSlot::Boots,
@@ -562,6 +570,7 @@ fn test_auto_equip_across_different_slots_is_independent() {
             ..AttributeBonuses::new()
         },
         affixes: vec![],
+        sockets: Vec::new(),
     };
 
     assert!(auto_equip_if_better(weapon, &mut game_state));
@@ -642,6 +651,7 @@ fn test_full_pipeline_progressive_upgrade_chain() {
                 ..AttributeBonuses::new()
             },
             affixes: vec![],
+            sockets: Vec::new(),
         },
         Item {
             slot: EquipmentSlot::Weapon,
@@ -657,6 +667,7 @@ fn test_full_pipeline_progressive_upgrade_chain() {
                 affix_type: AffixType::DamagePercent,
                 value: 8.0,
             }],
+            sockets: Vec::new(),
         },
         Item {
             slot: EquipmentSlot::Weapon,
@@ -679,6 +690,7 @@ fn test_full_pipeline_progressive_upgrade_chain() {
                     value: 12.0,
                 },
             ],
+            sockets: Vec::new(),
         },
         Item {
             slot: EquipmentSlot::Weapon,
@@ -710,6 +722,7 @@ fn test_full_pipeline_progressive_upgrade_chain() {
                     value: 30.0,
                 },
             ],
+            sockets: Vec::new(),
         },
     ];
 
@@ -906,6 +919,7 @@ fn test_damage_percent_affix_outscores_hp_bonus_affix_at_same_value() {
             affix_type: AffixType::DamagePercent,
             value: 10.0,
         }],
+        sockets: Vec::new(),
     };
 
     let hp_item = Item {
@@ -919,6 +933,7 @@ fn test_damage_percent_affix_outscores_hp_bonus_affix_at_same_value() {
             affix_type: AffixType::HPBonus,
             value: 10.0,
         }],
+        sockets: Vec::new(),
     };
 
     let dmg_score = score_item(&dmg_item, &game_state);
@@ -965,6 +980,7 @@ fn test_score_affix_only_item_is_positive() {
                 value: 20.0,
             },
         ],
+        sockets: Vec::new(),
     };
 
     let score = score_item(&item, &game_state);
@@ -1070,6 +1086,7 @@ fn test_auto_equip_equal_score_does_not_replace() {
             ..AttributeBonuses::new()
         },
         affixes: vec![],
+        sockets: Vec::new(),
     };
 
     // Create an identical item (same stats, different name)
@@ -1084,6 +1101,7 @@ fn test_auto_equip_equal_score_does_not_replace() {
             ..AttributeBonuses::new()
         },
         affixes: vec![],
+        sockets: Vec::new(),
     };
 
     // Verify both items have the same score
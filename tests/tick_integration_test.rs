@@ -17,6 +17,9 @@ use quest::GameState;
 use rand::SeedableRng;
 use rand_chacha::ChaCha8Rng;
 
+/// Fixed time between Elite Hunts, so spawns depend only on the seeded RNG
+const BETWEEN_HUNTS: i64 = quest::core::constants::ELITE_HUNT_ACTIVE_SECONDS;
+
 fn test_rng() -> ChaCha8Rng {
     ChaCha8Rng::seed_from_u64(42)
 }
@@ -42,7 +45,15 @@ fn run_ticks(
 ) -> Vec<TickEvent> {
     let mut all_events = Vec::new();
     for _ in 0..count {
-        let result = game_tick(state, tick_counter, haven, achievements, false, rng);
+        let result = game_tick(
+            state,
+            tick_counter,
+            haven,
+            achievements,
+            false,
+            rng,
+            BETWEEN_HUNTS,
+        );
         all_events.extend(result.events);
     }
     all_events
@@ -63,7 +74,15 @@ where
 {
     let mut all_events = Vec::new();
     for _ in 0..max_ticks {
-        let result = game_tick(state, tick_counter, haven, achievements, false, rng);
+        let result = game_tick(
+            state,
+            tick_counter,
+            haven,
+            achievements,
+            false,
+            rng,
+            BETWEEN_HUNTS,
+        );
         let found = result.events.iter().any(&pred);
         all_events.extend(result.events);
         if found {
@@ -92,6 +111,7 @@ fn test_game_tick_returns_tick_result() {
         &mut achievements,
         false,
         &mut rng,
+        BETWEEN_HUNTS,
     );
 
     // TickResult should be valid
@@ -116,6 +136,7 @@ fn test_game_tick_spawns_enemy_on_first_tick() {
         &mut achievements,
         false,
         &mut rng,
+        BETWEEN_HUNTS,
     );
 
     assert!(
@@ -139,6 +160,7 @@ fn test_game_tick_increments_tick_counter() {
         &mut achievements,
         false,
         &mut rng,
+        BETWEEN_HUNTS,
     );
 
     assert_eq!(tick_counter, 1);
@@ -162,6 +184,7 @@ fn test_game_tick_play_time_after_10_ticks() {
             &mut achievements,
             false,
             &mut rng,
+            BETWEEN_HUNTS,
         );
     }
 
@@ -187,6 +210,7 @@ fn test_game_tick_play_time_not_incremented_before_10_ticks() {
             &mut achievements,
             false,
             &mut rng,
+            BETWEEN_HUNTS,
         );
     }
 
@@ -545,6 +569,7 @@ fn test_game_tick_fishing_produces_events() {
             &mut achievements,
             false,
             &mut rng,
+            BETWEEN_HUNTS,
         );
         all_events.extend(result.events);
         if state.active_fishing.is_none() {
@@ -609,6 +634,7 @@ fn test_game_tick_fishing_skips_combat() {
         &mut achievements,
         false,
         &mut rng,
+        BETWEEN_HUNTS,
     );
 
     // No combat events should be produced
@@ -667,6 +693,7 @@ fn test_game_tick_fishing_still_tracks_play_time() {
             &mut achievements,
             false,
             &mut rng,
+            BETWEEN_HUNTS,
         );
     }
 
@@ -704,6 +731,7 @@ fn test_game_tick_fish_caught_event_has_rarity() {
             &mut achievements,
             false,
             &mut rng,
+            BETWEEN_HUNTS,
         );
         all_events.extend(result.events);
         if state.active_fishing.is_none() {
@@ -796,6 +824,7 @@ fn test_game_tick_dungeon_failed_event_on_death() {
             &mut achievements,
             false,
             &mut rng,
+            BETWEEN_HUNTS,
         );
         for event in &result.events {
             if matches!(
@@ -954,6 +983,7 @@ fn test_game_tick_achievements_changed_flag() {
             &mut achievements,
             false,
             &mut rng,
+            BETWEEN_HUNTS,
         );
         if result.achievements_changed {
             achievements_changed = true;
@@ -991,6 +1021,7 @@ fn test_game_tick_debug_mode_suppresses_achievement_save() {
             &mut achievements,
             true, // debug_mode = true
             &mut rng,
+            BETWEEN_HUNTS,
         );
     }
     // No crash means success
@@ -1067,6 +1098,7 @@ fn test_simulator_rng_param_is_used_for_challenge_ai() {
             &mut achievements,
             false,
             &mut rng,
+            BETWEEN_HUNTS,
         );
         // Verify no panics and events are well-formed
         for event in &result.events {
@@ -1100,6 +1132,7 @@ fn test_simulator_different_seeds_produce_different_results() {
                 &mut achievements,
                 false,
                 &mut rng,
+                BETWEEN_HUNTS,
             );
         }
 
@@ -1143,6 +1176,7 @@ fn test_game_tick_syncs_max_hp_with_attributes() {
         &mut achievements,
         false,
         &mut rng,
+        BETWEEN_HUNTS,
     );
 
     assert!(
@@ -1180,6 +1214,7 @@ fn test_game_tick_returns_events_in_chronological_order() {
             &mut achievements,
             false,
             &mut rng,
+            BETWEEN_HUNTS,
         );
 
         if result.events.len() >= 2 {
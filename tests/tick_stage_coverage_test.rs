@@ -16,6 +16,9 @@ use quest::GameState;
 use rand::SeedableRng;
 use rand_chacha::ChaCha8Rng;
 
+/// Fixed time between Elite Hunts, so spawns depend only on the seeded RNG
+const BETWEEN_HUNTS: i64 = quest::core::constants::ELITE_HUNT_ACTIVE_SECONDS;
+
 fn test_rng() -> ChaCha8Rng {
     ChaCha8Rng::seed_from_u64(42)
 }
@@ -41,7 +44,15 @@ fn run_ticks_collecting(
 ) -> Vec<TickEvent> {
     let mut all_events = Vec::new();
     for _ in 0..count {
-        let result = game_tick(state, tick_counter, haven, achievements, false, rng);
+        let result = game_tick(
+            state,
+            tick_counter,
+            haven,
+            achievements,
+            false,
+            rng,
+            BETWEEN_HUNTS,
+        );
         all_events.extend(result.events);
     }
     all_events
@@ -57,7 +68,15 @@ fn run_ticks_collecting_results(
 ) -> Vec<TickResult> {
     let mut all_results = Vec::new();
     for _ in 0..count {
-        let result = game_tick(state, tick_counter, haven, achievements, false, rng);
+        let result = game_tick(
+            state,
+            tick_counter,
+            haven,
+            achievements,
+            false,
+            rng,
+            BETWEEN_HUNTS,
+        );
         all_results.push(result);
     }
     all_results
@@ -184,6 +203,7 @@ fn test_tick_event_player_died_in_dungeon() {
             &mut achievements,
             false,
             &mut rng,
+            BETWEEN_HUNTS,
         );
         for event in &result.events {
             if matches!(
@@ -381,6 +401,7 @@ fn test_tick_event_fish_caught_fields() {
             &mut achievements,
             false,
             &mut rng,
+            BETWEEN_HUNTS,
         );
         all_events.extend(result.events);
         if state.active_fishing.is_none() {
@@ -439,6 +460,7 @@ fn test_tick_event_fishing_message_for_phase_transitions() {
             &mut achievements,
             false,
             &mut rng,
+            BETWEEN_HUNTS,
         );
         all_events.extend(result.events);
         if state.active_fishing.is_none() {
@@ -492,6 +514,7 @@ fn test_play_time_increments_during_fishing_session() {
             &mut achievements,
             false,
             &mut rng,
+            BETWEEN_HUNTS,
         );
     }
 
@@ -624,6 +647,7 @@ fn test_haven_discovery_sets_haven_changed_and_achievements_changed() {
                 &mut achievements,
                 false,
                 &mut rng,
+                BETWEEN_HUNTS,
             );
 
             if result.haven_changed {
@@ -742,7 +766,15 @@ fn test_challenge_discovered_event_has_type_and_messages() {
         let mut a = Achievements::default();
 
         for _ in 0..5_000 {
-            let result = game_tick(&mut test_state, &mut tc, &mut h, &mut a, false, &mut rng);
+            let result = game_tick(
+                &mut test_state,
+                &mut tc,
+                &mut h,
+                &mut a,
+                false,
+                &mut rng,
+                BETWEEN_HUNTS,
+            );
             for event in &result.events {
                 if let TickEvent::ChallengeDiscovered {
                     challenge_type,
@@ -870,6 +902,7 @@ fn test_leveled_up_event_on_enemy_defeat() {
             &mut achievements,
             false,
             &mut rng,
+            BETWEEN_HUNTS,
         );
         for event in &result.events {
             if matches!(event, TickEvent::LeveledUp { .. }) {
@@ -975,6 +1008,7 @@ fn test_item_dropped_event_from_boss() {
             &mut achievements,
             false,
             &mut rng,
+            BETWEEN_HUNTS,
         );
         all_events.extend(result.events);
 
@@ -1035,6 +1069,7 @@ fn test_subzone_boss_defeated_advances_progression() {
             &mut achievements,
             false,
             &mut rng,
+            BETWEEN_HUNTS,
         );
         let found = result
             .events
@@ -1092,6 +1127,7 @@ fn test_achievement_unlocked_event_format() {
             &mut achievements,
             false,
             &mut rng,
+            BETWEEN_HUNTS,
         );
         let found = result
             .events
@@ -1136,8 +1172,13 @@ fn test_debug_mode_suppresses_haven_and_achievement_save_on_storm_leviathan() {
     // Run in debug mode
     for _ in 0..100 {
         let result = game_tick(
-            &mut state, &mut tick, &mut h, &mut a, true, // debug_mode = true
+            &mut state,
+            &mut tick,
+            &mut h,
+            &mut a,
+            true, // debug_mode = true
             &mut rng,
+            BETWEEN_HUNTS,
         );
 
         // In debug mode, haven_changed and achievements_changed for storm leviathan
@@ -1170,6 +1211,7 @@ fn test_tick_result_achievement_modal_ready() {
             &mut achievements,
             false,
             &mut rng,
+            BETWEEN_HUNTS,
         );
 
         if !result.achievement_modal_ready.is_empty() {
@@ -1211,6 +1253,7 @@ fn test_dungeon_boss_defeated_event_fields() {
             &mut achievements,
             false,
             &mut rng,
+            BETWEEN_HUNTS,
         );
 
         let found = result.events.iter().any(|e| {
@@ -1274,6 +1317,7 @@ fn test_dungeon_elite_defeated_event() {
             &mut achievements,
             false,
             &mut rng,
+            BETWEEN_HUNTS,
         );
         let found = result.events.iter().any(|e| {
             matches!(
@@ -1330,6 +1374,7 @@ fn test_prestige_flat_hp_applied_in_tick() {
         &mut achievements,
         false,
         &mut rng,
+        BETWEEN_HUNTS,
     );
 
     // Prestige rank 10 should give flat HP bonus
@@ -1362,6 +1407,7 @@ fn test_enemy_defeated_increments_session_kills_via_tick() {
             &mut achievements,
             false,
             &mut rng,
+            BETWEEN_HUNTS,
         );
         for event in &result.events {
             if matches!(event, TickEvent::EnemyDefeated { .. }) {
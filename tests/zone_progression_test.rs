@@ -8,6 +8,9 @@ use quest::core::KILLS_FOR_BOSS;
 use quest::zones::get_all_zones;
 use quest::zones::{BossDefeatResult, ZoneProgression};
 
+/// Gear score comfortably above every zone's `min_gear_score`
+const WELL_GEARED: u64 = 10_000;
+
 // ============================================================================
 // Unit test gaps (functions not directly tested)
 // ============================================================================
@@ -249,7 +252,7 @@ fn test_on_boss_defeated_at_zone_boundary() {
     prog.fighting_boss = true;
 
     // Defeat boss with prestige 4 (zone 3 needs P5)
-    let result = prog.on_boss_defeated(4, &mut achievements);
+    let result = prog.on_boss_defeated(4, WELL_GEARED, &mut achievements);
 
     // Should be gated
     match result {
@@ -298,7 +301,7 @@ fn test_complete_game_progression() {
                 achievements.unlock(AchievementId::TheStormbreaker, None);
             }
 
-            let result = prog.on_boss_defeated(20, &mut achievements);
+            let result = prog.on_boss_defeated(20, WELL_GEARED, &mut achievements);
 
             // Verify appropriate result
             if zone.id == 10 && subzone_id == zone.subzones.len() as u32 {
@@ -344,7 +347,7 @@ fn test_speedrun_to_zone_10() {
             achievements.unlock(AchievementId::TheStormbreaker, None);
         }
 
-        let result = prog.on_boss_defeated(20, &mut achievements);
+        let result = prog.on_boss_defeated(20, WELL_GEARED, &mut achievements);
 
         if subzone_id == zone10.subzones.len() as u32 {
             assert!(matches!(result, BossDefeatResult::StormsEnd));